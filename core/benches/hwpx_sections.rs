@@ -0,0 +1,104 @@
+//! HWPX section reading: bulk `parse` vs lazy `section_text_iter`.
+//!
+//! Run with: cargo bench --bench hwpx_sections
+//!
+//! Besides the criterion timings, prints the peak heap usage of each path
+//! (tracked by a counting global allocator) for a 120-section package.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use mdm_core::hwpx::{HwpxDocument, HwpxParser};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io::{Cursor, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct PeakAlloc;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let now = CURRENT.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(now, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakAlloc = PeakAlloc;
+
+/// Peak heap growth while running `f`, in bytes
+fn peak_during<T>(f: impl FnOnce() -> T) -> usize {
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    black_box(f());
+    PEAK.load(Ordering::Relaxed) - base
+}
+
+/// HWPX package with `sections` sections of `paragraphs` paragraphs each
+fn build_hwpx(sections: usize, paragraphs: usize) -> Vec<u8> {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default();
+    writer.start_file("mimetype", options).unwrap();
+    writer.write_all(b"application/hwp+zip").unwrap();
+
+    let paragraph = "<hp:p><hp:run><hp:t>제1조(목적) 이 법은 문서 변환 성능을 측정하기 위한 예시 조문이다.</hp:t></hp:run></hp:p>";
+    for idx in 0..sections {
+        writer.start_file(format!("Contents/section{}.xml", idx), options).unwrap();
+        writer
+            .write_all(br#"<hs:sec xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph">"#)
+            .unwrap();
+        for _ in 0..paragraphs {
+            writer.write_all(paragraph.as_bytes()).unwrap();
+        }
+        writer.write_all(b"</hs:sec>").unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
+
+/// Consume sections one at a time, keeping only a running length
+fn stream_sections(bytes: Vec<u8>) -> usize {
+    HwpxParser::from_bytes(bytes)
+        .unwrap()
+        .section_text_iter()
+        .map(|section| section.unwrap().0.len())
+        .sum()
+}
+
+fn hwpx_section_benchmark(c: &mut Criterion) {
+    let bytes = build_hwpx(120, 200);
+
+    let bulk = peak_during(|| HwpxParser::from_bytes(bytes.clone()).unwrap().parse().unwrap());
+    let lazy = peak_during(|| stream_sections(bytes.clone()));
+    let collected = peak_during(|| {
+        let mut parser = HwpxParser::from_bytes(bytes.clone()).unwrap();
+        let meta = parser.read_meta().unwrap();
+        HwpxDocument::from_iter(parser.section_text_iter(), meta).unwrap()
+    });
+    println!(
+        "hwpx peak heap (120 sections): parse {} KiB, section_text_iter {} KiB, from_iter {} KiB",
+        bulk / 1024,
+        lazy / 1024,
+        collected / 1024
+    );
+
+    let mut group = c.benchmark_group("hwpx_sections");
+    group.bench_function("parse", |b| {
+        b.iter(|| black_box(HwpxParser::from_bytes(bytes.clone()).unwrap().parse().unwrap()))
+    });
+    group.bench_function("section_text_iter", |b| {
+        b.iter(|| black_box(stream_sections(bytes.clone())))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, hwpx_section_benchmark);
+criterion_main!(benches);
@@ -3,6 +3,12 @@
 //! Run with: cargo bench
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use flate2::{write::DeflateEncoder, Compression};
+use mdm_core::cache::Cache;
+use mdm_core::hwp::{CachedHwpParser, HwpParser, SectionCache};
+use std::io::{Cursor, Write};
+use std::sync::Arc;
+use std::time::Duration;
 
 fn record_parsing_benchmark(c: &mut Criterion) {
     // Create sample record data
@@ -71,11 +77,82 @@ fn image_detection_benchmark(c: &mut Criterion) {
     });
 }
 
+/// Compressed HWP 5.0 file with `sections` sections of `paragraphs` paragraphs each
+fn build_compressed_hwp(sections: usize, paragraphs: usize) -> Vec<u8> {
+    fn record(tag: u32, level: u32, data: &[u8]) -> Vec<u8> {
+        let header = tag | (level << 10) | ((data.len() as u32) << 20);
+        let mut out = header.to_le_bytes().to_vec();
+        out.extend_from_slice(data);
+        out
+    }
+    fn deflate(data: &[u8]) -> Vec<u8> {
+        let mut enc = DeflateEncoder::new(Vec::new(), Compression::default());
+        enc.write_all(data).unwrap();
+        enc.finish().unwrap()
+    }
+
+    let mut cf = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+    let mut header = vec![0u8; 256];
+    header[..17].copy_from_slice(b"HWP Document File");
+    header[32..36].copy_from_slice(&[0, 3, 0, 5]);
+    header[36] = 0x01; // compressed
+    cf.create_stream("/FileHeader").unwrap().write_all(&header).unwrap();
+    cf.create_stream("/DocInfo").unwrap().write_all(&deflate(&[])).unwrap();
+    cf.create_storage("/BodyText").unwrap();
+
+    let text: Vec<u8> = "안녕하세요 테스트 문자열입니다\r"
+        .encode_utf16()
+        .flat_map(|u| u.to_le_bytes())
+        .collect();
+    for idx in 0..sections {
+        let mut body = Vec::new();
+        for _ in 0..paragraphs {
+            body.extend(record(0x42, 0, &[0u8; 22])); // PARA_HEADER
+            body.extend(record(0x43, 1, &text)); // PARA_TEXT
+        }
+        let path = format!("/BodyText/Section{}", idx);
+        cf.create_stream(&path).unwrap().write_all(&deflate(&body)).unwrap();
+    }
+
+    cf.flush().unwrap();
+    cf.into_inner().into_inner()
+}
+
+fn section_cache_benchmark(c: &mut Criterion) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("bench.hwp");
+    std::fs::write(&path, build_compressed_hwp(10, 200)).unwrap();
+
+    let cache: Arc<SectionCache> =
+        Arc::new(Cache::new(dir.path().join("cache"), Duration::from_secs(3600)).unwrap());
+    // Warm the cache so the cached run measures hits only
+    CachedHwpParser::open(&path, Arc::clone(&cache))
+        .unwrap()
+        .extract_text()
+        .unwrap();
+
+    let mut group = c.benchmark_group("hwp_sections");
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            let mut parser = HwpParser::open(&path).unwrap();
+            black_box(parser.extract_text().unwrap())
+        })
+    });
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            let mut parser = CachedHwpParser::open(&path, Arc::clone(&cache)).unwrap();
+            black_box(parser.extract_text().unwrap())
+        })
+    });
+    group.finish();
+}
+
 criterion_group!(
     benches,
     record_parsing_benchmark,
     text_extraction_benchmark,
-    image_detection_benchmark
+    image_detection_benchmark,
+    section_cache_benchmark
 );
 
 criterion_main!(benches);
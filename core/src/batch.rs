@@ -0,0 +1,152 @@
+//! Batch input discovery for `hwp2mdm batch`
+//!
+//! Resolves a glob pattern such as `"docs/**/*.{hwp,hwpx,pdf}"` to a sorted
+//! list of files and maps each one to an output directory that mirrors the
+//! source tree below the pattern's literal base directory.
+//!
+//! The `glob` crate has no brace alternation, so `{a,b}` groups are
+//! expanded here first and each alternative is matched separately.
+
+use std::path::{Path, PathBuf};
+
+/// Expand `{a,b,...}` groups into one pattern per alternative.
+///
+/// Groups may be nested (`"*.{hwp{,x},pdf}"`). A `{` without a matching
+/// `}` is left as-is.
+pub fn expand_braces(pattern: &str) -> Vec<String> {
+    let Some(open) = pattern.find('{') else {
+        return vec![pattern.to_string()];
+    };
+
+    // Find the matching close brace and the top-level commas inside it
+    let mut depth = 0usize;
+    let mut commas = Vec::new();
+    let mut close = None;
+    for (i, c) in pattern[open..].char_indices() {
+        let i = open + i;
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(i);
+                    break;
+                }
+            }
+            ',' if depth == 1 => commas.push(i),
+            _ => {}
+        }
+    }
+    let Some(close) = close else {
+        return vec![pattern.to_string()];
+    };
+
+    let prefix = &pattern[..open];
+    let suffix = &pattern[close + 1..];
+    let mut bounds = vec![open];
+    bounds.extend(&commas);
+    bounds.push(close);
+
+    let mut out = Vec::new();
+    for pair in bounds.windows(2) {
+        let alternative = &pattern[pair[0] + 1..pair[1]];
+        out.extend(expand_braces(&format!("{}{}{}", prefix, alternative, suffix)));
+    }
+    out
+}
+
+/// Leading directory of `pattern` that contains no glob metacharacters.
+///
+/// `"src/**/*.hwp"` → `src`, `"*.hwp"` → `.`. Output paths are made
+/// relative to this directory.
+pub fn glob_base(pattern: &str) -> PathBuf {
+    let is_meta = |s: &str| s.contains(['*', '?', '[', '{']);
+    let path = Path::new(pattern);
+    let mut base = PathBuf::new();
+    let components: Vec<_> = path.components().collect();
+    for (i, component) in components.iter().enumerate() {
+        let text = component.as_os_str().to_string_lossy();
+        // The last component is the file pattern even when it is literal
+        if is_meta(&text) || i + 1 == components.len() {
+            break;
+        }
+        base.push(component.as_os_str());
+    }
+    if base.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        base
+    }
+}
+
+/// Resolve `pattern` to the matching files, sorted and de-duplicated.
+///
+/// Directories matched by the pattern are skipped, as are entries that
+/// cannot be read while walking.
+pub fn collect_inputs(pattern: &str) -> Result<Vec<PathBuf>, glob::PatternError> {
+    let mut files = Vec::new();
+    for expanded in expand_braces(pattern) {
+        for entry in glob::glob(&expanded)?.flatten() {
+            if entry.is_file() {
+                files.push(entry);
+            }
+        }
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Output directory for `input`: its directory relative to `base`, joined
+/// onto `output`. Inputs outside `base` go straight into `output`.
+pub fn mirrored_output_dir(input: &Path, base: &Path, output: &Path) -> PathBuf {
+    let parent = input.parent().unwrap_or(Path::new(""));
+    let base = base.strip_prefix(".").unwrap_or(base);
+    let parent = parent.strip_prefix(".").unwrap_or(parent);
+    match parent.strip_prefix(base) {
+        Ok(relative) => output.join(relative),
+        Err(_) => output.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_braces() {
+        assert_eq!(expand_braces("*.hwp"), vec!["*.hwp"]);
+        assert_eq!(
+            expand_braces("src/**/*.{hwp,hwpx,pdf}"),
+            vec!["src/**/*.hwp", "src/**/*.hwpx", "src/**/*.pdf"]
+        );
+        assert_eq!(expand_braces("{a,b}/*.{x,y}"), vec!["a/*.x", "a/*.y", "b/*.x", "b/*.y"]);
+        assert_eq!(expand_braces("*.{hwp{,x},pdf}"), vec!["*.hwp", "*.hwpx", "*.pdf"]);
+        assert_eq!(expand_braces("broken{a,b"), vec!["broken{a,b"]);
+    }
+
+    #[test]
+    fn test_glob_base() {
+        assert_eq!(glob_base("src/**/*.hwp"), PathBuf::from("src"));
+        assert_eq!(glob_base("docs/legal/*.{hwp,pdf}"), PathBuf::from("docs/legal"));
+        assert_eq!(glob_base("*.hwp"), PathBuf::from("."));
+        assert_eq!(glob_base("docs/report.hwp"), PathBuf::from("docs"));
+    }
+
+    #[test]
+    fn test_mirrored_output_dir() {
+        let out = Path::new("out");
+        assert_eq!(
+            mirrored_output_dir(Path::new("src/a/b/x.hwp"), Path::new("src"), out),
+            PathBuf::from("out/a/b")
+        );
+        assert_eq!(
+            mirrored_output_dir(Path::new("./x.hwp"), Path::new("."), out),
+            PathBuf::from("out")
+        );
+        assert_eq!(
+            mirrored_output_dir(Path::new("elsewhere/x.hwp"), Path::new("src"), out),
+            PathBuf::from("out")
+        );
+    }
+}
@@ -1,67 +1,587 @@
-//! Caching module for rendered media
+//! Disk-backed LRU cache for conversion results
+//!
+//! Entries are `bincode`-serialized into `{sha256(key)}.bin` files under a
+//! cache directory, behind a header carrying the entry schema version and
+//! the SHA-256 of the payload so truncated, foreign or outdated files read
+//! as misses. An entry is live while its
+//! file mtime is within `ttl`; the in-memory recency list bounds the number
+//! of files kept on disk.
 
-use std::collections::HashMap;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{self, Write};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
 
-/// Simple in-memory cache for rendered media
-pub struct Cache {
-    store: HashMap<String, Vec<u8>>,
-    max_size: usize,
+/// Key for document-level cache entries: SHA-256 digest of the source
+/// document (see `HwpParser::source_hash`).
+pub type DocumentKey = [u8; 32];
+
+/// Default number of entries kept on disk before LRU eviction
+const DEFAULT_CAPACITY: usize = 1000;
+
+/// Cache file extension
+const ENTRY_EXT: &str = "bin";
+
+/// Layout version of cached values, written into every entry header.
+///
+/// bincode 1 is not self-describing: an entry written before a cached type
+/// (`MdmDocument`, `ImageType`, ...) gained, lost or reordered a field can
+/// still decode — into the wrong fields. Bump this whenever such a type's
+/// serialized layout changes so older entries read as misses.
+pub const ENTRY_SCHEMA_VERSION: u32 = 2;
+
+/// Entry file header: magic, schema version (u32 LE), then SHA-256 of the
+/// payload that follows
+const ENTRY_MAGIC: &[u8; 4] = b"MDMC";
+const HEADER_LEN: usize = ENTRY_MAGIC.len() + 4 + 32;
+
+/// Thread-safe LRU cache persisted to a directory.
+///
+/// Share across threads with `Arc<Cache<K, V>>`; all methods take `&self`.
+pub struct Cache<K, V> {
+    dir: PathBuf,
+    ttl: Duration,
+    capacity: usize,
+    /// File stems in recency order (front = least recently used)
+    recency: Mutex<VecDeque<String>>,
+    _marker: PhantomData<fn(&K) -> V>,
 }
 
-impl Cache {
-    pub fn new(max_size: usize) -> Self {
-        Self {
-            store: HashMap::new(),
-            max_size,
+impl<K: Serialize, V: Serialize + DeserializeOwned> Cache<K, V> {
+    /// Open (creating if needed) a cache in `dir` whose entries expire after `ttl`
+    pub fn new<P: Into<PathBuf>>(dir: P, ttl: Duration) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            ttl,
+            capacity: DEFAULT_CAPACITY,
+            recency: Mutex::new(VecDeque::new()),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Limit the number of entries kept on disk (minimum 1)
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Cache directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Entry time-to-live
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Read `key`'s entry if present and not older than `ttl`.
+    ///
+    /// Unreadable or corrupt entries, and entries written under another
+    /// [`ENTRY_SCHEMA_VERSION`], count as misses.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let stem = Self::stem(key)?;
+        let path = self.entry_path(&stem);
+        let meta = fs::metadata(&path).ok()?;
+        if self.is_expired(&meta) {
+            return None;
         }
+        let bytes = fs::read(&path).ok()?;
+        let value = bincode::deserialize(unseal(&bytes)?).ok()?;
+        self.touch(stem);
+        Some(value)
     }
 
-    /// Get cached item by key
-    pub fn get(&self, key: &str) -> Option<&Vec<u8>> {
-        self.store.get(key)
+    /// Write `value` under `key`, evicting the least recently used entry
+    /// when the cache is over capacity.
+    pub fn insert(&self, key: &K, value: &V) -> io::Result<()> {
+        let stem = Self::stem(key)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "unserializable cache key"))?;
+        let payload = bincode::serialize(value)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        self.persist_entry(&stem, &seal(&payload))?;
+
+        for evicted in self.touch(stem) {
+            let _ = fs::remove_file(self.entry_path(&evicted));
+        }
+        Ok(())
     }
 
-    /// Store item in cache
-    pub fn set(&mut self, key: String, value: Vec<u8>) -> bool {
-        if self.store.len() >= self.max_size {
-            if let Some(first_key) = self.store.keys().next().cloned() {
-                self.store.remove(&first_key);
+    /// Load the live entries of `dir` — typically a cache volume left by an
+    /// earlier process — without touching any source document.
+    ///
+    /// Each `.bin` entry whose checksum header verifies and whose payload
+    /// decodes as `V` is registered in the recency list, newest first up to
+    /// the capacity; entries from another directory are copied into this
+    /// cache's own. Expired, corrupt and unrelated files are skipped.
+    /// Returns the number of entries loaded.
+    pub fn preload_dir(&self, dir: &Path) -> io::Result<usize> {
+        let same_dir = fs::canonicalize(dir)? == fs::canonicalize(&self.dir)?;
+
+        let mut candidates = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(ENTRY_EXT) {
+                continue;
+            }
+            let Some(stem) = path.file_stem().and_then(|s| s.to_str()).filter(|s| is_digest_stem(s)) else {
+                continue;
+            };
+            let Ok(meta) = fs::metadata(&path) else { continue };
+            if self.is_expired(&meta) {
+                continue;
             }
+            let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            candidates.push((modified, stem.to_string(), path));
+        }
+        // Newest first, so the capacity keeps the most recent entries
+        candidates.sort_by(|a, b| b.0.cmp(&a.0));
+
+        let mut loaded = Vec::new();
+        for (_, stem, path) in candidates {
+            if loaded.len() == self.capacity {
+                break;
+            }
+            let Ok(bytes) = fs::read(&path) else { continue };
+            let valid = unseal(&bytes).is_some_and(|payload| bincode::deserialize::<V>(payload).is_ok());
+            if !valid {
+                continue;
+            }
+            if !same_dir {
+                self.persist_entry(&stem, &bytes)?;
+            }
+            loaded.push(stem);
+        }
+
+        // Oldest first, leaving the newest entry most recently used
+        let count = loaded.len();
+        for stem in loaded.into_iter().rev() {
+            for evicted in self.touch(stem) {
+                let _ = fs::remove_file(self.entry_path(&evicted));
+            }
+        }
+        Ok(count)
+    }
+
+    /// Remove `key`'s entry. Returns `true` if a file was deleted.
+    pub fn remove(&self, key: &K) -> bool {
+        let Some(stem) = Self::stem(key) else {
+            return false;
+        };
+        self.lock().retain(|s| *s != stem);
+        fs::remove_file(self.entry_path(&stem)).is_ok()
+    }
+
+    /// Delete every entry file older than `ttl`. Returns the number removed.
+    pub fn evict_expired(&self) -> io::Result<usize> {
+        let mut removed = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(ENTRY_EXT) {
+                continue;
+            }
+            let Ok(meta) = entry.metadata() else { continue };
+            if self.is_expired(&meta) && fs::remove_file(&path).is_ok() {
+                if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                    removed.push(stem.to_string());
+                }
+            }
+        }
+        if !removed.is_empty() {
+            self.lock().retain(|s| !removed.contains(s));
         }
-        self.store.insert(key, value);
-        true
+        Ok(removed.len())
     }
 
-    /// Clear all cached items
-    pub fn clear(&mut self) {
-        self.store.clear();
+    /// Delete all entries
+    pub fn clear(&self) -> io::Result<()> {
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) == Some(ENTRY_EXT) {
+                fs::remove_file(&path)?;
+            }
+        }
+        self.lock().clear();
+        Ok(())
     }
 
-    /// Get current cache size
+    /// Number of entries tracked by this cache instance
     pub fn len(&self) -> usize {
-        self.store.len()
+        self.lock().len()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.store.is_empty()
+        self.lock().is_empty()
+    }
+
+    /// Hex SHA-256 of the bincode-encoded key
+    fn stem(key: &K) -> Option<String> {
+        let encoded = bincode::serialize(key).ok()?;
+        let digest = Sha256::digest(&encoded);
+        Some(digest.iter().map(|b| format!("{:02x}", b)).collect())
+    }
+
+    /// Write an entry file atomically
+    fn persist_entry(&self, stem: &str, bytes: &[u8]) -> io::Result<()> {
+        // Write to a temp file and rename so concurrent readers never see a
+        // partially written entry.
+        let mut tmp = tempfile::NamedTempFile::new_in(&self.dir)?;
+        tmp.write_all(bytes)?;
+        tmp.persist(self.entry_path(stem)).map_err(|e| e.error)?;
+        Ok(())
+    }
+
+    fn entry_path(&self, stem: &str) -> PathBuf {
+        self.dir.join(format!("{}.{}", stem, ENTRY_EXT))
+    }
+
+    fn is_expired(&self, meta: &fs::Metadata) -> bool {
+        match meta.modified().ok().and_then(|m| SystemTime::now().duration_since(m).ok()) {
+            Some(age) => age > self.ttl,
+            // mtime in the future (clock skew) — treat as fresh
+            None => false,
+        }
+    }
+
+    /// Mark `stem` most recently used; returns stems pushed out by capacity
+    fn touch(&self, stem: String) -> Vec<String> {
+        let mut recency = self.lock();
+        recency.retain(|s| *s != stem);
+        recency.push_back(stem);
+        let mut evicted = Vec::new();
+        while recency.len() > self.capacity {
+            if let Some(old) = recency.pop_front() {
+                evicted.push(old);
+            }
+        }
+        evicted
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<String>> {
+        // A panic while holding the lock leaves the list consistent enough
+        // (it only tracks recency), so recover instead of propagating.
+        self.recency.lock().unwrap_or_else(|e| e.into_inner())
     }
 }
 
-impl Default for Cache {
-    fn default() -> Self {
-        Self::new(1000)
+/// Prefix `payload` with the entry header
+fn seal(payload: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+    bytes.extend_from_slice(ENTRY_MAGIC);
+    bytes.extend_from_slice(&ENTRY_SCHEMA_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&Sha256::digest(payload));
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+/// Payload of an entry file, or `None` when the header is missing, was
+/// written under another schema version or its checksum does not match
+fn unseal(bytes: &[u8]) -> Option<&[u8]> {
+    let header = bytes.get(..HEADER_LEN)?;
+    let payload = &bytes[HEADER_LEN..];
+    let (version, checksum) = header[ENTRY_MAGIC.len()..].split_at(4);
+    (header.starts_with(ENTRY_MAGIC)
+        && version == ENTRY_SCHEMA_VERSION.to_le_bytes()
+        && checksum == &Sha256::digest(payload)[..])
+        .then_some(payload)
+}
+
+/// File stems written by [`Cache::stem`]: 64 lowercase hex digits
+fn is_digest_stem(stem: &str) -> bool {
+    stem.len() == 64 && stem.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+}
+
+#[cfg(feature = "tokio")]
+impl<K, V> Cache<K, V>
+where
+    K: Serialize + Send + 'static,
+    V: Serialize + DeserializeOwned + Send + 'static,
+{
+    /// [`Cache::get`] on tokio's blocking pool, for use from async handlers
+    pub async fn get_async(self: &std::sync::Arc<Self>, key: K) -> Option<V> {
+        let cache = std::sync::Arc::clone(self);
+        tokio::task::spawn_blocking(move || cache.get(&key)).await.ok().flatten()
+    }
+
+    /// [`Cache::insert`] on tokio's blocking pool
+    pub async fn insert_async(self: &std::sync::Arc<Self>, key: K, value: V) -> io::Result<()> {
+        let cache = std::sync::Arc::clone(self);
+        tokio::task::spawn_blocking(move || cache.insert(&key, &value))
+            .await
+            .map_err(io::Error::other)?
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    fn cache<V: Serialize + DeserializeOwned>(dir: &Path) -> Cache<String, V> {
+        Cache::new(dir, HOUR).unwrap()
+    }
+
+    /// Backdate `key`'s entry file by `age`
+    fn age_entry(cache: &Cache<String, Vec<u8>>, key: &str, age: Duration) {
+        let path = cache.entry_path(&Cache::<String, Vec<u8>>::stem(&key.to_string()).unwrap());
+        let file = fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(SystemTime::now() - age).unwrap();
+    }
 
     #[test]
     fn test_cache_operations() {
-        let mut cache = Cache::new(10);
-        cache.set("key1".to_string(), vec![1, 2, 3]);
-        assert!(cache.get("key1").is_some());
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache::<Vec<u8>>(dir.path());
+        cache.insert(&"key1".to_string(), &vec![1, 2, 3]).unwrap();
+        assert_eq!(cache.get(&"key1".to_string()), Some(vec![1, 2, 3]));
         assert_eq!(cache.len(), 1);
     }
+
+    #[test]
+    fn test_miss_on_unknown_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache::<Vec<u8>>(dir.path());
+        assert_eq!(cache.get(&"absent".to_string()), None);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_entry_file_named_by_key_sha256() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache::<u32>(dir.path());
+        cache.insert(&"abc".to_string(), &7).unwrap();
+
+        let encoded = bincode::serialize(&"abc".to_string()).unwrap();
+        let expected: String = Sha256::digest(&encoded).iter().map(|b| format!("{:02x}", b)).collect();
+        assert!(dir.path().join(format!("{}.bin", expected)).exists());
+    }
+
+    #[test]
+    fn test_insert_overwrites() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache::<String>(dir.path());
+        let key = "doc".to_string();
+        cache.insert(&key, &"v1".to_string()).unwrap();
+        cache.insert(&key, &"v2".to_string()).unwrap();
+        assert_eq!(cache.get(&key).as_deref(), Some("v2"));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_persists_across_instances() {
+        let dir = tempfile::tempdir().unwrap();
+        cache::<u64>(dir.path()).insert(&"k".to_string(), &42).unwrap();
+        assert_eq!(cache::<u64>(dir.path()).get(&"k".to_string()), Some(42));
+    }
+
+    #[test]
+    fn test_ttl_expiry_is_a_miss() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache: Cache<String, Vec<u8>> = Cache::new(dir.path(), Duration::from_secs(60)).unwrap();
+        cache.insert(&"old".to_string(), &vec![1]).unwrap();
+        assert!(cache.get(&"old".to_string()).is_some());
+
+        age_entry(&cache, "old", Duration::from_secs(120));
+        assert_eq!(cache.get(&"old".to_string()), None);
+    }
+
+    #[test]
+    fn test_evict_expired_removes_only_stale_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache: Cache<String, Vec<u8>> = Cache::new(dir.path(), Duration::from_secs(60)).unwrap();
+        cache.insert(&"stale".to_string(), &vec![1]).unwrap();
+        cache.insert(&"fresh".to_string(), &vec![2]).unwrap();
+        age_entry(&cache, "stale", Duration::from_secs(600));
+
+        assert_eq!(cache.evict_expired().unwrap(), 1);
+        assert_eq!(cache.get(&"fresh".to_string()), Some(vec![2]));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_lru_eviction_over_capacity() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache::<u8>(dir.path()).with_capacity(2);
+        cache.insert(&"a".to_string(), &1).unwrap();
+        cache.insert(&"b".to_string(), &2).unwrap();
+        // Touch "a" so "b" becomes least recently used
+        assert_eq!(cache.get(&"a".to_string()), Some(1));
+        cache.insert(&"c".to_string(), &3).unwrap();
+
+        assert_eq!(cache.get(&"b".to_string()), None);
+        assert_eq!(cache.get(&"a".to_string()), Some(1));
+        assert_eq!(cache.get(&"c".to_string()), Some(3));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_corrupt_entry_is_a_miss() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache::<Vec<String>>(dir.path());
+        let key = "bad".to_string();
+        let stem = Cache::<String, Vec<String>>::stem(&key).unwrap();
+        fs::write(cache.entry_path(&stem), [0xFF; 3]).unwrap();
+        assert_eq!(cache.get(&key), None);
+    }
+
+    #[test]
+    fn test_checksum_mismatch_is_a_miss() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache::<String>(dir.path());
+        let key = "doc".to_string();
+        cache.insert(&key, &"본문".to_string()).unwrap();
+
+        let path = cache.entry_path(&Cache::<String, String>::stem(&key).unwrap());
+        let mut bytes = fs::read(&path).unwrap();
+        *bytes.last_mut().unwrap() ^= 0x01;
+        fs::write(&path, &bytes).unwrap();
+        assert_eq!(cache.get(&key), None);
+    }
+
+    #[test]
+    fn test_other_schema_version_is_a_miss() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache::<String>(dir.path());
+        let key = "doc".to_string();
+        cache.insert(&key, &"본문".to_string()).unwrap();
+
+        let path = cache.entry_path(&Cache::<String, String>::stem(&key).unwrap());
+        let mut bytes = fs::read(&path).unwrap();
+        let version = ENTRY_MAGIC.len()..ENTRY_MAGIC.len() + 4;
+        bytes[version].copy_from_slice(&(ENTRY_SCHEMA_VERSION + 1).to_le_bytes());
+        fs::write(&path, &bytes).unwrap();
+        assert_eq!(cache.get(&key), None);
+        assert_eq!(cache.preload_dir(dir.path()).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_preload_dir_warms_fresh_cache() {
+        let volume = tempfile::tempdir().unwrap();
+        let previous = cache::<Vec<u8>>(volume.path());
+        previous.insert(&"a".to_string(), &vec![1]).unwrap();
+        previous.insert(&"b".to_string(), &vec![2, 2]).unwrap();
+        drop(previous);
+
+        let dir = tempfile::tempdir().unwrap();
+        let fresh = cache::<Vec<u8>>(dir.path());
+        assert!(fresh.is_empty());
+        assert_eq!(fresh.preload_dir(volume.path()).unwrap(), 2);
+        assert_eq!(fresh.len(), 2);
+
+        // Served from the fresh cache's own directory
+        drop(volume);
+        assert_eq!(fresh.get(&"a".to_string()), Some(vec![1]));
+        assert_eq!(fresh.get(&"b".to_string()), Some(vec![2, 2]));
+        assert_eq!(fresh.get(&"c".to_string()), None);
+    }
+
+    #[test]
+    fn test_preload_own_dir_restores_lru_bookkeeping() {
+        let dir = tempfile::tempdir().unwrap();
+        let previous = cache::<u8>(dir.path());
+        for (key, age) in [("old", 30), ("mid", 20), ("new", 10)] {
+            previous.insert(&key.to_string(), &1).unwrap();
+            let path = previous.entry_path(&Cache::<String, u8>::stem(&key.to_string()).unwrap());
+            let file = fs::File::options().write(true).open(path).unwrap();
+            file.set_modified(SystemTime::now() - Duration::from_secs(age)).unwrap();
+        }
+
+        // Only the newest entries fit; the next insert evicts the oldest of them
+        let restarted = cache::<u8>(dir.path()).with_capacity(2);
+        assert_eq!(restarted.preload_dir(dir.path()).unwrap(), 2);
+        restarted.insert(&"extra".to_string(), &2).unwrap();
+        assert_eq!(restarted.get(&"mid".to_string()), None);
+        assert_eq!(restarted.get(&"new".to_string()), Some(1));
+        assert_eq!(restarted.get(&"extra".to_string()), Some(2));
+    }
+
+    #[test]
+    fn test_preload_skips_invalid_entries() {
+        let volume = tempfile::tempdir().unwrap();
+        let previous: Cache<String, Vec<u8>> = Cache::new(volume.path(), Duration::from_secs(60)).unwrap();
+        previous.insert(&"good".to_string(), &vec![1]).unwrap();
+        previous.insert(&"stale".to_string(), &vec![2]).unwrap();
+        age_entry(&previous, "stale", Duration::from_secs(600));
+        // Pre-checksum entry, foreign file name, unrelated file
+        fs::write(volume.path().join(format!("{}.bin", "0".repeat(64))), [1, 0, 0, 0, 0, 0, 0, 0, 7]).unwrap();
+        fs::write(volume.path().join("notes.bin"), seal(b"x")).unwrap();
+        fs::write(volume.path().join("README"), b"cache volume").unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let fresh: Cache<String, Vec<u8>> = Cache::new(dir.path(), Duration::from_secs(60)).unwrap();
+        assert_eq!(fresh.preload_dir(volume.path()).unwrap(), 1);
+        assert_eq!(fresh.get(&"good".to_string()), Some(vec![1]));
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_preload_missing_dir_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache::<u8>(dir.path());
+        assert!(cache.preload_dir(&dir.path().join("absent")).is_err());
+    }
+
+    #[test]
+    fn test_remove_and_clear() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache::<u8>(dir.path());
+        cache.insert(&"x".to_string(), &1).unwrap();
+        cache.insert(&"y".to_string(), &2).unwrap();
+        assert!(cache.remove(&"x".to_string()));
+        assert!(!cache.remove(&"x".to_string()));
+        cache.clear().unwrap();
+        assert!(cache.is_empty());
+        assert_eq!(cache.get(&"y".to_string()), None);
+    }
+
+    #[test]
+    fn test_document_key_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache: Cache<DocumentKey, String> = Cache::new(dir.path(), HOUR).unwrap();
+        cache.insert(&[7u8; 32], &"본문".to_string()).unwrap();
+        assert_eq!(cache.get(&[7u8; 32]).as_deref(), Some("본문"));
+        assert_eq!(cache.get(&[8u8; 32]), None);
+    }
+
+    #[test]
+    fn test_concurrent_access_via_arc() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache: Arc<Cache<String, Vec<u32>>> = Arc::new(Cache::new(dir.path(), HOUR).unwrap());
+
+        let handles: Vec<_> = (0..8u32)
+            .map(|t| {
+                let cache = Arc::clone(&cache);
+                thread::spawn(move || {
+                    for i in 0..20u32 {
+                        let key = format!("k{}", i % 5);
+                        cache.insert(&key, &vec![t, i]).unwrap();
+                        // Every read sees some complete value, never a torn write
+                        let v = cache.get(&key).expect("entry present");
+                        assert_eq!(v.len(), 2);
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+
+        assert_eq!(cache.len(), 5);
+        for i in 0..5 {
+            assert!(cache.get(&format!("k{}", i)).is_some());
+        }
+    }
 }
@@ -8,6 +8,17 @@
 //! Approach adapted from microsoft/markitdown's `converter_utils/docx/math/`
 //! (itself derived from xiilei/dwml) — both MIT-licensed. The logic here is a
 //! Rust rewrite, not a copy; LaTeX emission rules are the common reference.
+//!
+//! [`extract_math`] collects whole equations (raw OMML plus LaTeX) for
+//! callers that want them outside the paragraph text. With the
+//! `pandoc-math` feature the LaTeX comes from `pandoc` when it is installed,
+//! falling back to the built-in converter.
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+
+use super::parser::comment_safe;
 
 /// A node in the partially-built math expression tree.
 #[derive(Debug)]
@@ -76,7 +87,8 @@ enum BarPos { Top, Bottom }
 enum PropsKind { Nary, D, Acc, Bar, GroupChr, Other }
 
 /// Output kind once finalized.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum MathKind {
     /// `$...$` inline (from `m:oMath`).
     Inline,
@@ -270,7 +282,17 @@ impl OmmlBuilder {
     }
 
     /// Consume the builder and produce the final LaTeX with delimiters.
-    pub fn finish(mut self) -> String {
+    pub fn finish(self) -> String {
+        let kind = self.kind;
+        let body = self.finish_body();
+        match kind {
+            MathKind::Inline => format!("${}$", body),
+            MathKind::Block  => format!("$${}$$", body),
+        }
+    }
+
+    /// Consume the builder and produce the LaTeX body without delimiters.
+    pub fn finish_body(mut self) -> String {
         // Pop everything down to Root.
         while self.stack.len() > 1 {
             let top = self.stack.pop().unwrap();
@@ -281,12 +303,165 @@ impl OmmlBuilder {
             Some(Frame::Root { children }) => children,
             _ => String::new(),
         };
-        let trimmed = inner.trim();
-        match self.kind {
-            MathKind::Inline => format!("${}$", trimmed),
-            MathKind::Block  => format!("$${}$$", trimmed),
+        inner.trim().to_string()
+    }
+}
+
+/// One Office Math equation of a DOCX body
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MathEquation {
+    /// Position among the document's equations, from 0
+    pub index: usize,
+    /// `Inline` for `m:oMath`, `Block` for `m:oMathPara`
+    pub kind: MathKind,
+    /// The raw `<m:oMath>` / `<m:oMathPara>` subtree
+    pub mathml: String,
+    /// LaTeX without `$` delimiters; `None` when conversion produced nothing
+    pub latex: Option<String>,
+}
+
+impl MathEquation {
+    /// Finish `builder`, which was fed the events of `mathml`
+    pub(crate) fn from_builder(index: usize, builder: OmmlBuilder, mathml: &str) -> Self {
+        let kind = builder.kind();
+        let mathml = mathml.trim().to_string();
+        let latex = pandoc_latex(&mathml)
+            .or_else(|| Some(builder.finish_body()).filter(|body| !body.is_empty()));
+        Self { index, kind, mathml, latex }
+    }
+
+    /// `$...$` / `$$...$$`, or a `<!-- mathml: ... -->` comment keeping the
+    /// source when there is no LaTeX
+    pub fn to_markdown(&self) -> String {
+        match (&self.latex, self.kind) {
+            (Some(latex), MathKind::Inline) => format!("${}$", latex),
+            (Some(latex), MathKind::Block) => format!("$${}$$", latex),
+            (None, _) => format!("<!-- mathml: {} -->", comment_safe(&self.mathml)),
+        }
+    }
+}
+
+/// Every outermost `m:oMath` / `m:oMathPara` of `para_xml`, in order.
+///
+/// `m:oMath` children of an `m:oMathPara` belong to the block equation and
+/// are not returned separately.
+pub fn extract_math(para_xml: &str) -> Vec<MathEquation> {
+    let mut reader = Reader::from_str(para_xml);
+    reader.trim_text(true);
+
+    let mut equations = Vec::new();
+    // Byte offset of the open equation's start tag, and its builder
+    let mut open: Option<(usize, OmmlBuilder)> = None;
+    loop {
+        let event_start = reader.buffer_position();
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) => {
+                let local = e.local_name().as_ref().to_vec();
+                match open {
+                    Some((_, ref mut mb)) if local == b"oMath" || local == b"oMathPara" => mb.start(&local, &[]),
+                    Some((_, ref mut mb)) => mb.start(&local, &omml_attrs(e)),
+                    None if local == b"oMathPara" => open = Some((event_start, OmmlBuilder::new(MathKind::Block))),
+                    None if local == b"oMath" => open = Some((event_start, OmmlBuilder::new(MathKind::Inline))),
+                    None => {}
+                }
+            }
+            Ok(Event::Empty(ref e)) => {
+                if let Some((_, ref mut mb)) = open {
+                    mb.empty(e.local_name().as_ref(), &omml_attrs(e));
+                }
+            }
+            Ok(Event::Text(ref e)) => {
+                if let Some((_, ref mut mb)) = open {
+                    mb.text(&e.unescape().unwrap_or_default());
+                }
+            }
+            Ok(Event::End(ref e)) => {
+                let local = e.local_name().into_inner();
+                let closes = matches!(open, Some((_, ref mb)) if is_outermost_end(mb.kind(), local));
+                if closes {
+                    let (start, mb) = open.take().unwrap();
+                    let mathml = &para_xml[start..reader.buffer_position()];
+                    equations.push(MathEquation::from_builder(equations.len(), mb, mathml));
+                } else if let Some((_, ref mut mb)) = open {
+                    mb.end(local);
+                }
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+    equations
+}
+
+/// `local` closes the equation a builder of `kind` was opened for
+pub(crate) fn is_outermost_end(kind: MathKind, local: &[u8]) -> bool {
+    match kind {
+        MathKind::Inline => local == b"oMath",
+        MathKind::Block => local == b"oMathPara",
+    }
+}
+
+/// Attributes of a math element as the builder takes them
+pub(crate) fn omml_attrs(e: &BytesStart) -> Vec<(Vec<u8>, String)> {
+    e.attributes()
+        .flatten()
+        .map(|a| (
+            a.key.local_name().as_ref().to_vec(),
+            String::from_utf8_lossy(&a.value).to_string(),
+        ))
+        .collect()
+}
+
+#[cfg(feature = "pandoc-math")]
+const PANDOC_CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/></Types>"#;
+
+#[cfg(feature = "pandoc-math")]
+const PANDOC_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/></Relationships>"#;
+
+/// LaTeX from `pandoc -f docx -t latex` run on a one-paragraph package
+/// holding `omml`. `None` when pandoc is missing, fails or prints nothing.
+#[cfg(feature = "pandoc-math")]
+fn pandoc_latex(omml: &str) -> Option<String> {
+    use std::io::Write;
+    use std::process::Command;
+
+    let document = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math"><w:body><w:p>{}</w:p></w:body></w:document>"#,
+        omml
+    );
+    let mut file = tempfile::Builder::new().prefix("mdm_math_").suffix(".docx").tempfile().ok()?;
+    {
+        let mut zw = zip::ZipWriter::new(file.as_file_mut());
+        let opts = zip::write::SimpleFileOptions::default();
+        for (name, xml) in [
+            ("[Content_Types].xml", PANDOC_CONTENT_TYPES),
+            ("_rels/.rels", PANDOC_RELS),
+            ("word/document.xml", document.as_str()),
+        ] {
+            zw.start_file(name, opts).ok()?;
+            zw.write_all(xml.as_bytes()).ok()?;
         }
+        zw.finish().ok()?;
+    }
+
+    let output = Command::new("pandoc").args(["-f", "docx", "-t", "latex"]).arg(file.path()).output().ok()?;
+    if !output.status.success() {
+        return None;
     }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let latex = stdout.trim();
+    // pandoc delimits inline math with `\(...\)` and display math with `\[...\]`
+    let body = [("\\(", "\\)"), ("\\[", "\\]")]
+        .iter()
+        .find_map(|(open, close)| latex.strip_prefix(open)?.strip_suffix(close))
+        .unwrap_or(latex)
+        .trim();
+    Some(body.to_string()).filter(|body| !body.is_empty())
+}
+
+#[cfg(not(feature = "pandoc-math"))]
+fn pandoc_latex(_omml: &str) -> Option<String> {
+    None
 }
 
 fn attr_string(attrs: &[(Vec<u8>, String)], name: &[u8]) -> Option<String> {
@@ -641,6 +816,70 @@ mod tests {
         assert_eq!(out, "$a\\_b \\& c$");
     }
 
+    const SUPERSCRIPT_OMML: &str = "<m:oMath><m:sSup><m:e><m:r><m:t>x</m:t></m:r></m:e>\
+        <m:sup><m:r><m:t>2</m:t></m:r></m:sup></m:sSup></m:oMath>";
+
+    #[test]
+    fn extract_inline_equation() {
+        let xml = format!("<w:p><w:r><w:t>Area </w:t></w:r>{}</w:p>", SUPERSCRIPT_OMML);
+        let equations = extract_math(&xml);
+        assert_eq!(equations.len(), 1);
+        let eq = &equations[0];
+        assert_eq!(eq.index, 0);
+        assert_eq!(eq.kind, MathKind::Inline);
+        assert_eq!(eq.mathml, SUPERSCRIPT_OMML);
+        assert_eq!(eq.latex.as_deref(), Some("{x}^{2}"));
+        assert_eq!(eq.to_markdown(), "${x}^{2}$");
+    }
+
+    #[test]
+    fn extract_block_equation_keeps_inner_omath() {
+        let xml = format!("<w:p><m:oMathPara>{}</m:oMathPara></w:p>", SUPERSCRIPT_OMML);
+        let equations = extract_math(&xml);
+        assert_eq!(equations.len(), 1, "inner oMath is part of the block");
+        assert_eq!(equations[0].kind, MathKind::Block);
+        assert!(equations[0].mathml.starts_with("<m:oMathPara><m:oMath>"));
+        assert!(equations[0].mathml.ends_with("</m:oMath></m:oMathPara>"));
+        assert_eq!(equations[0].to_markdown(), "$${x}^{2}$$");
+    }
+
+    #[cfg(feature = "pandoc-math")]
+    #[test]
+    fn pandoc_latex_block_equation() {
+        if std::process::Command::new("pandoc").arg("--version").output().is_err() {
+            eprintln!("pandoc not on PATH; skipping");
+            return;
+        }
+        let xml = format!("<w:p><m:oMathPara>{}</m:oMathPara></w:p>", SUPERSCRIPT_OMML);
+        let equations = extract_math(&xml);
+        assert_eq!(equations[0].kind, MathKind::Block);
+        // pandoc writes `x^{2}`; the built-in converter would give `{x}^{2}`
+        let latex = equations[0].latex.clone().expect("pandoc output");
+        assert!(latex.contains("x^{2}"), "{}", latex);
+        assert_eq!(equations[0].to_markdown(), format!("$${}$$", latex));
+    }
+
+    #[test]
+    fn extract_equations_in_order() {
+        let xml = format!(
+            "<w:body><w:p>{}</w:p><w:p><w:r><w:t>text</w:t></w:r></w:p>\
+             <w:p><m:oMath><m:r><m:t>y</m:t></m:r></m:oMath></w:p></w:body>",
+            SUPERSCRIPT_OMML
+        );
+        let equations = extract_math(&xml);
+        let indexes: Vec<usize> = equations.iter().map(|eq| eq.index).collect();
+        assert_eq!(indexes, vec![0, 1]);
+        assert_eq!(equations[1].latex.as_deref(), Some("y"));
+        assert!(extract_math("<w:p><w:r><w:t>no math</w:t></w:r></w:p>").is_empty());
+    }
+
+    #[test]
+    fn unconverted_equation_falls_back_to_comment() {
+        let equations = extract_math("<w:p><m:oMath><m:ctrlPr/></m:oMath></w:p>");
+        assert_eq!(equations[0].latex, None);
+        assert_eq!(equations[0].to_markdown(), "<!-- mathml: <m:oMath><m:ctrlPr/></m:oMath> -->");
+    }
+
     #[test]
     fn unknown_tag_passes_text_through() {
         // Unknown wrapper degrades to its child text.
@@ -4,10 +4,19 @@ pub mod math;
 pub use parser::{
     DocxParser,
     DocxDocument,
+    DocxComment,
+    CustomXmlPart,
+    HeaderFooterContent,
     DocxMetadata,
     DocxImage,
+    EmbeddedDocument,
     DocxTable,
     Paragraph,
+    RevisionMode,
+    RevisionType,
+    StructuredTag,
     TextRun,
     TableCell,
+    TableRow,
 };
+pub use math::{extract_math, MathEquation};
@@ -1,12 +1,13 @@
 use std::fs::File;
 use std::io::{self, Read, Seek, BufReader, Write, Cursor};
 use std::path::{Path, PathBuf};
+use std::borrow::Cow;
 use std::collections::HashMap;
 use quick_xml::Reader;
-use quick_xml::events::Event;
+use quick_xml::events::{BytesStart, Event};
 use serde::{Serialize, Deserialize};
 
-use super::math::{OmmlBuilder, MathKind};
+use super::math::{extract_math, is_outermost_end, omml_attrs, MathEquation, MathKind, OmmlBuilder};
 
 // Word XML namespaces (kept for reference; suppress unused warnings)
 #[allow(dead_code)]
@@ -63,6 +64,40 @@ pub struct TextRun {
     pub font_size: Option<u32>,
     pub font_name: Option<String>,
     pub color: Option<String>,
+    /// Tracked change (`<w:ins>` / `<w:del>`) this run belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision_type: Option<RevisionType>,
+}
+
+/// Kind of tracked change a [`TextRun`] belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RevisionType {
+    Insert,
+    Delete,
+}
+
+/// How [`DocxParser::parse`] resolves tracked changes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RevisionMode {
+    /// Keep both sides; deletions render as `~~struck~~` text
+    #[default]
+    Markup,
+    /// Keep insertions, drop deletions — the document as if every change were accepted
+    AcceptAll,
+    /// Drop insertions, keep deleted text — the original before any change
+    RejectAll,
+}
+
+impl RevisionMode {
+    /// Whether a run of revision `kind` survives this mode
+    fn keeps(self, kind: Option<RevisionType>) -> bool {
+        !matches!(
+            (self, kind),
+            (RevisionMode::AcceptAll, Some(RevisionType::Delete))
+                | (RevisionMode::RejectAll, Some(RevisionType::Insert))
+        )
+    }
 }
 
 
@@ -83,12 +118,36 @@ impl TextRun {
             result = format!("*{}*", result);
         }
 
-        if self.strike {
+        if self.strike || self.revision_type == Some(RevisionType::Delete) {
             result = format!("~~{}~~", result);
         }
 
         result
     }
+
+    /// Escaped text wrapped in `<strong>`/`<em>`/`<u>`/`<del>` as styled
+    pub fn to_html(&self) -> String {
+        if self.text.is_empty() {
+            return String::new();
+        }
+        let mut result = html_escape(&self.text);
+        if self.bold {
+            result = format!("<strong>{}</strong>", result);
+        }
+        if self.italic {
+            result = format!("<em>{}</em>", result);
+        }
+        if self.underline {
+            result = format!("<u>{}</u>", result);
+        }
+        match self.revision_type {
+            Some(RevisionType::Insert) => result = format!("<ins>{}</ins>", result),
+            Some(RevisionType::Delete) => result = format!("<del>{}</del>", result),
+            None if self.strike => result = format!("<del>{}</del>", result),
+            None => {}
+        }
+        result
+    }
 }
 
 /// Inline element: either a text run or a hyperlink wrapping runs
@@ -122,6 +181,27 @@ impl InlineElement {
             InlineElement::EndnoteRef { id } => format!("[^en{}]", id),
         }
     }
+
+    fn to_html(&self) -> String {
+        match self {
+            InlineElement::Run(run) => run.to_html(),
+            InlineElement::Hyperlink { url, runs } => {
+                let text: String = runs.iter().map(|r| r.to_html()).collect();
+                if text.is_empty() {
+                    return String::new();
+                }
+                format!("<a href=\"{}\">{}</a>", html_escape(url), text)
+            }
+            InlineElement::FootnoteRef { id } => {
+                let id = html_escape(id);
+                format!("<sup><a href=\"#fn-{0}\">{0}</a></sup>", id)
+            }
+            InlineElement::EndnoteRef { id } => {
+                let id = html_escape(id);
+                format!("<sup><a href=\"#en-{0}\">{0}</a></sup>", id)
+            }
+        }
+    }
 }
 
 /// Paragraph with style information
@@ -173,11 +253,67 @@ fn default_list_ordinal() -> u32 {
 }
 
 impl Paragraph {
+    /// Page-break paragraph for [`DocxParser::merge`]; renders as a `---`
+    /// rule in Markdown
+    pub fn page_break() -> Self {
+        Paragraph {
+            runs: vec![TextRun { text: "---".to_string(), ..Default::default() }],
+            style: Some("PageBreak".to_string()),
+            ..Default::default()
+        }
+    }
+
     /// Get plain text content
     pub fn text(&self) -> String {
         self.runs.iter().map(|r| r.text.as_str()).collect()
     }
 
+    /// Heading level 1-6, or `None` for body paragraphs
+    pub fn heading_level(&self) -> Option<u32> {
+        // Headings via outline_level (from styles.xml outlineLvl)
+        if let Some(level) = self.outline_level {
+            return Some((level + 1).min(6));
+        }
+
+        // Fallback heading detection via style name
+        if let Some(ref style) = self.style {
+            match style.as_str() {
+                "Heading1" | "heading 1" => return Some(1),
+                "Heading2" | "heading 2" => return Some(2),
+                "Heading3" | "heading 3" => return Some(3),
+                "Heading4" | "heading 4" => return Some(4),
+                "Title" => return Some(1),
+                "Subtitle" => return Some(2),
+                _ => {}
+            }
+        }
+        // Also check style_id for common patterns like "1", "2", etc.
+        match self.style_id.as_deref() {
+            Some("Heading1") | Some("1") => Some(1),
+            Some("Heading2") | Some("2") => Some(2),
+            Some("Heading3") | Some("3") => Some(3),
+            Some("Heading4") | Some("4") => Some(4),
+            _ => None,
+        }
+    }
+
+    /// Inline HTML of the paragraph content (no block wrapper)
+    pub fn inline_html(&self) -> String {
+        if !self.inlines.is_empty() {
+            self.inlines.iter().map(|i| i.to_html()).collect()
+        } else {
+            self.runs.iter().map(|r| r.to_html()).collect()
+        }
+    }
+
+    /// `<ol>` for numbered list types, `<ul>` for bullets
+    fn list_tag(&self) -> &'static str {
+        match self.list_type.as_deref() {
+            Some("bullet") | None => "ul",
+            _ => "ol",
+        }
+    }
+
     /// Convert to markdown using inline elements (hyperlinks, footnotes) when available,
     /// falling back to runs for backward compatibility.
     pub fn to_markdown(&self) -> String {
@@ -224,33 +360,8 @@ impl Paragraph {
             return String::new();
         }
 
-        // Handle headings via outline_level (from styles.xml outlineLvl)
-        if let Some(level) = self.outline_level {
-            let hashes = "#".repeat((level + 1).min(6) as usize);
-            return format!("{} {}", hashes, content);
-        }
-
-        // Fallback heading detection via style name
-        if let Some(ref style) = self.style {
-            match style.as_str() {
-                "Heading1" | "heading 1" => return format!("# {}", content),
-                "Heading2" | "heading 2" => return format!("## {}", content),
-                "Heading3" | "heading 3" => return format!("### {}", content),
-                "Heading4" | "heading 4" => return format!("#### {}", content),
-                "Title" => return format!("# {}", content),
-                "Subtitle" => return format!("## {}", content),
-                _ => {}
-            }
-        }
-        // Also check style_id for common patterns like "1", "2", etc.
-        if let Some(ref sid) = self.style_id {
-            match sid.as_str() {
-                "Heading1" | "1" => return format!("# {}", content),
-                "Heading2" | "2" => return format!("## {}", content),
-                "Heading3" | "3" => return format!("### {}", content),
-                "Heading4" | "4" => return format!("#### {}", content),
-                _ => {}
-            }
+        if let Some(level) = self.heading_level() {
+            return format!("{} {}", "#".repeat(level as usize), content);
         }
 
         // Handle blockquotes
@@ -362,11 +473,21 @@ impl Default for TableCell {
     }
 }
 
+/// Table row with its `<w:trHeight>` (twips) when specified
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TableRow {
+    pub height_twips: Option<u32>,
+    pub cells: Vec<TableCell>,
+}
+
 /// Table structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocxTable {
-    pub rows: Vec<Vec<TableCell>>,
+    pub rows: Vec<TableRow>,
     pub has_header: bool,
+    /// Column widths from the first row's `<w:tcW>` (twips); empty when absent
+    #[serde(default)]
+    pub column_widths_twips: Vec<u32>,
 }
 
 impl DocxTable {
@@ -378,28 +499,146 @@ impl DocxTable {
 
         let mut lines = Vec::new();
 
+        if !self.column_widths_twips.is_empty() {
+            let widths: Vec<String> = self.column_widths_twips.iter().map(|w| w.to_string()).collect();
+            lines.push(format!("<!-- widths: {} -->", widths.join(",")));
+        }
+
+        // Horizontally merged cells are expanded to one column per grid
+        // column so every row (and the separator) has the same width
+        let width = self
+            .rows
+            .iter()
+            .map(|r| r.cells.iter().map(|c| c.col_span.max(1) as usize).sum())
+            .max()
+            .unwrap_or(0);
         for (i, row) in self.rows.iter().enumerate() {
-            let cells: Vec<String> = row.iter()
-                .map(|c| {
-                    if c.v_merge_continue {
-                        String::new() // empty for vertically merged continuation cells
-                    } else {
-                        c.content.replace('|', "\\|").replace('\n', " ")
-                    }
-                })
-                .collect();
+            let mut cells: Vec<String> = Vec::with_capacity(width);
+            for c in &row.cells {
+                if c.v_merge_continue {
+                    cells.push(String::new()); // empty for vertically merged continuation cells
+                } else {
+                    cells.push(c.content.replace('|', "\\|").replace('\n', " "));
+                }
+                cells.extend((1..c.col_span.max(1)).map(|_| String::new()));
+            }
+            cells.resize(width, String::new());
 
             lines.push(format!("| {} |", cells.join(" | ")));
 
             // Add separator after header row
             if i == 0 {
-                let sep: Vec<String> = row.iter().map(|_| "---".to_string()).collect();
-                lines.push(format!("| {} |", sep.join(" | ")));
+                lines.push(format!("| {} |", vec!["---"; width].join(" | ")));
             }
         }
 
         lines.join("\n")
     }
+
+    /// Convert to an HTML `<table>` with `colspan`/`rowspan`.
+    ///
+    /// The first row goes in `<thead>` as `<th>` cells when `has_header` is
+    /// set. A cell's `rowspan` is its `row_span` or, for a vMerge restart,
+    /// the number of rows its continuation cells cover; covered positions
+    /// (continuations, and empty placeholders under an explicit `row_span`)
+    /// are not emitted.
+    pub fn to_html(&self) -> String {
+        if self.rows.is_empty() {
+            return String::new();
+        }
+
+        let rows = self.layout();
+        let mut out = String::from("<table>\n");
+        let body_start = if self.has_header { 1 } else { 0 };
+        if self.has_header {
+            out.push_str("<thead>\n");
+            out.push_str(&table_row_html(&rows[0], "th"));
+            out.push_str("</thead>\n");
+        }
+        if rows.len() > body_start {
+            out.push_str("<tbody>\n");
+            for row in &rows[body_start..] {
+                out.push_str(&table_row_html(row, "td"));
+            }
+            out.push_str("</tbody>\n");
+        }
+        out.push_str("</table>");
+        out
+    }
+
+    /// Cells to emit per row with their effective rowspan, walking the
+    /// column grid so positions covered by a span above are skipped
+    fn layout(&self) -> Vec<Vec<(&TableCell, u32)>> {
+        let mut rows: Vec<Vec<(&TableCell, u32)>> = Vec::with_capacity(self.rows.len());
+        // Per grid column: rows still covered by a rowspan from above, and
+        // the (row, index) of the cell that covers it
+        let mut covered: Vec<u32> = Vec::new();
+        let mut origin: Vec<Option<(usize, usize)>> = Vec::new();
+
+        for (r, row) in self.rows.iter().enumerate() {
+            let mut placed = Vec::new();
+            let mut next_covered: Vec<u32> = covered.iter().map(|n| n.saturating_sub(1)).collect();
+            let mut col = 0usize;
+            for cell in &row.cells {
+                let span = cell.col_span.max(1) as usize;
+                if cell.v_merge_continue {
+                    if let Some(Some((origin_row, index))) = origin.get(col).copied() {
+                        if origin_row < r {
+                            let rowspan = &mut rows[origin_row][index].1;
+                            *rowspan = (*rowspan).max((r - origin_row + 1) as u32);
+                        }
+                    }
+                    col += span;
+                    continue;
+                }
+                if cell.content.is_empty() && covered.get(col).is_some_and(|&n| n > 0) {
+                    col += span; // placeholder for a position covered from above
+                    continue;
+                }
+                while covered.get(col).is_some_and(|&n| n > 0) {
+                    col += 1;
+                }
+
+                let rowspan = cell.row_span.max(1);
+                let end = col + span;
+                if origin.len() < end {
+                    origin.resize(end, None);
+                    next_covered.resize(end, 0);
+                }
+                let index = placed.len();
+                for (o, n) in origin[col..end].iter_mut().zip(&mut next_covered[col..end]) {
+                    *o = Some((r, index));
+                    *n = (*n).max(rowspan - 1);
+                }
+                placed.push((cell, rowspan));
+                col = end;
+            }
+            rows.push(placed);
+            covered = next_covered;
+        }
+        rows
+    }
+}
+
+fn table_row_html(cells: &[(&TableCell, u32)], tag: &str) -> String {
+    let mut out = String::from("<tr>");
+    for &(cell, rowspan) in cells {
+        out.push('<');
+        out.push_str(tag);
+        if cell.col_span > 1 {
+            out.push_str(&format!(" colspan=\"{}\"", cell.col_span));
+        }
+        if rowspan > 1 {
+            out.push_str(&format!(" rowspan=\"{}\"", rowspan));
+        }
+        out.push('>');
+        out.push_str(&html_escape(cell.content.trim()).replace('\n', "<br>"));
+        out.push_str("</");
+        out.push_str(tag);
+        out.push('>');
+    }
+    out.push_str("</tr>\n");
+    out
 }
 
 /// Image reference
@@ -427,6 +666,110 @@ pub struct DocxMetadata {
     pub page_count: Option<u32>,
 }
 
+/// Reviewer comment from `word/comments.xml`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocxComment {
+    pub id: u32,
+    pub author: String,
+    pub date: String,
+    /// Comment body; paragraphs joined with `\n`
+    pub text: String,
+    /// Document text between `commentRangeStart`/`commentRangeEnd`
+    pub anchor_text: Option<String>,
+}
+
+/// Plain text of the `word/header*.xml` / `word/footer*.xml` parts
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HeaderFooterContent {
+    pub headers: Vec<String>,
+    pub footers: Vec<String>,
+}
+
+/// Office package (DOCX/XLSX/PPTX) embedded under `word/embeddings/`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddedDocument {
+    /// File name within `word/embeddings/`
+    pub name: String,
+    /// Raw package bytes, ready for a fresh [`DocxParser::from_bytes`]
+    pub data: Vec<u8>,
+}
+
+/// Structured document tag (`<w:sdt>`): a content control or form field
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StructuredTag {
+    /// Display name (`w:alias`), falling back to the `w:tag` key
+    pub name: String,
+    /// Programmatic key (`w:tag`)
+    pub tag: Option<String>,
+    /// Text of `w:sdtContent`; paragraphs joined with `\n`. Empty while the
+    /// control still shows its placeholder
+    pub value: String,
+    /// `(paragraph, inline)` indices into [`DocxDocument::paragraphs`] where
+    /// the value starts; `None` for a value inside a table cell
+    #[serde(default)]
+    pub position: Option<(usize, usize)>,
+}
+
+/// Custom XML data part (`customXml/itemN.xml`), e.g. SharePoint
+/// properties or a classification label
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CustomXmlPart {
+    /// Schema URI from the part's `itemProps` (via `customXml/_rels`),
+    /// falling back to the root element's namespace; empty when neither is set
+    pub namespace: String,
+    /// Raw XML of the part
+    pub content: String,
+}
+
+impl CustomXmlPart {
+    /// `(local name, text)` of every element that holds text and no child
+    /// elements, in document order
+    pub fn fields(&self) -> Vec<(String, String)> {
+        let mut reader = Reader::from_str(&self.content);
+        reader.trim_text(true);
+
+        // (local name, has child elements, text)
+        let mut open: Vec<(String, bool, String)> = Vec::new();
+        let mut fields = Vec::new();
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(ref e)) => {
+                    if let Some(parent) = open.last_mut() {
+                        parent.1 = true;
+                    }
+                    let name = String::from_utf8_lossy(e.local_name().as_ref()).to_string();
+                    open.push((name, false, String::new()));
+                }
+                Ok(Event::Empty(_)) => {
+                    if let Some(parent) = open.last_mut() {
+                        parent.1 = true;
+                    }
+                }
+                Ok(Event::Text(ref e)) => {
+                    if let Some(current) = open.last_mut() {
+                        current.2.push_str(&e.unescape().unwrap_or_default());
+                    }
+                }
+                Ok(Event::CData(ref e)) => {
+                    if let Some(current) = open.last_mut() {
+                        current.2.push_str(&String::from_utf8_lossy(e));
+                    }
+                }
+                Ok(Event::End(_)) => {
+                    if let Some((name, has_children, text)) = open.pop() {
+                        if !has_children && !text.trim().is_empty() {
+                            fields.push((name, text.trim().to_string()));
+                        }
+                    }
+                }
+                Ok(Event::Eof) | Err(_) => break,
+                _ => {}
+            }
+        }
+        fields
+    }
+}
+
 /// Complete DOCX document
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DocxDocument {
@@ -440,9 +783,53 @@ pub struct DocxDocument {
     /// Endnote definitions: id -> markdown content
     #[serde(skip)]
     pub endnotes: Vec<(String, String)>,
+    /// Reviewer comments, in `comments.xml` order
+    #[serde(default)]
+    pub comments: Vec<DocxComment>,
+    /// Distinct page header texts (see [`DocxParser::extract_headers_footers`])
+    #[serde(default)]
+    pub headers: Vec<String>,
+    /// Distinct page footer texts
+    #[serde(default)]
+    pub footers: Vec<String>,
+    /// Nested Office packages (see [`DocxParser::extract_embedded_documents`])
+    #[serde(default)]
+    pub embedded_documents: Vec<EmbeddedDocument>,
+    /// Named content controls, in document order
+    #[serde(default)]
+    pub structured_tags: Vec<StructuredTag>,
+    /// The body contains `<w:ins>` / `<w:del>` revision marks, whatever
+    /// [`RevisionMode`] did with them
+    #[serde(default)]
+    pub has_tracked_changes: bool,
+    /// Custom XML parts, only with [`DocxParser::with_custom_xml`]
+    #[serde(default)]
+    pub custom_xml: Vec<CustomXmlPart>,
+    /// Office Math equations, in document order. Their Markdown is also
+    /// part of the paragraph text.
+    #[serde(default)]
+    pub equations: Vec<MathEquation>,
 }
 
 impl DocxDocument {
+    /// Prefix image ids / file names and note ids (including the note
+    /// references in paragraphs) so they cannot collide with another
+    /// document's
+    fn prefix_ids(&mut self, prefix: &str) {
+        for image in &mut self.images {
+            image.id = format!("{}{}", prefix, image.id);
+            image.filename = format!("{}{}", prefix, image.filename);
+        }
+        for (id, _) in self.footnotes.iter_mut().chain(self.endnotes.iter_mut()) {
+            *id = format!("{}{}", prefix, id);
+        }
+        for inline in self.paragraphs.iter_mut().flat_map(|p| p.inlines.iter_mut()) {
+            if let InlineElement::FootnoteRef { id } | InlineElement::EndnoteRef { id } = inline {
+                *id = format!("{}{}", prefix, id);
+            }
+        }
+    }
+
     /// Get plain text content
     pub fn text(&self) -> String {
         self.paragraphs.iter()
@@ -453,9 +840,14 @@ impl DocxDocument {
 
     /// Convert to markdown
     pub fn to_markdown(&self) -> String {
+        self.markdown_with_paragraphs(self.paragraphs.iter())
+    }
+
+    /// [`Self::to_markdown`] with `paragraphs` in place of the document's own
+    fn markdown_with_paragraphs<'a>(&self, paragraphs: impl Iterator<Item = &'a Paragraph>) -> String {
         let mut parts = Vec::new();
 
-        for para in &self.paragraphs {
+        for para in paragraphs {
             let md = para.to_markdown();
             if !md.is_empty() {
                 parts.push(md);
@@ -497,19 +889,289 @@ impl DocxDocument {
         }
         output.push_str(&format!("source: \"{}\"\n", source_filename));
         output.push_str("format: docx\n");
+        if self.has_tracked_changes {
+            output.push_str("trackedChanges: true\n");
+        }
+        // Leaf `<key>value</key>` pairs of the custom XML parts; the first
+        // occurrence of a key wins so the mapping stays valid YAML
+        let mut custom_fields: Vec<(String, String)> = Vec::new();
+        for (key, value) in self.custom_xml.iter().flat_map(CustomXmlPart::fields) {
+            if !custom_fields.iter().any(|(k, _)| *k == key) {
+                custom_fields.push((key, value));
+            }
+        }
+        if !custom_fields.is_empty() {
+            output.push_str("customXml:\n");
+            for (key, value) in &custom_fields {
+                output.push_str(&format!(
+                    "  \"{}\": \"{}\"\n",
+                    key.replace('"', "\\\""),
+                    value.replace('\n', " ").replace('"', "\\\"")
+                ));
+            }
+        }
         output.push_str("---\n\n");
 
-        // Content
-        output.push_str(&self.to_markdown());
+        // Page headers/footers: kept out of the body text, which they would
+        // otherwise interrupt once per page
+        for (label, texts) in [("Header", &self.headers), ("Footer", &self.footers)] {
+            for text in texts {
+                output.push_str(&format!("<!-- {}: {} -->\n", label, comment_safe(text)));
+            }
+        }
+        if !self.headers.is_empty() || !self.footers.is_empty() {
+            output.push('\n');
+        }
+
+        // Content. Filled content controls are labelled in place:
+        // `**Company:** ACME`
+        let labelled = self.labelled_paragraphs();
+        let mut body = self.markdown_with_paragraphs(labelled.iter().map(|p| p.as_ref()));
 
         // Tables
         for (i, table) in self.tables.iter().enumerate() {
-            output.push_str(&format!("\n\n<!-- Table {} -->\n", i + 1));
-            output.push_str(&table.to_markdown());
+            body.push_str(&format!("\n\n<!-- Table {} -->\n", i + 1));
+            body.push_str(&table.to_markdown());
+        }
+
+        output.push_str(&body);
+
+        if !self.embedded_documents.is_empty() {
+            output.push('\n');
+            for embedded in &self.embedded_documents {
+                output.push_str(&format!("\n<!-- Embedded: {} -->", comment_safe(&embedded.name)));
+            }
+        }
+
+        // Reviewer comments
+        if !self.comments.is_empty() {
+            output.push_str("\n\n## Comments\n");
+            for comment in &self.comments {
+                let author = if comment.author.is_empty() { "Unknown" } else { comment.author.as_str() };
+                let text = comment.text.lines().map(str::trim).collect::<Vec<_>>().join(" ");
+                output.push_str(&format!("\n> **{}:** {}\n", author, text));
+            }
         }
 
         output
     }
+
+    /// Body paragraphs with a bold `alias:` run inserted where each filled
+    /// content control's value starts
+    fn labelled_paragraphs(&self) -> Vec<Cow<'_, Paragraph>> {
+        let mut paragraphs: Vec<Cow<'_, Paragraph>> = self.paragraphs.iter().map(Cow::Borrowed).collect();
+        let mut labels: Vec<(usize, usize, &str)> = self
+            .structured_tags
+            .iter()
+            .filter(|field| !field.value.trim().is_empty())
+            .filter_map(|field| field.position.map(|(para, inline)| (para, inline, field.name.as_str())))
+            .filter(|&(para, inline, _)| self.paragraphs.get(para).is_some_and(|p| inline <= p.inlines.len()))
+            .collect();
+        // Back to front so earlier indices stay valid
+        labels.sort_by(|a, b| (b.0, b.1).cmp(&(a.0, a.1)));
+        for (para, inline, name) in labels {
+            let label = TextRun { text: format!("{}:", name), bold: true, ..TextRun::default() };
+            paragraphs[para].to_mut().inlines.insert(inline, InlineElement::Run(label));
+        }
+        paragraphs
+    }
+
+    /// Convert to a self-contained `<article>` HTML block (no frontmatter).
+    ///
+    /// Body paragraphs come first, then tables and images in document
+    /// order as in [`Self::to_mdx`]. Images point at `assets/<filename>`,
+    /// where [`DocxParser::to_mdx`] writes them.
+    pub fn to_html(&self) -> String {
+        let mut out = String::from("<article>\n");
+        // Open lists as (tag, indent_level); each has an unclosed `<li>` that
+        // deeper lists nest inside
+        let mut lists: Vec<(&'static str, u32)> = Vec::new();
+
+        for para in &self.paragraphs {
+            let content = para.inline_html();
+            if content.trim().is_empty() {
+                continue;
+            }
+
+            let heading = para.heading_level();
+            if heading.is_none() && para.is_list_item {
+                let tag = para.list_tag();
+                // Close deeper lists, and a same-level list of the other kind
+                while let Some(&(open_tag, level)) = lists.last() {
+                    if level > para.indent_level || (level == para.indent_level && open_tag != tag) {
+                        out.push_str(&format!("</li>\n</{}>\n", open_tag));
+                        lists.pop();
+                    } else {
+                        break;
+                    }
+                }
+                match lists.last() {
+                    Some(&(_, level)) if level == para.indent_level => out.push_str("</li>\n"),
+                    _ => {
+                        out.push_str(&format!("<{}>\n", tag));
+                        lists.push((tag, para.indent_level));
+                    }
+                }
+                out.push_str(&format!("<li>{}", content));
+                continue;
+            }
+
+            while let Some((open_tag, _)) = lists.pop() {
+                out.push_str(&format!("</li>\n</{}>\n", open_tag));
+            }
+            if let Some(level) = heading {
+                out.push_str(&format!("<h{0}>{1}</h{0}>\n", level, content));
+            } else if para.is_blockquote {
+                out.push_str(&format!("<blockquote><p>{}</p></blockquote>\n", content));
+            } else {
+                out.push_str(&format!("<p>{}</p>\n", content));
+            }
+        }
+        while let Some((open_tag, _)) = lists.pop() {
+            out.push_str(&format!("</li>\n</{}>\n", open_tag));
+        }
+
+        for table in &self.tables {
+            let html = table.to_html();
+            if !html.is_empty() {
+                out.push_str(&html);
+                out.push('\n');
+            }
+        }
+
+        for image in &self.images {
+            let alt = image.alt_text.as_deref().unwrap_or(&image.filename);
+            out.push_str(&format!(
+                "<img src=\"assets/{}\" alt=\"{}\">\n",
+                html_escape(&image.filename),
+                html_escape(alt)
+            ));
+        }
+
+        for (prefix, notes) in [("fn", &self.footnotes), ("en", &self.endnotes)] {
+            if notes.is_empty() {
+                continue;
+            }
+            out.push_str("<ol class=\"footnotes\">\n");
+            for (id, content) in notes {
+                out.push_str(&format!(
+                    "<li id=\"{}-{}\">{}</li>\n",
+                    prefix,
+                    html_escape(id),
+                    html_escape(content)
+                ));
+            }
+            out.push_str("</ol>\n");
+        }
+
+        out.push_str("</article>");
+        out
+    }
+}
+
+/// `<w:sdt>` whose end tag has not been reached yet, with the `w:sdtPr`
+/// properties and the text seen so far
+#[derive(Default)]
+struct OpenSdt {
+    field: StructuredTag,
+    /// `w:showingPlcHdr`: the content is prompt text, not a filled-in value
+    showing_placeholder: bool,
+}
+
+impl OpenSdt {
+    /// Record a `w:sdtPr` child (`alias`, `tag`, `showingPlcHdr`)
+    fn apply_property(&mut self, e: &BytesStart) {
+        let val = e
+            .attributes()
+            .flatten()
+            .find(|a| a.key.local_name().as_ref() == b"val")
+            .map(|a| String::from_utf8_lossy(&a.value).to_string());
+        match e.local_name().as_ref() {
+            b"alias" => {
+                if let Some(alias) = val.filter(|v| !v.is_empty()) {
+                    self.field.name = alias;
+                }
+            }
+            b"tag" => {
+                if self.field.name.is_empty() {
+                    self.field.name = val.clone().unwrap_or_default();
+                }
+                self.field.tag = val;
+            }
+            b"showingPlcHdr" => {
+                self.showing_placeholder = !matches!(val.as_deref(), Some("0") | Some("false"));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Minimal HTML escaper for text and attribute values
+fn html_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Value of the first `attribute` (local name) on the first `element`
+/// (local name) in `xml`
+fn xml_attribute(xml: &str, element: &[u8], attribute: &[u8]) -> Option<String> {
+    let mut reader = Reader::from_str(xml);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) if e.local_name().as_ref() == element => {
+                return e
+                    .attributes()
+                    .flatten()
+                    .find(|a| a.key.local_name().as_ref() == attribute)
+                    .map(|a| String::from_utf8_lossy(&a.value).to_string());
+            }
+            Ok(Event::Eof) | Err(_) => return None,
+            _ => {}
+        }
+    }
+}
+
+/// Namespace URI of the root element (`xmlns`, or `xmlns:p` for a `p:`
+/// prefixed root)
+fn root_namespace(xml: &str) -> Option<String> {
+    let mut reader = Reader::from_str(xml);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => {
+                let key = match e.name().prefix() {
+                    Some(prefix) => [b"xmlns:".as_slice(), prefix.as_ref()].concat(),
+                    None => b"xmlns".to_vec(),
+                };
+                return e
+                    .attributes()
+                    .flatten()
+                    .find(|a| a.key.as_ref() == key.as_slice())
+                    .map(|a| String::from_utf8_lossy(&a.value).to_string());
+            }
+            Ok(Event::Eof) | Err(_) => return None,
+            _ => {}
+        }
+    }
+}
+
+/// Flatten `text` to one line that can sit inside `<!-- ... -->`
+pub(crate) fn comment_safe(text: &str) -> String {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    // `--` would end the comment early
+    let mut safe = line.replace("--", "- -");
+    while safe.contains("--") {
+        safe = safe.replace("--", "- -");
+    }
+    safe
 }
 
 /// DOCX Parser, generic over the underlying reader type.
@@ -529,6 +1191,8 @@ pub struct DocxParser<R: Read + Seek = BufReader<File>> {
     footnotes: HashMap<String, String>,
     /// Endnote id -> plain text content
     endnotes: HashMap<String, String>,
+    revision_mode: RevisionMode,
+    include_custom_xml: bool,
 }
 
 impl DocxParser<BufReader<File>> {
@@ -549,6 +1213,8 @@ impl DocxParser<BufReader<File>> {
             numbering: HashMap::new(),
             footnotes: HashMap::new(),
             endnotes: HashMap::new(),
+            revision_mode: RevisionMode::default(),
+            include_custom_xml: false,
         };
 
         parser.load_relationships()?;
@@ -584,6 +1250,8 @@ impl DocxParser<Cursor<Vec<u8>>> {
             numbering: HashMap::new(),
             footnotes: HashMap::new(),
             endnotes: HashMap::new(),
+            revision_mode: RevisionMode::default(),
+            include_custom_xml: false,
         };
 
         parser.load_relationships()?;
@@ -597,6 +1265,18 @@ impl DocxParser<Cursor<Vec<u8>>> {
 }
 
 impl<R: Read + Seek> DocxParser<R> {
+    /// How [`Self::parse`] resolves tracked changes
+    pub fn with_revision_mode(mut self, mode: RevisionMode) -> Self {
+        self.revision_mode = mode;
+        self
+    }
+
+    /// Also collect [`DocxDocument::custom_xml`] in [`Self::parse`]
+    pub fn with_custom_xml(mut self, include: bool) -> Self {
+        self.include_custom_xml = include;
+        self
+    }
+
     /// Load document relationships
     fn load_relationships(&mut self) -> io::Result<()> {
         let content = match self.read_archive_file("word/_rels/document.xml.rels") {
@@ -1071,7 +1751,8 @@ impl<R: Read + Seek> DocxParser<R> {
         let mut current_para = Paragraph::default();
         let mut current_run = TextRun::default();
         let mut current_table: Option<DocxTable> = None;
-        let mut current_row: Vec<TableCell> = Vec::new();
+        let mut current_row = TableRow::default();
+        let mut current_row_widths: Vec<u32> = Vec::new();
         let mut current_cell: Option<TableCell> = None;
         // For collecting formatted cell content (runs converted to markdown)
         let mut cell_inlines: Vec<InlineElement> = Vec::new();
@@ -1101,8 +1782,22 @@ impl<R: Read + Seek> DocxParser<R> {
 
         // OMML (math) streaming: active only between oMath/oMathPara boundaries.
         let mut math_builder: Option<OmmlBuilder> = None;
+        // Byte offset of the open equation's start tag in `content`
+        let mut math_start = 0usize;
+        let mut equations: Vec<MathEquation> = Vec::new();
+
+        // Open `<w:sdt>` content controls, innermost last. Their runs are
+        // still parsed as ordinary paragraph text; the stack only records
+        // each control's name and value.
+        let mut sdt_stack: Vec<OpenSdt> = Vec::new();
+        let mut structured_tags: Vec<StructuredTag> = Vec::new();
+
+        // Enclosing `<w:ins>` / `<w:del>`; stamped onto every run inside it
+        let mut revision: Option<RevisionType> = None;
+        let mut has_tracked_changes = false;
 
         loop {
+            let event_start = reader.buffer_position();
             match reader.read_event() {
                 Ok(Event::Start(ref e)) => {
                     // --- OMML math routing ---
@@ -1111,14 +1806,7 @@ impl<R: Read + Seek> DocxParser<R> {
                         // Inside a math region: forward everything to the builder,
                         // except an outermost oMath/oMathPara (already started).
                         if local != b"oMath" && local != b"oMathPara" {
-                            let attrs: Vec<(Vec<u8>, String)> = e.attributes()
-                                .flatten()
-                                .map(|a| (
-                                    a.key.local_name().as_ref().to_vec(),
-                                    String::from_utf8_lossy(&a.value).to_string(),
-                                ))
-                                .collect();
-                            mb.start(&local, &attrs);
+                            mb.start(&local, &omml_attrs(e));
                         } else {
                             // Nested oMath inside oMathPara — pass-through as a plain wrapper.
                             mb.start(&local, &[]);
@@ -1127,13 +1815,23 @@ impl<R: Read + Seek> DocxParser<R> {
                     }
                     if in_paragraph && local == b"oMathPara" {
                         math_builder = Some(OmmlBuilder::new(MathKind::Block));
+                        math_start = event_start;
                         continue;
                     }
                     if in_paragraph && local == b"oMath" {
                         math_builder = Some(OmmlBuilder::new(MathKind::Inline));
+                        math_start = event_start;
                         continue;
                     }
                     match e.local_name().as_ref() {
+                        b"sdt" => {
+                            sdt_stack.push(OpenSdt::default());
+                        }
+                        b"alias" | b"tag" | b"showingPlcHdr" => {
+                            if let Some(sdt) = sdt_stack.last_mut() {
+                                sdt.apply_property(e);
+                            }
+                        }
                         b"p" => {
                             in_paragraph = true;
                             current_para = Paragraph::default();
@@ -1160,11 +1858,17 @@ impl<R: Read + Seek> DocxParser<R> {
                                 }
                             }
                         }
+                        b"ins" if in_paragraph => {
+                            revision = Some(RevisionType::Insert);
+                        }
+                        b"del" if in_paragraph => {
+                            revision = Some(RevisionType::Delete);
+                        }
                         b"r" if in_paragraph => {
                             in_run = true;
-                            current_run = TextRun::default();
+                            current_run = TextRun { revision_type: revision, ..TextRun::default() };
                         }
-                        b"t" if in_run => {
+                        b"t" | b"delText" if in_run => {
                             in_text = true;
                         }
                         b"tbl" => {
@@ -1172,11 +1876,13 @@ impl<R: Read + Seek> DocxParser<R> {
                             current_table = Some(DocxTable {
                                 rows: Vec::new(),
                                 has_header: true,
+                                column_widths_twips: Vec::new(),
                             });
                         }
                         b"tr" if in_table => {
                             in_table_row = true;
-                            current_row = Vec::new();
+                            current_row = TableRow::default();
+                            current_row_widths.clear();
                         }
                         b"tc" if in_table_row => {
                             in_table_cell = true;
@@ -1266,6 +1972,24 @@ impl<R: Read + Seek> DocxParser<R> {
                                 }
                             }
                         }
+                        b"trHeight" if in_table_row => {
+                            for attr in e.attributes().flatten() {
+                                if attr.key.local_name().as_ref() == b"val" {
+                                    if let Ok(h) = String::from_utf8_lossy(&attr.value).parse::<u32>() {
+                                        current_row.height_twips = Some(h);
+                                    }
+                                }
+                            }
+                        }
+                        b"tcW" if in_table_cell => {
+                            for attr in e.attributes().flatten() {
+                                if attr.key.local_name().as_ref() == b"w" {
+                                    if let Ok(w) = String::from_utf8_lossy(&attr.value).parse::<u32>() {
+                                        current_row_widths.push(w);
+                                    }
+                                }
+                            }
+                        }
                         b"gridSpan" if in_table_cell => {
                             for attr in e.attributes().flatten() {
                                 if attr.key.local_name().as_ref() == b"val" {
@@ -1352,18 +2076,15 @@ impl<R: Read + Seek> DocxParser<R> {
                 Ok(Event::Empty(ref e)) => {
                     // --- OMML math routing for self-closing tags ---
                     if let Some(ref mut mb) = math_builder {
-                        let local = e.local_name().as_ref().to_vec();
-                        let attrs: Vec<(Vec<u8>, String)> = e.attributes()
-                            .flatten()
-                            .map(|a| (
-                                a.key.local_name().as_ref().to_vec(),
-                                String::from_utf8_lossy(&a.value).to_string(),
-                            ))
-                            .collect();
-                        mb.empty(&local, &attrs);
+                        mb.empty(e.local_name().as_ref(), &omml_attrs(e));
                         continue;
                     }
                     match e.local_name().as_ref() {
+                        b"alias" | b"tag" | b"showingPlcHdr" => {
+                            if let Some(sdt) = sdt_stack.last_mut() {
+                                sdt.apply_property(e);
+                            }
+                        }
                         b"b" if in_run => {
                             let mut is_off = false;
                             for attr in e.attributes().flatten() {
@@ -1470,12 +2191,30 @@ impl<R: Read + Seek> DocxParser<R> {
                                 current_cell_v_merge_continue = true;
                             }
                         }
-                        b"gridSpan" if in_table_cell => {
+                        b"trHeight" if in_table_row => {
                             for attr in e.attributes().flatten() {
                                 if attr.key.local_name().as_ref() == b"val" {
-                                    if let Ok(span) = String::from_utf8_lossy(&attr.value).parse::<u32>() {
-                                        if let Some(ref mut cell) = current_cell {
-                                            cell.col_span = span;
+                                    if let Ok(h) = String::from_utf8_lossy(&attr.value).parse::<u32>() {
+                                        current_row.height_twips = Some(h);
+                                    }
+                                }
+                            }
+                        }
+                        b"tcW" if in_table_cell => {
+                            for attr in e.attributes().flatten() {
+                                if attr.key.local_name().as_ref() == b"w" {
+                                    if let Ok(w) = String::from_utf8_lossy(&attr.value).parse::<u32>() {
+                                        current_row_widths.push(w);
+                                    }
+                                }
+                            }
+                        }
+                        b"gridSpan" if in_table_cell => {
+                            for attr in e.attributes().flatten() {
+                                if attr.key.local_name().as_ref() == b"val" {
+                                    if let Ok(span) = String::from_utf8_lossy(&attr.value).parse::<u32>() {
+                                        if let Some(ref mut cell) = current_cell {
+                                            cell.col_span = span;
                                         }
                                     }
                                 }
@@ -1510,6 +2249,20 @@ impl<R: Read + Seek> DocxParser<R> {
                         mb.text(&text);
                     } else if in_text {
                         let text = e.unescape().unwrap_or_default().to_string();
+                        // A control's value is its text after the revisions are resolved
+                        let live = match self.revision_mode {
+                            RevisionMode::RejectAll => current_run.revision_type != Some(RevisionType::Insert),
+                            _ => current_run.revision_type != Some(RevisionType::Delete),
+                        };
+                        if live {
+                            for sdt in &mut sdt_stack {
+                                // The run lands at the paragraph's next inline slot
+                                if sdt.field.value.trim().is_empty() && !text.trim().is_empty() && !in_table_cell {
+                                    sdt.field.position = Some((paragraphs.len(), current_para.inlines.len()));
+                                }
+                                sdt.field.value.push_str(&text);
+                            }
+                        }
                         current_run.text.push_str(&text);
                     }
                 }
@@ -1517,10 +2270,13 @@ impl<R: Read + Seek> DocxParser<R> {
                     // --- OMML math routing ---
                     let local = e.local_name().as_ref().to_vec();
                     if let Some(ref mut mb) = math_builder {
-                        if local == b"oMath" && mb.kind() == MathKind::Inline {
+                        let is_end = is_outermost_end(mb.kind(), &local);
+                        if is_end && mb.kind() == MathKind::Inline {
                             // Outermost oMath end: finalize inline math and emit TextRun.
-                            let latex = math_builder.take().unwrap().finish();
-                            let run = TextRun { text: latex, ..TextRun::default() };
+                            let mathml = &content[math_start..reader.buffer_position()];
+                            let equation = MathEquation::from_builder(equations.len(), math_builder.take().unwrap(), mathml);
+                            let run = TextRun { text: equation.to_markdown(), ..TextRun::default() };
+                            equations.push(equation);
                             if in_hyperlink {
                                 hyperlink_runs.push(run);
                             } else {
@@ -1532,10 +2288,12 @@ impl<R: Read + Seek> DocxParser<R> {
                             }
                             continue;
                         }
-                        if local == b"oMathPara" && mb.kind() == MathKind::Block {
+                        if is_end {
                             // Outermost oMathPara end: finalize as its own paragraph-equivalent run.
-                            let latex = math_builder.take().unwrap().finish();
-                            let run = TextRun { text: latex, ..TextRun::default() };
+                            let mathml = &content[math_start..reader.buffer_position()];
+                            let equation = MathEquation::from_builder(equations.len(), math_builder.take().unwrap(), mathml);
+                            let run = TextRun { text: equation.to_markdown(), ..TextRun::default() };
+                            equations.push(equation);
                             current_para.runs.push(run.clone());
                             current_para.inlines.push(InlineElement::Run(run));
                             continue;
@@ -1545,10 +2303,34 @@ impl<R: Read + Seek> DocxParser<R> {
                         continue;
                     }
                     match e.local_name().as_ref() {
-                        b"t" => {
+                        b"t" | b"delText" => {
                             in_text = false;
                         }
+                        b"ins" | b"del" => {
+                            revision = None;
+                        }
+                        b"sdt" => {
+                            if let Some(OpenSdt { mut field, showing_placeholder }) = sdt_stack.pop() {
+                                field.value = if showing_placeholder {
+                                    field.position = None;
+                                    String::new()
+                                } else {
+                                    field.value.trim().to_string()
+                                };
+                                if !field.name.is_empty() {
+                                    structured_tags.push(field);
+                                }
+                            }
+                        }
                         b"r" => {
+                            if current_run.revision_type.is_some() && !current_run.text.is_empty() {
+                                has_tracked_changes = true;
+                                if !self.revision_mode.keeps(current_run.revision_type) {
+                                    current_run.text.clear();
+                                } else if self.revision_mode != RevisionMode::Markup {
+                                    current_run.revision_type = None;
+                                }
+                            }
                             if !current_run.text.is_empty() {
                                 if in_hyperlink {
                                     hyperlink_runs.push(current_run.clone());
@@ -1642,6 +2424,12 @@ impl<R: Read + Seek> DocxParser<R> {
                                 }
                                 cell_inlines.clear();
                             }
+                            for sdt in &mut sdt_stack {
+                                let value = &mut sdt.field.value;
+                                if !value.is_empty() && !value.ends_with('\n') {
+                                    value.push('\n');
+                                }
+                            }
                             current_para = Paragraph::default();
                             in_paragraph = false;
                             in_num_pr = false;
@@ -1653,16 +2441,19 @@ impl<R: Read + Seek> DocxParser<R> {
                         b"tc" => {
                             if let Some(mut cell) = current_cell.take() {
                                 cell.v_merge_continue = current_cell_v_merge_continue;
-                                current_row.push(cell);
+                                current_row.cells.push(cell);
                             }
                             in_table_cell = false;
                             cell_inlines.clear();
                         }
                         b"tr" => {
                             if let Some(ref mut table) = current_table {
-                                table.rows.push(current_row.clone());
+                                if table.column_widths_twips.is_empty() {
+                                    table.column_widths_twips = current_row_widths.clone();
+                                }
+                                table.rows.push(std::mem::take(&mut current_row));
                             }
-                            current_row = Vec::new();
+                            current_row_widths.clear();
                             in_table_row = false;
                         }
                         b"tbl" => {
@@ -1696,6 +2487,11 @@ impl<R: Read + Seek> DocxParser<R> {
             .map(|(k, v)| (k.clone(), v.clone()))
             .collect();
 
+        let comments = self.extract_comments()?;
+        let HeaderFooterContent { headers, footers } = self.extract_headers_footers()?;
+        let embedded_documents = self.extract_embedded_documents()?;
+        let custom_xml = if self.include_custom_xml { self.extract_custom_xml()? } else { Vec::new() };
+
         Ok(DocxDocument {
             paragraphs,
             tables,
@@ -1703,9 +2499,404 @@ impl<R: Read + Seek> DocxParser<R> {
             metadata,
             footnotes,
             endnotes,
+            comments,
+            headers,
+            footers,
+            embedded_documents,
+            structured_tags,
+            has_tracked_changes,
+            custom_xml,
+            equations,
         })
     }
 
+    /// Parse several documents and concatenate them in order.
+    ///
+    /// Paragraphs, tables, images, notes, comments and embedded packages
+    /// are appended, with `separator` (e.g. [`Paragraph::page_break`])
+    /// inserted between documents. Each metadata field comes from the first
+    /// document that sets it; word and page counts are summed. Image ids,
+    /// image file names and note ids of the N-th document (N > 1) get a
+    /// `dN-` prefix so they stay unique.
+    pub fn merge(parsers: Vec<Self>, separator: Option<Paragraph>) -> io::Result<DocxDocument> {
+        if parsers.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "no documents to merge"));
+        }
+
+        let mut merged = DocxDocument {
+            paragraphs: Vec::new(),
+            tables: Vec::new(),
+            images: Vec::new(),
+            metadata: DocxMetadata::default(),
+            footnotes: Vec::new(),
+            endnotes: Vec::new(),
+            comments: Vec::new(),
+            headers: Vec::new(),
+            footers: Vec::new(),
+            embedded_documents: Vec::new(),
+            structured_tags: Vec::new(),
+            has_tracked_changes: false,
+            custom_xml: Vec::new(),
+            equations: Vec::new(),
+        };
+        for (idx, mut parser) in parsers.into_iter().enumerate() {
+            let mut doc = parser.parse()?;
+            if idx > 0 {
+                doc.prefix_ids(&format!("d{}-", idx + 1));
+                if let Some(ref separator) = separator {
+                    merged.paragraphs.push(separator.clone());
+                }
+            }
+
+            let paragraph_offset = merged.paragraphs.len();
+            merged.paragraphs.extend(doc.paragraphs);
+            merged.tables.extend(doc.tables);
+            merged.images.extend(doc.images);
+            merged.footnotes.extend(doc.footnotes);
+            merged.endnotes.extend(doc.endnotes);
+            merged.comments.extend(doc.comments);
+            merged.embedded_documents.extend(doc.embedded_documents);
+            merged.structured_tags.extend(doc.structured_tags.into_iter().map(|mut field| {
+                if let Some((para, _)) = field.position.as_mut() {
+                    *para += paragraph_offset;
+                }
+                field
+            }));
+            merged.has_tracked_changes |= doc.has_tracked_changes;
+            merged.custom_xml.extend(doc.custom_xml);
+            let offset = merged.equations.len();
+            merged.equations.extend(doc.equations.into_iter().map(|mut equation| {
+                equation.index += offset;
+                equation
+            }));
+            for header in doc.headers {
+                if !merged.headers.contains(&header) {
+                    merged.headers.push(header);
+                }
+            }
+            for footer in doc.footers {
+                if !merged.footers.contains(&footer) {
+                    merged.footers.push(footer);
+                }
+            }
+
+            let meta = &mut merged.metadata;
+            let sum = |a: Option<u32>, b: Option<u32>| match (a, b) {
+                (Some(a), Some(b)) => Some(a + b),
+                (a, b) => a.or(b),
+            };
+            meta.title = meta.title.take().or(doc.metadata.title);
+            meta.author = meta.author.take().or(doc.metadata.author);
+            meta.subject = meta.subject.take().or(doc.metadata.subject);
+            meta.created = meta.created.take().or(doc.metadata.created);
+            meta.modified = meta.modified.take().or(doc.metadata.modified);
+            meta.revision = meta.revision.or(doc.metadata.revision);
+            meta.word_count = sum(meta.word_count, doc.metadata.word_count);
+            meta.page_count = sum(meta.page_count, doc.metadata.page_count);
+        }
+        Ok(merged)
+    }
+
+    /// Office Math equations of `word/document.xml`, in document order.
+    ///
+    /// Same equations as [`DocxDocument::equations`] without parsing the
+    /// rest of the body; see [`extract_math`].
+    pub fn extract_math_equations(&mut self) -> io::Result<Vec<MathEquation>> {
+        let content = self.read_archive_file("word/document.xml")?;
+        Ok(extract_math(&content))
+    }
+
+    /// Embedded Office packages from `word/embeddings/`, in name order.
+    ///
+    /// Only OPC packages (ZIP local-file magic `PK\x03\x04`) are returned;
+    /// legacy OLE objects (`oleObject*.bin`) and other embeddings are skipped.
+    pub fn extract_embedded_documents(&mut self) -> io::Result<Vec<EmbeddedDocument>> {
+        let mut names: Vec<String> = self
+            .archive
+            .file_names()
+            .filter(|name| name.strip_prefix("word/embeddings/").is_some_and(|rest| !rest.is_empty() && !rest.ends_with('/')))
+            .map(str::to_string)
+            .collect();
+        names.sort();
+
+        let mut documents = Vec::new();
+        for path in names {
+            let data = self.read_archive_file_bytes(&path)?;
+            if !data.starts_with(b"PK\x03\x04") {
+                continue;
+            }
+            let name = path.trim_start_matches("word/embeddings/").to_string();
+            documents.push(EmbeddedDocument { name, data });
+        }
+        Ok(documents)
+    }
+
+    /// Custom XML data parts (`customXml/itemN.xml`), in item-number order.
+    ///
+    /// The namespace is the first `ds:schemaRef` URI of the item's
+    /// properties part, found through `customXml/_rels/itemN.xml.rels`;
+    /// without one it falls back to the root element's namespace.
+    pub fn extract_custom_xml(&mut self) -> io::Result<Vec<CustomXmlPart>> {
+        let mut items: Vec<(u32, String)> = self
+            .archive
+            .file_names()
+            .filter_map(|name| {
+                let number = name.strip_prefix("customXml/item")?.strip_suffix(".xml")?.parse().ok()?;
+                Some((number, name.to_string()))
+            })
+            .collect();
+        items.sort();
+
+        let mut parts = Vec::new();
+        for (number, path) in items {
+            let content = self.read_archive_file(&path)?;
+            let bound = self
+                .read_archive_file(&format!("customXml/_rels/item{}.xml.rels", number))
+                .ok()
+                .and_then(|rels| xml_attribute(&rels, b"Relationship", b"Target"))
+                .and_then(|target| self.read_archive_file(&format!("customXml/{}", target.trim_start_matches("./"))).ok())
+                .and_then(|props| xml_attribute(&props, b"schemaRef", b"uri"));
+            let namespace = bound.or_else(|| root_namespace(&content)).unwrap_or_default();
+            parts.push(CustomXmlPart { namespace, content });
+        }
+        Ok(parts)
+    }
+
+    /// Extract the plain text of every `word/headerN.xml` and
+    /// `word/footerN.xml` part, in part-number order.
+    ///
+    /// Paragraphs are joined with `\n`. Empty parts are skipped, and a text
+    /// repeated across parts (first-page / even-page variants usually carry
+    /// the same company name) is kept once. Field results such as the
+    /// cached page number of a `PAGE` field are included as they were last
+    /// rendered.
+    pub fn extract_headers_footers(&mut self) -> io::Result<HeaderFooterContent> {
+        let mut parts: Vec<(bool, u32, String)> = self
+            .archive
+            .file_names()
+            .filter_map(|name| {
+                let stem = name.strip_prefix("word/")?.strip_suffix(".xml")?;
+                let (is_header, number) = match stem.strip_prefix("header") {
+                    Some(n) => (true, n),
+                    None => (false, stem.strip_prefix("footer")?),
+                };
+                let number = if number.is_empty() { 0 } else { number.parse().ok()? };
+                Some((is_header, number, name.to_string()))
+            })
+            .collect();
+        parts.sort();
+
+        let mut content = HeaderFooterContent::default();
+        for (is_header, _, name) in parts {
+            let xml = self.read_archive_file(&name)?;
+            let text = Self::parse_header_footer_xml(&xml);
+            let list = if is_header { &mut content.headers } else { &mut content.footers };
+            if !text.is_empty() && !list.contains(&text) {
+                list.push(text);
+            }
+        }
+        Ok(content)
+    }
+
+    /// Plain text of a `<w:hdr>` / `<w:ftr>` part, one line per paragraph
+    fn parse_header_footer_xml(content: &str) -> String {
+        let mut reader = Reader::from_str(content);
+        reader.trim_text(false);
+
+        let mut paragraphs: Vec<String> = Vec::new();
+        let mut para_text = String::new();
+        let mut in_text = false;
+
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(ref e)) => match e.local_name().as_ref() {
+                    b"p" => para_text.clear(),
+                    b"t" => in_text = true,
+                    _ => {}
+                },
+                Ok(Event::Empty(ref e)) => match e.local_name().as_ref() {
+                    b"tab" | b"ptab" => para_text.push('\t'),
+                    b"br" => para_text.push('\n'),
+                    _ => {}
+                },
+                Ok(Event::Text(ref e)) if in_text => {
+                    para_text.push_str(&e.unescape().unwrap_or_default());
+                }
+                Ok(Event::End(ref e)) => match e.local_name().as_ref() {
+                    b"t" => in_text = false,
+                    b"p" => {
+                        let text = para_text.trim();
+                        if !text.is_empty() {
+                            paragraphs.push(text.to_string());
+                        }
+                        para_text.clear();
+                    }
+                    _ => {}
+                },
+                Ok(Event::Eof) | Err(_) => break,
+                _ => {}
+            }
+        }
+
+        paragraphs.join("\n")
+    }
+
+    /// Extract reviewer comments from `word/comments.xml`.
+    ///
+    /// `anchor_text` is the body text between the matching
+    /// `commentRangeStart`/`commentRangeEnd` in `word/document.xml`.
+    /// Documents without comments yield an empty list.
+    pub fn extract_comments(&mut self) -> io::Result<Vec<DocxComment>> {
+        let content = match self.read_archive_file("word/comments.xml") {
+            Ok(c) => c,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let mut comments = Self::parse_comments_xml(&content);
+        if comments.is_empty() {
+            return Ok(comments);
+        }
+
+        let document = self.read_archive_file("word/document.xml").unwrap_or_default();
+        let anchors = Self::parse_comment_anchors(&document);
+        for comment in &mut comments {
+            comment.anchor_text = anchors
+                .get(&comment.id)
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty());
+        }
+        Ok(comments)
+    }
+
+    /// Parse `<w:comment w:id w:author w:date>` entries from comments.xml
+    fn parse_comments_xml(content: &str) -> Vec<DocxComment> {
+        let mut comments = Vec::new();
+        let mut reader = Reader::from_str(content);
+        reader.trim_text(false);
+
+        let mut current: Option<DocxComment> = None;
+        let mut paragraphs: Vec<String> = Vec::new();
+        let mut para_text = String::new();
+        let mut in_text = false;
+
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(ref e)) => match e.local_name().as_ref() {
+                    b"comment" => {
+                        let mut comment = DocxComment {
+                            id: 0,
+                            author: String::new(),
+                            date: String::new(),
+                            text: String::new(),
+                            anchor_text: None,
+                        };
+                        for attr in e.attributes().flatten() {
+                            let value = attr.unescape_value().unwrap_or_default().to_string();
+                            match attr.key.local_name().as_ref() {
+                                b"id" => comment.id = value.parse().unwrap_or(0),
+                                b"author" => comment.author = value,
+                                b"date" => comment.date = value,
+                                _ => {}
+                            }
+                        }
+                        current = Some(comment);
+                        paragraphs.clear();
+                    }
+                    b"p" if current.is_some() => para_text.clear(),
+                    b"t" if current.is_some() => in_text = true,
+                    _ => {}
+                },
+                Ok(Event::Empty(ref e)) if current.is_some() => match e.local_name().as_ref() {
+                    b"tab" => para_text.push('\t'),
+                    b"br" => para_text.push('\n'),
+                    _ => {}
+                },
+                Ok(Event::Text(ref e)) if in_text => {
+                    para_text.push_str(&e.unescape().unwrap_or_default());
+                }
+                Ok(Event::End(ref e)) => match e.local_name().as_ref() {
+                    b"t" => in_text = false,
+                    b"p" if current.is_some() => {
+                        let text = para_text.trim();
+                        if !text.is_empty() {
+                            paragraphs.push(text.to_string());
+                        }
+                        para_text.clear();
+                    }
+                    b"comment" => {
+                        if let Some(mut comment) = current.take() {
+                            comment.text = paragraphs.join("\n");
+                            comments.push(comment);
+                        }
+                    }
+                    _ => {}
+                },
+                Ok(Event::Eof) | Err(_) => break,
+                _ => {}
+            }
+        }
+
+        comments
+    }
+
+    /// Collect the text covered by each `commentRangeStart`/`End` pair in
+    /// document.xml, keyed by comment id. Ranges may overlap or span
+    /// paragraphs (joined with a space).
+    fn parse_comment_anchors(document_xml: &str) -> HashMap<u32, String> {
+        let mut anchors: HashMap<u32, String> = HashMap::new();
+        let mut open: Vec<u32> = Vec::new();
+        let mut reader = Reader::from_str(document_xml);
+        // Keep inter-run whitespace ("Hello " + "world")
+        reader.trim_text(false);
+        let mut in_text = false;
+
+        let comment_id = |e: &quick_xml::events::BytesStart| -> Option<u32> {
+            e.attributes()
+                .flatten()
+                .find(|a| a.key.local_name().as_ref() == b"id")
+                .and_then(|a| String::from_utf8_lossy(&a.value).parse().ok())
+        };
+
+        loop {
+            let event = reader.read_event();
+            match &event {
+                Ok(Event::Start(e)) | Ok(Event::Empty(e)) => match e.local_name().as_ref() {
+                    b"commentRangeStart" => {
+                        if let Some(id) = comment_id(e) {
+                            anchors.entry(id).or_default();
+                            open.push(id);
+                        }
+                    }
+                    b"commentRangeEnd" => {
+                        if let Some(id) = comment_id(e) {
+                            open.retain(|&o| o != id);
+                        }
+                    }
+                    b"t" => in_text = matches!(event, Ok(Event::Start(_))),
+                    b"p" => {
+                        for id in &open {
+                            let text = anchors.entry(*id).or_default();
+                            if !text.is_empty() && !text.ends_with(' ') {
+                                text.push(' ');
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+                Ok(Event::Text(e)) if in_text && !open.is_empty() => {
+                    let text = e.unescape().unwrap_or_default();
+                    for id in &open {
+                        anchors.entry(*id).or_default().push_str(&text);
+                    }
+                }
+                Ok(Event::End(e)) if e.local_name().as_ref() == b"t" => in_text = false,
+                Ok(Event::Eof) | Err(_) => break,
+                _ => {}
+            }
+        }
+
+        anchors
+    }
+
     /// Extract images from the document
     pub fn extract_images(&mut self) -> io::Result<Vec<DocxImage>> {
         let mut images = Vec::new();
@@ -2120,16 +3311,17 @@ mod tests {
     fn test_table_vmerge() {
         let table = DocxTable {
             rows: vec![
-                vec![
+                TableRow { height_twips: None, cells: vec![
                     TableCell { content: "Merged".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
                     TableCell { content: "B".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
-                ],
-                vec![
+                ] },
+                TableRow { height_twips: None, cells: vec![
                     TableCell { content: String::new(), col_span: 1, row_span: 1, v_merge_continue: true },
                     TableCell { content: "D".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
-                ],
+                ] },
             ],
             has_header: true,
+            column_widths_twips: Vec::new(),
         };
 
         let md = table.to_markdown();
@@ -2141,16 +3333,17 @@ mod tests {
     fn test_table_markdown() {
         let table = DocxTable {
             rows: vec![
-                vec![
+                TableRow { height_twips: None, cells: vec![
                     TableCell { content: "A".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
                     TableCell { content: "B".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
-                ],
-                vec![
+                ] },
+                TableRow { height_twips: None, cells: vec![
                     TableCell { content: "1".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
                     TableCell { content: "2".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
-                ],
+                ] },
             ],
             has_header: true,
+            column_widths_twips: Vec::new(),
         };
 
         let md = table.to_markdown();
@@ -2159,6 +3352,142 @@ mod tests {
         assert!(md.contains("| 1 | 2 |"));
     }
 
+    fn cell(content: &str, col_span: u32) -> TableCell {
+        TableCell { content: content.to_string(), col_span, ..Default::default() }
+    }
+
+    #[test]
+    fn test_table_colspan_html_and_markdown() {
+        let table = DocxTable {
+            rows: vec![
+                TableRow { height_twips: None, cells: vec![cell("Name", 2), cell("Score", 1)] },
+                TableRow { height_twips: None, cells: vec![cell("Kim", 1), cell("Minsu", 1), cell("90", 1)] },
+            ],
+            has_header: true,
+            column_widths_twips: Vec::new(),
+        };
+
+        let html = table.to_html();
+        assert!(html.contains("<thead>\n<tr><th colspan=\"2\">Name</th><th>Score</th></tr>"), "{}", html);
+        assert!(html.contains("<tr><td>Kim</td><td>Minsu</td><td>90</td></tr>"), "{}", html);
+
+        let md = table.to_markdown();
+        assert_eq!(md, "| Name |  | Score |\n| --- | --- | --- |\n| Kim | Minsu | 90 |");
+    }
+
+    #[test]
+    fn test_table_html_rowspan_skips_covered_cells() {
+        let mut merged = cell("Region", 1);
+        merged.row_span = 2;
+        let table = DocxTable {
+            rows: vec![
+                TableRow { height_twips: None, cells: vec![cell("A", 1), cell("B", 1)] },
+                // vMerge restart + continuation → rowspan from the continuation
+                TableRow { height_twips: None, cells: vec![cell("Seoul", 1), cell("1", 1)] },
+                TableRow { height_twips: None, cells: vec![TableCell { v_merge_continue: true, ..cell("", 1) }, cell("2", 1)] },
+                // explicit row_span with an empty placeholder underneath
+                TableRow { height_twips: None, cells: vec![merged, cell("3", 1)] },
+                TableRow { height_twips: None, cells: vec![cell("", 1), cell("4", 1)] },
+            ],
+            has_header: true,
+            column_widths_twips: Vec::new(),
+        };
+
+        let html = table.to_html();
+        assert!(html.contains("<tr><td rowspan=\"2\">Seoul</td><td>1</td></tr>\n<tr><td>2</td></tr>"), "{}", html);
+        assert!(html.contains("<tr><td rowspan=\"2\">Region</td><td>3</td></tr>\n<tr><td>4</td></tr>"), "{}", html);
+        assert_eq!(html.matches("<td></td>").count(), 0);
+    }
+
+    /// Zip `parts` (`(path, content)`, in order) into a DOCX package
+    fn docx_from_parts<D: AsRef<[u8]>>(parts: &[(&str, D)]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        {
+            let mut zw = zip::ZipWriter::new(Cursor::new(&mut buf));
+            let opts = zip::write::SimpleFileOptions::default();
+            for (name, data) in parts {
+                zw.start_file(*name, opts).unwrap();
+                zw.write_all(data.as_ref()).unwrap();
+            }
+            zw.finish().unwrap();
+        }
+        buf
+    }
+
+    fn docx_from_document_xml(document_xml: &str) -> Vec<u8> {
+        docx_from_parts(&[("word/document.xml", document_xml)])
+    }
+
+    #[test]
+    fn test_numbering_xml_resolves_list_types() {
+        let numbering = r#"<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:abstractNum w:abstractNumId="0">
+                <w:lvl w:ilvl="0"><w:numFmt w:val="bullet"/><w:lvlText w:val="•"/></w:lvl>
+            </w:abstractNum>
+            <w:abstractNum w:abstractNumId="1">
+                <w:lvl w:ilvl="0"><w:numFmt w:val="decimal"/><w:lvlText w:val="%1."/></w:lvl>
+                <w:lvl w:ilvl="1"><w:numFmt w:val="lowerRoman"/><w:lvlText w:val="%2."/></w:lvl>
+            </w:abstractNum>
+            <w:num w:numId="1"><w:abstractNumId w:val="1"/></w:num>
+            <w:num w:numId="2"><w:abstractNumId w:val="0"/></w:num>
+        </w:numbering>"#;
+        let item = |num_id: u32, ilvl: u32, text: &str| {
+            format!(
+                r#"<w:p><w:pPr><w:numPr><w:ilvl w:val="{ilvl}"/><w:numId w:val="{num_id}"/></w:numPr></w:pPr><w:r><w:t>{text}</w:t></w:r></w:p>"#
+            )
+        };
+        let document = format!(
+            r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}{}{}{}</w:body></w:document>"#,
+            item(1, 0, "first"),
+            item(1, 1, "nested"),
+            item(2, 0, "dot"),
+            item(9, 0, "undefined"),
+        );
+
+        let buf = docx_from_parts(&[("word/document.xml", document.as_str()), ("word/numbering.xml", numbering)]);
+
+        let doc = DocxParser::from_bytes(buf).unwrap().parse().unwrap();
+        let types: Vec<(String, Option<&str>)> = doc
+            .paragraphs
+            .iter()
+            .filter(|p| p.is_list_item)
+            .map(|p| (p.text(), p.list_type.as_deref()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("first".to_string(), Some("decimal")),
+                ("nested".to_string(), Some("lowerRoman")),
+                // Format comes from the abstractNum, not from the numId value
+                ("dot".to_string(), Some("bullet")),
+                // numId with no definition falls back to bullet
+                ("undefined".to_string(), Some("bullet")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table_row_height_and_column_widths() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:tbl>
+            <w:tr><w:trPr><w:trHeight w:val="567"/></w:trPr>
+                <w:tc><w:tcPr><w:tcW w:w="2880" w:type="dxa"/></w:tcPr><w:p><w:r><w:t>A</w:t></w:r></w:p></w:tc>
+                <w:tc><w:tcPr><w:tcW w:w="5760" w:type="dxa"/></w:tcPr><w:p><w:r><w:t>B</w:t></w:r></w:p></w:tc>
+            </w:tr>
+            <w:tr>
+                <w:tc><w:p><w:r><w:t>1</w:t></w:r></w:p></w:tc>
+                <w:tc><w:p><w:r><w:t>2</w:t></w:r></w:p></w:tc>
+            </w:tr>
+        </w:tbl></w:body></w:document>"#;
+        let mut parser = DocxParser::from_bytes(docx_from_document_xml(xml)).unwrap();
+        let doc = parser.parse().unwrap();
+
+        let table = &doc.tables[0];
+        assert_eq!(table.rows[0].height_twips, Some(567));
+        assert_eq!(table.rows[1].height_twips, None);
+        assert_eq!(table.column_widths_twips, vec![2880, 5760]);
+        assert!(table.to_markdown().starts_with("<!-- widths: 2880,5760 -->\n| A | B |"));
+    }
+
     #[test]
     fn test_footnote_definitions_in_document() {
         let doc = DocxDocument {
@@ -2175,6 +3504,14 @@ mod tests {
             metadata: DocxMetadata::default(),
             footnotes: vec![("1".to_string(), "This is a footnote.".to_string())],
             endnotes: vec![],
+            comments: vec![],
+            headers: vec![],
+            footers: vec![],
+            embedded_documents: vec![],
+            structured_tags: vec![],
+            has_tracked_changes: false,
+            custom_xml: vec![],
+            equations: vec![],
         };
 
         let md = doc.to_markdown();
@@ -2182,6 +3519,67 @@ mod tests {
         assert!(md.contains("[^1]: This is a footnote."));
     }
 
+    #[test]
+    fn test_html_nested_lists_images_and_notes() {
+        let item = |text: &str, list_type: &str, indent_level: u32| Paragraph {
+            runs: vec![TextRun { text: text.to_string(), ..Default::default() }],
+            is_list_item: true,
+            list_type: Some(list_type.to_string()),
+            indent_level,
+            ..Default::default()
+        };
+        let doc = DocxDocument {
+            paragraphs: vec![
+                item("Fruit", "bullet", 0),
+                item("Apple", "number", 1),
+                item("Pear", "number", 1),
+                item("Bread", "bullet", 0),
+                Paragraph {
+                    runs: vec![TextRun { text: "See".to_string(), ..Default::default() }],
+                    inlines: vec![
+                        InlineElement::Hyperlink {
+                            url: "https://a.test/?x=1&y=2".to_string(),
+                            runs: vec![TextRun { text: "See".to_string(), ..Default::default() }],
+                        },
+                        InlineElement::FootnoteRef { id: "1".to_string() },
+                    ],
+                    ..Default::default()
+                },
+            ],
+            tables: vec![],
+            images: vec![DocxImage {
+                id: "rId5".to_string(),
+                filename: "chart.png".to_string(),
+                path: "word/media/chart.png".to_string(),
+                alt_text: Some("Sales \"Q1\"".to_string()),
+                width: None,
+                height: None,
+                data: None,
+            }],
+            metadata: DocxMetadata::default(),
+            footnotes: vec![("1".to_string(), "a < b".to_string())],
+            endnotes: vec![],
+            comments: vec![],
+            headers: vec![],
+            footers: vec![],
+            embedded_documents: vec![],
+            structured_tags: vec![],
+            has_tracked_changes: false,
+            custom_xml: vec![],
+            equations: vec![],
+        };
+
+        let html = doc.to_html();
+        assert!(html.contains(
+            "<ul>\n<li>Fruit<ol>\n<li>Apple</li>\n<li>Pear</li>\n</ol>\n</li>\n<li>Bread</li>\n</ul>\n"
+        ), "{}", html);
+        assert!(html.contains(
+            "<p><a href=\"https://a.test/?x=1&amp;y=2\">See</a><sup><a href=\"#fn-1\">1</a></sup></p>"
+        ), "{}", html);
+        assert!(html.contains("<img src=\"assets/chart.png\" alt=\"Sales &quot;Q1&quot;\">"), "{}", html);
+        assert!(html.contains("<li id=\"fn-1\">a &lt; b</li>"), "{}", html);
+    }
+
     #[test]
     fn test_parse_notes_xml() {
         let xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
@@ -2202,4 +3600,450 @@ mod tests {
         assert_eq!(notes.get("2").unwrap(), "**Bold note**");
         assert!(!notes.contains_key("0")); // separator skipped
     }
+
+    #[test]
+    fn test_extract_comments_with_anchor_text() {
+        let document = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+            <w:p><w:r><w:t xml:space="preserve">Intro </w:t></w:r><w:commentRangeStart w:id="0"/><w:r><w:t xml:space="preserve">needs </w:t></w:r><w:r><w:t>review</w:t></w:r><w:commentRangeEnd w:id="0"/><w:r><w:commentReference w:id="0"/></w:r></w:p>
+            <w:p><w:r><w:t>Unanchored</w:t></w:r></w:p>
+        </w:body></w:document>"#;
+        let comments = r#"<w:comments xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+            <w:comment w:id="0" w:author="Kim &amp; Lee" w:date="2024-05-01T09:00:00Z">
+                <w:p><w:r><w:t>Please rephrase.</w:t></w:r></w:p>
+                <w:p><w:r><w:t>Too vague.</w:t></w:r></w:p>
+            </w:comment>
+            <w:comment w:id="1" w:author="Park" w:date="2024-05-02T10:00:00Z">
+                <w:p><w:r><w:t>General note</w:t></w:r></w:p>
+            </w:comment>
+        </w:comments>"#;
+
+        let buf = docx_from_parts(&[("word/document.xml", document), ("word/comments.xml", comments)]);
+
+        let mut parser = DocxParser::from_bytes(buf).unwrap();
+        let extracted = parser.extract_comments().unwrap();
+        assert_eq!(extracted.len(), 2);
+        assert_eq!(extracted[0].id, 0);
+        assert_eq!(extracted[0].author, "Kim & Lee");
+        assert_eq!(extracted[0].date, "2024-05-01T09:00:00Z");
+        assert_eq!(extracted[0].text, "Please rephrase.\nToo vague.");
+        assert_eq!(extracted[0].anchor_text.as_deref(), Some("needs review"));
+        assert_eq!(extracted[1].anchor_text, None);
+
+        let doc = parser.parse().unwrap();
+        let mdx = doc.to_mdx("review.docx");
+        let section = &mdx[mdx.find("## Comments").expect("comments heading")..];
+        assert!(section.contains("> **Kim & Lee:** Please rephrase. Too vague."));
+        assert!(section.contains("> **Park:** General note"));
+    }
+
+    #[test]
+    fn test_merge_documents() {
+        fn docx(text: &str, title: &str) -> DocxParser<Cursor<Vec<u8>>> {
+            let document = format!(
+                r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>{text}</w:t></w:r></w:p></w:body></w:document>"#
+            );
+            let rels = r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="media/image1.png"/></Relationships>"#;
+            let core = format!(
+                r#"<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/"><dc:title>{title}</dc:title></cp:coreProperties>"#
+            );
+            let buf = docx_from_parts(&[
+                ("word/document.xml", document.as_bytes()),
+                ("word/_rels/document.xml.rels", rels.as_bytes()),
+                ("word/media/image1.png", b"\x89PNG".as_slice()),
+                ("docProps/core.xml", core.as_bytes()),
+            ]);
+            DocxParser::from_bytes(buf).unwrap()
+        }
+
+        let merged = DocxParser::merge(
+            vec![docx("Chapter one", "Book"), docx("Chapter two", "Part 2")],
+            Some(Paragraph::page_break()),
+        )
+        .unwrap();
+
+        let texts: Vec<String> = merged.paragraphs.iter().map(|p| p.text()).collect();
+        assert_eq!(texts, vec!["Chapter one", "---", "Chapter two"]);
+        assert_eq!(merged.metadata.title.as_deref(), Some("Book"));
+
+        let ids: Vec<&str> = merged.images.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["rId1", "d2-rId1"]);
+        assert_eq!(merged.images[1].filename, "d2-image1.png");
+
+        let markdown = merged.to_markdown();
+        assert!(markdown.contains("Chapter one\n\n---\n\nChapter two"), "{}", markdown);
+
+        let err = DocxParser::<Cursor<Vec<u8>>>::merge(Vec::new(), None).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_extract_embedded_documents() {
+        let body = |text: &str| {
+            format!(
+                r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>{text}</w:t></w:r></w:p></w:body></w:document>"#
+            )
+        };
+
+        let inner = docx_from_document_xml(&body("Inner text"));
+        let outer = docx_from_parts(&[
+            ("word/document.xml", body("Outer text").as_bytes()),
+            ("word/embeddings/Microsoft_Word_Document.docx", &inner[..]),
+            ("word/embeddings/oleObject1.bin", &b"\xD0\xCF\x11\xE0\xA1\xB1\x1A\xE1"[..]),
+        ]);
+
+        let mut parser = DocxParser::from_bytes(outer).unwrap();
+        let embedded = parser.extract_embedded_documents().unwrap();
+        assert_eq!(embedded.len(), 1);
+        assert_eq!(embedded[0].name, "Microsoft_Word_Document.docx");
+        assert_eq!(embedded[0].data, inner);
+
+        let doc = parser.parse().unwrap();
+        assert_eq!(doc.embedded_documents, embedded);
+        assert!(doc.to_mdx("outer.docx").contains("<!-- Embedded: Microsoft_Word_Document.docx -->"));
+
+        let nested = DocxParser::from_bytes(embedded[0].data.clone()).unwrap().parse().unwrap();
+        assert_eq!(nested.text(), "Inner text");
+    }
+
+    #[test]
+    fn test_extract_headers_footers() {
+        let part = |root: &str, body: &str| {
+            format!(
+                r#"<w:{root} xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">{body}</w:{root}>"#
+            )
+        };
+        let document = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>Body text</w:t></w:r></w:p></w:body></w:document>"#;
+        let files = [
+            ("word/document.xml", document.to_string()),
+            ("word/header2.xml", part("hdr", "<w:p><w:r><w:t>Acme Corp</w:t></w:r></w:p>")),
+            ("word/header10.xml", part("hdr", "<w:p><w:r><w:t>Draft -- internal</w:t></w:r></w:p>")),
+            ("word/header1.xml", part("hdr", "<w:p><w:r><w:t>Acme Corp</w:t></w:r></w:p>")),
+            (
+                "word/footer1.xml",
+                part(
+                    "ftr",
+                    r#"<w:p><w:r><w:t xml:space="preserve">Page </w:t></w:r><w:r><w:fldChar w:fldCharType="begin"/></w:r><w:r><w:instrText>PAGE</w:instrText></w:r><w:r><w:fldChar w:fldCharType="separate"/></w:r><w:r><w:t>1</w:t></w:r><w:r><w:fldChar w:fldCharType="end"/></w:r></w:p><w:p><w:r><w:t>2024-05-01</w:t></w:r></w:p>"#,
+                ),
+            ),
+            ("word/footer2.xml", part("ftr", "<w:p/>")),
+        ];
+
+        let mut parser = DocxParser::from_bytes(docx_from_parts(&files)).unwrap();
+        let content = parser.extract_headers_footers().unwrap();
+        assert_eq!(content.headers, vec!["Acme Corp", "Draft -- internal"]);
+        assert_eq!(content.footers, vec!["Page 1\n2024-05-01"]);
+
+        let mdx = parser.parse().unwrap().to_mdx("report.docx");
+        let body_at = mdx.find("Body text").unwrap();
+        let header_at = mdx.find("<!-- Header: Acme Corp -->\n").expect("header comment");
+        assert!(header_at < body_at);
+        assert!(mdx.contains("<!-- Header: Draft - - internal -->\n"));
+        assert!(mdx.contains("<!-- Footer: Page 1 2024-05-01 -->\n"));
+    }
+
+    fn docx_with_custom_xml() -> Vec<u8> {
+        let files = [
+            (
+                "word/document.xml",
+                r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>Body</w:t></w:r></w:p></w:body></w:document>"#,
+            ),
+            (
+                "customXml/item1.xml",
+                r#"<?xml version="1.0"?><p:properties xmlns:p="http://schemas.microsoft.com/office/2006/metadata/properties"><documentManagement><Classification>Confidential</Classification><Owner>Legal &amp; Compliance</Owner><Reviewers><Reviewer/></Reviewers></documentManagement></p:properties>"#,
+            ),
+            (
+                "customXml/_rels/item1.xml.rels",
+                r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXmlProps" Target="itemProps1.xml"/></Relationships>"#,
+            ),
+            (
+                "customXml/itemProps1.xml",
+                r#"<ds:datastoreItem ds:itemID="{1}" xmlns:ds="http://schemas.openxmlformats.org/officeDocument/2006/customXml"><ds:schemaRefs><ds:schemaRef ds:uri="http://schemas.example.com/records"/></ds:schemaRefs></ds:datastoreItem>"#,
+            ),
+            (
+                "customXml/item2.xml",
+                r#"<labels xmlns="urn:example:labels"><Classification>Public</Classification><Retention>7y</Retention></labels>"#,
+            ),
+        ];
+        docx_from_parts(&files)
+    }
+
+    #[test]
+    fn test_equations_collected_and_rendered() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math"><w:body>
+            <w:p><w:r><w:t>Energy </w:t></w:r><m:oMath><m:r><m:t>E</m:t></m:r></m:oMath></w:p>
+            <w:p><m:oMathPara><m:oMath><m:sSup><m:e><m:r><m:t>c</m:t></m:r></m:e><m:sup><m:r><m:t>2</m:t></m:r></m:sup></m:sSup></m:oMath></m:oMathPara></w:p>
+            <w:p><m:oMath><m:ctrlPr/></m:oMath></w:p>
+        </w:body></w:document>"#;
+        let mut parser = DocxParser::from_bytes(docx_from_document_xml(xml)).unwrap();
+        let doc = parser.parse().unwrap();
+
+        assert_eq!(doc.equations.len(), 3);
+        assert_eq!(doc.equations[0].latex.as_deref(), Some("E"));
+        assert!(doc.equations[1].mathml.starts_with("<m:oMathPara>"));
+        assert_eq!(doc.equations, parser.extract_math_equations().unwrap());
+
+        let mdx = doc.to_mdx("paper.docx");
+        assert!(mdx.contains("Energy") && mdx.contains("$E$"), "{}", mdx);
+        assert!(mdx.contains("$${c}^{2}$$"), "{}", mdx);
+        assert!(mdx.contains("<!-- mathml: <m:oMath><m:ctrlPr/></m:oMath> -->"), "{}", mdx);
+    }
+
+    #[test]
+    fn test_extract_custom_xml_namespaces() {
+        let parts = DocxParser::from_bytes(docx_with_custom_xml()).unwrap().extract_custom_xml().unwrap();
+        assert_eq!(parts.len(), 2, "itemProps parts are not data items");
+        assert_eq!(parts[0].namespace, "http://schemas.example.com/records");
+        assert!(parts[0].content.contains("<Classification>Confidential</Classification>"));
+        assert_eq!(parts[1].namespace, "urn:example:labels", "root namespace without a rels binding");
+    }
+
+    #[test]
+    fn test_custom_xml_fields() {
+        let part = CustomXmlPart {
+            namespace: String::new(),
+            content: "<a><b>1</b><c><d> two </d></c><e/><f>x<g>y</g></f></a>".to_string(),
+        };
+        assert_eq!(
+            part.fields(),
+            vec![("b".to_string(), "1".to_string()), ("d".to_string(), "two".to_string()), ("g".to_string(), "y".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_custom_xml_in_frontmatter() {
+        let doc = DocxParser::from_bytes(docx_with_custom_xml()).unwrap().parse().unwrap();
+        assert!(doc.custom_xml.is_empty(), "off by default");
+        assert!(!doc.to_mdx("a.docx").contains("customXml"));
+
+        let doc = DocxParser::from_bytes(docx_with_custom_xml()).unwrap().with_custom_xml(true).parse().unwrap();
+        assert_eq!(doc.custom_xml.len(), 2);
+        let mdx = doc.to_mdx("a.docx");
+        let frontmatter = &mdx[..mdx.find("\n---\n").unwrap()];
+        assert!(frontmatter.contains(
+            "customXml:\n  \"Classification\": \"Confidential\"\n  \"Owner\": \"Legal & Compliance\"\n  \"Retention\": \"7y\"\n"
+        ), "{}", frontmatter);
+        assert!(!frontmatter.contains("Public"), "first value of a repeated key wins");
+    }
+
+    const SDT_DOCUMENT: &str = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+        <w:p><w:r><w:t>Contract between</w:t></w:r>
+            <w:sdt><w:sdtPr><w:alias w:val="Company"/><w:tag w:val="company_name"/></w:sdtPr>
+                <w:sdtContent><w:r><w:t>ACME Corp</w:t></w:r></w:sdtContent></w:sdt>
+            <w:r><w:t>and us.</w:t></w:r></w:p>
+        <w:sdt><w:sdtPr><w:tag w:val="signed_on"/></w:sdtPr><w:sdtContent>
+            <w:p><w:r><w:t>2024-05-01</w:t></w:r></w:p>
+            <w:p><w:r><w:t>Seoul</w:t></w:r></w:p>
+        </w:sdtContent></w:sdt>
+        <w:sdt><w:sdtPr><w:alias w:val="Notes"/><w:showingPlcHdr/></w:sdtPr><w:sdtContent>
+            <w:p><w:r><w:t>Click here to enter text.</w:t></w:r></w:p>
+        </w:sdtContent></w:sdt>
+        <w:sdt><w:sdtPr><w:docPartObj/></w:sdtPr><w:sdtContent>
+            <w:p><w:r><w:t>Unnamed block</w:t></w:r></w:p>
+        </w:sdtContent></w:sdt>
+    </w:body></w:document>"#;
+
+    #[test]
+    fn test_structured_tags_collected() {
+        let doc = DocxParser::from_bytes(docx_from_document_xml(SDT_DOCUMENT)).unwrap().parse().unwrap();
+        assert_eq!(
+            doc.structured_tags,
+            vec![
+                StructuredTag {
+                    name: "Company".to_string(),
+                    tag: Some("company_name".to_string()),
+                    value: "ACME Corp".to_string(),
+                    position: Some((0, 1)),
+                },
+                StructuredTag {
+                    name: "signed_on".to_string(),
+                    tag: Some("signed_on".to_string()),
+                    value: "2024-05-01\nSeoul".to_string(),
+                    position: Some((1, 0)),
+                },
+                StructuredTag { name: "Notes".to_string(), tag: None, value: String::new(), position: None },
+            ]
+        );
+        // Content control text stays part of the body
+        assert!(doc.text().contains("ACME Corp"));
+        assert!(doc.text().contains("Unnamed block"));
+    }
+
+    #[test]
+    fn test_structured_tags_labelled_in_mdx() {
+        let doc = DocxParser::from_bytes(docx_from_document_xml(SDT_DOCUMENT)).unwrap().parse().unwrap();
+        let mdx = doc.to_mdx("contract.docx");
+        assert!(mdx.contains("Contract between **Company:** ACME Corp and us."), "{}", mdx);
+        assert!(mdx.contains("**signed_on:** 2024-05-01\n\nSeoul"), "{}", mdx);
+        assert!(!mdx.contains("**Notes:**"), "{}", mdx);
+        assert_eq!(mdx.matches("**").count(), 4);
+    }
+
+    #[test]
+    fn test_structured_tag_label_at_recorded_position() {
+        // The value also appears earlier in the body; the label must land on
+        // the control, not on the first text match
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+            <w:p><w:r><w:t>Seoul office</w:t></w:r></w:p>
+            <w:p><w:r><w:t>Based in</w:t></w:r>
+                <w:sdt><w:sdtPr><w:alias w:val="City"/></w:sdtPr><w:sdtContent><w:r><w:t>Seoul</w:t></w:r></w:sdtContent></w:sdt></w:p>
+        </w:body></w:document>"#;
+        let doc = DocxParser::from_bytes(docx_from_document_xml(xml)).unwrap().parse().unwrap();
+        let mdx = doc.to_mdx("x.docx");
+        assert!(mdx.contains("Seoul office\n\nBased in **City:** Seoul"), "{}", mdx);
+        assert!(!doc.to_markdown().contains("**"), "labels are MDX-only");
+    }
+
+    #[test]
+    fn test_nested_structured_tags() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+            <w:sdt><w:sdtPr><w:alias w:val="Address"/></w:sdtPr><w:sdtContent><w:p>
+                <w:sdt><w:sdtPr><w:alias w:val="City"/></w:sdtPr><w:sdtContent><w:r><w:t>Busan</w:t></w:r></w:sdtContent></w:sdt>
+                <w:r><w:t>Haeundae</w:t></w:r>
+            </w:p></w:sdtContent></w:sdt>
+        </w:body></w:document>"#;
+        let doc = DocxParser::from_bytes(docx_from_document_xml(xml)).unwrap().parse().unwrap();
+        let names: Vec<(&str, &str)> =
+            doc.structured_tags.iter().map(|t| (t.name.as_str(), t.value.as_str())).collect();
+        assert_eq!(names, vec![("City", "Busan"), ("Address", "BusanHaeundae")]);
+    }
+
+    #[test]
+    fn test_no_comments_part() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>x</w:t></w:r></w:p></w:body></w:document>"#;
+        let mut parser = DocxParser::from_bytes(docx_from_document_xml(xml)).unwrap();
+        assert!(parser.extract_comments().unwrap().is_empty());
+        assert!(!parser.parse().unwrap().to_mdx("x.docx").contains("## Comments"));
+    }
+
+    const REVISION_DOCUMENT: &str = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+        <w:p><w:r><w:t>The</w:t></w:r>
+            <w:del w:id="1" w:author="Kim" w:date="2024-05-01T09:00:00Z"><w:r><w:delText>old</w:delText></w:r></w:del>
+            <w:ins w:id="2" w:author="Kim" w:date="2024-05-01T09:00:00Z"><w:r><w:t>new</w:t></w:r></w:ins>
+            <w:r><w:t>term.</w:t></w:r></w:p>
+        <w:p><w:ins w:id="3" w:author="Lee"><w:r><w:rPr><w:b/></w:rPr><w:t>Added clause</w:t></w:r></w:ins></w:p>
+        <w:tbl><w:tr><w:tc><w:p><w:r><w:t>Fee</w:t></w:r></w:p></w:tc><w:tc><w:p>
+            <w:del w:id="4" w:author="Lee"><w:r><w:delText>100</w:delText></w:r></w:del>
+            <w:ins w:id="5" w:author="Lee"><w:r><w:t>120</w:t></w:r></w:ins></w:p></w:tc></w:tr></w:tbl>
+    </w:body></w:document>"#;
+
+    fn parse_revisions(mode: RevisionMode) -> DocxDocument {
+        DocxParser::from_bytes(docx_from_document_xml(REVISION_DOCUMENT))
+            .unwrap()
+            .with_revision_mode(mode)
+            .parse()
+            .unwrap()
+    }
+
+    fn revision_run(text: &str, revision_type: Option<RevisionType>) -> TextRun {
+        TextRun { text: text.to_string(), revision_type, ..Default::default() }
+    }
+
+    #[test]
+    fn test_deleted_run_markdown_is_struck() {
+        assert_eq!(revision_run("old", Some(RevisionType::Delete)).to_markdown(), "~~old~~");
+        // Already struck through: no double `~~~~`
+        let struck = TextRun { strike: true, ..revision_run("old", Some(RevisionType::Delete)) };
+        assert_eq!(struck.to_markdown(), "~~old~~");
+    }
+
+    #[test]
+    fn test_inserted_run_markdown_is_plain() {
+        assert_eq!(revision_run("new", Some(RevisionType::Insert)).to_markdown(), "new");
+        let bold = TextRun { bold: true, ..revision_run("new", Some(RevisionType::Insert)) };
+        assert_eq!(bold.to_markdown(), "**new**");
+    }
+
+    #[test]
+    fn test_revision_runs_html() {
+        assert_eq!(revision_run("a<b", Some(RevisionType::Insert)).to_html(), "<ins>a&lt;b</ins>");
+        assert_eq!(revision_run("old", Some(RevisionType::Delete)).to_html(), "<del>old</del>");
+        assert_eq!(revision_run("plain", None).to_html(), "plain");
+    }
+
+    #[test]
+    fn test_parse_marks_inserted_runs() {
+        let doc = parse_revisions(RevisionMode::Markup);
+        let inserted: Vec<&str> = doc.paragraphs.iter()
+            .flat_map(|p| &p.runs)
+            .filter(|r| r.revision_type == Some(RevisionType::Insert))
+            .map(|r| r.text.as_str())
+            .collect();
+        assert_eq!(inserted, vec!["new", "Added clause"]);
+        assert!(doc.paragraphs[1].runs[0].bold);
+    }
+
+    #[test]
+    fn test_parse_collects_deleted_text() {
+        let doc = parse_revisions(RevisionMode::Markup);
+        let deleted: Vec<&TextRun> = doc.paragraphs[0].runs.iter()
+            .filter(|r| r.revision_type == Some(RevisionType::Delete))
+            .collect();
+        assert_eq!(deleted.len(), 1);
+        assert_eq!(deleted[0].text, "old");
+        // Runs outside <w:ins>/<w:del> carry no revision
+        assert_eq!(doc.paragraphs[0].runs[0].revision_type, None);
+        assert_eq!(doc.paragraphs[0].runs[3].revision_type, None);
+    }
+
+    #[test]
+    fn test_has_tracked_changes() {
+        assert!(parse_revisions(RevisionMode::Markup).has_tracked_changes);
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>x</w:t></w:r></w:p></w:body></w:document>"#;
+        let doc = DocxParser::from_bytes(docx_from_document_xml(xml)).unwrap().parse().unwrap();
+        assert!(!doc.has_tracked_changes);
+        assert!(!doc.to_mdx("x.docx").contains("trackedChanges"));
+    }
+
+    #[test]
+    fn test_markup_renders_both_sides() {
+        let doc = parse_revisions(RevisionMode::Markup);
+        let md = doc.to_markdown();
+        assert!(md.contains("The ~~old~~ new term."), "{}", md);
+        assert!(md.contains("**Added clause**"), "{}", md);
+        assert_eq!(doc.tables[0].rows[0].cells[1].content, "~~100~~120");
+        assert!(doc.to_mdx("x.docx").contains("format: docx\ntrackedChanges: true\n"));
+    }
+
+    #[test]
+    fn test_accept_all_keeps_insertions() {
+        let doc = parse_revisions(RevisionMode::AcceptAll);
+        let md = doc.to_markdown();
+        assert!(md.contains("The new term."), "{}", md);
+        assert!(md.contains("**Added clause**"), "{}", md);
+        assert!(!md.contains("old") && !md.contains('~'), "{}", md);
+        assert_eq!(doc.tables[0].rows[0].cells[1].content, "120");
+        // Accepted runs are ordinary text now, but the document still had changes
+        assert!(doc.paragraphs.iter().flat_map(|p| &p.runs).all(|r| r.revision_type.is_none()));
+        assert!(doc.has_tracked_changes);
+    }
+
+    #[test]
+    fn test_reject_all_keeps_original_text() {
+        let doc = parse_revisions(RevisionMode::RejectAll);
+        let md = doc.to_markdown();
+        assert!(md.contains("The old term."), "{}", md);
+        assert!(!md.contains("new") && !md.contains("Added clause") && !md.contains('~'), "{}", md);
+        assert_eq!(doc.tables[0].rows[0].cells[1].content, "100");
+        assert!(doc.has_tracked_changes);
+    }
+
+    #[test]
+    fn test_revisions_in_content_control_value() {
+        let xml = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p>
+            <w:sdt><w:sdtPr><w:alias w:val="Party"/></w:sdtPr><w:sdtContent>
+                <w:del w:id="1" w:author="Kim"><w:r><w:delText>ACME</w:delText></w:r></w:del>
+                <w:ins w:id="2" w:author="Kim"><w:r><w:t>Globex</w:t></w:r></w:ins>
+            </w:sdtContent></w:sdt></w:p></w:body></w:document>"#;
+        let value = |mode| {
+            let doc = DocxParser::from_bytes(docx_from_document_xml(xml))
+                .unwrap()
+                .with_revision_mode(mode)
+                .parse()
+                .unwrap();
+            doc.structured_tags[0].value.clone()
+        };
+        assert_eq!(value(RevisionMode::Markup), "Globex");
+        assert_eq!(value(RevisionMode::AcceptAll), "Globex");
+        assert_eq!(value(RevisionMode::RejectAll), "ACME");
+    }
 }
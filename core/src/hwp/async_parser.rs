@@ -0,0 +1,197 @@
+//! Async facade over [`HwpParser`] for tokio-based servers (feature `tokio`)
+//!
+//! HWP parsing is synchronous file I/O plus CPU-bound decompression, so each
+//! call runs the blocking parser on tokio's blocking pool via
+//! `spawn_blocking` instead of stalling the async worker threads.
+
+use super::parser::{HwpParser, MdmDocument};
+use crate::cache::{Cache, DocumentKey};
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// [`HwpParser`] whose methods can be awaited from async code.
+///
+/// The parser lives behind `Arc<Mutex<_>>` so a cancelled future never loses
+/// it: the blocking task keeps its own handle and finishes in the background.
+pub struct AsyncHwpParser {
+    inner: Arc<Mutex<HwpParser>>,
+    /// Same cache handed to [`HwpParser::with_cache`], kept for lookups
+    cache: Option<Arc<Cache<DocumentKey, MdmDocument>>>,
+}
+
+impl AsyncHwpParser {
+    /// Open an HWP file without blocking the async runtime
+    pub async fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let parser = blocking(move || HwpParser::open(path)).await?;
+        Ok(Self::from_parser(parser))
+    }
+
+    /// Open an HWP file whose [`Self::to_mdm`] results are stored in `cache`
+    pub async fn open_with_cache<P: AsRef<Path>>(
+        path: P,
+        cache: Arc<Cache<DocumentKey, MdmDocument>>,
+    ) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let shared = Arc::clone(&cache);
+        let parser = blocking(move || Ok(HwpParser::open(path)?.with_cache(shared))).await?;
+        Ok(Self { inner: Arc::new(Mutex::new(parser)), cache: Some(cache) })
+    }
+
+    /// Wrap an already configured parser
+    pub fn from_parser(parser: HwpParser) -> Self {
+        Self { inner: Arc::new(Mutex::new(parser)), cache: None }
+    }
+
+    /// Async [`HwpParser::extract_text`]
+    pub async fn extract_text(&mut self) -> io::Result<String> {
+        self.run(|parser| parser.extract_text()).await
+    }
+
+    /// Async [`HwpParser::to_mdm`]; consults the cache when opened with one
+    pub async fn to_mdm(&mut self) -> io::Result<MdmDocument> {
+        self.run(|parser| parser.to_mdm()).await
+    }
+
+    /// Async [`HwpParser::source_hash`]
+    pub async fn source_hash(&mut self) -> io::Result<DocumentKey> {
+        self.run(|parser| parser.source_hash()).await
+    }
+
+    /// Cached [`Self::to_mdm`] result for this document, without converting.
+    ///
+    /// `Ok(None)` on a miss or when the parser has no cache.
+    pub async fn cached_mdm(&mut self) -> io::Result<Option<MdmDocument>> {
+        let Some(cache) = self.cache.clone() else {
+            return Ok(None);
+        };
+        let key = self.source_hash().await?;
+        Ok(cache.get_async(key).await)
+    }
+
+    /// Run `f` against the parser on the blocking pool
+    async fn run<T, F>(&self, f: F) -> io::Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut HwpParser) -> io::Result<T> + Send + 'static,
+    {
+        let inner = Arc::clone(&self.inner);
+        blocking(move || {
+            let mut parser = inner
+                .lock()
+                .map_err(|_| io::Error::other("HWP parser poisoned by a panicked task"))?;
+            f(&mut parser)
+        })
+        .await
+    }
+}
+
+/// `spawn_blocking` with join errors (task panic/cancellation) folded into `io::Error`
+async fn blocking<T, F>(f: F) -> io::Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> io::Result<T> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f).await.map_err(io::Error::other)?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+    use std::time::Duration;
+
+    /// Uncompressed HWP 5.0 file with a single one-paragraph section
+    fn write_hwp(dir: &Path, text: &str) -> std::path::PathBuf {
+        let record = |tag: u32, level: u32, data: &[u8]| {
+            let mut out = (tag | (level << 10) | ((data.len() as u32) << 20)).to_le_bytes().to_vec();
+            out.extend_from_slice(data);
+            out
+        };
+        let mut utf16: Vec<u8> = text.encode_utf16().flat_map(|u| u.to_le_bytes()).collect();
+        utf16.extend_from_slice(&0x000Du16.to_le_bytes());
+        let mut body = record(0x42, 0, &[0u8; 22]);
+        body.extend(record(0x43, 1, &utf16));
+
+        let mut cf = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        let mut header = vec![0u8; 256];
+        header[..17].copy_from_slice(b"HWP Document File");
+        header[32..36].copy_from_slice(&[0, 3, 0, 5]);
+        cf.create_stream("/FileHeader").unwrap().write_all(&header).unwrap();
+        cf.create_stream("/DocInfo").unwrap();
+        cf.create_storage("/BodyText").unwrap();
+        cf.create_stream("/BodyText/Section0").unwrap().write_all(&body).unwrap();
+        cf.flush().unwrap();
+
+        let path = dir.join("sample.hwp");
+        std::fs::write(&path, cf.into_inner().into_inner()).unwrap();
+        path
+    }
+
+    fn doc_cache(dir: &Path) -> Arc<Cache<DocumentKey, MdmDocument>> {
+        Arc::new(Cache::new(dir.join("cache"), Duration::from_secs(3600)).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_open_missing_file_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = AsyncHwpParser::open(dir.path().join("missing.hwp")).await.err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[tokio::test]
+    async fn test_extract_text() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_hwp(dir.path(), "비동기 본문");
+        let mut parser = AsyncHwpParser::open(&path).await.unwrap();
+        assert!(parser.extract_text().await.unwrap().contains("비동기 본문"));
+        // The parser survives across calls
+        assert!(parser.extract_text().await.unwrap().contains("비동기 본문"));
+    }
+
+    #[tokio::test]
+    async fn test_to_mdm_matches_blocking_parser() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_hwp(dir.path(), "변환 결과");
+        let expected = HwpParser::open(&path).unwrap().to_mdm().unwrap();
+        let doc = AsyncHwpParser::open(&path).await.unwrap().to_mdm().await.unwrap();
+        assert_eq!(doc.content, expected.content);
+        assert!(doc.content.contains("변환 결과"));
+    }
+
+    #[tokio::test]
+    async fn test_cache_miss_then_hit() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_hwp(dir.path(), "캐시 문서");
+        let cache = doc_cache(dir.path());
+
+        let mut parser = AsyncHwpParser::open_with_cache(&path, Arc::clone(&cache)).await.unwrap();
+        assert!(parser.cached_mdm().await.unwrap().is_none());
+        let doc = parser.to_mdm().await.unwrap();
+
+        let mut reopened = AsyncHwpParser::open_with_cache(&path, cache).await.unwrap();
+        let cached = reopened.cached_mdm().await.unwrap().expect("cache hit");
+        assert_eq!(cached.content, doc.content);
+    }
+
+    #[tokio::test]
+    async fn test_cached_mdm_without_cache_is_none() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_hwp(dir.path(), "캐시 없음");
+        let mut parser = AsyncHwpParser::open(&path).await.unwrap();
+        parser.to_mdm().await.unwrap();
+        assert!(parser.cached_mdm().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cache_get_async_and_insert_async() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache: Arc<Cache<String, String>> =
+            Arc::new(Cache::new(dir.path(), Duration::from_secs(3600)).unwrap());
+
+        assert_eq!(cache.get_async("key".to_string()).await, None);
+        cache.insert_async("key".to_string(), "value".to_string()).await.unwrap();
+        assert_eq!(cache.get_async("key".to_string()).await, Some("value".to_string()));
+    }
+}
@@ -1,4 +1,4 @@
-//! HWP 배포용(distribution / 열람 제한) 문서 복호화.
+//! HWP 배포용(distribution / 열람 제한) 문서와 암호 설정 문서 복호화.
 //!
 //! 배포용 HWP 파일은 일반 `BodyText/Section{N}` 대신 `ViewText/Section{N}`
 //! 스트림에 암호화된 본문을 저장한다. 각 ViewText 스트림의 첫 레코드
@@ -16,6 +16,11 @@
 //! - `reference/kordoc/src/hwp5/crypto.ts` (순수 JS 포팅)
 //! - `reference/kordoc/src/hwp5/aes.ts` (AES FIPS-197)
 //! - rhwp (MIT) `src/parser/crypto.rs`
+//!
+//! 암호 설정 문서 (FileHeader `encrypted` 플래그) 는 지원하지 않는다. 한컴이
+//! 공개한 HWP 5.0 문서 형식에는 속성 비트 1 "암호 설정 여부" 만 있고 키
+//! 유도나 암호 검증 방식은 나와 있지 않다 (`HwpParser::open_with_password`
+//! 는 `Unsupported` 를 돌려준다).
 
 use crate::utils::bounded_io::{decompress_raw_deflate_limited, MAX_HWP_SECTION};
 use aes::cipher::{generic_array::GenericArray, BlockDecrypt, KeyInit};
@@ -170,6 +175,45 @@ fn aes128_ecb_decrypt(ciphertext: &[u8], key: &[u8; 16]) -> Vec<u8> {
     out
 }
 
+// ── RC4 ──────────────────────────────────────────────────────────────────────
+
+/// RC4 스트림 암호. 암호화와 복호화가 같은 연산이다.
+pub struct Rc4 {
+    state: [u8; 256],
+    i: u8,
+    j: u8,
+}
+
+impl Rc4 {
+    /// 키 스케줄링. 빈 키는 `InvalidInput`.
+    pub fn new(key: &[u8]) -> io::Result<Self> {
+        if key.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "empty RC4 key"));
+        }
+        let mut state = [0u8; 256];
+        for (i, s) in state.iter_mut().enumerate() {
+            *s = i as u8;
+        }
+        let mut j: u8 = 0;
+        for i in 0..=255u8 {
+            j = j.wrapping_add(state[i as usize]).wrapping_add(key[i as usize % key.len()]);
+            state.swap(i as usize, j as usize);
+        }
+        Ok(Self { state, i: 0, j: 0 })
+    }
+
+    /// 키 스트림을 `data` 에 XOR 한다
+    pub fn apply(&mut self, data: &mut [u8]) {
+        for byte in data {
+            self.i = self.i.wrapping_add(1);
+            self.j = self.j.wrapping_add(self.state[self.i as usize]);
+            self.state.swap(self.i as usize, self.j as usize);
+            let k = self.state[self.state[self.i as usize].wrapping_add(self.state[self.j as usize]) as usize];
+            *byte ^= k;
+        }
+    }
+}
+
 // ── 공개 API ─────────────────────────────────────────────────────────────────
 
 /// `ViewText/Section{N}` 스트림의 원본 바이트를 받아 복호화된 레코드 데이터를
@@ -344,4 +388,19 @@ mod tests {
         let err = decrypt_view_text(&data, false).unwrap_err();
         assert!(err.to_string().contains("DISTRIBUTE_DOC_DATA"));
     }
+
+    #[test]
+    fn rc4_known_answer() {
+        // Classic RC4 test vector: key "Key", plaintext "Plaintext"
+        let mut data = b"Plaintext".to_vec();
+        Rc4::new(b"Key").unwrap().apply(&mut data);
+        assert_eq!(data, [0xbb, 0xf3, 0x16, 0xe8, 0xd9, 0x40, 0xaf, 0x0a, 0xd3]);
+        Rc4::new(b"Key").unwrap().apply(&mut data);
+        assert_eq!(data, b"Plaintext");
+    }
+
+    #[test]
+    fn rc4_rejects_empty_key() {
+        assert_eq!(Rc4::new(&[]).err().unwrap().kind(), io::ErrorKind::InvalidInput);
+    }
 }
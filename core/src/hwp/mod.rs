@@ -1,8 +1,20 @@
+#[cfg(feature = "tokio")]
+pub mod async_parser;
 pub mod cfb_lenient;
 pub mod crypto;
 pub mod ole;
 pub mod parser;
 pub mod record;
 
-pub use parser::HwpParser;
-pub use record::{HwpRecord, RecordParser, extract_para_text};
+#[cfg(feature = "tokio")]
+pub use async_parser::AsyncHwpParser;
+pub use parser::{
+    BinDataType, CachedHwpParser, EquationData, HwpLink, HwpParser, SectionCache, SectionContent, SectionKey,
+    StreamWarning, StylesSummary, TextMatch,
+};
+pub use record::{
+    HwpRecord, NumberShape, NumberingDef, NumberingLevel, PageDef, PageOrientation, ParaShape, ParagraphAlignment,
+    ParagraphStyle, ParseMode, ParseWarning, RecordParser, RevisionEntry, ShapeComponent, ShapeType, TocEntry,
+    extract_para_text, hwpunit_to_mm, parse_doc_history, parse_face_name, parse_numbering_definition, parse_toc_ctrl,
+    shape_to_svg,
+};
@@ -222,6 +222,27 @@ impl OleReader {
         }
     }
 
+    /// 모든 스트림의 이름과 크기 (스트림 내용은 읽지 않는다)
+    pub fn stream_sizes(&self) -> Vec<(String, u64)> {
+        match &self.backend {
+            OleBackend::Standard(cf) => cf
+                .walk()
+                .filter(|entry| entry.is_stream())
+                .map(|entry| (entry.path().to_string_lossy().to_string(), entry.len()))
+                .collect(),
+            OleBackend::Memory(cf) => cf
+                .walk()
+                .filter(|entry| entry.is_stream())
+                .map(|entry| (entry.path().to_string_lossy().to_string(), entry.len()))
+                .collect(),
+            OleBackend::Lenient(lcfb) => lcfb
+                .entries()
+                .into_iter()
+                .map(|e| (e.name.clone(), e.size))
+                .collect(),
+        }
+    }
+
     /// 특정 스트림의 내용을 읽습니다 (raw, uncompressed).
     /// Capped at `MAX_HWP_SECTION` so a malformed CFB with a gigantic stream
     /// cannot exhaust memory.
@@ -271,6 +292,13 @@ impl OleReader {
         self.read_compressed_stream("DocInfo")
     }
 
+    /// DocHistory 스트림(문서 이력)을 읽습니다.
+    /// Only present when the author kept revision history
+    /// ([`HwpFlags::history`]); a missing stream is `NotFound`.
+    pub fn read_doc_history(&mut self) -> io::Result<Vec<u8>> {
+        self.read_compressed_stream("DocHistory")
+    }
+
     /// BodyText 섹션을 읽습니다
     pub fn read_body_text(&mut self, section: usize) -> io::Result<Vec<u8>> {
         let stream_name = format!("BodyText/Section{}", section);
@@ -1,17 +1,33 @@
 use super::ole::OleReader;
 use super::record::{
-    HwpRecord, RecordParser, extract_para_text, parse_table_info,
-    parse_char_shape, parse_para_char_shape, extract_para_text_formatted,
-    parse_cell_list_header, parse_picture_component, CellSpan,
-    CharShape, ParaCharShapeMapping,
+    HwpRecord, ParseWarning, RecordParser, extract_para_text, parse_table_info,
+    parse_char_shape, parse_para_char_shape, parse_para_shape, extract_para_text_formatted,
+    parse_cell_list_header, parse_picture_component, parse_border_fill,
+    parse_cell_border_fill_id, parse_face_name, parse_style, parse_shape_component, parse_line_points,
+    parse_polygon_points, parse_toc_ctrl, parse_numbering_definition, shape_to_svg, BorderFill,
+    CellSpan, CharShape, HwpTable, NumberingDef, PageDef, ParaCharShapeMapping, ParagraphAlignment, ParagraphStyle, ShapeComponent,
+    ShapeType, TableCell, TocEntry, RevisionEntry, parse_doc_history,
     HWPTAG_PARA_TEXT, HWPTAG_PARA_HEADER, HWPTAG_TABLE, HWPTAG_LIST_HEADER,
+    HWPTAG_BORDER_FILL, HWPTAG_STYLE, HWPTAG_NUMBERING, HWPTAG_SHAPE_COMPONENT,
+    HWPTAG_SHAPE_COMPONENT_LINE, HWPTAG_SHAPE_COMPONENT_RECTANGLE,
+    HWPTAG_SHAPE_COMPONENT_ELLIPSE, HWPTAG_SHAPE_COMPONENT_POLYGON,
     HWPTAG_PARA_CHAR_SHAPE, HWPTAG_CHAR_SHAPE, HWPTAG_PARA_SHAPE, HWPTAG_CTRL_HEADER,
     HWPTAG_SHAPE_COMPONENT_PICTURE, HWPTAG_BIN_DATA, HWPTAG_EQEDIT,
+    HWPTAG_PAGE_DEF, HWPTAG_PARA_LINE_SEG, HWPTAG_FACE_NAME, parse_line_seg_positions, parse_page_def,
+    CHAR_FIELD_END, CHAR_FIELD_START, EXTENDED_CTRL_CHARS, html_escape,
 };
-use crate::ir::{blocks_to_markdown, IRBlock, IRCell, IRTable};
-use std::collections::HashMap;
+use crate::cache::{Cache, DocumentKey};
+use crate::ir::{blocks_to_markdown, IRBlock, IRCell, IRTable, InlineNote};
+use base64::Engine;
+use lazy_static::lazy_static;
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, VecDeque};
 use std::io::{self};
-use std::path::Path;
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
 
 /// HWP 파일 파서
 pub struct HwpParser {
@@ -20,6 +36,45 @@ pub struct HwpParser {
     char_shapes: HashMap<u32, CharShape>,
     /// Paragraph shape definitions from DocInfo (outline_level per paraShapeId)
     para_shapes: HashMap<u32, ParaShapeInfo>,
+    /// Border/fill definitions from DocInfo, keyed by 1-based borderFillId
+    border_fills: HashMap<u16, BorderFill>,
+    /// Paragraph styles from DocInfo, keyed by 0-based styleId
+    paragraph_styles: HashMap<u32, ParagraphStyle>,
+    /// Paragraph numbering definitions from DocInfo, keyed by 1-based numberingId
+    numberings: HashMap<u16, NumberingDef>,
+    /// First `PAGE_DEF` of each section, filled by [`HwpParser::page_definitions`]
+    page_defs: Vec<PageDef>,
+    /// Conversion cache consulted by [`HwpParser::to_mdm`]
+    cache: Option<Arc<Cache<DocumentKey, MdmDocument>>>,
+    /// Mirrors [`crate::Config::cache_enabled`]; gates every cache operation
+    cache_enabled: bool,
+    /// Inverse of [`crate::Config::strip_color_spans`]; wrap colored text in
+    /// `<span style="color:...">`
+    color_spans: bool,
+    /// Mirrors [`crate::Config::strict_records`]; see [`HwpParser::record_parser`]
+    strict_records: bool,
+    /// [`crate::Config::validate`] failures of the config given to
+    /// [`HwpParser::with_config`]; [`HwpParser::to_mdm`] refuses to run
+    config_errors: Vec<crate::ConfigError>,
+    /// Decompressed-section cache, set by [`CachedHwpParser::open`]
+    section_cache: Option<SectionSource>,
+    /// Recovery-mode warnings, filled by [`HwpParser::parse_warnings`]
+    parse_warnings: Option<Vec<StreamWarning>>,
+}
+
+/// Section cache key: (canonical source path, section index, source mtime).
+/// A newer mtime produces new keys, so edited files are never served stale
+/// sections.
+pub type SectionKey = (PathBuf, usize, SystemTime);
+
+/// Disk cache of decompressed `BodyText/Section{N}` bytes
+pub type SectionCache = Cache<SectionKey, Vec<u8>>;
+
+/// The cache plus the key parts fixed at open time
+struct SectionSource {
+    cache: Arc<SectionCache>,
+    path: PathBuf,
+    mtime: SystemTime,
 }
 
 /// Minimal ParaShape info extracted from DocInfo
@@ -27,8 +82,28 @@ pub struct HwpParser {
 struct ParaShapeInfo {
     /// Outline level (0 = body, 1-7 = heading levels)
     outline_level: u8,
+    /// Paragraph alignment (properties1 bits 2-4)
+    alignment: ParagraphAlignment,
+    /// Left margin in HWPUNIT
+    indent_left: i32,
+    /// Paragraph head type (properties1 bits 23-24): 0 none, 1 outline,
+    /// 2 numbering, 3 bullet
+    head_shape: u8,
+    /// Paragraph level (properties1 bits 25-27, 0-based) — the heading
+    /// level for outlines, the list level for numbering and bullets
+    para_level: u8,
+    /// 1-based numbering/bullet ID (offset 30); 0 when absent
+    numbering_id: u16,
 }
 
+/// Number of numbering levels tracked per list
+const LIST_LEVELS: usize = 7;
+
+/// Left margin (HWPUNIT) at which a body paragraph is rendered as a
+/// `>` blockquote. 720 HWPUNIT = 0.1 inch; anything smaller is usually
+/// just list or numbering alignment.
+const BLOCKQUOTE_INDENT: i32 = 720;
+
 impl HwpParser {
     /// HWP 파일을 엽니다
     pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
@@ -37,9 +112,32 @@ impl HwpParser {
             ole_reader,
             char_shapes: HashMap::new(),
             para_shapes: HashMap::new(),
+            border_fills: HashMap::new(),
+            paragraph_styles: HashMap::new(),
+            numberings: HashMap::new(),
+            page_defs: Vec::new(),
+            cache: None,
+            cache_enabled: true,
+            color_spans: true,
+            strict_records: false,
+            config_errors: Vec::new(),
+            section_cache: None,
+            parse_warnings: None,
         })
     }
 
+    /// 암호가 설정된 HWP 파일을 엽니다.
+    ///
+    /// 암호 설정 문서 (FileHeader `encrypted` 플래그) 는 지원하지 않는다:
+    /// 공개된 HWP 5.0 규격에 암호 방식이 나와 있지 않아 `Unsupported` 를
+    /// 돌려준다. 암호가 없는 파일은 `password` 를 무시하고
+    /// [`HwpParser::open`] 과 같다.
+    pub fn open_with_password<P: AsRef<Path>>(path: P, _password: &str) -> io::Result<Self> {
+        let parser = Self::open(path)?;
+        parser.ensure_not_encrypted()?;
+        Ok(parser)
+    }
+
     /// Create an HWP parser from in-memory data.
     ///
     /// This constructor is used for WASM and other environments
@@ -50,18 +148,293 @@ impl HwpParser {
             ole_reader,
             char_shapes: HashMap::new(),
             para_shapes: HashMap::new(),
+            border_fills: HashMap::new(),
+            paragraph_styles: HashMap::new(),
+            numberings: HashMap::new(),
+            page_defs: Vec::new(),
+            cache: None,
+            cache_enabled: true,
+            color_spans: true,
+            strict_records: false,
+            config_errors: Vec::new(),
+            section_cache: None,
+            parse_warnings: None,
         })
     }
 
-    /// Parse DocInfo stream to extract character shapes and paragraph shapes
+    /// Apply engine [`crate::Config`] options (`cache_enabled`,
+    /// `strip_color_spans`, `strict_records`).
+    ///
+    /// An invalid config (see [`crate::Config::validate`]) makes
+    /// [`HwpParser::to_mdm`] fail with `InvalidInput`.
+    pub fn with_config(mut self, config: &crate::Config) -> Self {
+        self.cache_enabled = config.cache_enabled;
+        self.color_spans = !config.strip_color_spans;
+        self.strict_records = config.strict_records;
+        self.config_errors = config.validate().err().unwrap_or_default();
+        self
+    }
+
+    /// Reuse [`HwpParser::to_mdm`] results from `cache`, keyed by
+    /// [`HwpParser::source_hash`]
+    pub fn with_cache(mut self, cache: Arc<Cache<DocumentKey, MdmDocument>>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// SHA-256 over the OLE streams that shape [`HwpParser::to_mdm`] output.
+    ///
+    /// `FileHeader`, `DocInfo`, the summary information and the body text
+    /// sections (`BodyText`/`ViewText`) contribute name + raw bytes; every
+    /// other stream — `BinData` images, previews, scripts — only its name and
+    /// size, so large embedded binaries are never read just to build a cache
+    /// key. Identifies the source document independent of its path, so
+    /// renamed or re-uploaded copies hit the same cache entry.
+    pub fn source_hash(&mut self) -> io::Result<DocumentKey> {
+        let mut streams = self.ole_reader.stream_sizes();
+        streams.sort();
+        let mut hasher = Sha256::new();
+        for (name, size) in streams {
+            hasher.update((name.len() as u64).to_le_bytes());
+            hasher.update(name.as_bytes());
+            hasher.update(size.to_le_bytes());
+            if !is_content_stream(&name) {
+                continue;
+            }
+            // Unreadable streams contribute only their name and size
+            if let Ok(data) = self.ole_reader.read_stream(&name) {
+                hasher.update(&data);
+            }
+        }
+        Ok(hasher.finalize().into())
+    }
+
+    /// Duplicate-detection fingerprint: the first 16 hex digits of the
+    /// SHA-256 of section 0's plain text.
+    ///
+    /// Unlike [`Self::source_hash`] it ignores everything but the body text
+    /// of the first section, so re-saved copies of a document (different
+    /// summary info, preview image, ...) still match.
+    pub fn content_fingerprint(&mut self) -> io::Result<String> {
+        let data = self.read_section_records_data(0)?;
+        let digest = Sha256::digest(self.parse_section_records(&data).as_bytes());
+        Ok(digest.iter().take(8).map(|b| format!("{:02x}", b)).collect())
+    }
+
+    /// Estimated page count of section 0.
+    ///
+    /// Top-level `PARA_LINE_SEG` vertical positions are page-relative, so
+    /// every drop marks a page break. Documents saved without line segments
+    /// fall back to [`estimate_page_count`] over the section text, with the
+    /// lines per page taken from `PAGE_DEF` (or [`DEFAULT_LINES_PER_PAGE`]).
+    pub fn page_count(&mut self) -> io::Result<u32> {
+        let data = self.read_section_records_data(0)?;
+        let records = self.record_parser(&data).parse_all();
+
+        // Paragraphs in the section body sit at level 0, their line segments at 1
+        let positions: Vec<i32> = records
+            .iter()
+            .filter(|r| r.tag_id == HWPTAG_PARA_LINE_SEG && r.level == 1)
+            .flat_map(|r| parse_line_seg_positions(&r.data))
+            .collect();
+        if !positions.is_empty() {
+            let breaks = positions.windows(2).filter(|w| w[1] < w[0]).count();
+            return Ok(breaks as u32 + 1);
+        }
+
+        let lines_per_page = records
+            .iter()
+            .find(|r| r.tag_id == HWPTAG_PAGE_DEF)
+            .and_then(|r| parse_page_def(&r.data))
+            .map_or(DEFAULT_LINES_PER_PAGE, |page| page.lines_per_page());
+        Ok(estimate_page_count(&self.parse_section_records(&data), lines_per_page))
+    }
+
+    /// 구역별 용지 설정 (각 구역의 첫 `HWPTAG_PAGE_DEF`).
+    ///
+    /// 처음 호출할 때 모든 구역을 읽어 모아 둔다. 읽을 수 없거나 용지
+    /// 설정이 없는 구역은 건너뛰고, 잠긴 암호 문서는 빈 목록이다.
+    pub fn page_definitions(&mut self) -> &[PageDef] {
+        if self.page_defs.is_empty() && self.ensure_not_encrypted().is_ok() {
+            for index in 0..self.ole_reader.section_count() {
+                let Ok(data) = self.read_section_records_data(index) else { continue };
+                let page = self.record_parser(&data)
+                    .parse_all()
+                    .iter()
+                    .find(|r| r.tag_id == HWPTAG_PAGE_DEF)
+                    .and_then(|r| parse_page_def(&r.data));
+                self.page_defs.extend(page);
+            }
+        }
+        &self.page_defs
+    }
+
+    /// Record parser for a decompressed stream: recovery mode, or strict mode
+    /// (stop at the first truncated record) with
+    /// [`crate::Config::strict_records`]. Skipped records are reported by
+    /// [`HwpParser::parse_warnings`], not here.
+    fn record_parser<'a>(&self, data: &'a [u8]) -> RecordParser<'a> {
+        if self.strict_records {
+            RecordParser::new(data)
+        } else {
+            RecordParser::with_recovery(data)
+        }
+    }
+
+    /// 레코드 복구 경고 (잘려서 건너뛴 레코드), 스트림별.
+    ///
+    /// 처음 호출할 때 DocInfo 와 모든 구역을 한 번 복구 모드로 읽어 모아
+    /// 둔다 — 변환 중에는 구역을 몇 번 다시 읽든 경고를 출력하지 않는다.
+    /// `strict_records` 와 상관없이 복구 모드로 읽으므로 잘린 레코드가
+    /// 가려지지 않는다. 잠긴 암호 문서는 빈 목록이다.
+    pub fn parse_warnings(&mut self) -> &[StreamWarning] {
+        if self.parse_warnings.is_none() {
+            let mut found = Vec::new();
+            if self.ensure_not_encrypted().is_ok() {
+                let mut scan = |stream: String, data: &[u8]| {
+                    let (_, warnings) = RecordParser::with_recovery(data).parse_all_with_stats();
+                    found.extend(warnings.into_iter().map(|warning| StreamWarning { stream: stream.clone(), warning }));
+                };
+                if let Ok(data) = self.ole_reader.read_doc_info() {
+                    scan("DocInfo".to_string(), &data);
+                }
+                let distributed = self.ole_reader.flags().distributed;
+                let (storage, count) = if distributed {
+                    ("ViewText", self.ole_reader.view_section_count())
+                } else {
+                    ("BodyText", self.ole_reader.section_count())
+                };
+                for index in 0..count {
+                    if let Ok(data) = self.read_section_records_data(index) {
+                        scan(format!("{}/Section{}", storage, index), &data);
+                    }
+                }
+            }
+            self.parse_warnings = Some(found);
+        }
+        self.parse_warnings.as_deref().unwrap_or_default()
+    }
+
+    /// Decompressed record stream of section `index`, read from
+    /// `ViewText` and decrypted for distributed documents
+    fn read_section_records_data(&mut self, index: usize) -> io::Result<Vec<u8>> {
+        let flags = *self.ole_reader.flags();
+        if flags.distributed {
+            let raw = self.ole_reader.read_view_text_raw(index)?;
+            crate::hwp::crypto::decrypt_view_text(&raw, flags.compressed)
+        } else {
+            self.read_body_section(index)
+        }
+    }
+
+    /// `BodyText/Section{index}`, decompressed. Served from the section
+    /// cache when one is attached, populating it on a miss.
+    fn read_body_section(&mut self, index: usize) -> io::Result<Vec<u8>> {
+        let Some(source) = self.section_cache.as_ref().filter(|_| self.cache_enabled) else {
+            return self.ole_reader.read_body_text(index);
+        };
+        let key = (source.path.clone(), index, source.mtime);
+        if let Some(data) = source.cache.get(&key) {
+            return Ok(data);
+        }
+        let cache = Arc::clone(&source.cache);
+        let data = self.ole_reader.read_body_text(index)?;
+        if let Err(e) = cache.insert(&key, &data) {
+            eprintln!("Warning: Could not write section cache entry: {}", e);
+        }
+        Ok(data)
+    }
+
+    /// 문단 번호 정의를 추출합니다.
+    ///
+    /// DocInfo `HWPTAG_NUMBERING` records keyed by the 1-based numberingId
+    /// that numbered ParaShapes refer to (see [`parse_numbering_definition`]).
+    pub fn extract_number_lists(&mut self) -> &HashMap<u16, NumberingDef> {
+        if self.numberings.is_empty() {
+            let _ = self.parse_doc_info();
+        }
+        &self.numberings
+    }
+
+    /// Paragraph styles from DocInfo `HWPTAG_STYLE`, keyed by styleId
+    /// (the 0-based record order PARA_HEADER refers to)
+    pub fn paragraph_styles(&mut self) -> &HashMap<u32, ParagraphStyle> {
+        if self.paragraph_styles.is_empty() {
+            let _ = self.parse_doc_info();
+        }
+        &self.paragraph_styles
+    }
+
+    /// DocInfo 서식 통계: 글꼴(FACE_NAME), 글자 모양(CHAR_SHAPE), 문단
+    /// 스타일(STYLE) 레코드를 집계한다. 문서가 얼마나 다양한 서식을 쓰는지
+    /// 가늠하는 용도로 `analyze` 출력에 쓰인다.
+    pub fn extract_styles_summary(&mut self) -> io::Result<StylesSummary> {
+        let data = self.ole_reader.read_doc_info()?;
+        let mut summary = StylesSummary::default();
+        let mut combinations = std::collections::HashSet::new();
+        let mut style_names = std::collections::HashSet::new();
+
+        for record in self.record_parser(&data).parse_all() {
+            match record.tag_id {
+                // 글꼴은 언어별(한글/영문/한자 …) 목록에 같은 이름이 반복된다
+                HWPTAG_FACE_NAME => {
+                    if let Some(name) = parse_face_name(&record.data) {
+                        if !summary.font_names.contains(&name) {
+                            summary.font_names.push(name);
+                        }
+                    }
+                }
+                HWPTAG_CHAR_SHAPE => {
+                    summary.char_shape_count += 1;
+                    if let Some(shape) = parse_char_shape(&record.data) {
+                        summary.bold_count += usize::from(shape.bold);
+                        summary.italic_count += usize::from(shape.italic);
+                        summary.underline_count += usize::from(shape.underline);
+                        combinations.insert((shape.bold, shape.italic, shape.underline));
+                    }
+                }
+                HWPTAG_STYLE => {
+                    if let Some(style) = parse_style(&record.data).filter(|s| !s.is_char_style) {
+                        style_names.insert(style.name);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        summary.font_count = summary.font_names.len();
+        summary.emphasis_combinations = combinations.len();
+        summary.paragraph_style_count = style_names.len();
+        Ok(summary)
+    }
+
+    /// Parse DocInfo stream to extract character shapes, paragraph shapes,
+    /// border fills and paragraph styles
     fn parse_doc_info(&mut self) -> io::Result<()> {
         let data = self.ole_reader.read_doc_info()?;
-        let mut parser = RecordParser::new(&data);
+        let mut parser = self.record_parser(&data);
         let records = parser.parse_all();
 
         let mut char_shape_index: u32 = 0;
         let mut para_shape_index: u32 = 0;
+        let mut style_index: u32 = 0;
+        // numberingId references in ParaShape are 1-based (0 = none)
+        let mut numbering_index: u16 = 1;
+        // borderFillId references in cells/paragraphs are 1-based (0 = none)
+        let mut border_fill_index: u16 = 1;
         for record in records {
+            if record.tag_id == HWPTAG_BORDER_FILL {
+                if let Some(fill) = parse_border_fill(&record.data) {
+                    self.border_fills.insert(border_fill_index, fill);
+                }
+                border_fill_index = border_fill_index.saturating_add(1);
+            }
+            if record.tag_id == HWPTAG_NUMBERING {
+                if let Some(def) = parse_numbering_definition(&record.data) {
+                    self.numberings.insert(numbering_index, def);
+                }
+                numbering_index = numbering_index.saturating_add(1);
+            }
             if record.tag_id == HWPTAG_CHAR_SHAPE {
                 if let Some(shape) = parse_char_shape(&record.data) {
                     self.char_shapes.insert(char_shape_index, shape);
@@ -78,22 +451,62 @@ impl HwpParser {
                         record.data[2], record.data[3],
                     ]);
                     let head_shape_type = (props1 >> 23) & 0x03;
+                    let para_level = ((props1 >> 25) & 0x07) as u8;
                     let outline_level = if head_shape_type == 1 {
-                        ((props1 >> 25) & 0x07) as u8 + 1 // 0-based → 1-based (H1-H7)
+                        para_level + 1 // 0-based → 1-based (H1-H7)
                     } else {
                         0 // not a heading
                     };
+                    let indent_left = parse_para_shape(&record.data)
+                        .map(|shape| shape.indent_left)
+                        .unwrap_or(0);
                     self.para_shapes.insert(para_shape_index, ParaShapeInfo {
                         outline_level,
+                        alignment: ParagraphAlignment::from_bits(props1 >> 2),
+                        indent_left,
+                        head_shape: head_shape_type as u8,
+                        para_level,
+                        numbering_id: record
+                            .data
+                            .get(30..32)
+                            .map_or(0, |id| u16::from_le_bytes([id[0], id[1]])),
                     });
                 }
                 para_shape_index += 1;
             }
+            if record.tag_id == HWPTAG_STYLE {
+                if let Some(style) = parse_style(&record.data) {
+                    self.paragraph_styles.insert(style_index, style);
+                }
+                style_index += 1;
+            }
+        }
+
+        // STYLE records follow PARA_SHAPE in DocInfo; resolve alignment and
+        // outline-based heading levels once both tables are complete.
+        for style in self.paragraph_styles.values_mut() {
+            if let Some(shape) = self.para_shapes.get(&(style.para_shape_id as u32)) {
+                style.alignment = shape.alignment;
+                if style.heading_level.is_none() && shape.outline_level > 0 {
+                    style.heading_level = Some(shape.outline_level.min(6));
+                }
+            }
         }
 
         Ok(())
     }
 
+    /// 암호 설정 문서는 본문이 암호문이라 읽을 수 없으므로 거부한다
+    fn ensure_not_encrypted(&self) -> io::Result<()> {
+        if self.ole_reader.flags().encrypted {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "password-protected HWP files are not supported",
+            ));
+        }
+        Ok(())
+    }
+
     /// HWP 파일 구조를 분석합니다
     pub fn analyze(&self) -> FileStructure {
         let streams = self.ole_reader.list_streams();
@@ -108,6 +521,8 @@ impl HwpParser {
             bin_data_count: bin_data.len(),
             compressed: flags.compressed,
             encrypted: flags.encrypted,
+            page_count: None,
+            page_definitions: Vec::new(),
         }
     }
 
@@ -119,6 +534,8 @@ impl HwpParser {
     /// 한 뒤 동일한 레코드 파서에 흘려보낸다. 복호화 실패 시 해당 섹션은
     /// 경고만 남기고 스킵한다.
     pub fn extract_text(&mut self) -> io::Result<String> {
+        self.ensure_not_encrypted()?;
+
         // First, parse DocInfo to get character shapes
         if self.char_shapes.is_empty() {
             let _ = self.parse_doc_info();
@@ -152,7 +569,7 @@ impl HwpParser {
                         crate::hwp::crypto::decrypt_view_text(&raw, compressed)
                     })
             } else {
-                self.ole_reader.read_body_text(section_num)
+                self.read_body_section(section_num)
             };
 
             match section_data {
@@ -204,6 +621,7 @@ impl HwpParser {
     /// round-trip through the AES decryption path transparently.
     /// Per-section errors emit a warning to stderr and are skipped.
     pub fn extract_blocks(&mut self) -> io::Result<Vec<IRBlock>> {
+        self.ensure_not_encrypted()?;
         if self.char_shapes.is_empty() {
             let _ = self.parse_doc_info();
         }
@@ -230,7 +648,7 @@ impl HwpParser {
                         crate::hwp::crypto::decrypt_view_text(&raw, compressed)
                     })
             } else {
-                self.ole_reader.read_body_text(section_num)
+                self.read_body_section(section_num)
             };
 
             match section_data {
@@ -252,9 +670,60 @@ impl HwpParser {
         Ok(out)
     }
 
+    /// Stream the document one decompressed section at a time.
+    ///
+    /// Each item owns only that section's data, so peak memory stays at one
+    /// section instead of the whole body — needed for 50 MB+ files. Sections
+    /// are read lazily as the iterator advances. Distribution-locked files
+    /// go through the `ViewText` decryption path like [`extract_text`].
+    ///
+    /// Blocks are the raw per-section walk: document-wide heading inference
+    /// by font size (see [`extract_blocks`]) is not applied.
+    pub fn stream_sections(&mut self) -> impl Iterator<Item = io::Result<SectionContent>> + '_ {
+        if self.char_shapes.is_empty() {
+            let _ = self.parse_doc_info();
+        }
+
+        let flags = *self.ole_reader.flags();
+        let distributed = flags.distributed;
+        let compressed = flags.compressed;
+        let section_count = if distributed {
+            self.ole_reader.view_section_count()
+        } else {
+            self.ole_reader.section_count()
+        };
+
+        (0..section_count).map(move |index| {
+            let data = if distributed {
+                self.ole_reader
+                    .read_view_text_raw(index)
+                    .and_then(|raw| crate::hwp::crypto::decrypt_view_text(&raw, compressed))
+            } else {
+                self.read_body_section(index)
+            };
+            let data = data.map_err(|e| {
+                io::Error::new(
+                    e.kind(),
+                    format!(
+                        "{}Section{}: {}",
+                        if distributed { "View" } else { "Body" },
+                        index,
+                        e
+                    ),
+                )
+            })?;
+            Ok(SectionContent {
+                index,
+                paragraphs: self.parse_section_records_to_blocks(&data),
+                tables: self.parse_section_tables(&data),
+                shapes: self.parse_section_shapes(&data),
+            })
+        })
+    }
+
     /// Parse records from decompressed section data (without formatting - for compatibility)
     fn parse_section_records(&self, data: &[u8]) -> String {
-        let mut parser = RecordParser::new(data);
+        let mut parser = self.record_parser(data);
         let records = parser.parse_all();
 
         let mut paragraphs = Vec::new();
@@ -306,7 +775,7 @@ impl HwpParser {
     /// `LIST_HEADER` cell placement, same `gso` / `fn` / `en` subtree
     /// skipping to prevent duplicate emission.
     fn parse_section_records_to_blocks(&self, data: &[u8]) -> Vec<IRBlock> {
-        let mut parser = RecordParser::new(data);
+        let mut parser = self.record_parser(data);
         let records = parser.parse_all();
 
         let mut blocks: Vec<IRBlock> = Vec::new();
@@ -334,6 +803,7 @@ impl HwpParser {
                                     &text_data,
                                     current_char_shape_mapping.as_ref(),
                                     &self.char_shapes,
+                                    self.color_spans,
                                 );
                                 push_paragraph(&mut blocks, text);
                                 current_char_shape_mapping = None;
@@ -344,8 +814,14 @@ impl HwpParser {
                                 });
                             } else if let Some(box_text) =
                                 extract_subtree_text(&records, i, 200, "\n")
+                                    .filter(|t| !t.trim().is_empty())
                             {
                                 push_paragraph(&mut blocks, box_text);
+                            } else if extract_subtree_shape(&records, i, 200).is_some() {
+                                // Placeholder — MdmDocument::to_mdx inlines the SVG
+                                blocks.push(IRBlock::Image {
+                                    alt: SHAPE_ALT.to_string(),
+                                });
                             }
                             let end = subtree_end(&records, i, 200);
                             i = end;
@@ -362,6 +838,7 @@ impl HwpParser {
                                     &text_data,
                                     current_char_shape_mapping.as_ref(),
                                     &self.char_shapes,
+                                    self.color_spans,
                                 );
                                 push_paragraph(&mut blocks, text);
                                 current_char_shape_mapping = None;
@@ -369,20 +846,17 @@ impl HwpParser {
                             if let Some(note) = extract_subtree_text(&records, i, 100, " ") {
                                 let trimmed = note.trim();
                                 if !trimmed.is_empty() {
-                                    let is_endnote = id == b"  ne" || id == b"en  ";
-                                    let label = if is_endnote { "[미주]" } else { "[각주]" };
-                                    let body = format!("{} {}", label, trimmed);
+                                    let note = InlineNote {
+                                        is_endnote: id == b"  ne" || id == b"en  ",
+                                        body: trimmed.to_string(),
+                                    };
                                     match blocks.last_mut() {
-                                        Some(IRBlock::Paragraph { footnote, .. }) => {
-                                            match footnote {
-                                                Some(existing) => {
-                                                    existing.push_str("; ");
-                                                    existing.push_str(&body);
-                                                }
-                                                None => *footnote = Some(body),
-                                            }
-                                        }
-                                        _ => blocks.push(IRBlock::paragraph(body)),
+                                        Some(IRBlock::Paragraph { notes, .. }) => notes.push(note),
+                                        _ => blocks.push(IRBlock::Paragraph {
+                                            text: String::new(),
+                                            notes: vec![note],
+                                            href: None,
+                                        }),
                                     }
                                 }
                             }
@@ -398,6 +872,7 @@ impl HwpParser {
                                     &text_data,
                                     current_char_shape_mapping.as_ref(),
                                     &self.char_shapes,
+                                    self.color_spans,
                                 );
                                 push_paragraph(&mut blocks, text);
                                 current_char_shape_mapping = None;
@@ -444,7 +919,7 @@ impl HwpParser {
                                     _ => {
                                         blocks.push(IRBlock::Paragraph {
                                             text: String::new(),
-                                            footnote: None,
+                                            notes: Vec::new(),
                                             href: Some(url),
                                         });
                                     }
@@ -473,6 +948,7 @@ impl HwpParser {
                                 &text_data,
                                 current_char_shape_mapping.as_ref(),
                                 &self.char_shapes,
+                                self.color_spans,
                             );
                             push_paragraph_with_level(&mut blocks, text, current_outline_level);
                             current_char_shape_mapping = None;
@@ -490,6 +966,17 @@ impl HwpParser {
                             current_outline_level = ps.outline_level;
                         }
                     }
+                    // Fall back to the paragraph style (styleId at offset 10)
+                    if current_outline_level == 0 {
+                        if let Some(level) = record
+                            .data
+                            .get(10)
+                            .and_then(|id| self.paragraph_styles.get(&(*id as u32)))
+                            .and_then(|style| style.heading_level)
+                        {
+                            current_outline_level = level;
+                        }
+                    }
                 }
                 HWPTAG_TABLE => {
                     if let Some(text_data) = current_text_data.take() {
@@ -497,6 +984,7 @@ impl HwpParser {
                             &text_data,
                             current_char_shape_mapping.as_ref(),
                             &self.char_shapes,
+                            self.color_spans,
                         );
                         push_paragraph(&mut blocks, text);
                         current_char_shape_mapping = None;
@@ -561,6 +1049,7 @@ impl HwpParser {
                 &text_data,
                 current_char_shape_mapping.as_ref(),
                 &self.char_shapes,
+                self.color_spans,
             );
             push_paragraph_with_level(&mut blocks, text, current_outline_level);
         }
@@ -578,7 +1067,12 @@ impl HwpParser {
     }
 
     fn parse_section_records_formatted(&self, data: &[u8]) -> String {
-        let mut parser = RecordParser::new(data);
+        self.parse_section_blocks_formatted(data).join("\n\n")
+    }
+
+    /// Paragraph-level Markdown blocks of one section, before joining
+    fn parse_section_blocks_formatted(&self, data: &[u8]) -> Vec<String> {
+        let mut parser = self.record_parser(data);
         let records = parser.parse_all();
 
         let mut blocks: Vec<String> = Vec::new();
@@ -586,6 +1080,14 @@ impl HwpParser {
         // Paragraph state
         let mut current_text_data: Option<Vec<u8>> = None;
         let mut current_char_shape_mapping: Option<ParaCharShapeMapping> = None;
+        // Heading level of the current paragraph's style (PARA_HEADER styleId)
+        let mut current_heading: Option<u8> = None;
+        // Whether the current paragraph's ParaShape is indented enough to quote
+        let mut current_quote = false;
+        // List marker prefix of a numbered / bulleted paragraph, and the
+        // running numbers of each numbering definition
+        let mut current_list: Option<String> = None;
+        let mut list_counters: HashMap<u16, [u32; LIST_LEVELS]> = HashMap::new();
 
         // Table state machine
         let mut in_table = false;
@@ -624,10 +1126,9 @@ impl HwpParser {
                                     &text_data,
                                     current_char_shape_mapping.as_ref(),
                                     &self.char_shapes,
+                                    self.color_spans,
                                 );
-                                if !text.trim().is_empty() {
-                                    blocks.push(text);
-                                }
+                                push_styled_block(&mut blocks, text, current_heading, current_list.as_deref(), current_quote);
                                 current_char_shape_mapping = None;
                             }
                             // First check if this gso wraps an image (SHAPE_COMPONENT_PICTURE
@@ -662,10 +1163,9 @@ impl HwpParser {
                                     &text_data,
                                     current_char_shape_mapping.as_ref(),
                                     &self.char_shapes,
+                                    self.color_spans,
                                 );
-                                if !text.trim().is_empty() {
-                                    blocks.push(text);
-                                }
+                                push_styled_block(&mut blocks, text, current_heading, current_list.as_deref(), current_quote);
                                 current_char_shape_mapping = None;
                             }
 
@@ -699,10 +1199,9 @@ impl HwpParser {
                                     &text_data,
                                     current_char_shape_mapping.as_ref(),
                                     &self.char_shapes,
+                                    self.color_spans,
                                 );
-                                if !text.trim().is_empty() {
-                                    blocks.push(text);
-                                }
+                                push_styled_block(&mut blocks, text, current_heading, current_list.as_deref(), current_quote);
                                 current_char_shape_mapping = None;
                             }
                             if let Some(script) = extract_subtree_equation_script(&records, i, 50) {
@@ -772,12 +1271,26 @@ impl HwpParser {
                                 &text_data,
                                 current_char_shape_mapping.as_ref(),
                                 &self.char_shapes,
+                                self.color_spans,
                             );
-                            if !text.trim().is_empty() {
-                                blocks.push(text);
-                            }
+                            push_styled_block(&mut blocks, text, current_heading, current_list.as_deref(), current_quote);
                             current_char_shape_mapping = None;
                         }
+                        // PARA_HEADER: nChars u32, controlMask u32,
+                        // paraShapeId u16, styleId u8 (offset 10)
+                        current_heading = record
+                            .data
+                            .get(10)
+                            .and_then(|id| self.paragraph_styles.get(&(*id as u32)))
+                            .and_then(|style| style.heading_level);
+                        let shape = record
+                            .data
+                            .get(8..10)
+                            .map(|id| u16::from_le_bytes([id[0], id[1]]) as u32)
+                            .and_then(|id| self.para_shapes.get(&id));
+                        current_quote = shape.is_some_and(|shape| shape.indent_left >= BLOCKQUOTE_INDENT);
+                        current_list = shape
+                            .and_then(|shape| list_prefix(shape, &self.numberings, &mut list_counters));
                     }
                 }
                 HWPTAG_TABLE => {
@@ -787,10 +1300,9 @@ impl HwpParser {
                             &text_data,
                             current_char_shape_mapping.as_ref(),
                             &self.char_shapes,
+                            self.color_spans,
                         );
-                        if !text.trim().is_empty() {
-                            blocks.push(text);
-                        }
+                        push_styled_block(&mut blocks, text, current_heading, current_list.as_deref(), current_quote);
                         current_char_shape_mapping = None;
                     }
 
@@ -883,10 +1395,9 @@ impl HwpParser {
                 &text_data,
                 current_char_shape_mapping.as_ref(),
                 &self.char_shapes,
+                self.color_spans,
             );
-            if !text.trim().is_empty() {
-                blocks.push(text);
-            }
+            push_styled_block(&mut blocks, text, current_heading, current_list.as_deref(), current_quote);
         }
 
         // Flush trailing table (common case: merged cells make rows*cols
@@ -903,38 +1414,264 @@ impl HwpParser {
         // is critical for downstream RAG splitters that key on heading levels.
         // We deliberately AVOID font-size-based detection since CHAR_SHAPE
         // styling in HWP files is unreliable.
-        let blocks = blocks
+        blocks
             .into_iter()
             .map(|b| promote_korean_heading(&b).unwrap_or(b))
-            .collect::<Vec<_>>();
+            .collect()
+    }
 
-        blocks.join("\n\n")
+    /// Find occurrences of `query` in the body text, section by section.
+    ///
+    /// Paragraphs are the blocks of the formatted text pipeline used by
+    /// [`extract_text`], and `byte_offset` indexes into that paragraph's
+    /// text. Sections are read lazily and reading stops as soon as
+    /// `max_results` matches are found. Unreadable sections are skipped
+    /// with a warning.
+    pub fn find_text(&mut self, query: &str, max_results: Option<usize>) -> io::Result<Vec<TextMatch>> {
+        let mut matches = Vec::new();
+        let limit = max_results.unwrap_or(usize::MAX);
+        if query.is_empty() || limit == 0 {
+            return Ok(matches);
+        }
+        if self.char_shapes.is_empty() {
+            let _ = self.parse_doc_info();
+        }
+
+        let section_count = if self.ole_reader.flags().distributed {
+            self.ole_reader.view_section_count()
+        } else {
+            self.ole_reader.section_count()
+        };
+        for section in 0..section_count {
+            let data = match self.read_section_records_data(section) {
+                Ok(data) => data,
+                Err(e) => {
+                    eprintln!("Warning: Could not read Section{}: {}", section, e);
+                    continue;
+                }
+            };
+            for (paragraph_index, text) in self.parse_section_blocks_formatted(&data).iter().enumerate() {
+                for (byte_offset, found) in text.match_indices(query) {
+                    matches.push(TextMatch {
+                        section,
+                        paragraph_index,
+                        byte_offset,
+                        context: match_context(text, byte_offset, found.len()),
+                    });
+                    if matches.len() >= limit {
+                        return Ok(matches);
+                    }
+                }
+            }
+        }
+        Ok(matches)
+    }
+
+    /// 각주/미주를 추출합니다.
+    ///
+    /// HWP 5.0 has no separate `Footnotes`/`Endnotes` streams — note bodies
+    /// live inline in `BodyText/Section{N}` as `fn  ` / `en  ` control
+    /// subtrees right after the anchoring text. This walks those subtrees
+    /// with the same `RecordParser` + `extract_para_text` pipeline as the
+    /// body and numbers notes sequentially (1-based) across the document.
+    /// `anchor_paragraph` counts top-level paragraphs from 0, document-wide.
+    pub fn extract_footnotes(&mut self) -> io::Result<Vec<NoteData>> {
+        let mut notes = Vec::new();
+        let mut paragraph_count: usize = 0;
+
+        for section_num in 0..self.ole_reader.section_count() {
+            let data = match self.read_body_section(section_num) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            let mut parser = self.record_parser(&data);
+            let records = parser.parse_all();
+
+            let mut i = 0usize;
+            while i < records.len() {
+                let record = &records[i];
+                if record.tag_id == HWPTAG_PARA_HEADER && record.level == 0 {
+                    paragraph_count += 1;
+                }
+                if record.tag_id == HWPTAG_CTRL_HEADER && record.data.len() >= 4 {
+                    let id = &record.data[0..4];
+                    let is_footnote = id == b"  nf" || id == b"fn  ";
+                    let is_endnote = id == b"  ne" || id == b"en  ";
+                    if is_footnote || is_endnote {
+                        if let Some(body) = extract_subtree_text(&records, i, 100, " ") {
+                            let body = body.trim().to_string();
+                            if !body.is_empty() {
+                                notes.push(NoteData {
+                                    number: notes.len() + 1,
+                                    anchor_paragraph: paragraph_count.saturating_sub(1),
+                                    body,
+                                    is_endnote,
+                                    marker: None,
+                                });
+                            }
+                        }
+                        i = subtree_end(&records, i, 100);
+                        continue;
+                    }
+                }
+                i += 1;
+            }
+        }
+
+        Ok(notes)
+    }
+
+    /// 수식을 추출합니다.
+    ///
+    /// Each `eqed` control's `HWPTAG_EQEDIT` script is returned in document
+    /// order together with its LaTeX conversion
+    /// ([`crate::equation::hulk_to_latex`]: `over` → `\frac`, `sum`/`int`
+    /// with `_{}`/`^{}` limits, `sqrt`, matrices, …). The body text already
+    /// carries the same equations inline as `$...$`; this is the
+    /// structured view. `index` is 0-based across the whole document.
+    pub fn extract_equations(&mut self) -> io::Result<Vec<EquationData>> {
+        let mut equations = Vec::new();
+
+        for section_num in 0..self.ole_reader.section_count() {
+            let data = match self.read_body_section(section_num) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            let mut parser = self.record_parser(&data);
+            collect_equations(&parser.parse_all(), &mut equations);
+        }
+
+        Ok(equations)
+    }
+
+    /// 하이퍼링크를 추출합니다.
+    ///
+    /// A hyperlink is a `%hlk` field: the target lives in the field's
+    /// `HWPTAG_CTRL_HEADER` as an escaped command string
+    /// (`http\://example.com;1;0;0;`), the display text is the run of
+    /// `HWPTAG_PARA_TEXT` between the field's begin and end control
+    /// characters. Links are returned in document order; `paragraph` counts
+    /// top-level paragraphs from 0, document-wide, like
+    /// [`NoteData::anchor_paragraph`].
+    pub fn extract_hyperlinks(&mut self) -> io::Result<Vec<HwpLink>> {
+        let mut links = Vec::new();
+        let mut paragraph_count: usize = 0;
+
+        for section_num in 0..self.ole_reader.section_count() {
+            let data = match self.read_body_section(section_num) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            let mut parser = self.record_parser(&data);
+            collect_hyperlinks(&parser.parse_all(), section_num, &mut paragraph_count, &mut links);
+        }
+
+        Ok(links)
+    }
+
+    /// 문서 이력을 추출합니다.
+    ///
+    /// Saved revisions from the `DocHistory` stream, oldest first (see
+    /// [`parse_doc_history`]). Documents saved without history have no
+    /// such stream and yield an empty list.
+    pub fn extract_revision_history(&mut self) -> io::Result<Vec<RevisionEntry>> {
+        match self.ole_reader.read_doc_history() {
+            Ok(data) => parse_doc_history(&data),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 차례 항목을 추출합니다.
+    ///
+    /// An auto-generated table of contents is a run of `toc ` controls, one
+    /// `HWPTAG_CTRL_HEADER` per linked heading (see [`parse_toc_ctrl`]).
+    /// Entries are returned in document order.
+    pub fn extract_toc(&mut self) -> io::Result<Vec<TocEntry>> {
+        let mut entries = Vec::new();
+        for section_num in 0..self.ole_reader.section_count() {
+            let data = match self.read_body_section(section_num) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            entries.extend(
+                self.record_parser(&data)
+                    .parse_all()
+                    .iter()
+                    .filter(|record| record.tag_id == HWPTAG_CTRL_HEADER)
+                    .filter_map(|record| parse_toc_ctrl(&record.data)),
+            );
+        }
+        Ok(entries)
     }
 
     /// 이미지를 추출합니다
+    ///
+    /// Only raster images ([`BinDataType::Image`]) are returned; OLE objects,
+    /// metafiles and media clips are available through
+    /// [`HwpParser::extract_all_bin_data`].
     pub fn extract_images(&mut self) -> io::Result<Vec<ImageData>> {
+        self.extract_images_with(false)
+    }
+
+    /// 모든 BinData 스트림을 추출합니다 (images, embedded OLE objects such as
+    /// Excel charts, EMF/WMF metafiles, sound and video clips, and streams of
+    /// unknown type), each tagged with its [`BinDataType`].
+    pub fn extract_all_bin_data(&mut self) -> io::Result<Vec<ImageData>> {
+        self.collect_bin_data(false, false)
+    }
+
+    /// 이미지를 추출하고, `deduplicate` 이면 중복 이미지를 표시합니다.
+    ///
+    /// Documents often embed the same logo or watermark in many BinData
+    /// streams. With `deduplicate`, every image whose fingerprint (64-bit
+    /// dHash + pixel size, or SHA-256 of the bytes when the image cannot be
+    /// decoded) was already seen gets `is_duplicate_of` set to the first
+    /// image's `name`. Nothing is dropped — see
+    /// [`HwpParser::extract_images_deduplicated`].
+    pub fn extract_images_with(&mut self, deduplicate: bool) -> io::Result<Vec<ImageData>> {
+        self.collect_bin_data(deduplicate, true)
+    }
+
+    fn collect_bin_data(&mut self, deduplicate: bool, images_only: bool) -> io::Result<Vec<ImageData>> {
         let mut images = Vec::new();
+        let mut seen: HashMap<ImageFingerprint, String> = HashMap::new();
         
         // Get list of BinData streams
         let bin_data_names = self.ole_reader.list_bin_data();
         
         for name in bin_data_names {
             if let Ok(data) = self.ole_reader.read_bin_data(&name) {
-                // Detect image format from magic bytes
-                let format = detect_image_format(&data);
-                if !format.is_empty() {
+                // Detect stream type from magic bytes
+                let bin_type = detect_bin_data_type(&data);
+                if !images_only || matches!(bin_type, BinDataType::Image(_)) {
+                    let format = bin_type.extension(&name);
                     // Generate proper filename
-                    let filename = if name.ends_with(&format!(".{}", format)) {
+                    let filename = if name.to_ascii_lowercase().ends_with(&format!(".{}", format)) {
                         name.clone()
                     } else {
                         format!("{}.{}", name, format)
                     };
                     
+                    let is_duplicate_of = if deduplicate {
+                        match seen.entry(image_fingerprint(&data)) {
+                            std::collections::hash_map::Entry::Occupied(first) => Some(first.get().clone()),
+                            std::collections::hash_map::Entry::Vacant(slot) => {
+                                slot.insert(filename.clone());
+                                None
+                            }
+                        }
+                    } else {
+                        None
+                    };
+
                     images.push(ImageData {
                         name: filename,
                         original_name: name,
                         format,
                         data,
+                        is_duplicate_of,
+                        bin_type,
                     });
                 }
             }
@@ -943,98 +1680,206 @@ impl HwpParser {
         Ok(images)
     }
 
+    /// 중복을 제거한 이미지 목록 (first occurrence of each image only)
+    pub fn extract_images_deduplicated(&mut self) -> io::Result<Vec<ImageData>> {
+        let mut images = self.extract_images_with(true)?;
+        images.retain(|image| image.is_duplicate_of.is_none());
+        Ok(images)
+    }
+
     /// 표 구조를 추출합니다
     pub fn extract_tables(&mut self) -> io::Result<Vec<TableData>> {
+        // Border fills (cell background colors) live in DocInfo
+        if self.char_shapes.is_empty() {
+            let _ = self.parse_doc_info();
+        }
+
         let mut tables = Vec::new();
         let section_count = self.ole_reader.section_count();
-        
+
         for section_num in 0..section_count {
-            if let Ok(data) = self.ole_reader.read_body_text(section_num) {
-                let mut parser = RecordParser::new(&data);
-                let records = parser.parse_all();
-                
-                // Find TABLE records and associated text
-                let mut current_table: Option<TableData> = None;
-                let mut current_cells: Vec<String> = Vec::new();
-                let mut current_cell_spans: Vec<CellSpan> = Vec::new();
-                let mut in_table = false;
-                let mut table_info: Option<(u16, u16)> = None;
-                let mut cell_index: usize = 0;
-
-                for record in &records {
-                    match record.tag_id {
-                        HWPTAG_TABLE => {
-                            // Finish previous table if any
+            if let Ok(data) = self.read_body_section(section_num) {
+                tables.extend(self.parse_section_tables(&data));
+            }
+        }
+
+        Ok(tables)
+    }
+
+    /// Collect the tables of one decompressed section's record stream.
+    fn parse_section_tables(&self, data: &[u8]) -> Vec<TableData> {
+        let mut tables = Vec::new();
+        let mut parser = self.record_parser(data);
+        let records = parser.parse_all();
+        
+        // Find TABLE records and associated text
+        let mut current_table: Option<TableData> = None;
+        let mut current_cells: Vec<String> = Vec::new();
+        let mut current_cell_spans: Vec<CellSpan> = Vec::new();
+        let mut current_cell_styles: Vec<TableCell> = Vec::new();
+        let mut in_table = false;
+        let mut table_info: Option<(u16, u16)> = None;
+        // Table-level fill, used for cells whose own borderFill has no color
+        let mut table_background: Option<u32> = None;
+        // Per cell: whether all of its text is bold (None = no char shapes)
+        let mut current_cell_bold: Vec<Option<bool>> = Vec::new();
+        let mut cell_index: usize = 0;
+
+        for record in &records {
+            match record.tag_id {
+                HWPTAG_TABLE => {
+                    // Finish previous table if any
+                    if let Some(mut table) = current_table.take() {
+                        table.cells = organize_cells(&current_cells, table.cols);
+                        table.cell_spans = current_cell_spans.clone();
+                        table.cell_styles = current_cell_styles.clone();
+                        table.has_header |= first_row_is_bold(&current_cell_bold, table.cols);
+                        tables.push(table);
+                        current_cells.clear();
+                        current_cell_spans.clear();
+                        current_cell_styles.clear();
+                    }
+                    current_cell_bold.clear();
+
+                    // Start new table
+                    if let Some(info) = parse_table_info(&record.data) {
+                        current_table = Some(TableData {
+                            rows: info.rows as usize,
+                            cols: info.cols as usize,
+                            cells: Vec::new(),
+                            cell_spans: Vec::new(),
+                            cell_styles: Vec::new(),
+                            has_header: info.has_header_row,
+                        });
+                        table_info = Some((info.rows, info.cols));
+                        table_background = info
+                            .border_fill_id
+                            .and_then(|id| self.border_fills.get(&id))
+                            .and_then(|fill| fill.background_color);
+                        in_table = true;
+                        cell_index = 0;
+                    }
+                }
+                HWPTAG_LIST_HEADER if in_table => {
+                    // Parse cell span information from LIST_HEADER
+                    if let Some((_rows, cols)) = table_info {
+                        // Calculate row/col from cell index
+                        let row = (cell_index / cols as usize) as u16;
+                        let col = (cell_index % cols as usize) as u16;
+
+                        // Resolve the cell's borderFillId to a solid background
+                        let background_color = parse_cell_border_fill_id(&record.data)
+                            .and_then(|id| self.border_fills.get(&id))
+                            .and_then(|fill| fill.background_color)
+                            .or(table_background);
+                        if background_color.is_some() {
+                            current_cell_styles.push(TableCell {
+                                row,
+                                col,
+                                background_color,
+                                ..Default::default()
+                            });
+                        }
+
+                        current_cell_bold.push(None);
+
+                        if let Some(mut span) = parse_cell_list_header(&record.data) {
+                            span.row = row;
+                            span.col = col;
+
+                            // Only store if there's actual spanning (row_span > 1 or col_span > 1)
+                            if span.row_span > 1 || span.col_span > 1 {
+                                current_cell_spans.push(span);
+                            }
+                        }
+                        cell_index += 1;
+                    }
+                }
+                HWPTAG_PARA_CHAR_SHAPE if in_table => {
+                    if let (Some(bold), Some(mapping)) =
+                        (current_cell_bold.last_mut(), parse_para_char_shape(&record.data))
+                    {
+                        let all_bold = mapping.mappings.iter().all(|(_, id)| {
+                            self.char_shapes.get(id).is_some_and(|shape| shape.bold)
+                        });
+                        *bold = Some(bold.unwrap_or(true) && all_bold);
+                    }
+                }
+                HWPTAG_PARA_TEXT if in_table => {
+                    let text = extract_para_text(&record.data);
+                    current_cells.push(text);
+
+                    // Check if we've collected all cells
+                    if let Some((rows, cols)) = table_info {
+                        if current_cells.len() >= (rows * cols) as usize {
                             if let Some(mut table) = current_table.take() {
                                 table.cells = organize_cells(&current_cells, table.cols);
                                 table.cell_spans = current_cell_spans.clone();
+                                table.cell_styles = current_cell_styles.clone();
+                                table.has_header |= first_row_is_bold(&current_cell_bold, table.cols);
                                 tables.push(table);
                                 current_cells.clear();
                                 current_cell_spans.clear();
-                            }
-
-                            // Start new table
-                            if let Some(info) = parse_table_info(&record.data) {
-                                current_table = Some(TableData {
-                                    rows: info.rows as usize,
-                                    cols: info.cols as usize,
-                                    cells: Vec::new(),
-                                    cell_spans: Vec::new(),
-                                });
-                                table_info = Some((info.rows, info.cols));
-                                in_table = true;
-                                cell_index = 0;
+                                current_cell_styles.clear();
+                                in_table = false;
+                                table_info = None;
                             }
                         }
-                        HWPTAG_LIST_HEADER if in_table => {
-                            // Parse cell span information from LIST_HEADER
-                            if let Some((_rows, cols)) = table_info {
-                                if let Some(mut span) = parse_cell_list_header(&record.data) {
-                                    // Calculate row/col from cell index
-                                    span.row = (cell_index / cols as usize) as u16;
-                                    span.col = (cell_index % cols as usize) as u16;
-
-                                    // Only store if there's actual spanning (row_span > 1 or col_span > 1)
-                                    if span.row_span > 1 || span.col_span > 1 {
-                                        current_cell_spans.push(span);
-                                    }
-                                }
-                                cell_index += 1;
-                            }
-                        }
-                        HWPTAG_PARA_TEXT if in_table => {
-                            let text = extract_para_text(&record.data);
-                            current_cells.push(text);
-
-                            // Check if we've collected all cells
-                            if let Some((rows, cols)) = table_info {
-                                if current_cells.len() >= (rows * cols) as usize {
-                                    if let Some(mut table) = current_table.take() {
-                                        table.cells = organize_cells(&current_cells, table.cols);
-                                        table.cell_spans = current_cell_spans.clone();
-                                        tables.push(table);
-                                        current_cells.clear();
-                                        current_cell_spans.clear();
-                                        in_table = false;
-                                        table_info = None;
-                                    }
-                                }
-                            }
+                    }
+                }
+                _ => {}
+            }
+        }
+        
+        // Handle last table
+        if let Some(mut table) = current_table.take() {
+            table.cells = organize_cells(&current_cells, table.cols);
+            table.cell_spans = current_cell_spans;
+            table.cell_styles = current_cell_styles;
+            table.has_header |= first_row_is_bold(&current_cell_bold, table.cols);
+            tables.push(table);
+        }
+
+        tables
+    }
+
+    /// Collect drawing objects (rectangle / ellipse / line / polygon) in
+    /// reading order.
+    ///
+    /// Walks `gso` controls with the same precedence as
+    /// [`parse_section_records_to_blocks`] — pictures and non-empty text
+    /// boxes are not shapes — so the N-th shape here matches the N-th
+    /// shape placeholder in the section's blocks.
+    fn parse_section_shapes(&self, data: &[u8]) -> Vec<ShapeComponent> {
+        let mut parser = self.record_parser(data);
+        let records = parser.parse_all();
+
+        let mut shapes = Vec::new();
+        let mut i = 0usize;
+        while i < records.len() {
+            let record = &records[i];
+            if record.tag_id == HWPTAG_CTRL_HEADER && record.data.len() >= 4 {
+                let id = &record.data[0..4];
+                if id == b" osg" || id == b"gso " {
+                    let has_text = extract_subtree_text(&records, i, 200, "\n")
+                        .is_some_and(|t| !t.trim().is_empty());
+                    if extract_subtree_image_id(&records, i, 200).is_none() && !has_text {
+                        if let Some(shape) = extract_subtree_shape(&records, i, 200) {
+                            shapes.push(shape);
                         }
-                        _ => {}
                     }
+                    i = subtree_end(&records, i, 200);
+                    continue;
                 }
-                
-                // Handle last table
-                if let Some(mut table) = current_table.take() {
-                    table.cells = organize_cells(&current_cells, table.cols);
-                    table.cell_spans = current_cell_spans;
-                    tables.push(table);
+                // Notes are skipped whole by the block walker
+                if id == b"  nf" || id == b"fn  " || id == b"  ne" || id == b"en  " {
+                    i = subtree_end(&records, i, 100);
+                    continue;
                 }
             }
+            i += 1;
         }
-        
-        Ok(tables)
+        shapes
     }
 
     /// 메타데이터를 추출합니다
@@ -1087,25 +1932,176 @@ impl HwpParser {
     // away from `to_*` would be a public API break, so silence the convention lint.
     #[allow(clippy::wrong_self_convention)]
     pub fn to_mdm(&mut self) -> io::Result<MdmDocument> {
-        let content = match self.extract_blocks() {
-            Ok(blocks) if !blocks.is_empty() => {
-                crate::ir::blocks_to_markdown(&blocks)
+        if !self.config_errors.is_empty() {
+            let messages: Vec<String> = self.config_errors.iter().map(ToString::to_string).collect();
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid config: {}", messages.join("; ")),
+            ));
+        }
+        let cache = match (&self.cache, self.cache_enabled) {
+            (Some(cache), true) => Some(Arc::clone(cache)),
+            _ => None,
+        };
+        let Some(cache) = cache else {
+            return self.convert_to_mdm();
+        };
+
+        let key = self.source_hash()?;
+        if let Some(doc) = cache.get(&key) {
+            return Ok(doc);
+        }
+        let doc = self.convert_to_mdm()?;
+        if let Err(e) = cache.insert(&key, &doc) {
+            eprintln!("Warning: Could not write cache entry: {}", e);
+        }
+        Ok(doc)
+    }
+
+    /// Uncached body of [`HwpParser::to_mdm`]
+    fn convert_to_mdm(&mut self) -> io::Result<MdmDocument> {
+        let mut blocks: Vec<IRBlock> = Vec::new();
+        let mut tables: Vec<TableData> = Vec::new();
+        let mut shapes: Vec<ShapeComponent> = Vec::new();
+        for section in self.stream_sections() {
+            match section {
+                Ok(section) => {
+                    blocks.extend(section.paragraphs);
+                    tables.extend(section.tables);
+                    shapes.extend(section.shapes);
+                }
+                Err(e) => eprintln!("Warning: Could not read {}", e),
             }
-            _ => self.extract_text()?,
+        }
+        infer_headings_by_font_size(&mut blocks, &self.char_shapes);
+
+        let (content, note_ranges) = if blocks.is_empty() {
+            (self.extract_text()?, Vec::new())
+        } else {
+            crate::ir::blocks_to_markdown_with_notes(&blocks)
         };
         let images = self.extract_images()?;
-        let tables = self.extract_tables()?;
+        let mut footnotes = self.extract_footnotes()?;
+        place_note_markers(&mut footnotes, &blocks, note_ranges);
+        let equations = self.extract_equations()?;
+        let hyperlinks = self.extract_hyperlinks()?;
+        let toc = self.extract_toc()?;
+        // A damaged history stream should not fail the whole conversion
+        let revision_history = self.extract_revision_history().unwrap_or_else(|e| {
+            eprintln!("Warning: Could not read DocHistory: {}", e);
+            Vec::new()
+        });
         let metadata = self.extract_metadata()?;
 
         Ok(MdmDocument {
             content,
             images,
             tables,
+            footnotes,
             metadata,
+            shapes,
+            equations,
+            hyperlinks,
+            toc,
+            revision_history,
         })
     }
 }
 
+/// [`HwpParser`] that keeps decompressed body sections in a shared
+/// [`SectionCache`].
+///
+/// Inflating `BodyText/Section{N}` dominates repeated conversions of large
+/// files; with this wrapper only the first pass over an unchanged file pays
+/// for it. Derefs to [`HwpParser`], so every parser method is available and
+/// transparently uses the cache. Distribution-locked (`ViewText`) sections
+/// are decrypted per call and not cached.
+pub struct CachedHwpParser {
+    inner: HwpParser,
+}
+
+impl CachedHwpParser {
+    /// Open `path`, keying its sections by canonical path and current mtime
+    pub fn open<P: AsRef<Path>>(path: P, cache: Arc<SectionCache>) -> io::Result<Self> {
+        let path = std::fs::canonicalize(path)?;
+        let mtime = std::fs::metadata(&path)?.modified()?;
+        let mut inner = HwpParser::open(&path)?;
+        inner.section_cache = Some(SectionSource { cache, path, mtime });
+        Ok(Self { inner })
+    }
+
+    /// Key under which section `index` of this file is cached
+    pub fn section_key(&self, index: usize) -> Option<SectionKey> {
+        let source = self.inner.section_cache.as_ref()?;
+        Some((source.path.clone(), index, source.mtime))
+    }
+
+    /// Unwrap the parser; it keeps using the cache
+    pub fn into_inner(self) -> HwpParser {
+        self.inner
+    }
+}
+
+impl Deref for CachedHwpParser {
+    type Target = HwpParser;
+
+    fn deref(&self) -> &HwpParser {
+        &self.inner
+    }
+}
+
+impl DerefMut for CachedHwpParser {
+    fn deref_mut(&mut self) -> &mut HwpParser {
+        &mut self.inner
+    }
+}
+
+/// Record where each note's inline marker landed in the rendered content.
+///
+/// The block walk and [`HwpParser::extract_footnotes`] visit notes in the
+/// same document order, but the block walk can miss some (inside table
+/// cells, for instance), so each inline note claims the next unplaced note
+/// of the same kind and body.
+fn place_note_markers(footnotes: &mut [NoteData], blocks: &[IRBlock], ranges: Vec<std::ops::Range<usize>>) {
+    let inline = blocks.iter().flat_map(|block| match block {
+        IRBlock::Paragraph { notes, .. } => notes.as_slice(),
+        _ => &[][..],
+    });
+    let mut next = 0;
+    for (note, range) in inline.zip(ranges) {
+        let claimed = footnotes[next..]
+            .iter()
+            .position(|n| n.is_endnote == note.is_endnote && n.body == note.body);
+        if let Some(offset) = claimed {
+            next += offset;
+            footnotes[next].marker = Some(range);
+            next += 1;
+        }
+    }
+}
+
+/// Streams whose bytes [`HwpParser::source_hash`] reads: document header,
+/// DocInfo, summary information and body text sections. Matches on the last
+/// path component, since the lenient CFB reader drops storage prefixes.
+fn is_content_stream(name: &str) -> bool {
+    let leaf = name.rsplit('/').next().unwrap_or(name);
+    matches!(leaf, "FileHeader" | "DocInfo" | "\u{0005}HwpSummaryInformation") || leaf.starts_with("Section")
+}
+
+/// GitHub-style heading anchor: lowercase, spaces to `-`, punctuation
+/// dropped (Hangul and other letters kept)
+fn heading_anchor(text: &str) -> String {
+    text.trim()
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
 /// Push a paragraph onto the IR block list, promoting to
 /// `IRBlock::Heading` when [`promote_korean_heading_level`] matches.
 /// Empty / whitespace-only text is dropped.
@@ -1365,6 +2361,76 @@ fn build_ir_blocks_from_cells(
     vec![IRBlock::Table(IRTable::new(ir_cells))]
 }
 
+/// List marker prefix for a paragraph with a numbering or bullet head:
+/// two spaces per list level, then `- ` for bullets or the numbering
+/// definition's marker (`1.`, `가.`, `①`, ...) and a space.
+///
+/// Numbering advances the counter of the paragraph's level in `counters`
+/// and resets the deeper levels. Returns `None` for other paragraphs and
+/// for numbering IDs without a definition.
+fn list_prefix(
+    shape: &ParaShapeInfo,
+    numberings: &HashMap<u16, NumberingDef>,
+    counters: &mut HashMap<u16, [u32; LIST_LEVELS]>,
+) -> Option<String> {
+    let level = (shape.para_level as usize).min(LIST_LEVELS - 1);
+    let indent = "  ".repeat(level);
+    match shape.head_shape {
+        3 => Some(format!("{}- ", indent)),
+        2 => {
+            let def = numberings.get(&shape.numbering_id)?;
+            let counts = counters.entry(shape.numbering_id).or_insert([0; LIST_LEVELS]);
+            counts[level] += 1;
+            counts[level + 1..].iter_mut().for_each(|c| *c = 0);
+            let start = u32::from(def.start);
+            let numbers: Vec<u32> = counts.iter().map(|&c| start + c.saturating_sub(1)).collect();
+            Some(format!("{}{} ", indent, def.marker(level, &numbers)?))
+        }
+        _ => None,
+    }
+}
+
+/// Push a formatted paragraph, prefixing `#` markers when its paragraph
+/// style is a heading or the list marker of a numbered / bulleted
+/// paragraph. Headings are collapsed to one line and lose the outer `**`
+/// that heading char shapes usually add. Empty text is dropped.
+fn push_styled_block(
+    blocks: &mut Vec<String>,
+    text: String,
+    heading: Option<u8>,
+    list: Option<&str>,
+    quote: bool,
+) {
+    if text.trim().is_empty() {
+        return;
+    }
+    match heading {
+        Some(level) if !text.starts_with('|') => {
+            let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            let line = line
+                .strip_prefix("**")
+                .and_then(|l| l.strip_suffix("**"))
+                .filter(|l| !l.contains("**"))
+                .unwrap_or(&line)
+                .to_string();
+            blocks.push(format!("{} {}", "#".repeat(level.clamp(1, 6) as usize), line));
+        }
+        _ if list.is_some() && !text.starts_with('|') => {
+            blocks.push(format!("{}{}", list.unwrap_or_default(), text.trim()));
+        }
+        _ if quote && !text.starts_with('|') => {
+            let quoted = text
+                .trim_end()
+                .lines()
+                .map(|line| if line.is_empty() { ">".to_string() } else { format!("> {}", line) })
+                .collect::<Vec<_>>()
+                .join("\n");
+            blocks.push(quoted);
+        }
+        _ => blocks.push(text),
+    }
+}
+
 /// LEGACY: kept only as a reference implementation. All HWP heading
 /// promotion now goes through [`promote_korean_heading_level`] +
 /// [`push_paragraph`]. Marked dead_code to suppress the warning without
@@ -1774,6 +2840,144 @@ fn extract_subtree_equation_script(records: &[HwpRecord], ctrl_idx: usize, max_l
     None
 }
 
+/// Append every equation control's script in `records` to `out`, numbering
+/// on from `out.len()`
+fn collect_equations(records: &[HwpRecord], out: &mut Vec<EquationData>) {
+    let mut i = 0usize;
+    while i < records.len() {
+        let record = &records[i];
+        if record.tag_id == HWPTAG_CTRL_HEADER && record.data.len() >= 4 {
+            let id = &record.data[0..4];
+            if id == b"eqed" || id == b"deqe" {
+                if let Some(script) = extract_subtree_equation_script(records, i, 50) {
+                    out.push(EquationData {
+                        index: out.len(),
+                        latex: crate::equation::hulk_to_latex(&script),
+                        hwp_source: script,
+                    });
+                }
+                i = subtree_end(records, i, 50);
+                continue;
+            }
+        }
+        i += 1;
+    }
+}
+
+fn is_hyperlink_ctrl_id(id: &[u8]) -> bool {
+    id == b"%hlk" || id == b"klh%"
+}
+
+/// Append every hyperlink field in `records` (one body section) to `out`.
+///
+/// Display texts are queued per record level as PARA_TEXT records are seen
+/// and consumed in order by the `%hlk` CTRL_HEADERs of the same paragraph,
+/// which sit at the PARA_TEXT's level. A link whose text cannot be found
+/// falls back to its URL.
+fn collect_hyperlinks(
+    records: &[HwpRecord],
+    section: usize,
+    paragraph_count: &mut usize,
+    out: &mut Vec<HwpLink>,
+) {
+    let mut pending: HashMap<u16, VecDeque<String>> = HashMap::new();
+    for record in records {
+        if record.tag_id == HWPTAG_PARA_HEADER {
+            if record.level == 0 {
+                *paragraph_count += 1;
+            }
+            pending.remove(&(record.level + 1));
+        } else if record.tag_id == HWPTAG_PARA_TEXT {
+            pending.insert(record.level, hyperlink_display_texts(&record.data).into());
+        } else if record.tag_id == HWPTAG_CTRL_HEADER
+            && record.data.len() >= 4
+            && is_hyperlink_ctrl_id(&record.data[0..4])
+        {
+            let display = pending.get_mut(&record.level).and_then(|texts| texts.pop_front());
+            if let Some(url) = decode_field_url(&record.data) {
+                out.push(HwpLink {
+                    display_text: display.filter(|t| !t.is_empty()).unwrap_or_else(|| url.clone()),
+                    url,
+                    section,
+                    paragraph: paragraph_count.saturating_sub(1),
+                });
+            }
+        }
+    }
+}
+
+/// Texts enclosed by hyperlink fields in a PARA_TEXT record, in order.
+///
+/// The field opens with an extended control whose payload starts with the
+/// `%hlk` ctrl id (0x03 in HWP 5.0 files, [`CHAR_FIELD_START`] in some
+/// writers) and closes with the next 0x04 / [`CHAR_FIELD_END`] control.
+/// An unterminated field runs to the end of the record.
+fn hyperlink_display_texts(data: &[u8]) -> Vec<String> {
+    let units: Vec<u16> = data.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+    let mut texts = Vec::new();
+    let mut current: Option<Vec<u16>> = None;
+
+    let mut i = 0usize;
+    while i < units.len() {
+        let ch = units[i];
+        if EXTENDED_CTRL_CHARS.contains(&ch) {
+            let id: Vec<u8> = units
+                .iter()
+                .skip(i + 1)
+                .take(2)
+                .flat_map(|u| u.to_le_bytes())
+                .collect();
+            if current.is_some() && (ch == CHAR_FIELD_START || ch == CHAR_FIELD_END) {
+                if let Some(run) = current.take() {
+                    texts.push(String::from_utf16_lossy(&run).trim().to_string());
+                }
+            } else if is_hyperlink_ctrl_id(&id) {
+                if let Some(run) = current.replace(Vec::new()) {
+                    texts.push(String::from_utf16_lossy(&run).trim().to_string());
+                }
+            }
+            i += 8;
+            continue;
+        }
+        if let Some(run) = current.as_mut() {
+            if ch >= 0x20 {
+                run.push(ch);
+            } else if ch == 0x09 || ch == 0x0A {
+                run.push(0x20);
+            }
+        }
+        i += 1;
+    }
+    if let Some(run) = current {
+        texts.push(String::from_utf16_lossy(&run).trim().to_string());
+    }
+    texts
+}
+
+/// URL of a hyperlink field from its CTRL_HEADER payload.
+///
+/// Layout: ctrl id (4), properties (4), extra properties (1), command
+/// length in WCHARs (2), UTF-16LE command. The URL is the command up to
+/// its first unescaped `;` with `\:`, `\;` and `\\` unescaped.
+fn decode_field_url(data: &[u8]) -> Option<String> {
+    let len = u16::from_le_bytes([*data.get(9)?, *data.get(10)?]) as usize;
+    let bytes = data.get(11..11 + len * 2)?;
+    let units: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+    let command = String::from_utf16_lossy(&units);
+
+    let mut url = String::new();
+    let mut chars = command.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => url.extend(chars.next()),
+            ';' => break,
+            _ => url.push(c),
+        }
+    }
+    let url = url.trim().to_string();
+    if url.is_empty() { None } else { Some(url) }
+}
+
 fn extract_subtree_image_id(records: &[HwpRecord], ctrl_idx: usize, max_lookahead: usize) -> Option<u16> {
     if ctrl_idx >= records.len() {
         return None;
@@ -1818,6 +3022,46 @@ fn subtree_end(records: &[HwpRecord], ctrl_idx: usize, max_lookahead: usize) ->
     last + 1
 }
 
+/// Find a drawable shape inside a `gso` control subtree.
+///
+/// Geometry comes from the generic SHAPE_COMPONENT record, the shape kind
+/// (and line / polygon vertices) from the type-specific child record.
+/// Returns `None` for pictures, OLE objects and containers.
+fn extract_subtree_shape(records: &[HwpRecord], ctrl_idx: usize, max_lookahead: usize) -> Option<ShapeComponent> {
+    if ctrl_idx >= records.len() {
+        return None;
+    }
+    let ctrl_level = records[ctrl_idx].level;
+    let end = (ctrl_idx + max_lookahead + 1).min(records.len());
+
+    let mut base: Option<ShapeComponent> = None;
+    for r in records.iter().take(end).skip(ctrl_idx + 1) {
+        if r.level <= ctrl_level {
+            break;
+        }
+        match r.tag_id {
+            HWPTAG_SHAPE_COMPONENT => {
+                base = parse_shape_component(&r.data, ShapeType::Unknown(r.tag_id));
+            }
+            HWPTAG_SHAPE_COMPONENT_LINE
+            | HWPTAG_SHAPE_COMPONENT_RECTANGLE
+            | HWPTAG_SHAPE_COMPONENT_ELLIPSE
+            | HWPTAG_SHAPE_COMPONENT_POLYGON => {
+                let mut shape = base.take().unwrap_or_default();
+                shape.shape_type = ShapeType::from(r.tag_id);
+                shape.points = match r.tag_id {
+                    HWPTAG_SHAPE_COMPONENT_LINE => parse_line_points(&r.data),
+                    HWPTAG_SHAPE_COMPONENT_POLYGON => parse_polygon_points(&r.data),
+                    _ => Vec::new(),
+                };
+                return Some(shape);
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
 /// Extract a hyperlink URL from a CTRL_HEADER (klnk / %tok) record.
 ///
 /// HWP stores the link target as a UTF-16LE string somewhere inside the record
@@ -2217,43 +3461,81 @@ fn render_merged_html(
     Some(out)
 }
 
-/// Minimal HTML escaper for cell text (`&`, `<`, `>`).
-fn html_escape(s: &str) -> String {
-    let mut out = String::with_capacity(s.len());
-    for ch in s.chars() {
-        match ch {
-            '&' => out.push_str("&amp;"),
-            '<' => out.push_str("&lt;"),
-            '>' => out.push_str("&gt;"),
-            _ => out.push(ch),
+
+/// BinData 스트림 종류
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum BinDataType {
+    /// Raster image; holds the file extension (`"png"`, `"jpeg"`, …)
+    Image(String),
+    /// OLE2 compound file — embedded Excel charts, equations, other documents
+    Ole,
+    /// Enhanced Metafile
+    Emf,
+    /// Windows Metafile (placeable or standard)
+    Wmf,
+    /// WAV / MP3 / MIDI clip
+    Sound,
+    /// AVI / MP4 / MPEG / ASF clip
+    Video,
+    #[default]
+    Unknown,
+}
+
+impl BinDataType {
+    /// File extension for a stream of this type. Sound, video and unknown
+    /// streams keep the extension of their BinData `name` (`BIN0003.wav`),
+    /// falling back to `bin`.
+    pub fn extension(&self, name: &str) -> String {
+        match self {
+            BinDataType::Image(ext) => ext.clone(),
+            BinDataType::Ole => "ole".to_string(),
+            BinDataType::Emf => "emf".to_string(),
+            BinDataType::Wmf => "wmf".to_string(),
+            BinDataType::Sound | BinDataType::Video | BinDataType::Unknown => name
+                .rsplit_once('.')
+                .map(|(_, ext)| ext.to_ascii_lowercase())
+                .filter(|ext| !ext.is_empty())
+                .unwrap_or_else(|| "bin".to_string()),
         }
     }
-    out
 }
 
-/// 이미지 포맷 감지
-fn detect_image_format(data: &[u8]) -> String {
+/// BinData 스트림 종류 감지 (magic bytes)
+fn detect_bin_data_type(data: &[u8]) -> BinDataType {
     if data.len() < 8 {
-        return String::new();
+        return BinDataType::Unknown;
     }
-    
-    // Check magic bytes
+    let riff_form = if &data[0..4] == b"RIFF" && data.len() >= 12 { Some(&data[8..12]) } else { None };
+
     if data[0] == 0xFF && data[1] == 0xD8 && data[2] == 0xFF {
-        "jpeg".to_string()
+        BinDataType::Image("jpeg".to_string())
     } else if data[0] == 0x89 && data[1] == 0x50 && data[2] == 0x4E && data[3] == 0x47 {
-        "png".to_string()
+        BinDataType::Image("png".to_string())
     } else if data[0] == 0x47 && data[1] == 0x49 && data[2] == 0x46 {
-        "gif".to_string()
+        BinDataType::Image("gif".to_string())
     } else if data[0] == 0x42 && data[1] == 0x4D {
-        "bmp".to_string()
+        BinDataType::Image("bmp".to_string())
+    } else if riff_form == Some(b"WEBP") {
+        BinDataType::Image("webp".to_string())
+    } else if data.starts_with(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]) {
+        BinDataType::Ole
     } else if data[0] == 0xD7 && data[1] == 0xCD && data[2] == 0xC6 && data[3] == 0x9A {
-        "wmf".to_string()
-    } else if data[0] == 0x01 && data[1] == 0x00 && data[2] == 0x00 && data[3] == 0x00 {
-        "emf".to_string()
-    } else if &data[0..4] == b"RIFF" && data.len() >= 12 && &data[8..12] == b"WEBP" {
-        "webp".to_string()
+        BinDataType::Wmf
+    } else if data[0] == 0x01 && data[1] == 0x00 && data[2] == 0x00 && data[3] == 0x00
+        // EMR_HEADER carries the " EMF" signature at offset 40
+        && (data.len() < 44 || &data[40..44] == b" EMF")
+    {
+        BinDataType::Emf
+    } else if riff_form == Some(b"WAVE") || data.starts_with(b"ID3") || data.starts_with(b"MThd") {
+        BinDataType::Sound
+    } else if riff_form == Some(b"AVI ")
+        || &data[4..8] == b"ftyp"
+        || data.starts_with(&[0x00, 0x00, 0x01, 0xBA])
+        || data.starts_with(&[0x30, 0x26, 0xB2, 0x75])
+    {
+        BinDataType::Video
     } else {
-        String::new()
+        BinDataType::Unknown
     }
 }
 
@@ -2271,6 +3553,16 @@ fn parse_version(data: &[u8]) -> String {
     }
 }
 
+/// Lines per page when a section has no usable PAGE_DEF (A4, default margins)
+pub const DEFAULT_LINES_PER_PAGE: u32 = 40;
+
+/// Page count from line count: `text` lines divided by `lines_per_page`,
+/// rounded up. Empty text still occupies one page.
+pub fn estimate_page_count(text: &str, lines_per_page: u32) -> u32 {
+    let lines = text.lines().count() as u32;
+    lines.div_ceil(lines_per_page.max(1)).max(1)
+}
+
 /// HWP 파일 구조 정보
 #[derive(Debug)]
 pub struct FileStructure {
@@ -2280,15 +3572,94 @@ pub struct FileStructure {
     pub bin_data_count: usize,
     pub compressed: bool,
     pub encrypted: bool,
+    /// Estimated pages; [`HwpParser::analyze`] leaves this unset, fill it
+    /// from [`HwpParser::page_count`]
+    pub page_count: Option<u32>,
+    /// Page setup per section; [`HwpParser::analyze`] leaves this empty,
+    /// fill it from [`HwpParser::page_definitions`]
+    pub page_definitions: Vec<PageDef>,
+}
+
+/// DocInfo 서식 통계 ([`HwpParser::extract_styles_summary`])
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StylesSummary {
+    /// 서로 다른 글꼴 수
+    pub font_count: usize,
+    /// 글꼴 이름, FACE_NAME 레코드 순서대로 중복 제거
+    pub font_names: Vec<String>,
+    /// CHAR_SHAPE 레코드 수
+    pub char_shape_count: usize,
+    /// 굵게가 설정된 글자 모양 수
+    pub bold_count: usize,
+    /// 기울임이 설정된 글자 모양 수
+    pub italic_count: usize,
+    /// 밑줄이 설정된 글자 모양 수
+    pub underline_count: usize,
+    /// 서로 다른 굵게/기울임/밑줄 조합 수
+    pub emphasis_combinations: usize,
+    /// 이름이 서로 다른 문단 스타일 수 (글자 스타일 제외)
+    pub paragraph_style_count: usize,
 }
 
 /// 이미지 데이터
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ImageData {
     pub name: String,
     pub original_name: String,
     pub format: String,
     pub data: Vec<u8>,
+    /// `name` of the first identical image, set by
+    /// [`HwpParser::extract_images_with`] when deduplicating
+    #[serde(default)]
+    pub is_duplicate_of: Option<String>,
+    /// Stream type sniffed from the data; always `Image` for
+    /// [`HwpParser::extract_images`]
+    #[serde(default)]
+    pub bin_type: BinDataType,
+}
+
+/// Identity used to spot repeated BinData images
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum ImageFingerprint {
+    /// dHash plus pixel size of a decodable image
+    Perceptual { hash: u64, width: u32, height: u32 },
+    /// SHA-256 of the raw bytes (WMF/EMF, flat images, or builds without
+    /// the `image-processing` feature)
+    Exact([u8; 32]),
+}
+
+fn image_fingerprint(data: &[u8]) -> ImageFingerprint {
+    #[cfg(feature = "image-processing")]
+    if let Some((hash, width, height)) = difference_hash(data) {
+        // Every flat image hashes to 0 — a white box is not a black box
+        if hash != 0 {
+            return ImageFingerprint::Perceptual { hash, width, height };
+        }
+    }
+    ImageFingerprint::Exact(Sha256::digest(data).into())
+}
+
+/// 64-bit difference hash: shrink to 9x8 grayscale and set one bit per
+/// pixel that is brighter than its right neighbour. Returns the hash and
+/// the original pixel size.
+#[cfg(feature = "image-processing")]
+fn difference_hash(data: &[u8]) -> Option<(u64, u32, u32)> {
+    use image::GenericImageView;
+
+    let img = image::load_from_memory(data).ok()?;
+    let (width, height) = img.dimensions();
+    let small = img
+        .grayscale()
+        .resize_exact(9, 8, image::imageops::FilterType::Triangle)
+        .to_luma8();
+    let mut hash = 0u64;
+    for y in 0..8 {
+        for x in 0..8 {
+            let brighter = small.get_pixel(x, y)[0] > small.get_pixel(x + 1, y)[0];
+            hash = (hash << 1) | brighter as u64;
+        }
+    }
+    Some((hash, width, height))
 }
 
 /// 표 데이터
@@ -2299,6 +3670,19 @@ pub struct TableData {
     pub cells: Vec<Vec<String>>,
     /// Cell span information for merged cells
     pub cell_spans: Vec<CellSpan>,
+    /// Per-cell styling (background color) for cells that carry any.
+    /// Sparse like `cell_spans` — unstyled cells are omitted.
+    #[serde(default)]
+    pub cell_styles: Vec<TableCell>,
+    /// Row 0 is a header: the TABLE header flag is set, or every cell of
+    /// row 0 is bold while a later row is not. Controls the `---` row.
+    #[serde(default = "default_has_header")]
+    pub has_header: bool,
+}
+
+/// Tables serialized before header detection rendered row 0 as a header
+fn default_has_header() -> bool {
+    true
 }
 
 impl TableData {
@@ -2308,6 +3692,7 @@ impl TableData {
     /// - 1-column tables (layout wrappers, not data) are unwrapped to plain
     ///   paragraphs. This matches kordoc's `flattenLayoutTables` behavior and
     ///   prevents ugly `| title |` artifacts in the output stream.
+    /// - The `---` separator row is emitted only when `has_header` is set.
     /// - The header separator width always matches the actual max row width,
     ///   not `self.cols`, to keep GFM well-formed when row lengths differ.
     /// - Newlines inside cells become `<br>` for true GFM rendering instead of
@@ -2354,7 +3739,7 @@ impl TableData {
             md.push('\n');
 
             // Header separator after first row
-            if i == 0 {
+            if i == 0 && self.has_header {
                 md.push('|');
                 for _ in 0..actual_cols {
                     md.push_str(" --- |");
@@ -2365,10 +3750,89 @@ impl TableData {
 
         md
     }
+
+    /// True when any cell has a background other than white (see
+    /// [`HwpTable::has_cell_colors`])
+    pub fn has_cell_colors(&self) -> bool {
+        self.cell_styles.iter().any(|c| c.visible_background().is_some())
+    }
+
+    /// Whether every whitespace-separated word of every cell occurs in
+    /// `rendered`. Words with Markdown/HTML-escaped characters are skipped.
+    fn words_appear_in(&self, rendered: &str) -> bool {
+        self.cells
+            .iter()
+            .flatten()
+            .flat_map(|cell| cell.split_whitespace())
+            .filter(|word| !word.contains(['|', '<', '>', '&', '\\']))
+            .all(|word| rendered.contains(word))
+    }
+
+    /// Convert table to Markdown honoring engine [`crate::Config`] options.
+    ///
+    /// With `preserve_table_colors` set and at least one colored cell, the
+    /// table is emitted via [`TableData::to_html`] so colored cells can carry
+    /// `<td style="background-color:#rrggbb">` — GFM pipe tables have no
+    /// per-cell styling. Otherwise identical to [`TableData::to_markdown`].
+    /// [`HwpTable::to_markdown_with_config`] makes the same choice.
+    pub fn to_markdown_with_config(&self, config: &crate::Config) -> String {
+        if !config.preserve_table_colors || !self.has_cell_colors() || self.cells.is_empty() {
+            return self.to_markdown();
+        }
+        self.to_html()
+    }
+
+    /// Rebuild the cell grid as an [`HwpTable`] with spans and backgrounds
+    pub fn to_hwp_table(&self) -> HwpTable {
+        let cols = self.cells.iter().map(Vec::len).max().unwrap_or(0).max(self.cols);
+        let mut table = HwpTable::new(self.cells.len() as u16, cols as u16);
+        table.info.has_header_row = self.has_header;
+        for (r, row) in self.cells.iter().enumerate() {
+            for (c, text) in row.iter().enumerate() {
+                table.cells[r][c].content = text.clone();
+            }
+        }
+        for span in &self.cell_spans {
+            if let Some(cell) = table
+                .cells
+                .get_mut(span.row as usize)
+                .and_then(|row| row.get_mut(span.col as usize))
+            {
+                cell.row_span = span.row_span.max(1);
+                cell.col_span = span.col_span.max(1);
+            }
+        }
+        for style in &self.cell_styles {
+            if let Some(cell) = table
+                .cells
+                .get_mut(style.row as usize)
+                .and_then(|row| row.get_mut(style.col as usize))
+            {
+                cell.background_color = style.background_color;
+            }
+        }
+        table
+    }
+
+    /// Convert table to an HTML `<table>` (see [`HwpTable::to_html`])
+    pub fn to_html(&self) -> String {
+        self.to_hwp_table().to_html()
+    }
+}
+
+/// Header heuristic for tables without the TABLE header flag: every cell
+/// of row 0 is bold while some later cell is not (an all-bold table has no
+/// distinct header). `cell_bold` is in LIST_HEADER order.
+fn first_row_is_bold(cell_bold: &[Option<bool>], cols: usize) -> bool {
+    if cols == 0 || cell_bold.len() <= cols {
+        return false;
+    }
+    let (first, rest) = cell_bold.split_at(cols);
+    first.iter().all(|b| *b == Some(true)) && rest.iter().any(|b| *b == Some(false))
 }
 
 /// 메타데이터
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct Metadata {
     pub version: String,
     pub compressed: bool,
@@ -2389,18 +3853,198 @@ pub struct Metadata {
     pub last_author: Option<String>,
 }
 
-/// MDM 문서 (변환 결과)
+/// One body section yielded by [`HwpParser::stream_sections`]
 #[derive(Debug)]
+pub struct SectionContent {
+    /// 0-based `Section{N}` index
+    pub index: usize,
+    /// Paragraph-level blocks in reading order (tables included inline)
+    pub paragraphs: Vec<IRBlock>,
+    /// Tables of this section with cell spans / styles
+    pub tables: Vec<TableData>,
+    /// Drawing objects, one per shape placeholder in `paragraphs`
+    pub shapes: Vec<ShapeComponent>,
+}
+
+/// Truncated record skipped in one stream, from [`HwpParser::parse_warnings`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamWarning {
+    /// Stream path, e.g. `BodyText/Section0`
+    pub stream: String,
+    pub warning: ParseWarning,
+}
+
+impl std::fmt::Display for StreamWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.stream, self.warning)
+    }
+}
+
+/// One hit from [`HwpParser::find_text`]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct TextMatch {
+    /// 0-based `Section{N}` index
+    pub section: usize,
+    /// 0-based paragraph (block) index within the section
+    pub paragraph_index: usize,
+    /// Byte offset of the match within the paragraph text
+    pub byte_offset: usize,
+    /// The match with up to [`FIND_CONTEXT_CHARS`] surrounding characters
+    pub context: String,
+}
+
+/// Characters of context around a [`TextMatch`], split evenly before and after
+pub const FIND_CONTEXT_CHARS: usize = 50;
+
+/// `text[start..start + len]` widened by half of [`FIND_CONTEXT_CHARS`]
+/// on each side, on char boundaries, with line breaks flattened
+fn match_context(text: &str, start: usize, len: usize) -> String {
+    let half = FIND_CONTEXT_CHARS / 2;
+    let end = start + len;
+    let from = text[..start].char_indices().rev().nth(half - 1).map_or(0, |(i, _)| i);
+    let to = text[end..].char_indices().nth(half).map_or(text.len(), |(i, _)| end + i);
+    text[from..to].replace('\n', " ")
+}
+
+/// Alt text of the `IRBlock::Image` placeholder emitted for drawing objects
+const SHAPE_ALT: &str = "shape";
+
+/// MDM 문서 (변환 결과)
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct MdmDocument {
     pub content: String,
     pub images: Vec<ImageData>,
     pub tables: Vec<TableData>,
+    pub footnotes: Vec<NoteData>,
     pub metadata: Metadata,
+    /// Drawing objects in reading order, rendered as inline SVG by `to_mdx`
+    #[serde(default)]
+    pub shapes: Vec<ShapeComponent>,
+    /// Equations in document order; the content carries them inline as `$...$`
+    #[serde(default)]
+    pub equations: Vec<EquationData>,
+    /// Hyperlink fields in document order, linked by `to_mdx`
+    #[serde(default)]
+    pub hyperlinks: Vec<HwpLink>,
+    /// Auto-generated table of contents, rendered by `to_mdx` before the body
+    #[serde(default)]
+    pub toc: Vec<TocEntry>,
+    /// Saved revisions from `DocHistory`, oldest first
+    #[serde(default)]
+    pub revision_history: Vec<RevisionEntry>,
+}
+
+/// 하이퍼링크 (hyperlink field)
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct HwpLink {
+    /// Link target, unescaped (`http://example.com`)
+    pub url: String,
+    /// Text covered by the field; the URL itself when the field is empty
+    pub display_text: String,
+    /// Body section the link was found in (0-based)
+    pub section: usize,
+    /// Index of the top-level paragraph carrying the link
+    pub paragraph: usize,
+}
+
+/// 각주/미주 (footnote / endnote)
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct NoteData {
+    /// Sequential note number (1-based, footnotes and endnotes share it)
+    pub number: usize,
+    /// Index of the top-level paragraph carrying the note reference
+    pub anchor_paragraph: usize,
+    /// Note body text
+    pub body: String,
+    /// true for 미주 (endnote), false for 각주 (footnote)
+    pub is_endnote: bool,
+    /// Byte range of the note's inline marker in [`MdmDocument::content`]
+    /// (see [`crate::ir::blocks_to_markdown_with_notes`]), which `to_mdx`
+    /// replaces with the `[^n]` reference. `None` when the body has no
+    /// marker for the note.
+    #[serde(default)]
+    pub marker: Option<std::ops::Range<usize>>,
+}
+
+/// 수식 (equation) from an `HWPTAG_EQEDIT` record
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct EquationData {
+    /// 0-based position among the document's equations
+    pub index: usize,
+    /// Script in HWP's equation language, e.g. `{a} over {b}`
+    pub hwp_source: String,
+    /// LaTeX conversion of `hwp_source`
+    pub latex: String,
+}
+
+/// Stable 32-byte digest of a converted document.
+///
+/// Covers `content`, every table cell, the byte length of every image, and
+/// the footnotes, hyperlinks, table of contents and equations that `to_mdx`
+/// renders from outside `content` — enough to tell whether re-converting an
+/// unchanged source would produce the same output. Each field is
+/// length-prefixed so concatenation boundaries cannot collide
+/// (`["ab","c"]` ≠ `["a","bc"]`). Blake3.
+pub fn content_hash(doc: &MdmDocument) -> [u8; 32] {
+    let mut hasher = blake3::Hasher::new();
+    let mut feed = |bytes: &[u8]| {
+        hasher.update(&(bytes.len() as u64).to_le_bytes());
+        hasher.update(bytes);
+    };
+    feed(doc.content.as_bytes());
+    for table in &doc.tables {
+        feed(&(table.rows as u64).to_le_bytes());
+        feed(&(table.cols as u64).to_le_bytes());
+        for cell in table.cells.iter().flatten() {
+            feed(cell.as_bytes());
+        }
+    }
+    for image in &doc.images {
+        feed(&(image.data.len() as u64).to_le_bytes());
+    }
+    for note in &doc.footnotes {
+        feed(&(note.number as u64).to_le_bytes());
+        feed(&[note.is_endnote as u8]);
+        feed(note.body.as_bytes());
+        if let Some(marker) = &note.marker {
+            feed(&(marker.start as u64).to_le_bytes());
+            feed(&(marker.end as u64).to_le_bytes());
+        }
+    }
+    for link in &doc.hyperlinks {
+        feed(link.url.as_bytes());
+        feed(link.display_text.as_bytes());
+    }
+    for entry in &doc.toc {
+        feed(&[entry.level]);
+        feed(entry.text.as_bytes());
+    }
+    for equation in &doc.equations {
+        feed(equation.latex.as_bytes());
+    }
+    hasher.finalize().into()
 }
 
 impl MdmDocument {
+    /// Content hash for idempotent caching — see [`content_hash`].
+    pub fn hash(&self) -> [u8; 32] {
+        content_hash(self)
+    }
+
+    /// Words (어절) in the converted content — see
+    /// [`crate::utils::text_stats::word_count`]
+    pub fn word_count(&self) -> usize {
+        crate::utils::text_stats::word_count(&self.content)
+    }
+
     /// Generate MDX content
     pub fn to_mdx(&self) -> String {
+        self.to_mdx_with_config(&crate::Config::default())
+    }
+
+    /// Generate MDX content honoring engine [`crate::Config`] options
+    /// (`preserve_table_colors`)
+    pub fn to_mdx_with_config(&self, config: &crate::Config) -> String {
         let mut mdx = String::new();
 
         // YAML-safe escaping for free-form metadata strings
@@ -2444,18 +4088,249 @@ impl MdmDocument {
         mdx.push_str(&format!("sections: {}\n", self.metadata.section_count));
         mdx.push_str(&format!("images: {}\n", self.images.len()));
         mdx.push_str(&format!("tables: {}\n", self.tables.len()));
+        if !self.equations.is_empty() {
+            mdx.push_str(&format!("equations: {}\n", self.equations.len()));
+        }
         mdx.push_str("---\n\n");
-        
-        // Content
-        mdx.push_str(&self.content);
-        
+
+        // 차례: linked headings, indented below the shallowest level
+        if !self.toc.is_empty() {
+            mdx.push_str("## Table of Contents\n\n");
+            let top = self.toc.iter().map(|e| e.level).min().unwrap_or(1);
+            let mut seen: HashMap<String, usize> = HashMap::new();
+            for entry in &self.toc {
+                let base = heading_anchor(&entry.text);
+                let count = seen.entry(base.clone()).or_insert(0);
+                let anchor = if *count == 0 { base } else { format!("{}-{}", base, count) };
+                *count += 1;
+                mdx.push_str(&format!(
+                    "{}- [{}](#{})\n",
+                    "  ".repeat((entry.level - top) as usize),
+                    entry.text.replace('[', "\\[").replace(']', "\\]"),
+                    anchor
+                ));
+            }
+            mdx.push('\n');
+        }
+
+        // Content — inline note markers become `[^n]` references with the
+        // bodies collected into a trailing footnote block.
+        let mut content = self.content_with_note_references();
+        if config.preserve_table_colors {
+            content = self.colored_tables_in(&content, config);
+        }
+
+        // Shape placeholders, in order, become inline SVG data URIs
+        let placeholder = format!("![{}](assets/{})", SHAPE_ALT, SHAPE_ALT);
+        for shape in &self.shapes {
+            if !content.contains(&placeholder) {
+                break;
+            }
+            let svg = shape_to_svg(shape);
+            let encoded = base64::engine::general_purpose::STANDARD.encode(svg.as_bytes());
+            let image = format!("![shape](data:image/svg+xml;base64,{})", encoded);
+            content = content.replacen(&placeholder, &image, 1);
+        }
+
+        // Hyperlinks, in document order, wrap the next occurrence of their
+        // display text (a bare URL included) as `[text](url)`
+        let mut cursor = 0usize;
+        for link in &self.hyperlinks {
+            if link.display_text.is_empty() {
+                continue;
+            }
+            let Some(offset) = content[cursor..].find(&link.display_text) else {
+                continue;
+            };
+            let start = cursor + offset;
+            let label = link.display_text.replace('[', "\\[").replace(']', "\\]");
+            let target = link.url.replace(' ', "%20").replace(')', "%29");
+            let markdown = format!("[{}]({})", label, target);
+            content.replace_range(start..start + link.display_text.len(), &markdown);
+            cursor = start + markdown.len();
+        }
+        mdx.push_str(&content);
+
+        if !self.footnotes.is_empty() {
+            mdx.push_str("\n\n");
+            for note in &self.footnotes {
+                mdx.push_str(&format!("[^{}]: {}\n", note.number, note.body));
+            }
+        }
+
         mdx
     }
+
+    /// `content` with the marker of every placed note
+    /// ([`NoteData::marker`]) replaced by its `[^n]` reference.
+    ///
+    /// Markers are replaced back to front so earlier ranges stay valid; a
+    /// range that no longer ends in the note body (content edited after
+    /// conversion) is left alone.
+    fn content_with_note_references(&self) -> String {
+        let mut content = self.content.clone();
+        let mut placed: Vec<(&std::ops::Range<usize>, &NoteData)> = self
+            .footnotes
+            .iter()
+            .filter_map(|note| note.marker.as_ref().map(|range| (range, note)))
+            .collect();
+        placed.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+        for (range, note) in placed {
+            let holds_marker = content.get(range.clone()).is_some_and(|m| m.ends_with(note.body.as_str()));
+            if holds_marker {
+                content.replace_range(range.clone(), &format!("[^{}]", note.number));
+            }
+        }
+        content
+    }
+
+    /// `content` with every table block whose [`TableData`] has colored
+    /// cells replaced by [`TableData::to_markdown_with_config`].
+    ///
+    /// Table blocks are matched to `tables` in order, as in
+    /// [`MdmDocument::to_html`]. Tables the block builder unwrapped into
+    /// paragraphs (1-column, label/body) have no block, so a table only
+    /// matches a block that contains all of its cell words.
+    fn colored_tables_in(&self, content: &str, config: &crate::Config) -> String {
+        let mut tables = self.tables.iter();
+        let mut blocks: Vec<String> = Vec::new();
+        for block in content.split("\n\n") {
+            let trimmed = block.trim_start();
+            if trimmed.starts_with('|') || trimmed.starts_with("<table") {
+                if let Some(skip) = tables.clone().position(|t| t.words_appear_in(trimmed)) {
+                    let table = tables.nth(skip).expect("position is in range");
+                    if table.has_cell_colors() {
+                        blocks.push(table.to_markdown_with_config(config));
+                        continue;
+                    }
+                }
+            }
+            blocks.push(block.to_string());
+        }
+        blocks.join("\n\n")
+    }
+
+    /// Generate a standalone HTML `<article>` (no frontmatter).
+    ///
+    /// Headings, lists, paragraphs and `**bold**` / `*italic*` spans in
+    /// `content` become HTML elements. Table blocks are replaced, in order,
+    /// by the matching entry of `tables` (see [`TableData::to_html`]), and
+    /// block-level image references become `<figure>`s captioned with their
+    /// alt text.
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<article>\n");
+        let mut tables = self.tables.iter();
+        for block in self.content.split("\n\n") {
+            let block = block.trim();
+            if block.is_empty() {
+                continue;
+            }
+            let lines: Vec<&str> = block.lines().map(str::trim).collect();
+
+            if block.starts_with('|') || block.starts_with("<table") {
+                match tables.next() {
+                    Some(table) => html.push_str(&table.to_html()),
+                    None if block.starts_with('|') => html.push_str(&pipe_table_to_html(&lines)),
+                    None => html.push_str(block),
+                }
+            } else if let Some((level, text)) = markdown_heading(block) {
+                html.push_str(&format!("<h{0}>{1}</h{0}>", level, inline_markdown_to_html(text)));
+            } else if let Some(caps) = RE_IMAGE_BLOCK.captures(block) {
+                let alt = html_escape(&caps[1]);
+                html.push_str(&format!(
+                    "<figure><img src=\"{}\" alt=\"{}\"><figcaption>{}</figcaption></figure>",
+                    html_escape(&caps[2]).replace('"', "&quot;"),
+                    alt.replace('"', "&quot;"),
+                    alt
+                ));
+            } else if block == "---" {
+                html.push_str("<hr>");
+            } else if lines.iter().all(|l| l.starts_with("- ")) {
+                html.push_str("<ul>");
+                for line in &lines {
+                    html.push_str(&format!("<li>{}</li>", inline_markdown_to_html(&line[2..])));
+                }
+                html.push_str("</ul>");
+            } else if lines.iter().all(|l| RE_ORDERED_ITEM.is_match(l)) {
+                html.push_str("<ol>");
+                for line in &lines {
+                    let text = RE_ORDERED_ITEM.replace(line, "");
+                    html.push_str(&format!("<li>{}</li>", inline_markdown_to_html(&text)));
+                }
+                html.push_str("</ol>");
+            } else {
+                let text: Vec<String> = lines.iter().map(|l| inline_markdown_to_html(l)).collect();
+                html.push_str(&format!("<p>{}</p>", text.join("<br>\n")));
+            }
+            html.push('\n');
+        }
+        html.push_str("</article>\n");
+        html
+    }
+}
+
+lazy_static! {
+    /// Block-level image reference: `![alt](src)`
+    static ref RE_IMAGE_BLOCK: Regex = Regex::new(r"^!\[([^\]]*)\]\(([^)\s]+)\)$").unwrap();
+
+    /// Ordered list item marker: `1. `
+    static ref RE_ORDERED_ITEM: Regex = Regex::new(r"^\d+\.\s+").unwrap();
+
+    /// Escaped color span / underline tags emitted by the converter
+    static ref RE_ESCAPED_INLINE_TAG: Regex =
+        Regex::new(r#"&lt;(span style="color:#[0-9a-f]{6}"|/span|/?u)&gt;"#).unwrap();
+
+    /// `**bold**`
+    static ref RE_BOLD: Regex = Regex::new(r"\*\*([^*]+)\*\*").unwrap();
+
+    /// `*italic*`
+    static ref RE_ITALIC: Regex = Regex::new(r"\*([^*\s][^*]*)\*").unwrap();
+}
+
+/// `## Title` → `(2, "Title")`
+fn markdown_heading(block: &str) -> Option<(usize, &str)> {
+    if block.contains('\n') {
+        return None;
+    }
+    let level = block.chars().take_while(|&c| c == '#').count();
+    let text = block[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then(|| (level, text.trim()))
+}
+
+/// Escape text and turn `**bold**` / `*italic*` into `<strong>` / `<em>`.
+/// The `<span style="color:...">` and `<u>` tags the converter emits for
+/// colored and underlined text are kept as markup.
+fn inline_markdown_to_html(text: &str) -> String {
+    let escaped = html_escape(text);
+    let html = RE_ESCAPED_INLINE_TAG.replace_all(&escaped, "<$1>");
+    let html = RE_BOLD.replace_all(&html, "<strong>$1</strong>");
+    RE_ITALIC.replace_all(&html, "<em>$1</em>").into_owned()
+}
+
+/// GFM pipe table lines → `<table>`; a `|---|` line marks the row above as header
+fn pipe_table_to_html(lines: &[&str]) -> String {
+    let is_separator = |line: &str| line.chars().all(|c| matches!(c, '|' | '-' | ':' | ' '));
+    let has_header = lines.get(1).is_some_and(|l| is_separator(l));
+
+    let mut out = String::from("<table>");
+    for (r, line) in lines.iter().filter(|l| !is_separator(l)).enumerate() {
+        let tag = if r == 0 && has_header { "th" } else { "td" };
+        out.push_str("<tr>");
+        let inner = line.trim().trim_start_matches('|').trim_end_matches('|');
+        for cell in inner.replace("\\|", "\u{0}").split('|') {
+            let cell = cell.replace('\u{0}', "|");
+            out.push_str(&format!("<{0}>{1}</{0}>", tag, inline_markdown_to_html(cell.trim())));
+        }
+        out.push_str("</tr>");
+    }
+    out.push_str("</table>");
+    out
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hwp::record::{NumberShape, NumberingLevel};
 
     // ── push_paragraph + heading promotion ──
 
@@ -2500,6 +4375,62 @@ mod tests {
 
     // ── EQEDIT script extraction ──
 
+    fn shape_records(child_tag: u16, child_data: Vec<u8>) -> Vec<HwpRecord> {
+        let mut base = Vec::new();
+        base.extend_from_slice(&0u32.to_le_bytes()); // flags
+        base.extend_from_slice(&0i16.to_le_bytes()); // rotation
+        base.extend_from_slice(&0i32.to_le_bytes()); // x
+        base.extend_from_slice(&0i32.to_le_bytes()); // y
+        base.extend_from_slice(&7500u32.to_le_bytes()); // width  (100px)
+        base.extend_from_slice(&3750u32.to_le_bytes()); // height (50px)
+        base.extend_from_slice(&[0, 0]); // flips
+        vec![
+            HwpRecord { tag_id: HWPTAG_CTRL_HEADER, level: 0, size: 4, data: b" osg".to_vec() },
+            HwpRecord { tag_id: HWPTAG_SHAPE_COMPONENT, level: 1, size: base.len() as u32, data: base },
+            HwpRecord { tag_id: child_tag, level: 2, size: child_data.len() as u32, data: child_data },
+        ]
+    }
+
+    #[test]
+    fn subtree_shape_reads_geometry_and_kind() {
+        let records = shape_records(HWPTAG_SHAPE_COMPONENT_ELLIPSE, vec![0; 8]);
+        let shape = extract_subtree_shape(&records, 0, 10).expect("ellipse");
+        assert_eq!(shape.shape_type, ShapeType::Ellipse);
+        assert_eq!((shape.width, shape.height), (7500, 3750));
+
+        let mut poly = Vec::new();
+        poly.extend_from_slice(&3i16.to_le_bytes());
+        for (x, y) in [(0i32, 3750i32), (3750, 0), (7500, 3750)] {
+            poly.extend_from_slice(&x.to_le_bytes());
+            poly.extend_from_slice(&y.to_le_bytes());
+        }
+        let records = shape_records(HWPTAG_SHAPE_COMPONENT_POLYGON, poly);
+        let shape = extract_subtree_shape(&records, 0, 10).expect("polygon");
+        assert_eq!(shape.points, vec![(0, 3750), (3750, 0), (7500, 3750)]);
+
+        let records = shape_records(HWPTAG_SHAPE_COMPONENT_PICTURE, vec![0; 8]);
+        assert!(extract_subtree_shape(&records, 0, 10).is_none());
+    }
+
+    #[test]
+    fn to_mdx_inlines_shapes_as_svg_data_uris() {
+        let mut doc = sample_doc("앞\n\n![shape](assets/shape)\n\n뒤");
+        doc.shapes.push(ShapeComponent {
+            shape_type: ShapeType::Rectangle,
+            width: 7500,
+            height: 3750,
+            ..Default::default()
+        });
+        let mdx = doc.to_mdx();
+        let prefix = "![shape](data:image/svg+xml;base64,";
+        let start = mdx.find(prefix).expect("inline svg") + prefix.len();
+        let end = start + mdx[start..].find(')').unwrap();
+        let svg = base64::engine::general_purpose::STANDARD.decode(&mdx[start..end]).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains(r#"<rect x="0" y="0" width="100" height="50""#));
+        assert!(!mdx.contains("assets/shape"));
+    }
+
     #[test]
     fn eqedit_extracts_utf16_script() {
         // HWPTAG_EQEDIT data layout: property u32 | len u16 | wchars...
@@ -2529,6 +4460,43 @@ mod tests {
         assert_eq!(script.as_deref(), Some("y=x^2"));
     }
 
+    fn eqedit_record(script: &str, level: u16) -> HwpRecord {
+        let utf16: Vec<u16> = script.encode_utf16().collect();
+        let mut data = Vec::new();
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&(utf16.len() as u16).to_le_bytes());
+        for w in &utf16 {
+            data.extend_from_slice(&w.to_le_bytes());
+        }
+        HwpRecord { tag_id: HWPTAG_EQEDIT, level, size: data.len() as u32, data }
+    }
+
+    #[test]
+    fn collect_equations_numbers_and_converts() {
+        let ctrl = |level| HwpRecord { tag_id: HWPTAG_CTRL_HEADER, level, size: 4, data: b"deqe".to_vec() };
+        let records = vec![
+            ctrl(0),
+            eqedit_record("{a} over {b}", 1),
+            HwpRecord { tag_id: HWPTAG_PARA_HEADER, level: 0, size: 0, data: Vec::new() },
+            ctrl(0),
+            eqedit_record("   ", 1),
+            ctrl(0),
+            eqedit_record("x^{2} + y_{1}", 1),
+        ];
+
+        let mut equations = vec![EquationData { index: 0, hwp_source: "E=mc^2".into(), latex: "E=mc^2".into() }];
+        collect_equations(&records, &mut equations);
+
+        assert_eq!(equations.len(), 3);
+        assert_eq!(equations[1].index, 1);
+        assert_eq!(equations[1].hwp_source, "{a} over {b}");
+        assert!(equations[1].latex.contains("\\frac"), "{}", equations[1].latex);
+        assert_eq!(equations[2].index, 2);
+        assert_eq!(equations[2].hwp_source, "x^{2} + y_{1}");
+        let sup_sub = equations[2].latex.replace(' ', "");
+        assert!(sup_sub.contains("^{2}") && sup_sub.contains("_{1}"), "{}", equations[2].latex);
+    }
+
     #[test]
     fn eqedit_returns_none_for_empty_script() {
         let mut data = Vec::new();
@@ -2626,29 +4594,140 @@ mod tests {
     // ── parse_section_records_formatted round-trip through IR ──
 
     #[test]
-    fn test_image_format_detection() {
+    fn push_styled_block_prefixes_heading_markers() {
+        let mut blocks = Vec::new();
+        push_styled_block(&mut blocks, "**제1장 총칙**\n".to_string(), Some(2), None, false);
+        push_styled_block(&mut blocks, "**굵게** 보통".to_string(), Some(1), None, false);
+        push_styled_block(&mut blocks, "본문\n".to_string(), None, None, false);
+        push_styled_block(&mut blocks, "  \n".to_string(), Some(1), None, false);
+        assert_eq!(blocks, vec!["## 제1장 총칙", "# **굵게** 보통", "본문\n"]);
+    }
+
+    #[test]
+    fn push_styled_block_quotes_indented_paragraphs() {
+        let mut blocks = Vec::new();
+        push_styled_block(&mut blocks, "인용문 첫 줄\n\n둘째 줄\n".to_string(), None, None, true);
+        push_styled_block(&mut blocks, "| a | b |".to_string(), None, None, true);
+        // Headings win over indentation
+        push_styled_block(&mut blocks, "제목".to_string(), Some(2), None, true);
+        assert_eq!(blocks, vec!["> 인용문 첫 줄\n>\n> 둘째 줄", "| a | b |", "## 제목"]);
+    }
+
+    fn list_shape(head_shape: u8, para_level: u8, numbering_id: u16) -> ParaShapeInfo {
+        ParaShapeInfo { head_shape, para_level, numbering_id, ..Default::default() }
+    }
+
+    fn numbering(levels: &[(NumberShape, &str)]) -> NumberingDef {
+        NumberingDef {
+            levels: levels
+                .iter()
+                .map(|&(shape, format)| NumberingLevel { shape, format: format.to_string() })
+                .collect(),
+            start: 1,
+        }
+    }
+
+    #[test]
+    fn list_prefix_numbers_arabic_levels() {
+        let numberings = HashMap::from([(1, numbering(&[(NumberShape::Digit, "^1."), (NumberShape::Digit, "^2)")]))]);
+        let mut counters = HashMap::new();
+        let prefixes: Vec<_> = [(0, 1), (1, 1), (1, 1), (0, 1), (1, 1)]
+            .iter()
+            .map(|&(level, id)| list_prefix(&list_shape(2, level, id), &numberings, &mut counters))
+            .collect();
+        assert_eq!(
+            prefixes,
+            vec![
+                Some("1. ".to_string()),
+                Some("  1) ".to_string()),
+                Some("  2) ".to_string()),
+                Some("2. ".to_string()),
+                Some("  1) ".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn list_prefix_numbers_korean_styles() {
+        let mut def = numbering(&[(NumberShape::CircledDigit, "^1"), (NumberShape::Ganada, "^2.")]);
+        def.start = 2;
+        let numberings = HashMap::from([(3, def)]);
+        let mut counters = HashMap::new();
+        let mut next = |level| list_prefix(&list_shape(2, level, 3), &numberings, &mut counters);
+        assert_eq!(next(0).as_deref(), Some("② "));
+        assert_eq!(next(1).as_deref(), Some("  나. "));
+        assert_eq!(next(1).as_deref(), Some("  다. "));
+        assert_eq!(next(0).as_deref(), Some("③ "));
+    }
+
+    #[test]
+    fn list_prefix_bullets_and_plain_paragraphs() {
+        let numberings = HashMap::new();
+        let mut counters = HashMap::new();
+        assert_eq!(list_prefix(&list_shape(3, 1, 0), &numberings, &mut counters).as_deref(), Some("  - "));
+        assert_eq!(list_prefix(&list_shape(0, 0, 0), &numberings, &mut counters), None);
+        assert_eq!(list_prefix(&list_shape(1, 2, 0), &numberings, &mut counters), None, "outline heading");
+        assert_eq!(list_prefix(&list_shape(2, 0, 9), &numberings, &mut counters), None, "unknown numbering");
+    }
+
+    #[test]
+    fn push_styled_block_prefixes_list_markers() {
+        let mut blocks = Vec::new();
+        push_styled_block(&mut blocks, "목적\n".to_string(), None, Some("① "), true);
+        push_styled_block(&mut blocks, "세부 항목".to_string(), None, Some("  가. "), false);
+        push_styled_block(&mut blocks, "제목".to_string(), Some(2), Some("1. "), false);
+        assert_eq!(blocks, vec!["① 목적", "  가. 세부 항목", "## 제목"]);
+    }
+
+    #[test]
+    fn test_bin_data_type_detection() {
+        let image = |ext: &str| BinDataType::Image(ext.to_string());
+
         let jpeg = vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x00, 0x00, 0x00];
-        assert_eq!(detect_image_format(&jpeg), "jpeg");
+        assert_eq!(detect_bin_data_type(&jpeg), image("jpeg"));
         
         let png = vec![0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A];
-        assert_eq!(detect_image_format(&png), "png");
+        assert_eq!(detect_bin_data_type(&png), image("png"));
         
         let gif = vec![0x47, 0x49, 0x46, 0x38, 0x39, 0x61, 0x00, 0x00];
-        assert_eq!(detect_image_format(&gif), "gif");
+        assert_eq!(detect_bin_data_type(&gif), image("gif"));
         
         let bmp = vec![0x42, 0x4D, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
-        assert_eq!(detect_image_format(&bmp), "bmp");
+        assert_eq!(detect_bin_data_type(&bmp), image("bmp"));
         
         let wmf = vec![0xD7, 0xCD, 0xC6, 0x9A, 0x00, 0x00, 0x00, 0x00];
-        assert_eq!(detect_image_format(&wmf), "wmf");
+        assert_eq!(detect_bin_data_type(&wmf), BinDataType::Wmf);
         
         // WebP (needs 12 bytes: RIFF + size + WEBP)
         let webp = b"RIFF\x00\x00\x00\x00WEBP";
-        assert_eq!(detect_image_format(webp), "webp");
+        assert_eq!(detect_bin_data_type(webp), image("webp"));
+
+        let mut emf = vec![0u8; 48];
+        emf[0] = 0x01;
+        emf[40..44].copy_from_slice(b" EMF");
+        assert_eq!(detect_bin_data_type(&emf), BinDataType::Emf);
+        // Record type 1 without the signature is not an EMF
+        emf[40..44].copy_from_slice(b"XXXX");
+        assert_eq!(detect_bin_data_type(&emf), BinDataType::Unknown);
+
+        let ole = vec![0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1, 0x00];
+        assert_eq!(detect_bin_data_type(&ole), BinDataType::Ole);
+
+        assert_eq!(detect_bin_data_type(b"RIFF\x24\x00\x00\x00WAVEfmt "), BinDataType::Sound);
+        assert_eq!(detect_bin_data_type(b"RIFF\x24\x00\x00\x00AVI LIST"), BinDataType::Video);
+        assert_eq!(detect_bin_data_type(b"\x00\x00\x00\x18ftypmp42"), BinDataType::Video);
         
         // Too short
         let short = vec![0xFF, 0xD8];
-        assert_eq!(detect_image_format(&short), "");
+        assert_eq!(detect_bin_data_type(&short), BinDataType::Unknown);
+    }
+
+    #[test]
+    fn test_bin_data_type_extension() {
+        assert_eq!(BinDataType::Image("png".to_string()).extension("BIN0001.jpg"), "png");
+        assert_eq!(BinDataType::Ole.extension("BIN0002.OLE"), "ole");
+        assert_eq!(BinDataType::Sound.extension("BIN0003.WAV"), "wav");
+        assert_eq!(BinDataType::Unknown.extension("BIN0004"), "bin");
     }
 
     #[test]
@@ -2661,6 +4740,8 @@ mod tests {
                 vec!["Cell 1".to_string(), "Cell 2".to_string()],
             ],
             cell_spans: Vec::new(),
+            cell_styles: Vec::new(),
+            has_header: true,
         };
         
         let md = table.to_markdown();
@@ -2669,6 +4750,429 @@ mod tests {
         assert!(md.contains("| Cell 1 |"));
     }
 
+    #[test]
+    fn test_table_to_markdown_without_header() {
+        let table = TableData {
+            rows: 2,
+            cols: 2,
+            cells: vec![
+                vec!["a".to_string(), "b".to_string()],
+                vec!["c".to_string(), "d".to_string()],
+            ],
+            cell_spans: Vec::new(),
+            cell_styles: Vec::new(),
+            has_header: false,
+        };
+        assert_eq!(table.to_markdown(), "| a | b |\n| c | d |\n");
+        assert!(!table.to_hwp_table().to_markdown().contains("---"));
+
+        // Tables cached before the field existed keep their header row
+        let json = r#"{"rows":1,"cols":2,"cells":[["a","b"]],"cell_spans":[]}"#;
+        let cached: TableData = serde_json::from_str(json).unwrap();
+        assert!(cached.has_header);
+    }
+
+    #[test]
+    fn test_first_row_is_bold() {
+        let bold = Some(true);
+        let plain = Some(false);
+        assert!(first_row_is_bold(&[bold, bold, plain, None], 2));
+        // All bold, or the header cells not all bold → no header
+        assert!(!first_row_is_bold(&[bold, bold, bold, bold], 2));
+        assert!(!first_row_is_bold(&[bold, None, plain, plain], 2));
+        // Single row
+        assert!(!first_row_is_bold(&[bold, bold], 2));
+    }
+
+    fn sample_doc(content: &str) -> MdmDocument {
+        MdmDocument {
+            content: content.to_string(),
+            images: Vec::new(),
+            tables: vec![TableData {
+                rows: 1,
+                cols: 2,
+                cells: vec![vec!["가".to_string(), "나".to_string()]],
+                cell_spans: Vec::new(),
+                cell_styles: Vec::new(),
+                has_header: true,
+            }],
+            footnotes: Vec::new(),
+            metadata: Metadata::default(),
+            shapes: Vec::new(),
+            equations: Vec::new(),
+            hyperlinks: Vec::new(),
+            toc: Vec::new(),
+            revision_history: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_word_count_counts_eojeol() {
+        let doc = sample_doc("## 제1장 총칙\n\n이 규정은 **업무 처리** 기준을 정한다.\n\n| --- |");
+        assert_eq!(doc.word_count(), 8);
+    }
+
+    #[test]
+    fn test_to_html_structure() {
+        let doc = sample_doc(
+            "# 규정\n\n## 제1장 총칙\n\n이 규정은 **업무 처리** 기준을 *간략히* 정한다 (a < b).\n\n\
+             | 가 | 나 |\n|---|---|\n\n![image1](assets/image1.png)\n\n- 첫째\n- 둘째",
+        );
+        let html = doc.to_html();
+
+        assert!(html.starts_with("<article>\n"));
+        assert!(html.trim_end().ends_with("</article>"));
+        assert!(html.contains("<h1>규정</h1>"));
+        assert!(html.contains("<h2>제1장 총칙</h2>"));
+        assert!(html.contains("<p>이 규정은 <strong>업무 처리</strong> 기준을 <em>간략히</em> 정한다 (a &lt; b).</p>"));
+        assert!(html.contains(&doc.tables[0].to_html()));
+        assert!(!html.contains("|---|"));
+        assert!(html.contains(
+            "<figure><img src=\"assets/image1.png\" alt=\"image1\"><figcaption>image1</figcaption></figure>"
+        ));
+        assert!(html.contains("<ul><li>첫째</li><li>둘째</li></ul>"));
+    }
+
+    #[test]
+    fn test_to_html_pipe_table_without_table_data() {
+        let mut doc = sample_doc("| 이름 | 값 |\n|---|---|\n| a \\| b | <span style=\"color:#ff0000\">1</span> |");
+        doc.tables.clear();
+        let html = doc.to_html();
+        assert!(html.contains("<tr><th>이름</th><th>값</th></tr>"), "{}", html);
+        assert!(html.contains("<tr><td>a | b</td><td><span style=\"color:#ff0000\">1</span></td></tr>"), "{}", html);
+    }
+
+    #[test]
+    fn test_strip_color_spans_config_drops_spans() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../samples/input/charshape.hwp");
+        let config = crate::Config {
+            strip_color_spans: true,
+            ..Default::default()
+        };
+        let doc = HwpParser::open(&path).unwrap().with_config(&config).to_mdm().unwrap();
+        assert!(!doc.content.is_empty());
+        assert!(!doc.content.contains("<span style=\"color:"), "{}", doc.content);
+    }
+
+    #[test]
+    fn test_to_mdx_emits_footnote_references() {
+        // Body text quoting a note marker verbatim must stay as written
+        let mut doc = sample_doc("본문에 \"[각주] 첫 각주\" 인용\n\n본문 문장 [각주] 첫 각주; [미주] 끝 미주\n\n다음 문단");
+        doc.tables.clear();
+        let at = |marker: &str| {
+            let start = doc.content.rfind(marker).unwrap();
+            Some(start..start + marker.len())
+        };
+        let (first, second) = (at(" [각주] 첫 각주"), at("; [미주] 끝 미주"));
+        doc.footnotes = vec![
+            NoteData { number: 1, anchor_paragraph: 1, body: "첫 각주".to_string(), is_endnote: false, marker: first },
+            NoteData { number: 2, anchor_paragraph: 1, body: "끝 미주".to_string(), is_endnote: true, marker: second },
+        ];
+
+        let mdx = doc.to_mdx();
+        assert!(mdx.contains("본문에 \"[각주] 첫 각주\" 인용\n\n본문 문장[^1][^2]\n\n다음 문단"), "{}", mdx);
+        assert!(mdx.contains("[^1]: 첫 각주\n"));
+        assert!(mdx.contains("[^2]: 끝 미주\n"));
+    }
+
+    #[test]
+    fn test_footnote_endnote_sample_references() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../samples/input/footnote-endnote.hwp");
+        let mut parser = HwpParser::open(&path).unwrap();
+
+        let notes = parser.extract_footnotes().unwrap();
+        let bodies: Vec<(&str, bool)> = notes.iter().map(|n| (n.body.as_str(), n.is_endnote)).collect();
+        assert_eq!(
+            bodies,
+            [("각주입니다.", false), ("각주 두 번째입니다.", false), ("미주입니다.", true), ("미주 두 번째입니다.", true)]
+        );
+        assert_eq!(notes.iter().map(|n| n.anchor_paragraph).collect::<Vec<_>>(), [0, 0, 1, 1]);
+
+        let doc = parser.to_mdm().unwrap();
+        assert!(doc.footnotes.iter().all(|n| n.marker.is_some()), "{:?}", doc.footnotes);
+        let mdx = doc.to_mdx();
+        assert!(mdx.contains("각주참조[^1][^2]"), "{}", mdx);
+        assert!(mdx.contains("미주참조[^3][^4]"), "{}", mdx);
+        assert!(mdx.contains("[^3]: 미주입니다.\n"), "{}", mdx);
+        assert!(!mdx.contains("[각주]") && !mdx.contains("[미주]"), "{}", mdx);
+    }
+
+    /// PARA_TEXT payload: `text` with hyperlink fields opened by `{` and closed by `}`
+    fn linked_para_text(text: &str, level: u16) -> HwpRecord {
+        let mut units: Vec<u16> = Vec::new();
+        for c in text.chars() {
+            match c {
+                '{' => {
+                    units.extend([0x03, u16::from_le_bytes(*b"kl"), u16::from_le_bytes(*b"h%"), 0, 0, 0, 0, 0x03]);
+                }
+                '}' => units.extend([0x04, 0, 0, 0, 0, 0, 0, 0x04]),
+                _ => {
+                    let mut buf = [0u16; 2];
+                    units.extend_from_slice(c.encode_utf16(&mut buf));
+                }
+            }
+        }
+        units.push(0x0D);
+        let data: Vec<u8> = units.iter().flat_map(|u| u.to_le_bytes()).collect();
+        HwpRecord { tag_id: HWPTAG_PARA_TEXT, level, size: data.len() as u32, data }
+    }
+
+    /// `%hlk` CTRL_HEADER with `command` as the field command string
+    fn hyperlink_ctrl(command: &str, level: u16) -> HwpRecord {
+        let utf16: Vec<u16> = command.encode_utf16().collect();
+        let mut data = b"klh%".to_vec();
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.push(0);
+        data.extend_from_slice(&(utf16.len() as u16).to_le_bytes());
+        data.extend(utf16.iter().flat_map(|u| u.to_le_bytes()));
+        HwpRecord { tag_id: HWPTAG_CTRL_HEADER, level, size: data.len() as u32, data }
+    }
+
+    fn para_header(level: u16) -> HwpRecord {
+        HwpRecord { tag_id: HWPTAG_PARA_HEADER, level, size: 0, data: Vec::new() }
+    }
+
+    #[test]
+    fn test_decode_field_url_unescapes_command() {
+        let ctrl = hyperlink_ctrl("http\\://www.hancom.com/a\\;b;1;0;0;", 1);
+        assert_eq!(decode_field_url(&ctrl.data).as_deref(), Some("http://www.hancom.com/a;b"));
+    }
+
+    #[test]
+    fn test_decode_field_url_rejects_truncated_or_empty() {
+        let mut ctrl = hyperlink_ctrl("http\\://x.com;1;0;0;", 1);
+        ctrl.data.truncate(15);
+        assert_eq!(decode_field_url(&ctrl.data), None);
+        assert_eq!(decode_field_url(b"klh%"), None);
+        assert_eq!(decode_field_url(&hyperlink_ctrl(";1;0;0;", 1).data), None);
+    }
+
+    #[test]
+    fn test_hyperlink_display_texts_in_order() {
+        let record = linked_para_text("앞 {한컴} 중간 {누리집 안내} 뒤", 1);
+        assert_eq!(hyperlink_display_texts(&record.data), vec!["한컴", "누리집 안내"]);
+    }
+
+    #[test]
+    fn test_hyperlink_display_texts_unterminated_and_plain() {
+        assert_eq!(hyperlink_display_texts(&linked_para_text("앞 {끝까지", 1).data), vec!["끝까지"]);
+        assert!(hyperlink_display_texts(&linked_para_text("링크 없음", 1).data).is_empty());
+    }
+
+    #[test]
+    fn test_hyperlink_display_texts_ignores_other_fields() {
+        // A 0x03 field without the %hlk id (e.g. a date field) is not a link
+        let mut units: Vec<u16> = vec![0x03, u16::from_le_bytes(*b"et"), u16::from_le_bytes(*b"ad"), 0, 0, 0, 0, 0x03];
+        units.extend("2024".encode_utf16());
+        units.extend([0x04, 0, 0, 0, 0, 0, 0, 0x04]);
+        let data: Vec<u8> = units.iter().flat_map(|u| u.to_le_bytes()).collect();
+        assert!(hyperlink_display_texts(&data).is_empty());
+    }
+
+    #[test]
+    fn test_collect_hyperlinks_pairs_text_and_url() {
+        let records = vec![
+            para_header(0),
+            linked_para_text("본문", 1),
+            para_header(0),
+            linked_para_text("{한컴} 및 {정부24} 참조", 1),
+            hyperlink_ctrl("http\\://www.hancom.com;1;0;0;", 1),
+            hyperlink_ctrl("https\\://www.gov.kr;1;0;0;", 1),
+        ];
+        let mut paragraphs = 0;
+        let mut links = Vec::new();
+        collect_hyperlinks(&records, 2, &mut paragraphs, &mut links);
+
+        assert_eq!(paragraphs, 2);
+        assert_eq!(
+            links,
+            vec![
+                HwpLink { url: "http://www.hancom.com".into(), display_text: "한컴".into(), section: 2, paragraph: 1 },
+                HwpLink { url: "https://www.gov.kr".into(), display_text: "정부24".into(), section: 2, paragraph: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collect_hyperlinks_falls_back_to_url() {
+        let records = vec![para_header(0), linked_para_text("텍스트 없음", 1), hyperlink_ctrl("http\\://x.com;1;0;0;", 1)];
+        let mut paragraphs = 3;
+        let mut links = Vec::new();
+        collect_hyperlinks(&records, 0, &mut paragraphs, &mut links);
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].display_text, "http://x.com");
+        assert_eq!(links[0].paragraph, 3);
+    }
+
+    #[test]
+    fn test_to_mdx_emits_table_of_contents() {
+        let entry = |text: &str, level: u8| TocEntry { text: text.to_string(), level, paragraph: 0 };
+        let mut doc = sample_doc("## 제1장 총칙\n\n본문");
+        doc.toc = vec![entry("제1장 총칙", 2), entry("1. 목적 (Purpose)", 3), entry("제1장 총칙", 2)];
+
+        let mdx = doc.to_mdx();
+        let toc = "## Table of Contents\n\n\
+                   - [제1장 총칙](#제1장-총칙)\n\
+                   \x20\x20- [1. 목적 (Purpose)](#1-목적-purpose)\n\
+                   - [제1장 총칙](#제1장-총칙-1)\n\n";
+        let toc_at = mdx.find(toc).expect("toc section");
+        assert!(toc_at < mdx.find("본문").unwrap());
+
+        doc.toc.clear();
+        assert!(!doc.to_mdx().contains("Table of Contents"));
+    }
+
+    #[test]
+    fn test_to_mdx_links_display_text_and_bare_url() {
+        let mut doc = sample_doc("한컴 누리집과 http://x.com 주소, 다시 한컴");
+        doc.hyperlinks = vec![
+            HwpLink { url: "http://www.hancom.com".into(), display_text: "한컴".into(), section: 0, paragraph: 0 },
+            HwpLink { url: "http://x.com".into(), display_text: "http://x.com".into(), section: 0, paragraph: 0 },
+        ];
+        let mdx = doc.to_mdx();
+        assert!(
+            mdx.contains("[한컴](http://www.hancom.com) 누리집과 [http://x.com](http://x.com) 주소, 다시 한컴"),
+            "{}",
+            mdx
+        );
+    }
+
+    #[test]
+    fn test_to_mdx_escapes_link_text_and_skips_missing() {
+        let mut doc = sample_doc("자료 [1] 보기");
+        doc.hyperlinks = vec![
+            HwpLink { url: "http://a.com/x y".into(), display_text: "[1]".into(), section: 0, paragraph: 0 },
+            HwpLink { url: "http://b.com".into(), display_text: "없는 텍스트".into(), section: 0, paragraph: 0 },
+        ];
+        let mdx = doc.to_mdx();
+        assert!(mdx.contains("자료 [\\[1\\]](http://a.com/x%20y) 보기"), "{}", mdx);
+        assert!(!mdx.contains("b.com"));
+    }
+
+    #[test]
+    fn test_mdm_document_hyperlinks_default_when_absent() {
+        let mut json = serde_json::to_value(sample_doc("본문")).unwrap();
+        json.as_object_mut().unwrap().remove("hyperlinks");
+        let doc: MdmDocument = serde_json::from_value(json).unwrap();
+        assert!(doc.hyperlinks.is_empty());
+    }
+
+    #[test]
+    fn test_content_hash_same_parse_is_stable() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../samples/input/charshape.hwp");
+        let first = HwpParser::open(&path).unwrap().to_mdm().unwrap();
+        let second = HwpParser::open(&path).unwrap().to_mdm().unwrap();
+        assert_eq!(first.hash(), second.hash());
+    }
+
+    #[test]
+    fn test_content_hash_changes_with_one_character() {
+        let base = sample_doc("본문 내용");
+        assert_eq!(base.hash(), sample_doc("본문 내용").hash());
+        assert_ne!(base.hash(), sample_doc("본문 내욤").hash());
+
+        let mut edited_cell = sample_doc("본문 내용");
+        edited_cell.tables[0].cells[0][1] = "다".to_string();
+        assert_ne!(base.hash(), edited_cell.hash());
+
+        // Cell boundaries are part of the hash, not just the joined text.
+        let mut shifted = sample_doc("본문 내용");
+        shifted.tables[0].cells[0] = vec!["가나".to_string(), String::new()];
+        assert_ne!(base.hash(), shifted.hash());
+
+        // Parts `to_mdx` renders outside `content` count too
+        let mut with_toc = sample_doc("본문 내용");
+        with_toc.toc.push(TocEntry { text: "제1장".to_string(), level: 1, paragraph: 0 });
+        assert_ne!(base.hash(), with_toc.hash());
+    }
+
+    #[test]
+    fn test_table_to_markdown_preserves_cell_background() {
+        let table = TableData {
+            rows: 1,
+            cols: 2,
+            cells: vec![vec!["빨강".to_string(), "plain".to_string()]],
+            cell_spans: Vec::new(),
+            cell_styles: vec![TableCell {
+                row: 0,
+                col: 0,
+                background_color: Some(0xFFFF0000),
+                ..Default::default()
+            }],
+            has_header: true,
+        };
+
+        let config = crate::Config {
+            preserve_table_colors: true,
+            ..Default::default()
+        };
+        let html = table.to_markdown_with_config(&config);
+        assert!(html.contains("<td style=\"background-color:#ff0000\">빨강</td>"));
+        assert!(html.contains("<td>plain</td>"));
+
+        // Off by default → unchanged GFM output
+        assert_eq!(
+            table.to_markdown_with_config(&crate::Config::default()),
+            table.to_markdown()
+        );
+    }
+
+    #[test]
+    fn test_to_mdx_with_config_colors_matching_table() {
+        // A 1-column table unwrapped to paragraphs precedes the colored one
+        let mut doc = sample_doc("서문\n\n| 가 | 나 |\n| --- | --- |\n\n끝");
+        doc.tables.insert(0, TableData {
+            rows: 1,
+            cols: 1,
+            cells: vec![vec!["서문".to_string()]],
+            cell_spans: Vec::new(),
+            cell_styles: Vec::new(),
+            has_header: false,
+        });
+        doc.tables[1].cell_styles.push(TableCell {
+            row: 0,
+            col: 1,
+            background_color: Some(0xFFFF0000),
+            ..Default::default()
+        });
+
+        let config = crate::Config {
+            preserve_table_colors: true,
+            ..Default::default()
+        };
+        let mdx = doc.to_mdx_with_config(&config);
+        assert!(mdx.contains("서문\n\n<table>"), "{}", mdx);
+        assert!(mdx.contains("<td style=\"background-color:#ff0000\">나</td>"), "{}", mdx);
+        assert!(!mdx.contains("| 가 |"));
+        assert!(mdx.trim_end().ends_with("끝"));
+
+        // Off by default
+        assert!(doc.to_mdx().contains("| 가 | 나 |"));
+    }
+
+    #[test]
+    fn test_table_data_to_html_carries_spans_and_colors() {
+        let table = TableData {
+            rows: 2,
+            cols: 2,
+            cells: vec![
+                vec!["제목".to_string(), String::new()],
+                vec!["a".to_string(), "b".to_string()],
+            ],
+            cell_spans: vec![CellSpan { row: 0, col: 0, row_span: 1, col_span: 2, ..Default::default() }],
+            cell_styles: vec![TableCell {
+                row: 1,
+                col: 1,
+                background_color: Some(0xFF00FF00),
+                ..Default::default()
+            }],
+            has_header: true,
+        };
+        let html = table.to_html();
+        assert!(html.contains("<tr><td colspan=\"2\">제목</td></tr>"));
+        assert!(html.contains("<td>a</td><td style=\"background-color:#00ff00\">b</td>"));
+    }
+
     /// Ported from kordoc `tests/table-builder.test.ts` (2026-04-09, f68e825).
     /// HWP 5.x merged colSpan → HTML `<table>` with `colspan="N"` (skips cleanup).
     #[test]
@@ -2835,4 +5339,15 @@ mod tests {
         assert_eq!(organized[0], vec!["A", "B"]);
         assert_eq!(organized[1], vec!["C", "D"]);
     }
+
+    #[test]
+    fn test_estimate_page_count() {
+        let text = vec!["한 줄"; 100].join("\n");
+        assert_eq!(estimate_page_count(&text, 40), 3);
+        assert_eq!(estimate_page_count(&text, 50), 2);
+        assert_eq!(estimate_page_count(&text, 100), 1);
+        assert_eq!(estimate_page_count("", DEFAULT_LINES_PER_PAGE), 1);
+        // A zero lines-per-page layout is treated as one line per page
+        assert_eq!(estimate_page_count("a\nb", 0), 2);
+    }
 }
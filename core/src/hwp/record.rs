@@ -142,15 +142,72 @@ impl HwpRecord {
     }
 }
 
+/// Highest tag ID a resynchronized header may carry (body tags end at 0x58)
+const MAX_RECOVERY_TAG: u16 = 0x80;
+
+/// What [`RecordParser`] does with a record that runs past the end of data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// Stop at the first truncated record (the historical behaviour)
+    #[default]
+    Strict,
+    /// Skip ahead to the next plausible record header and keep going
+    Recovery,
+}
+
+/// One truncated record skipped in [`ParseMode::Recovery`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseWarning {
+    /// Offset of the bad record header
+    pub offset: usize,
+    pub tag_id: u16,
+    /// Size from the header (0 when the extended size itself is cut off)
+    pub declared_size: u32,
+    /// Bytes actually left after the header
+    pub available: usize,
+    /// Offset parsing resumed at; `None` when no later header was found
+    pub resumed_at: Option<usize>,
+}
+
+impl std::fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "record 0x{:03X} at offset {} declares {} bytes but only {} remain",
+            self.tag_id, self.offset, self.declared_size, self.available
+        )?;
+        match self.resumed_at {
+            Some(next) => write!(f, "; resumed at offset {}", next),
+            None => write!(f, "; no later record header found"),
+        }
+    }
+}
+
 /// Record parser for decompressed HWP data
 pub struct RecordParser<'a> {
     data: &'a [u8],
     position: usize,
+    mode: ParseMode,
+    warnings: Vec<ParseWarning>,
 }
 
 impl<'a> RecordParser<'a> {
     pub fn new(data: &'a [u8]) -> Self {
-        RecordParser { data, position: 0 }
+        RecordParser { data, position: 0, mode: ParseMode::Strict, warnings: Vec::new() }
+    }
+
+    /// Parser in [`ParseMode::Recovery`]
+    pub fn with_recovery(data: &'a [u8]) -> Self {
+        RecordParser { mode: ParseMode::Recovery, ..Self::new(data) }
+    }
+
+    pub fn mode(&self) -> ParseMode {
+        self.mode
+    }
+
+    /// Recovery events so far
+    pub fn warnings(&self) -> &[ParseWarning] {
+        &self.warnings
     }
 
     /// Parse all records from the data
@@ -162,20 +219,57 @@ impl<'a> RecordParser<'a> {
         records
     }
 
+    /// [`Self::parse_all`] plus the truncated records skipped on the way
+    pub fn parse_all_with_stats(&mut self) -> (Vec<HwpRecord>, Vec<ParseWarning>) {
+        let records = self.parse_all();
+        (records, std::mem::take(&mut self.warnings))
+    }
+
     /// Parse next record
     pub fn parse_next(&mut self) -> Option<HwpRecord> {
-        if self.position + 4 > self.data.len() {
-            return None;
+        loop {
+            let start = self.position;
+            let (header, header_len) = self.read_header(start)?;
+            let Header { tag_id, level, size } = header;
+            let body = start + header_len;
+
+            if let Some(size) = size.filter(|&size| body.saturating_add(size as usize) <= self.data.len()) {
+                self.position = body + size as usize;
+                return Some(HwpRecord {
+                    tag_id,
+                    level,
+                    size,
+                    data: self.data[body..body + size as usize].to_vec(),
+                });
+            }
+
+            // Truncated record: give up, or resynchronize on the next header
+            if self.mode == ParseMode::Strict {
+                self.position = self.data.len();
+                return None;
+            }
+            let resumed_at = self.find_next_header(start);
+            let warning = ParseWarning {
+                offset: start,
+                tag_id,
+                declared_size: size.unwrap_or(0),
+                available: self.data.len().saturating_sub(body),
+                resumed_at,
+            };
+            self.warnings.push(warning);
+            self.position = resumed_at.unwrap_or(self.data.len());
         }
+    }
 
-        // Read 4-byte header
-        let header = u32::from_le_bytes([
-            self.data[self.position],
-            self.data[self.position + 1],
-            self.data[self.position + 2],
-            self.data[self.position + 3],
-        ]);
-        self.position += 4;
+    /// Header at `pos` and its length (4, or 8 with an extended size);
+    /// `size` is `None` when the extended size field is cut off
+    fn read_header(&self, pos: usize) -> Option<(Header, usize)> {
+        let word = |at: usize| {
+            self.data
+                .get(at..at + 4)
+                .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        };
+        let header = word(pos)?;
 
         // Parse header fields
         // Tag ID: bits 0-9 (10 bits)
@@ -186,39 +280,42 @@ impl<'a> RecordParser<'a> {
         let size_field = (header >> 20) & 0xFFF;
 
         // Extended size if size_field == 0xFFF
-        let size = if size_field == 0xFFF {
-            if self.position + 4 > self.data.len() {
-                return None;
+        if size_field == 0xFFF {
+            match word(pos + 4) {
+                Some(extended) => Some((Header { tag_id, level, size: Some(extended) }, 8)),
+                None => Some((Header { tag_id, level, size: None }, self.data.len() - pos)),
             }
-            let extended = u32::from_le_bytes([
-                self.data[self.position],
-                self.data[self.position + 1],
-                self.data[self.position + 2],
-                self.data[self.position + 3],
-            ]);
-            self.position += 4;
-            extended
         } else {
-            size_field
-        };
-
-        // Read data
-        if self.position + size as usize > self.data.len() {
-            return None;
+            Some((Header { tag_id, level, size: Some(size_field) }, 4))
         }
+    }
 
-        let data = self.data[self.position..self.position + size as usize].to_vec();
-        self.position += size as usize;
-
-        Some(HwpRecord {
-            tag_id,
-            level,
-            size,
-            data,
-        })
+    /// First 4-byte-aligned offset after `from` holding a header with a
+    /// known tag (0..=0x80) whose record fits in the remaining data
+    fn find_next_header(&self, from: usize) -> Option<usize> {
+        let mut pos = (from + 4) & !3;
+        while pos + 4 <= self.data.len() {
+            if let Some((header, header_len)) = self.read_header(pos) {
+                let fits = header
+                    .size
+                    .is_some_and(|size| (pos + header_len).saturating_add(size as usize) <= self.data.len());
+                if header.tag_id <= MAX_RECOVERY_TAG && fits {
+                    return Some(pos);
+                }
+            }
+            pos += 4;
+        }
+        None
     }
 }
 
+/// Decoded record header fields
+struct Header {
+    tag_id: u16,
+    level: u16,
+    size: Option<u32>,
+}
+
 /// Extract text from PARA_TEXT record data
 /// Handles UTF-16LE encoding, control characters, extended controls, and surrogate pairs
 pub fn extract_para_text(data: &[u8]) -> String {
@@ -326,8 +423,61 @@ pub struct CellSpan {
     pub has_addr: bool,
 }
 
+/// Read the border/fill ID referenced by a table cell LIST_HEADER.
+///
+/// Continues the layout documented on [`parse_cell_list_header`]:
+///  16..20  width        u32 (HWPUNIT)
+///  20..24  height       u32 (HWPUNIT)
+///  24..32  margins      u16 × 4 (left/right/top/bottom)
+///  32..34  borderFillId u16 — 1-based index into DocInfo `HWPTAG_BORDER_FILL`
+///
+/// Returns `None` when the record is too short or the ID is 0 (no fill).
+pub fn parse_cell_border_fill_id(data: &[u8]) -> Option<u16> {
+    if data.len() < 34 {
+        return None;
+    }
+    let id = u16::from_le_bytes([data[32], data[33]]);
+    if id == 0 { None } else { Some(id) }
+}
+
+/// Border/fill definition from a DocInfo `HWPTAG_BORDER_FILL` record.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BorderFill {
+    /// Solid fill color as `0xAARRGGBB` (alpha always 0xFF). `None` when the
+    /// fill has no solid-color component (gradient / image / empty fill).
+    pub background_color: Option<u32>,
+}
+
+/// Parse HWPTAG_BORDER_FILL record.
+///
+/// Layout (HWP 5.0 spec 표 23, verified against pyhwp `BorderFill`):
+///   0..2   property      u16
+///   2..32  borders       5 × (type u8, width u8, COLORREF u32)
+///                        left / right / top / bottom / diagonal
+///  32..36  fill type     u32 — bit 0 = solid color fill
+///  36..40  background    COLORREF (bytes R, G, B, reserved)
+///  40..44  pattern color COLORREF
+///  44..48  pattern type  i32
+///
+/// Only the solid background is extracted; gradient and image fills follow
+/// the pattern fields and are ignored.
+pub fn parse_border_fill(data: &[u8]) -> Option<BorderFill> {
+    if data.len() < 36 {
+        return None;
+    }
+    let fill_type = u32::from_le_bytes([data[32], data[33], data[34], data[35]]);
+    let background_color = if fill_type & 0x01 != 0 && data.len() >= 40 {
+        // COLORREF is 0x00BBGGRR — on disk that is R, G, B, 0.
+        let (r, g, b) = (data[36] as u32, data[37] as u32, data[38] as u32);
+        Some(0xFF00_0000 | (r << 16) | (g << 8) | b)
+    } else {
+        None
+    };
+    Some(BorderFill { background_color })
+}
+
 /// Table cell with content and merge info
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TableCell {
     pub row: u16,
     pub col: u16,
@@ -354,6 +504,32 @@ impl Default for TableCell {
     }
 }
 
+impl TableCell {
+    /// `background_color` unless it is white (the default cell fill)
+    pub fn visible_background(&self) -> Option<u32> {
+        self.background_color.filter(|c| c & 0x00FF_FFFF != 0x00FF_FFFF)
+    }
+}
+
+/// `0xAARRGGBB` → CSS `#rrggbb` (alpha dropped).
+pub(crate) fn css_hex_color(argb: u32) -> String {
+    format!("#{:06x}", argb & 0x00FF_FFFF)
+}
+
+/// Minimal HTML escaper for cell text (`&`, `<`, `>`).
+pub(crate) fn html_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
 /// Parse table structure from TABLE record
 #[derive(Debug, Clone)]
 #[derive(Default)]
@@ -364,8 +540,16 @@ pub struct TableInfo {
     pub cell_spans: Vec<CellSpan>,
     pub row_heights: Vec<u16>,
     pub col_widths: Vec<u16>,
+    /// Table-level borderFillId (1-based DocInfo `HWPTAG_BORDER_FILL` index)
+    pub border_fill_id: Option<u16>,
+    /// First row is a header row (제목 줄 자동 반복, TABLE flags bit 2)
+    pub has_header_row: bool,
 }
 
+/// TABLE record flags bit 2: repeat the header row on every page.
+/// Bits 0-1 are the page-split mode.
+const TABLE_FLAG_REPEAT_HEADER: u32 = 1 << 2;
+
 
 pub fn parse_table_info(data: &[u8]) -> Option<TableInfo> {
     if data.len() < 8 {
@@ -383,6 +567,7 @@ pub fn parse_table_info(data: &[u8]) -> Option<TableInfo> {
     // - Zone info count: 2 bytes
     // - Zone infos: ...
 
+    let flags = u32::from_le_bytes([data[0], data[1], data[2], data[3]]);
     let rows = u16::from_le_bytes([data[4], data[5]]);
     let cols = u16::from_le_bytes([data[6], data[7]]);
 
@@ -393,6 +578,8 @@ pub fn parse_table_info(data: &[u8]) -> Option<TableInfo> {
         cell_spans: Vec::new(),
         row_heights: Vec::new(),
         col_widths: Vec::new(),
+        border_fill_id: None,
+        has_header_row: flags & TABLE_FLAG_REPEAT_HEADER != 0,
     };
 
     // Parse row heights if available (after margins at offset 18)
@@ -405,6 +592,13 @@ pub fn parse_table_info(data: &[u8]) -> Option<TableInfo> {
                 info.row_heights.push(height);
             }
         }
+
+        // Border fill ID follows the row sizes (0 = none)
+        let fill_offset = row_heights_offset + rows as usize * 2;
+        if let Some(bytes) = data.get(fill_offset..fill_offset + 2) {
+            let id = u16::from_le_bytes([bytes[0], bytes[1]]);
+            info.border_fill_id = (id != 0).then_some(id);
+        }
     }
 
     Some(info)
@@ -458,7 +652,7 @@ pub fn parse_cell_list_header(data: &[u8]) -> Option<CellSpan> {
 }
 
 /// Shape component types
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum ShapeType {
     Line,
     Rectangle,
@@ -489,7 +683,7 @@ impl From<u16> for ShapeType {
 }
 
 /// Shape component structure for drawings and pictures
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ShapeComponent {
     pub shape_type: ShapeType,
     pub x: i32,
@@ -501,6 +695,9 @@ pub struct ShapeComponent {
     pub y_flip: bool,
     pub bin_data_id: Option<u16>,  // For pictures, reference to BinData
     pub alt_text: Option<String>,
+    /// Shape-local vertices: line endpoints or polygon points
+    #[serde(default)]
+    pub points: Vec<(i32, i32)>,
 }
 
 impl Default for ShapeComponent {
@@ -516,6 +713,7 @@ impl Default for ShapeComponent {
             y_flip: false,
             bin_data_id: None,
             alt_text: None,
+            points: Vec::new(),
         }
     }
 }
@@ -557,9 +755,126 @@ pub fn parse_shape_component(data: &[u8], shape_type: ShapeType) -> Option<Shape
         y_flip,
         bin_data_id: None,
         alt_text: None,
+        points: Vec::new(),
     })
 }
 
+/// Parse SHAPE_COMPONENT_LINE record
+///
+/// - Start X, Y: 4 bytes each (signed)
+/// - End X, Y: 4 bytes each (signed)
+/// - ...
+pub fn parse_line_points(data: &[u8]) -> Vec<(i32, i32)> {
+    read_points(data, 0, 2)
+}
+
+/// Parse SHAPE_COMPONENT_POLYGON record
+///
+/// - Point count: 2 bytes
+/// - Points: (X, Y) 4 bytes each (signed), repeated
+pub fn parse_polygon_points(data: &[u8]) -> Vec<(i32, i32)> {
+    if data.len() < 2 {
+        return Vec::new();
+    }
+    let count = i16::from_le_bytes([data[0], data[1]]).max(0) as usize;
+    read_points(data, 2, count)
+}
+
+fn read_points(data: &[u8], offset: usize, count: usize) -> Vec<(i32, i32)> {
+    data.get(offset..)
+        .unwrap_or_default()
+        .chunks_exact(8)
+        .take(count)
+        .map(|c| {
+            (
+                i32::from_le_bytes([c[0], c[1], c[2], c[3]]),
+                i32::from_le_bytes([c[4], c[5], c[6], c[7]]),
+            )
+        })
+        .collect()
+}
+
+/// Render a drawing object as a standalone SVG document.
+///
+/// HWPUNIT (1/7200 inch) is scaled to CSS pixels at 96 dpi. The viewBox
+/// covers the shape's bounding box at `x`/`y`, grown to fit when rotated.
+/// Rectangles, ellipses, lines and polygons are drawn as such; other shape
+/// types fall back to their bounding rectangle.
+pub fn shape_to_svg(shape: &ShapeComponent) -> String {
+    const HWPUNIT_PER_PX: f64 = 75.0;
+    let px = |v: f64| (v / HWPUNIT_PER_PX * 100.0).round() / 100.0;
+
+    let x = px(shape.x as f64);
+    let y = px(shape.y as f64);
+    let w = px(shape.width as f64);
+    let h = px(shape.height as f64);
+    let (cx, cy) = (x + w / 2.0, y + h / 2.0);
+
+    // Local shape coordinates, mirrored by the flip flags
+    let point = |(lx, ly): (i32, i32)| {
+        let mut px_x = px(lx as f64);
+        let mut px_y = px(ly as f64);
+        if shape.x_flip {
+            px_x = w - px_x;
+        }
+        if shape.y_flip {
+            px_y = h - px_y;
+        }
+        (x + px_x, y + px_y)
+    };
+
+    const STYLE: &str = r#"fill="none" stroke="black" stroke-width="1""#;
+    let element = match shape.shape_type {
+        ShapeType::Ellipse => format!(
+            r#"<ellipse cx="{}" cy="{}" rx="{}" ry="{}" {}/>"#,
+            cx, cy, w / 2.0, h / 2.0, STYLE
+        ),
+        ShapeType::Line => {
+            let (start, end) = match shape.points.as_slice() {
+                [a, b, ..] => (point(*a), point(*b)),
+                _ => (point((0, 0)), point((shape.width as i32, shape.height as i32))),
+            };
+            format!(
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" {}/>"#,
+                start.0, start.1, end.0, end.1, STYLE
+            )
+        }
+        ShapeType::Polygon => {
+            let vertices: Vec<(f64, f64)> = if shape.points.len() >= 3 {
+                shape.points.iter().map(|&p| point(p)).collect()
+            } else {
+                vec![(x, y), (x + w, y), (x + w, y + h), (x, y + h)]
+            };
+            let list: Vec<String> = vertices.iter().map(|(vx, vy)| format!("{},{}", vx, vy)).collect();
+            format!(r#"<polygon points="{}" {}/>"#, list.join(" "), STYLE)
+        }
+        _ => format!(
+            r#"<rect x="{}" y="{}" width="{}" height="{}" {}/>"#,
+            x, y, w, h, STYLE
+        ),
+    };
+
+    // Bounding box of the rotated shape, centred on the original one
+    let theta = (shape.rotation as f64).to_radians();
+    let (sin, cos) = (theta.sin().abs(), theta.cos().abs());
+    let bw = ((w * cos + h * sin) * 100.0).round() / 100.0;
+    let bh = ((w * sin + h * cos) * 100.0).round() / 100.0;
+    // Keep zero-width lines visible
+    let (bw, bh) = (bw.max(1.0), bh.max(1.0));
+    let (bx, by) = (cx - bw / 2.0, cy - bh / 2.0);
+
+    let body = if shape.rotation != 0 {
+        format!(r#"<g transform="rotate({} {} {})">{}</g>"#, shape.rotation, cx, cy, element)
+    } else {
+        element
+    };
+
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">{}</svg>"#,
+        bw, bh, bx, by, bw, bh, body
+    )
+}
+
 /// Parse SHAPE_COMPONENT_PICTURE record
 ///
 /// Additional picture-specific data after SHAPE_COMPONENT:
@@ -620,17 +935,28 @@ impl HwpTable {
         }
 
         HwpTable {
+            // Built tables keep the conventional header row
             info: TableInfo {
                 rows,
                 cols,
                 cell_count: rows * cols,
+                has_header_row: true,
                 ..Default::default()
             },
             cells,
         }
     }
 
-    /// Convert table to markdown
+    /// True when any cell has a background other than white
+    pub fn has_cell_colors(&self) -> bool {
+        self.cells.iter().flatten().any(|cell| cell.visible_background().is_some())
+    }
+
+    /// Convert table to markdown.
+    ///
+    /// The `---` separator follows row 0 only when
+    /// [`TableInfo::has_header_row`] is set. Cell backgrounds are dropped;
+    /// see [`HwpTable::to_markdown_with_config`].
     pub fn to_markdown(&self) -> String {
         if self.cells.is_empty() {
             return String::new();
@@ -672,7 +998,7 @@ impl HwpTable {
             lines.push(format!("| {} |", cell_contents.join(" | ")));
 
             // Add separator after header row
-            if row_idx == 0 {
+            if row_idx == 0 && self.info.has_header_row {
                 let sep: Vec<&str> = (0..cell_contents.len()).map(|_| "---").collect();
                 lines.push(format!("| {} |", sep.join(" | ")));
             }
@@ -680,6 +1006,61 @@ impl HwpTable {
 
         lines.join("\n")
     }
+
+    /// Convert table to Markdown honoring engine [`crate::Config`] options.
+    ///
+    /// With `preserve_table_colors` set and at least one colored cell, the
+    /// table is emitted via [`HwpTable::to_html`] — GFM pipe tables have no
+    /// per-cell styling. Otherwise identical to [`HwpTable::to_markdown`].
+    pub fn to_markdown_with_config(&self, config: &crate::Config) -> String {
+        if !config.preserve_table_colors || !self.has_cell_colors() || self.cells.is_empty() {
+            return self.to_markdown();
+        }
+        self.to_html()
+    }
+
+    /// Convert table to an HTML `<table>`.
+    ///
+    /// Merged cells carry `rowspan`/`colspan` and the cells they cover are
+    /// skipped; non-white backgrounds become
+    /// `style="background-color:#rrggbb"`.
+    pub fn to_html(&self) -> String {
+        let mut out = String::from("<table>\n");
+        let mut skip_cells: std::collections::HashSet<(usize, usize)> = std::collections::HashSet::new();
+
+        for (row_idx, row) in self.cells.iter().enumerate() {
+            out.push_str("<tr>");
+            for (col_idx, cell) in row.iter().enumerate() {
+                if skip_cells.contains(&(row_idx, col_idx)) {
+                    continue;
+                }
+                for r in 0..cell.row_span.max(1) as usize {
+                    for c in 0..cell.col_span.max(1) as usize {
+                        if r != 0 || c != 0 {
+                            skip_cells.insert((row_idx + r, col_idx + c));
+                        }
+                    }
+                }
+
+                out.push_str("<td");
+                if cell.row_span > 1 {
+                    out.push_str(&format!(" rowspan=\"{}\"", cell.row_span));
+                }
+                if cell.col_span > 1 {
+                    out.push_str(&format!(" colspan=\"{}\"", cell.col_span));
+                }
+                if let Some(color) = cell.visible_background() {
+                    out.push_str(&format!(" style=\"background-color:{}\"", css_hex_color(color)));
+                }
+                out.push('>');
+                out.push_str(&html_escape(cell.content.trim()).replace('\n', "<br>"));
+                out.push_str("</td>");
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("</table>");
+        out
+    }
 }
 
 /// Character style properties (matching HWPX CharStyle)
@@ -691,6 +1072,8 @@ pub struct CharShape {
     pub strikeout: bool,
     /// Font size in points (BaseSize / 100.0). 0.0 means unknown.
     pub font_size_pt: f32,
+    /// Text color as `0xRRGGBB`; `None` when the record is too short to carry it
+    pub color: Option<u32>,
 }
 
 /// Parse HWPTAG_CHAR_SHAPE record to extract character formatting
@@ -713,7 +1096,8 @@ pub struct CharShape {
 ///   - Bit 11: Superscript
 ///   - Bit 12: Subscript
 ///   - Bits 18-21: Strikeout type
-/// - ShadowGap: 2 bytes
+/// - ShadowGap: 2 bytes (INT8 x, INT8 y)
+/// - TextColor: 4 bytes (COLORREF, `0x00BBGGRR`)
 /// - ... more fields
 pub fn parse_char_shape(data: &[u8]) -> Option<CharShape> {
     // Minimum size: 7*2 + 7*1*4 + 4 + 4 = 14 + 28 + 8 = 50 bytes for basic fields
@@ -731,6 +1115,8 @@ pub fn parse_char_shape(data: &[u8]) -> Option<CharShape> {
     // Offset 35-41: Position (7 BYTEs)
     // Offset 42-45: BaseSize (INT32)
     // Offset 46-49: Attr (UINT32) <- formatting flags here
+    // Offset 50-51: ShadowGap (2 INT8)
+    // Offset 52-55: TextColor (COLORREF)
 
     if data.len() < 50 {
         return None;
@@ -749,15 +1135,602 @@ pub fn parse_char_shape(data: &[u8]) -> Option<CharShape> {
     let underline_type = (attr >> 2) & 0x03; // Bits 2-3
     let strikeout_type = (attr >> 18) & 0x0F; // Bits 18-21
 
+    // COLORREF is stored as R, G, B, 0 bytes
+    let color = data
+        .get(52..55)
+        .map(|rgb| ((rgb[0] as u32) << 16) | ((rgb[1] as u32) << 8) | rgb[2] as u32);
+
     Some(CharShape {
         bold,
         italic,
         underline: underline_type != 0,
         strikeout: strikeout_type != 0,
         font_size_pt,
+        color,
     })
 }
 
+/// Paragraph alignment (ParaShape properties1 bits 2-4)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum ParagraphAlignment {
+    /// 양쪽 정렬 (HWP default)
+    #[default]
+    Justify,
+    Left,
+    Right,
+    Center,
+    /// 배분 정렬
+    Distribute,
+    /// 나눔 정렬
+    Divide,
+}
+
+impl ParagraphAlignment {
+    /// Decode the 3-bit alignment field; unknown values fall back to `Justify`
+    pub fn from_bits(bits: u32) -> Self {
+        match bits & 0x07 {
+            1 => ParagraphAlignment::Left,
+            2 => ParagraphAlignment::Right,
+            3 => ParagraphAlignment::Center,
+            4 => ParagraphAlignment::Distribute,
+            5 => ParagraphAlignment::Divide,
+            _ => ParagraphAlignment::Justify,
+        }
+    }
+}
+
+/// Paragraph indentation and alignment from DocInfo HWPTAG_PARA_SHAPE
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParaShape {
+    /// Left margin in HWPUNIT
+    pub indent_left: i32,
+    /// Right margin in HWPUNIT
+    pub indent_right: i32,
+    /// First-line indent in HWPUNIT (negative = hanging indent)
+    pub indent_first: i32,
+    /// Paragraph alignment (properties1 bits 2-4)
+    pub alignment: ParagraphAlignment,
+}
+
+/// Parse HWPTAG_PARA_SHAPE record
+///
+/// HWP 5.0 PARA_SHAPE structure (leading fields):
+/// - UINT32: properties1 (bits 2-4: alignment)
+/// - INT32: left margin
+/// - INT32: right margin
+/// - INT32: first-line indent
+/// - INT32: spacing before / after, line spacing, ... (ignored)
+pub fn parse_para_shape(data: &[u8]) -> Option<ParaShape> {
+    let field = |offset: usize| -> Option<[u8; 4]> { data.get(offset..offset + 4)?.try_into().ok() };
+    let props1 = u32::from_le_bytes(field(0)?);
+    Some(ParaShape {
+        indent_left: i32::from_le_bytes(field(4)?),
+        indent_right: i32::from_le_bytes(field(8)?),
+        indent_first: i32::from_le_bytes(field(12)?),
+        alignment: ParagraphAlignment::from_bits(props1 >> 2),
+    })
+}
+
+/// 문단 번호의 번호 모양 (문단 머리 정보 속성 bits 5-8)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NumberShape {
+    /// 1, 2, 3
+    #[default]
+    Digit,
+    /// ①, ②, ③
+    CircledDigit,
+    /// I, II, III
+    RomanUpper,
+    /// i, ii, iii
+    RomanLower,
+    /// A, B, C
+    LatinUpper,
+    /// a, b, c
+    LatinLower,
+    /// Ⓐ, Ⓑ, Ⓒ
+    CircledLatinUpper,
+    /// ⓐ, ⓑ, ⓒ
+    CircledLatinLower,
+    /// 가, 나, 다
+    Ganada,
+    /// ㉮, ㉯, ㉰
+    CircledGanada,
+    /// ㄱ, ㄴ, ㄷ
+    Chosung,
+    /// ㉠, ㉡, ㉢
+    CircledChosung,
+    /// 일, 이, 삼
+    HangulDigit,
+    /// 一, 二, 三
+    Ideograph,
+    /// ㊀, ㊁, ㊂
+    CircledIdeograph,
+}
+
+impl NumberShape {
+    fn from_bits(value: u32) -> Self {
+        match value & 0x0F {
+            1 => NumberShape::CircledDigit,
+            2 => NumberShape::RomanUpper,
+            3 => NumberShape::RomanLower,
+            4 => NumberShape::LatinUpper,
+            5 => NumberShape::LatinLower,
+            6 => NumberShape::CircledLatinUpper,
+            7 => NumberShape::CircledLatinLower,
+            8 => NumberShape::Ganada,
+            9 => NumberShape::CircledGanada,
+            10 => NumberShape::Chosung,
+            11 => NumberShape::CircledChosung,
+            12 => NumberShape::HangulDigit,
+            13 => NumberShape::Ideograph,
+            14 => NumberShape::CircledIdeograph,
+            _ => NumberShape::Digit,
+        }
+    }
+
+    /// 1부터 시작하는 번호 `n`을 이 모양으로 표기한다.
+    /// 글자 수가 정해진 모양(가나다, ㄱㄴㄷ, 원문자 등)은 끝까지 가면 처음부터 순환하고,
+    /// 원문자 숫자는 20을 넘으면 `(21)`처럼 괄호 숫자로 쓴다.
+    pub fn format(self, n: u32) -> String {
+        const GANADA: [char; 14] = [
+            '가', '나', '다', '라', '마', '바', '사', '아', '자', '차', '카', '타', '파', '하',
+        ];
+        const CHOSUNG: [char; 14] = [
+            'ㄱ', 'ㄴ', 'ㄷ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅅ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+        ];
+        const HANGUL_DIGITS: [&str; 10] = ["일", "이", "삼", "사", "오", "육", "칠", "팔", "구", "십"];
+        const IDEOGRAPHS: [char; 10] = ['一', '二', '三', '四', '五', '六', '七', '八', '九', '十'];
+
+        let n = n.max(1);
+        let index = (n - 1) as usize;
+        let offset = |base: u32, len: u32| char::from_u32(base + (n - 1) % len).map(String::from);
+        let formatted = match self {
+            NumberShape::Digit => None,
+            NumberShape::CircledDigit if n <= 20 => offset(0x2460, 20),
+            NumberShape::CircledDigit => Some(format!("({})", n)),
+            NumberShape::RomanUpper => Some(roman_numeral(n)),
+            NumberShape::RomanLower => Some(roman_numeral(n).to_lowercase()),
+            NumberShape::LatinUpper => offset('A' as u32, 26),
+            NumberShape::LatinLower => offset('a' as u32, 26),
+            NumberShape::CircledLatinUpper => offset(0x24B6, 26),
+            NumberShape::CircledLatinLower => offset(0x24D0, 26),
+            NumberShape::Ganada => Some(GANADA[index % GANADA.len()].to_string()),
+            NumberShape::CircledGanada => offset(0x326E, 14),
+            NumberShape::Chosung => Some(CHOSUNG[index % CHOSUNG.len()].to_string()),
+            NumberShape::CircledChosung => offset(0x3260, 14),
+            NumberShape::HangulDigit => HANGUL_DIGITS.get(index).map(|d| d.to_string()),
+            NumberShape::Ideograph => IDEOGRAPHS.get(index).map(|c| c.to_string()),
+            NumberShape::CircledIdeograph if n <= 10 => offset(0x3280, 10),
+            NumberShape::CircledIdeograph => None,
+        };
+        formatted.unwrap_or_else(|| n.to_string())
+    }
+}
+
+/// 1 → I, 4 → IV, 1999 → MCMXCIX
+fn roman_numeral(mut n: u32) -> String {
+    const VALUES: [(u32, &str); 13] = [
+        (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"),
+        (100, "C"), (90, "XC"), (50, "L"), (40, "XL"),
+        (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+    ];
+    let mut out = String::new();
+    for (value, symbol) in VALUES {
+        while n >= value {
+            out.push_str(symbol);
+            n -= value;
+        }
+    }
+    out
+}
+
+/// 문단 번호 한 수준의 정의
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NumberingLevel {
+    /// 번호 모양
+    pub shape: NumberShape,
+    /// 번호 형식 문자열. `^1`~`^7`은 해당 수준의 번호로 치환된다 (예: `"^1."`, `"^2)"`)
+    pub format: String,
+}
+
+/// DocInfo HWPTAG_NUMBERING 문단 번호 정의
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NumberingDef {
+    /// 수준별 정의 (0 = 1수준)
+    pub levels: Vec<NumberingLevel>,
+    /// 시작 번호
+    pub start: u16,
+}
+
+impl NumberingDef {
+    /// `level`(0부터) 문단의 번호 표기를 만든다. `counters[i]`는 `i`수준의
+    /// 현재 번호(1부터)이며, 형식 문자열이 비어 있으면 `None`.
+    pub fn marker(&self, level: usize, counters: &[u32]) -> Option<String> {
+        let format = &self.levels.get(level)?.format;
+        if format.is_empty() {
+            return None;
+        }
+        let mut out = String::new();
+        let mut chars = format.chars().peekable();
+        while let Some(c) = chars.next() {
+            let target = match chars.peek() {
+                Some(&d @ '1'..='7') if c == '^' => d as usize - '1' as usize,
+                _ => {
+                    out.push(c);
+                    continue;
+                }
+            };
+            chars.next();
+            let shape = self.levels.get(target).map(|l| l.shape).unwrap_or_default();
+            out.push_str(&shape.format(counters.get(target).copied().unwrap_or(1)));
+        }
+        Some(out)
+    }
+}
+
+/// 문단 번호 수준 수 (HWP 5.0 기본 7수준)
+const NUMBERING_LEVELS: usize = 7;
+
+/// Parse HWPTAG_NUMBERING record
+///
+/// 수준마다 (7회 반복):
+/// - 문단 머리 정보 12바이트: UINT32 속성 (bits 0-1 정렬, bits 5-8 번호 모양),
+///   HWPUNIT16 너비 보정, HWPUNIT16 본문과의 거리, UINT32 글자 모양 ID
+/// - WORD 형식 문자열 길이 + WCHAR 형식 문자열
+///
+/// 그 뒤에 UINT16 시작 번호가 온다 (없으면 1). 첫 수준도 읽을 수 없으면 `None`,
+/// 뒤쪽 수준이 잘렸으면 읽은 수준까지만 돌려준다.
+pub fn parse_numbering_definition(data: &[u8]) -> Option<NumberingDef> {
+    let mut levels = Vec::new();
+    let mut pos = 0usize;
+    while levels.len() < NUMBERING_LEVELS {
+        let Some(head) = data.get(pos..pos + 14) else { break };
+        let props = u32::from_le_bytes([head[0], head[1], head[2], head[3]]);
+        let len = u16::from_le_bytes([head[12], head[13]]) as usize;
+        let Some(text) = data.get(pos + 14..pos + 14 + len * 2) else { break };
+        let units: Vec<u16> = text.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+        levels.push(NumberingLevel {
+            shape: NumberShape::from_bits(props >> 5),
+            format: String::from_utf16_lossy(&units),
+        });
+        pos += 14 + len * 2;
+    }
+    if levels.is_empty() {
+        return None;
+    }
+    let start = data
+        .get(pos..pos + 2)
+        .filter(|_| levels.len() == NUMBERING_LEVELS)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .unwrap_or(1);
+    Some(NumberingDef { levels, start })
+}
+
+/// Paragraph style from DocInfo HWPTAG_STYLE (e.g. 바탕글, 본문, 개요 1)
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ParagraphStyle {
+    /// Localized style name (한글 이름)
+    pub name: String,
+    /// English style name
+    pub english_name: String,
+    /// Markdown heading level (1-6) when the style is an outline/heading style
+    pub heading_level: Option<u8>,
+    /// Alignment of the style's ParaShape
+    pub alignment: ParagraphAlignment,
+    /// ParaShape ID referenced by the style
+    pub para_shape_id: u16,
+    /// CharShape ID referenced by the style
+    pub char_shape_id: u16,
+    /// True for character styles (properties bits 0-2 == 1)
+    pub is_char_style: bool,
+}
+
+/// Parse HWPTAG_STYLE record
+///
+/// HWP 5.0 STYLE structure:
+/// - WORD len + WCHAR[len]: local name
+/// - WORD len + WCHAR[len]: English name
+/// - BYTE: properties (bits 0-2: 0 = paragraph style, 1 = character style)
+/// - BYTE: next style ID
+/// - INT16: language ID
+/// - UINT16: ParaShape ID
+/// - UINT16: CharShape ID
+///
+/// `heading_level` is derived from the name only ("개요 N", "Outline N",
+/// "Heading N", "제목 N"); alignment is left at the default for the
+/// caller to resolve from the ParaShape table.
+pub fn parse_style(data: &[u8]) -> Option<ParagraphStyle> {
+    fn read_wstr(data: &[u8], pos: &mut usize) -> Option<String> {
+        let len = u16::from_le_bytes([*data.get(*pos)?, *data.get(*pos + 1)?]) as usize;
+        *pos += 2;
+        let bytes = data.get(*pos..*pos + len * 2)?;
+        *pos += len * 2;
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        Some(String::from_utf16_lossy(&units))
+    }
+
+    let mut pos = 0;
+    let name = read_wstr(data, &mut pos)?;
+    let english_name = read_wstr(data, &mut pos)?;
+    let tail = data.get(pos..pos + 8)?;
+    let properties = tail[0];
+    let para_shape_id = u16::from_le_bytes([tail[4], tail[5]]);
+    let char_shape_id = u16::from_le_bytes([tail[6], tail[7]]);
+
+    let heading_level = style_heading_level(&name).or_else(|| style_heading_level(&english_name));
+
+    Some(ParagraphStyle {
+        name,
+        english_name,
+        heading_level,
+        alignment: ParagraphAlignment::default(),
+        para_shape_id,
+        char_shape_id,
+        is_char_style: properties & 0x07 == 1,
+    })
+}
+
+/// Parse HWPTAG_FACE_NAME record (글꼴 이름)
+///
+/// HWP 5.0 FACE_NAME structure (leading fields):
+/// - BYTE: properties (대체 글꼴 / 글꼴 유형 정보 / 기본 글꼴 존재 여부)
+/// - WORD len + WCHAR[len]: face name
+/// - ... substitute font, type info and default font (ignored)
+///
+/// Blank names yield `None`.
+pub fn parse_face_name(data: &[u8]) -> Option<String> {
+    let len = u16::from_le_bytes([*data.get(1)?, *data.get(2)?]) as usize;
+    let bytes = data.get(3..3 + len * 2)?;
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+    let name = String::from_utf16_lossy(&units);
+    let name = name.trim_end_matches('\0').trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// 차례 (table of contents) 항목
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TocEntry {
+    /// Linked heading text
+    pub text: String,
+    /// Heading level, 1 (H1) to 6 (H6)
+    pub level: u8,
+    /// Index of the target paragraph within the body
+    pub paragraph: u32,
+}
+
+/// 차례 컨트롤 ID (`toc `, stored byte-reversed in the file)
+pub fn is_toc_ctrl_id(id: &[u8]) -> bool {
+    id == b"toc " || id == b" cot"
+}
+
+/// Parse the CTRL_HEADER payload of a `toc ` entry (차례 항목)
+///
+/// Layout:
+/// - UINT32: ctrl id (`toc `)
+/// - UINT32: properties, bits 0-2 = heading level (1-6)
+/// - UINT32: target paragraph index
+/// - WORD len + WCHAR[len]: heading text
+///
+/// Levels outside 1-6 are clamped. Other ctrl ids, truncated payloads and
+/// blank headings yield `None`.
+pub fn parse_toc_ctrl(ctrl_data: &[u8]) -> Option<TocEntry> {
+    if !is_toc_ctrl_id(ctrl_data.get(0..4)?) {
+        return None;
+    }
+    let u32_at = |at: usize| ctrl_data.get(at..at + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]));
+    let level = (u32_at(4)? & 0x07).clamp(1, 6) as u8;
+    let paragraph = u32_at(8)?;
+    let len = u16::from_le_bytes([*ctrl_data.get(12)?, *ctrl_data.get(13)?]) as usize;
+    let bytes = ctrl_data.get(14..14 + len * 2)?;
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+    let text = String::from_utf16_lossy(&units);
+    let text = text.trim_end_matches('\0').trim();
+    (!text.is_empty()).then(|| TocEntry { text: text.to_string(), level, paragraph })
+}
+
+/// 문서 이력 항목 (one saved revision from the `DocHistory` stream)
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct RevisionEntry {
+    /// Revision number as stored, 1 for the first save
+    pub revision_number: u32,
+    /// User who saved the revision
+    pub author: String,
+    /// Save time, Unix epoch seconds
+    pub timestamp: u64,
+    /// 변경 내용 요약 (description entered when saving the revision)
+    pub change_summary: String,
+}
+
+/// Smallest entry: revision number, timestamp and two empty strings
+const REVISION_ENTRY_MIN_SIZE: usize = 4 + 8 + 2 + 2;
+
+/// Parse the `DocHistory` stream (문서 이력)
+///
+/// Layout:
+/// - UINT32: entry count
+/// - per entry:
+///   - UINT32: revision number
+///   - UINT64: save time (Unix epoch seconds)
+///   - WORD len + WCHAR[len]: author
+///   - WORD len + WCHAR[len]: change summary
+///
+/// Entries are returned in stream order. A stream that ends before the
+/// counted entries do fails with `InvalidData`; bytes after them are ignored.
+pub fn parse_doc_history(data: &[u8]) -> io::Result<Vec<RevisionEntry>> {
+    let mut pos = 0;
+    let count = u32::from_le_bytes(history_take::<4>(data, &mut pos)?) as usize;
+    // The count comes from the file: bound the allocation by what can fit
+    let mut entries = Vec::with_capacity(count.min(data.len() / REVISION_ENTRY_MIN_SIZE));
+    for _ in 0..count {
+        let revision_number = u32::from_le_bytes(history_take::<4>(data, &mut pos)?);
+        let timestamp = u64::from_le_bytes(history_take::<8>(data, &mut pos)?);
+        let author = history_string(data, &mut pos)?;
+        let change_summary = history_string(data, &mut pos)?;
+        entries.push(RevisionEntry { revision_number, author, timestamp, change_summary });
+    }
+    Ok(entries)
+}
+
+/// Next `N` bytes of a `DocHistory` stream
+fn history_take<const N: usize>(data: &[u8], pos: &mut usize) -> io::Result<[u8; N]> {
+    let bytes = data
+        .get(*pos..*pos + N)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "truncated DocHistory stream"))?;
+    *pos += N;
+    let mut out = [0u8; N];
+    out.copy_from_slice(bytes);
+    Ok(out)
+}
+
+/// WORD len + WCHAR[len] string of a `DocHistory` stream, NUL padding trimmed
+fn history_string(data: &[u8], pos: &mut usize) -> io::Result<String> {
+    let len = u16::from_le_bytes(history_take::<2>(data, pos)?) as usize;
+    let bytes = data
+        .get(*pos..*pos + len * 2)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "truncated DocHistory string"))?;
+    *pos += len * 2;
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+    Ok(String::from_utf16_lossy(&units).trim_end_matches('\0').to_string())
+}
+
+/// Heading level from a style name such as "개요 3" or "Heading 2" (clamped to 6)
+fn style_heading_level(name: &str) -> Option<u8> {
+    let name = name.trim();
+    let rest = ["개요", "Outline", "Heading", "제목"]
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix))?;
+    let level: u8 = rest.trim().parse().ok()?;
+    (level >= 1).then_some(level.min(6))
+}
+
+/// Line height assumed when estimating lines per page: 10pt text at
+/// 160% line spacing, in HWPUNIT (1pt = 100)
+const DEFAULT_LINE_HEIGHT: u32 = 1600;
+
+/// Page layout from a section's HWPTAG_PAGE_DEF record (HWPUNIT)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageDef {
+    pub width: u32,
+    pub height: u32,
+    pub margin_left: u32,
+    pub margin_right: u32,
+    pub margin_top: u32,
+    pub margin_bottom: u32,
+    pub margin_header: u32,
+    pub margin_footer: u32,
+    /// 제본 여백
+    pub margin_gutter: u32,
+    /// 용지 방향 넓게 (property bit 0)
+    pub landscape: bool,
+}
+
+/// 용지 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PageOrientation {
+    Portrait,
+    Landscape,
+}
+
+/// HWPUNIT (1/7200 inch) → millimetres
+pub fn hwpunit_to_mm(value: u32) -> f64 {
+    value as f64 * 25.4 / 7200.0
+}
+
+impl PageDef {
+    /// Paper `(width, height)` as laid out: the stored size is swapped when
+    /// the landscape bit is set
+    pub fn paper_size(&self) -> (u32, u32) {
+        if self.landscape {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        }
+    }
+
+    /// Landscape when the laid-out paper is wider than it is tall
+    pub fn orientation(&self) -> PageOrientation {
+        let (width, height) = self.paper_size();
+        if width > height {
+            PageOrientation::Landscape
+        } else {
+            PageOrientation::Portrait
+        }
+    }
+
+    /// Laid-out paper width in mm
+    pub fn paper_width_mm(&self) -> f64 {
+        hwpunit_to_mm(self.paper_size().0)
+    }
+
+    /// Laid-out paper height in mm
+    pub fn paper_height_mm(&self) -> f64 {
+        hwpunit_to_mm(self.paper_size().1)
+    }
+
+    /// Vertical space left for body text once margins, header and footer
+    /// are taken off the (orientation-adjusted) paper height
+    pub fn body_height(&self) -> u32 {
+        let paper = self.paper_size().1;
+        paper
+            .saturating_sub(self.margin_top + self.margin_bottom)
+            .saturating_sub(self.margin_header + self.margin_footer)
+    }
+
+    /// Lines of default-sized text that fit on one page (at least 1)
+    pub fn lines_per_page(&self) -> u32 {
+        (self.body_height() / DEFAULT_LINE_HEIGHT).max(1)
+    }
+}
+
+/// Parse HWPTAG_PAGE_DEF record
+///
+/// HWP 5.0 PAGE_DEF structure (40 bytes, all UINT32 HWPUNIT):
+/// paper width, paper height, left/right/top/bottom margins,
+/// header/footer margins, gutter, properties
+pub fn parse_page_def(data: &[u8]) -> Option<PageDef> {
+    let field = |i: usize| -> Option<u32> {
+        let bytes = data.get(i * 4..i * 4 + 4)?;
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    };
+
+    Some(PageDef {
+        width: field(0)?,
+        height: field(1)?,
+        margin_left: field(2)?,
+        margin_right: field(3)?,
+        margin_top: field(4)?,
+        margin_bottom: field(5)?,
+        margin_header: field(6)?,
+        margin_footer: field(7)?,
+        margin_gutter: field(8)?,
+        landscape: field(9)? & 1 == 1,
+    })
+}
+
+/// Vertical positions of each line segment in a HWPTAG_PARA_LINE_SEG record
+///
+/// Each segment is 36 bytes; the INT32 at offset 4 is the line's vertical
+/// position relative to the top of its page, so it drops back when the
+/// text continues on a new page.
+pub fn parse_line_seg_positions(data: &[u8]) -> Vec<i32> {
+    data.chunks_exact(36)
+        .map(|seg| i32::from_le_bytes([seg[4], seg[5], seg[6], seg[7]]))
+        .collect()
+}
+
 /// Character shape mapping for a paragraph
 /// Maps text positions to character shape IDs
 #[derive(Debug, Clone)]
@@ -788,8 +1761,11 @@ pub fn parse_para_char_shape(data: &[u8]) -> Option<ParaCharShapeMapping> {
     Some(ParaCharShapeMapping { mappings })
 }
 
-/// Apply Markdown formatting based on CharShape
-pub fn apply_markdown_formatting(text: &str, style: &CharShape) -> String {
+/// Apply Markdown formatting based on CharShape.
+///
+/// `color_spans` wraps non-black text in `<span style="color:#rrggbb">`;
+/// plain-markdown output turns it off.
+pub fn apply_markdown_formatting(text: &str, style: &CharShape, color_spans: bool) -> String {
     if text.is_empty() {
         return String::new();
     }
@@ -815,15 +1791,21 @@ pub fn apply_markdown_formatting(text: &str, style: &CharShape) -> String {
         // Markdown doesn't have native underline, use HTML
         result = format!("<u>{}</u>", result);
     }
+    // Black is the default text color; only other colors get a span
+    if let Some(color) = style.color.filter(|&c| color_spans && c != 0) {
+        result = format!("<span style=\"color:#{:06x}\">{}</span>", color & 0xFF_FFFF, result);
+    }
 
     result
 }
 
-/// Extract text from PARA_TEXT with formatting applied
+/// Extract text from PARA_TEXT with formatting applied (see
+/// [`apply_markdown_formatting`] for `color_spans`)
 pub fn extract_para_text_formatted(
     text_data: &[u8],
     char_shape_mapping: Option<&ParaCharShapeMapping>,
     char_shapes: &HashMap<u32, CharShape>,
+    color_spans: bool,
 ) -> String {
     // First, extract raw text with positions
     let text_with_positions = extract_para_text_with_positions(text_data);
@@ -851,7 +1833,7 @@ pub fn extract_para_text_formatted(
         if current_style_id != style_id && !current_run.is_empty() {
             let formatted = if let Some(id) = current_style_id {
                 if let Some(style) = char_shapes.get(&id) {
-                    apply_markdown_formatting(&current_run, style)
+                    apply_markdown_formatting(&current_run, style, color_spans)
                 } else {
                     current_run.clone()
                 }
@@ -870,7 +1852,7 @@ pub fn extract_para_text_formatted(
     if !current_run.is_empty() {
         let formatted = if let Some(id) = current_style_id {
             if let Some(style) = char_shapes.get(&id) {
-                apply_markdown_formatting(&current_run, style)
+                apply_markdown_formatting(&current_run, style, color_spans)
             } else {
                 current_run.clone()
             }
@@ -985,6 +1967,74 @@ fn extract_para_text_with_positions(data: &[u8]) -> Vec<(u32, char)> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_page_def() {
+        // A4 portrait: 210×297mm, 30mm side / 20mm top-bottom / 15mm header-footer margins
+        let fields: [u32; 10] = [59528, 84188, 8504, 8504, 5668, 5668, 4252, 4252, 0, 0];
+        let data: Vec<u8> = fields.iter().flat_map(|f| f.to_le_bytes()).collect();
+
+        let page = parse_page_def(&data).unwrap();
+        assert!(!page.landscape);
+        assert_eq!(page.body_height(), 84188 - 5668 * 2 - 4252 * 2);
+        assert_eq!(page.lines_per_page(), 40);
+
+        let mut landscape = data.clone();
+        landscape[36] = 1;
+        let page = parse_page_def(&landscape).unwrap();
+        assert!(page.landscape);
+        assert_eq!(page.lines_per_page(), (59528 - 5668 * 2 - 4252 * 2) / 1600);
+
+        assert!(parse_page_def(&data[..36]).is_none());
+    }
+
+    #[test]
+    fn test_page_def_a4_in_mm() {
+        // 한글 기본 A4: 좌우 30mm, 위 20mm, 아래 15mm, 머리말/꼬리말 15mm, 제본 0
+        let fields: [u32; 10] = [59528, 84188, 8504, 8504, 5668, 4252, 4252, 4252, 0, 0];
+        let data: Vec<u8> = fields.iter().flat_map(|f| f.to_le_bytes()).collect();
+        let page = parse_page_def(&data).unwrap();
+
+        assert_eq!(page.orientation(), PageOrientation::Portrait);
+        assert!((page.paper_width_mm() - 210.0).abs() < 0.01, "{}", page.paper_width_mm());
+        assert!((page.paper_height_mm() - 297.0).abs() < 0.01, "{}", page.paper_height_mm());
+        assert!((hwpunit_to_mm(page.margin_left) - 30.0).abs() < 0.01);
+        assert!((hwpunit_to_mm(page.margin_bottom) - 15.0).abs() < 0.01);
+        assert_eq!(page.margin_gutter, 0);
+    }
+
+    #[test]
+    fn test_page_def_us_letter_landscape() {
+        // US Letter 8.5×11in, 1in margins, 0.5in gutter, 용지 방향 넓게
+        let fields: [u32; 10] = [61200, 79200, 7200, 7200, 7200, 7200, 3600, 3600, 3600, 1];
+        let data: Vec<u8> = fields.iter().flat_map(|f| f.to_le_bytes()).collect();
+        let page = parse_page_def(&data).unwrap();
+
+        assert_eq!(page.paper_size(), (79200, 61200));
+        assert_eq!(page.orientation(), PageOrientation::Landscape);
+        assert!((page.paper_width_mm() - 279.4).abs() < 0.01);
+        assert!((page.paper_height_mm() - 215.9).abs() < 0.01);
+        assert!((hwpunit_to_mm(page.margin_gutter) - 12.7).abs() < 0.01);
+
+        // Landscape dimensions stored as-is, without the property bit
+        let mut wide = fields;
+        wide.swap(0, 1);
+        wide[9] = 0;
+        let data: Vec<u8> = wide.iter().flat_map(|f| f.to_le_bytes()).collect();
+        assert_eq!(parse_page_def(&data).unwrap().orientation(), PageOrientation::Landscape);
+    }
+
+    #[test]
+    fn test_parse_line_seg_positions() {
+        let mut data = Vec::new();
+        for pos in [0i32, 1600, 3200] {
+            let mut seg = [0u8; 36];
+            seg[4..8].copy_from_slice(&pos.to_le_bytes());
+            data.extend_from_slice(&seg);
+        }
+        data.extend_from_slice(&[0u8; 10]); // trailing partial segment is ignored
+        assert_eq!(parse_line_seg_positions(&data), vec![0, 1600, 3200]);
+    }
+
     #[test]
     fn test_record_header_parsing() {
         // Create a simple record: tag=0x43 (PARA_TEXT), level=0, size=4
@@ -1002,6 +2052,63 @@ mod tests {
         assert_eq!(record.size, 4);
     }
 
+    fn record(tag: u16, payload: &[u8]) -> Vec<u8> {
+        let header = tag as u32 | ((payload.len() as u32) << 20);
+        let mut data = header.to_le_bytes().to_vec();
+        data.extend_from_slice(payload);
+        data
+    }
+
+    #[test]
+    fn test_strict_mode_stops_at_truncated_record() {
+        let mut data = record(HWPTAG_PARA_HEADER, b"abcd");
+        // Declares 100 bytes, carries 4
+        data.extend_from_slice(&(HWPTAG_PARA_TEXT as u32 | (100 << 20)).to_le_bytes());
+        data.extend_from_slice(b"oops");
+        data.extend(record(HWPTAG_PARA_TEXT, b"late"));
+
+        let mut parser = RecordParser::new(&data);
+        assert_eq!(parser.mode(), ParseMode::Strict);
+        let (records, warnings) = parser.parse_all_with_stats();
+        assert_eq!(records.len(), 1);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_recovery_mode_resyncs_on_next_header() {
+        let mut data = record(HWPTAG_PARA_HEADER, b"abcd");
+        data.extend_from_slice(&(HWPTAG_PARA_TEXT as u32 | (100 << 20)).to_le_bytes());
+        // Garbage whose words look like oversized or out-of-range headers
+        data.extend_from_slice(&[0xFF; 8]);
+        data.extend(record(HWPTAG_PARA_TEXT, b"late"));
+
+        let (records, warnings) = RecordParser::with_recovery(&data).parse_all_with_stats();
+        let tags: Vec<u16> = records.iter().map(|r| r.tag_id).collect();
+        assert_eq!(tags, vec![HWPTAG_PARA_HEADER, HWPTAG_PARA_TEXT]);
+        assert_eq!(records[1].data, b"late");
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].offset, 8);
+        assert_eq!(warnings[0].tag_id, HWPTAG_PARA_TEXT);
+        assert_eq!(warnings[0].declared_size, 100);
+        assert_eq!(warnings[0].available, 16);
+        assert_eq!(warnings[0].resumed_at, Some(20));
+    }
+
+    #[test]
+    fn test_recovery_mode_without_later_header() {
+        let mut data = record(HWPTAG_PARA_HEADER, b"abcd");
+        // Extended size (0xFFF) with the size word cut off
+        data.extend_from_slice(&(HWPTAG_PARA_TEXT as u32 | (0xFFF << 20)).to_le_bytes());
+
+        let mut parser = RecordParser::with_recovery(&data);
+        assert_eq!(parser.parse_all().len(), 1);
+        assert_eq!(parser.warnings().len(), 1);
+        assert_eq!(parser.warnings()[0].declared_size, 0);
+        assert_eq!(parser.warnings()[0].resumed_at, None);
+        assert!(parser.warnings()[0].to_string().contains("no later record header"));
+    }
+
     #[test]
     fn test_extract_para_text() {
         // UTF-16LE "Hello"
@@ -1051,6 +2158,30 @@ mod tests {
         assert_eq!(text2, "XY", "0x19 control char should skip 14 bytes payload");
     }
 
+    #[test]
+    fn test_parse_border_fill_solid_background() {
+        let mut data = vec![0u8; 48];
+        data[32] = 0x01; // fill type: solid color
+        data[36..40].copy_from_slice(&[0xFF, 0x00, 0x00, 0x00]); // COLORREF red
+        let fill = parse_border_fill(&data).unwrap();
+        assert_eq!(fill.background_color, Some(0xFFFF0000));
+
+        // No solid-fill bit → no background even if the color bytes are set
+        data[32] = 0x00;
+        assert_eq!(parse_border_fill(&data).unwrap().background_color, None);
+
+        assert!(parse_border_fill(&data[..20]).is_none());
+    }
+
+    #[test]
+    fn test_parse_cell_border_fill_id() {
+        let mut data = vec![0u8; 34];
+        assert_eq!(parse_cell_border_fill_id(&data), None, "id 0 = no fill");
+        data[32] = 3;
+        assert_eq!(parse_cell_border_fill_id(&data), Some(3));
+        assert_eq!(parse_cell_border_fill_id(&data[..16]), None);
+    }
+
     #[test]
     fn test_parse_char_shape() {
         // Create a minimal CHAR_SHAPE record data (50 bytes)
@@ -1101,6 +2232,100 @@ mod tests {
         assert!(shape.strikeout);
     }
 
+    fn para_shape_record(props1: u32, left: i32, right: i32, first: i32) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&props1.to_le_bytes());
+        data.extend_from_slice(&left.to_le_bytes());
+        data.extend_from_slice(&right.to_le_bytes());
+        data.extend_from_slice(&first.to_le_bytes());
+        // spacing before/after and line spacing
+        data.extend_from_slice(&[0u8; 12]);
+        data
+    }
+
+    #[test]
+    fn test_parse_para_shape() {
+        // alignment 3 (center) in bits 2-4
+        let data = para_shape_record(3 << 2, 1440, 200, -600);
+        let shape = parse_para_shape(&data).unwrap();
+        assert_eq!(shape.indent_left, 1440);
+        assert_eq!(shape.indent_right, 200);
+        assert_eq!(shape.indent_first, -600);
+        assert_eq!(shape.alignment, ParagraphAlignment::Center);
+
+        let plain = parse_para_shape(&para_shape_record(0, 0, 0, 0)).unwrap();
+        assert_eq!(plain, ParaShape::default());
+    }
+
+    /// HWPTAG_NUMBERING record with one `(shape, format)` per level and the
+    /// remaining levels empty
+    fn numbering_record(levels: &[(u32, &str)], start: u16) -> Vec<u8> {
+        let mut data = Vec::new();
+        for i in 0..7 {
+            let (shape, format) = levels.get(i).copied().unwrap_or((0, ""));
+            data.extend_from_slice(&(shape << 5).to_le_bytes());
+            data.extend_from_slice(&[0u8; 8]);
+            let units: Vec<u16> = format.encode_utf16().collect();
+            data.extend_from_slice(&(units.len() as u16).to_le_bytes());
+            for unit in units {
+                data.extend_from_slice(&unit.to_le_bytes());
+            }
+        }
+        data.extend_from_slice(&start.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_parse_numbering_definition_arabic() {
+        let def = parse_numbering_definition(&numbering_record(&[(0, "^1."), (8, "^2."), (0, "^1.^3)")], 1)).unwrap();
+        assert_eq!(def.levels.len(), 7);
+        assert_eq!(def.start, 1);
+        assert_eq!(def.levels[1], NumberingLevel { shape: NumberShape::Ganada, format: "^2.".to_string() });
+        assert_eq!(def.marker(0, &[3]).as_deref(), Some("3."));
+        assert_eq!(def.marker(1, &[1, 2]).as_deref(), Some("나."));
+        assert_eq!(def.marker(2, &[2, 1, 4]).as_deref(), Some("2.4)"));
+        assert_eq!(def.marker(3, &[1, 1, 1, 1]), None, "empty format");
+    }
+
+    #[test]
+    fn test_parse_numbering_definition_korean_styles() {
+        let def = parse_numbering_definition(&numbering_record(&[(1, "^1"), (9, "^2"), (10, "^3.")], 5)).unwrap();
+        assert_eq!(def.start, 5);
+        assert_eq!(def.marker(0, &[1]).as_deref(), Some("①"));
+        assert_eq!(def.marker(0, &[3]).as_deref(), Some("③"));
+        assert_eq!(def.marker(1, &[1, 2]).as_deref(), Some("㉯"));
+        assert_eq!(def.marker(2, &[1, 1, 3]).as_deref(), Some("ㄷ."));
+    }
+
+    #[test]
+    fn test_number_shape_format() {
+        assert_eq!(NumberShape::CircledDigit.format(20), "⑳");
+        assert_eq!(NumberShape::CircledDigit.format(21), "(21)");
+        assert_eq!(NumberShape::RomanLower.format(14), "xiv");
+        assert_eq!(NumberShape::LatinUpper.format(27), "A");
+        assert_eq!(NumberShape::Ganada.format(15), "가");
+        assert_eq!(NumberShape::HangulDigit.format(10), "십");
+        assert_eq!(NumberShape::HangulDigit.format(11), "11");
+        assert_eq!(NumberShape::CircledIdeograph.format(2), "㊁");
+    }
+
+    #[test]
+    fn test_parse_numbering_definition_truncated() {
+        assert!(parse_numbering_definition(&[]).is_none());
+        let data = numbering_record(&[(0, "^1."), (0, "^2)")], 3);
+        // Cut inside the second level's format string
+        let def = parse_numbering_definition(&data[..14 + 6 + 14 + 2]).unwrap();
+        assert_eq!(def.levels.len(), 1);
+        assert_eq!(def.start, 1);
+    }
+
+    #[test]
+    fn test_parse_para_shape_too_short() {
+        assert!(parse_para_shape(&[]).is_none());
+        assert!(parse_para_shape(&para_shape_record(0, 720, 0, 0)[..15]).is_none());
+        assert!(parse_para_shape(&para_shape_record(0, 720, 0, 0)[..16]).is_some());
+    }
+
     #[test]
     fn test_parse_para_char_shape() {
         // Create mapping: position 0 -> shape 0, position 5 -> shape 1
@@ -1120,28 +2345,56 @@ mod tests {
     #[test]
     fn test_apply_markdown_formatting() {
         // Test bold
-        let style = CharShape { bold: true, italic: false, underline: false, strikeout: false, font_size_pt: 0.0 };
-        assert_eq!(apply_markdown_formatting("테스트", &style), "**테스트**");
+        let style = CharShape { bold: true, italic: false, underline: false, strikeout: false, font_size_pt: 0.0, color: None };
+        assert_eq!(apply_markdown_formatting("테스트", &style, true), "**테스트**");
 
         // Test italic
-        let style = CharShape { bold: false, italic: true, underline: false, strikeout: false, font_size_pt: 0.0 };
-        assert_eq!(apply_markdown_formatting("테스트", &style), "*테스트*");
+        let style = CharShape { bold: false, italic: true, underline: false, strikeout: false, font_size_pt: 0.0, color: None };
+        assert_eq!(apply_markdown_formatting("테스트", &style, true), "*테스트*");
 
         // Test bold+italic
-        let style = CharShape { bold: true, italic: true, underline: false, strikeout: false, font_size_pt: 0.0 };
-        assert_eq!(apply_markdown_formatting("테스트", &style), "***테스트***");
+        let style = CharShape { bold: true, italic: true, underline: false, strikeout: false, font_size_pt: 0.0, color: None };
+        assert_eq!(apply_markdown_formatting("테스트", &style, true), "***테스트***");
 
         // Test underline
-        let style = CharShape { bold: false, italic: false, underline: true, strikeout: false, font_size_pt: 0.0 };
-        assert_eq!(apply_markdown_formatting("테스트", &style), "<u>테스트</u>");
+        let style = CharShape { bold: false, italic: false, underline: true, strikeout: false, font_size_pt: 0.0, color: None };
+        assert_eq!(apply_markdown_formatting("테스트", &style, true), "<u>테스트</u>");
 
         // Test strikeout
-        let style = CharShape { bold: false, italic: false, underline: false, strikeout: true, font_size_pt: 0.0 };
-        assert_eq!(apply_markdown_formatting("테스트", &style), "~~테스트~~");
+        let style = CharShape { bold: false, italic: false, underline: false, strikeout: true, font_size_pt: 0.0, color: None };
+        assert_eq!(apply_markdown_formatting("테스트", &style, true), "~~테스트~~");
 
         // Test combined: bold + strikeout
-        let style = CharShape { bold: true, italic: false, underline: false, strikeout: true, font_size_pt: 0.0 };
-        assert_eq!(apply_markdown_formatting("테스트", &style), "**~~테스트~~**");
+        let style = CharShape { bold: true, italic: false, underline: false, strikeout: true, font_size_pt: 0.0, color: None };
+        assert_eq!(apply_markdown_formatting("테스트", &style, true), "**~~테스트~~**");
+
+        // Test color: black is left alone, other colors get a span
+        let style = CharShape { color: Some(0x000000), ..Default::default() };
+        assert_eq!(apply_markdown_formatting("테스트", &style, true), "테스트");
+        let style = CharShape { bold: true, color: Some(0xFF0000), ..Default::default() };
+        assert_eq!(
+            apply_markdown_formatting("테스트", &style, true),
+            "<span style=\"color:#ff0000\">**테스트**</span>"
+        );
+        // Plain-markdown output keeps the other formatting
+        assert_eq!(apply_markdown_formatting("테스트", &style, false), "**테스트**");
+    }
+
+    #[test]
+    fn test_parse_char_shape_color() {
+        let mut data = vec![0u8; 72];
+        data[42..46].copy_from_slice(&1000i32.to_le_bytes());
+        // COLORREF 0x00BBGGRR = R 0x12, G 0x34, B 0x56
+        data[52..56].copy_from_slice(&0x0056_3412u32.to_le_bytes());
+        let shape = parse_char_shape(&data).unwrap();
+        assert_eq!(shape.color, Some(0x123456));
+        assert_eq!(shape.font_size_pt, 10.0);
+
+        data[52..56].copy_from_slice(&[0x00, 0x00, 0xFF, 0x00]);
+        assert_eq!(parse_char_shape(&data).unwrap().color, Some(0x0000FF));
+
+        // Record ending before TextColor: no color
+        assert_eq!(parse_char_shape(&data[..50]).unwrap().color, None);
     }
 
     #[test]
@@ -1156,14 +2409,279 @@ mod tests {
         // Create char shapes: 0 = normal, 1 = bold
         let mut char_shapes = HashMap::new();
         char_shapes.insert(0, CharShape::default());
-        char_shapes.insert(1, CharShape { bold: true, italic: false, underline: false, strikeout: false, font_size_pt: 0.0 });
+        char_shapes.insert(1, CharShape { bold: true, italic: false, underline: false, strikeout: false, font_size_pt: 0.0, color: None });
 
         // Mapping: position 0-5 = shape 0 (normal), position 6+ = shape 1 (bold)
         let mapping = ParaCharShapeMapping {
             mappings: vec![(0, 0), (6, 1)],
         };
 
-        let result = extract_para_text_formatted(&text_data, Some(&mapping), &char_shapes);
+        let result = extract_para_text_formatted(&text_data, Some(&mapping), &char_shapes, true);
         assert_eq!(result, "Hello**World**");
     }
+
+    fn style_record(name: &str, english: &str, properties: u8, para_shape_id: u16) -> Vec<u8> {
+        let mut data = Vec::new();
+        for s in [name, english] {
+            let units: Vec<u16> = s.encode_utf16().collect();
+            data.extend_from_slice(&(units.len() as u16).to_le_bytes());
+            data.extend(units.iter().flat_map(|u| u.to_le_bytes()));
+        }
+        data.push(properties);
+        data.push(0); // next style
+        data.extend_from_slice(&0x0412i16.to_le_bytes()); // ko-KR
+        data.extend_from_slice(&para_shape_id.to_le_bytes());
+        data.extend_from_slice(&3u16.to_le_bytes()); // char shape
+        data
+    }
+
+    #[test]
+    fn test_parse_style_outline_heading() {
+        let style = parse_style(&style_record("개요 2", "Outline 2", 0, 5)).unwrap();
+        assert_eq!(style.name, "개요 2");
+        assert_eq!(style.english_name, "Outline 2");
+        assert_eq!(style.heading_level, Some(2));
+        assert_eq!(style.para_shape_id, 5);
+        assert_eq!(style.char_shape_id, 3);
+        assert!(!style.is_char_style);
+    }
+
+    #[test]
+    fn test_parse_style_body_and_clamping() {
+        let body = parse_style(&style_record("바탕글", "Normal", 0, 0)).unwrap();
+        assert_eq!(body.heading_level, None);
+
+        // English name is the fallback; levels past 6 clamp to H6
+        let deep = parse_style(&style_record("사용자", "Outline 7", 0, 0)).unwrap();
+        assert_eq!(deep.heading_level, Some(6));
+
+        let char_style = parse_style(&style_record("강조", "Emphasis", 1, 0)).unwrap();
+        assert!(char_style.is_char_style);
+    }
+
+    fn face_name_record(name: &str) -> Vec<u8> {
+        let units: Vec<u16> = name.encode_utf16().collect();
+        let mut data = vec![0x21]; // has substitute font + type info
+        data.extend_from_slice(&(units.len() as u16).to_le_bytes());
+        data.extend(units.iter().flat_map(|u| u.to_le_bytes()));
+        // substitute font: type BYTE + WORD len + WCHAR[len]
+        data.push(1);
+        data.extend_from_slice(&5u16.to_le_bytes());
+        data.extend("Arial".encode_utf16().flat_map(|u| u.to_le_bytes()));
+        data
+    }
+
+    #[test]
+    fn test_parse_face_name() {
+        assert_eq!(parse_face_name(&face_name_record("함초롬바탕")).as_deref(), Some("함초롬바탕"));
+        assert_eq!(parse_face_name(&face_name_record("Times New Roman")).as_deref(), Some("Times New Roman"));
+        assert_eq!(parse_face_name(&face_name_record("  ")), None);
+
+        let data = face_name_record("맑은 고딕");
+        assert!(parse_face_name(&data[..6]).is_none());
+        assert!(parse_face_name(&[0x00]).is_none());
+    }
+
+    fn toc_ctrl(id: &[u8; 4], properties: u32, paragraph: u32, text: &str) -> Vec<u8> {
+        let mut data = id.to_vec();
+        data.extend_from_slice(&properties.to_le_bytes());
+        data.extend_from_slice(&paragraph.to_le_bytes());
+        let units: Vec<u16> = text.encode_utf16().collect();
+        data.extend_from_slice(&(units.len() as u16).to_le_bytes());
+        data.extend(units.iter().flat_map(|u| u.to_le_bytes()));
+        data
+    }
+
+    #[test]
+    fn test_parse_toc_ctrl() {
+        let entry = parse_toc_ctrl(&toc_ctrl(b" cot", 2, 17, "제1장 개요")).unwrap();
+        assert_eq!(entry, TocEntry { text: "제1장 개요".to_string(), level: 2, paragraph: 17 });
+        // Unreversed id, upper property bits ignored
+        let entry = parse_toc_ctrl(&toc_ctrl(b"toc ", 0xF0 | 3, 0, "Scope")).unwrap();
+        assert_eq!((entry.level, entry.paragraph), (3, 0));
+    }
+
+    #[test]
+    fn test_parse_toc_ctrl_clamps_level() {
+        assert_eq!(parse_toc_ctrl(&toc_ctrl(b" cot", 0, 1, "A")).unwrap().level, 1);
+        assert_eq!(parse_toc_ctrl(&toc_ctrl(b" cot", 7, 1, "A")).unwrap().level, 6);
+    }
+
+    #[test]
+    fn test_parse_toc_ctrl_rejects_bad_payloads() {
+        assert!(parse_toc_ctrl(&toc_ctrl(b"klh%", 1, 1, "Link")).is_none(), "other ctrl id");
+        assert!(parse_toc_ctrl(&toc_ctrl(b" cot", 1, 1, " \0")).is_none(), "blank heading");
+        let data = toc_ctrl(b" cot", 1, 1, "Truncated");
+        assert!(parse_toc_ctrl(&data[..data.len() - 2]).is_none());
+        assert!(parse_toc_ctrl(&data[..10]).is_none());
+        assert!(parse_toc_ctrl(b"").is_none());
+    }
+
+    fn history_entry(revision: u32, timestamp: u64, author: &str, summary: &str) -> Vec<u8> {
+        let mut data = revision.to_le_bytes().to_vec();
+        data.extend_from_slice(&timestamp.to_le_bytes());
+        for text in [author, summary] {
+            let units: Vec<u16> = text.encode_utf16().collect();
+            data.extend_from_slice(&(units.len() as u16).to_le_bytes());
+            data.extend(units.iter().flat_map(|u| u.to_le_bytes()));
+        }
+        data
+    }
+
+    fn doc_history(entries: &[Vec<u8>]) -> Vec<u8> {
+        let mut data = (entries.len() as u32).to_le_bytes().to_vec();
+        data.extend(entries.iter().flatten());
+        data
+    }
+
+    #[test]
+    fn test_parse_doc_history() {
+        let data = doc_history(&[
+            history_entry(1, 1_700_000_000, "홍길동", "초안 작성"),
+            history_entry(2, 1_700_086_400, "Kim", "제3조 수정\0"),
+        ]);
+        let entries = parse_doc_history(&data).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                RevisionEntry {
+                    revision_number: 1,
+                    author: "홍길동".to_string(),
+                    timestamp: 1_700_000_000,
+                    change_summary: "초안 작성".to_string(),
+                },
+                RevisionEntry {
+                    revision_number: 2,
+                    author: "Kim".to_string(),
+                    timestamp: 1_700_086_400,
+                    change_summary: "제3조 수정".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_doc_history_empty_strings_and_count() {
+        assert!(parse_doc_history(&0u32.to_le_bytes()).unwrap().is_empty());
+        let entries = parse_doc_history(&doc_history(&[history_entry(7, 0, "", "")])).unwrap();
+        assert_eq!((entries[0].revision_number, entries[0].author.as_str()), (7, ""));
+        // Trailing bytes after the counted entries are ignored
+        let mut data = doc_history(&[history_entry(1, 5, "a", "b")]);
+        data.extend_from_slice(&[0xFF; 3]);
+        assert_eq!(parse_doc_history(&data).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_doc_history_truncated() {
+        let data = doc_history(&[history_entry(1, 1_700_000_000, "홍길동", "초안")]);
+        for cut in [0, 2, 4, 10, 17, data.len() - 1] {
+            let err = parse_doc_history(&data[..cut]).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData, "cut at {}", cut);
+        }
+        // Count larger than the entries present
+        let mut data = doc_history(&[history_entry(1, 0, "a", "b")]);
+        data[0] = 2;
+        assert!(parse_doc_history(&data).is_err());
+        // Absurd count is rejected without a huge allocation
+        assert!(parse_doc_history(&u32::MAX.to_le_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_parse_style_truncated() {
+        let data = style_record("개요 1", "Outline 1", 0, 0);
+        assert!(parse_style(&data[..data.len() - 1]).is_none());
+        assert!(parse_style(&[0x05, 0x00, 0x41]).is_none());
+    }
+
+    #[test]
+    fn test_paragraph_alignment_bits() {
+        assert_eq!(ParagraphAlignment::from_bits(0), ParagraphAlignment::Justify);
+        assert_eq!(ParagraphAlignment::from_bits(3), ParagraphAlignment::Center);
+        assert_eq!(ParagraphAlignment::from_bits(7), ParagraphAlignment::Justify);
+    }
+
+    #[test]
+    fn test_parse_table_info_border_fill_id() {
+        // flags, 2 rows, 2 cols, spacing, 4 margins, 2 row sizes, fill id 3
+        let mut data = vec![0u8; 4];
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend_from_slice(&[0u8; 10]);
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend_from_slice(&2u16.to_le_bytes());
+        data.extend_from_slice(&3u16.to_le_bytes());
+
+        let info = parse_table_info(&data).unwrap();
+        assert_eq!(info.row_heights, vec![2, 2]);
+        assert_eq!(info.border_fill_id, Some(3));
+
+        // Truncated before the fill id → None
+        assert_eq!(parse_table_info(&data[..22]).unwrap().border_fill_id, None);
+    }
+
+    #[test]
+    fn test_parse_table_info_header_flag() {
+        let table = |flags: u32| {
+            let mut data = flags.to_le_bytes().to_vec();
+            data.extend_from_slice(&1u16.to_le_bytes());
+            data.extend_from_slice(&2u16.to_le_bytes());
+            parse_table_info(&data).unwrap()
+        };
+        assert!(table(TABLE_FLAG_REPEAT_HEADER).has_header_row);
+        // Page-split bits alone do not mark a header
+        assert!(!table(0b11).has_header_row);
+    }
+
+    #[test]
+    fn test_hwp_table_separator_follows_header_flag() {
+        let mut table = HwpTable::new(2, 2);
+        table.cells[0][0].content = "A".to_string();
+        table.cells[1][0].content = "1".to_string();
+        assert_eq!(table.to_markdown(), "| A |  |\n| --- | --- |\n| 1 |  |");
+
+        table.info.has_header_row = false;
+        assert_eq!(table.to_markdown(), "| A |  |\n| 1 |  |");
+    }
+
+    #[test]
+    fn test_hwp_table_colored_cells_emit_html() {
+        let mut table = HwpTable::new(2, 2);
+        table.cells[0][0].content = "헤더".to_string();
+        table.cells[0][0].background_color = Some(0xFFD9E2F3);
+        table.cells[0][1].content = "a<b".to_string();
+        table.cells[1][0].content = "x".to_string();
+        table.cells[1][1].content = "y".to_string();
+
+        let html = table.to_html();
+        assert!(html.starts_with("<table>"));
+        assert!(html.contains("<td style=\"background-color:#d9e2f3\">헤더</td>"));
+        assert!(html.contains("<td>a&lt;b</td>"));
+
+        let config = crate::Config { preserve_table_colors: true, ..Default::default() };
+        assert_eq!(table.to_markdown_with_config(&config), html);
+        // Off by default → pipe table, like TableData
+        assert!(table.to_markdown_with_config(&crate::Config::default()).starts_with("| 헤더 |"));
+        assert_eq!(table.to_markdown_with_config(&crate::Config::default()), table.to_markdown());
+    }
+
+    #[test]
+    fn test_hwp_table_white_background_keeps_pipe_table() {
+        let mut table = HwpTable::new(2, 2);
+        table.cells[0][0].content = "a".to_string();
+        table.cells[0][0].background_color = Some(0xFFFFFFFF);
+        assert!(!table.has_cell_colors());
+        assert!(table.to_markdown().starts_with("| a |"));
+    }
+
+    #[test]
+    fn test_hwp_table_to_html_spans() {
+        let mut table = HwpTable::new(2, 2);
+        table.cells[0][0].content = "merged".to_string();
+        table.cells[0][0].col_span = 2;
+        table.cells[1][0].content = "l".to_string();
+        table.cells[1][1].content = "r".to_string();
+        let html = table.to_html();
+        assert!(html.contains("<tr><td colspan=\"2\">merged</td></tr>"));
+        assert!(html.contains("<tr><td>l</td><td>r</td></tr>"));
+    }
 }
@@ -4,4 +4,8 @@
 
 mod parser;
 
-pub use parser::HwpxParser;
+pub use parser::{
+    decode_preview_text, extract_bookmarks, extract_drawing_objects, extract_fields, structured_json_schema,
+    Bookmark, DrawingObject, HwpxDocument, HwpxError, HwpxField, HwpxMeta, HwpxParser, HwpxSectionIter,
+    ImageJson, ParaStyle, SectionJoinMode, SectionJson, ShapeKind, Table, TableJson,
+};
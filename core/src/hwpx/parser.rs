@@ -3,12 +3,40 @@
 use crate::utils::bounded_io::{
     read_limited, read_limited_to_string, MAX_HWPX_BINDATA, MAX_HWPX_XML,
 };
+use base64::Engine;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{self, Read, Seek, Cursor};
 use std::path::Path;
+use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
 use zip::ZipArchive;
 
+/// ZIP entry Hancom writes into password-protected HWPX packages
+const ENCRYPT_ENTRY: &str = "settings/encrypt.xml";
+
+/// HWPX-specific errors
+#[derive(Error, Debug)]
+pub enum HwpxError {
+    #[error("HWPX document is encrypted (settings/encrypt.xml present); remove the password in Hancom Office and re-save")]
+    Encrypted,
+
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+}
+
+impl From<HwpxError> for io::Error {
+    fn from(e: HwpxError) -> Self {
+        match e {
+            HwpxError::Io(e) => e,
+            HwpxError::Encrypted => {
+                io::Error::new(io::ErrorKind::PermissionDenied, HwpxError::Encrypted.to_string())
+            }
+        }
+    }
+}
+
 /// Character style properties
 #[derive(Debug, Clone, Default)]
 pub struct CharStyle {
@@ -22,6 +50,34 @@ pub struct CharStyle {
     pub emphasis_dot: bool,
 }
 
+/// Paragraph properties from `<hh:paraPr>` in header.xml
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ParaStyle {
+    /// 0 = body text, 1 = Heading 1, 2 = Heading 2, …
+    pub outline_level: u8,
+    /// `<hh:align horizontal>` value (`JUSTIFY`, `CENTER`, …); empty if absent
+    pub alignment: String,
+}
+
+/// Paragraph spacing from `<hp:paraShape paraSpaceAbove/paraSpaceBelow>`.
+///
+/// HWP units are 1/100 pt, so `paraSpaceAbove="1000"` is 10pt.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HwpxParaSpacing {
+    pub above_pt: f64,
+    pub below_pt: f64,
+}
+
+impl HwpxParaSpacing {
+    /// Inline CSS declarations, e.g. `margin-top:10pt;margin-bottom:5pt`.
+    pub fn to_css(&self) -> String {
+        format!(
+            "margin-top:{}pt;margin-bottom:{}pt",
+            self.above_pt, self.below_pt
+        )
+    }
+}
+
 /// Image information from HWPX file
 #[derive(Debug, Clone)]
 pub struct ImageInfo {
@@ -29,6 +85,10 @@ pub struct ImageInfo {
     pub path: String,         // BinData/image1.bmp
     pub media_type: String,   // image/bmp, image/png
     pub data: Vec<u8>,        // actual binary data
+    /// Caption (or shape comment) text from the section XML
+    pub alt_text: Option<String>,
+    /// Displayed size in pixels at 96 DPI, from `<hp:sz>`
+    pub dimensions: Option<(u32, u32)>,
 }
 
 /// HWPX document parser, generic over the underlying reader type.
@@ -38,6 +98,58 @@ pub struct HwpxParser<R: Read + Seek = File> {
     archive: ZipArchive<R>,
     char_styles: HashMap<u32, CharStyle>,
     heading_styles: HashMap<u32, u8>,
+    para_styles: HashMap<u32, ParaStyle>,
+    para_shapes: HashMap<u32, HwpxParaSpacing>,
+    preserve_para_spacing: bool,
+    join_mode: SectionJoinMode,
+    /// Encoding the last [`Self::read_preview_text`] decoded with
+    preview_encoding: &'static str,
+    /// NFC-normalize extracted text, see [`Self::with_unicode_normalization`]
+    normalize_unicode: bool,
+}
+
+/// What goes between sections when they are joined into one document
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SectionJoinMode {
+    /// Blank line only
+    Concatenate,
+    /// Markdown thematic break (`---`)
+    #[default]
+    HorizontalRule,
+    /// `<!-- pagebreak -->`
+    PageBreakComment,
+    /// `<div class="page-break"></div>` for print CSS
+    HtmlPageBreak,
+}
+
+impl SectionJoinMode {
+    /// Separator placed between two sections
+    pub fn separator(&self) -> &'static str {
+        match self {
+            Self::Concatenate => "\n\n",
+            Self::HorizontalRule => "\n\n---\n\n",
+            Self::PageBreakComment => "\n\n<!-- pagebreak -->\n\n",
+            Self::HtmlPageBreak => "\n\n<div class=\"page-break\"></div>\n\n",
+        }
+    }
+}
+
+impl std::str::FromStr for SectionJoinMode {
+    type Err = String;
+
+    /// Kebab-case names as used by the CLI, e.g. `page-break-comment`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "concatenate" | "none" => Ok(Self::Concatenate),
+            "horizontal-rule" | "hr" => Ok(Self::HorizontalRule),
+            "page-break-comment" => Ok(Self::PageBreakComment),
+            "html-page-break" => Ok(Self::HtmlPageBreak),
+            other => Err(format!(
+                "unknown section separator '{}' (concatenate, horizontal-rule, page-break-comment, html-page-break)",
+                other
+            )),
+        }
+    }
 }
 
 /// Parsed HWPX document
@@ -48,7 +160,414 @@ pub struct HwpxDocument {
     pub images: Vec<String>,
     pub image_info: Vec<ImageInfo>,
     pub preview_text: String,
+    /// Encoding `Preview/PrvText.txt` was decoded with: `"utf-8"` or
+    /// `"euc-kr"` (older packages)
+    pub preview_text_encoding: String,
     pub tables: Vec<Table>,
+    /// CSS rules for sections whose paragraphs all share one spacing
+    /// (only with `Config::preserve_para_spacing`). Meant for the MDX
+    /// frontmatter; the section's paragraphs reference the class.
+    pub para_spacing_css: Vec<String>,
+    /// Paragraphs rendered as Markdown headings across all sections
+    pub heading_count: usize,
+    /// Vector shapes of all sections, in document order
+    pub drawings: Vec<DrawingObject>,
+    /// `<hp:bookmark>` positions of all sections, in document order
+    pub bookmarks: Vec<Bookmark>,
+    /// `<hp:fieldBegin>` … `<hp:fieldEnd>` form fields of all sections,
+    /// in document order
+    pub fields: Vec<HwpxField>,
+    /// Separator chosen with [`HwpxParser::with_join_mode`]
+    pub join_mode: SectionJoinMode,
+}
+
+/// Named position from a section's `<hp:bookmark name="...">`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bookmark {
+    pub name: String,
+    /// Section the bookmark is in (0 from [`extract_bookmarks`], which only
+    /// sees one section)
+    pub section_index: usize,
+    /// Index of the enclosing top-level `<hp:p>` within the section
+    pub paragraph_index: usize,
+    /// Characters of paragraph text before the bookmark
+    pub char_offset: usize,
+    /// Plain text of the enclosing paragraph, used to place the anchor in
+    /// rendered output
+    pub paragraph_text: String,
+}
+
+/// Form field from a section's `<hp:fieldBegin>` … `<hp:fieldEnd>` pair.
+///
+/// Hyperlinks are fields too in HWPX, but they render as links and are
+/// left out.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct HwpxField {
+    /// Kebab-case `type` attribute: `click-here`, `date`, `doc-date`,
+    /// `user`, `mail-merge`, ...
+    pub field_type: String,
+    /// `name` attribute, usually the label of a 누름틀 (click-here) field
+    pub name: Option<String>,
+    /// Text between begin and end; `None` when nothing was filled in
+    pub value: Option<String>,
+    /// Section the field is in (0 from [`extract_fields`])
+    pub section_index: usize,
+    /// Index of the enclosing top-level `<hp:p>` within the section
+    pub paragraph_index: usize,
+    /// Plain text of the enclosing paragraph, used to place the field in
+    /// rendered output
+    pub paragraph_text: String,
+}
+
+impl HwpxField {
+    /// `**field_type:** value`, or `**field_type:** ___` when empty
+    pub fn to_markdown(&self) -> String {
+        format!("**{}:** {}", self.field_type, self.value.as_deref().unwrap_or("___"))
+    }
+}
+
+impl HwpxDocument {
+    /// Collect a [`HwpxSectionIter`] into a document, failing on the first
+    /// section error. Spacing CSS, drawings, bookmarks and fields stay empty (see
+    /// [`HwpxParser::section_text_iter`]).
+    pub fn from_iter<R: Read + Seek>(iter: HwpxSectionIter<R>, metadata: HwpxMeta) -> io::Result<HwpxDocument> {
+        let mut sections = Vec::new();
+        let mut tables = Vec::new();
+        for section in iter {
+            let (text, section_tables) = section?;
+            sections.push(text);
+            tables.extend(section_tables);
+        }
+        let heading_count = sections.iter().map(|s| count_headings(s)).sum();
+
+        Ok(HwpxDocument {
+            version: metadata.version,
+            sections,
+            images: metadata.images,
+            image_info: metadata.image_info,
+            preview_text: metadata.preview_text,
+            preview_text_encoding: metadata.preview_text_encoding,
+            tables,
+            para_spacing_css: Vec::new(),
+            heading_count,
+            drawings: Vec::new(),
+            bookmarks: Vec::new(),
+            fields: Vec::new(),
+            join_mode: SectionJoinMode::default(),
+        })
+    }
+
+    /// Replace `[이미지: id]` markers in `content` with Markdown images.
+    ///
+    /// The alt text is the image caption when the section XML has one,
+    /// otherwise the image id. `src` maps each image to its link target.
+    /// Markers without a matching image are left untouched.
+    pub fn link_images<F: Fn(&ImageInfo) -> String>(&self, content: &str, src: F) -> String {
+        let mut out = content.to_string();
+        for image in &self.image_info {
+            let marker = format!("[이미지: {}]", image.id);
+            if !out.contains(&marker) {
+                continue;
+            }
+            let alt = image
+                .alt_text
+                .as_deref()
+                .unwrap_or(&image.id)
+                .replace('[', "\\[")
+                .replace(']', "\\]");
+            out = out.replace(&marker, &format!("![{}]({})", alt, src(image)));
+        }
+        out
+    }
+
+    /// Generate MDX: frontmatter plus section content joined per `mode`,
+    /// with image markers linked to `assets/<file name>`, form fields
+    /// labelled with their type and an `<a id="...">` anchor before each
+    /// bookmarked paragraph.
+    pub fn to_mdx(&self, mode: SectionJoinMode) -> String {
+        let content = if self.sections.iter().any(|s| !s.is_empty()) {
+            self.sections
+                .iter()
+                .enumerate()
+                .map(|(idx, section)| {
+                    let fields: Vec<&HwpxField> =
+                        self.fields.iter().filter(|f| f.section_index == idx).collect();
+                    let section = label_fields(section, &fields);
+                    let bookmarks: Vec<&Bookmark> =
+                        self.bookmarks.iter().filter(|b| b.section_index == idx).collect();
+                    anchor_bookmarks(&section, &bookmarks)
+                })
+                .collect::<Vec<_>>()
+                .join(mode.separator())
+        } else {
+            self.preview_text.clone()
+        };
+        let content = self.link_images(&content, |image| {
+            let name = image.path.rsplit('/').next().unwrap_or(&image.id);
+            format!("assets/{}", name)
+        });
+
+        let mut mdx = String::from("---\nformat: hwpx\n");
+        mdx.push_str(&format!("version: \"{}\"\n", self.version));
+        mdx.push_str(&format!("sections: {}\n", self.sections.len()));
+        mdx.push_str(&format!("images: {}\n", self.image_info.len()));
+        if !self.para_spacing_css.is_empty() {
+            mdx.push_str("styles:\n");
+            for rule in &self.para_spacing_css {
+                mdx.push_str(&format!("  - \"{}\"\n", rule));
+            }
+        }
+        mdx.push_str("---\n\n");
+        mdx.push_str(&content);
+
+        // Shapes have no anchor in the text; they follow it as inline SVG
+        for drawing in &self.drawings {
+            let encoded = base64::engine::general_purpose::STANDARD.encode(drawing.to_svg());
+            mdx.push_str(&format!("\n\n![shape](data:image/svg+xml;base64,{})", encoded));
+        }
+        mdx
+    }
+
+    /// Machine-readable view of the document: `metadata`, `sections` split
+    /// into heading / paragraphs / tables, and `images` without their bytes.
+    ///
+    /// Tables are matched back to the section whose text contains their
+    /// Markdown and left out of that section's paragraphs. The output
+    /// follows [`structured_json_schema`].
+    pub fn to_structured_json(&self) -> serde_json::Value {
+        let mut remaining = self.tables.iter().peekable();
+        let mut sections = Vec::with_capacity(self.sections.len());
+        for text in &self.sections {
+            let mut body = String::new();
+            let mut tables = Vec::new();
+            let mut cursor = 0;
+            while let Some(table) = remaining.peek() {
+                let markdown = table.to_markdown();
+                let needle = markdown.trim_end();
+                let Some(found) = text[cursor..].find(needle) else { break };
+                body.push_str(&text[cursor..cursor + found]);
+                body.push_str("\n\n");
+                cursor += found + needle.len();
+                tables.push(TableJson::from(*table));
+                remaining.next();
+            }
+            body.push_str(&text[cursor..]);
+            sections.push(SectionJson::from_body(&body, tables));
+        }
+
+        let images: Vec<ImageJson> = self
+            .image_info
+            .iter()
+            .map(|i| ImageJson {
+                id: i.id.clone(),
+                path: i.path.clone(),
+                media_type: i.media_type.clone(),
+                size: i.data.len(),
+            })
+            .collect();
+
+        serde_json::json!({
+            "metadata": {
+                "format": "hwpx",
+                "version": self.version,
+                "section_count": self.sections.len(),
+            },
+            "sections": sections,
+            "images": images,
+        })
+    }
+}
+
+/// Table in [`HwpxDocument::to_structured_json`] output
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct TableJson {
+    /// First row when the table has a header row, otherwise empty
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    /// Same rendering as in the section text (HTML when cells are merged)
+    pub markdown: String,
+}
+
+impl From<&Table> for TableJson {
+    fn from(table: &Table) -> Self {
+        let mut rows: Vec<Vec<String>> = table
+            .cells
+            .iter()
+            .map(|row| row.iter().map(|c| c.trim().to_string()).collect())
+            .collect();
+        let headers = if table.has_header && !rows.is_empty() { rows.remove(0) } else { Vec::new() };
+        TableJson { headers, rows, markdown: table.to_markdown() }
+    }
+}
+
+/// Section in [`HwpxDocument::to_structured_json`] output
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct SectionJson {
+    /// Text of the section's first Markdown heading, without the `#`s
+    pub heading: Option<String>,
+    /// Blank-line separated blocks of the section, tables excluded
+    pub paragraphs: Vec<String>,
+    pub tables: Vec<TableJson>,
+}
+
+impl SectionJson {
+    fn from_body(body: &str, tables: Vec<TableJson>) -> Self {
+        let mut heading = None;
+        let mut paragraphs = Vec::new();
+        for block in body.split("\n\n").map(str::trim).filter(|b| !b.is_empty()) {
+            if heading.is_none() && !block.contains('\n') && count_headings(block) == 1 {
+                heading = Some(block.trim_start_matches('#').trim().to_string());
+            } else {
+                paragraphs.push(block.to_string());
+            }
+        }
+        SectionJson { heading, paragraphs, tables }
+    }
+}
+
+/// Image entry in [`HwpxDocument::to_structured_json`] output
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ImageJson {
+    pub id: String,
+    pub path: String,
+    pub media_type: String,
+    /// Size of the embedded data in bytes
+    pub size: usize,
+}
+
+/// JSON Schema (draft-07) of [`HwpxDocument::to_structured_json`] output
+pub fn structured_json_schema() -> serde_json::Value {
+    let strings = serde_json::json!({ "type": "array", "items": { "type": "string" } });
+    serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "HWPX structured document",
+        "type": "object",
+        "required": ["metadata", "sections", "images"],
+        "properties": {
+            "metadata": {
+                "type": "object",
+                "required": ["format", "version", "section_count"],
+                "properties": {
+                    "format": { "type": "string" },
+                    "version": { "type": "string" },
+                    "section_count": { "type": "integer", "minimum": 0 },
+                },
+            },
+            "sections": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["heading", "paragraphs", "tables"],
+                    "properties": {
+                        "heading": { "type": ["string", "null"] },
+                        "paragraphs": strings,
+                        "tables": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "required": ["headers", "rows", "markdown"],
+                                "properties": {
+                                    "headers": strings,
+                                    "rows": { "type": "array", "items": strings },
+                                    "markdown": { "type": "string" },
+                                },
+                            },
+                        },
+                    },
+                },
+            },
+            "images": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "required": ["id", "path", "media_type", "size"],
+                    "properties": {
+                        "id": { "type": "string" },
+                        "path": { "type": "string" },
+                        "media_type": { "type": "string" },
+                        "size": { "type": "integer", "minimum": 0 },
+                    },
+                },
+            },
+        },
+    })
+}
+
+/// Kind of an HWPX vector drawing object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeKind {
+    Rect,
+    Ellipse,
+    Line,
+}
+
+/// Vector shape from a section's `<hp:rect>`, `<hp:ellipse>` or `<hp:line>`.
+///
+/// Position (`<hp:pos horzOffset vertOffset>`) and size (`<hp:sz>`) are in
+/// HWPUNIT (1/7200 inch); colors are `0xRRGGBB`, `None` when unpainted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrawingObject {
+    pub shape_type: ShapeKind,
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    /// `<hc:winBrush faceColor>`
+    pub fill_color: Option<u32>,
+    /// `<hp:lineShape color>`, `None` for `style="NONE"`
+    pub stroke_color: Option<u32>,
+}
+
+impl DrawingObject {
+    /// Bounds in CSS pixels at 96 DPI, rounded to 2 decimals
+    fn px_bounds(&self) -> (f64, f64, f64, f64) {
+        let px = |v: u32| (v as f64 / 75.0 * 100.0).round() / 100.0;
+        (px(self.x), px(self.y), px(self.width), px(self.height))
+    }
+
+    /// SVG element for the shape in page pixel coordinates.
+    ///
+    /// Lines run corner to corner of the bounding box, top-left to
+    /// bottom-right.
+    pub fn to_svg_element(&self) -> String {
+        let (x, y, w, h) = self.px_bounds();
+        let css = |color: Option<u32>| match color {
+            Some(c) => format!("#{:06x}", c & 0xFF_FFFF),
+            None => "none".to_string(),
+        };
+        let paint = format!(
+            r#"fill="{}" stroke="{}" stroke-width="1""#,
+            css(self.fill_color),
+            css(self.stroke_color)
+        );
+        match self.shape_type {
+            ShapeKind::Rect => format!(
+                r#"<rect x="{}" y="{}" width="{}" height="{}" {}/>"#,
+                x, y, w, h, paint
+            ),
+            ShapeKind::Ellipse => format!(
+                r#"<ellipse cx="{}" cy="{}" rx="{}" ry="{}" {}/>"#,
+                x + w / 2.0, y + h / 2.0, w / 2.0, h / 2.0, paint
+            ),
+            ShapeKind::Line => format!(
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="1"/>"#,
+                x, y, x + w, y + h,
+                // An unstroked line would be invisible
+                css(self.stroke_color.or(Some(0)))
+            ),
+        }
+    }
+
+    /// Standalone SVG document whose viewBox is the shape's bounding box
+    pub fn to_svg(&self) -> String {
+        let (x, y, w, h) = self.px_bounds();
+        // Keep zero-width lines visible
+        let (w, h) = (w.max(1.0), h.max(1.0));
+        format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">{}</svg>"#,
+            w, h, x, y, w, h, self.to_svg_element()
+        )
+    }
 }
 
 /// Table structure
@@ -196,6 +715,120 @@ fn html_escape(s: &str) -> String {
     out
 }
 
+/// Insert `<a id="name"></a>` anchors into rendered section text.
+///
+/// Rendered paragraphs don't map 1:1 onto `<hp:p>` elements (empty ones are
+/// dropped, tables expand to many lines), so each bookmark is placed before
+/// the first line at or after the previous anchor that contains the start
+/// of its paragraph text, ignoring emphasis markers. Unmatched bookmarks
+/// go where the previous one went.
+fn anchor_bookmarks(section: &str, bookmarks: &[&Bookmark]) -> String {
+    if bookmarks.is_empty() {
+        return section.to_string();
+    }
+    let lines: Vec<&str> = section.split('\n').collect();
+    let mut anchors: Vec<String> = vec![String::new(); lines.len()];
+    let mut cursor = 0;
+
+    let mut ordered = bookmarks.to_vec();
+    ordered.sort_by_key(|b| (b.paragraph_index, b.char_offset));
+    for bookmark in ordered {
+        let probe: String = bookmark.paragraph_text.trim().chars().take(16).collect();
+        if !probe.is_empty() {
+            let found = lines[cursor..].iter().position(|line| {
+                line.replace(['*', '_', '~', '`'], "").contains(probe.as_str())
+            });
+            if let Some(offset) = found {
+                cursor += offset;
+            }
+        }
+        let id: String = bookmark
+            .name
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join("-");
+        anchors[cursor].push_str(&format!("<a id=\"{}\"></a>", html_escape(&id).replace('"', "&quot;")));
+    }
+
+    let mut out = String::with_capacity(section.len());
+    for (idx, (line, anchor)) in lines.iter().zip(&anchors).enumerate() {
+        if idx > 0 {
+            out.push('\n');
+        }
+        if !anchor.is_empty() {
+            out.push_str(anchor);
+            out.push_str("\n\n");
+        }
+        out.push_str(line);
+    }
+    out
+}
+
+/// Mark each field in `section` with [`HwpxField::to_markdown`].
+///
+/// Fields are located like bookmarks in [`anchor_bookmarks`]: by the start
+/// of their paragraph text, at or after the previous field's line. A filled
+/// field's value is labelled in place; an empty field is appended to its
+/// line, or gets a paragraph of its own when the line cannot be found (a
+/// paragraph holding nothing but an empty field is not rendered at all).
+fn label_fields(section: &str, fields: &[&HwpxField]) -> String {
+    if fields.is_empty() {
+        return section.to_string();
+    }
+    // Probe the unlabelled text; labels would break later matches on a line
+    let original: Vec<&str> = section.split('\n').collect();
+    let mut lines: Vec<String> = original.iter().map(|line| line.to_string()).collect();
+    let mut extra: Vec<Vec<String>> = vec![Vec::new(); lines.len()];
+    let mut cursor = 0;
+    // Byte offset in `lines[cursor]` already consumed by earlier fields
+    let mut column = 0;
+
+    for field in fields {
+        let probe: String = field.paragraph_text.trim().chars().take(16).collect();
+        let found = if probe.is_empty() {
+            None
+        } else {
+            original[cursor..]
+                .iter()
+                .position(|line| line.replace(['*', '_', '~', '`'], "").contains(probe.as_str()))
+        };
+        if let Some(offset) = found.filter(|&offset| offset > 0) {
+            cursor += offset;
+            column = 0;
+        }
+
+        let label = field.to_markdown();
+        let line = &mut lines[cursor];
+        match (&field.value, found) {
+            (Some(value), Some(_)) if line[column..].contains(value.as_str()) => {
+                let at = column + line[column..].find(value.as_str()).unwrap_or(0);
+                line.replace_range(at..at + value.len(), &label);
+                column = at + label.len();
+            }
+            (_, Some(_)) => {
+                line.truncate(line.trim_end().len());
+                line.push(' ');
+                line.push_str(&label);
+                column = line.len();
+            }
+            (_, None) => extra[cursor].push(label),
+        }
+    }
+
+    let mut out = String::with_capacity(section.len());
+    for (idx, (line, after)) in lines.iter().zip(&extra).enumerate() {
+        if idx > 0 {
+            out.push('\n');
+        }
+        out.push_str(line);
+        for label in after {
+            out.push_str("\n\n");
+            out.push_str(label);
+        }
+    }
+    out
+}
+
 impl HwpxParser<File> {
     /// Open an HWPX file from disk.
     pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
@@ -206,6 +839,12 @@ impl HwpxParser<File> {
             archive,
             char_styles: HashMap::new(),
             heading_styles: HashMap::new(),
+            para_styles: HashMap::new(),
+            para_shapes: HashMap::new(),
+            preserve_para_spacing: false,
+            join_mode: SectionJoinMode::default(),
+            preview_encoding: PREVIEW_UTF8,
+            normalize_unicode: false,
         })
     }
 }
@@ -228,24 +867,76 @@ impl HwpxParser<Cursor<Vec<u8>>> {
             archive,
             char_styles: HashMap::new(),
             heading_styles: HashMap::new(),
+            para_styles: HashMap::new(),
+            para_shapes: HashMap::new(),
+            preserve_para_spacing: false,
+            join_mode: SectionJoinMode::default(),
+            preview_encoding: PREVIEW_UTF8,
+            normalize_unicode: false,
         })
     }
 }
 
 impl<R: Read + Seek> HwpxParser<R> {
-    /// Parse the HWPX document
-    pub fn parse(&mut self) -> io::Result<HwpxDocument> {
+    /// Apply conversion options from `config`.
+    pub fn with_config(mut self, config: &crate::Config) -> Self {
+        self.preserve_para_spacing = config.preserve_para_spacing;
+        self
+    }
+
+    /// Separator recorded as [`HwpxDocument::join_mode`] by [`Self::parse`]
+    pub fn with_join_mode(mut self, mode: SectionJoinMode) -> Self {
+        self.join_mode = mode;
+        self
+    }
+
+    /// NFC-normalize section text, table cells and preview text.
+    ///
+    /// HWPX files written on macOS can store Hangul decomposed (NFD, one
+    /// jamo per code point), which breaks search against ordinary NFC
+    /// Korean text. Off by default. See [`normalize_text`].
+    pub fn with_unicode_normalization(mut self, normalize: bool) -> Self {
+        self.normalize_unicode = normalize;
+        self
+    }
+
+    /// Parse the HWPX document.
+    ///
+    /// Fails with [`HwpxError::Encrypted`] for password-protected packages —
+    /// their section XML is ciphertext, so there is nothing to extract.
+    pub fn parse(&mut self) -> Result<HwpxDocument, HwpxError> {
+        if self.is_encrypted() {
+            return Err(HwpxError::Encrypted);
+        }
         let version = self.read_version()?;
-        let preview_text = self.read_preview_text().unwrap_or_default();
+        let mut preview_text = self.read_preview_text().unwrap_or_default();
+        if self.normalize_unicode {
+            preview_text = normalize_text(&preview_text);
+        }
 
         // Parse header.xml for character styles
         self.parse_header_styles()?;
 
-        let (sections, tables) = self.extract_sections_with_tables()?;
+        let SectionsExtract {
+            sections,
+            tables,
+            css: para_spacing_css,
+            placements,
+            drawings,
+            bookmarks,
+            fields,
+        } = self.extract_sections_with_tables()?;
         let images = self.list_images();
-        
+        let heading_count = sections.iter().map(|s| count_headings(s)).sum();
+
         // Parse manifest and extract image info
-        let image_info = self.extract_images_with_data()?;
+        let mut image_info = self.extract_images_with_data()?;
+        for info in &mut image_info {
+            if let Some(placement) = placements.iter().find(|p| p.id == info.id) {
+                info.alt_text = placement.alt_text.clone();
+                info.dimensions = placement.dimensions;
+            }
+        }
 
         Ok(HwpxDocument {
             version,
@@ -253,7 +944,14 @@ impl<R: Read + Seek> HwpxParser<R> {
             images,
             image_info,
             preview_text,
+            preview_text_encoding: self.preview_encoding.to_string(),
             tables,
+            para_spacing_css,
+            heading_count,
+            drawings,
+            bookmarks,
+            fields,
+            join_mode: self.join_mode,
         })
     }
 
@@ -263,6 +961,8 @@ impl<R: Read + Seek> HwpxParser<R> {
             let content = read_limited_to_string(&mut file, MAX_HWPX_XML)?;
             self.char_styles = parse_char_properties(&content);
             self.heading_styles = parse_heading_styles(&content);
+            self.para_styles = parse_para_properties(&content);
+            self.para_shapes = parse_para_shapes(&content);
         }
         Ok(())
     }
@@ -281,16 +981,36 @@ impl<R: Read + Seek> HwpxParser<R> {
         Ok("unknown".to_string())
     }
 
-    /// Read preview text (fast method)
+    /// Read preview text (fast method), decoded per [`decode_preview_text`]
     fn read_preview_text(&mut self) -> io::Result<String> {
+        let bytes = self.read_preview_text_raw()?;
+        let (text, encoding) = decode_preview_text(&bytes);
+        self.preview_encoding = encoding;
+        Ok(text)
+    }
+
+    /// Undecoded bytes of `Preview/PrvText.txt`, for callers bringing their
+    /// own decoder
+    pub fn read_preview_text_raw(&mut self) -> io::Result<Vec<u8>> {
         let mut file = self.archive.by_name("Preview/PrvText.txt")?;
-        read_limited_to_string(&mut file, MAX_HWPX_XML)
+        read_limited(&mut file, MAX_HWPX_XML)
+    }
+
+    /// Encoding the preview text was last decoded with (`"utf-8"` until
+    /// [`Self::parse`] or [`Self::read_meta`] has read it)
+    pub fn preview_encoding(&self) -> &str {
+        self.preview_encoding
     }
 
-    /// Extract text and tables from all sections
-    fn extract_sections_with_tables(&mut self) -> io::Result<(Vec<String>, Vec<Table>)> {
+    /// Extract text, tables, image placements and shapes from all sections
+    fn extract_sections_with_tables(&mut self) -> io::Result<SectionsExtract> {
         let mut sections = Vec::new();
         let mut all_tables = Vec::new();
+        let mut css = Vec::new();
+        let mut placements: Vec<SectionImage> = Vec::new();
+        let mut drawings = Vec::new();
+        let mut bookmarks = Vec::new();
+        let mut fields = Vec::new();
         let mut section_idx = 0;
 
         loop {
@@ -299,16 +1019,76 @@ impl<R: Read + Seek> HwpxParser<R> {
                 Ok(mut file) => {
                     let content = read_limited_to_string(&mut file, MAX_HWPX_XML)?;
 
-                    let (text, tables) = parse_section_xml(&content, &self.char_styles, &self.heading_styles);
+                    let spacing = self.preserve_para_spacing.then(|| ParaSpacingOpts {
+                        shapes: &self.para_shapes,
+                        section_idx,
+                    });
+                    let (mut text, mut tables, rule) = parse_section_xml_with_spacing(
+                        &content,
+                        &self.char_styles,
+                        &self.heading_styles,
+                        &self.para_styles,
+                        spacing,
+                    );
+                    if self.normalize_unicode {
+                        normalize_section(&mut text, &mut tables);
+                    }
                     sections.push(text);
+                    css.extend(rule);
                     all_tables.extend(tables);
+                    drawings.extend(extract_drawing_objects(&content));
+                    bookmarks.extend(extract_bookmarks(&content).into_iter().map(|b| Bookmark {
+                        section_index: section_idx,
+                        ..b
+                    }));
+                    fields.extend(extract_fields(&content).into_iter().map(|f| HwpxField {
+                        section_index: section_idx,
+                        ..f
+                    }));
+                    for image in parse_section_images(&content) {
+                        if !placements.iter().any(|p| p.id == image.id) {
+                            placements.push(image);
+                        }
+                    }
                     section_idx += 1;
                 }
                 Err(_) => break,
             }
         }
 
-        Ok((sections, all_tables))
+        Ok(SectionsExtract { sections, tables: all_tables, css, placements, drawings, bookmarks, fields })
+    }
+
+    /// Lazily read sections one `Contents/section{N}.xml` at a time.
+    ///
+    /// Consumes the parser because the iterator owns the archive. Header
+    /// styles are loaded on the first call to `next`; an encrypted package
+    /// yields a single [`HwpxError::Encrypted`] error. Paragraph spacing
+    /// classes, image captions and drawings are only collected by
+    /// [`Self::parse`].
+    pub fn section_text_iter(self) -> HwpxSectionIter<R> {
+        HwpxSectionIter {
+            parser: self,
+            next_index: 0,
+            started: false,
+            done: false,
+        }
+    }
+
+    /// Document-level parts for [`HwpxDocument::from_iter`]: version,
+    /// preview text and BinData images
+    pub fn read_meta(&mut self) -> io::Result<HwpxMeta> {
+        let mut preview_text = self.read_preview_text().unwrap_or_default();
+        if self.normalize_unicode {
+            preview_text = normalize_text(&preview_text);
+        }
+        Ok(HwpxMeta {
+            version: self.read_version()?,
+            preview_text,
+            preview_text_encoding: self.preview_encoding.to_string(),
+            images: self.list_images(),
+            image_info: self.extract_images_with_data()?,
+        })
     }
 
     /// List all images in BinData
@@ -333,9 +1113,11 @@ impl<R: Read + Seek> HwpxParser<R> {
         true
     }
 
-    /// Check if encrypted
+    /// Check if encrypted (`settings/encrypt.xml` present in the package)
     pub fn is_encrypted(&self) -> bool {
-        false
+        self.archive
+            .file_names()
+            .any(|name| name.eq_ignore_ascii_case(ENCRYPT_ENTRY))
     }
 
     /// Parse manifest (content.hpf) and extract images with binary data
@@ -380,6 +1162,8 @@ impl<R: Read + Seek> HwpxParser<R> {
                     path,
                     media_type,
                     data,
+                    alt_text: None,
+                    dimensions: None,
                 });
             }
         }
@@ -567,6 +1351,67 @@ fn parse_heading_styles(header_xml: &str) -> HashMap<u32, u8> {
     map
 }
 
+/// Parse `<hh:paraPr>` elements from header.xml into `paraPrId -> ParaStyle`.
+///
+/// The outline level comes from an `outlineLevel` attribute when present,
+/// otherwise from `<hh:heading type="OUTLINE" level="N">`, whose level is
+/// 0-based (`level="0"` is 개요 1). Numbered/bulleted `heading` types are
+/// list paragraphs, not headings, and stay at level 0.
+fn parse_para_properties(header_xml: &str) -> HashMap<u32, ParaStyle> {
+    let mut styles = HashMap::new();
+    let mut pos = 0;
+
+    while let Some(start) = header_xml[pos..].find("<hh:paraPr ") {
+        let para_pr_start = pos + start;
+        let Some(tag_end) = header_xml[para_pr_start..].find('>').map(|i| para_pr_start + i + 1) else {
+            break;
+        };
+        let open_tag = &header_xml[para_pr_start..tag_end];
+        let end = if open_tag.ends_with("/>") {
+            tag_end
+        } else {
+            header_xml[tag_end..]
+                .find("</hh:paraPr>")
+                .map(|i| tag_end + i + "</hh:paraPr>".len())
+                .unwrap_or(tag_end)
+        };
+        let body = &header_xml[tag_end..end];
+
+        if let Some(id) = extract_attr(open_tag, "id").and_then(|v| v.parse::<u32>().ok()) {
+            let outline_level = extract_attr(open_tag, "outlineLevel")
+                .and_then(|v| v.parse::<u8>().ok())
+                .or_else(|| {
+                    let heading = &body[body.find("<hh:heading ")?..];
+                    let heading = &heading[..heading.find('>')?];
+                    if extract_attr(heading, "type").as_deref() != Some("OUTLINE") {
+                        return None;
+                    }
+                    extract_attr(heading, "level")?.parse::<u8>().ok().map(|l| l + 1)
+                })
+                .unwrap_or(0);
+            let alignment = body
+                .find("<hh:align ")
+                .and_then(|i| extract_attr(&body[i..], "horizontal"))
+                .unwrap_or_default();
+            styles.insert(id, ParaStyle { outline_level, alignment });
+        }
+
+        pos = end;
+    }
+
+    styles
+}
+
+/// Number of Markdown heading lines (`# ` … `####### `) in rendered text
+fn count_headings(text: &str) -> usize {
+    text.lines()
+        .filter(|line| {
+            let hashes = line.len() - line.trim_start_matches('#').len();
+            (1..=7).contains(&hashes) && line[hashes..].starts_with(' ')
+        })
+        .count()
+}
+
 /// Extract heading level from a style name like "개요 1", "Outline 3", etc.
 fn extract_outline_level(name: &str) -> Option<u8> {
     let trimmed = name.trim();
@@ -579,6 +1424,41 @@ fn extract_outline_level(name: &str) -> Option<u8> {
     None
 }
 
+/// Rewrite `<hwp:...>` / `</hwp:...>` tags to the `hp:` spelling every
+/// scanner in this file expects.
+///
+/// Some HWPX files from older Hancom versions bind the paragraph namespace
+/// to `hwp` instead of `hp`; without this their sections come out empty.
+/// Borrowed (no copy) for the usual `hp:` documents.
+fn canonical_prefixes(xml: &str) -> Cow<'_, str> {
+    if !xml.contains("<hwp:") && !xml.contains("</hwp:") {
+        return Cow::Borrowed(xml);
+    }
+    Cow::Owned(xml.replace("<hwp:", "<hp:").replace("</hwp:", "</hp:"))
+}
+
+/// Earliest start tag `<name` among `names`, returning its byte offset and
+/// the matched name. The name must end at whitespace or `>` so `hp:p` does
+/// not match `<hp:pic` and self-closing `<hp:p/>` is skipped.
+fn find_element<'n>(xml: &str, names: &[&'n str]) -> Option<(usize, &'n str)> {
+    names
+        .iter()
+        .filter_map(|&name| {
+            let mut from = 0;
+            while let Some(rel) = xml[from..].find('<').map(|i| from + i) {
+                let tag = &xml[rel + 1..];
+                if tag.starts_with(name)
+                    && matches!(tag[name.len()..].chars().next(), Some('>' | ' ' | '\t' | '\r' | '\n'))
+                {
+                    return Some((rel, name));
+                }
+                from = rel + 1;
+            }
+            None
+        })
+        .min_by_key(|&(offset, _)| offset)
+}
+
 /// Remove `<hp:secPr>...</hp:secPr>` blocks from section XML.
 fn strip_sec_pr(xml: &str) -> String {
     let mut out = String::with_capacity(xml.len());
@@ -631,7 +1511,541 @@ fn find_matching_close(xml: &str, from: usize, open: &str, close: &str) -> Optio
             _ => return None,
         }
     }
-    None
+    None
+}
+
+/// Parse `<hp:paraShape id=".." paraSpaceAbove=".." paraSpaceBelow="..">`
+/// definitions into a spacing table keyed by shape id.
+fn parse_para_shapes(xml: &str) -> HashMap<u32, HwpxParaSpacing> {
+    let mut shapes = HashMap::new();
+    let mut pos = 0;
+    while let Some(start) = xml[pos..].find("<hp:paraShape") {
+        let abs = pos + start;
+        let end = match xml[abs..].find('>') {
+            Some(e) => abs + e,
+            None => break,
+        };
+        let tag = &xml[abs..end];
+        if let Some(id) = extract_attr(tag, "id").and_then(|v| v.parse::<u32>().ok()) {
+            if let Some(spacing) = spacing_from_attrs(tag) {
+                shapes.insert(id, spacing);
+            }
+        }
+        pos = end;
+    }
+    shapes
+}
+
+/// Read `paraSpaceAbove` / `paraSpaceBelow` (HWP units) from a tag.
+/// `None` when neither attribute is present.
+fn spacing_from_attrs(tag: &str) -> Option<HwpxParaSpacing> {
+    let above = extract_attr(tag, "paraSpaceAbove").and_then(|v| v.parse::<i32>().ok());
+    let below = extract_attr(tag, "paraSpaceBelow").and_then(|v| v.parse::<i32>().ok());
+    if above.is_none() && below.is_none() {
+        return None;
+    }
+    Some(HwpxParaSpacing {
+        above_pt: f64::from(above.unwrap_or(0)) / 100.0,
+        below_pt: f64::from(below.unwrap_or(0)) / 100.0,
+    })
+}
+
+/// Spacing of one `<hp:p>`: an inline `<hp:paraShape>` wins, otherwise the
+/// paragraph's `paraPrIDRef` is looked up in `shapes`.
+fn para_spacing_of(
+    para_xml: &str,
+    shapes: &HashMap<u32, HwpxParaSpacing>,
+) -> Option<HwpxParaSpacing> {
+    if let Some(start) = para_xml.find("<hp:paraShape") {
+        let end = para_xml[start..].find('>').map(|e| start + e)?;
+        if let Some(spacing) = spacing_from_attrs(&para_xml[start..end]) {
+            return Some(spacing);
+        }
+    }
+    let open_end = para_xml.find('>')?;
+    extract_attr(&para_xml[..open_end], "paraPrIDRef")
+        .and_then(|id| id.parse::<u32>().ok())
+        .and_then(|id| shapes.get(&id).copied())
+}
+
+const PREVIEW_UTF8: &str = "utf-8";
+const PREVIEW_EUC_KR: &str = "euc-kr";
+
+/// Decode `Preview/PrvText.txt`: UTF-8 (BOM stripped) when valid, else
+/// EUC-KR/CP949 as written by older Hangul versions, else lossy UTF-8.
+/// Returns the text and the encoding label.
+pub fn decode_preview_text(bytes: &[u8]) -> (String, &'static str) {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return (text.to_string(), PREVIEW_UTF8);
+    }
+    if let Some(text) = encoding_rs::EUC_KR.decode_without_bom_handling_and_without_replacement(bytes) {
+        return (text.into_owned(), PREVIEW_EUC_KR);
+    }
+    (String::from_utf8_lossy(bytes).into_owned(), PREVIEW_UTF8)
+}
+
+/// Everything outside the section XML, read by [`HwpxParser::read_meta`]
+#[derive(Debug, Clone, Default)]
+pub struct HwpxMeta {
+    pub version: String,
+    pub preview_text: String,
+    pub preview_text_encoding: String,
+    pub images: Vec<String>,
+    pub image_info: Vec<ImageInfo>,
+}
+
+/// Section-by-section reader returned by [`HwpxParser::section_text_iter`].
+///
+/// Yields each section's Markdown text and tables; only one section's XML
+/// is held in memory at a time. Stops at the first missing section or
+/// after the first error.
+pub struct HwpxSectionIter<R: Read + Seek = File> {
+    parser: HwpxParser<R>,
+    next_index: usize,
+    started: bool,
+    done: bool,
+}
+
+impl<R: Read + Seek> Iterator for HwpxSectionIter<R> {
+    type Item = io::Result<(String, Vec<Table>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if !self.started {
+            self.started = true;
+            if self.parser.is_encrypted() {
+                self.done = true;
+                return Some(Err(HwpxError::Encrypted.into()));
+            }
+            if let Err(e) = self.parser.parse_header_styles() {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+
+        let section_name = format!("Contents/section{}.xml", self.next_index);
+        let content = match self.parser.archive.by_name(&section_name) {
+            Ok(mut file) => read_limited_to_string(&mut file, MAX_HWPX_XML),
+            Err(_) => {
+                self.done = true;
+                return None;
+            }
+        };
+        let content = match content {
+            Ok(content) => content,
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        };
+        self.next_index += 1;
+
+        let (mut text, mut tables, _) = parse_section_xml_with_spacing(
+            &content,
+            &self.parser.char_styles,
+            &self.parser.heading_styles,
+            &self.parser.para_styles,
+            None,
+        );
+        if self.parser.normalize_unicode {
+            normalize_section(&mut text, &mut tables);
+        }
+        Some(Ok((text, tables)))
+    }
+}
+
+/// Everything [`HwpxParser::parse`] collects from the section XML files
+struct SectionsExtract {
+    sections: Vec<String>,
+    tables: Vec<Table>,
+    css: Vec<String>,
+    placements: Vec<SectionImage>,
+    drawings: Vec<DrawingObject>,
+    bookmarks: Vec<Bookmark>,
+    fields: Vec<HwpxField>,
+}
+
+/// Paragraph-spacing options threaded through section extraction.
+#[derive(Clone, Copy)]
+struct ParaSpacingOpts<'a> {
+    shapes: &'a HashMap<u32, HwpxParaSpacing>,
+    section_idx: usize,
+}
+
+/// CSS class shared by every paragraph of a uniformly spaced section.
+fn para_spacing_class(section_idx: usize) -> String {
+    format!("hwpx-para-s{}", section_idx)
+}
+
+/// Image placement read from section XML, keyed by `binaryItemIDRef`
+#[derive(Debug, Clone, Default, PartialEq)]
+struct SectionImage {
+    id: String,
+    alt_text: Option<String>,
+    dimensions: Option<(u32, u32)>,
+}
+
+/// Elements that wrap an image reference, with their closing tags
+const IMAGE_CONTAINERS: [(&str, &str); 3] = [
+    ("<hp:pic", "</hp:pic>"),
+    ("<hp:drawingObject", "</hp:drawingObject>"),
+    ("<hp:img", "</hp:img>"),
+];
+
+/// HWPUNIT (1/7200 inch) to CSS pixels at 96 DPI
+fn hwpunit_to_px(value: u64) -> u32 {
+    (value as f64 / 75.0).round() as u32
+}
+
+/// Collect caption and size for every image in a section.
+///
+/// Looks at `<hp:pic>`, `<hp:drawingObject>` and `<hp:img>` elements: the
+/// `binaryItemIDRef` inside names the image, `<hp:sz width height>` gives
+/// its displayed size and the `<hp:caption>` paragraphs (falling back to
+/// `<hp:shapeComment>`) its alt text. The first placement of an id wins.
+fn parse_section_images(xml: &str) -> Vec<SectionImage> {
+    let xml = canonical_prefixes(xml);
+    let xml = xml.as_ref();
+    let mut images: Vec<SectionImage> = Vec::new();
+    let mut pos = 0;
+
+    loop {
+        // Earliest container opening at or after `pos`
+        let next = IMAGE_CONTAINERS
+            .iter()
+            .filter_map(|&(open, close)| {
+                let mut from = pos;
+                while let Some(rel) = xml[from..].find(open) {
+                    let abs = from + rel;
+                    // `<hp:pic` must not match `<hp:picture...`
+                    if matches!(xml[abs + open.len()..].chars().next(), Some(' ' | '>' | '/')) {
+                        return Some((abs, open, close));
+                    }
+                    from = abs + open.len();
+                }
+                None
+            })
+            .min_by_key(|&(abs, _, _)| abs);
+        let Some((start, open, close)) = next else {
+            break;
+        };
+        let Some(tag_len) = xml[start..].find('>') else {
+            break;
+        };
+        let tag_end = start + tag_len + 1;
+        let end = if xml[..tag_end].ends_with("/>") {
+            tag_end
+        } else {
+            find_matching_close(xml, tag_end, open, close)
+                .map(|c| c + close.len())
+                .unwrap_or(tag_end)
+        };
+
+        let block = &xml[start..end];
+        if let Some(id) = extract_attr(block, "binaryItemIDRef") {
+            if !images.iter().any(|i| i.id == id) {
+                images.push(SectionImage {
+                    id,
+                    alt_text: image_caption(block),
+                    dimensions: image_size(block),
+                });
+            }
+        }
+        pos = end.max(start + 1);
+    }
+
+    images
+}
+
+/// Drawing-object element names and the shape kind each maps to
+const DRAWING_ELEMENTS: [(&str, ShapeKind); 3] = [
+    ("hp:rect", ShapeKind::Rect),
+    ("hp:ellipse", ShapeKind::Ellipse),
+    ("hp:line", ShapeKind::Line),
+];
+
+/// Rectangles, ellipses and lines of one section XML, in document order.
+///
+/// Position comes from `<hp:pos horzOffset vertOffset>`, size from
+/// `<hp:sz>` (falling back to `<hp:curSz>`), fill from the
+/// `<hc:winBrush faceColor>` and stroke from `<hp:lineShape color>`.
+pub fn extract_drawing_objects(section_xml: &str) -> Vec<DrawingObject> {
+    let xml = canonical_prefixes(section_xml);
+    let xml = xml.as_ref();
+    let names: Vec<&str> = DRAWING_ELEMENTS.iter().map(|(name, _)| *name).collect();
+    let mut objects = Vec::new();
+    let mut pos = 0;
+
+    while let Some((rel, name)) = find_element(&xml[pos..], &names) {
+        let start = pos + rel;
+        let Some(tag_len) = xml[start..].find('>') else {
+            break;
+        };
+        let tag_end = start + tag_len + 1;
+        let (open, close) = (format!("<{}", name), format!("</{}>", name));
+        let end = if xml[..tag_end].ends_with("/>") {
+            tag_end
+        } else {
+            find_matching_close(xml, tag_end, &open, &close)
+                .map(|c| c + close.len())
+                .unwrap_or(tag_end)
+        };
+        let block = &xml[start..end];
+        let kind = DRAWING_ELEMENTS
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, kind)| *kind)
+            .unwrap_or(ShapeKind::Rect);
+
+        let number = |tag: &str, attr: &str| -> u32 {
+            child_tag(block, tag)
+                .and_then(|t| extract_attr(t, attr))
+                .and_then(|v| v.parse::<i64>().ok())
+                .map_or(0, |v| v.clamp(0, u32::MAX as i64) as u32)
+        };
+        let size_tag = if child_tag(block, "<hp:sz ").is_some() { "<hp:sz " } else { "<hp:curSz " };
+        let stroke_color = child_tag(block, "<hp:lineShape ")
+            .filter(|t| !extract_attr(t, "style").is_some_and(|s| s.eq_ignore_ascii_case("NONE")))
+            .and_then(|t| extract_attr(t, "color"))
+            .and_then(|c| parse_hex_color(&c));
+        let fill_color = child_tag(block, ":winBrush ")
+            .and_then(|t| extract_attr(t, "faceColor"))
+            .and_then(|c| parse_hex_color(&c));
+
+        objects.push(DrawingObject {
+            shape_type: kind,
+            x: number("<hp:pos ", "horzOffset"),
+            y: number("<hp:pos ", "vertOffset"),
+            width: number(size_tag, "width"),
+            height: number(size_tag, "height"),
+            fill_color,
+            stroke_color,
+        });
+        pos = end.max(start + 1);
+    }
+
+    objects
+}
+
+/// `<hp:bookmark>` elements of one section XML, in document order.
+///
+/// Bookmarks inside table cells or notes count toward the top-level
+/// paragraph that contains them; `char_offset` counts the `<hp:t>` text of
+/// that paragraph before the bookmark.
+pub fn extract_bookmarks(section_xml: &str) -> Vec<Bookmark> {
+    let xml = canonical_prefixes(section_xml);
+    let xml = xml.as_ref();
+    let mut bookmarks = Vec::new();
+    let mut pos = 0;
+    let mut paragraph_index = 0;
+
+    while let Some((rel, _)) = find_element(&xml[pos..], &["hp:p"]) {
+        let start = pos + rel;
+        let Some(tag_len) = xml[start..].find('>') else {
+            break;
+        };
+        let end = find_matching_close_para(xml, start + tag_len + 1)
+            .map_or(xml.len(), |close| close + "</hp:p>".len());
+        let para = &xml[start..end];
+
+        let mut from = 0;
+        while let Some((rel, _)) = find_element(&para[from..], &["hp:bookmark"]) {
+            let at = from + rel;
+            let tag_end = para[at..].find('>').map_or(para.len(), |i| at + i);
+            if let Some(name) = extract_attr(&para[at..tag_end], "name") {
+                bookmarks.push(Bookmark {
+                    name: decode_xml_entities(&name),
+                    section_index: 0,
+                    paragraph_index,
+                    char_offset: extract_text_simple(&para[..at]).chars().filter(|&c| c != '\n').count(),
+                    paragraph_text: decode_xml_entities(
+                        &extract_text_simple(para).split_whitespace().collect::<Vec<_>>().join(" "),
+                    ),
+                });
+            }
+            from = at + 1;
+        }
+
+        paragraph_index += 1;
+        pos = end;
+    }
+
+    bookmarks
+}
+
+/// Form fields (`<hp:fieldBegin>` … `<hp:fieldEnd>`) of one section XML, in
+/// document order.
+///
+/// The end is the `<hp:fieldEnd>` whose `beginIDRef` matches the begin's
+/// `id`, else the next one in the paragraph; a field left open runs to the
+/// end of its paragraph. Like [`extract_bookmarks`], fields inside table
+/// cells count toward the top-level paragraph that contains them.
+pub fn extract_fields(section_xml: &str) -> Vec<HwpxField> {
+    let xml = canonical_prefixes(section_xml);
+    let xml = xml.as_ref();
+    let mut fields = Vec::new();
+    let mut pos = 0;
+    let mut paragraph_index = 0;
+
+    while let Some((rel, _)) = find_element(&xml[pos..], &["hp:p"]) {
+        let start = pos + rel;
+        let Some(tag_len) = xml[start..].find('>') else {
+            break;
+        };
+        let end = find_matching_close_para(xml, start + tag_len + 1)
+            .map_or(xml.len(), |close| close + "</hp:p>".len());
+        let para = &xml[start..end];
+
+        let mut from = 0;
+        while let Some((rel, _)) = find_element(&para[from..], &["hp:fieldBegin"]) {
+            let at = from + rel;
+            let tag_end = para[at..].find('>').map_or(para.len(), |i| at + i + 1);
+            let tag = &para[at..tag_end];
+            from = tag_end;
+
+            let raw_type = extract_attr(tag, " type").unwrap_or_default();
+            if raw_type.eq_ignore_ascii_case("HYPERLINK") {
+                continue;
+            }
+            // Leading space: `fieldid="..."` must not match `id`
+            let id = extract_attr(tag, " id");
+            let mut close = None;
+            let mut scan = tag_end;
+            while let Some((rel, _)) = find_element(&para[scan..], &["hp:fieldEnd"]) {
+                let end_at = scan + rel;
+                let end_tag = &para[end_at..para[end_at..].find('>').map_or(para.len(), |i| end_at + i)];
+                if id.is_none() || extract_attr(end_tag, "beginIDRef") == id {
+                    close = Some(end_at);
+                    break;
+                }
+                scan = end_at + 1;
+            }
+            // `<hp:parameters>` inside the begin element carries no `<hp:t>`
+            let value = run_text(&para[tag_end..close.unwrap_or(para.len())]);
+
+            fields.push(HwpxField {
+                field_type: field_type_name(&raw_type),
+                name: extract_attr(tag, " name").map(|n| decode_xml_entities(&n)).filter(|n| !n.is_empty()),
+                value: (!value.is_empty()).then_some(value),
+                section_index: 0,
+                paragraph_index,
+                paragraph_text: decode_xml_entities(
+                    &extract_text_simple(para).split_whitespace().collect::<Vec<_>>().join(" "),
+                ),
+            });
+        }
+
+        paragraph_index += 1;
+        pos = end;
+    }
+
+    fields
+}
+
+/// `DOC_DATE` → `doc-date`; `USER_INFO` is shortened to `user`
+fn field_type_name(raw: &str) -> String {
+    if raw.eq_ignore_ascii_case("USER_INFO") {
+        return "user".to_string();
+    }
+    if raw.is_empty() {
+        return "field".to_string();
+    }
+    raw.to_ascii_lowercase().replace('_', "-")
+}
+
+/// The start tag beginning with `prefix` (e.g. `"<hp:sz "`) inside `block`
+fn child_tag<'a>(block: &'a str, prefix: &str) -> Option<&'a str> {
+    let start = block.find(prefix)?;
+    let end = start + block[start..].find('>')?;
+    Some(&block[start..end])
+}
+
+/// `#RRGGBB` → `0xRRGGBB`; `none` and malformed values → `None`
+fn parse_hex_color(value: &str) -> Option<u32> {
+    let hex = value.strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok()
+}
+
+/// `<hp:sz width=".." height="..">` in HWPUNIT → pixels
+fn image_size(block: &str) -> Option<(u32, u32)> {
+    let start = block.find("<hp:sz ")?;
+    let tag = &block[start..start + block[start..].find('>')?];
+    let width: u64 = extract_attr(tag, "width")?.parse().ok()?;
+    let height: u64 = extract_attr(tag, "height")?.parse().ok()?;
+    (width > 0 && height > 0).then(|| (hwpunit_to_px(width), hwpunit_to_px(height)))
+}
+
+/// Caption paragraphs joined with a space, or the shape comment
+fn image_caption(block: &str) -> Option<String> {
+    fn inner<'a>(block: &'a str, open: &str, close: &str) -> Option<&'a str> {
+        let start = block.find(open)?;
+        let end = start + block[start..].find(close)?;
+        Some(&block[start..end])
+    }
+
+    let text = match inner(block, "<hp:caption", "</hp:caption>") {
+        Some(caption) => caption
+            .split("</hp:p>")
+            .map(run_text)
+            .filter(|t| !t.is_empty())
+            .collect::<Vec<_>>()
+            .join(" "),
+        None => inner(block, "<hp:shapeComment", "</hp:shapeComment>")
+            .and_then(|c| c.find('>').map(|i| &c[i + 1..]))
+            .map(|c| decode_xml_entities(c.trim()))
+            .unwrap_or_default(),
+    };
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!text.is_empty()).then_some(text)
+}
+
+/// Concatenated `<hp:t>` contents of an XML fragment, tags inside removed
+fn run_text(xml: &str) -> String {
+    let mut text = String::new();
+    let mut pos = 0;
+    while let Some(rel) = xml[pos..].find("<hp:t") {
+        let abs = pos + rel;
+        // Skip <hp:tab/>, <hp:tbl>, ...
+        if !matches!(xml[abs + 5..].chars().next(), Some(' ' | '>')) {
+            pos = abs + 5;
+            continue;
+        }
+        let Some(open_len) = xml[abs..].find('>') else {
+            break;
+        };
+        let content_start = abs + open_len + 1;
+        if xml[..content_start].ends_with("/>") {
+            pos = content_start;
+            continue;
+        }
+        let Some(close) = xml[content_start..].find("</hp:t>") else {
+            break;
+        };
+        let mut in_tag = false;
+        let raw: String = xml[content_start..content_start + close]
+            .chars()
+            .filter(|&c| match c {
+                '<' => {
+                    in_tag = true;
+                    false
+                }
+                '>' => {
+                    in_tag = false;
+                    false
+                }
+                _ => !in_tag,
+            })
+            .collect();
+        text.push_str(&decode_xml_entities(&raw));
+        pos = content_start + close + "</hp:t>".len();
+    }
+    text.trim().to_string()
 }
 
 /// Parse section XML and extract text with tables
@@ -639,11 +2053,39 @@ fn parse_section_xml(
     xml: &str,
     char_styles: &HashMap<u32, CharStyle>,
     heading_styles: &HashMap<u32, u8>,
+    para_styles: &HashMap<u32, ParaStyle>,
 ) -> (String, Vec<Table>) {
+    let (text, tables, _) =
+        parse_section_xml_with_spacing(xml, char_styles, heading_styles, para_styles, None);
+    (text, tables)
+}
+
+/// [`parse_section_xml`] with optional paragraph spacing preservation.
+///
+/// With `spacing` set, section-level `<hp:paraShape>` definitions are merged
+/// over the header ones and every paragraph that has spacing is wrapped in
+/// `<p style="margin-top:..pt;margin-bottom:..pt">`. When all wrapped
+/// paragraphs share one spacing, the inline styles are swapped for a class
+/// and its CSS rule is returned for the frontmatter.
+fn parse_section_xml_with_spacing(
+    xml: &str,
+    char_styles: &HashMap<u32, CharStyle>,
+    heading_styles: &HashMap<u32, u8>,
+    para_styles: &HashMap<u32, ParaStyle>,
+    spacing: Option<ParaSpacingOpts<'_>>,
+) -> (String, Vec<Table>, Option<String>) {
     // Strip <hp:secPr>...</hp:secPr> section-property blocks before processing.
-    let xml = strip_sec_pr(xml);
+    let xml = strip_sec_pr(&canonical_prefixes(xml));
     let xml = xml.as_str();
 
+    let section_shapes = spacing.map(|opts| {
+        let mut shapes = opts.shapes.clone();
+        shapes.extend(parse_para_shapes(xml));
+        shapes
+    });
+    let mut used_spacing: Vec<HwpxParaSpacing> = Vec::new();
+    let mut spacing_ctx = section_shapes.as_ref().map(|shapes| (shapes, &mut used_spacing));
+
     let mut result = String::new();
     let mut tables = Vec::new();
     let mut pos = 0;
@@ -659,7 +2101,13 @@ fn parse_section_xml(
 
             // Extract text before table
             let before_table = &xml[pos..tbl_pos];
-            result.push_str(&extract_text_with_formatting(before_table, char_styles, heading_styles));
+            result.push_str(&extract_text_with_formatting(
+                before_table,
+                char_styles,
+                heading_styles,
+                para_styles,
+                spacing_ctx.as_mut().map(|(shapes, used)| (*shapes, &mut **used)),
+            ));
 
             // Find matching table close — must be depth-aware because HWPX
             // tables can nest. See find_matching_close() for rationale.
@@ -698,14 +2146,33 @@ fn parse_section_xml(
             }
         } else {
             // No more tables, extract remaining text
-            result.push_str(&extract_text_with_formatting(&xml[pos..], char_styles, heading_styles));
+            result.push_str(&extract_text_with_formatting(
+                &xml[pos..],
+                char_styles,
+                heading_styles,
+                para_styles,
+                spacing_ctx.as_mut().map(|(shapes, used)| (*shapes, &mut **used)),
+            ));
             break;
         }
     }
 
     // Clean up result
-    let cleaned = clean_text(&result);
-    (cleaned, tables)
+    let mut cleaned = clean_text(&result);
+
+    // Uniform spacing → one class rule instead of repeating inline styles.
+    let mut css_rule = None;
+    if let (Some(opts), Some(first)) = (spacing, used_spacing.first()) {
+        if used_spacing.iter().all(|s| s == first) {
+            let class = para_spacing_class(opts.section_idx);
+            cleaned = cleaned.replace(
+                &format!("<p style=\"{}\">", first.to_css()),
+                &format!("<p class=\"{}\">", class),
+            );
+            css_rule = Some(format!(".{} {{ {} }}", class, first.to_css()));
+        }
+    }
+    (cleaned, tables, css_rule)
 }
 
 /// Classification threshold copied from kordoc's `handleNestedTable`
@@ -854,6 +2321,8 @@ fn preprocess_nested_tables(
 /// numbering to stay coherent across multiple top-level tables should call
 /// `parse_table_ctx` directly and supply a shared counter.
 fn parse_table(xml: &str, char_styles: &HashMap<u32, CharStyle>) -> Option<Table> {
+    let xml = canonical_prefixes(xml);
+    let xml = xml.as_ref();
     let mut counter = NestedTableCounter::default();
     let mut _separate = Vec::new();
     parse_table_ctx(xml, char_styles, &mut counter, &mut _separate, 0)
@@ -1078,17 +2547,19 @@ fn parse_table_ctx(
 /// For each `<hp:run>`, reads the `charPrIDRef` attribute and checks if the
 /// referenced style has bold/italic set. Applies `**` / `*` wrapping inline.
 fn extract_cell_text(xml: &str, char_styles: &HashMap<u32, CharStyle>) -> String {
+    let xml = canonical_prefixes(xml);
+    let xml = xml.as_ref();
+
     // Collect text segments per <hp:p> paragraph
     let mut paragraphs: Vec<String> = Vec::new();
     let mut p_pos = 0;
 
     loop {
         // Find next <hp:p>... opening (allow with or without attrs)
-        let opening = xml[p_pos..].find("<hp:p>").map(|i| (p_pos + i, 6))
-            .or_else(|| xml[p_pos..].find("<hp:p ").map(|i| (p_pos + i, 6)));
+        let opening = find_element(&xml[p_pos..], &["hp:p"]).map(|(i, _)| p_pos + i);
 
         match opening {
-            Some((p_start, _)) => {
+            Some(p_start) => {
                 let after_open = match xml[p_start..].find('>') {
                     Some(i) => p_start + i + 1,
                     None => break,
@@ -1730,6 +3201,7 @@ fn extract_attr(xml: &str, attr: &str) -> Option<String> {
 ///
 /// When `heading_styles` contains a mapping for the paragraph's `styleIDRef`,
 /// the paragraph text is prefixed with the appropriate number of `#` markers.
+/// Otherwise the outline level of its `paraPrIDRef` in `para_styles` is used.
 /// Depth-aware locator for the `</hp:p>` that closes the currently open
 /// paragraph starting just past `from`. Needed because paragraphs can
 /// nest — `<hp:footNote>` / `<hp:endNote>` / `<hp:tc>` each carry their
@@ -1782,11 +3254,30 @@ fn find_matching_close_para(xml: &str, from: usize) -> Option<usize> {
     None
 }
 
+/// NFC-normalize `input`: decomposed Hangul jamo sequences such as
+/// `"\u{1100}\u{1161}\u{11A8}"` become the precomposed syllable `"각"`.
+/// Text that is already NFC is returned unchanged.
+pub fn normalize_text(input: &str) -> String {
+    input.nfc().collect()
+}
+
+/// [`normalize_text`] over a parsed section's text and table cells
+fn normalize_section(text: &mut String, tables: &mut [Table]) {
+    *text = normalize_text(text);
+    for cell in tables.iter_mut().flat_map(|t| t.cells.iter_mut().flatten()) {
+        *cell = normalize_text(cell);
+    }
+}
+
 fn extract_text_with_formatting(
     xml: &str,
     char_styles: &HashMap<u32, CharStyle>,
     heading_styles: &HashMap<u32, u8>,
+    para_styles: &HashMap<u32, ParaStyle>,
+    mut spacing: Option<(&HashMap<u32, HwpxParaSpacing>, &mut Vec<HwpxParaSpacing>)>,
 ) -> String {
+    let xml = canonical_prefixes(xml);
+    let xml = xml.as_ref();
     let mut result = String::new();
     let mut pos = 0;
 
@@ -1794,16 +3285,9 @@ fn extract_text_with_formatting(
     // depth-aware matching to find the paired `</hp:p>` — naive substring
     // matching would be fooled by nested `<hp:p>` inside `<hp:footNote>` /
     // `<hp:endNote>` subLists.
-    while let Some(p_start) = xml[pos..].find("<hp:p") {
+    while let Some((p_start, _)) = find_element(&xml[pos..], &["hp:p"]) {
         let p_pos = pos + p_start;
-
-        // Confirm this is a real `<hp:p` opening (not `<hp:pic>` etc).
-        let after_tag_start = p_pos + 5;
-        let ch = xml[after_tag_start..].chars().next();
-        if !matches!(ch, Some('>') | Some(' ')) {
-            pos = after_tag_start;
-            continue;
-        }
+        let after_tag_start = p_pos + "<hp:p".len();
 
         let p_close = match find_matching_close_para(xml, after_tag_start) {
             Some(idx) => idx,
@@ -1812,10 +3296,19 @@ fn extract_text_with_formatting(
 
         let para_xml = &xml[p_pos..p_close + 7];
 
-        // Check for heading via styleIDRef on the <hp:p> tag
+        // Check for heading via styleIDRef on the <hp:p> tag, then via the
+        // outline level of its paraPrIDRef
+        let open_tag = &para_xml[..para_xml.find('>').unwrap_or(para_xml.len())];
         let heading_level = extract_attr(para_xml, "styleIDRef")
             .and_then(|id_str| id_str.parse::<u32>().ok())
             .and_then(|id| heading_styles.get(&id).copied())
+            .or_else(|| {
+                extract_attr(open_tag, "paraPrIDRef")
+                    .and_then(|id_str| id_str.parse::<u32>().ok())
+                    .and_then(|id| para_styles.get(&id))
+                    .map(|style| style.outline_level)
+                    .filter(|&level| level > 0)
+            })
             .unwrap_or(0);
 
         // Extract runs from this paragraph
@@ -1834,7 +3327,19 @@ fn extract_text_with_formatting(
                 let clean_heading = para_text.trim().replace("**", "");
                 result.push_str(&clean_heading);
             } else {
-                result.push_str(&para_text);
+                let para_spacing = spacing.as_mut().and_then(|(shapes, used)| {
+                    let sp = para_spacing_of(para_xml, shapes)?;
+                    used.push(sp);
+                    Some(sp)
+                });
+                match para_spacing {
+                    Some(sp) => result.push_str(&format!(
+                        "<p style=\"{}\">{}</p>",
+                        sp.to_css(),
+                        para_text.trim()
+                    )),
+                    None => result.push_str(&para_text),
+                }
             }
             result.push('\n');
         }
@@ -1847,6 +3352,8 @@ fn extract_text_with_formatting(
 
 /// Extract runs with formatting applied
 fn extract_runs_with_formatting(para_xml: &str, char_styles: &HashMap<u32, CharStyle>) -> String {
+    let para_xml = canonical_prefixes(para_xml);
+    let para_xml = para_xml.as_ref();
     let mut result = String::new();
     let mut pos = 0;
 
@@ -2222,6 +3729,672 @@ fn clean_text(text: &str) -> String {
 mod tests {
     use super::*;
 
+    /// In-memory HWPX-shaped ZIP with the given `(name, contents)` entries
+    fn zip_bytes(entries: &[(&str, &str)]) -> Vec<u8> {
+        use std::io::Write;
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default();
+        for (name, contents) in entries {
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(contents.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    /// Package whose preview part holds raw `preview` bytes
+    fn preview_package(preview: &[u8]) -> Vec<u8> {
+        use std::io::Write;
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default();
+        writer.start_file("mimetype", options).unwrap();
+        writer.write_all(b"application/hwp+zip").unwrap();
+        writer.start_file("Contents/section0.xml", options).unwrap();
+        writer.write_all("<hs:sec><hp:p><hp:run><hp:t>본문</hp:t></hp:run></hp:p></hs:sec>".as_bytes()).unwrap();
+        writer.start_file("Preview/PrvText.txt", options).unwrap();
+        writer.write_all(preview).unwrap();
+        writer.finish().unwrap().into_inner()
+    }
+
+    /// "각" and "한글" decomposed into conjoining jamo (NFD)
+    const NFD_GAK: &str = "\u{1100}\u{1161}\u{11A8}";
+    const NFD_HANGUL: &str = "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}";
+
+    #[test]
+    fn test_normalize_text_composes_jamo() {
+        assert_eq!(normalize_text(NFD_GAK), "각");
+        assert_eq!(normalize_text(&format!("{} 문서", NFD_HANGUL)), "한글 문서");
+        assert_eq!(normalize_text("이미 NFC, ascii"), "이미 NFC, ascii");
+        assert_eq!(normalize_text(""), "");
+    }
+
+    /// Package whose section paragraph and table cell hold NFD text
+    fn nfd_package() -> Vec<u8> {
+        let section = format!(
+            "<hs:sec><hp:p><hp:run><hp:t>{gak}</hp:t></hp:run></hp:p>\
+             <hp:tbl rowCnt=\"1\" colCnt=\"1\"><hp:tr><hp:tc><hp:subList><hp:p><hp:run>\
+             <hp:t>{hangul}</hp:t></hp:run></hp:p></hp:subList></hp:tc></hp:tr></hp:tbl></hs:sec>",
+            gak = NFD_GAK,
+            hangul = NFD_HANGUL,
+        );
+        zip_bytes(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Contents/section0.xml", &section),
+            ("Preview/PrvText.txt", NFD_GAK),
+        ])
+    }
+
+    #[test]
+    fn test_unicode_normalization_enabled() {
+        let doc = HwpxParser::from_bytes(nfd_package())
+            .unwrap()
+            .with_unicode_normalization(true)
+            .parse()
+            .unwrap();
+        assert!(doc.sections[0].contains("각"), "{:?}", doc.sections[0]);
+        assert!(!doc.sections[0].contains(NFD_GAK));
+        assert_eq!(doc.tables[0].cells[0][0], "한글");
+        assert_eq!(doc.preview_text, "각");
+    }
+
+    #[test]
+    fn test_unicode_normalization_disabled_by_default() {
+        let doc = HwpxParser::from_bytes(nfd_package()).unwrap().parse().unwrap();
+        assert!(doc.sections[0].contains(NFD_GAK));
+        assert!(!doc.sections[0].contains("각"));
+        assert_eq!(doc.preview_text, NFD_GAK);
+    }
+
+    #[test]
+    fn test_unicode_normalization_in_section_iter() {
+        let sections: Vec<_> = HwpxParser::from_bytes(nfd_package())
+            .unwrap()
+            .with_unicode_normalization(true)
+            .section_text_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert!(sections[0].0.contains("각"), "{:?}", sections[0].0);
+    }
+
+    #[test]
+    fn test_preview_text_utf8() {
+        let mut parser = HwpxParser::from_bytes(preview_package("<미리보기>\r\n둘째 줄".as_bytes())).unwrap();
+        let doc = parser.parse().unwrap();
+        assert_eq!(doc.preview_text, "<미리보기>\r\n둘째 줄");
+        assert_eq!(doc.preview_text_encoding, "utf-8");
+        assert_eq!(parser.preview_encoding(), "utf-8");
+    }
+
+    #[test]
+    fn test_preview_text_euc_kr() {
+        let (encoded, _, _) = encoding_rs::EUC_KR.encode("한글 문서 미리보기");
+        assert!(std::str::from_utf8(&encoded).is_err());
+        let mut parser = HwpxParser::from_bytes(preview_package(&encoded)).unwrap();
+        assert_eq!(parser.preview_encoding(), "utf-8");
+
+        let doc = parser.parse().unwrap();
+        assert_eq!(doc.preview_text, "한글 문서 미리보기");
+        assert_eq!(doc.preview_text_encoding, "euc-kr");
+        assert_eq!(parser.preview_encoding(), "euc-kr");
+
+        let meta = parser.read_meta().unwrap();
+        assert_eq!(meta.preview_text, "한글 문서 미리보기");
+        assert_eq!(meta.preview_text_encoding, "euc-kr");
+    }
+
+    #[test]
+    fn test_preview_text_raw_bytes() {
+        let (encoded, _, _) = encoding_rs::EUC_KR.encode("원문");
+        let mut parser = HwpxParser::from_bytes(preview_package(&encoded)).unwrap();
+        assert_eq!(parser.read_preview_text_raw().unwrap(), encoded.into_owned());
+
+        let mut missing = HwpxParser::from_bytes(zip_bytes(&[("mimetype", "application/hwp+zip")])).unwrap();
+        assert!(missing.read_preview_text_raw().is_err());
+    }
+
+    #[test]
+    fn test_decode_preview_text_fallbacks() {
+        assert_eq!(decode_preview_text(b"\xEF\xBB\xBFplain"), ("plain".to_string(), "utf-8"));
+        // 0xFF is neither UTF-8 nor a valid EUC-KR lead byte
+        let (text, encoding) = decode_preview_text(b"ok\xFF");
+        assert_eq!(text, "ok\u{FFFD}");
+        assert_eq!(encoding, "utf-8");
+    }
+
+    #[test]
+    fn test_section_text_iter_matches_parse() {
+        let section = |text: &str| {
+            format!(
+                r#"<hs:sec xmlns:hs="http://www.hancom.co.kr/hwpml/2011/section" xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph"><hp:p><hp:run><hp:t>{}</hp:t></hp:run></hp:p></hs:sec>"#,
+                text
+            )
+        };
+        let (first, second) = (section("첫째 섹션"), section("둘째 섹션"));
+        let bytes = zip_bytes(&[
+            ("mimetype", "application/hwp+zip"),
+            ("version.xml", r#"<hv:HCFVersion version="1.4"/>"#),
+            ("Contents/section0.xml", &first),
+            ("Contents/section1.xml", &second),
+        ]);
+
+        let texts: Vec<String> = HwpxParser::from_bytes(bytes.clone())
+            .unwrap()
+            .section_text_iter()
+            .map(|s| s.unwrap().0)
+            .collect();
+        assert_eq!(texts.len(), 2);
+        assert!(texts[0].contains("첫째 섹션"));
+
+        let bulk = HwpxParser::from_bytes(bytes.clone()).unwrap().parse().unwrap();
+        let mut parser = HwpxParser::from_bytes(bytes).unwrap();
+        let meta = parser.read_meta().unwrap();
+        let lazy = HwpxDocument::from_iter(parser.section_text_iter(), meta).unwrap();
+        assert_eq!(lazy.sections, bulk.sections);
+        assert_eq!(lazy.version, "1.4");
+    }
+
+    #[test]
+    fn test_section_text_iter_rejects_encrypted() {
+        let bytes = zip_bytes(&[
+            ("settings/encrypt.xml", "<encrypt/>"),
+            ("Contents/section0.xml", "ciphertext"),
+        ]);
+        let mut iter = HwpxParser::from_bytes(bytes).unwrap().section_text_iter();
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_encrypted_hwpx_detected() {
+        let bytes = zip_bytes(&[
+            ("mimetype", "application/hwp+zip"),
+            ("settings/encrypt.xml", "<encrypt algorithm=\"AES\"/>"),
+            ("Contents/section0.xml", "ciphertext"),
+        ]);
+        let archive = ZipArchive::new(Cursor::new(bytes.clone())).unwrap();
+        assert!(archive.file_names().any(|n| n == ENCRYPT_ENTRY));
+
+        let mut parser = HwpxParser::from_bytes(bytes).unwrap();
+        assert!(parser.is_encrypted());
+        let err = parser.parse().unwrap_err();
+        assert!(matches!(err, HwpxError::Encrypted));
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::PermissionDenied);
+    }
+
+    const PIC_SECTION: &str = r#"<hs:sec xmlns:hp="http://www.hancom.co.kr/hwpml/2011/paragraph">
+        <hp:p><hp:run><hp:t>본문</hp:t></hp:run></hp:p>
+        <hp:p><hp:run><hp:pic id="1">
+            <hp:sz width="28800" widthRelTo="ABSOLUTE" height="14400" heightRelTo="ABSOLUTE" protect="0"/>
+            <hc:img binaryItemIDRef="image1" bright="0" contrast="0"/>
+            <hp:shapeComment>그림입니다.</hp:shapeComment>
+            <hp:caption side="BOTTOM"><hp:subList>
+                <hp:p><hp:run><hp:t>그림 1. 시장 </hp:t><hp:t>&amp; 구조</hp:t></hp:run></hp:p>
+                <hp:p><hp:run><hp:t>(2024년)</hp:t></hp:run></hp:p>
+            </hp:subList></hp:caption>
+        </hp:pic></hp:run></hp:p>
+        <hp:p><hp:run><hp:drawingObject>
+            <hp:sz width="7500" height="3750"/>
+            <hc:img binaryItemIDRef="image2"/>
+            <hp:shapeComment>로고 [회사]</hp:shapeComment>
+        </hp:drawingObject></hp:run></hp:p>
+    </hs:sec>"#;
+
+    #[test]
+    fn test_parse_section_images() {
+        let images = parse_section_images(PIC_SECTION);
+        assert_eq!(
+            images,
+            vec![
+                SectionImage {
+                    id: "image1".to_string(),
+                    alt_text: Some("그림 1. 시장 & 구조 (2024년)".to_string()),
+                    dimensions: Some((384, 192)),
+                },
+                SectionImage {
+                    id: "image2".to_string(),
+                    alt_text: Some("로고 [회사]".to_string()),
+                    dimensions: Some((100, 50)),
+                },
+            ]
+        );
+        assert!(parse_section_images("<hs:sec><hp:p/></hs:sec>").is_empty());
+    }
+
+    const SHAPE_SECTION: &str = r##"<hs:sec><hp:p><hp:run charPrIDRef="0">
+        <hp:rect id="1" ratio="0">
+            <hp:lineShape color="#0000FF" width="33" style="SOLID"/>
+            <hp:fillBrush><hc:winBrush faceColor="#FF0000" hatchColor="#000000" alpha="0"/></hp:fillBrush>
+            <hp:sz width="7500" widthRelTo="ABSOLUTE" height="3750" heightRelTo="ABSOLUTE"/>
+            <hp:pos treatAsChar="0" vertOffset="1500" horzOffset="750"/>
+        </hp:rect>
+        <hp:ellipse id="2">
+            <hp:lineShape color="#000000" width="33" style="NONE"/>
+            <hp:fillBrush><hc:winBrush faceColor="none" hatchColor="#000000" alpha="0"/></hp:fillBrush>
+            <hp:curSz width="1500" height="750"/>
+            <hp:pos vertOffset="0" horzOffset="0"/>
+        </hp:ellipse>
+        <hp:line id="3" isReverseHV="0">
+            <hp:lineShape color="#00FF00" width="33" style="SOLID"/>
+            <hp:sz width="7500" height="0"/>
+            <hp:pos vertOffset="-75" horzOffset="150"/>
+        </hp:line>
+        <hp:t>본문</hp:t><hp:lineBreak/>
+    </hp:run></hp:p></hs:sec>"##;
+
+    #[test]
+    fn test_extract_drawing_rect() {
+        let objects = extract_drawing_objects(SHAPE_SECTION);
+        assert_eq!(objects.len(), 3);
+        let rect = &objects[0];
+        assert_eq!(
+            rect,
+            &DrawingObject {
+                shape_type: ShapeKind::Rect,
+                x: 750,
+                y: 1500,
+                width: 7500,
+                height: 3750,
+                fill_color: Some(0xFF0000),
+                stroke_color: Some(0x0000FF),
+            }
+        );
+        assert_eq!(
+            rect.to_svg_element(),
+            r##"<rect x="10" y="20" width="100" height="50" fill="#ff0000" stroke="#0000ff" stroke-width="1"/>"##
+        );
+        assert!(rect.to_svg().contains(r#"width="100" height="50" viewBox="10 20 100 50""#));
+    }
+
+    #[test]
+    fn test_extract_drawing_ellipse() {
+        let ellipse = &extract_drawing_objects(SHAPE_SECTION)[1];
+        assert_eq!(ellipse.shape_type, ShapeKind::Ellipse);
+        assert_eq!((ellipse.width, ellipse.height), (1500, 750));
+        // faceColor="none" and style="NONE" leave the shape unpainted
+        assert_eq!((ellipse.fill_color, ellipse.stroke_color), (None, None));
+        assert_eq!(
+            ellipse.to_svg_element(),
+            r#"<ellipse cx="10" cy="5" rx="10" ry="5" fill="none" stroke="none" stroke-width="1"/>"#
+        );
+    }
+
+    #[test]
+    fn test_extract_drawing_line() {
+        let line = &extract_drawing_objects(SHAPE_SECTION)[2];
+        assert_eq!(line.shape_type, ShapeKind::Line);
+        // Negative offsets clamp to the page origin
+        assert_eq!((line.x, line.y, line.width, line.height), (150, 0, 7500, 0));
+        assert_eq!(line.stroke_color, Some(0x00FF00));
+        assert_eq!(
+            line.to_svg_element(),
+            r##"<line x1="2" y1="0" x2="102" y2="0" stroke="#00ff00" stroke-width="1"/>"##
+        );
+        // The zero-height box still gets a visible viewBox
+        assert!(line.to_svg().contains(r#"width="100" height="1""#));
+    }
+
+    #[test]
+    fn test_drawings_in_mdx() {
+        let bytes = zip_bytes(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Contents/section0.xml", SHAPE_SECTION),
+        ]);
+        let doc = HwpxParser::from_bytes(bytes).unwrap().parse().unwrap();
+        assert_eq!(doc.drawings.len(), 3);
+        assert!(extract_drawing_objects("<hs:sec><hp:p><hp:run><hp:t>x</hp:t></hp:run></hp:p></hs:sec>").is_empty());
+
+        let mdx = doc.to_mdx(doc.join_mode);
+        let uris: Vec<&str> = mdx
+            .split("![shape](data:image/svg+xml;base64,")
+            .skip(1)
+            .map(|rest| rest.split(')').next().unwrap())
+            .collect();
+        assert_eq!(uris.len(), 3);
+        let svg = base64::engine::general_purpose::STANDARD.decode(uris[0]).unwrap();
+        assert!(String::from_utf8(svg).unwrap().contains("<rect x=\"10\""));
+    }
+
+    const FIELD_SECTION: &str = r#"<hs:sec>
+        <hp:p id="0"><hp:run><hp:t>신청인: </hp:t></hp:run><hp:run><hp:ctrl><hp:fieldBegin id="11" type="CLICK_HERE" name="성명" fieldid="627272811"><hp:parameters cnt="1"><hp:stringParam name="Direction">이름을 입력하세요</hp:stringParam></hp:parameters></hp:fieldBegin></hp:ctrl></hp:run><hp:run><hp:t>홍길동</hp:t></hp:run><hp:run><hp:ctrl><hp:fieldEnd beginIDRef="11" fieldid="627272811"/></hp:ctrl></hp:run></hp:p>
+        <hp:p id="1"><hp:run><hp:t>작성일: </hp:t><hp:ctrl><hp:fieldBegin id="12" type="DATE"/></hp:ctrl><hp:t>2024년 5월 1일</hp:t><hp:ctrl><hp:fieldEnd beginIDRef="12"/></hp:ctrl></hp:run></hp:p>
+        <hp:p id="2"><hp:run><hp:t>담당자: </hp:t><hp:ctrl><hp:fieldBegin id="13" type="USER_INFO"/></hp:ctrl><hp:ctrl><hp:fieldEnd beginIDRef="13"/></hp:ctrl></hp:run></hp:p>
+        <hp:p id="3"><hp:run><hp:ctrl><hp:fieldBegin id="14" type="HYPERLINK" name="https://example.com"/></hp:ctrl><hp:t>링크</hp:t><hp:ctrl><hp:fieldEnd beginIDRef="14"/></hp:ctrl></hp:run></hp:p>
+        <hp:p id="4"><hp:run><hp:ctrl><hp:fieldBegin id="15" type="DOC_DATE"/></hp:ctrl><hp:ctrl><hp:fieldEnd beginIDRef="15"/></hp:ctrl></hp:run></hp:p>
+    </hs:sec>"#;
+
+    #[test]
+    fn test_extract_fields_types_and_values() {
+        let fields = extract_fields(FIELD_SECTION);
+        let found: Vec<(&str, Option<&str>)> =
+            fields.iter().map(|f| (f.field_type.as_str(), f.value.as_deref())).collect();
+        assert_eq!(
+            found,
+            vec![
+                ("click-here", Some("홍길동")),
+                ("date", Some("2024년 5월 1일")),
+                ("user", None),
+                ("doc-date", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_fields_paragraph_position() {
+        let fields = extract_fields(FIELD_SECTION);
+        let indices: Vec<usize> = fields.iter().map(|f| f.paragraph_index).collect();
+        assert_eq!(indices, vec![0, 1, 2, 4]);
+        assert_eq!(fields[0].paragraph_text, "신청인: 홍길동");
+        assert_eq!(fields[0].name.as_deref(), Some("성명"));
+        assert_eq!(fields[1].name, None);
+        assert!(fields.iter().all(|f| f.section_index == 0));
+    }
+
+    #[test]
+    fn test_extract_fields_skips_hyperlinks_and_plain_text() {
+        assert!(extract_fields(FIELD_SECTION).iter().all(|f| f.value.as_deref() != Some("링크")));
+        assert!(extract_fields("<hs:sec><hp:p><hp:run><hp:t>x</hp:t></hp:run></hp:p></hs:sec>").is_empty());
+    }
+
+    #[test]
+    fn test_extract_fields_end_matched_by_id() {
+        // Outer field encloses a whole inner field; `fieldid` must not be read as `id`
+        let xml = r#"<hs:sec><hp:p><hp:run><hp:ctrl><hp:fieldBegin fieldid="9" id="1" type="MAIL_MERGE"/></hp:ctrl><hp:t>가</hp:t><hp:ctrl><hp:fieldBegin fieldid="1" id="2" type="SUMMARY"/></hp:ctrl><hp:t>나</hp:t><hp:ctrl><hp:fieldEnd beginIDRef="2"/></hp:ctrl><hp:t>다</hp:t><hp:ctrl><hp:fieldEnd beginIDRef="1"/></hp:ctrl></hp:run></hp:p></hs:sec>"#;
+        let fields = extract_fields(xml);
+        assert_eq!(fields.len(), 2);
+        assert_eq!((fields[0].field_type.as_str(), fields[0].value.as_deref()), ("mail-merge", Some("가나다")));
+        assert_eq!((fields[1].field_type.as_str(), fields[1].value.as_deref()), ("summary", Some("나")));
+    }
+
+    #[test]
+    fn test_extract_fields_unclosed_and_hwp_prefix() {
+        let xml = r#"<hs:sec><hwp:p><hwp:run><hwp:ctrl><hwp:fieldBegin type="PATH"/></hwp:ctrl><hwp:t>C:\문서</hwp:t></hwp:run></hwp:p><hwp:p><hwp:run><hwp:t>다음</hwp:t></hwp:run></hwp:p></hs:sec>"#;
+        let fields = extract_fields(xml);
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].field_type, "path");
+        assert_eq!(fields[0].value.as_deref(), Some(r"C:\문서"));
+    }
+
+    #[test]
+    fn test_field_type_name_and_markdown() {
+        assert_eq!(field_type_name("DOC_DATE"), "doc-date");
+        assert_eq!(field_type_name("USER_INFO"), "user");
+        assert_eq!(field_type_name("CLICK_HERE"), "click-here");
+        assert_eq!(field_type_name(""), "field");
+
+        let mut field = extract_fields(FIELD_SECTION).remove(1);
+        assert_eq!(field.to_markdown(), "**date:** 2024년 5월 1일");
+        field.value = None;
+        assert_eq!(field.to_markdown(), "**date:** ___");
+    }
+
+    #[test]
+    fn test_fields_in_mdx() {
+        let bytes = zip_bytes(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Contents/section0.xml", "<hs:sec><hp:p><hp:run><hp:t>표지</hp:t></hp:run></hp:p></hs:sec>"),
+            ("Contents/section1.xml", FIELD_SECTION),
+        ]);
+        let doc = HwpxParser::from_bytes(bytes).unwrap().parse().unwrap();
+        assert_eq!(doc.fields.len(), 4);
+        assert!(doc.fields.iter().all(|f| f.section_index == 1));
+
+        let mdx = doc.to_mdx(SectionJoinMode::Concatenate);
+        assert!(mdx.contains("신청인: **click-here:** 홍길동"), "{}", mdx);
+        assert!(mdx.contains("작성일: **date:** 2024년 5월 1일"), "{}", mdx);
+        assert!(mdx.contains("담당자: **user:** ___"), "{}", mdx);
+        assert!(mdx.contains("\n\n**doc-date:** ___"), "{}", mdx);
+        assert!(mdx.contains("[링크](https://example.com)"), "{}", mdx);
+        assert!(!mdx.contains("홍길동 **click-here"), "{}", mdx);
+    }
+
+    #[test]
+    fn test_label_fields_repeated_values_on_one_line() {
+        let field = |value: &str| HwpxField {
+            field_type: "click-here".to_string(),
+            name: None,
+            value: Some(value.to_string()),
+            section_index: 0,
+            paragraph_index: 0,
+            paragraph_text: "성명 김 김".to_string(),
+        };
+        let (first, second) = (field("김"), field("김"));
+        let out = label_fields("머리\n성명 김 김", &[&first, &second]);
+        assert_eq!(out, "머리\n성명 **click-here:** 김 **click-here:** 김");
+        assert_eq!(label_fields("그대로", &[]), "그대로");
+    }
+
+    const BOOKMARK_SECTION: &str = r#"<hs:sec>
+        <hp:p id="0"><hp:run><hp:t>서문</hp:t></hp:run></hp:p>
+        <hp:p id="1"><hp:run><hp:ctrl><hp:bookmark name="목적"/></hp:ctrl><hp:t>제1조 이 규정은 목적을 정한다.</hp:t></hp:run></hp:p>
+        <hp:p id="2"><hp:run><hp:t>제2조 용어</hp:t><hp:ctrl><hp:bookmark name="term &amp; def"/></hp:ctrl><hp:t>의 정의</hp:t></hp:run></hp:p>
+        <hp:p id="3"><hp:run><hp:t>맺음말</hp:t><hp:ctrl><hp:bookmark name="end"/></hp:ctrl></hp:run></hp:p>
+    </hs:sec>"#;
+
+    #[test]
+    fn test_extract_bookmarks() {
+        let bookmarks = extract_bookmarks(BOOKMARK_SECTION);
+        let found: Vec<(&str, usize, usize)> = bookmarks
+            .iter()
+            .map(|b| (b.name.as_str(), b.paragraph_index, b.char_offset))
+            .collect();
+        assert_eq!(found, vec![("목적", 1, 0), ("term & def", 2, 6), ("end", 3, 3)]);
+        assert_eq!(bookmarks[0].paragraph_text, "제1조 이 규정은 목적을 정한다.");
+        assert!(bookmarks.iter().all(|b| b.section_index == 0));
+        assert!(extract_bookmarks("<hs:sec><hp:p><hp:run><hp:t>x</hp:t></hp:run></hp:p></hs:sec>").is_empty());
+    }
+
+    #[test]
+    fn test_bookmark_anchors_in_mdx() {
+        let bytes = zip_bytes(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Contents/section0.xml", "<hs:sec><hp:p><hp:run><hp:t>첫 섹션</hp:t></hp:run></hp:p></hs:sec>"),
+            ("Contents/section1.xml", BOOKMARK_SECTION),
+        ]);
+        let doc = HwpxParser::from_bytes(bytes).unwrap().parse().unwrap();
+        assert_eq!(doc.bookmarks.len(), 3);
+        assert!(doc.bookmarks.iter().all(|b| b.section_index == 1));
+
+        let mdx = doc.to_mdx(doc.join_mode);
+        let anchor = mdx.find("<a id=\"목적\"></a>").expect("anchor for 목적");
+        assert!(anchor > mdx.find("서문").unwrap());
+        assert!(anchor < mdx.find("제1조").unwrap());
+        let term = mdx.find("<a id=\"term-&amp;-def\"></a>").expect("anchor for term & def");
+        assert!(term > mdx.find("제1조").unwrap() && term < mdx.find("제2조").unwrap());
+        let end = mdx.find("<a id=\"end\"></a>").unwrap();
+        assert!(end > mdx.find("제2조").unwrap() && end < mdx.find("맺음말").unwrap());
+    }
+
+    fn two_section_doc(mode: SectionJoinMode) -> HwpxDocument {
+        let bytes = zip_bytes(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Contents/section0.xml", "<hs:sec><hp:p><hp:run><hp:t>첫 섹션</hp:t></hp:run></hp:p></hs:sec>"),
+            ("Contents/section1.xml", "<hs:sec><hp:p><hp:run><hp:t>둘째 섹션</hp:t></hp:run></hp:p></hs:sec>"),
+        ]);
+        HwpxParser::from_bytes(bytes).unwrap().with_join_mode(mode).parse().unwrap()
+    }
+
+    /// Minimal JSON Schema check: `type`, `required`, `properties`, `items`
+    /// and `minimum` — enough for [`structured_json_schema`]
+    fn schema_errors(value: &serde_json::Value, schema: &serde_json::Value, path: &str) -> Vec<String> {
+        use serde_json::Value;
+        let mut errors = Vec::new();
+        if let Some(ty) = schema.get("type") {
+            let types: Vec<&str> = match ty {
+                Value::Array(list) => list.iter().filter_map(Value::as_str).collect(),
+                other => other.as_str().into_iter().collect(),
+            };
+            let matches = types.iter().any(|t| match *t {
+                "object" => value.is_object(),
+                "array" => value.is_array(),
+                "string" => value.is_string(),
+                "integer" => value.is_u64() || value.is_i64(),
+                "null" => value.is_null(),
+                _ => false,
+            });
+            if !matches {
+                errors.push(format!("{}: expected {:?}, got {}", path, types, value));
+                return errors;
+            }
+        }
+        if let (Some(min), Some(n)) = (schema.get("minimum").and_then(Value::as_i64), value.as_i64()) {
+            if n < min {
+                errors.push(format!("{}: {} < {}", path, n, min));
+            }
+        }
+        for key in schema.get("required").and_then(Value::as_array).into_iter().flatten() {
+            let key = key.as_str().unwrap();
+            if value.get(key).is_none() {
+                errors.push(format!("{}: missing {}", path, key));
+            }
+        }
+        if let (Some(props), Some(obj)) = (schema.get("properties").and_then(Value::as_object), value.as_object()) {
+            for (key, sub) in props {
+                if let Some(v) = obj.get(key) {
+                    errors.extend(schema_errors(v, sub, &format!("{}.{}", path, key)));
+                }
+            }
+        }
+        if let (Some(items), Some(list)) = (schema.get("items"), value.as_array()) {
+            for (i, v) in list.iter().enumerate() {
+                errors.extend(schema_errors(v, items, &format!("{}[{}]", path, i)));
+            }
+        }
+        errors
+    }
+
+    const STRUCTURED_SECTION: &str = r#"<hs:sec><hp:p><hp:run><hp:t>머리말</hp:t></hp:run></hp:p><hp:p><hp:run><hp:t>표 앞 문단</hp:t></hp:run></hp:p><hp:tbl rowCnt="2" colCnt="2"><hp:tr><hp:tc header="1"><hp:subList><hp:p><hp:run><hp:t>항목</hp:t></hp:run></hp:p></hp:subList></hp:tc><hp:tc header="1"><hp:subList><hp:p><hp:run><hp:t>값</hp:t></hp:run></hp:p></hp:subList></hp:tc></hp:tr><hp:tr><hp:tc header="0"><hp:subList><hp:p><hp:run><hp:t>가</hp:t></hp:run></hp:p></hp:subList></hp:tc><hp:tc header="0"><hp:subList><hp:p><hp:run><hp:t>1</hp:t></hp:run></hp:p></hp:subList></hp:tc></hp:tr></hp:tbl><hp:p><hp:run><hp:t>표 뒤 문단</hp:t></hp:run></hp:p></hs:sec>"#;
+
+    #[test]
+    fn test_structured_json_matches_schema() {
+        let manifest = r#"<opf:package><opf:manifest>
+            <opf:item id="image1" href="BinData/image1.png" media-type="image/png"/>
+        </opf:manifest></opf:package>"#;
+        let bytes = zip_bytes(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Contents/content.hpf", manifest),
+            ("Contents/section0.xml", STRUCTURED_SECTION),
+            ("Contents/section1.xml", "<hs:sec><hp:p><hp:run><hp:t>둘째 섹션</hp:t></hp:run></hp:p></hs:sec>"),
+            ("BinData/image1.png", "png-bytes"),
+        ]);
+        let doc = HwpxParser::from_bytes(bytes).unwrap().parse().unwrap();
+        let json = doc.to_structured_json();
+
+        let errors = schema_errors(&json, &structured_json_schema(), "$");
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        assert_eq!(json["metadata"]["section_count"], 2);
+        let first = &json["sections"][0];
+        let tables = first["tables"].as_array().unwrap();
+        assert_eq!(tables.len(), 1, "{}", json);
+        assert_eq!(tables[0]["headers"], serde_json::json!(["항목", "값"]));
+        assert_eq!(tables[0]["rows"], serde_json::json!([["가", "1"]]));
+        assert!(tables[0]["markdown"].as_str().unwrap().contains("| 항목 | 값 |"));
+        let paragraphs = first["paragraphs"].as_array().unwrap();
+        assert!(paragraphs.iter().any(|p| p.as_str().unwrap().contains("표 뒤 문단")));
+        assert!(paragraphs.iter().all(|p| !p.as_str().unwrap().contains('|')));
+        assert_eq!(json["sections"][1]["tables"], serde_json::json!([]));
+
+        let image = &json["images"][0];
+        assert_eq!(image["id"], "image1");
+        assert_eq!(image["media_type"], "image/png");
+        assert_eq!(image["size"], 9);
+    }
+
+    #[test]
+    fn test_structured_json_heading_and_schema_rejects_bad_shape() {
+        let section = SectionJson::from_body("## 제1장 총칙\n\n첫 문단\n\n## 제2장", Vec::new());
+        assert_eq!(section.heading.as_deref(), Some("제1장 총칙"));
+        assert_eq!(section.paragraphs, vec!["첫 문단", "## 제2장"]);
+
+        let table = Table {
+            rows: 2,
+            cols: 2,
+            cells: vec![vec!["a".into(), "b".into()], vec!["1".into(), "2".into()]],
+            has_header: false,
+            spans: Vec::new(),
+        };
+        let json = TableJson::from(&table);
+        assert!(json.headers.is_empty());
+        assert_eq!(json.rows.len(), 2);
+
+        let schema = structured_json_schema();
+        let bad = serde_json::json!({
+            "metadata": { "format": "hwpx", "version": "1.0", "section_count": -1 },
+            "sections": [{ "heading": 3, "paragraphs": [], "tables": [] }],
+        });
+        let errors = schema_errors(&bad, &schema, "$");
+        assert!(errors.iter().any(|e| e.contains("missing images")), "{:?}", errors);
+        assert!(errors.iter().any(|e| e.starts_with("$.sections[0].heading")), "{:?}", errors);
+        assert!(errors.iter().any(|e| e.starts_with("$.metadata.section_count")), "{:?}", errors);
+    }
+
+    #[test]
+    fn test_section_join_modes_in_mdx() {
+        let doc = two_section_doc(SectionJoinMode::PageBreakComment);
+        assert_eq!(doc.join_mode, SectionJoinMode::PageBreakComment);
+        assert!(doc.to_mdx(doc.join_mode).contains("첫 섹션\n\n<!-- pagebreak -->\n\n둘째 섹션"));
+
+        let cases = [
+            (SectionJoinMode::Concatenate, "첫 섹션\n\n둘째 섹션"),
+            (SectionJoinMode::HorizontalRule, "첫 섹션\n\n---\n\n둘째 섹션"),
+            (SectionJoinMode::HtmlPageBreak, "첫 섹션\n\n<div class=\"page-break\"></div>\n\n둘째 섹션"),
+        ];
+        for (mode, expected) in cases {
+            let mdx = doc.to_mdx(mode);
+            assert!(mdx.contains(expected), "{:?}: {}", mode, mdx);
+        }
+        assert_eq!(SectionJoinMode::default(), SectionJoinMode::HorizontalRule);
+    }
+
+    #[test]
+    fn test_section_join_mode_from_str() {
+        assert_eq!("concatenate".parse::<SectionJoinMode>(), Ok(SectionJoinMode::Concatenate));
+        assert_eq!("Horizontal-Rule".parse::<SectionJoinMode>(), Ok(SectionJoinMode::HorizontalRule));
+        assert_eq!("page-break-comment".parse::<SectionJoinMode>(), Ok(SectionJoinMode::PageBreakComment));
+        assert_eq!("html-page-break".parse::<SectionJoinMode>(), Ok(SectionJoinMode::HtmlPageBreak));
+        assert!("pagebreak".parse::<SectionJoinMode>().is_err());
+    }
+
+    #[test]
+    fn test_image_alt_text_in_mdx() {
+        let manifest = r#"<opf:package><opf:manifest>
+            <opf:item id="image1" href="BinData/image1.png" media-type="image/png"/>
+            <opf:item id="image2" href="BinData/image2.bmp" media-type="image/bmp"/>
+        </opf:manifest></opf:package>"#;
+        let bytes = zip_bytes(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Contents/content.hpf", manifest),
+            ("Contents/section0.xml", PIC_SECTION),
+            ("BinData/image1.png", "png"),
+            ("BinData/image2.bmp", "bmp"),
+        ]);
+        let doc = HwpxParser::from_bytes(bytes).unwrap().parse().unwrap();
+
+        let first = doc.image_info.iter().find(|i| i.id == "image1").unwrap();
+        assert_eq!(first.alt_text.as_deref(), Some("그림 1. 시장 & 구조 (2024년)"));
+        assert_eq!(first.dimensions, Some((384, 192)));
+
+        let content = "[이미지: image1]\n\n[이미지: image2]\n\n[이미지: image9]";
+        let linked = doc.link_images(content, |i| format!("assets/{}", i.id));
+        assert!(linked.contains("![그림 1. 시장 & 구조 (2024년)](assets/image1)"));
+        assert!(linked.contains(r"![로고 \[회사\]](assets/image2)"));
+        assert!(linked.contains("[이미지: image9]"));
+
+        let mdx = doc.to_mdx(doc.join_mode);
+        assert!(mdx.starts_with("---\nformat: hwpx\n"));
+        assert!(!mdx.contains("![]("));
+    }
+
+    #[test]
+    fn test_plain_hwpx_not_encrypted() {
+        let bytes = zip_bytes(&[
+            ("mimetype", "application/hwp+zip"),
+            ("Contents/section0.xml", "<hs:sec/>"),
+        ]);
+        let mut parser = HwpxParser::from_bytes(bytes).unwrap();
+        assert!(!parser.is_encrypted());
+        assert!(parser.parse().is_ok());
+    }
+
     #[test]
     fn test_table_to_markdown() {
         let table = Table {
@@ -2763,18 +4936,53 @@ mod tests {
         let heading_styles: HashMap<u32, u8> = [(2, 1)].into_iter().collect();
         let char_styles = HashMap::new();
         let xml = r#"<hp:sec><hp:p styleIDRef="2"><hp:run charPrIDRef="0"><hp:t>제목입니다</hp:t></hp:run></hp:p><hp:p styleIDRef="0"><hp:run charPrIDRef="0"><hp:t>본문입니다</hp:t></hp:run></hp:p></hp:sec>"#;
-        let (result, _) = parse_section_xml(xml, &char_styles, &heading_styles);
+        let (result, _) = parse_section_xml(xml, &char_styles, &heading_styles, &HashMap::new());
         assert!(result.contains("# 제목입니다"), "heading marker missing: {}", result);
         assert!(result.contains("본문입니다"));
     }
 
+    #[test]
+    fn test_parse_para_properties() {
+        let header_xml = r#"
+            <hh:paraPr id="0" tabPrIDRef="0" condense="0"><hh:align horizontal="JUSTIFY" vertical="BASELINE"/><hh:heading type="NONE" idRef="0" level="0"/></hh:paraPr>
+            <hh:paraPr id="3" tabPrIDRef="0"><hh:align horizontal="LEFT" vertical="BASELINE"/><hh:heading type="OUTLINE" idRef="0" level="1"/></hh:paraPr>
+            <hh:paraPr id="4"><hh:heading type="NUMBER" idRef="1" level="0"/></hh:paraPr>
+            <hh:paraPr id="5" outlineLevel="1"/>
+        "#;
+        let map = parse_para_properties(header_xml);
+        assert_eq!(map.len(), 4);
+        assert_eq!(map[&0], ParaStyle { outline_level: 0, alignment: "JUSTIFY".to_string() });
+        assert_eq!(map[&3], ParaStyle { outline_level: 2, alignment: "LEFT".to_string() });
+        assert_eq!(map[&4].outline_level, 0);
+        assert_eq!(map[&5].outline_level, 1);
+        assert_eq!(map[&5].alignment, "");
+    }
+
+    #[test]
+    fn test_heading_from_para_pr_in_section_xml() {
+        let para_styles = parse_para_properties(
+            r#"<hh:paraPr id="1" outlineLevel="1"/><hh:paraPr id="2" outlineLevel="2"/><hh:paraPr id="3" outlineLevel="3"/>"#,
+        );
+        let heading_styles: HashMap<u32, u8> = [(7, 1)].into_iter().collect();
+        let xml = r#"<hp:sec><hp:p paraPrIDRef="1"><hp:run charPrIDRef="0"><hp:t>장</hp:t></hp:run></hp:p><hp:p paraPrIDRef="2"><hp:run charPrIDRef="0"><hp:t>절</hp:t></hp:run></hp:p><hp:p paraPrIDRef="3"><hp:run charPrIDRef="0"><hp:t>관</hp:t></hp:run></hp:p><hp:p paraPrIDRef="0"><hp:run charPrIDRef="0"><hp:t>본문</hp:t></hp:run></hp:p><hp:p paraPrIDRef="3" styleIDRef="7"><hp:run charPrIDRef="0"><hp:t>스타일 우선</hp:t></hp:run></hp:p></hp:sec>"#;
+        let (result, _) = parse_section_xml(xml, &HashMap::new(), &heading_styles, &para_styles);
+        let lines: Vec<&str> = result.lines().filter(|l| !l.trim().is_empty()).collect();
+        assert_eq!(lines, vec!["# 장", "## 절", "### 관", "본문", "# 스타일 우선"], "{}", result);
+        assert_eq!(count_headings(&result), 4);
+    }
+
+    #[test]
+    fn test_count_headings() {
+        assert_eq!(count_headings("# a\nbody\n## b\n#hashtag\n| # | x |\n######## deep"), 2);
+    }
+
     #[test]
     fn test_linebreak_in_runs() {
         let char_styles = HashMap::new();
         let heading_styles = HashMap::new();
         // extract_runs_with_formatting requires <hp:run with attrs (space after "run")
         let xml = r#"<hp:p styleIDRef="0"><hp:run charPrIDRef="0"><hp:t>줄1</hp:t><hp:lineBreak/><hp:t>줄2</hp:t></hp:run></hp:p>"#;
-        let result = extract_text_with_formatting(xml, &char_styles, &heading_styles);
+        let result = extract_text_with_formatting(xml, &char_styles, &heading_styles, &HashMap::new(), None);
         assert!(result.contains("줄1\n줄2"), "linebreak not handled: {:?}", result);
     }
 
@@ -2786,7 +4994,7 @@ mod tests {
         let char_styles = HashMap::new();
         let heading_styles = HashMap::new();
         let xml = r#"<hp:sec><hp:p><hp:run charPrIDRef="0"><hp:ctrl><hp:footNote number="1" suffixChar="41"><hp:subList><hp:p><hp:run charPrIDRef="3"><hp:t>12345</hp:t></hp:run></hp:p></hp:subList></hp:footNote></hp:ctrl><hp:t/></hp:run></hp:p></hp:sec>"#;
-        let (result, _) = parse_section_xml(xml, &char_styles, &heading_styles);
+        let (result, _) = parse_section_xml(xml, &char_styles, &heading_styles, &HashMap::new());
         assert!(
             result.contains("[각주: 12345]"),
             "footnote marker missing or malformed: {:?}",
@@ -2799,7 +5007,7 @@ mod tests {
         let char_styles = HashMap::new();
         let heading_styles = HashMap::new();
         let xml = r#"<hp:sec><hp:p><hp:run charPrIDRef="0"><hp:ctrl><hp:endNote number="1" suffixChar="41"><hp:subList><hp:p><hp:run charPrIDRef="3"><hp:t>7890</hp:t></hp:run></hp:p></hp:subList></hp:endNote></hp:ctrl><hp:t/></hp:run></hp:p></hp:sec>"#;
-        let (result, _) = parse_section_xml(xml, &char_styles, &heading_styles);
+        let (result, _) = parse_section_xml(xml, &char_styles, &heading_styles, &HashMap::new());
         assert!(
             result.contains("[미주: 7890]"),
             "endnote marker missing or malformed: {:?}",
@@ -2815,7 +5023,7 @@ mod tests {
         let char_styles = HashMap::new();
         let heading_styles = HashMap::new();
         let xml = r#"<hp:sec><hp:p><hp:run charPrIDRef="0"><hp:equation version="Equation Version 60"><hp:script>y = x^2 + 2x + 1</hp:script></hp:equation><hp:t/></hp:run></hp:p></hp:sec>"#;
-        let (result, _) = parse_section_xml(xml, &char_styles, &heading_styles);
+        let (result, _) = parse_section_xml(xml, &char_styles, &heading_styles, &HashMap::new());
         assert!(
             result.contains("$y = x^2 + 2x + 1$"),
             "equation script not extracted: {:?}",
@@ -2831,7 +5039,7 @@ mod tests {
         let char_styles = HashMap::new();
         let heading_styles = HashMap::new();
         let xml = r#"<hp:sec><hp:p><hp:run charPrIDRef="0"><hp:t>본문</hp:t></hp:run><hp:run charPrIDRef="0"><hp:ctrl><hp:footNote number="1"><hp:subList><hp:p><hp:run charPrIDRef="3"><hp:t>주석내용</hp:t></hp:run></hp:p></hp:subList></hp:footNote></hp:ctrl><hp:t/></hp:run></hp:p></hp:sec>"#;
-        let (result, _) = parse_section_xml(xml, &char_styles, &heading_styles);
+        let (result, _) = parse_section_xml(xml, &char_styles, &heading_styles, &HashMap::new());
         // Body "본문" appears exactly once; footnote body "주석내용" appears
         // only inside the marker, not as standalone text.
         let body_count = result.matches("본문").count();
@@ -2849,7 +5057,7 @@ mod tests {
         let char_styles = HashMap::new();
         let heading_styles = HashMap::new();
         let xml = r#"<hp:sec><hp:p><hp:run charPrIDRef="0"><hp:ctrl><hp:footNote><hp:subList><hp:p><hp:run charPrIDRef="3"><hp:t>inner</hp:t></hp:run></hp:p></hp:subList></hp:footNote></hp:ctrl><hp:t>outer_after_note</hp:t></hp:run></hp:p></hp:sec>"#;
-        let (result, _) = parse_section_xml(xml, &char_styles, &heading_styles);
+        let (result, _) = parse_section_xml(xml, &char_styles, &heading_styles, &HashMap::new());
         // After the fix, the outer run completes properly and
         // "outer_after_note" is emitted too.
         assert!(
@@ -2872,4 +5080,104 @@ mod tests {
         assert!(!stripped.contains("숨김"));
         assert!(!stripped.contains("secPr"));
     }
+
+    fn spacing_opts(shapes: &HashMap<u32, HwpxParaSpacing>) -> Option<ParaSpacingOpts<'_>> {
+        Some(ParaSpacingOpts { shapes, section_idx: 0 })
+    }
+
+    #[test]
+    fn test_para_spacing_inline_styles() {
+        let xml = r#"<hp:sec><hp:paraShape id="1" paraSpaceAbove="1000" paraSpaceBelow="500"/><hp:paraShape id="2" paraSpaceAbove="0" paraSpaceBelow="0"/><hp:p paraPrIDRef="1"><hp:run charPrIDRef="0"><hp:t>첫째</hp:t></hp:run></hp:p><hp:p paraPrIDRef="2"><hp:run charPrIDRef="0"><hp:t>둘째</hp:t></hp:run></hp:p></hp:sec>"#;
+        let shapes = HashMap::new();
+        let (result, _, css) = parse_section_xml_with_spacing(
+            xml,
+            &HashMap::new(),
+            &HashMap::new(),
+            &HashMap::new(),
+            spacing_opts(&shapes),
+        );
+        assert!(result.contains(r#"<p style="margin-top:10pt;margin-bottom:5pt">첫째</p>"#), "{}", result);
+        assert!(result.contains(r#"<p style="margin-top:0pt;margin-bottom:0pt">둘째</p>"#), "{}", result);
+        assert!(css.is_none());
+    }
+
+    #[test]
+    fn test_para_spacing_uniform_section_uses_class() {
+        let xml = r#"<hp:sec><hp:p paraPrIDRef="1"><hp:run charPrIDRef="0"><hp:t>가</hp:t></hp:run></hp:p><hp:p paraPrIDRef="1"><hp:run charPrIDRef="0"><hp:t>나</hp:t></hp:run></hp:p></hp:sec>"#;
+        let shapes = parse_para_shapes(r#"<hp:paraShape id="1" paraSpaceAbove="1000" paraSpaceBelow="1000">"#);
+        let (result, _, css) = parse_section_xml_with_spacing(
+            xml,
+            &HashMap::new(),
+            &HashMap::new(),
+            &HashMap::new(),
+            spacing_opts(&shapes),
+        );
+        assert!(result.contains(r#"<p class="hwpx-para-s0">가</p>"#), "{}", result);
+        assert!(!result.contains("style="));
+        assert_eq!(
+            css.as_deref(),
+            Some(".hwpx-para-s0 { margin-top:10pt;margin-bottom:10pt }")
+        );
+    }
+
+    #[test]
+    fn test_para_spacing_off_by_default() {
+        let xml = r#"<hp:sec><hp:paraShape id="1" paraSpaceAbove="1000"/><hp:p paraPrIDRef="1"><hp:run charPrIDRef="0"><hp:t>본문</hp:t></hp:run></hp:p></hp:sec>"#;
+        let (result, _) = parse_section_xml(xml, &HashMap::new(), &HashMap::new(), &HashMap::new());
+        assert!(result.contains("본문"));
+        assert!(!result.contains("<p"));
+    }
+
+    #[test]
+    fn test_find_element_earliest_match() {
+        let xml = r#"<hp:pic/><hp:p id="1"><hp:p>"#;
+        assert_eq!(find_element(xml, &["hp:p"]), Some((9, "hp:p")));
+        assert_eq!(find_element(xml, &["hp:p", "hp:pic"]), Some((9, "hp:p")));
+        assert_eq!(find_element("<hp:p/><hp:p>", &["hp:p"]), Some((7, "hp:p")));
+        assert_eq!(find_element("<hp:pic>", &["hp:p"]), None);
+    }
+
+    #[test]
+    fn test_canonical_prefixes() {
+        assert!(matches!(canonical_prefixes("<hp:p><hp:t>x</hp:t></hp:p>"), Cow::Borrowed(_)));
+        assert_eq!(
+            canonical_prefixes("<hwp:p><hwp:t>x</hwp:t></hwp:p>"),
+            "<hp:p><hp:t>x</hp:t></hp:p>"
+        );
+    }
+
+    #[test]
+    fn test_parse_section_xml_hwp_prefix() {
+        let xml = r#"<hwp:sec><hwp:p><hwp:run charPrIDRef="0"><hwp:t>첫 문단</hwp:t></hwp:run></hwp:p><hwp:p><hwp:run charPrIDRef="0"><hwp:t>둘째 문단</hwp:t></hwp:run></hwp:p></hwp:sec>"#;
+        let (result, _) = parse_section_xml(xml, &HashMap::new(), &HashMap::new(), &HashMap::new());
+        assert!(result.contains("첫 문단"), "{}", result);
+        assert!(result.contains("둘째 문단"), "{}", result);
+    }
+
+    #[test]
+    fn test_extract_runs_with_formatting_hwp_prefix() {
+        let mut char_styles = HashMap::new();
+        char_styles.insert(1, CharStyle { bold: true, ..Default::default() });
+        let xml = r#"<hwp:p><hwp:run charPrIDRef="1"><hwp:t>굵게</hwp:t></hwp:run></hwp:p>"#;
+        assert_eq!(extract_runs_with_formatting(xml, &char_styles), "**굵게**");
+    }
+
+    #[test]
+    fn test_parse_table_hwp_prefix() {
+        let xml = concat!(
+            "<hwp:tbl rowCnt=\"1\" colCnt=\"2\">",
+            "<hwp:tr>",
+            "<hwp:tc><hwp:cellAddr colAddr=\"0\" rowAddr=\"0\"/><hwp:cellSpan colSpan=\"1\" rowSpan=\"1\"/>",
+            "<hwp:subList><hwp:p><hwp:run><hwp:t>이름</hwp:t></hwp:run></hwp:p></hwp:subList></hwp:tc>",
+            "<hwp:tc><hwp:cellAddr colAddr=\"1\" rowAddr=\"0\"/><hwp:cellSpan colSpan=\"1\" rowSpan=\"1\"/>",
+            "<hwp:subList><hwp:p><hwp:run><hwp:t>값</hwp:t></hwp:run></hwp:p></hwp:subList></hwp:tc>",
+            "</hwp:tr>",
+            "</hwp:tbl>",
+        );
+        let table = parse_table(xml, &HashMap::new()).expect("table");
+        assert_eq!(table.cells[0], vec!["이름".to_string(), "값".to_string()]);
+
+        let cell = r#"<hwp:tc><hwp:subList><hwp:p><hwp:run><hwp:t>셀</hwp:t></hwp:run></hwp:p></hwp:subList></hwp:tc>"#;
+        assert_eq!(extract_cell_text(cell, &HashMap::new()), "셀");
+    }
 }
@@ -153,14 +153,31 @@ impl IRTable {
     }
 }
 
+/// Footnote / endnote attached to an [`IRBlock::Paragraph`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InlineNote {
+    /// true for 미주 (endnote), false for 각주 (footnote)
+    pub is_endnote: bool,
+    /// Note body text
+    pub body: String,
+}
+
+impl InlineNote {
+    /// Inline rendering after the paragraph text: `[각주] body` / `[미주] body`
+    pub fn marker(&self) -> String {
+        let label = if self.is_endnote { "[미주]" } else { "[각주]" };
+        format!("{} {}", label, self.body)
+    }
+}
+
 /// Block-level union. The `IRBlock` variants carry all structural data
 /// that downstream features (diff, form extraction, search) need.
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum IRBlock {
     Paragraph {
         text: String,
-        /// Footnote / endnote body attached inline to this paragraph.
-        footnote: Option<String>,
+        /// Footnotes / endnotes referenced from this paragraph, in order.
+        notes: Vec<InlineNote>,
         /// Hyperlink URL attached to this paragraph (from HWP klnk / %tok).
         href: Option<String>,
     },
@@ -185,7 +202,7 @@ impl IRBlock {
     pub fn paragraph<S: Into<String>>(text: S) -> Self {
         IRBlock::Paragraph {
             text: text.into(),
-            footnote: None,
+            notes: Vec::new(),
             href: None,
         }
     }
@@ -335,24 +352,41 @@ pub(crate) fn ordered_list_ordinals(items: &[ListItem]) -> Vec<usize> {
 }
 
 pub fn blocks_to_markdown(blocks: &[IRBlock]) -> String {
+    blocks_to_markdown_with_notes(blocks).0
+}
+
+/// [`blocks_to_markdown`] plus the byte range of every inline note marker,
+/// one per [`InlineNote`] in block order.
+///
+/// A range covers the marker and the separator in front of it (`" "` for
+/// a paragraph's first note, `"; "` for the ones after, none on a
+/// paragraph without text), so replacing it leaves the paragraph text
+/// untouched.
+pub fn blocks_to_markdown_with_notes(blocks: &[IRBlock]) -> (String, Vec<std::ops::Range<usize>>) {
     let mut out = String::new();
+    let mut note_ranges = Vec::new();
     for (i, block) in blocks.iter().enumerate() {
         if i > 0 {
             out.push_str("\n\n");
         }
         match block {
-            IRBlock::Paragraph { text, footnote, href } => {
+            IRBlock::Paragraph { text, notes, href } => {
                 out.push_str(text);
                 if let Some(url) = href {
                     out.push_str(&format!(" <{}>", url));
                 }
-                if let Some(note) = footnote {
-                    // `note` already includes its own label ("[각주] ..." /
-                    // "[미주] ...") — set by the parser at emit time, so
-                    // appending a second "[각주]" here would duplicate the
-                    // label and misclassify endnotes as footnotes.
-                    out.push(' ');
-                    out.push_str(note);
+                // A note-only paragraph (reference mark after a table or
+                // image) starts with its marker
+                let bare = text.is_empty() && href.is_none();
+                for (n, note) in notes.iter().enumerate() {
+                    let start = out.len();
+                    out.push_str(match (n, bare) {
+                        (0, true) => "",
+                        (0, false) => " ",
+                        _ => "; ",
+                    });
+                    out.push_str(&note.marker());
+                    note_ranges.push(start..out.len());
                 }
             }
             IRBlock::Heading { level, text } => {
@@ -392,7 +426,7 @@ pub fn blocks_to_markdown(blocks: &[IRBlock]) -> String {
             }
         }
     }
-    out
+    (out, note_ranges)
 }
 
 fn render_table(table: &IRTable) -> String {
@@ -1496,12 +1530,9 @@ mod tests {
 
     #[test]
     fn markdown_paragraph_with_footnote_and_href() {
-        // Contract: the parser stores a fully-labeled marker in `footnote`
-        // (e.g. "[각주] …" or "[미주] …"). Serializer must NOT prepend a
-        // second label — see ir.rs Paragraph serialization.
         let blocks = vec![IRBlock::Paragraph {
             text: "본문".to_string(),
-            footnote: Some("[각주] 각주 내용".to_string()),
+            notes: vec![InlineNote { is_endnote: false, body: "각주 내용".to_string() }],
             href: Some("https://law.go.kr".to_string()),
         }];
         let md = blocks_to_markdown(&blocks);
@@ -1514,11 +1545,9 @@ mod tests {
 
     #[test]
     fn markdown_paragraph_endnote_not_misclassified_as_footnote() {
-        // Before the fix the serializer always prepended "[각주]" even when
-        // the stored marker was an endnote — producing "[각주] [미주] …".
         let blocks = vec![IRBlock::Paragraph {
             text: "본문".to_string(),
-            footnote: Some("[미주] 미주 내용".to_string()),
+            notes: vec![InlineNote { is_endnote: true, body: "미주 내용".to_string() }],
             href: None,
         }];
         let md = blocks_to_markdown(&blocks);
@@ -1526,6 +1555,23 @@ mod tests {
         assert!(!md.contains("[각주]"), "endnote mislabeled: {:?}", md);
     }
 
+    #[test]
+    fn markdown_note_ranges_cover_each_marker() {
+        let note = |is_endnote, body: &str| InlineNote { is_endnote, body: body.to_string() };
+        let blocks = vec![
+            IRBlock::paragraph("앞 문단"),
+            IRBlock::Paragraph {
+                text: "본문".to_string(),
+                notes: vec![note(false, "첫째"), note(true, "둘째")],
+                href: None,
+            },
+        ];
+        let (md, ranges) = blocks_to_markdown_with_notes(&blocks);
+        assert_eq!(md, "앞 문단\n\n본문 [각주] 첫째; [미주] 둘째");
+        let markers: Vec<&str> = ranges.iter().map(|r| &md[r.clone()]).collect();
+        assert_eq!(markers, [" [각주] 첫째", "; [미주] 둘째"]);
+    }
+
     #[test]
     fn markdown_heading_level_clamped() {
         let blocks = vec![
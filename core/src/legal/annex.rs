@@ -187,6 +187,8 @@ impl AnnexParser {
                         cols: t.cols,
                         cells: t.cells.clone(),
                         cell_spans: Vec::new(),
+                        cell_styles: Vec::new(),
+                        has_header: true,
                     };
                     td.to_markdown()
                 }).collect::<Vec<_>>().join("\n\n"),
@@ -2,13 +2,24 @@
 //!
 //! 한국 법령 문서를 조(Article) 단위로 파싱하여 청크를 생성합니다.
 
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 use sha2::{Sha256, Digest};
+use thiserror::Error;
 
 use crate::legal::patterns::*;
 use crate::legal::types::*;
 
+/// 청킹 오류
+#[derive(Error, Debug)]
+pub enum ChunkerError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Parse error: {0}")]
+    ParseError(String),
+}
+
 /// 한국 법률 문서 청킹 클래스
 pub struct KoreanLegalChunker {
     /// 조(Article) 단위로 청킹할지 여부
@@ -57,13 +68,64 @@ impl KoreanLegalChunker {
         }
     }
 
-    /// 토큰 수 추정 (한글은 대략 1.5자당 1토큰)
+    /// 토큰 수 추정 ([`TokenBreakdown::estimated_tokens`])
     pub fn estimate_tokens(&self, text: &str) -> usize {
-        let korean_count = RE_KOREAN.find_iter(text).count();
-        let alphanum_count = RE_ALPHANUMERIC.find_iter(text).count();
-        let space_count = RE_WHITESPACE.find_iter(text).count();
+        Self::tokenize_breakdown(text).estimated_tokens()
+    }
+
+    /// `text`를 어절·로마자·한자·숫자·문장부호로 나눠 센다
+    ///
+    /// 한글이 하나라도 든 어절은 한 번만 세고, 그 안의 한자·숫자·문장부호는
+    /// 따로 센다 (`제42조제1항에` → 어절 1, 수 2). 옛 법령의 한자는 글자마다,
+    /// 로마자와 숫자는 연속 구간마다 센다.
+    pub fn tokenize_breakdown(text: &str) -> TokenBreakdown {
+        #[derive(PartialEq)]
+        enum Run {
+            None,
+            Latin,
+            Digit,
+        }
 
-        (korean_count as f64 / 1.5 + alphanum_count as f64 / 4.0 + space_count as f64 / 4.0) as usize
+        let mut counts = TokenBreakdown::default();
+        for word in text.split_whitespace() {
+            let mut has_hangul = false;
+            let mut run = Run::None;
+            for c in word.chars() {
+                let kind = match c {
+                    '\u{AC00}'..='\u{D7A3}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}' => {
+                        has_hangul = true;
+                        Run::None
+                    }
+                    '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}' => {
+                        counts.cjk_chars += 1;
+                        Run::None
+                    }
+                    '0'..='9' => {
+                        counts.digits += 1;
+                        if run != Run::Digit {
+                            counts.numbers += 1;
+                        }
+                        Run::Digit
+                    }
+                    c if c.is_alphabetic() => {
+                        counts.latin_chars += 1;
+                        if run != Run::Latin {
+                            counts.latin_words += 1;
+                        }
+                        Run::Latin
+                    }
+                    _ => {
+                        counts.punctuation += 1;
+                        Run::None
+                    }
+                };
+                run = kind;
+            }
+            if has_hangul {
+                counts.hangul_eojeol += 1;
+            }
+        }
+        counts
     }
 
     /// 청크 고유 ID 생성 (SHA256 해시)
@@ -230,12 +292,16 @@ impl KoreanLegalChunker {
             self.current_state.set_article(formatted);
         }
 
+        // 본문 뒤에 나오는 편/장/절/관 제목은 다음 조의 것이므로 조 시작 시점의 계층을 기록
+        let hierarchy = self.current_state.clone();
+        let context_path = self.build_context_path();
+
         // 다음 조가 나올 때까지 수집
         while current_idx < lines.len() {
             let line = lines[current_idx].trim();
 
-            // 다음 조 시작 감지
-            if current_idx > start_idx && RE_ARTICLE.is_match(line) {
+            // 다음 조 또는 별표/부칙 시작 감지
+            if current_idx > start_idx && (RE_ARTICLE.is_match(line) || match_appendix(line).is_some()) {
                 break;
             }
 
@@ -283,13 +349,14 @@ impl KoreanLegalChunker {
             revision_date: base_metadata.revision_date.clone(),
             revision_number: base_metadata.revision_number.clone(),
             effective_date: base_metadata.effective_date.clone(),
-            part: self.current_state.part.clone(),
-            chapter: self.current_state.chapter.clone(),
-            section: self.current_state.section.clone(),
-            subsection: self.current_state.subsection.clone(),
+            part: hierarchy.part,
+            chapter: hierarchy.chapter,
+            section: hierarchy.section,
+            subsection: hierarchy.subsection,
             article_number: Some(article_num),
             article_title,
             paragraph_number: None,
+            appendix_number: None,
             references,
             source_file: base_metadata.source_file.clone(),
             line_start: start_idx,
@@ -298,7 +365,6 @@ impl KoreanLegalChunker {
 
         let chunk_id = self.generate_chunk_id(&content, &chunk_metadata);
         let token_count = self.estimate_tokens(&content);
-        let context_path = self.build_context_path();
 
         let chunk = LegalChunk {
             id: chunk_id,
@@ -308,23 +374,98 @@ impl KoreanLegalChunker {
             token_count,
             context_path,
             parent_chunk_id: None,
+            overlap_prefix_end: 0,
+            source_chunk_ids: Vec::new(),
+        };
+
+        (chunk, current_idx)
+    }
+
+    /// 별표/별지/부칙 블록 파싱
+    ///
+    /// 다음 부록 제목이 나올 때까지의 모든 줄(부칙 안의 조문 포함)을 한 청크로 묶는다.
+    fn parse_appendix_block(
+        &mut self,
+        lines: &[&str],
+        start_idx: usize,
+        base_metadata: &LegalMetadata,
+    ) -> (LegalChunk, usize) {
+        let heading = match_appendix(lines[start_idx].trim())
+            .expect("parse_appendix_block called on a non-appendix line");
+
+        let mut current_idx = start_idx + 1;
+        while current_idx < lines.len() && match_appendix(lines[current_idx].trim()).is_none() {
+            current_idx += 1;
+        }
+
+        let content = lines[start_idx..current_idx]
+            .iter()
+            .map(|line| line.trim())
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim()
+            .to_string();
+        let references = self.extract_references(&content);
+
+        let chunk_metadata = LegalMetadata {
+            law_name: base_metadata.law_name.clone(),
+            law_id: base_metadata.law_id.clone(),
+            category: base_metadata.category.clone(),
+            revision_date: base_metadata.revision_date.clone(),
+            revision_number: base_metadata.revision_number.clone(),
+            effective_date: base_metadata.effective_date.clone(),
+            article_title: Some(heading.title.unwrap_or_else(|| heading.label.clone())),
+            appendix_number: heading.number,
+            references,
+            source_file: base_metadata.source_file.clone(),
+            line_start: start_idx,
+            line_end: current_idx.saturating_sub(1),
+            ..Default::default()
+        };
+
+        let chunk = LegalChunk {
+            id: self.generate_chunk_id(&content, &chunk_metadata),
+            token_count: self.estimate_tokens(&content),
+            content,
+            metadata: chunk_metadata,
+            chunk_type: heading.chunk_type,
+            context_path: heading.label,
+            parent_chunk_id: None,
+            overlap_prefix_end: 0,
+            source_chunk_ids: Vec::new(),
         };
 
         (chunk, current_idx)
     }
 
     /// 마크다운 파일 파싱
-    pub fn parse_markdown<P: AsRef<Path>>(&mut self, filepath: P) -> Result<Vec<LegalChunk>, std::io::Error> {
+    pub fn parse_markdown<P: AsRef<Path>>(&mut self, filepath: P) -> Result<Vec<LegalChunk>, ChunkerError> {
         let content = fs::read_to_string(filepath.as_ref())?;
-        let lines: Vec<&str> = content.lines().collect();
-
-        // 메타데이터 헤더 파싱
-        let (mut base_metadata, body_start) = self.parse_metadata_header(&lines);
-        base_metadata.source_file = filepath
+        let source_name = filepath
             .as_ref()
             .file_name()
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_default();
+        self.parse_str(&content, &source_name)
+    }
+
+    /// 메모리 상의 마크다운 문자열 파싱
+    ///
+    /// HWPX/PDF 변환 결과처럼 이미 메모리에 있는 내용을 임시 파일 없이 청킹합니다.
+    /// `source_name`은 각 청크의 `metadata.source_file`로 기록됩니다.
+    pub fn parse_str(&mut self, content: &str, source_name: &str) -> Result<Vec<LegalChunk>, ChunkerError> {
+        // 바이너리 데이터(예: 변환 전 HWP 원본)를 잘못 넘긴 경우
+        if content.contains('\0') {
+            return Err(ChunkerError::ParseError(format!(
+                "{}: input contains NUL bytes; expected Markdown text",
+                source_name
+            )));
+        }
+        let lines: Vec<&str> = content.lines().collect();
+
+        // 메타데이터 헤더 파싱
+        let (mut base_metadata, body_start) = self.parse_metadata_header(&lines);
+        base_metadata.source_file = source_name.to_string();
 
         // 상태 초기화
         self.current_state.reset();
@@ -371,6 +512,14 @@ impl KoreanLegalChunker {
                 continue;
             }
 
+            // 별표/별지/부칙 파싱 (이후 내용은 모두 부록 영역)
+            if match_appendix(line).is_some() {
+                let (chunk, next_idx) = self.parse_appendix_block(&lines, current_idx, &base_metadata);
+                chunks.push(chunk);
+                current_idx = next_idx;
+                continue;
+            }
+
             // 조 파싱
             if RE_ARTICLE.is_match(line) {
                 let (chunk, next_idx) = self.parse_article_block(&lines, current_idx, &base_metadata);
@@ -396,7 +545,6 @@ impl KoreanLegalChunker {
         let lines: Vec<&str> = chunk.content.lines().collect();
         let mut current_content = Vec::new();
         let mut current_paragraph: Option<String> = None;
-        let parent_id = chunk.id.clone();
 
         for line in lines {
             // 항 시작 감지
@@ -407,19 +555,7 @@ impl KoreanLegalChunker {
                     if !content.is_empty() {
                         let mut sub_meta = chunk.metadata.clone();
                         sub_meta.paragraph_number = current_paragraph.clone();
-
-                        let sub_id = self.generate_chunk_id(&content, &sub_meta);
-                        let token_count = self.estimate_tokens(&content);
-
-                        sub_chunks.push(LegalChunk {
-                            id: sub_id,
-                            content,
-                            metadata: sub_meta,
-                            chunk_type: ChunkType::Paragraph,
-                            token_count,
-                            context_path: chunk.context_path.clone(),
-                            parent_chunk_id: Some(parent_id.clone()),
-                        });
+                        sub_chunks.extend(self.paragraph_chunks(&content, sub_meta, &chunk));
                     }
 
                     current_content.clear();
@@ -447,19 +583,7 @@ impl KoreanLegalChunker {
             if !content.is_empty() {
                 let mut sub_meta = chunk.metadata.clone();
                 sub_meta.paragraph_number = current_paragraph;
-
-                let sub_id = self.generate_chunk_id(&content, &sub_meta);
-                let token_count = self.estimate_tokens(&content);
-
-                sub_chunks.push(LegalChunk {
-                    id: sub_id,
-                    content,
-                    metadata: sub_meta,
-                    chunk_type: ChunkType::Paragraph,
-                    token_count,
-                    context_path: chunk.context_path.clone(),
-                    parent_chunk_id: Some(parent_id),
-                });
+                sub_chunks.extend(self.paragraph_chunks(&content, sub_meta, &chunk));
             }
         }
 
@@ -469,6 +593,265 @@ impl KoreanLegalChunker {
             sub_chunks
         }
     }
+
+    /// 항 하나를 청크로 변환. `max_chunk_tokens`를 넘는 항은
+    /// [`Self::split_at_sentence_boundary`]로 문장 단위 분할한다.
+    fn paragraph_chunks(&self, content: &str, metadata: LegalMetadata, parent: &LegalChunk) -> Vec<LegalChunk> {
+        let pieces = if self.estimate_tokens(content) > self.max_chunk_tokens {
+            self.split_at_sentence_boundary(content, self.max_chunk_tokens)
+        } else {
+            vec![content.to_string()]
+        };
+
+        pieces
+            .into_iter()
+            .map(|content| LegalChunk {
+                id: self.generate_chunk_id(&content, &metadata),
+                token_count: self.estimate_tokens(&content),
+                content,
+                metadata: metadata.clone(),
+                chunk_type: ChunkType::Paragraph,
+                context_path: parent.context_path.clone(),
+                parent_chunk_id: Some(parent.id.clone()),
+                overlap_prefix_end: 0,
+                source_chunk_ids: Vec::new(),
+            })
+            .collect()
+    }
+
+    /// 문장 경계(`다.`, `한다.`, `않는다.`, `。` — [`RE_SENTENCE_END`])에서
+    /// 텍스트를 `max_tokens` 이하 조각으로 분할
+    ///
+    /// 두 번째 조각부터는 앞 조각의 마지막 문장들을 `overlap_tokens` 이내로
+    /// 앞에 붙여 문맥을 잇는다. 한 문장이 `max_tokens`를 넘으면 문장을
+    /// 자르지 않고 그 문장 하나로 된 조각을 만든다.
+    pub fn split_at_sentence_boundary(&self, text: &str, max_tokens: usize) -> Vec<String> {
+        let mut sentences: Vec<&str> = Vec::new();
+        let mut start = 0;
+        for m in RE_SENTENCE_END.find_iter(text) {
+            sentences.push(&text[start..m.end()]);
+            start = m.end();
+        }
+        if start < text.len() {
+            sentences.push(&text[start..]);
+        }
+
+        let mut pieces: Vec<String> = Vec::new();
+        let mut current: Vec<&str> = Vec::new();
+        let mut current_tokens = 0;
+
+        for sentence in sentences {
+            let tokens = self.estimate_tokens(sentence);
+            if !current.is_empty() && current_tokens + tokens > max_tokens {
+                pieces.push(current.concat().trim().to_string());
+
+                // 이전 조각 끝 문장들을 overlap으로 이어 붙임
+                let mut overlap: Vec<&str> = Vec::new();
+                let mut overlap_tokens = 0;
+                for prev in current.iter().rev() {
+                    let prev_tokens = self.estimate_tokens(prev);
+                    if overlap_tokens + prev_tokens > self.overlap_tokens {
+                        break;
+                    }
+                    overlap_tokens += prev_tokens;
+                    overlap.insert(0, prev);
+                }
+                if overlap_tokens + tokens > max_tokens {
+                    overlap.clear();
+                    overlap_tokens = 0;
+                }
+                current = overlap;
+                current_tokens = overlap_tokens;
+            }
+            current.push(sentence);
+            current_tokens += tokens;
+        }
+        if !current.is_empty() {
+            pieces.push(current.concat().trim().to_string());
+        }
+
+        pieces.retain(|p| !p.is_empty());
+        pieces
+    }
+
+    /// 파싱이 끝난 청크 목록에 슬라이딩 윈도 청크를 끼워 넣는다
+    ///
+    /// 같은 법령의 연속한 두 청크마다 [`LegalChunk::overlap_with`]로 앞 청크의
+    /// 마지막 `overlap_tokens` 분량을 뒤 청크 앞에 붙인 [`ChunkType::Overlap`]
+    /// 청크를 만들어 뒤 청크 바로 다음에 둔다. 원래 청크는 그대로 유지된다.
+    pub fn generate_overlapping_chunks(&self, chunks: Vec<LegalChunk>) -> Vec<LegalChunk> {
+        let mut out = Vec::with_capacity(chunks.len() * 2);
+        for chunk in chunks {
+            let overlap = match out.iter().rev().find(|c: &&LegalChunk| c.chunk_type != ChunkType::Overlap) {
+                Some(previous) if previous.metadata.law_name == chunk.metadata.law_name => {
+                    chunk.overlap_with(previous, self)
+                }
+                _ => None,
+            };
+            out.push(chunk);
+            out.extend(overlap);
+        }
+        out
+    }
+
+    /// 청크 목록에서 OCR 오류나 서식 문제로 생긴 계층 구조 이상을 찾는다
+    ///
+    /// 법령별로 조(Article) 청크만 검사한다 (항 분할·오버랩·별표·부칙 청크는
+    /// 제외). 장으로 나뉜 법령에서 장 밖에 있는 조, 편으로 나뉜 법령에서 편 없이
+    /// 장·절 아래에 있는 조, 1보다 크게 건너뛴 조 번호, 같은 조 번호(가지번호
+    /// 포함)의 중복을 경고한다.
+    pub fn validate_hierarchy(chunks: &[LegalChunk]) -> Vec<HierarchyWarning> {
+        let articles: Vec<&LegalChunk> = chunks.iter().filter(|c| c.chunk_type == ChunkType::Article).collect();
+        let chaptered: HashSet<&str> = articles
+            .iter()
+            .filter(|c| c.metadata.chapter.is_some())
+            .map(|c| c.metadata.law_name.as_str())
+            .collect();
+        let parted: HashSet<&str> = articles
+            .iter()
+            .filter(|c| c.metadata.part.is_some())
+            .map(|c| c.metadata.law_name.as_str())
+            .collect();
+
+        let mut warnings = Vec::new();
+        let mut last_number: HashMap<&str, u32> = HashMap::new();
+        let mut first_seen: HashMap<(&str, (String, Option<String>)), &str> = HashMap::new();
+        for &chunk in &articles {
+            let Some(key) = chunk_article_key(chunk) else {
+                continue;
+            };
+            let meta = &chunk.metadata;
+            let law = meta.law_name.as_str();
+            let label = article_label(&key);
+            let mut warn = |warning_type, detail| {
+                warnings.push(HierarchyWarning { chunk_id: chunk.id.clone(), warning_type, detail });
+            };
+
+            if meta.chapter.is_none() && chaptered.contains(law) {
+                warn(
+                    WarningType::ArticleBeforeChapter,
+                    format!("{} has no enclosing chapter (장) although the law is divided into chapters", label),
+                );
+            }
+            if meta.part.is_none() && parted.contains(law) {
+                if let Some(parent) = meta.chapter.as_ref().or(meta.section.as_ref()) {
+                    warn(
+                        WarningType::MissingPart,
+                        format!("{} is under {} with no enclosing part (편)", label, parent),
+                    );
+                }
+            }
+            if let Ok(number) = key.0.parse::<u32>() {
+                if let Some(&previous) = last_number.get(law) {
+                    if number > previous + 1 {
+                        warn(
+                            WarningType::SkippedArticleNumber,
+                            format!("article number jumps from 제{}조 to 제{}조", previous, number),
+                        );
+                    }
+                }
+                last_number.insert(law, number);
+            }
+            match first_seen.get(&(law, key.clone())) {
+                Some(first) => warn(
+                    WarningType::DuplicateArticle,
+                    format!("{} already appears in chunk {}", label, first),
+                ),
+                None => {
+                    first_seen.insert((law, key), chunk.id.as_str());
+                }
+            }
+        }
+        warnings
+    }
+}
+
+/// (조 번호, 가지번호)의 표기 (예: "제3조", "제3조의2")
+fn article_label((number, branch): &(String, Option<String>)) -> String {
+    match branch {
+        Some(branch) => format!("제{}조의{}", number, branch),
+        None => format!("제{}조", number),
+    }
+}
+
+/// 청크 간 참조 그래프 생성
+///
+/// 각 청크의 내부 참조(`제N조`, `제N조의M`)를 같은 법령 안에서
+/// `article_number`가 일치하는 청크로 해석한다. 모든 청크가 키로 포함되며,
+/// 해석되지 않는 참조와 자기 자신에 대한 참조는 제외된다.
+pub fn build_reference_graph(chunks: &[LegalChunk]) -> ReferenceGraph {
+    let keys: Vec<Option<(String, Option<String>)>> = chunks.iter().map(chunk_article_key).collect();
+
+    chunks
+        .iter()
+        .map(|chunk| {
+            let mut targets: Vec<String> = Vec::new();
+            for reference in chunk.metadata.references.iter().filter(|r| r.target_law.is_none()) {
+                let Some(target) = reference.target_article.as_deref().and_then(parse_article_key) else {
+                    continue;
+                };
+                for (other, key) in chunks.iter().zip(&keys) {
+                    if other.id != chunk.id
+                        && other.metadata.law_name == chunk.metadata.law_name
+                        && key.as_ref() == Some(&target)
+                        && !targets.contains(&other.id)
+                    {
+                        targets.push(other.id.clone());
+                    }
+                }
+            }
+            (chunk.id.clone(), targets)
+        })
+        .collect()
+}
+
+/// `제N조의M` 형식에서 (조 번호, 가지번호) 추출
+fn parse_article_key(text: &str) -> Option<(String, Option<String>)> {
+    let caps = RE_ARTICLE.captures(text)?;
+    Some((caps[1].to_string(), caps.get(2).map(|m| m.as_str().to_string())))
+}
+
+/// 청크의 조 번호와 가지번호 (가지번호는 계층 경로의 마지막 조 제목에서 읽음)
+fn chunk_article_key(chunk: &LegalChunk) -> Option<(String, Option<String>)> {
+    let number = chunk.metadata.article_number.clone()?;
+    let branch = chunk
+        .context_path
+        .rsplit(" > ")
+        .next()
+        .and_then(parse_article_key)
+        .filter(|(n, _)| *n == number)
+        .and_then(|(_, branch)| branch);
+    Some((number, branch))
+}
+
+/// 별표/별지/부칙 제목 정보
+struct AppendixHeading {
+    chunk_type: ChunkType,
+    /// 표제 (예: "별표 1", "별지 제2호서식", "부칙")
+    label: String,
+    /// 번호 (예: "1", "2의3"); 부칙은 `None`
+    number: Option<String>,
+    title: Option<String>,
+}
+
+/// 줄이 별표/별지/부칙 제목이면 파싱 (마크다운 제목 기호 `#`는 무시)
+fn match_appendix(line: &str) -> Option<AppendixHeading> {
+    let caps = RE_APPENDIX.captures(line.trim_start_matches('#').trim())?;
+    let number = caps
+        .get(2)
+        .or_else(|| caps.get(4))
+        .map(|n| match caps.get(3).or_else(|| caps.get(5)) {
+            Some(branch) => format!("{}의{}", n.as_str(), branch.as_str()),
+            None => n.as_str().to_string(),
+        });
+    let chunk_type = if number.is_some() { ChunkType::Appendix } else { ChunkType::Supplement };
+
+    Some(AppendixHeading {
+        chunk_type,
+        label: caps[1].to_string(),
+        number,
+        title: caps.get(6).map(|t| t.as_str().to_string()).filter(|t| !t.is_empty()),
+    })
 }
 
 /// hex 인코딩 헬퍼
@@ -486,15 +869,46 @@ mod tests {
     fn test_estimate_tokens() {
         let chunker = KoreanLegalChunker::new();
         
-        // 한글 텍스트
+        // 한글 텍스트: 어절 7 × 1.3 + 마침표 1
         let korean = "이 규정은 유가증권시장의 상장에 관한 사항을 정한다.";
-        let tokens = chunker.estimate_tokens(korean);
-        assert!(tokens > 0);
+        assert_eq!(chunker.estimate_tokens(korean), 10);
         
-        // 영문 텍스트
+        // 영문 텍스트: 단어 5개(19자) + 마침표 1
         let english = "This is a test sentence.";
-        let eng_tokens = chunker.estimate_tokens(english);
-        assert!(eng_tokens > 0);
+        assert_eq!(chunker.estimate_tokens(english), 6);
+
+        assert_eq!(chunker.estimate_tokens(""), 0);
+    }
+
+    #[test]
+    fn test_tokenize_breakdown_categories() {
+        let refs = KoreanLegalChunker::tokenize_breakdown("「상법」 제42조제1항에 따라 제5조제2항을 적용한다.");
+        assert_eq!(
+            refs,
+            TokenBreakdown { hangul_eojeol: 5, numbers: 4, digits: 5, punctuation: 3, ..Default::default() }
+        );
+
+        // 국한문 혼용: 한자는 글자마다 센다
+        let hanja = KoreanLegalChunker::tokenize_breakdown("第1條(目的) 이 法은 國民의 權利를 保護함을 目的으로 한다.");
+        assert_eq!((hanja.hangul_eojeol, hanja.cjk_chars, hanja.numbers, hanja.punctuation), (7, 13, 1, 3));
+        assert_eq!(hanja.estimated_tokens(), 22);
+
+        // 긴 로마자 단어는 4자당 1토큰, 긴 수는 3자리당 1토큰
+        let mixed = KoreanLegalChunker::tokenize_breakdown("Section 2024 applies to KRX-listed 1,000,000 shares.");
+        assert_eq!((mixed.latin_words, mixed.latin_chars), (6, 31));
+        assert_eq!((mixed.numbers, mixed.digits, mixed.punctuation), (4, 11, 4));
+        assert_eq!(mixed.estimated_tokens(), 16);
+    }
+
+    #[test]
+    fn test_estimate_tokens_sample_paragraph() {
+        // 상장규정 제1조 형식의 표본 문단. 가중치를 바꾸면 이 값도 바뀐다
+        let paragraph = "제1조(목적) 이 규정은 「자본시장과 금융투자업에 관한 법률」(이하 \"법\"이라 한다) \
+            제390조에 따라 유가증권시장의 상장에 관하여 필요한 사항을 정함을 목적으로 한다.";
+
+        let breakdown = KoreanLegalChunker::tokenize_breakdown(paragraph);
+        assert_eq!((breakdown.hangul_eojeol, breakdown.numbers, breakdown.punctuation), (19, 2, 9));
+        assert_eq!(KoreanLegalChunker::new().estimate_tokens(paragraph), 36);
     }
 
     #[test]
@@ -543,4 +957,350 @@ mod tests {
         let path = chunker.build_context_path();
         assert_eq!(path, "제1편 총칙 > 제1장 통칙 > 제1조(목적)");
     }
+
+    const SAMPLE_LAW: &str = "# 테스트 규정\n\n제1장 총칙\n\n제1조(목적) 이 규정은 테스트를 목적으로 한다.\n\n제2조(정의) 이 규정에서 사용하는 용어의 뜻은 다음과 같다.\n";
+
+    #[test]
+    fn test_parse_str_sets_source_and_hierarchy() {
+        let mut chunker = KoreanLegalChunker::new();
+        let chunks = chunker.parse_str(SAMPLE_LAW, "converted.hwpx").unwrap();
+
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks.iter().all(|c| c.metadata.source_file == "converted.hwpx"));
+        assert_eq!(chunks[0].metadata.law_name, "테스트 규정");
+        assert_eq!(chunks[0].metadata.article_number.as_deref(), Some("1"));
+        assert!(chunks[0].context_path.contains("제1장 총칙"));
+    }
+
+    #[test]
+    fn test_parse_markdown_matches_parse_str() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("law.md");
+        fs::write(&path, SAMPLE_LAW).unwrap();
+
+        let from_file = KoreanLegalChunker::new().parse_markdown(&path).unwrap();
+        let from_str = KoreanLegalChunker::new().parse_str(SAMPLE_LAW, "law.md").unwrap();
+        let ids = |chunks: &[LegalChunk]| chunks.iter().map(|c| c.id.clone()).collect::<Vec<_>>();
+        assert_eq!(ids(&from_file), ids(&from_str));
+        assert_eq!(from_file[0].metadata.source_file, "law.md");
+    }
+
+    #[test]
+    fn test_split_at_sentence_boundary() {
+        // ~11 tokens per sentence: three fit in 40, one fits in the 20-token overlap
+        let chunker = KoreanLegalChunker::with_options(true, true, 40, 20);
+        let sentence = "회사는 이 규정에서 정하는 바에 따라 공시하여야 한다. ";
+        let text = sentence.repeat(6);
+
+        let pieces = chunker.split_at_sentence_boundary(&text, 40);
+        assert!(pieces.len() > 1);
+        for piece in &pieces {
+            assert!(piece.ends_with("한다."), "{}", piece);
+            assert!(chunker.estimate_tokens(piece) <= 40, "{}", piece);
+        }
+        // Each piece after the first repeats the previous piece's last sentence
+        let sentences: usize = pieces.iter().map(|p| p.matches("한다.").count()).sum();
+        assert_eq!(sentences, 6 + pieces.len() - 1);
+
+        // Short text is returned whole
+        assert_eq!(chunker.split_at_sentence_boundary("짧은 문장이다.", 40), vec!["짧은 문장이다."]);
+    }
+
+    #[test]
+    fn test_chunk_large_article_splits_oversized_paragraph() {
+        let chunker = KoreanLegalChunker::with_options(true, true, 40, 0);
+        let long_paragraph = format!(
+            "② {}",
+            "상장법인은 거래소가 정하는 기준에 따라 사업보고서를 제출하여야 한다. ".repeat(8)
+        );
+        let content = format!("① 이 조는 공시에 관하여 정한다.\n{}", long_paragraph.trim());
+        let metadata = LegalMetadata { law_name: "테스트 규정".to_string(), ..Default::default() };
+        let chunk = LegalChunk {
+            id: chunker.generate_chunk_id(&content, &metadata),
+            token_count: chunker.estimate_tokens(&content),
+            content,
+            metadata,
+            chunk_type: ChunkType::Article,
+            context_path: "제3조(공시)".to_string(),
+            parent_chunk_id: None,
+            overlap_prefix_end: 0,
+            source_chunk_ids: Vec::new(),
+        };
+
+        let parent_id = chunk.id.clone();
+        let subs = chunker.chunk_large_article(chunk);
+        assert!(subs.len() > 2);
+        assert_eq!(subs[0].metadata.paragraph_number.as_deref(), Some("1"));
+        assert!(subs[1..].iter().all(|c| c.metadata.paragraph_number.as_deref() == Some("2")));
+        assert!(subs.iter().all(|c| c.token_count <= 40));
+        assert!(subs.iter().all(|c| c.parent_chunk_id.as_deref() == Some(parent_id.as_str())));
+    }
+
+    #[test]
+    fn test_generate_overlapping_chunks() {
+        let chunker = KoreanLegalChunker::with_options(true, true, 512, 10);
+        let content = "# 테스트 규정\n\n\
+            제1조(목적) 이 규정은 유가증권시장의 상장에 관한 사항을 정한다.\n\n\
+            제2조(정의) 이 규정에서 사용하는 용어의 뜻은 다음과 같다.\n\n\
+            제3조(적용) 이 규정은 모든 상장법인에 적용한다.";
+        let mut parser = KoreanLegalChunker::new();
+        let chunks = parser.parse_str(content, "test.md").unwrap();
+        assert_eq!(chunks.len(), 3);
+
+        let all = chunker.generate_overlapping_chunks(chunks.clone());
+        let kinds: Vec<ChunkType> = all.iter().map(|c| c.chunk_type).collect();
+        assert_eq!(
+            kinds,
+            [ChunkType::Article, ChunkType::Article, ChunkType::Overlap, ChunkType::Article, ChunkType::Overlap]
+        );
+
+        for (overlap, (previous, next)) in [(&all[2], (&chunks[0], &chunks[1])), (&all[4], (&chunks[1], &chunks[2]))] {
+            let prefix = &overlap.content[..overlap.overlap_prefix_end];
+            assert!(!prefix.is_empty());
+            assert!(previous.content.ends_with(prefix), "{:?} / {:?}", prefix, previous.content);
+            assert!(!prefix.starts_with(char::is_whitespace));
+            assert!(chunker.estimate_tokens(prefix) <= 10);
+            assert_eq!(overlap.content[overlap.overlap_prefix_end..].trim_start(), next.content);
+            assert_eq!(overlap.source_chunk_ids, vec![previous.id.clone(), next.id.clone()]);
+            assert!(overlap.parent_chunk_id.is_none());
+            assert_eq!(overlap.metadata.article_number, next.metadata.article_number);
+        }
+        assert!(chunks.iter().all(|c| c.overlap_prefix_end == 0));
+        assert!(!chunks[0].to_json().as_object().unwrap().contains_key("overlap_prefix_end"));
+        assert!(!chunks[0].to_json().as_object().unwrap().contains_key("source_chunk_ids"));
+    }
+
+    #[test]
+    fn test_overlap_disabled_with_zero_tokens() {
+        let chunker = KoreanLegalChunker::with_options(true, true, 512, 0);
+        let a = graph_chunk("a", "1", vec![]);
+        let mut b = graph_chunk("b", "2", vec![]);
+        b.content = "제2조 내용".to_string();
+        assert!(b.overlap_with(&LegalChunk { content: "제1조 내용".to_string(), ..a }, &chunker).is_none());
+    }
+
+    fn graph_chunk(id: &str, article: &str, references: Vec<LegalReference>) -> LegalChunk {
+        LegalChunk {
+            id: id.to_string(),
+            content: String::new(),
+            metadata: LegalMetadata {
+                law_name: "테스트 규정".to_string(),
+                article_number: Some(article.to_string()),
+                references,
+                ..Default::default()
+            },
+            chunk_type: ChunkType::Article,
+            token_count: 0,
+            context_path: format!("제1장 총칙 > 제{}조", article),
+            parent_chunk_id: None,
+            overlap_prefix_end: 0,
+            source_chunk_ids: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_build_reference_graph() {
+        let chunks = vec![
+            graph_chunk("a", "1", vec![LegalReference::internal("제2조".to_string(), "제2조".to_string())]),
+            graph_chunk("b", "2", vec![]),
+            graph_chunk("c", "3", vec![
+                LegalReference::internal("제1조".to_string(), "제1조제1항".to_string()),
+                LegalReference::internal("제9조".to_string(), "제9조".to_string()),
+                LegalReference::external("상법".to_string(), Some("제2조".to_string()), "「상법」 제2조".to_string()),
+            ]),
+        ];
+
+        let graph = build_reference_graph(&chunks);
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph["a"], vec!["b".to_string()]);
+        assert!(graph["b"].is_empty());
+        assert_eq!(graph["c"], vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn test_build_reference_graph_distinguishes_branch_articles() {
+        let mut branch = graph_chunk("b2", "2", vec![]);
+        branch.context_path = "제2조의2(특례)".to_string();
+        let chunks = vec![
+            graph_chunk("a", "1", vec![LegalReference::internal("제2조의2".to_string(), "제2조의2".to_string())]),
+            graph_chunk("b", "2", vec![]),
+            branch,
+        ];
+
+        let graph = build_reference_graph(&chunks);
+        assert_eq!(graph["a"], vec!["b2".to_string()]);
+    }
+
+    const LAW_WITH_APPENDIX: &str = "# 테스트 규정\n\n제1조(목적) 이 규정은 테스트를 목적으로 한다.\n\n제2조(수수료) 수수료는 별표 1과 같다.\n\n## [별표 1] 수수료 기준\n\n| 구분 | 금액 |\n|---|---|\n| 신청 | 1,000원 |\n\n별지 제2호의3서식\n\n신청서 양식\n\n부칙 <제15호, 2024. 1. 15.>\n\n제1조(시행일) 이 규정은 공포한 날부터 시행한다.\n";
+
+    #[test]
+    fn test_parse_str_appendix_chunks() {
+        let mut chunker = KoreanLegalChunker::new();
+        let chunks = chunker.parse_str(LAW_WITH_APPENDIX, "law.md").unwrap();
+
+        let types: Vec<ChunkType> = chunks.iter().map(|c| c.chunk_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                ChunkType::Article,
+                ChunkType::Article,
+                ChunkType::Appendix,
+                ChunkType::Appendix,
+                ChunkType::Supplement,
+            ]
+        );
+
+        // 마지막 조문은 별표 앞에서 끝난다
+        assert!(!chunks[1].content.contains("별표 1]"));
+
+        let annex = &chunks[2];
+        assert_eq!(annex.metadata.article_number, None);
+        assert_eq!(annex.metadata.appendix_number.as_deref(), Some("1"));
+        assert_eq!(annex.metadata.article_title.as_deref(), Some("수수료 기준"));
+        assert_eq!(annex.context_path, "별표 1");
+        assert!(annex.content.contains("| 신청 | 1,000원 |"));
+
+        let form = &chunks[3];
+        assert_eq!(form.metadata.appendix_number.as_deref(), Some("2의3"));
+        assert_eq!(form.metadata.article_title.as_deref(), Some("별지 제2호의3서식"));
+
+        // 부칙 안의 조문은 별도 조 청크가 되지 않는다
+        let supplement = &chunks[4];
+        assert_eq!(supplement.metadata.appendix_number, None);
+        assert!(supplement.content.contains("제1조(시행일)"));
+        assert_eq!(chunks.iter().filter(|c| c.metadata.article_number.as_deref() == Some("1")).count(), 1);
+    }
+
+    #[test]
+    fn test_match_appendix_ignores_inline_mentions() {
+        assert!(match_appendix("별표 1에 따른 기준을 적용한다.").is_none());
+        assert!(match_appendix("부칙에서 정한다.").is_none());
+        assert!(match_appendix("[별표 3]").is_some());
+    }
+
+    #[test]
+    fn test_parse_str_rejects_binary_input() {
+        let mut chunker = KoreanLegalChunker::new();
+        let err = chunker.parse_str("HWP Document File\0\0", "raw.hwp").unwrap_err();
+        assert!(matches!(err, ChunkerError::ParseError(_)));
+
+        let err = chunker.parse_markdown("/nonexistent/law.md").unwrap_err();
+        assert!(matches!(err, ChunkerError::Io(_)));
+    }
+
+    fn hierarchy_warnings(body: &str) -> (Vec<LegalChunk>, Vec<HierarchyWarning>) {
+        let chunks = KoreanLegalChunker::new()
+            .parse_str(&format!("# 테스트 규정\n\n{}", body), "law.md")
+            .unwrap();
+        let warnings = KoreanLegalChunker::validate_hierarchy(&chunks);
+        (chunks, warnings)
+    }
+
+    #[test]
+    fn test_validate_hierarchy_clean_law() {
+        let (chunks, warnings) = hierarchy_warnings(
+            "제1편 총칙\n\n제1장 통칙\n\n제1조(목적) 목적을 정한다.\n\n제2조(정의) 정의를 정한다.\n\n\
+             제2장 상장\n\n제3조(신청) 신청을 정한다.",
+        );
+        assert_eq!(chunks.len(), 3);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_validate_hierarchy_article_before_chapter() {
+        let (chunks, warnings) = hierarchy_warnings(
+            "제1조(목적) 목적을 정한다.\n\n제1장 총칙\n\n제2조(정의) 정의를 정한다.",
+        );
+        // 제1조 본문 뒤의 장 제목은 제2조에 속한다
+        assert_eq!(chunks[0].metadata.chapter, None);
+        assert_eq!(chunks[1].metadata.chapter.as_deref(), Some("제1장 총칙"));
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].warning_type, WarningType::ArticleBeforeChapter);
+        assert_eq!(warnings[0].chunk_id, chunks[0].id);
+        assert!(warnings[0].detail.contains("제1조"), "{}", warnings[0].detail);
+    }
+
+    #[test]
+    fn test_validate_hierarchy_law_without_chapters() {
+        let (_, warnings) = hierarchy_warnings("제1조(목적) 목적을 정한다.\n\n제2조(정의) 정의를 정한다.");
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_validate_hierarchy_missing_part() {
+        let (chunks, warnings) = hierarchy_warnings(
+            "제1장 통칙\n\n제1조(목적) 목적을 정한다.\n\n제1편 상장\n\n제2장 신청\n\n제2조(신청) 신청을 정한다.",
+        );
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert_eq!(warnings[0].warning_type, WarningType::MissingPart);
+        assert_eq!(warnings[0].chunk_id, chunks[0].id);
+        assert!(warnings[0].detail.contains("제1장 통칙"), "{}", warnings[0].detail);
+    }
+
+    #[test]
+    fn test_validate_hierarchy_law_without_parts() {
+        let (_, warnings) = hierarchy_warnings(
+            "제1장 통칙\n\n제1조(목적) 목적을 정한다.\n\n제2장 상장\n\n제2조(신청) 신청을 정한다.",
+        );
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_validate_hierarchy_skipped_article_number() {
+        let (chunks, warnings) = hierarchy_warnings(
+            "제1조(목적) 목적을 정한다.\n\n제2조(정의) 정의를 정한다.\n\n제5조(신청) 신청을 정한다.",
+        );
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].warning_type, WarningType::SkippedArticleNumber);
+        assert_eq!(warnings[0].chunk_id, chunks[2].id);
+        assert!(warnings[0].detail.contains("제2조 to 제5조"), "{}", warnings[0].detail);
+    }
+
+    #[test]
+    fn test_validate_hierarchy_branch_articles_are_not_anomalies() {
+        let (chunks, warnings) = hierarchy_warnings(
+            "제1조(목적) 목적을 정한다.\n\n제2조(정의) 정의를 정한다.\n\n\
+             제2조의2(특례) 특례를 정한다.\n\n제3조(신청) 신청을 정한다.",
+        );
+        assert_eq!(chunks.len(), 4);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_validate_hierarchy_duplicate_article() {
+        let (chunks, warnings) = hierarchy_warnings(
+            "제1조(목적) 목적을 정한다.\n\n제2조(정의) 정의를 정한다.\n\n제2조(정의) 용어의 뜻을 정한다.",
+        );
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].warning_type, WarningType::DuplicateArticle);
+        assert_eq!(warnings[0].chunk_id, chunks[2].id);
+        assert!(warnings[0].detail.contains(&chunks[1].id), "{}", warnings[0].detail);
+    }
+
+    #[test]
+    fn test_validate_hierarchy_ignores_derived_chunks_and_other_laws() {
+        let mut parser = KoreanLegalChunker::new();
+        let first = parser.parse_str("# 가 규정\n\n제1조(목적) 목적을 정한다.\n\n제2조(정의) 정의를 정한다.", "a.md").unwrap();
+        let second = parser.parse_str("# 나 규정\n\n제1조(목적) 목적을 정한다.", "b.md").unwrap();
+
+        // 오버랩 청크는 앞뒤 조와 같은 조 번호를 갖지만 중복이 아니다
+        let chunker = KoreanLegalChunker::with_options(true, true, 512, 10);
+        let mut chunks = chunker.generate_overlapping_chunks(first);
+        assert!(chunks.iter().any(|c| c.chunk_type == ChunkType::Overlap));
+        chunks.extend(second);
+        assert!(KoreanLegalChunker::validate_hierarchy(&chunks).is_empty());
+    }
+
+    #[test]
+    fn test_hierarchy_warning_serializes_snake_case() {
+        let warning = HierarchyWarning {
+            chunk_id: "abc".to_string(),
+            warning_type: WarningType::SkippedArticleNumber,
+            detail: "article number jumps from 제2조 to 제5조".to_string(),
+        };
+        let json = serde_json::to_value(&warning).unwrap();
+        assert_eq!(json["warning_type"], "skipped_article_number");
+        assert_eq!(serde_json::from_value::<HierarchyWarning>(json).unwrap(), warning);
+    }
 }
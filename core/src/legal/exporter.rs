@@ -2,13 +2,15 @@
 //!
 //! 법률 청크를 다양한 형식으로 내보내기
 
+use std::collections::{BTreeMap, HashSet};
 use std::fs::File;
-use std::io::{BufWriter, Write};
+use std::io::{self, BufWriter, Write};
 use std::path::Path;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
 
-use crate::legal::types::LegalChunk;
+use crate::hwp::record::html_escape;
+use crate::legal::patterns::RE_INTERNAL_REFERENCE;
+use crate::legal::types::{LegalChunk, ReferenceGraph};
 
 /// weknora RAG 서비스용 내보내기 클래스
 pub struct WeKnoraExporter {
@@ -38,58 +40,42 @@ impl WeKnoraExporter {
     }
 
     /// 임베딩용 데이터 내보내기
+    ///
+    /// 각 레코드는 `LegalChunk` 직렬화 결과(`id`, `content`, `token_count`,
+    /// `context_path`, `chunk_type`, 전체 `metadata`)이므로 그대로
+    /// `LegalChunk`로 역직렬화할 수 있습니다. 컨텍스트를 내용에 포함하면
+    /// 원문은 `raw_content`에 보존됩니다.
     pub fn export_for_embedding(&self, chunks: &[LegalChunk]) -> Vec<serde_json::Value> {
         chunks
             .iter()
             .map(|chunk| {
+                let mut record = serde_json::to_value(chunk).unwrap_or_default();
+
                 // 컨텍스트를 내용에 포함 (RAG 검색 품질 향상)
-                let enhanced_content = if self.include_context_in_content && !chunk.context_path.is_empty() {
-                    format!("[{}]\n\n{}", chunk.context_path, chunk.content)
-                } else {
-                    chunk.content.clone()
-                };
-
-                json!({
-                    "id": chunk.id,
-                    "content": enhanced_content,
-                    "raw_content": chunk.content,
-                    "metadata": {
-                        "law_name": chunk.metadata.law_name,
-                        "law_id": chunk.metadata.law_id,
-                        "category": chunk.metadata.category,
-                        "revision_date": chunk.metadata.revision_date,
-                        "effective_date": chunk.metadata.effective_date,
-                        "hierarchy": {
-                            "part": chunk.metadata.part,
-                            "chapter": chunk.metadata.chapter,
-                            "section": chunk.metadata.section,
-                            "subsection": chunk.metadata.subsection,
-                        },
-                        "article": {
-                            "number": chunk.metadata.article_number,
-                            "title": chunk.metadata.article_title,
-                            "paragraph": chunk.metadata.paragraph_number,
-                        },
-                        "references": chunk.metadata.references,
-                        "source_file": chunk.metadata.source_file,
-                        "chunk_type": chunk.chunk_type,
-                        "token_count": chunk.token_count,
-                        "context_path": chunk.context_path,
+                if self.include_context_in_content && !chunk.context_path.is_empty() {
+                    if let Some(obj) = record.as_object_mut() {
+                        obj.insert(
+                            "content".to_string(),
+                            format!("[{}]\n\n{}", chunk.context_path, chunk.content).into(),
+                        );
+                        obj.insert("raw_content".to_string(), chunk.content.clone().into());
                     }
-                })
+                }
+                record
             })
             .collect()
     }
 
     /// JSONL 형식으로 내보내기 (weknora 인제스트용)
+    ///
+    /// 한 줄에 청크 하나. 기록한 청크 수를 반환합니다.
     pub fn export_to_jsonl<P: AsRef<Path>>(
         &self,
         chunks: &[LegalChunk],
         output_path: P,
     ) -> Result<usize, std::io::Error> {
         let data = self.export_for_embedding(chunks);
-        let file = File::create(output_path)?;
-        let mut writer = BufWriter::new(file);
+        let mut writer = BufWriter::new(create_output(output_path.as_ref())?);
 
         for item in &data {
             serde_json::to_writer(&mut writer, item)?;
@@ -107,12 +93,152 @@ impl WeKnoraExporter {
         output_path: P,
     ) -> Result<usize, std::io::Error> {
         let data = self.export_for_embedding(chunks);
-        let file = File::create(output_path)?;
-        let writer = BufWriter::new(file);
+        let mut writer = BufWriter::new(create_output(output_path.as_ref())?);
 
-        serde_json::to_writer_pretty(writer, &data)?;
+        serde_json::to_writer_pretty(&mut writer, &data)?;
+        writer.flush()?;
         Ok(data.len())
     }
+
+    /// 참조 그래프를 `{chunk_id: [참조 chunk_id]}` JSON 객체로 내보내기
+    ///
+    /// 출력이 실행마다 같도록 키를 정렬해서 기록합니다.
+    pub fn export_graph(&self, graph: &ReferenceGraph, path: &str) -> io::Result<()> {
+        let sorted: BTreeMap<&String, &Vec<String>> = graph.iter().collect();
+        let mut writer = BufWriter::new(create_output(Path::new(path))?);
+
+        serde_json::to_writer_pretty(&mut writer, &sorted)?;
+        writer.flush()
+    }
+
+    /// 시맨틱 HTML 문서로 내보내기 (검색 엔진 인덱싱용)
+    ///
+    /// 청크마다 `<article>` 하나를 만들고 `data-chunk-id`, `data-article`,
+    /// `data-law` 속성을 붙입니다. `<header>`에는 법령명과 조 번호,
+    /// `<section>`에는 본문, `<footer>`에는 컨텍스트 경로가 들어가며
+    /// 본문 안의 조문 참조(예: `제5조제1항`)는 `<mark>`로 감쌉니다.
+    pub fn export_to_html(&self, chunks: &[LegalChunk], output_path: &str) -> io::Result<()> {
+        let mut writer = BufWriter::new(create_output(Path::new(output_path))?);
+        let title = chunks
+            .iter()
+            .map(|c| c.metadata.law_name.as_str())
+            .find(|name| !name.is_empty())
+            .unwrap_or("법령");
+
+        writeln!(writer, "<!DOCTYPE html>")?;
+        writeln!(writer, "<html lang=\"ko\">")?;
+        writeln!(writer, "<head>")?;
+        writeln!(writer, "<meta charset=\"utf-8\">")?;
+        writeln!(writer, "<title>{}</title>", html_escape(title))?;
+        writeln!(writer, "</head>")?;
+        writeln!(writer, "<body>")?;
+        for chunk in chunks {
+            write_html_article(&mut writer, chunk)?;
+        }
+        writeln!(writer, "</body>")?;
+        writeln!(writer, "</html>")?;
+        writer.flush()
+    }
+
+    /// 내보내기 요약 (청크 수, 총 토큰, 법령 수)
+    pub fn summary(&self, chunks: &[LegalChunk]) -> ExportSummary {
+        let unique_laws: HashSet<&str> = chunks
+            .iter()
+            .map(|c| c.metadata.law_name.as_str())
+            .filter(|name| !name.is_empty())
+            .collect();
+
+        ExportSummary {
+            chunk_count: chunks.len(),
+            total_tokens: chunks.iter().map(|c| c.token_count).sum(),
+            unique_laws: unique_laws.len(),
+        }
+    }
+}
+
+/// 출력 파일 생성 — 실패 시 경로를 오류 메시지에 포함
+fn create_output(path: &Path) -> io::Result<File> {
+    File::create(path).map_err(|e| {
+        io::Error::new(e.kind(), format!("cannot create {}: {}", path.display(), e))
+    })
+}
+
+/// 청크 하나를 `<article>` 요소로 기록
+fn write_html_article<W: Write>(writer: &mut W, chunk: &LegalChunk) -> io::Result<()> {
+    let meta = &chunk.metadata;
+    let article = meta.article_number.as_deref().unwrap_or("");
+    let attr = |s: &str| html_escape(s).replace('"', "&quot;");
+
+    writeln!(
+        writer,
+        "<article data-chunk-id=\"{}\" data-article=\"{}\" data-law=\"{}\">",
+        attr(&chunk.id),
+        attr(article),
+        attr(&meta.law_name)
+    )?;
+
+    let mut heading = html_escape(&meta.law_name);
+    if !article.is_empty() {
+        if !heading.is_empty() {
+            heading.push(' ');
+        }
+        heading.push_str(&html_escape(&article_label(article)));
+        if let Some(title) = meta.article_title.as_deref().filter(|t| !t.is_empty()) {
+            heading.push_str(&format!("({})", html_escape(title)));
+        }
+    }
+    writeln!(writer, "<header><h2>{}</h2></header>", heading)?;
+
+    writeln!(writer, "<section>")?;
+    for line in chunk.content.lines().filter(|l| !l.trim().is_empty()) {
+        writeln!(writer, "<p>{}</p>", mark_references(line.trim()))?;
+    }
+    writeln!(writer, "</section>")?;
+
+    if !chunk.context_path.is_empty() {
+        writeln!(writer, "<footer>{}</footer>", html_escape(&chunk.context_path))?;
+    }
+    writeln!(writer, "</article>")
+}
+
+/// 메타데이터의 조 번호("1", "1의2")를 "제1조", "제1조의2"로 표기
+fn article_label(number: &str) -> String {
+    if number.starts_with('제') {
+        return number.to_string();
+    }
+    match number.split_once('의') {
+        Some((num, branch)) => format!("제{}조의{}", num, branch),
+        None => format!("제{}조", number),
+    }
+}
+
+/// 본문 한 줄을 이스케이프하고 조문 참조를 `<mark>`로 감싸기
+///
+/// 줄 맨 앞의 조 번호는 참조가 아니라 조문 자신의 제목이므로 제외합니다.
+fn mark_references(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut last = 0;
+    for m in RE_INTERNAL_REFERENCE.find_iter(line) {
+        if m.start() == 0 {
+            continue;
+        }
+        out.push_str(&html_escape(&line[last..m.start()]));
+        out.push_str("<mark>");
+        out.push_str(&html_escape(m.as_str()));
+        out.push_str("</mark>");
+        last = m.end();
+    }
+    out.push_str(&html_escape(&line[last..]));
+    out
+}
+
+/// 내보내기 요약
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportSummary {
+    pub chunk_count: usize,
+    pub total_tokens: usize,
+    /// 서로 다른 법령명 수
+    pub unique_laws: usize,
 }
 
 /// 처리 통계
@@ -184,6 +310,8 @@ mod tests {
             token_count: 25,
             context_path: "제1편 총칙 > 제1조(목적)".to_string(),
             parent_chunk_id: None,
+            overlap_prefix_end: 0,
+            source_chunk_ids: Vec::new(),
         }
     }
 
@@ -209,6 +337,193 @@ mod tests {
         assert!(!data[0]["content"].as_str().unwrap().starts_with('['));
     }
 
+    #[test]
+    fn test_export_to_jsonl_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("chunks.jsonl");
+        let mut second = create_test_chunk();
+        second.id = "test456".to_string();
+        second.chunk_type = ChunkType::Paragraph;
+        second.metadata.paragraph_number = Some("2".to_string());
+        let chunks = vec![create_test_chunk(), second];
+
+        let count = WeKnoraExporter::with_options(false).export_to_jsonl(&chunks, &path).unwrap();
+        assert_eq!(count, 2);
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let raw: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(raw["chunk_type"], "paragraph");
+        assert_eq!(raw["token_count"], 25);
+        assert_eq!(raw["context_path"], "제1편 총칙 > 제1조(목적)");
+        assert_eq!(raw["metadata"]["law_id"], "12345");
+
+        for (line, original) in lines.iter().zip(&chunks) {
+            let parsed: LegalChunk = serde_json::from_str(line).unwrap();
+            assert_eq!(parsed.id, original.id);
+            assert_eq!(parsed.content, original.content);
+            assert_eq!(parsed.chunk_type, original.chunk_type);
+            assert_eq!(parsed.metadata.paragraph_number, original.metadata.paragraph_number);
+        }
+    }
+
+    #[test]
+    fn test_export_to_json_round_trip_with_context() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("chunks.json");
+        let chunks = vec![create_test_chunk()];
+
+        WeKnoraExporter::new().export_to_json(&chunks, &path).unwrap();
+
+        let raw: Vec<serde_json::Value> =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(raw[0]["raw_content"], chunks[0].content.as_str());
+
+        let parsed: Vec<LegalChunk> = serde_json::from_value(serde_json::Value::Array(raw)).unwrap();
+        assert!(parsed[0].content.starts_with("[제1편 총칙 > 제1조(목적)]"));
+        assert_eq!(parsed[0].metadata.law_name, "유가증권시장 상장규정");
+    }
+
+    #[test]
+    fn test_export_reports_unwritable_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing").join("chunks.jsonl");
+        let err = WeKnoraExporter::new()
+            .export_to_jsonl(&[create_test_chunk()], &path)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(err.to_string().contains("chunks.jsonl"));
+    }
+
+    #[test]
+    fn test_export_graph() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("graph.json");
+        let mut graph = ReferenceGraph::new();
+        graph.insert("c".to_string(), vec!["a".to_string()]);
+        graph.insert("a".to_string(), vec!["b".to_string()]);
+        graph.insert("b".to_string(), vec![]);
+
+        WeKnoraExporter::new()
+            .export_graph(&graph, path.to_str().unwrap())
+            .unwrap();
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        let parsed: ReferenceGraph = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed, graph);
+        assert!(text.find("\"a\"").unwrap() < text.find("\"c\"").unwrap());
+    }
+
+    fn export_html(chunks: &[LegalChunk]) -> String {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("chunks.html");
+        WeKnoraExporter::new()
+            .export_to_html(chunks, path.to_str().unwrap())
+            .unwrap();
+        std::fs::read_to_string(&path).unwrap()
+    }
+
+    #[test]
+    fn test_export_to_html_document_shell() {
+        let html = export_html(&[create_test_chunk()]);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<html lang=\"ko\">"));
+        assert!(html.contains("<title>유가증권시장 상장규정</title>"));
+        assert!(html.trim_end().ends_with("</html>"));
+    }
+
+    #[test]
+    fn test_export_to_html_one_article_per_chunk() {
+        let mut second = create_test_chunk();
+        second.id = "test456".to_string();
+        let html = export_html(&[create_test_chunk(), second]);
+        assert_eq!(html.matches("<article ").count(), 2);
+        assert_eq!(html.matches("</article>").count(), 2);
+        assert!(html.find("test123").unwrap() < html.find("test456").unwrap());
+    }
+
+    #[test]
+    fn test_export_to_html_data_attributes() {
+        let html = export_html(&[create_test_chunk()]);
+        assert!(html.contains(
+            "<article data-chunk-id=\"test123\" data-article=\"1\" data-law=\"유가증권시장 상장규정\">"
+        ));
+    }
+
+    #[test]
+    fn test_export_to_html_header_has_law_and_article() {
+        let html = export_html(&[create_test_chunk()]);
+        assert!(html.contains("<header><h2>유가증권시장 상장규정 제1조(목적)</h2></header>"));
+
+        let mut branch = create_test_chunk();
+        branch.metadata.article_number = Some("2의3".to_string());
+        branch.metadata.article_title = None;
+        assert!(export_html(&[branch]).contains("<h2>유가증권시장 상장규정 제2조의3</h2>"));
+    }
+
+    #[test]
+    fn test_export_to_html_section_and_footer() {
+        let mut chunk = create_test_chunk();
+        chunk.content = "제1조(목적)\n\n① 첫째 항\n② 둘째 항".to_string();
+        let html = export_html(&[chunk]);
+        let section = &html[html.find("<section>").unwrap()..html.find("</section>").unwrap()];
+        assert_eq!(section.matches("<p>").count(), 3, "blank lines are dropped");
+        assert!(section.contains("<p>② 둘째 항</p>"));
+        assert!(html.contains("<footer>제1편 총칙 &gt; 제1조(목적)</footer>"));
+    }
+
+    #[test]
+    fn test_export_to_html_marks_cross_references() {
+        let mut chunk = create_test_chunk();
+        chunk.content = "제3조(적용) 제5조제1항 및 제7조의2에 따른다.".to_string();
+        let html = export_html(&[chunk]);
+        assert!(html.contains("<mark>제5조제1항</mark>"));
+        assert!(html.contains("<mark>제7조의2</mark>"));
+        assert!(!html.contains("<mark>제3조</mark>"), "own article heading is not a reference");
+    }
+
+    #[test]
+    fn test_export_to_html_escapes_text_and_attributes() {
+        let mut chunk = create_test_chunk();
+        chunk.id = "a\"b".to_string();
+        chunk.metadata.law_name = "<법> & 규정".to_string();
+        chunk.content = "x < y & z".to_string();
+        let html = export_html(&[chunk]);
+        assert!(html.contains("data-chunk-id=\"a&quot;b\""));
+        assert!(html.contains("data-law=\"&lt;법&gt; &amp; 규정\""));
+        assert!(html.contains("<p>x &lt; y &amp; z</p>"));
+        assert!(!html.contains("<법>"));
+    }
+
+    #[test]
+    fn test_export_to_html_without_article_or_context() {
+        let mut chunk = create_test_chunk();
+        chunk.chunk_type = ChunkType::Appendix;
+        chunk.metadata.article_number = None;
+        chunk.context_path.clear();
+        let html = export_html(&[chunk]);
+        assert!(html.contains("data-article=\"\""));
+        assert!(html.contains("<header><h2>유가증권시장 상장규정</h2></header>"));
+        assert!(!html.contains("<footer>"));
+    }
+
+    #[test]
+    fn test_summary() {
+        let exporter = WeKnoraExporter::new();
+        let mut other = create_test_chunk();
+        other.metadata.law_name = "코스닥시장 상장규정".to_string();
+        other.token_count = 10;
+        let chunks = vec![create_test_chunk(), create_test_chunk(), other];
+
+        assert_eq!(
+            exporter.summary(&chunks),
+            ExportSummary { chunk_count: 3, total_tokens: 60, unique_laws: 2 }
+        );
+        assert_eq!(exporter.summary(&[]), ExportSummary::default());
+    }
+
     #[test]
     fn test_processing_stats() {
         let mut stats = ProcessingStats::new();
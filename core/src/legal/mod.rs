@@ -25,7 +25,7 @@ pub mod chains;
 
 pub use types::*;
 pub use patterns::*;
-pub use chunker::KoreanLegalChunker;
-pub use exporter::WeKnoraExporter;
+pub use chunker::{build_reference_graph, ChunkerError, KoreanLegalChunker};
+pub use exporter::{ExportSummary, WeKnoraExporter};
 pub use annex::{AnnexParser, AnnexInfo, AnnexType};
 pub use chains::{ChainPlan, ChainStep, ChainType};
@@ -68,6 +68,11 @@ lazy_static! {
     /// 공백 패턴
     pub static ref RE_WHITESPACE: Regex = Regex::new(r"\s").unwrap();
 
+    /// 문장 끝 패턴: 한글 음절 뒤 마침표(`다.`, `한다.`, `않는다.`) 또는 `。`.
+    /// 뒤따르는 공백까지 포함하며, 숫자 뒤 마침표(`2024. 1.`, `1.`)는
+    /// 제외한다.
+    pub static ref RE_SENTENCE_END: Regex = Regex::new(r"(?:[가-힣]\.|。)(?:\s+|$)").unwrap();
+
     /// 별표(Annex) 패턴: 별표 1, 별표1의2, [별표 3] 안전관리기준
     pub static ref RE_ANNEX: Regex = Regex::new(
         r"^\[?별표\s*(\d+)(?:의\s*(\d+))?\]?\s*(.*?)$"
@@ -78,6 +83,13 @@ lazy_static! {
         r"^\[?별지\s*(?:서식\s*)?(?:제?\s*)?(\d+)(?:호)?(?:의\s*(\d+))?\s*(?:서식)?\]?\s*(.*?)$"
     ).unwrap();
 
+    /// 부록 제목 패턴: [별표 1] 수수료, 별지 제2호서식, 부칙 <제15호, 2024. 1. 15.>
+    /// Groups: (1) 표제(`별표 1`, `별지 제2호서식`, `부칙`), (2) 별표 번호, (3) 별표 가지번호,
+    /// (4) 별지 번호, (5) 별지 가지번호, (6) 제목
+    pub static ref RE_APPENDIX: Regex = Regex::new(
+        r"^\[?(별표\s*(\d+)(?:의\s*(\d+))?|별지\s*(?:제\s*)?(\d+)호(?:의\s*(\d+))?(?:\s*서식)?|부칙)\]?(?:\s+(.*?))?\s*$"
+    ).unwrap();
+
     /// 첨부(Attachment) 패턴: [첨부1], 첨부 2
     pub static ref RE_ATTACHMENT: Regex = Regex::new(
         r"^\[?첨부\s*(\d+)\]?\s*(.*?)$"
@@ -194,6 +206,17 @@ mod tests {
         assert!(RE_PARAGRAPH.is_match("(1) 괄호 숫자"));
     }
 
+    #[test]
+    fn test_re_sentence_end() {
+        let ends: Vec<_> = RE_SENTENCE_END
+            .find_iter("이 조를 적용한다. 다만, 2024. 1. 1. 이후는 그러하지 않는다.")
+            .map(|m| m.as_str().trim())
+            .collect();
+        assert_eq!(ends, vec!["다.", "다."]);
+        assert!(RE_SENTENCE_END.is_match("끝。"));
+        assert!(!RE_SENTENCE_END.is_match("1. 첫 번째 호"));
+    }
+
     #[test]
     fn test_re_subparagraph() {
         let caps = RE_SUBPARAGRAPH.captures("1. 첫 번째 호").unwrap();
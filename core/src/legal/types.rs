@@ -135,6 +135,9 @@ pub struct LegalMetadata {
     /// 항 번호
     #[serde(skip_serializing_if = "Option::is_none")]
     pub paragraph_number: Option<String>,
+    /// 별표/별지 번호 (예: "1", "2의3")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub appendix_number: Option<String>,
 
     /// 참조 관계
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -160,6 +163,12 @@ pub enum ChunkType {
     Paragraph,
     /// 정의 청크
     Definition,
+    /// 별표/별지 청크
+    Appendix,
+    /// 부칙 청크
+    Supplement,
+    /// 앞 청크의 끝부분을 이어 붙인 슬라이딩 윈도 청크
+    Overlap,
 }
 
 
@@ -181,13 +190,138 @@ pub struct LegalChunk {
     /// 상위 청크 ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parent_chunk_id: Option<String>,
+    /// [`ChunkType::Overlap`] 청크에서 앞 청크로부터 가져온 접두부의 끝
+    /// (`content` 바이트 오프셋). 그 외 청크는 0
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub overlap_prefix_end: usize,
+    /// [`ChunkType::Overlap`] 청크를 만든 두 청크의 ID (앞 청크, 이 청크
+    /// 순서). 그 외 청크는 비어 있다
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub source_chunk_ids: Vec<String>,
+}
+
+/// 계층 구조 경고 유형
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningType {
+    /// 장으로 나뉜 법령에서 장 밖에 있는 조
+    ArticleBeforeChapter,
+    /// 편으로 나뉜 법령에서 편 없이 장·절 아래에 있는 조
+    MissingPart,
+    /// 앞 조보다 번호가 2 이상 큰 조
+    SkippedArticleNumber,
+    /// 같은 조 번호(가지번호 포함)를 가진 두 번째 이후 청크
+    DuplicateArticle,
+}
+
+/// [`crate::legal::KoreanLegalChunker::validate_hierarchy`]가 찾은 구조 이상
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HierarchyWarning {
+    /// 문제가 발견된 청크 ID
+    pub chunk_id: String,
+    /// 경고 유형
+    pub warning_type: WarningType,
+    /// 사람이 읽을 수 있는 설명
+    pub detail: String,
+}
+
+/// [`crate::legal::KoreanLegalChunker::tokenize_breakdown`]의 범주별 집계
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenBreakdown {
+    /// 한글이 들어 있는 어절(공백으로 나뉜 단어) 수
+    pub hangul_eojeol: usize,
+    /// 로마자 연속 구간 수
+    pub latin_words: usize,
+    /// 로마자 글자 수
+    pub latin_chars: usize,
+    /// 한자(CJK 통합 한자) 글자 수
+    pub cjk_chars: usize,
+    /// 숫자 연속 구간 수
+    pub numbers: usize,
+    /// 숫자 글자 수
+    pub digits: usize,
+    /// 문장부호·기호 글자 수 (「」, ①, 괄호 등)
+    pub punctuation: usize,
+}
+
+impl TokenBreakdown {
+    /// 어절당 1.3, 로마자 4자당 1(단어마다 최소 1), 한자 1자당 0.7,
+    /// 숫자 3자리당 1(수마다 최소 1), 문장부호 1자당 1토큰으로 합산해 반올림
+    pub fn estimated_tokens(&self) -> usize {
+        let latin = self.latin_words.max(self.latin_chars.div_ceil(4));
+        let numbers = self.numbers.max(self.digits.div_ceil(3));
+        let total = self.hangul_eojeol as f64 * 1.3
+            + latin as f64
+            + self.cjk_chars as f64 * 0.7
+            + numbers as f64
+            + self.punctuation as f64;
+        total.round() as usize
+    }
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
 }
 
+/// 청크 간 참조 그래프: 청크 ID → 참조하는 청크 ID 목록
+pub type ReferenceGraph = HashMap<String, Vec<String>>;
+
 impl LegalChunk {
     /// JSON 객체로 변환 (serde_json::Value)
     pub fn to_json(&self) -> serde_json::Value {
         serde_json::to_value(self).unwrap_or_default()
     }
+
+    /// `previous`의 마지막 `chunker.overlap_tokens` 분량을 내용 앞에 붙인
+    /// [`ChunkType::Overlap`] 청크
+    ///
+    /// 접두부는 가능하면 공백에서 시작해 단어를 자르지 않는다. 메타데이터와
+    /// 컨텍스트 경로는 이 청크의 것을 따르고 `source_chunk_ids`에는
+    /// `previous`와 이 청크의 ID를 기록한다. 가져올 내용이 없으면 `None`.
+    pub fn overlap_with(
+        &self,
+        previous: &LegalChunk,
+        chunker: &crate::legal::chunker::KoreanLegalChunker,
+    ) -> Option<LegalChunk> {
+        let tail = overlap_tail(&previous.content, chunker.overlap_tokens, |t| chunker.estimate_tokens(t));
+        if tail.is_empty() || self.content.is_empty() {
+            return None;
+        }
+
+        let content = format!("{}\n{}", tail, self.content);
+        Some(LegalChunk {
+            id: chunker.generate_chunk_id(&content, &self.metadata),
+            token_count: chunker.estimate_tokens(&content),
+            content,
+            metadata: self.metadata.clone(),
+            chunk_type: ChunkType::Overlap,
+            context_path: self.context_path.clone(),
+            parent_chunk_id: None,
+            overlap_prefix_end: tail.len(),
+            source_chunk_ids: vec![previous.id.clone(), self.id.clone()],
+        })
+    }
+}
+
+/// `text`의 접미부 중 추정 토큰 수가 `max_tokens` 이하인 가장 긴 것
+fn overlap_tail(text: &str, max_tokens: usize, estimate: impl Fn(&str) -> usize) -> &str {
+    let text = text.trim_end();
+    if max_tokens == 0 {
+        return "";
+    }
+    // 접미부가 길어질수록 추정치는 줄지 않으므로 이분 탐색
+    let starts: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+    let first_fit = starts.partition_point(|&start| estimate(&text[start..]) > max_tokens);
+    let Some(&start) = starts.get(first_fit) else {
+        return "";
+    };
+    let tail = &text[start..];
+    // 단어 중간에서 시작하면 다음 공백 뒤로 당긴다
+    let mid_word = text[..start].chars().next_back().is_some_and(|c| !c.is_whitespace());
+    match tail.find(char::is_whitespace) {
+        Some(space) if mid_word => tail[space..].trim_start(),
+        _ => tail.trim_start(),
+    }
 }
 
 /// 파싱 상태 추적
@@ -28,6 +28,8 @@ pub mod xls;
 pub mod rtf;
 #[cfg(feature = "epub")]
 pub mod epub;
+#[cfg(feature = "odt")]
+pub mod odt;
 pub mod pptx;
 #[cfg(feature = "url-fetch")]
 pub mod url_fetch;
@@ -45,14 +47,17 @@ pub mod ir;
 pub mod print;
 pub mod chunker;
 pub mod ocr;
+pub use mdm_placeholder as placeholder;
 #[cfg(feature = "image-processing")]
 pub mod renderer;
 #[cfg(feature = "image-processing")]
 pub mod optimizer;
 pub mod cache;
+pub mod batch;
 pub mod legal;
 pub mod form;
 pub mod manifest;
+pub mod multipart;
 pub mod utils;
 pub mod pii;
 pub mod lint;
@@ -81,6 +86,22 @@ pub struct Config {
     pub quality: u8,
     /// Enable caching
     pub cache_enabled: bool,
+    /// Emit HWP table cell background colors as inline `<td style>` HTML
+    #[serde(default)]
+    pub preserve_table_colors: bool,
+    /// Split MDX output into numbered parts above this size (bytes)
+    #[serde(default)]
+    pub max_output_size_bytes: Option<u64>,
+    /// Wrap HWPX paragraphs in `<p>` carrying their above/below spacing
+    #[serde(default)]
+    pub preserve_para_spacing: bool,
+    /// Leave colored HWP text unwrapped instead of emitting `<span style="color">`
+    #[serde(default)]
+    pub strip_color_spans: bool,
+    /// Stop reading an HWP record stream at the first truncated record
+    /// instead of skipping ahead to the next plausible record header
+    #[serde(default)]
+    pub strict_records: bool,
 }
 
 impl Default for Config {
@@ -89,6 +110,61 @@ impl Default for Config {
             format: "svg".to_string(),
             quality: 85,
             cache_enabled: true,
+            preserve_table_colors: false,
+            max_output_size_bytes: None,
+            preserve_para_spacing: false,
+            strip_color_spans: false,
+            strict_records: false,
         }
     }
 }
+
+impl Config {
+    /// Values accepted for [`Config::format`]
+    pub const FORMATS: &'static [&'static str] = &["svg", "png", "webp", "jpeg", "avif"];
+
+    /// Check every field, collecting all problems instead of stopping at the
+    /// first one
+    pub fn validate(&self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = Vec::new();
+        if self.quality > 100 {
+            errors.push(ConfigError::new("quality", format!("must be 0-100, got {}", self.quality)));
+        }
+        if !Self::FORMATS.contains(&self.format.as_str()) {
+            errors.push(ConfigError::new(
+                "format",
+                format!("unsupported format {:?}, expected one of {}", self.format, Self::FORMATS.join("|")),
+            ));
+        }
+        if self.max_output_size_bytes == Some(0) {
+            errors.push(ConfigError::new("max_output_size_bytes", "must be greater than 0 when set"));
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// [`Config::validate`] for builder-style construction
+    pub fn validated(self) -> Result<Self, Vec<ConfigError>> {
+        self.validate()?;
+        Ok(self)
+    }
+}
+
+/// One invalid [`Config`] field
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{field}: {message}")]
+pub struct ConfigError {
+    /// Name of the offending field, e.g. `"quality"`
+    pub field: String,
+    /// What is wrong with its value
+    pub message: String,
+}
+
+impl ConfigError {
+    fn new(field: &str, message: impl Into<String>) -> Self {
+        Self { field: field.to_string(), message: message.into() }
+    }
+}
@@ -26,6 +26,8 @@ mod xls;
 mod rtf;
 #[cfg(feature = "epub")]
 mod epub;
+#[cfg(feature = "odt")]
+mod odt;
 mod pptx;
 #[cfg(feature = "url-fetch")]
 mod url_fetch;
@@ -52,6 +54,13 @@ use pptx::PptxParser;
 use html::HtmlParser;
 use csv_parser::CsvParser;
 use txt_parser::TxtParser;
+// Shared engine config — re-exported at the crate root so modules compiled
+// into the binary resolve `crate::Config` the same way they do in the lib.
+use mdm_core::{Config, ConfigError};
+// Lib-only disk cache, referenced as `crate::cache` by the HWP parser.
+use mdm_core::cache;
+use mdm_core::batch;
+use mdm_core::multipart::{write_multipart_with_config, MultipartOutput};
 use quick_xml::events::Event;
 use quick_xml::Reader;
 use serde_json::json;
@@ -95,6 +104,14 @@ struct Cli {
     /// Enable OCR for scanned/image-based PDF pages
     #[arg(long)]
     ocr: bool,
+
+    /// Split MDX output into numbered parts above this size (bytes)
+    #[arg(long, value_name = "BYTES")]
+    max_output_size: Option<u64>,
+
+    /// Validate FILE as an `.mdm` manifest instead of converting it (exit 0 = valid, 1 = invalid)
+    #[arg(long)]
+    validate: bool,
 }
 
 #[derive(Subcommand)]
@@ -108,7 +125,7 @@ enum Commands {
         #[arg(short, long, default_value = "./output")]
         output: PathBuf,
         
-        /// Output format (mdx, json)
+        /// Output format (mdx, json, html — html for HWP input)
         #[arg(short, long, default_value = "mdx")]
         format: String,
         
@@ -119,12 +136,65 @@ enum Commands {
         /// Enable OCR for scanned/image-based pages
         #[arg(long)]
         ocr: bool,
+
+        /// PDF: with --ocr, OCR every page with tesseract in this language
+        /// (e.g. kor, kor+eng) instead of the built-in engine
+        #[arg(long, value_name = "LANG", requires = "ocr", conflicts_with = "pages")]
+        ocr_lang: Option<String>,
+
+        /// Split MDX output into numbered parts above this size (bytes)
+        #[arg(long, value_name = "BYTES")]
+        max_output_size: Option<u64>,
+
+        /// Only convert these PDF pages (1-based, e.g. 3-7)
+        #[arg(long, value_name = "START-END", value_parser = pdf::parse_page_range)]
+        pages: Option<std::ops::RangeInclusive<usize>>,
+
+        /// Re-encode images under <output>/assets in place when that makes them smaller
+        #[arg(long)]
+        optimize_assets: bool,
+
+        /// Between HWPX sections: concatenate, horizontal-rule, page-break-comment or html-page-break
+        #[arg(long, value_name = "MODE", default_value = "horizontal-rule")]
+        section_separator: String,
+
+        /// DOCX tracked changes: keep insertions and drop deletions
+        #[arg(long, conflicts_with = "reject_all")]
+        accept_all: bool,
+
+        /// DOCX tracked changes: drop insertions and keep the original text
+        #[arg(long)]
+        reject_all: bool,
+
+        /// DOCX: add key/value pairs from customXml/ parts to the frontmatter
+        #[arg(long)]
+        include_custom_xml: bool,
+
+        /// HWP: leave out the "Table of Contents" section built from 차례 fields
+        #[arg(long)]
+        no_toc: bool,
+
+        /// HWP: plain Markdown for colored text, without `<span style="color">` wrappers
+        #[arg(long)]
+        strip_color_spans: bool,
+
+        /// HWP: emit tables with colored cells as HTML carrying their backgrounds
+        #[arg(long)]
+        preserve_table_colors: bool,
+
+        /// HWPX: wrap paragraphs in `<p>` carrying their above/below spacing
+        #[arg(long)]
+        preserve_para_spacing: bool,
     },
     
     /// Analyze HWP file structure
     Analyze {
         /// Input HWP file
         input: PathBuf,
+
+        /// List `<hp:bookmark>` names with their section and paragraph (HWPX only)
+        #[arg(long)]
+        bookmarks: bool,
     },
     
     /// Extract text from HWP file
@@ -141,16 +211,62 @@ enum Commands {
         /// Output directory
         #[arg(short, long, default_value = "./output/assets")]
         output: PathBuf,
+
+        /// Also write 64/128/256px previews to <output>/thumbnails
+        #[arg(long)]
+        thumbnails: bool,
+
+        /// Remove EXIF/XMP (APP1) metadata such as GPS from JPEG images
+        #[arg(long)]
+        strip_exif: bool,
+
+        /// Draw this text over every extracted image
+        #[arg(long)]
+        watermark: Option<String>,
+
+        /// Watermark anchor: top-left, top-right, bottom-left, bottom-right or center
+        #[arg(long, default_value = "bottom-right")]
+        watermark_position: String,
+
+        /// Also write responsive variants at these pixel widths (e.g.
+        /// "400,800,1200") to <output>/srcset and print their srcset value
+        #[arg(long, value_name = "WIDTHS")]
+        srcset: Option<String>,
     },
     
     /// Batch convert multiple files
     Batch {
-        /// Glob pattern (e.g., "*.hwp", "docs/**/*.hwp")
+        /// Glob pattern (e.g., "*.hwp", "docs/**/*.hwp", "src/**/*.{hwp,hwpx,pdf}")
         pattern: String,
         
-        /// Output directory
+        /// Output directory (mirrors the source tree below the pattern's base)
         #[arg(short, long, default_value = "./output")]
         output: PathBuf,
+
+        /// List matched files and their output directories without converting
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Convert files concurrently (requires the `parallel` feature)
+        #[arg(long)]
+        parallel: bool,
+
+        /// Worker threads for --parallel [default: number of CPUs]
+        #[arg(long, value_name = "N", requires = "parallel")]
+        jobs: Option<usize>,
+
+        /// Convert HWP files even when their first section duplicates an earlier file
+        #[arg(long)]
+        no_dedup: bool,
+
+        /// Re-encode images in the output tree in place when that makes them smaller
+        #[arg(long)]
+        optimize_assets: bool,
+
+        /// HWP section cache directory (e.g. a volume kept from an earlier run),
+        /// preloaded before converting and updated as files are read
+        #[arg(long, value_name = "DIR")]
+        warm_cache: Option<PathBuf>,
     },
     
     /// Show file information and metadata
@@ -158,9 +274,90 @@ enum Commands {
         /// Input file (HWP, HWPX, PDF)
         input: PathBuf,
 
-        /// Output format (text, json)
+        /// Output format (text, json, html — html for HWP input)
         #[arg(short, long, default_value = "text")]
         format: String,
+
+        /// Print preflight for PDFs: exit 0 if any CMYK image or color
+        /// operator is found, 1 otherwise
+        #[arg(long)]
+        check_cmyk: bool,
+    },
+
+    /// Merge DOCX files (e.g. one per chapter) into a single MDX document.
+    ///
+    /// Documents are joined in argument order with a page break (`---`)
+    /// between them; images are written to `assets/` next to the output.
+    ///
+    /// Example:
+    ///   hwp2mdm merge ch1.docx ch2.docx ch3.docx -o book.mdx
+    Merge {
+        /// Input DOCX files, in order
+        #[arg(required = true, num_args = 2..)]
+        inputs: Vec<PathBuf>,
+
+        /// Output MDX file
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Merge PDF files into a single MDX document.
+    ///
+    /// Pages are renumbered sequentially in argument order; metadata comes
+    /// from the first file.
+    ///
+    /// Example:
+    ///   hwp2mdm merge-pdf part1.pdf part2.pdf -o report.mdx
+    MergePdf {
+        /// Input PDF files, in order
+        #[arg(required = true, num_args = 2..)]
+        inputs: Vec<PathBuf>,
+
+        /// Output MDX file
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Find which section and paragraph of an HWP file contain a phrase.
+    ///
+    /// Example:
+    ///   hwp2mdm find report.hwp "시행일"
+    ///   hwp2mdm find report.hwp "시행일" --max-results 5
+    Find {
+        /// Input HWP file
+        input: PathBuf,
+
+        /// Text to search for (exact match)
+        query: String,
+
+        /// Stop after this many matches
+        #[arg(long)]
+        max_results: Option<usize>,
+    },
+
+    /// Check that every font in a PDF is embedded (PDF/A prerequisite).
+    ///
+    /// Lists each font with its embedding status and encoding; exits with
+    /// code 1 if any font is only referenced by name.
+    ///
+    /// Example:
+    ///   hwp2mdm check-fonts report.pdf
+    CheckFonts {
+        /// Input PDF file
+        input: PathBuf,
+    },
+
+    /// Split a PDF into one file per page (`{stem}_page_0001.pdf`, ...).
+    ///
+    /// Example:
+    ///   hwp2mdm split archive.pdf -o ./pages
+    Split {
+        /// Input PDF file
+        input: PathBuf,
+
+        /// Output directory for the per-page PDFs
+        #[arg(short, long, default_value = "./output/pages")]
+        output: PathBuf,
     },
 
     /// Inspect document structure: tables (merged cells, nested), equations, images.
@@ -238,7 +435,7 @@ enum Commands {
     /// Example:
     ///   cat contract.hwp | hwp2mdm stream --ext hwp > out.md
     Stream {
-        /// File extension hint (hwp, hwpx, pdf, docx, pptx, xlsx, xls, rtf, epub, html, csv, tsv, txt).
+        /// File extension hint (hwp, hwpx, pdf, docx, odt, pptx, xlsx, xls, rtf, epub, html, csv, tsv, txt).
         /// Required because stdin has no filename for auto-detection.
         #[arg(long)]
         ext: String,
@@ -379,26 +576,38 @@ enum Commands {
         overlap: usize,
     },
 
-    /// Watch a directory and auto-convert documents on change.
+    /// Watch a directory or a single document and auto-convert on change.
     ///
-    /// Monitors a directory for new/modified documents and converts them
-    /// automatically. Optional webhook notification on each conversion.
+    /// A directory is monitored for new/modified documents, with optional
+    /// webhook notification on each conversion. A single file is converted
+    /// once on startup and again every time it is saved; CTRL-C prints a
+    /// summary of the session.
     ///
     /// Example:
     ///   hwp2mdm watch ./incoming -o ./output
     ///   hwp2mdm watch ./incoming --webhook https://hooks.example/convert
+    ///   hwp2mdm watch report.hwp -o ./output --format mdx
     #[cfg(feature = "watch")]
     Watch {
-        /// Directory to watch
-        dir: PathBuf,
+        /// Directory or document to watch
+        input: PathBuf,
 
         /// Output directory for converted files
         #[arg(short, long, default_value = "./output")]
         output: PathBuf,
 
-        /// Webhook URL for conversion notifications
+        /// Output format when watching a single document (mdx, json)
+        #[arg(short, long, default_value = "mdx")]
+        format: String,
+
+        /// Webhook URL for conversion notifications (directory mode)
         #[arg(long)]
         webhook: Option<String>,
+
+        /// Coalesce change events closer together than N milliseconds
+        /// [default: 500 for a document, 1000 for a directory]
+        #[arg(long, value_name = "N")]
+        debounce_ms: Option<u64>,
     },
 
     /// Parse Korean legal documents with hierarchy detection.
@@ -419,6 +628,69 @@ enum Commands {
         format: String,
     },
 
+    /// Export Korean legal chunks to a file for search / RAG ingestion.
+    ///
+    /// Example:
+    ///   hwp2mdm legal-export law.md -o law.jsonl
+    ///   hwp2mdm legal-export law.md -o law.html --format html
+    LegalExport {
+        /// Input markdown file
+        input: PathBuf,
+
+        /// Output file
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Output format: jsonl, json or html (semantic <article> per chunk)
+        #[arg(short, long, default_value = "jsonl")]
+        format: String,
+    },
+
+    /// List the form fields (누름틀, date, user info, ...) of an HWPX file.
+    ///
+    /// Example:
+    ///   hwp2mdm fields application.hwpx
+    ///   hwp2mdm fields application.hwpx --format json
+    Fields {
+        /// Input HWPX file
+        input: PathBuf,
+
+        /// Output format: text (human-readable) or json (structured)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// List the saved revisions (문서 이력) of an HWP file.
+    ///
+    /// Example:
+    ///   hwp2mdm history contract.hwp
+    ///   hwp2mdm history contract.hwp --format json
+    History {
+        /// Input HWP file
+        input: PathBuf,
+
+        /// Output format: text (human-readable) or json (structured)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
+    /// Check a Korean legal document for hierarchy anomalies.
+    ///
+    /// Reports articles outside any chapter, chapters missing their part,
+    /// skipped article numbers and duplicate articles — usually OCR or
+    /// formatting errors. Exits 1 when any warning is found.
+    ///
+    /// Example:
+    ///   hwp2mdm legal-check law.md
+    LegalCheck {
+        /// Input markdown file
+        input: PathBuf,
+
+        /// Output format: text (human-readable) or json (structured)
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
     /// Fetch and convert a web page to Markdown.
     ///
     /// Downloads a URL, extracts the main content body (stripping navigation,
@@ -461,17 +733,19 @@ enum Commands {
         direction: String,
     },
 
-    /// Render HWPX pages to layout-preserving SVG (or PNG).
+    /// Render HWPX pages to layout-preserving SVG (or PNG), or one PDF page to PNG.
     ///
     /// Writes one file per page (`<stem>-p001.svg`, ...) into the output
     /// directory. Requires the `hwpx-render` feature (PNG needs
     /// `hwpx-render-png`); without them a build note is printed.
+    /// PDF input is rasterized with `pdftoppm` or `mutool` at `--dpi`.
     ///
     /// Example:
     ///   hwp2mdm render document.hwpx -o ./pages
     ///   hwp2mdm render document.hwpx --png --scale 3.0 -o ./pages
+    ///   hwp2mdm render document.pdf --page 3 --dpi 200 -o ./pages
     Render {
-        /// Input HWPX file
+        /// Input HWPX or PDF file
         input: PathBuf,
 
         /// Output directory for page images
@@ -489,6 +763,14 @@ enum Commands {
         /// Tier-2 reflow — render files without a layout cache via synthetic typesetting
         #[arg(long)]
         reflow: bool,
+
+        /// Page to render, 1-based (PDF input only)
+        #[arg(long, default_value = "1")]
+        page: usize,
+
+        /// Rasterization resolution (PDF input only)
+        #[arg(long, default_value = "150")]
+        dpi: u32,
     },
 
     /// Render Markdown (or any supported document) to print-ready HTML (or PDF).
@@ -556,23 +838,72 @@ fn main() {
         .ok(); // Ignore if already initialized
 
     match cli.command {
-        Some(Commands::Convert { input, output, format, extract_images, ocr }) => {
-            convert_file(&input, &output, &format, extract_images, true, ocr);
+        Some(Commands::Convert {
+            input, output, format, extract_images, ocr, ocr_lang, max_output_size, pages, optimize_assets,
+            section_separator, accept_all, reject_all, include_custom_xml, no_toc, strip_color_spans,
+            preserve_table_colors, preserve_para_spacing,
+        }) => {
+            let section_join: hwpx::SectionJoinMode = match section_separator.parse() {
+                Ok(mode) => mode,
+                Err(e) => {
+                    eprintln!("❌ {}", e);
+                    std::process::exit(1);
+                }
+            };
+            let revisions = if accept_all {
+                docx::RevisionMode::AcceptAll
+            } else if reject_all {
+                docx::RevisionMode::RejectAll
+            } else {
+                docx::RevisionMode::Markup
+            };
+            let options = FormatOptions {
+                revisions, include_custom_xml, no_toc, ocr_lang, strip_color_spans, preserve_table_colors,
+                preserve_para_spacing,
+            };
+            convert_file(&input, &output, &format, extract_images, true, ocr, max_output_size, pages, section_join, options);
+            if optimize_assets {
+                optimize_assets_dir(&output.join("assets"));
+            }
         }
-        Some(Commands::Analyze { input }) => {
-            analyze_file(&input);
+        Some(Commands::Analyze { input, bookmarks }) => {
+            analyze_file(&input, bookmarks);
         }
         Some(Commands::Text { input }) => {
             extract_text(&input);
         }
-        Some(Commands::Images { input, output }) => {
-            extract_images(&input, &output);
+        Some(Commands::Images { input, output, thumbnails, strip_exif, watermark, watermark_position, srcset }) => {
+            let watermark = watermark.map(|text| (text, watermark_position));
+            let srcset = srcset.map(|widths| parse_srcset_widths(&widths));
+            extract_images(&input, &output, thumbnails, strip_exif, watermark.as_ref(), srcset.as_deref());
+        }
+        Some(Commands::Batch { pattern, output, dry_run, parallel, jobs, no_dedup, optimize_assets, warm_cache }) => {
+            batch_convert(&pattern, &output, dry_run, parallel, jobs, !no_dedup, warm_cache.as_deref());
+            if optimize_assets && !dry_run {
+                optimize_assets_dir(&output);
+            }
+        }
+        Some(Commands::Info { input, format, check_cmyk }) => {
+            if check_cmyk {
+                check_pdf_cmyk(&input);
+            } else {
+                show_info(&input, &format);
+            }
         }
-        Some(Commands::Batch { pattern, output }) => {
-            batch_convert(&pattern, &output);
+        Some(Commands::Merge { inputs, output }) => {
+            merge_docx_files(&inputs, &output);
         }
-        Some(Commands::Info { input, format }) => {
-            show_info(&input, &format);
+        Some(Commands::MergePdf { inputs, output }) => {
+            merge_pdf_files(&inputs, &output);
+        }
+        Some(Commands::Find { input, query, max_results }) => {
+            find_in_file(&input, &query, max_results);
+        }
+        Some(Commands::CheckFonts { input }) => {
+            check_fonts(&input);
+        }
+        Some(Commands::Split { input, output }) => {
+            split_pdf(&input, &output);
         }
         Some(Commands::Inspect { input, format }) => {
             inspect_file(&input, &format);
@@ -614,12 +945,32 @@ fn main() {
             cmd_chunks(&input, &granularity, max_chars, overlap);
         }
         #[cfg(feature = "watch")]
-        Some(Commands::Watch { dir, output, webhook }) => {
-            cmd_watch(&dir, &output, webhook.as_deref());
+        Some(Commands::Watch { input, output, format, webhook, debounce_ms }) => {
+            let debounce = debounce_ms.map(std::time::Duration::from_millis);
+            if input.is_file() {
+                if webhook.is_some() {
+                    eprintln!("\u{26a0}\u{fe0f}  --webhook applies to directory watches only; ignoring");
+                }
+                cmd_watch_file(&input, &output, &format, debounce.unwrap_or(WATCH_FILE_DEBOUNCE));
+            } else {
+                cmd_watch(&input, &output, webhook.as_deref(), debounce);
+            }
         }
         Some(Commands::Legal { input, format }) => {
             cmd_legal(&input, &format);
         }
+        Some(Commands::LegalExport { input, output, format }) => {
+            cmd_legal_export(&input, &output, &format);
+        }
+        Some(Commands::LegalCheck { input, format }) => {
+            cmd_legal_check(&input, &format);
+        }
+        Some(Commands::Fields { input, format }) => {
+            cmd_fields(&input, &format);
+        }
+        Some(Commands::History { input, format }) => {
+            cmd_history(&input, &format);
+        }
         #[cfg(feature = "url-fetch")]
         Some(Commands::Url { urls, output }) => {
             cmd_url(&urls, output.as_deref());
@@ -630,8 +981,15 @@ fn main() {
         Some(Commands::Equation { input, direction }) => {
             cmd_equation(&input, &direction);
         }
-        Some(Commands::Render { input, output, png, scale, reflow }) => {
-            cmd_render(&input, &output, png, scale, reflow);
+        Some(Commands::Render { input, output, png, scale, reflow, page, dpi }) => {
+            let is_pdf = input
+                .extension()
+                .is_some_and(|e| e.eq_ignore_ascii_case("pdf"));
+            if is_pdf {
+                render_pdf_page(&input, &output, page, dpi);
+            } else {
+                cmd_render(&input, &output, png, scale, reflow);
+            }
         }
         Some(Commands::Print { input, output, preset, pdf }) => {
             cmd_print(&input, output.as_deref(), &preset, pdf);
@@ -642,7 +1000,10 @@ fn main() {
         None => {
             // Quick conversion mode
             if let Some(input) = cli.input {
-                convert_file(&input, &cli.output, &cli.format, cli.extract_images, cli.verbose, cli.ocr);
+                if cli.validate {
+                    std::process::exit(validate_manifest_file(&input));
+                }
+                convert_file(&input, &cli.output, &cli.format, cli.extract_images, cli.verbose, cli.ocr, cli.max_output_size, None, Default::default(), Default::default());
             } else {
                 // Show help
                 println!("hwp2mdm - HWP to MDM Converter");
@@ -654,6 +1015,7 @@ fn main() {
                 println!("  hwp2mdm text <FILE>         Extract text only");
                 println!("  hwp2mdm images <FILE>       Extract images only");
                 println!("  hwp2mdm batch <PATTERN>     Batch convert files");
+                println!("  hwp2mdm --validate <MDM>    Validate an .mdm manifest");
                 println!();
                 println!("OPTIONS:");
                 println!("  -o, --output <DIR>          Output directory [default: ./output]");
@@ -670,7 +1032,7 @@ fn main() {
     }
 }
 
-/// Peek inside a ZIP file to determine if it's DOCX or HWPX.
+/// Peek inside a ZIP file to determine if it's DOCX, HWPX, ODT, ...
 fn detect_zip_format(path: &Path) -> String {
     let file = match std::fs::File::open(path) {
         Ok(f) => f,
@@ -703,6 +1065,9 @@ fn detect_zip_format(path: &Path) -> String {
                     if mt.contains("application/epub+zip") {
                         return "epub".to_string();
                     }
+                    if mt.trim() == "application/vnd.oasis.opendocument.text" {
+                        return "odt".to_string();
+                    }
                 }
             }
             if name.starts_with("META-INF/container.xml") || name.ends_with(".opf") {
@@ -732,6 +1097,27 @@ fn save_asset_file(output_dir: &Path, asset: &manifest::Asset, data: &[u8]) -> i
     Ok(())
 }
 
+/// `hwp2mdm --validate`: check an `.mdm` manifest and return the exit code.
+fn validate_manifest_file(path: &Path) -> i32 {
+    let json = match fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!("error: cannot read {}: {}", path.display(), e);
+            return 1;
+        }
+    };
+    let errors = manifest::ManifestValidator::new().validate_str(&json);
+    if errors.is_empty() {
+        println!("\u{2713} {} is a valid manifest", path.display());
+        return 0;
+    }
+    eprintln!("\u{2717} {}: {} problem(s)", path.display(), errors.len());
+    for error in &errors {
+        eprintln!("  - {}", error);
+    }
+    1
+}
+
 /// RAII guard that redirects stdout to /dev/null for its lifetime.
 ///
 /// Used by `stream_convert` so that the heavily-println-heavy `convert_*`
@@ -811,7 +1197,7 @@ fn stream_convert(ext: &str, mode: &str) -> io::Result<()> {
     // 3. Run the existing converter with stdout redirected to /dev/null.
     {
         let _silencer = StdoutSilencer::new()?;
-        convert_file(&in_path, &out_dir, "mdx", false, false, false);
+        convert_file(&in_path, &out_dir, "mdx", false, false, false, None, None, Default::default(), Default::default());
     } // stdout restored here
 
     // 4. Pick up the produced .mdx.
@@ -848,9 +1234,69 @@ fn strip_frontmatter(s: &str) -> String {
     s.to_string()
 }
 
-fn convert_file(input: &Path, output: &Path, format: &str, extract_images: bool, verbose: bool, ocr: bool) {
+/// Format-specific `convert` flags
+#[derive(Debug, Clone, Default)]
+struct FormatOptions {
+    /// DOCX tracked changes
+    revisions: docx::RevisionMode,
+    /// DOCX custom XML frontmatter
+    include_custom_xml: bool,
+    /// HWP: drop the table of contents section
+    no_toc: bool,
+    /// PDF: tesseract language for whole-page OCR
+    ocr_lang: Option<String>,
+    /// HWP: no `<span style="color">` around colored text
+    strip_color_spans: bool,
+    /// HWP: colored tables as HTML in MDX
+    preserve_table_colors: bool,
+    /// HWPX: paragraph spacing as `<p style>`
+    preserve_para_spacing: bool,
+}
+
+/// Contents of the `{stem}.hash` file next to HWP output: the document's
+/// content hash plus everything else that shapes the written files, so a
+/// re-run with other flags or after an upgrade converts again.
+fn output_fingerprint(hash: &[u8; 32], format: &str, config: &Config, options: &FormatOptions) -> String {
+    let hex: String = hash.iter().map(|b| format!("{:02x}", b)).collect();
+    format!(
+        "{}\nformat={}\nversion={}\nno_toc={}\nconfig={}\n",
+        hex,
+        format,
+        env!("CARGO_PKG_VERSION"),
+        options.no_toc,
+        serde_json::to_string(config).unwrap_or_default(),
+    )
+}
+
+/// Convert one input file into `output`.
+///
+/// Returns the written parts when `max_output_size` forced the MDX to be
+/// split (see [`write_multipart_with_config`]); `None` for regular single-file output.
+#[allow(clippy::too_many_arguments)]
+fn convert_file(
+    input: &Path,
+    output: &Path,
+    format: &str,
+    extract_images: bool,
+    verbose: bool,
+    ocr: bool,
+    max_output_size: Option<u64>,
+    pages: Option<std::ops::RangeInclusive<usize>>,
+    section_join: hwpx::SectionJoinMode,
+    options: FormatOptions,
+) -> Option<MultipartOutput> {
     println!("📄 Converting: {}", input.display());
 
+    let config = Config {
+        max_output_size_bytes: max_output_size,
+        strip_color_spans: options.strip_color_spans,
+        preserve_table_colors: options.preserve_table_colors,
+        preserve_para_spacing: options.preserve_para_spacing,
+        // Debugging aid: surface truncated HWP records instead of skipping them
+        strict_records: std::env::var_os("PARSER_STRICT").is_some(),
+        ..Default::default()
+    };
+
     let ext = input.extension().and_then(|e| e.to_str()).unwrap_or("");
 
     // Detect format by MAGIC BYTES first — many real files have a `.hwp`
@@ -875,8 +1321,11 @@ fn convert_file(input: &Path, output: &Path, format: &str, extract_images: bool,
 
     // PDF magic takes priority — some files have wrong extensions (e.g. .hwpx but actually PDF)
     if is_pdf {
-        convert_pdf(input, output, format, verbose, ocr);
-        return;
+        convert_pdf(input, output, format, verbose, ocr, pages, options.ocr_lang.as_deref());
+        return None;
+    }
+    if pages.is_some() && !ext.eq_ignore_ascii_case("pdf") {
+        eprintln!("  \u{26a0}\u{fe0f}  --pages only applies to PDF input; ignoring");
     }
 
     // ZIP-based formats: peek inside to distinguish DOCX vs HWPX vs EPUB
@@ -884,29 +1333,32 @@ fn convert_file(input: &Path, output: &Path, format: &str, extract_images: bool,
         // Check internal structure to determine actual format
         let actual = detect_zip_format(input);
         match actual.as_str() {
-            "docx" => { convert_docx(input, output, format, verbose); return; }
-            "hwpx" => { convert_hwpx(input, output, format, extract_images, verbose); return; }
-            "pptx" => { convert_pptx(input, output, format, verbose); return; }
-            "xlsx" => { convert_xlsx(input, output, format, verbose); return; }
-            "epub" => { convert_epub(input, output, format, verbose); return; }
+            "docx" => { convert_docx(input, output, format, verbose, options); return None; }
+            "hwpx" => { convert_hwpx(input, output, format, extract_images, verbose, section_join, &config); return None; }
+            "pptx" => { convert_pptx(input, output, format, verbose); return None; }
+            "xlsx" => { convert_xlsx(input, output, format, verbose); return None; }
+            "epub" => { convert_epub(input, output, format, verbose); return None; }
+            "odt" => { convert_odt(input, output, format, verbose); return None; }
             _ => {
                 // Fallback to extension for ZIP-based formats
     if ext.eq_ignore_ascii_case("doc") {
         convert_doc97(input, output, format, verbose);
-        return;
+        return None;
     }
     if ext.eq_ignore_ascii_case("docx") {
-                    convert_docx(input, output, format, verbose);
+                    convert_docx(input, output, format, verbose, options);
                 } else if ext.eq_ignore_ascii_case("pptx") {
                     convert_pptx(input, output, format, verbose);
                 } else if ext.eq_ignore_ascii_case("xlsx") || ext.eq_ignore_ascii_case("xls") {
                     convert_xlsx(input, output, format, verbose);
                 } else if ext.eq_ignore_ascii_case("epub") {
                     convert_epub(input, output, format, verbose);
+                } else if ext.eq_ignore_ascii_case("odt") {
+                    convert_odt(input, output, format, verbose);
                 } else {
-                    convert_hwpx(input, output, format, extract_images, verbose);
+                    convert_hwpx(input, output, format, extract_images, verbose, section_join, &config);
                 }
-                return;
+                return None;
             }
         }
     }
@@ -914,50 +1366,54 @@ fn convert_file(input: &Path, output: &Path, format: &str, extract_images: bool,
     // Extension-based fallback for non-magic-detected files
     if ext.eq_ignore_ascii_case("rtf") || magic.starts_with(b"{\\rtf") {
         convert_rtf(input, output, format, verbose);
-        return;
+        return None;
     }
     if ext.eq_ignore_ascii_case("docx") {
-        convert_docx(input, output, format, verbose);
-        return;
+        convert_docx(input, output, format, verbose, options);
+        return None;
     }
     if ext.eq_ignore_ascii_case("hwpx") {
-        convert_hwpx(input, output, format, extract_images, verbose);
-        return;
+        convert_hwpx(input, output, format, extract_images, verbose, section_join, &config);
+        return None;
     }
     if ext.eq_ignore_ascii_case("pdf") {
-        convert_pdf(input, output, format, verbose, ocr);
-        return;
+        convert_pdf(input, output, format, verbose, ocr, pages, options.ocr_lang.as_deref());
+        return None;
     }
     if ext.eq_ignore_ascii_case("xlsx") || ext.eq_ignore_ascii_case("xls") {
         convert_xlsx(input, output, format, verbose);
-        return;
+        return None;
     }
     if ext.eq_ignore_ascii_case("pptx") {
         convert_pptx(input, output, format, verbose);
-        return;
+        return None;
     }
     if ext.eq_ignore_ascii_case("epub") {
         convert_epub(input, output, format, verbose);
-        return;
+        return None;
+    }
+    if ext.eq_ignore_ascii_case("odt") {
+        convert_odt(input, output, format, verbose);
+        return None;
     }
     if ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm") || ext.eq_ignore_ascii_case("mhtml") {
         convert_html(input, output, format, verbose);
-        return;
+        return None;
     }
     if ext.eq_ignore_ascii_case("csv") || ext.eq_ignore_ascii_case("tsv") {
         convert_csv(input, output, format, verbose);
-        return;
+        return None;
     }
     if ext.eq_ignore_ascii_case("txt") || ext.eq_ignore_ascii_case("text") || ext.eq_ignore_ascii_case("log") {
         convert_txt(input, output, format, verbose);
-        return;
+        return None;
     }
     // Raw HWPML exports (.hml / .hwpml). Handled here in addition to the
     // `<?xml` magic-byte path below, so BOM-prefixed or oddly-encoded files that
     // miss the magic check still route to the HWPML parser by extension.
     if ext.eq_ignore_ascii_case("hml") || ext.eq_ignore_ascii_case("hwpml") {
         convert_hwpml(input, output, format, verbose);
-        return;
+        return None;
     }
 
     // Neither ZIP nor PDF nor CFB → unknown
@@ -969,7 +1425,7 @@ fn convert_file(input: &Path, output: &Path, format: &str, extract_images: bool,
             if let Ok(data) = std::fs::read(input) {
                 if xls::looks_like_xls(&data) {
                     convert_xls(input, output, format, verbose);
-                    return;
+                    return None;
                 }
             }
         }
@@ -978,11 +1434,11 @@ fn convert_file(input: &Path, output: &Path, format: &str, extract_images: bool,
         if let Ok(data) = std::fs::read(input) {
             if hwp3::is_hwp3(&data) {
                 convert_hwp3(input, output, format, verbose);
-                return;
+                return None;
             }
             if doc97::looks_like_doc(&data) {
                 convert_doc97(input, output, format, verbose);
-                return;
+                return None;
             }
         }
     }
@@ -995,32 +1451,58 @@ fn convert_file(input: &Path, output: &Path, format: &str, extract_images: bool,
         eprintln!("❌ This file is DRM-protected (Fasoo DRMONE).");
         eprintln!("   Open it in Hancom Office with a valid license to remove DRM,");
         eprintln!("   then re-export. Open-source parsers cannot read DRM-locked HWPs.");
-        return;
+        return None;
     }
 
     // Plain XML can be raw HWPML exports (often mislabeled as `.hwp`).
     // Handle those directly instead of pretending to be a CFB file.
     if magic.len() >= 5 && magic.starts_with(b"<?xml") {
         convert_hwpml(input, output, format, verbose);
-        return;
+        return None;
     }
 
-    match HwpParser::open(input) {
+    match HwpParser::open(input).map(|parser| parser.with_config(&config)) {
         Ok(mut parser) => {
             // Create output directory
             fs::create_dir_all(output).expect("Failed to create output directory");
 
             // Extract content
-            let mdm = match parser.to_mdm() {
+            let mut mdm = match parser.to_mdm() {
                 Ok(doc) => doc,
+                Err(e) if e.kind() == io::ErrorKind::Unsupported => {
+                    eprintln!("\u{1f512} {} is password-protected.", input.display());
+                    eprintln!("   Open it in Hancom Office, remove the document password (보안 > 문서 암호 해제), save, and convert again.");
+                    std::process::exit(2);
+                }
                 Err(e) => {
                     eprintln!("\u{274c} Error extracting content: {}", e);
-                    return;
+                    return None;
                 }
             };
+            if options.no_toc {
+                mdm.toc.clear();
+            }
+            for warning in parser.parse_warnings() {
+                eprintln!("  \u{26a0}\u{fe0f}  Skipped truncated record in {}", warning);
+            }
 
             let stem = input.file_stem().unwrap_or_default().to_string_lossy();
 
+            // Idempotent re-runs: same content hash, output settings and
+            // converter version + existing output → skip.
+            let hash_path = output.join(format!("{}.hash", stem));
+            let content_hash = output_fingerprint(&mdm.hash(), format, &config, &options);
+            let out_ext = match format {
+                "json" => "json",
+                "html" => "html",
+                _ => "mdx",
+            };
+            let previous = fs::read_to_string(&hash_path).unwrap_or_default();
+            if previous == content_hash && output.join(format!("{}.{}", stem, out_ext)).exists() {
+                println!("  \u{2713} Unchanged: {}", input.display());
+                return None;
+            }
+
             // Build ManifestV2
             let mut mv2 = ManifestV2::new(input, "hwp");
 
@@ -1060,6 +1542,7 @@ fn convert_file(input: &Path, output: &Path, format: &str, extract_images: bool,
             }
 
             // Save output based on format
+            let mut multipart: Option<MultipartOutput> = None;
             match format {
                 "json" => {
                     let json_path = output.join(format!("{}.json", stem));
@@ -1083,23 +1566,56 @@ fn convert_file(input: &Path, output: &Path, format: &str, extract_images: bool,
                             "format": i.format,
                             "size": i.data.len(),
                         })).collect::<Vec<_>>(),
+                        "revision_history": mdm.revision_history,
                     });
 
                     fs::write(&json_path, serde_json::to_string_pretty(&json_data).unwrap())
                         .expect("Failed to write JSON");
                     println!("  \u{2713} Created: {}", json_path.display());
                 }
+                "html" => {
+                    // Point image references at the hashed asset files
+                    let mut html_content = mdm.to_html();
+                    for (orig_name, hash_fn) in &image_map {
+                        html_content = html_content.replace(
+                            &format!("src=\"assets/{}\"", orig_name),
+                            &format!("src=\"assets/images/{}\"", hash_fn),
+                        );
+                    }
+                    let html_path = output.join(format!("{}.html", stem));
+                    fs::write(&html_path, &html_content).expect("Failed to write HTML");
+                    println!("  \u{2713} Created: {}", html_path.display());
+                }
                 _ => {
                     // Default: MDX format with @[[]] media references
-                    let mut mdx_content = mdm.to_mdx();
+                    let mut mdx_content = mdm.to_mdx_with_config(&config);
                     for (orig_name, _hash_fn) in &image_map {
                         let md_img = format!("![{}](assets/{})", orig_name, orig_name);
                         let replacement = format!("@[[{}]]", orig_name);
                         mdx_content = mdx_content.replace(&md_img, &replacement);
                     }
-                    let mdx_path = output.join(format!("{}.mdx", stem));
-                    fs::write(&mdx_path, &mdx_content).expect("Failed to write MDX");
-                    println!("  \u{2713} Created: {}", mdx_path.display());
+                    let split = match write_multipart_with_config(output, &stem, &mdx_content, &config) {
+                        Ok(parts) => parts,
+                        Err(e) => {
+                            eprintln!("  \u{26a0}\u{fe0f}  Multipart split failed ({}), writing single file", e);
+                            None
+                        }
+                    };
+                    match split {
+                        Some(parts) => {
+                            println!(
+                                "  \u{2713} Created: {} ({} parts)",
+                                parts.index.display(),
+                                parts.parts.len()
+                            );
+                            multipart = Some(parts);
+                        }
+                        None => {
+                            let mdx_path = output.join(format!("{}.mdx", stem));
+                            fs::write(&mdx_path, &mdx_content).expect("Failed to write MDX");
+                            println!("  \u{2713} Created: {}", mdx_path.display());
+                        }
+                    }
                 }
             }
 
@@ -1110,6 +1626,9 @@ fn convert_file(input: &Path, output: &Path, format: &str, extract_images: bool,
             if let Err(e) = save_manifest(&mv2, output, &stem) {
                 eprintln!("  \u{26a0}\u{fe0f}  Failed to write manifest: {}", e);
             }
+            if let Err(e) = fs::write(&hash_path, &content_hash) {
+                eprintln!("  \u{26a0}\u{fe0f}  Failed to write content hash: {}", e);
+            }
 
             if verbose {
                 println!("\n\u{1f4ca} Summary:");
@@ -1120,9 +1639,11 @@ fn convert_file(input: &Path, output: &Path, format: &str, extract_images: bool,
             }
 
             println!("\u{2705} Conversion complete!");
+            multipart
         }
         Err(e) => {
             eprintln!("\u{274c} Error opening file: {}", e);
+            None
         }
     }
 }
@@ -1316,8 +1837,16 @@ fn input_fallback_title(xml: &str) -> String {
     }
 }
 
-fn convert_hwpx(input: &Path, output: &Path, format: &str, _extract_images: bool, verbose: bool) {
-    match HwpxParser::open(input) {
+fn convert_hwpx(
+    input: &Path,
+    output: &Path,
+    format: &str,
+    _extract_images: bool,
+    verbose: bool,
+    section_join: hwpx::SectionJoinMode,
+    config: &Config,
+) {
+    match HwpxParser::open(input).map(|p| p.with_join_mode(section_join).with_config(config)) {
         Ok(mut parser) => {
             fs::create_dir_all(output).expect("Failed to create output directory");
 
@@ -1361,31 +1890,25 @@ fn convert_hwpx(input: &Path, output: &Path, format: &str, _extract_images: bool
 
                     // Use sections (with embedded tables) instead of preview text
                     let content = if doc.sections.iter().any(|s| !s.is_empty()) {
-                        doc.sections.join("\n\n---\n\n")
+                        doc.sections.join(doc.join_mode.separator())
                     } else if !doc.preview_text.is_empty() {
                         doc.preview_text.clone()
                     } else {
                         String::new()
                     };
+                    // `[이미지: id]` markers → `![caption](assets/images/<hash>.ext)`
+                    let content = doc.link_images(&content, |img| {
+                        image_map
+                            .iter()
+                            .find(|(id, _)| *id == img.id)
+                            .map(|(_, hash_filename)| format!("assets/images/{}", hash_filename))
+                            .unwrap_or_else(|| format!("assets/{}", img.id))
+                    });
 
                     match format {
                         "json" => {
                             let json_path = output.join(format!("{}.json", stem));
-                            let json_data = json!({
-                                "version": "1.0",
-                                "format": "hwpx",
-                                "metadata": {
-                                    "hwpx_version": doc.version,
-                                    "sections": doc.sections.len(),
-                                },
-                                "content": content,
-                                "images": doc.image_info.iter().map(|i| json!({
-                                    "id": i.id,
-                                    "path": i.path,
-                                    "mediaType": i.media_type,
-                                    "size": i.data.len(),
-                                })).collect::<Vec<_>>(),
-                            });
+                            let json_data = doc.to_structured_json();
 
                             fs::write(&json_path, serde_json::to_string_pretty(&json_data).unwrap())
                                 .expect("Failed to write JSON");
@@ -1395,11 +1918,19 @@ fn convert_hwpx(input: &Path, output: &Path, format: &str, _extract_images: bool
                             // MDX format with @[[]] image references
                             let mdx_path = output.join(format!("{}.mdx", stem));
 
+                            let mut styles = String::new();
+                            if !doc.para_spacing_css.is_empty() {
+                                styles.push_str("styles:\n");
+                                for rule in &doc.para_spacing_css {
+                                    styles.push_str(&format!("  - \"{}\"\n", rule));
+                                }
+                            }
                             let mdx_content = format!(
-                                "---\nformat: hwpx\nversion: \"{}\"\nsections: {}\nimages: {}\n---\n\n{}",
+                                "---\nformat: hwpx\nversion: \"{}\"\nsections: {}\nimages: {}\n{}---\n\n{}",
                                 doc.version,
                                 doc.sections.len(),
                                 doc.image_info.len(),
+                                styles,
                                 content
                             );
 
@@ -1426,6 +1957,11 @@ fn convert_hwpx(input: &Path, output: &Path, format: &str, _extract_images: bool
 
                     println!("\u{2705} Conversion complete!");
                 }
+                Err(hwpx::HwpxError::Encrypted) => {
+                    eprintln!("\u{1f512} {} is password-protected.", input.display());
+                    eprintln!("   Open it in Hancom Office, remove the document password (보안 > 문서 암호 해제), save, and convert again.");
+                    std::process::exit(2);
+                }
                 Err(e) => eprintln!("\u{274c} Error parsing HWPX: {}", e),
             }
         }
@@ -1433,8 +1969,16 @@ fn convert_hwpx(input: &Path, output: &Path, format: &str, _extract_images: bool
     }
 }
 
-fn convert_pdf(input: &Path, output: &Path, format: &str, verbose: bool, ocr: bool) {
-    if ocr && !ocr_available() {
+fn convert_pdf(
+    input: &Path,
+    output: &Path,
+    format: &str,
+    verbose: bool,
+    ocr: bool,
+    pages: Option<std::ops::RangeInclusive<usize>>,
+    ocr_lang: Option<&str>,
+) {
+    if ocr && ocr_lang.is_none() && !ocr_available() {
         eprintln!("  \u{26a0}\u{fe0f}  OCR requested but OCR engine not available. Build with `--features ocr`.");
         eprintln!("  \u{26a0}\u{fe0f}  Continuing with text-only extraction.");
     }
@@ -1443,9 +1987,20 @@ fn convert_pdf(input: &Path, output: &Path, format: &str, verbose: bool, ocr: bo
         Ok(parser) => {
             fs::create_dir_all(output).expect("Failed to create output directory");
 
-            match parser.parse() {
+            let parsed = match (&pages, ocr_lang) {
+                (_, Some(lang)) => {
+                    println!("  \u{1f50d} OCR with tesseract ({})...", lang);
+                    parser.ocr_all(lang)
+                }
+                (Some(range), None) => parser.parse_pages(range.clone()),
+                (None, None) => parser.parse(),
+            };
+            match parsed {
                 Ok(doc) => {
                     let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+                    if let Some(range) = &pages {
+                        println!("  \u{2713} Pages {}-{}", range.start(), range.end());
+                    }
 
                     // Build ManifestV2
                     let mut mv2 = ManifestV2::new(input, "pdf");
@@ -1493,6 +2048,7 @@ fn convert_pdf(input: &Path, output: &Path, format: &str, verbose: bool, ocr: bo
                                     "pages": doc.page_count,
                                     "title": doc.metadata.title,
                                     "author": doc.metadata.author,
+                                    "language": doc.detect_language(),
                                 },
                                 "content": doc.full_text(),
                                 "pages": doc.pages.iter().map(|p| json!({
@@ -1535,7 +2091,7 @@ fn convert_pdf(input: &Path, output: &Path, format: &str, verbose: bool, ocr: bo
                     }
 
                     // OCR: if enabled and available, run OCR on image-heavy pages
-                    let ocr_text = if ocr && ocr_available() {
+                    let ocr_text = if ocr && ocr_lang.is_none() && ocr_available() {
                         // `mut` is only exercised when an OCR path is compiled
                         // in (`ocr` fallback loop or `ocr-pdf` branch push); under
                         // default features nothing pushes, so allow unused_mut.
@@ -1631,16 +2187,38 @@ fn convert_pdf(input: &Path, output: &Path, format: &str, verbose: bool, ocr: bo
 
                     println!("\u{2705} Conversion complete!");
                 }
-                Err(e) => eprintln!("\u{274c} Error parsing PDF: {}", e),
+                Err(e) => report_pdf_error("Error parsing PDF", &e),
             }
         }
-        Err(e) => eprintln!("\u{274c} Error opening PDF file: {}", e),
+        Err(e) => report_pdf_error("Error opening PDF file", &e),
     }
 }
 
-fn convert_docx(input: &Path, output: &Path, format: &str, verbose: bool) {
+/// Print a PDF failure plus a hint for the failure modes the user can act on
+fn report_pdf_error(context: &str, err: &pdf::PdfError) {
+    eprintln!("\u{274c} {}: {}", context, err);
+    let hint = match err {
+        pdf::PdfError::EncryptedNoPassword | pdf::PdfError::InvalidPassword => {
+            "The PDF is encrypted; remove the password and convert again."
+        }
+        pdf::PdfError::InvalidStructure(_) | pdf::PdfError::ObjectNotFound(..) => {
+            "The file is damaged or not a PDF; re-export it from the source application."
+        }
+        pdf::PdfError::InvalidPageRange(_) => "Check --pages against the document's page count (`info`).",
+        pdf::PdfError::ParseError(msg) if msg == "tesseract not found" => {
+            "Install tesseract with the language data (e.g. tesseract-ocr-kor) or drop --ocr-lang."
+        }
+        _ => return,
+    };
+    eprintln!("   {}", hint);
+}
+
+fn convert_docx(input: &Path, output: &Path, format: &str, verbose: bool, options: FormatOptions) {
     match DocxParser::open(input) {
-        Ok(mut parser) => {
+        Ok(parser) => {
+            let mut parser = parser
+                .with_revision_mode(options.revisions)
+                .with_custom_xml(options.include_custom_xml);
             fs::create_dir_all(output).expect("Failed to create output directory");
 
             match parser.parse() {
@@ -1704,7 +2282,14 @@ fn convert_docx(input: &Path, output: &Path, format: &str, verbose: bool) {
                                     "subject": doc.metadata.subject,
                                     "pages": doc.metadata.page_count,
                                     "words": doc.metadata.word_count,
+                                    "tracked_changes": doc.has_tracked_changes,
                                 },
+                                "custom_xml": doc.custom_xml.iter().map(|part| json!({
+                                    "namespace": part.namespace,
+                                    "fields": part.fields().into_iter()
+                                        .map(|(k, v)| (k, json!(v)))
+                                        .collect::<serde_json::Map<_, _>>(),
+                                })).collect::<Vec<_>>(),
                                 "content": doc.to_markdown(),
                                 "tables": doc.tables.iter().map(|t| json!({
                                     "markdown": t.to_markdown(),
@@ -1856,11 +2441,13 @@ fn convert_pptx(input: &Path, output: &Path, format: &str, verbose: bool) {
                                 "format": "pptx",
                                 "metadata": {
                                     "slides": doc.metadata.slide_count,
+                                    "slide_width_emu": doc.metadata.slide_width,
+                                    "slide_height_emu": doc.metadata.slide_height,
                                 },
                                 "slides": doc.slides.iter().map(|s| json!({
                                     "number": s.number,
                                     "title": s.title,
-                                    "content": s.content,
+                                    "content": s.body_text,
                                     "notes": s.notes,
                                 })).collect::<Vec<_>>(),
                                 "content": doc.to_markdown(),
@@ -2084,18 +2671,22 @@ fn convert_txt(input: &Path, output: &Path, format: &str, verbose: bool) {
     }
 }
 
-fn analyze_file(input: &Path) {
+fn analyze_file(input: &Path, bookmarks: bool) {
     println!("🔍 Analyzing: {}", input.display());
 
     let ext = input.extension().and_then(|e| e.to_str()).unwrap_or("");
     if ext.eq_ignore_ascii_case("hwpx") {
-        analyze_hwpx(input);
+        analyze_hwpx(input, bookmarks);
         return;
     }
+    if bookmarks {
+        eprintln!("⚠️  --bookmarks is only supported for HWPX files");
+    }
 
     match HwpParser::open(input) {
-        Ok(parser) => {
-            let structure = parser.analyze();
+        Ok(mut parser) => {
+            let mut structure = parser.analyze();
+            structure.page_definitions = parser.page_definitions().to_vec();
             
             println!("\n📊 File Structure:");
             println!("  - Total streams: {}", structure.total_streams);
@@ -2103,8 +2694,30 @@ fn analyze_file(input: &Path) {
             println!("  - BinData items: {}", structure.bin_data_count);
             println!("  - Compressed: {}", if structure.compressed { "Yes" } else { "No" });
             println!("  - Encrypted: {}", if structure.encrypted { "Yes ⚠️" } else { "No" });
+
+            if !structure.page_definitions.is_empty() {
+                println!("\n📐 Page Setup:");
+                for (idx, page) in structure.page_definitions.iter().enumerate() {
+                    println!("  - Section {}: {}", idx, page_def_summary(page));
+                }
+            }
             
-            println!("\n📁 Streams:");
+            match parser.extract_styles_summary() {
+                Ok(styles) => {
+                    println!("\n🎨 Styles:");
+                    println!("  - Fonts: {}", styles.font_count);
+                    if !styles.font_names.is_empty() {
+                        println!("    {}", styles.font_names.join(", "));
+                    }
+                    println!("  - Character shapes: {} ({} bold, {} italic, {} underline)",
+                        styles.char_shape_count, styles.bold_count, styles.italic_count, styles.underline_count);
+                    println!("  - Emphasis combinations: {}", styles.emphasis_combinations);
+                    println!("  - Paragraph styles: {}", styles.paragraph_style_count);
+                }
+                Err(e) => eprintln!("⚠️  Could not read DocInfo styles: {}", e),
+            }
+
+            println!("\n📁 Streams:");
             for stream in &structure.streams {
                 println!("  {}", stream);
             }
@@ -2115,14 +2728,29 @@ fn analyze_file(input: &Path) {
     }
 }
 
-fn analyze_hwpx(input: &Path) {
+fn analyze_hwpx(input: &Path, bookmarks: bool) {
     match HwpxParser::open(input) {
-        Ok(parser) => {
+        Ok(mut parser) => {
             println!("\n📊 File Structure:");
             println!("  - Format: HWPX (ZIP-based XML)");
             println!("  - Sections: {}", parser.section_count());
             println!("  - Compressed: Yes (ZIP)");
             println!("  - Encrypted: {}", if parser.is_encrypted() { "Yes ⚠️" } else { "No" });
+
+            if bookmarks {
+                match parser.parse() {
+                    Ok(doc) => {
+                        println!("\n🔖 Bookmarks: {}", doc.bookmarks.len());
+                        for b in &doc.bookmarks {
+                            println!(
+                                "  section {} ¶{} +{}  {}",
+                                b.section_index, b.paragraph_index, b.char_offset, b.name
+                            );
+                        }
+                    }
+                    Err(e) => eprintln!("❌ Error: {}", e),
+                }
+            }
         }
         Err(e) => eprintln!("❌ Error: {}", e),
     }
@@ -2162,7 +2790,15 @@ fn extract_text(input: &Path) {
     }
 }
 
-fn extract_images(input: &Path, output: &Path) {
+/// `watermark` is `(text, position)` as given on the command line.
+fn extract_images(
+    input: &Path,
+    output: &Path,
+    thumbnails: bool,
+    strip_exif: bool,
+    watermark: Option<&(String, String)>,
+    srcset: Option<&[u32]>,
+) {
     println!("📷 Extracting images from: {}", input.display());
     
     match HwpParser::open(input) {
@@ -2170,12 +2806,18 @@ fn extract_images(input: &Path, output: &Path) {
             fs::create_dir_all(output).expect("Failed to create output directory");
             
             match parser.extract_images() {
-                Ok(images) => {
+                Ok(mut images) => {
                     if images.is_empty() {
                         println!("  No images found.");
                         return;
                     }
                     
+                    if strip_exif {
+                        strip_image_metadata(&mut images);
+                    }
+                    if let Some((text, position)) = watermark {
+                        watermark_images(&mut images, text, position);
+                    }
                     for img in &images {
                         let img_path = output.join(&img.name);
                         match fs::write(&img_path, &img.data) {
@@ -2183,6 +2825,13 @@ fn extract_images(input: &Path, output: &Path) {
                             Err(e) => println!("  ❌ {} - {}", img.name, e),
                         }
                     }
+
+                    if thumbnails {
+                        write_thumbnails(&images, &output.join("thumbnails"));
+                    }
+                    if let Some(widths) = srcset {
+                        write_srcsets(&images, &output.join("srcset"), widths);
+                    }
                     
                     println!("\n✅ Extracted {} images to {}", images.len(), output.display());
                 }
@@ -2195,41 +2844,389 @@ fn extract_images(input: &Path, output: &Path) {
     }
 }
 
-fn batch_convert(pattern: &str, output: &Path) {
-    println!("📦 Batch converting: {}", pattern);
-    
-    // Simple glob matching using walkdir
-    let base_dir = Path::new(".");
-    let mut count = 0;
-    let mut errors = 0;
-    
-    if let Ok(entries) = fs::read_dir(base_dir) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.extension().is_some_and(|e| e == "hwp") {
-                let file_name = path.file_name().unwrap_or_default().to_string_lossy();
-                
-                // Simple pattern matching
-                if pattern == "*.hwp" || file_name.contains(pattern.trim_start_matches('*').trim_end_matches("*.hwp")) {
-                    println!("\n  Processing: {}", path.display());
-                    
-                    if std::panic::catch_unwind(|| {
-                        convert_file(&path, output, "mdx", true, false, false);
-                    })
-                    .is_err()
-                    {
-                        errors += 1;
-                    } else {
-                        count += 1;
-                    }
+/// Drop APP1 (EXIF/XMP) segments from extracted JPEGs in place.
+/// Other formats and JPEGs that fail to parse are left as they are.
+fn strip_image_metadata(images: &mut [hwp::parser::ImageData]) {
+    #[cfg(feature = "image-processing")]
+    {
+        use mdm_core::optimizer::{ImageType, Optimizer};
+
+        for img in images.iter_mut() {
+            if ImageType::from_bytes(&img.data) != ImageType::Jpeg {
+                continue;
+            }
+            match Optimizer::strip_exif(&img.data) {
+                Ok(stripped) => img.data = stripped,
+                Err(e) => println!("  ⚠️ Kept metadata in {} - {}", img.name, e),
+            }
+        }
+    }
+    #[cfg(not(feature = "image-processing"))]
+    {
+        let _ = images;
+        eprintln!("⚠️  --strip-exif requires the `image-processing` feature.");
+    }
+}
+
+/// Draw `text` over each extracted image, keeping its format.
+/// Formats the decoder cannot read (WMF, OLE, ...) are left as they are.
+fn watermark_images(images: &mut [hwp::parser::ImageData], text: &str, position: &str) {
+    #[cfg(feature = "image-processing")]
+    {
+        use mdm_core::optimizer::{ImageType, OptimizeSettings, Optimizer, WatermarkConfig, WatermarkPosition};
+
+        let position: WatermarkPosition = match position.parse() {
+            Ok(position) => position,
+            Err(e) => {
+                eprintln!("❌ {}", e);
+                std::process::exit(1);
+            }
+        };
+        let optimizer = Optimizer::with_settings(OptimizeSettings {
+            watermark: Some(WatermarkConfig { position, ..WatermarkConfig::new(text) }),
+            ..OptimizeSettings::high_quality()
+        });
+
+        for img in images.iter_mut() {
+            let format = ImageType::from_bytes(&img.data);
+            if format == ImageType::Unknown {
+                println!("  ⚠️ Skipped watermark on {} - unsupported format", img.name);
+                continue;
+            }
+            match optimizer.optimize_to_format(&img.data, format) {
+                Ok(result) => img.data = result.data,
+                Err(e) => println!("  ⚠️ Skipped watermark on {} - {}", img.name, e),
+            }
+        }
+    }
+    #[cfg(not(feature = "image-processing"))]
+    {
+        let _ = (images, text, position);
+        eprintln!("⚠️  --watermark requires the `image-processing` feature.");
+    }
+}
+
+/// `--optimize-assets`: shrink every image below `dir` in place and report
+/// the savings. A missing `dir` (nothing was extracted) is not an error.
+fn optimize_assets_dir(dir: &Path) {
+    #[cfg(feature = "image-processing")]
+    {
+        use mdm_core::optimizer::{OptimizeSettings, Optimizer};
+
+        if !dir.is_dir() {
+            println!("🗜️  No assets to optimize in {}", dir.display());
+            return;
+        }
+        let report = match Optimizer::batch_optimize_dir(dir, &OptimizeSettings::balanced()) {
+            Ok(report) => report,
+            Err(e) => {
+                eprintln!("❌ Failed to optimize {}: {}", dir.display(), e);
+                std::process::exit(1);
+            }
+        };
+        for (path, error) in &report.errors {
+            println!("  ⚠️ {} - {} (restored)", path, error);
+        }
+        let original: u64 = report.processed.iter().map(|f| f.original_size).sum();
+        let percent = if original > 0 { report.total_saved_bytes as f64 * 100.0 / original as f64 } else { 0.0 };
+        println!(
+            "🗜️  Optimized {} image(s), saved {} bytes ({:.1}%); {} skipped, {} failed",
+            report.processed.len(),
+            report.total_saved_bytes,
+            percent,
+            report.skipped,
+            report.errors.len()
+        );
+    }
+    #[cfg(not(feature = "image-processing"))]
+    {
+        let _ = dir;
+        eprintln!("⚠️  --optimize-assets requires the `image-processing` feature.");
+    }
+}
+
+/// Write a 64/128/256px preview set for each extracted image.
+/// Images the decoder cannot read (WMF, OLE, ...) are skipped.
+fn write_thumbnails(images: &[hwp::parser::ImageData], dir: &Path) {
+    #[cfg(feature = "image-processing")]
+    {
+        use mdm_core::optimizer::{Optimizer, THUMBNAIL_SIZES};
+
+        fs::create_dir_all(dir).expect("Failed to create thumbnail directory");
+        let mut written = 0;
+        for img in images {
+            let set = match Optimizer::generate_thumbnail_set(&img.data) {
+                Ok(set) => set,
+                Err(e) => {
+                    println!("  ⚠️ No thumbnail for {} - {}", img.name, e);
+                    continue;
+                }
+            };
+            let stem = Path::new(&img.name).file_stem().unwrap_or_default().to_string_lossy();
+            for (size, data) in THUMBNAIL_SIZES.iter().zip([&set.small, &set.medium, &set.large]) {
+                let path = dir.join(format!("{}_{}.{}", stem, size, set.format.extension()));
+                if let Err(e) = fs::write(&path, data) {
+                    println!("  ❌ {} - {}", path.display(), e);
                 }
             }
+            written += 1;
         }
+        println!("🖼️  Thumbnails for {} image(s) in {}", written, dir.display());
     }
-    
+    #[cfg(not(feature = "image-processing"))]
+    {
+        let _ = (images, dir);
+        eprintln!("⚠️  Thumbnails require the `image-processing` feature.");
+    }
+}
+
+/// `--srcset 400,800` → `[400, 800]`; exits on anything but positive integers
+fn parse_srcset_widths(widths: &str) -> Vec<u32> {
+    let parsed: Result<Vec<u32>, _> = widths.split(',').map(|w| w.trim().parse::<u32>()).collect();
+    match parsed {
+        Ok(widths) if !widths.is_empty() && !widths.contains(&0) => widths,
+        _ => {
+            eprintln!("❌ --srcset expects comma-separated pixel widths, e.g. 400,800,1200");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Write responsive variants of each extracted image and print the
+/// `srcset` attribute value pointing at them.
+/// Images the decoder cannot read (WMF, OLE, ...) are skipped.
+fn write_srcsets(images: &[hwp::parser::ImageData], dir: &Path, widths: &[u32]) {
+    #[cfg(feature = "image-processing")]
+    {
+        use mdm_core::optimizer::Optimizer;
+
+        fs::create_dir_all(dir).expect("Failed to create srcset directory");
+        let optimizer = Optimizer::new();
+        let mut written = 0;
+        for img in images {
+            let set = match optimizer.generate_srcset(&img.data, widths) {
+                Ok(set) => set,
+                Err(e) => {
+                    println!("  ⚠️ No srcset for {} - {}", img.name, e);
+                    continue;
+                }
+            };
+            let stem = Path::new(&img.name).file_stem().unwrap_or_default().to_string_lossy();
+            for variant in &set.variants {
+                let path = dir.join(variant.path(&stem));
+                if let Err(e) = fs::write(&path, &variant.data) {
+                    println!("  ❌ {} - {}", path.display(), e);
+                }
+            }
+            // Relative to the image output directory
+            println!("  {}: srcset=\"{}\"", img.name, set.to_html_attr(&format!("srcset/{}", stem)));
+            written += 1;
+        }
+        println!("🖼️  Srcsets for {} image(s) in {}", written, dir.display());
+    }
+    #[cfg(not(feature = "image-processing"))]
+    {
+        let _ = (images, dir, widths);
+        eprintln!("⚠️  --srcset requires the `image-processing` feature.");
+    }
+}
+
+fn batch_convert(
+    pattern: &str,
+    output: &Path,
+    dry_run: bool,
+    parallel: bool,
+    jobs: Option<usize>,
+    dedup: bool,
+    warm_cache: Option<&Path>,
+) {
+    println!("📦 Batch converting: {}", pattern);
+
+    let inputs = match batch::collect_inputs(pattern) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("❌ Invalid pattern '{}': {}", pattern, e);
+            std::process::exit(1);
+        }
+    };
+    if inputs.is_empty() {
+        println!("No files matched: {}", pattern);
+        return;
+    }
+
+    // Mirror the source tree below the pattern's literal base directory
+    let base = batch::glob_base(pattern);
+    let total = inputs.len();
+
+    if dry_run {
+        for (n, path) in inputs.iter().enumerate() {
+            let out_dir = batch::mirrored_output_dir(path, &base, output);
+            println!("[{}/{}] {} -> {}", n + 1, total, path.display(), out_dir.display());
+        }
+        println!("\n🔍 Dry run: {} file(s) matched, nothing converted", total);
+        return;
+    }
+
+    let section_cache = warm_cache.map(|dir| {
+        let cache: hwp::SectionCache = match cache::Cache::new(dir, std::time::Duration::from_secs(7 * 24 * 3600)) {
+            Ok(cache) => cache,
+            Err(e) => {
+                eprintln!("❌ Cannot open cache directory {}: {}", dir.display(), e);
+                std::process::exit(1);
+            }
+        };
+        match cache.preload_dir(dir) {
+            Ok(n) => println!("🔥 Warmed {} cache entries from {}", n, dir.display()),
+            Err(e) => eprintln!("⚠️  Could not preload {}: {}", dir.display(), e),
+        }
+        std::sync::Arc::new(cache)
+    });
+
+    let inputs = if dedup { skip_duplicate_hwp(inputs, section_cache.as_ref()) } else { inputs };
+    let (count, errors) = if parallel {
+        batch_convert_parallel(&inputs, &base, output, jobs)
+    } else {
+        batch_convert_sequential(&inputs, &base, output)
+    };
     println!("\n📊 Batch complete: {} converted, {} errors", count, errors);
 }
 
+/// Drop HWP inputs whose [`HwpParser::content_fingerprint`] matches an
+/// earlier input. Other formats, and HWP files that cannot be read, are
+/// kept so the conversion reports them as usual. With `section_cache`,
+/// first sections are read through it.
+fn skip_duplicate_hwp(inputs: Vec<PathBuf>, section_cache: Option<&std::sync::Arc<hwp::SectionCache>>) -> Vec<PathBuf> {
+    let mut seen: HashMap<String, PathBuf> = HashMap::new();
+    inputs
+        .into_iter()
+        .filter(|path| {
+            let is_hwp = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| e.eq_ignore_ascii_case("hwp"));
+            if !is_hwp {
+                return true;
+            }
+            let fingerprint = match section_cache {
+                Some(cache) => hwp::CachedHwpParser::open(path, std::sync::Arc::clone(cache))
+                    .and_then(|mut p| p.content_fingerprint()),
+                None => HwpParser::open(path).and_then(|mut p| p.content_fingerprint()),
+            };
+            let Ok(fingerprint) = fingerprint else {
+                return true;
+            };
+            match seen.get(&fingerprint) {
+                Some(original) => {
+                    println!(
+                        "⚠️ Skipping duplicate: {} (same as {})",
+                        path.file_name().unwrap_or_default().to_string_lossy(),
+                        original.file_name().unwrap_or_default().to_string_lossy()
+                    );
+                    false
+                }
+                None => {
+                    seen.insert(fingerprint, path.clone());
+                    true
+                }
+            }
+        })
+        .collect()
+}
+
+/// Convert one batch input; `false` when the converter panicked
+fn batch_convert_one(path: &Path, base: &Path, output: &Path) -> bool {
+    let out_dir = batch::mirrored_output_dir(path, base, output);
+    std::panic::catch_unwind(|| {
+        convert_file(path, &out_dir, "mdx", true, false, false, None, None, Default::default(), Default::default());
+    })
+    .is_ok()
+}
+
+/// Returns `(converted, errors)`
+fn batch_convert_sequential(inputs: &[PathBuf], base: &Path, output: &Path) -> (usize, usize) {
+    let total = inputs.len();
+    let mut count = 0;
+    let mut errors = 0;
+    for (n, path) in inputs.iter().enumerate() {
+        println!("\n[{}/{}] Converting: {}", n + 1, total, path.display());
+        if batch_convert_one(path, base, output) {
+            count += 1;
+        } else {
+            errors += 1;
+        }
+    }
+    (count, errors)
+}
+
+/// Progress shared between `--parallel` workers
+#[cfg(feature = "parallel")]
+#[derive(Default)]
+struct ProgressState {
+    started: usize,
+    converted: usize,
+    errors: usize,
+}
+
+/// Convert `inputs` on a rayon pool (`jobs` threads, default one per CPU).
+///
+/// Every file gets its own parser inside `convert_file` — parsers are not
+/// `Send`, so nothing is shared but the progress counters. The lock also
+/// keeps `[N/M]` lines from interleaving. Returns `(converted, errors)`.
+#[cfg(feature = "parallel")]
+fn batch_convert_parallel(
+    inputs: &[PathBuf],
+    base: &Path,
+    output: &Path,
+    jobs: Option<usize>,
+) -> (usize, usize) {
+    use rayon::prelude::*;
+    use std::sync::Mutex;
+
+    let total = inputs.len();
+    let progress = Mutex::new(ProgressState::default());
+    let run = || {
+        inputs.par_iter().for_each(|path| {
+            {
+                let mut state = progress.lock().unwrap_or_else(|e| e.into_inner());
+                state.started += 1;
+                println!("\n[{}/{}] Converting: {}", state.started, total, path.display());
+            }
+            let ok = batch_convert_one(path, base, output);
+            let mut state = progress.lock().unwrap_or_else(|e| e.into_inner());
+            if ok {
+                state.converted += 1;
+            } else {
+                state.errors += 1;
+            }
+        })
+    };
+
+    match jobs {
+        Some(n) => match rayon::ThreadPoolBuilder::new().num_threads(n).build() {
+            Ok(pool) => pool.install(run),
+            Err(e) => {
+                eprintln!("  \u{26a0}\u{fe0f}  Could not start {} workers ({}); using the global pool", n, e);
+                run()
+            }
+        },
+        None => run(),
+    }
+
+    let state = progress.into_inner().unwrap_or_else(|e| e.into_inner());
+    (state.converted, state.errors)
+}
+
+#[cfg(not(feature = "parallel"))]
+fn batch_convert_parallel(
+    inputs: &[PathBuf],
+    base: &Path,
+    output: &Path,
+    _jobs: Option<usize>,
+) -> (usize, usize) {
+    eprintln!("  \u{26a0}\u{fe0f}  Built without the `parallel` feature; converting sequentially");
+    batch_convert_sequential(inputs, base, output)
+}
+
 fn show_info(input: &Path, format: &str) {
     let ext = input.extension().and_then(|e| e.to_str()).unwrap_or("");
     
@@ -2250,14 +3247,19 @@ fn show_info(input: &Path, format: &str) {
         "hwpx" => show_hwpx_info(input, format, &file_size_str),
         "pdf" => show_pdf_info(input, format, &file_size_str),
         "docx" => show_docx_info(input, format, &file_size_str),
+        "rtf" => show_rtf_info(input, format, &file_size_str),
+        "odt" => show_odt_info(input, format, &file_size_str),
+        "pptx" => show_pptx_info(input, format, &file_size_str),
         _ => show_hwp_info(input, format, &file_size_str),
     }
 }
 
 fn show_hwp_info(input: &Path, format: &str, file_size: &str) {
     match HwpParser::open(input) {
-        Ok(parser) => {
-            let structure = parser.analyze();
+        Ok(mut parser) => {
+            let mut structure = parser.analyze();
+            structure.page_count = parser.page_count().ok();
+            structure.page_definitions = parser.page_definitions().to_vec();
             
             if format == "json" {
                 let info = json!({
@@ -2269,14 +3271,46 @@ fn show_hwp_info(input: &Path, format: &str, file_size: &str) {
                     },
                     "document": {
                         "sections": structure.section_count,
+                        "pages": structure.page_count,
                         "streams": structure.total_streams,
                         "bin_data_count": structure.bin_data_count,
                         "compressed": structure.compressed,
                         "encrypted": structure.encrypted,
                     },
+                    "page_setup": structure.page_definitions.iter().map(page_def_json).collect::<Vec<_>>(),
                     "streams": structure.streams,
                 });
                 println!("{}", serde_json::to_string_pretty(&info).unwrap());
+            } else if format == "html" {
+                let escape = |s: &str| s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+                let pages = structure.page_count.map(|p| format!("~{}", p)).unwrap_or_else(|| "-".to_string());
+                let rows = [
+                    ("Name", input.file_name().unwrap_or_default().to_string_lossy().into_owned()),
+                    ("Path", input.display().to_string()),
+                    ("Size", file_size.to_string()),
+                    ("Format", "HWP (OLE Compound Document)".to_string()),
+                    ("Sections", structure.section_count.to_string()),
+                    ("Pages", pages),
+                    ("Streams", structure.total_streams.to_string()),
+                    ("BinData", structure.bin_data_count.to_string()),
+                    ("Compressed", if structure.compressed { "Yes" } else { "No" }.to_string()),
+                    ("Encrypted", if structure.encrypted { "Yes" } else { "No" }.to_string()),
+                ];
+                println!("<article>");
+                println!("<table>");
+                for (label, value) in &rows {
+                    println!("<tr><th>{}</th><td>{}</td></tr>", label, escape(value));
+                }
+                for (idx, page) in structure.page_definitions.iter().enumerate() {
+                    println!("<tr><th>Page setup {}</th><td>{}</td></tr>", idx, escape(&page_def_summary(page)));
+                }
+                println!("</table>");
+                println!("<ul>");
+                for stream in &structure.streams {
+                    println!("<li>{}</li>", escape(stream));
+                }
+                println!("</ul>");
+                println!("</article>");
             } else {
                 println!("📄 File Information");
                 println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
@@ -2288,10 +3322,21 @@ fn show_hwp_info(input: &Path, format: &str, file_size: &str) {
                 println!("📊 Document Structure");
                 println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
                 println!("  Sections:     {}", structure.section_count);
+                if let Some(pages) = structure.page_count {
+                    println!("  Pages:        ~{} (section 1)", pages);
+                }
                 println!("  Streams:      {}", structure.total_streams);
                 println!("  BinData:      {} items", structure.bin_data_count);
                 println!("  Compressed:   {}", if structure.compressed { "Yes" } else { "No" });
                 println!("  Encrypted:    {}", if structure.encrypted { "Yes ⚠️" } else { "No" });
+                if !structure.page_definitions.is_empty() {
+                    println!();
+                    println!("📐 Page Setup");
+                    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+                    for (idx, page) in structure.page_definitions.iter().enumerate() {
+                        println!("  Section {}:    {}", idx, page_def_summary(page));
+                    }
+                }
                 println!();
                 println!("📁 Streams ({}):", structure.streams.len());
                 println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
@@ -2304,6 +3349,42 @@ fn show_hwp_info(input: &Path, format: &str, file_size: &str) {
     }
 }
 
+/// `210.0 × 297.0 mm portrait, margins L 30.0 R 30.0 T 20.0 B 15.0 mm ...`
+fn page_def_summary(page: &hwp::PageDef) -> String {
+    let mm = hwp::hwpunit_to_mm;
+    format!(
+        "{:.1} × {:.1} mm {}, margins L {:.1} R {:.1} T {:.1} B {:.1} mm (header {:.1}, footer {:.1}, gutter {:.1})",
+        page.paper_width_mm(),
+        page.paper_height_mm(),
+        if page.orientation() == hwp::PageOrientation::Landscape { "landscape" } else { "portrait" },
+        mm(page.margin_left),
+        mm(page.margin_right),
+        mm(page.margin_top),
+        mm(page.margin_bottom),
+        mm(page.margin_header),
+        mm(page.margin_footer),
+        mm(page.margin_gutter),
+    )
+}
+
+/// Page setup in millimetres for `info --format json`
+fn page_def_json(page: &hwp::PageDef) -> serde_json::Value {
+    let mm = |v: u32| (hwp::hwpunit_to_mm(v) * 10.0).round() / 10.0;
+    let (width, height) = page.paper_size();
+    json!({
+        "paper_width_mm": mm(width),
+        "paper_height_mm": mm(height),
+        "margin_left_mm": mm(page.margin_left),
+        "margin_right_mm": mm(page.margin_right),
+        "margin_top_mm": mm(page.margin_top),
+        "margin_bottom_mm": mm(page.margin_bottom),
+        "margin_header_mm": mm(page.margin_header),
+        "margin_footer_mm": mm(page.margin_footer),
+        "margin_gutter_mm": mm(page.margin_gutter),
+        "orientation": page.orientation(),
+    })
+}
+
 fn show_hwpx_info(input: &Path, format: &str, file_size: &str) {
     match HwpxParser::open(input) {
         Ok(parser) => {
@@ -2367,6 +3448,7 @@ fn show_docx_info(input: &Path, format: &str, file_size: &str) {
                                 "tables": doc.tables.len(),
                                 "images": doc.images.len(),
                             },
+                            "structured_tags": doc.structured_tags,
                         });
                         println!("{}", serde_json::to_string_pretty(&info).unwrap());
                     } else {
@@ -2394,6 +3476,14 @@ fn show_docx_info(input: &Path, format: &str, file_size: &str) {
                         println!("  Paragraphs:  {}", doc.paragraphs.len());
                         println!("  Tables:       {}", doc.tables.len());
                         println!("  Images:       {}", doc.images.len());
+                        if !doc.structured_tags.is_empty() {
+                            println!();
+                            println!("🏷️  Content Controls ({}):", doc.structured_tags.len());
+                            println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+                            for field in &doc.structured_tags {
+                                println!("  • {}: {}", field.name, field.value.replace('\n', " / "));
+                            }
+                        }
                     }
                 }
                 Err(e) => eprintln!("❌ Error parsing DOCX: {}", e),
@@ -2403,6 +3493,169 @@ fn show_docx_info(input: &Path, format: &str, file_size: &str) {
     }
 }
 
+#[cfg(feature = "rtf")]
+fn show_rtf_info(input: &Path, format: &str, file_size: &str) {
+    let doc = match rtf::RtfParser::open(input).and_then(|p| p.parse()) {
+        Ok(doc) => doc,
+        Err(e) => {
+            eprintln!("❌ Error: {}", e);
+            return;
+        }
+    };
+    let bold_runs = doc.paragraphs.iter().flat_map(|p| &p.runs).filter(|r| r.bold).count();
+    let italic_runs = doc.paragraphs.iter().flat_map(|p| &p.runs).filter(|r| r.italic).count();
+
+    if format == "json" {
+        let info = json!({
+            "file": {
+                "name": input.file_name().unwrap_or_default().to_string_lossy(),
+                "path": input.display().to_string(),
+                "size": file_size,
+                "format": "rtf",
+            },
+            "document": {
+                "paragraphs": doc.paragraphs.len(),
+                "images": doc.images.len(),
+                "characters": doc.text.chars().count(),
+                "bold_runs": bold_runs,
+                "italic_runs": italic_runs,
+            },
+        });
+        println!("{}", serde_json::to_string_pretty(&info).unwrap());
+    } else {
+        println!("📄 File Information");
+        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        println!("  Name:       {}", input.file_name().unwrap_or_default().to_string_lossy());
+        println!("  Path:       {}", input.display());
+        println!("  Size:       {}", file_size);
+        println!("  Format:     RTF (Rich Text Format)");
+        println!();
+        println!("📊 Document Structure");
+        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        println!("  Paragraphs:   {}", doc.paragraphs.len());
+        println!("  Images:       {}", doc.images.len());
+        println!("  Characters:   {}", doc.text.chars().count());
+        println!("  Bold runs:    {}", bold_runs);
+        println!("  Italic runs:  {}", italic_runs);
+    }
+}
+
+#[cfg(not(feature = "rtf"))]
+fn show_rtf_info(_input: &Path, _format: &str, _file_size: &str) {
+    eprintln!("\u{274c} RTF support disabled. Enable the 'rtf' feature in Cargo.toml.");
+}
+
+#[cfg(feature = "odt")]
+fn show_odt_info(input: &Path, format: &str, file_size: &str) {
+    let doc = match odt::OdtParser::open(input).and_then(|mut p| p.parse()) {
+        Ok(doc) => doc,
+        Err(e) => {
+            eprintln!("❌ Error: {}", e);
+            return;
+        }
+    };
+
+    if format == "json" {
+        let info = json!({
+            "file": {
+                "name": input.file_name().unwrap_or_default().to_string_lossy(),
+                "path": input.display().to_string(),
+                "size": file_size,
+                "format": "odt",
+            },
+            "document": {
+                "title": doc.metadata.title,
+                "author": doc.metadata.author,
+                "subject": doc.metadata.subject,
+                "pages": doc.metadata.page_count,
+                "words": doc.metadata.word_count,
+                "paragraphs": doc.paragraphs.len(),
+                "tables": doc.tables.len(),
+                "images": doc.images.len(),
+            },
+        });
+        println!("{}", serde_json::to_string_pretty(&info).unwrap());
+    } else {
+        println!("📄 File Information");
+        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        println!("  Name:       {}", input.file_name().unwrap_or_default().to_string_lossy());
+        println!("  Path:       {}", input.display());
+        println!("  Size:       {}", file_size);
+        println!("  Format:     ODT (OpenDocument Text)");
+        println!();
+        println!("📊 Document Properties");
+        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        if let Some(ref title) = doc.metadata.title {
+            println!("  Title:        {}", title);
+        }
+        if let Some(ref author) = doc.metadata.author {
+            println!("  Author:       {}", author);
+        }
+        if let Some(pages) = doc.metadata.page_count {
+            println!("  Pages:        {}", pages);
+        }
+        if let Some(words) = doc.metadata.word_count {
+            println!("  Words:        {}", words);
+        }
+        println!("  Paragraphs:   {}", doc.paragraphs.len());
+        println!("  Tables:       {}", doc.tables.len());
+        println!("  Images:       {}", doc.images.len());
+    }
+}
+
+#[cfg(not(feature = "odt"))]
+fn show_odt_info(_input: &Path, _format: &str, _file_size: &str) {
+    eprintln!("\u{274c} ODT support disabled. Enable the 'odt' feature in Cargo.toml.");
+}
+
+fn show_pptx_info(input: &Path, format: &str, file_size: &str) {
+    let doc = match PptxParser::open(input).and_then(|p| p.parse()) {
+        Ok(doc) => doc,
+        Err(e) => {
+            eprintln!("❌ Error: {}", e);
+            return;
+        }
+    };
+    let notes = doc.slides.iter().filter(|s| s.notes.is_some()).count();
+
+    if format == "json" {
+        let info = json!({
+            "file": {
+                "name": input.file_name().unwrap_or_default().to_string_lossy(),
+                "path": input.display().to_string(),
+                "size": file_size,
+                "format": "pptx",
+            },
+            "document": {
+                "slides": doc.metadata.slide_count,
+                "slide_width_emu": doc.metadata.slide_width,
+                "slide_height_emu": doc.metadata.slide_height,
+                "slides_with_notes": notes,
+                "titles": doc.slides.iter().map(|s| s.title.as_deref()).collect::<Vec<_>>(),
+            },
+        });
+        println!("{}", serde_json::to_string_pretty(&info).unwrap());
+    } else {
+        println!("📄 File Information");
+        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        println!("  Name:       {}", input.file_name().unwrap_or_default().to_string_lossy());
+        println!("  Path:       {}", input.display());
+        println!("  Size:       {}", file_size);
+        println!("  Format:     PPTX (PowerPoint)");
+        println!();
+        println!("📊 Presentation Properties");
+        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        println!("  Slides:       {}", doc.metadata.slide_count);
+        if let Some((width, height)) = doc.metadata.slide_size_pt() {
+            println!("  Slide size:   {} x {} pt", width, height);
+        }
+        println!("  With notes:   {}", notes);
+        for slide in &doc.slides {
+            println!("    - Slide {}: {}", slide.number, slide.title.as_deref().unwrap_or("(untitled)"));
+        }
+    }
+}
+
 fn show_pdf_info(input: &Path, format: &str, file_size: &str) {
     match PdfParser::open(input) {
         Ok(parser) => {
@@ -2423,7 +3676,17 @@ fn show_pdf_info(input: &Path, format: &str, file_size: &str) {
                                 "author": doc.metadata.author,
                                 "creator": doc.metadata.creator,
                                 "producer": doc.metadata.producer,
+                                "language": doc.detect_language(),
+                                "word_count": doc.word_count(),
+                                "reading_time_minutes": doc.reading_time_minutes(),
+                                "all_fonts_embedded": doc.has_all_fonts_embedded(),
+                                "color_mode": doc.color_mode,
                             },
+                            "fonts": doc.fonts.iter().map(|f| json!({
+                                "name": f.base_font,
+                                "embedded": f.is_embedded,
+                                "encoding": f.encoding,
+                            })).collect::<Vec<_>>(),
                         });
                         println!("{}", serde_json::to_string_pretty(&info).unwrap());
                     } else {
@@ -2438,6 +3701,8 @@ fn show_pdf_info(input: &Path, format: &str, file_size: &str) {
                         println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
                         println!("  PDF Version:  {}", doc.version);
                         println!("  Pages:        {}", doc.page_count);
+                        println!("  Words:        {} (~{:.1} min read)", doc.word_count(), doc.reading_time_minutes());
+                        println!("  Color Mode:   {:?}", doc.color_mode);
                         if !doc.metadata.title.is_empty() {
                             println!("  Title:        {}", doc.metadata.title);
                         }
@@ -2452,10 +3717,141 @@ fn show_pdf_info(input: &Path, format: &str, file_size: &str) {
                         }
                     }
                 }
-                Err(e) => eprintln!("❌ Error parsing PDF: {}", e),
+                Err(e) => report_pdf_error("Error parsing PDF", &e),
             }
         }
-        Err(e) => eprintln!("❌ Error: {}", e),
+        Err(e) => report_pdf_error("Error", &e),
+    }
+}
+
+/// `info --check-cmyk` — exit 0 if the PDF has CMYK content, 1 if not
+fn check_pdf_cmyk(input: &Path) {
+    if !input.extension().and_then(|e| e.to_str()).is_some_and(|e| e.eq_ignore_ascii_case("pdf")) {
+        eprintln!("❌ --check-cmyk requires a PDF input");
+        std::process::exit(1);
+    }
+    let parser = match PdfParser::open(input) {
+        Ok(parser) => parser,
+        Err(e) => {
+            report_pdf_error("Error", &e);
+            std::process::exit(1);
+        }
+    };
+    if parser.has_cmyk_content() {
+        println!("✅ CMYK content found in {} ({:?})", input.display(), parser.detect_color_mode());
+    } else {
+        println!("❌ No CMYK content in {} ({:?})", input.display(), parser.detect_color_mode());
+        std::process::exit(1);
+    }
+}
+
+/// `merge` — concatenate DOCX files into one MDX with page breaks between them
+fn merge_docx_files(inputs: &[PathBuf], output: &Path) {
+    let mut parsers = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        match DocxParser::open(input) {
+            Ok(parser) => parsers.push(parser),
+            Err(e) => { eprintln!("❌ Failed to open {}: {}", input.display(), e); std::process::exit(1); }
+        }
+    }
+    let doc = match DocxParser::merge(parsers, Some(docx::Paragraph::page_break())) {
+        Ok(doc) => doc,
+        Err(e) => { eprintln!("❌ Merge failed: {}", e); std::process::exit(1); }
+    };
+
+    let out_dir = output.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    fs::create_dir_all(out_dir).expect("Failed to create output directory");
+    let assets_dir = out_dir.join("assets");
+    for image in &doc.images {
+        if let Some(ref data) = image.data {
+            fs::create_dir_all(&assets_dir).expect("Failed to create assets directory");
+            if let Err(e) = fs::write(assets_dir.join(&image.filename), data) {
+                eprintln!("  \u{26a0}\u{fe0f}  Failed to save {}: {}", image.filename, e);
+            }
+        }
+    }
+
+    let source_name = output.file_name().unwrap_or_default().to_string_lossy();
+    fs::write(output, doc.to_mdx(&source_name)).expect("Failed to write MDX");
+    println!("  \u{2713} Created: {}", output.display());
+    println!("\u{2705} Merged {} documents.", inputs.len());
+}
+
+/// `merge-pdf` — concatenate PDFs into one MDX via `PdfParser::merge`
+fn merge_pdf_files(inputs: &[PathBuf], output: &Path) {
+    let mut parsers = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        match PdfParser::open(input) {
+            Ok(parser) => parsers.push(parser),
+            Err(e) => { eprintln!("❌ Failed to open {}: {}", input.display(), e); std::process::exit(1); }
+        }
+    }
+    let doc = match PdfParser::merge(parsers) {
+        Ok(doc) => doc,
+        Err(e) => { eprintln!("❌ Merge failed: {}", e); std::process::exit(1); }
+    };
+
+    if let Some(out_dir) = output.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(out_dir).expect("Failed to create output directory");
+    }
+    fs::write(output, doc.to_mdx()).expect("Failed to write MDX");
+    println!("  \u{2713} Created: {}", output.display());
+    println!("\u{2705} Merged {} documents ({} pages).", inputs.len(), doc.page_count);
+}
+
+/// `find` — list section / paragraph positions of `query` in an HWP file
+fn find_in_file(input: &Path, query: &str, max_results: Option<usize>) {
+    let mut parser = match HwpParser::open(input) {
+        Ok(p) => p,
+        Err(e) => { eprintln!("❌ Error: {}", e); std::process::exit(1); }
+    };
+    match parser.find_text(query, max_results) {
+        Ok(matches) => {
+            for m in &matches {
+                println!(
+                    "  Section {}, paragraph {}, byte {}: …{}…",
+                    m.section, m.paragraph_index, m.byte_offset, m.context
+                );
+            }
+            println!("🔎 {} match(es) for \"{}\"", matches.len(), query);
+        }
+        Err(e) => { eprintln!("❌ Search failed: {}", e); std::process::exit(1); }
+    }
+}
+
+/// `check-fonts` — exit 1 unless every PDF font is embedded
+fn check_fonts(input: &Path) {
+    let parser = match PdfParser::open(input) {
+        Ok(p) => p,
+        Err(e) => { eprintln!("❌ Error: {}", e); std::process::exit(1); }
+    };
+    let fonts = parser.extract_fonts();
+    for font in &fonts {
+        let encoding = if font.encoding.is_empty() { "built-in" } else { font.encoding.as_str() };
+        let status = if font.is_embedded { "✓ embedded    " } else { "✗ not embedded" };
+        println!("  {}  {} ({})", status, font.base_font, encoding);
+    }
+    let missing = fonts.iter().filter(|f| !f.is_embedded).count();
+    if missing > 0 {
+        eprintln!("❌ {} of {} font(s) not embedded", missing, fonts.len());
+        std::process::exit(1);
+    }
+    println!("✅ All {} font(s) embedded", fonts.len());
+}
+
+fn split_pdf(input: &Path, output: &Path) {
+    let parser = match PdfParser::open(input) {
+        Ok(p) => p,
+        Err(e) => { eprintln!("❌ Error: {}", e); std::process::exit(1); }
+    };
+    match parser.split_by_page(output) {
+        Ok(paths) => {
+            for path in &paths {
+                println!("  ✓ {}", path.display());
+            }
+            println!("\n✅ Split {} page(s) into {}", paths.len(), output.display());
+        }
+        Err(e) => { eprintln!("❌ Split failed: {}", e); std::process::exit(1); }
     }
 }
 
@@ -2654,13 +4050,36 @@ fn convert_rtf(input: &Path, output: &Path, format: &str, verbose: bool) {
 
                     let mut mv2 = ManifestV2::new(input, "rtf");
 
+                    // Save \pict images and point the markdown at the saved assets
+                    let mut md = doc.to_markdown();
+                    let mut saved = 0usize;
+                    for (idx, img) in doc.images.iter().enumerate() {
+                        let name = img.file_name(idx);
+                        let meta = AssetMetadata {
+                            format: Some(img.format.clone()),
+                            ..Default::default()
+                        };
+                        let hash_filename = mv2.add_asset(&img.data, MediaType::Image, &img.format, meta);
+                        if let Some(asset) = mv2.assets.iter().find(|a| a.src.ends_with(&hash_filename)) {
+                            md = md.replace(&format!("(assets/{})", name), &format!("({})", asset.src));
+                            match save_asset_file(output, asset, &img.data) {
+                                Ok(()) => saved += 1,
+                                Err(e) => eprintln!("  \u{26a0}\u{fe0f}  Failed to save {}: {}", name, e),
+                            }
+                        }
+                    }
+                    if saved > 0 {
+                        println!("  \u{2713} Extracted {} images to assets/images/", saved);
+                    }
+
                     match format {
                         "json" => {
                             let json_path = output.join(format!("{}.json", stem));
                             let json_data = json!({
                                 "version": "1.0",
                                 "format": "rtf",
-                                "content": doc.to_markdown(),
+                                "content": md,
+                                "images": doc.images.len(),
                             });
                             fs::write(&json_path, serde_json::to_string_pretty(&json_data).unwrap())
                                 .expect("Failed to write JSON");
@@ -2668,12 +4087,17 @@ fn convert_rtf(input: &Path, output: &Path, format: &str, verbose: bool) {
                         }
                         _ => {
                             let mdx_path = output.join(format!("{}.mdx", stem));
-                            fs::write(&mdx_path, doc.to_mdx(&source_name)).expect("Failed to write MDX");
+                            let mdx = format!(
+                                "---\nformat: rtf\nsource: \"{}\"\nimages: {}\n---\n\n{}",
+                                source_name.replace('"', "\\\""),
+                                doc.images.len(),
+                                md
+                            );
+                            fs::write(&mdx_path, mdx).expect("Failed to write MDX");
                             println!("  \u{2713} Created: {}", mdx_path.display());
                         }
                     }
 
-                    let md = doc.to_markdown();
                     mv2.stats.markdown_lines = md.lines().count();
                     mv2.stats.markdown_chars = md.len();
 
@@ -2684,6 +4108,8 @@ fn convert_rtf(input: &Path, output: &Path, format: &str, verbose: bool) {
                     if verbose {
                         println!("\n\u{1f4ca} Summary:");
                         println!("  - Format: RTF");
+                        println!("  - Paragraphs: {}", doc.paragraphs.len());
+                        println!("  - Images: {}", doc.images.len());
                         println!("  - Text length: {} chars", md.len());
                     }
 
@@ -2701,6 +4127,132 @@ fn convert_rtf(_input: &Path, _output: &Path, _format: &str, _verbose: bool) {
     eprintln!("\u{274c} RTF support disabled. Enable the 'rtf' feature in Cargo.toml.");
 }
 
+#[cfg(feature = "odt")]
+fn convert_odt(input: &Path, output: &Path, format: &str, verbose: bool) {
+    match odt::OdtParser::open(input) {
+        Ok(mut parser) => {
+            fs::create_dir_all(output).expect("Failed to create output directory");
+
+            match parser.parse() {
+                Ok(doc) => {
+                    let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+                    let source_name = input.file_name()
+                        .map(|n| n.to_string_lossy().to_string())
+                        .unwrap_or_else(|| "document.odt".to_string());
+
+                    let mut mv2 = ManifestV2::new(input, "odt");
+                    mv2.source.title = doc.metadata.title.clone();
+                    mv2.source.author = doc.metadata.author.clone();
+                    mv2.source.pages = doc.metadata.page_count.map(|p| p as usize);
+
+                    // Save frame images and point the markdown at the saved assets
+                    let mut mdx_content = doc.to_mdx(&source_name);
+                    let mut saved = 0usize;
+                    for image in &doc.images {
+                        let Some(ref data) = image.data else { continue };
+                        let ext = Path::new(&image.filename)
+                            .extension()
+                            .and_then(|e| e.to_str())
+                            .unwrap_or("bin");
+                        let meta = AssetMetadata {
+                            width: image.width,
+                            height: image.height,
+                            format: Some(ext.to_string()),
+                            alt_text: image.alt_text.clone(),
+                            ..Default::default()
+                        };
+                        let hash_filename = mv2.add_asset(data, MediaType::Image, ext, meta);
+                        if let Some(asset) = mv2.assets.iter().rev().find(|a| a.src.ends_with(&hash_filename)) {
+                            mdx_content = mdx_content.replace(
+                                &format!("(assets/{})", image.filename),
+                                &format!("({})", asset.src),
+                            );
+                            match save_asset_file(output, asset, data) {
+                                Ok(()) => {
+                                    saved += 1;
+                                    if verbose {
+                                        println!("  \u{1f4f7} Saved: {} ({} bytes)", asset.src, data.len());
+                                    }
+                                }
+                                Err(e) => eprintln!("  \u{26a0}\u{fe0f}  Failed to save {}: {}", image.filename, e),
+                            }
+                        }
+                    }
+                    if saved > 0 {
+                        println!("  \u{2713} Extracted {} images to assets/images/", saved);
+                    }
+
+                    match format {
+                        "json" => {
+                            let json_path = output.join(format!("{}.json", stem));
+                            let json_data = json!({
+                                "version": "1.0",
+                                "format": "odt",
+                                "metadata": {
+                                    "title": doc.metadata.title,
+                                    "author": doc.metadata.author,
+                                    "subject": doc.metadata.subject,
+                                    "pages": doc.metadata.page_count,
+                                    "words": doc.metadata.word_count,
+                                },
+                                "content": doc.to_markdown(),
+                                "tables": doc.tables.iter().map(|t| json!({
+                                    "markdown": t.to_markdown(),
+                                })).collect::<Vec<_>>(),
+                                "images": doc.images.iter().map(|i| json!({
+                                    "id": i.id,
+                                    "filename": i.filename,
+                                    "alt": i.alt_text,
+                                    "width": i.width,
+                                    "height": i.height,
+                                    "size": i.data.as_ref().map(|d| d.len()).unwrap_or(0),
+                                })).collect::<Vec<_>>(),
+                            });
+                            fs::write(&json_path, serde_json::to_string_pretty(&json_data).unwrap())
+                                .expect("Failed to write JSON");
+                            println!("  \u{2713} Created: {}", json_path.display());
+                        }
+                        _ => {
+                            let mdx_path = output.join(format!("{}.mdx", stem));
+                            fs::write(&mdx_path, &mdx_content).expect("Failed to write MDX");
+                            println!("  \u{2713} Created: {}", mdx_path.display());
+                        }
+                    }
+
+                    let md = doc.to_markdown();
+                    mv2.stats.markdown_lines = md.lines().count();
+                    mv2.stats.markdown_chars = md.len();
+
+                    if let Err(e) = save_manifest(&mv2, output, &stem) {
+                        eprintln!("  \u{26a0}\u{fe0f}  Failed to write manifest: {}", e);
+                    }
+
+                    if verbose {
+                        println!("\n\u{1f4ca} Summary:");
+                        println!("  - Format: ODT");
+                        if let Some(ref title) = doc.metadata.title {
+                            println!("  - Title: {}", title);
+                        }
+                        println!("  - Paragraphs: {}", doc.paragraphs.len());
+                        println!("  - Tables: {}", doc.tables.len());
+                        println!("  - Images: {}", doc.images.len());
+                        println!("  - Text length: {} chars", doc.text().len());
+                    }
+
+                    println!("\u{2705} Conversion complete!");
+                }
+                Err(e) => eprintln!("\u{274c} Error parsing ODT: {}", e),
+            }
+        }
+        Err(e) => eprintln!("\u{274c} Error opening ODT file: {}", e),
+    }
+}
+
+#[cfg(not(feature = "odt"))]
+fn convert_odt(_input: &Path, _output: &Path, _format: &str, _verbose: bool) {
+    eprintln!("\u{274c} ODT support disabled. Enable the 'odt' feature in Cargo.toml.");
+}
+
 #[cfg(feature = "epub")]
 fn convert_epub(input: &Path, output: &Path, format: &str, verbose: bool) {
     match epub::EpubParser::open(input) {
@@ -3298,6 +4850,34 @@ fn cmd_fill(input: &Path, values_path: Option<&Path>, output: Option<&Path>, dry
     }
 }
 
+/// `render` for PDF input — one page → PNG via `pdftoppm`/`mutool`,
+/// cached on disk by (path, page, dpi, mtime).
+fn render_pdf_page(input: &Path, output: &Path, page: usize, dpi: u32) {
+    let parser = match PdfParser::open(input) {
+        Ok(p) => p,
+        Err(e) => { eprintln!("\u{274c} Failed to open {}: {}", input.display(), e); std::process::exit(1); }
+    };
+    let cache: Option<pdf::PageRenderCache> = cache::Cache::new(
+        std::env::temp_dir().join("mdm-render-cache"),
+        std::time::Duration::from_secs(7 * 24 * 3600),
+    )
+    .ok();
+    let rendered = match &cache {
+        Some(cache) => parser.render_page_to_png_cached(page, dpi, cache),
+        None => parser.render_page_to_png(page, dpi),
+    };
+    match rendered {
+        Ok(png) => {
+            fs::create_dir_all(output).expect("Failed to create output directory");
+            let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+            let path = output.join(format!("{}-p{:03}.png", stem, page));
+            fs::write(&path, png).expect("Failed to write PNG");
+            println!("\u{2705} Rendered page {} at {} dpi: {}", page, dpi, path.display());
+        }
+        Err(e) => { eprintln!("\u{274c} Render failed: {}", e); std::process::exit(1); }
+    }
+}
+
 /// `render` — HWPX → per-page SVG (or PNG) via the layout-preserving renderer.
 fn cmd_render(input: &Path, output: &Path, png: bool, scale: f32, reflow: bool) {
     let bytes = match fs::read(input) {
@@ -3510,14 +5090,16 @@ fn cmd_chunks(input: &Path, granularity: &str, max_chars: usize, overlap: usize)
 }
 
 #[cfg(feature = "watch")]
-fn cmd_watch(dir: &Path, output: &Path, webhook: Option<&str>) {
+fn cmd_watch(dir: &Path, output: &Path, webhook: Option<&str>, debounce: Option<std::time::Duration>) {
     use watch::{OutputFormat, WatchOptions};
 
+    let defaults = WatchOptions::default();
     let opts = WatchOptions {
         out_dir: Some(output.to_path_buf()),
         webhook: webhook.map(|s| s.to_string()),
         format: OutputFormat::Markdown,
         silent: false,
+        debounce: debounce.unwrap_or(defaults.debounce),
     };
 
     println!("\u{1f4c2} Watching {} ...", dir.display());
@@ -3544,6 +5126,114 @@ fn cmd_watch(dir: &Path, output: &Path, webhook: Option<&str>) {
     }
 }
 
+/// Default quiet period for `watch <file>`: long enough to swallow the
+/// truncate + write (or write-temp + rename) pair of a single editor save.
+#[cfg(feature = "watch")]
+const WATCH_FILE_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Set by the SIGINT handler so `cmd_watch_file` can print its summary.
+#[cfg(feature = "watch")]
+static WATCH_INTERRUPTED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+#[cfg(all(feature = "watch", unix))]
+fn install_watch_interrupt_handler() {
+    extern "C" fn on_sigint(_: libc::c_int) {
+        WATCH_INTERRUPTED.store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+    unsafe {
+        libc::signal(libc::SIGINT, on_sigint as libc::sighandler_t);
+    }
+}
+
+/// No handler off Unix: CTRL-C terminates without the summary.
+#[cfg(all(feature = "watch", not(unix)))]
+fn install_watch_interrupt_handler() {}
+
+/// Reconvert a single document every time it is saved.
+///
+/// The parent directory is watched rather than the file itself, because
+/// editors that save atomically replace the file and a watch on the old
+/// inode would go silent after the first save.
+#[cfg(feature = "watch")]
+fn cmd_watch_file(input: &Path, output: &Path, format: &str, debounce: std::time::Duration) {
+    use notify::event::{ModifyKind, RenameMode};
+    use notify::{EventKind, RecursiveMode, Watcher};
+    use std::sync::atomic::Ordering;
+    use std::sync::mpsc::{channel, RecvTimeoutError};
+    use std::time::{Duration, Instant};
+
+    let input = match fs::canonicalize(input) {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("\u{274c} Cannot watch {}: {}", input.display(), e);
+            std::process::exit(1);
+        }
+    };
+    let parent = input.parent().unwrap_or(Path::new("."));
+
+    let (tx, rx) = channel::<notify::Result<notify::Event>>();
+    let mut watcher = match notify::recommended_watcher(tx) {
+        Ok(w) => w,
+        Err(e) => {
+            eprintln!("\u{274c} Watch failed: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Err(e) = watcher.watch(parent, RecursiveMode::NonRecursive) {
+        eprintln!("\u{274c} Watch failed: {}", e);
+        std::process::exit(1);
+    }
+    install_watch_interrupt_handler();
+
+    let mut conversions = 0u32;
+    let mut total_time = Duration::ZERO;
+    let mut reconvert = |label: &str| {
+        let started = Instant::now();
+        convert_file(&input, output, format, false, false, false, None, None, Default::default(), Default::default());
+        total_time += started.elapsed();
+        conversions += 1;
+        println!("[{}] {}: {}", chrono::Local::now().format("%H:%M:%S"), label, input.display());
+    };
+
+    reconvert("Converted");
+    println!("\u{1f440} Watching {} (debounce {} ms, CTRL-C to stop)", input.display(), debounce.as_millis());
+
+    let mut due: Option<Instant> = None;
+    while !WATCH_INTERRUPTED.load(Ordering::SeqCst) {
+        match rx.recv_timeout(Duration::from_millis(100)) {
+            Ok(Ok(event)) => {
+                // In-place writes report `Data` (or `Any` on backends that
+                // cannot tell); atomic saves surface as a create or a rename
+                // onto the watched name.
+                let relevant = matches!(
+                    event.kind,
+                    EventKind::Modify(ModifyKind::Data(_) | ModifyKind::Any)
+                        | EventKind::Modify(ModifyKind::Name(RenameMode::To | RenameMode::Both | RenameMode::Any))
+                        | EventKind::Create(_)
+                );
+                if relevant && event.paths.iter().any(|p| p.file_name() == input.file_name()) {
+                    due = Some(Instant::now() + debounce);
+                }
+            }
+            Ok(Err(e)) => eprintln!("\u{26a0}\u{fe0f}  Watch error: {}", e),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+
+        if due.is_some_and(|d| Instant::now() >= d) && input.exists() {
+            due = None;
+            reconvert("Reconverted");
+        }
+    }
+
+    println!();
+    println!("\u{1f4ca} Watch summary");
+    println!("  Conversions: {}", conversions);
+    if conversions > 0 {
+        println!("  Average time: {:.1} ms", total_time.as_secs_f64() * 1000.0 / f64::from(conversions));
+    }
+}
+
 fn convert_hwp3(input: &Path, output: &Path, format: &str, verbose: bool) {
     let data = match fs::read(input) {
         Ok(d) => d,
@@ -3636,6 +5326,96 @@ fn cmd_legal(input: &Path, format: &str) {
     }
 }
 
+/// `legal-export` — write chunks as JSONL, JSON or semantic HTML
+fn cmd_legal_export(input: &Path, output: &Path, format: &str) {
+    let chunks = match legal::KoreanLegalChunker::new().parse_markdown(input) {
+        Ok(chunks) => chunks,
+        Err(e) => { eprintln!("\u{274c} Legal parsing failed: {}", e); std::process::exit(1); }
+    };
+    let exporter = legal::WeKnoraExporter::new();
+    let result = match format {
+        "jsonl" => exporter.export_to_jsonl(&chunks, output).map(|_| ()),
+        "json" => exporter.export_to_json(&chunks, output).map(|_| ()),
+        "html" => exporter.export_to_html(&chunks, &output.to_string_lossy()),
+        other => {
+            eprintln!("\u{274c} Unknown format '{}' (expected jsonl, json or html)", other);
+            std::process::exit(1);
+        }
+    };
+    match result {
+        Ok(()) => println!("\u{2705} Exported {} chunk(s) to {}", chunks.len(), output.display()),
+        Err(e) => { eprintln!("\u{274c} Export failed: {}", e); std::process::exit(1); }
+    }
+}
+
+/// `legal-check` — exit 1 if the hierarchy validator finds anything
+fn cmd_legal_check(input: &Path, format: &str) {
+    let chunks = match legal::KoreanLegalChunker::new().parse_markdown(input) {
+        Ok(chunks) => chunks,
+        Err(e) => { eprintln!("\u{274c} Legal parsing failed: {}", e); std::process::exit(1); }
+    };
+    let warnings = legal::KoreanLegalChunker::validate_hierarchy(&chunks);
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&warnings).unwrap_or_default());
+    } else {
+        for warning in &warnings {
+            println!("  \u{26a0}\u{fe0f}  [{:?}] {} ({})", warning.warning_type, warning.detail, warning.chunk_id);
+        }
+        if warnings.is_empty() {
+            println!("\u{2705} No hierarchy anomalies in {} chunk(s)", chunks.len());
+        } else {
+            eprintln!("\u{274c} {} hierarchy warning(s) in {}", warnings.len(), input.display());
+        }
+    }
+    if !warnings.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+fn cmd_fields(input: &Path, format: &str) {
+    let doc = match HwpxParser::open(input).and_then(|mut parser| parser.parse().map_err(io::Error::from)) {
+        Ok(doc) => doc,
+        Err(e) => { eprintln!("\u{274c} Failed to parse HWPX: {}", e); std::process::exit(1); }
+    };
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&doc.fields).unwrap_or_default());
+        return;
+    }
+    for field in &doc.fields {
+        let name = field.name.as_deref().map(|n| format!(" \"{}\"", n)).unwrap_or_default();
+        println!(
+            "  section {} \u{b6}{}  {}{}: {}",
+            field.section_index,
+            field.paragraph_index,
+            field.field_type,
+            name,
+            field.value.as_deref().unwrap_or("___"),
+        );
+    }
+    let empty = doc.fields.iter().filter(|f| f.value.is_none()).count();
+    println!("\u{1f4dd} {} field(s), {} empty", doc.fields.len(), empty);
+}
+
+fn cmd_history(input: &Path, format: &str) {
+    let history = match HwpParser::open(input).and_then(|mut parser| parser.extract_revision_history()) {
+        Ok(history) => history,
+        Err(e) => { eprintln!("\u{274c} Failed to read HWP history: {}", e); std::process::exit(1); }
+    };
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&history).unwrap_or_default());
+        return;
+    }
+    for entry in &history {
+        let saved = i64::try_from(entry.timestamp)
+            .ok()
+            .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+            .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+            .unwrap_or_else(|| entry.timestamp.to_string());
+        println!("  r{}  {}  {}: {}", entry.revision_number, saved, entry.author, entry.change_summary);
+    }
+    println!("\u{1f4dc} {} revision(s)", history.len());
+}
+
 #[cfg(feature = "ocr")]
 fn ocr_available() -> bool {
     ocr::ocr_available()
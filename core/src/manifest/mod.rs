@@ -4,6 +4,10 @@
 //! extracted assets (images, tables, charts, equations) using SHA-256 hashes
 //! for content deduplication and addressable storage paths.
 
+pub mod validator;
+
+pub use validator::{ManifestValidator, ValidationError};
+
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::path::Path;
@@ -0,0 +1,353 @@
+//! Structural validation for `.mdm` manifest files
+//!
+//! Two manifest shapes are in circulation: the v1 resource map written by
+//! `DocxParser::to_mdx` (`format` + `resources: { id: { type, src } }`) and
+//! [`super::ManifestV2`] written by the CLI (`source.format` + `assets: [...]`).
+//! [`ManifestValidator`] accepts either and reports every problem it finds,
+//! each tagged with the JSON path of the offending field.
+
+use serde_json::Value;
+use std::fmt;
+
+/// Formats a manifest may declare.
+///
+/// The four office formats plus every other format the CLI converters
+/// write a manifest for, so `hwp2mdm --validate` accepts its own output.
+pub const MANIFEST_FORMATS: &[&str] = &[
+    "hwp", "hwpx", "pdf", "docx", "hwp3", "hwpml", "doc", "xls", "xlsx", "pptx", "odt", "rtf",
+    "epub", "html", "csv", "txt",
+];
+
+/// One validation failure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// JSON path of the field, e.g. `resources.img1.src` or `assets[0].src`
+    pub path: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { path: path.into(), message: message.into() }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Checks a manifest's version, format and resource entries
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ManifestValidator;
+
+impl ManifestValidator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Parse `json` and validate it; a parse failure is reported at path `$`
+    pub fn validate_str(&self, json: &str) -> Vec<ValidationError> {
+        match serde_json::from_str::<Value>(json) {
+            Ok(value) => self.validate(&value),
+            Err(e) => vec![ValidationError::new("$", format!("invalid JSON: {}", e))],
+        }
+    }
+
+    /// Validate a parsed manifest. An empty result means it is valid.
+    pub fn validate(&self, manifest: &Value) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        let Some(root) = manifest.as_object() else {
+            errors.push(ValidationError::new("$", "manifest must be a JSON object"));
+            return errors;
+        };
+
+        match root.get("version") {
+            Some(Value::String(v)) if is_semver(v) => {}
+            Some(Value::String(v)) => {
+                errors.push(ValidationError::new("version", format!("'{}' is not a semver version", v)))
+            }
+            Some(_) => errors.push(ValidationError::new("version", "must be a string")),
+            None => errors.push(ValidationError::new("version", "is required")),
+        }
+
+        if let Some(resources) = root.get("resources") {
+            check_format(root.get("format"), "format", &mut errors);
+            self.check_resources(resources, &mut errors);
+        } else if let Some(assets) = root.get("assets") {
+            let format = root.get("source").and_then(|s| s.get("format"));
+            check_format(format, "source.format", &mut errors);
+            self.check_assets(assets, &mut errors);
+        } else {
+            errors.push(ValidationError::new("$", "missing `resources` (v1) or `assets` (v2)"));
+        }
+
+        errors
+    }
+
+    /// v1: `resources` maps ids to `{ "type": .., "src": .. }`
+    fn check_resources(&self, resources: &Value, errors: &mut Vec<ValidationError>) {
+        let Some(resources) = resources.as_object() else {
+            errors.push(ValidationError::new("resources", "must be an object"));
+            return;
+        };
+        for (id, resource) in resources {
+            let path = format!("resources.{}", id);
+            let Some(resource) = resource.as_object() else {
+                errors.push(ValidationError::new(path, "must be an object"));
+                continue;
+            };
+            require_string(resource.get("type"), &format!("{}.type", path), errors);
+            if let Some(src) = require_string(resource.get("src"), &format!("{}.src", path), errors) {
+                check_relative(src, &format!("{}.src", path), errors);
+            }
+        }
+    }
+
+    /// v2: `assets` is a list of `{ "media_type": .., "src": .., .. }`
+    fn check_assets(&self, assets: &Value, errors: &mut Vec<ValidationError>) {
+        let Some(assets) = assets.as_array() else {
+            errors.push(ValidationError::new("assets", "must be an array"));
+            return;
+        };
+        for (i, asset) in assets.iter().enumerate() {
+            let path = format!("assets[{}]", i);
+            let Some(asset) = asset.as_object() else {
+                errors.push(ValidationError::new(path, "must be an object"));
+                continue;
+            };
+            require_string(asset.get("media_type"), &format!("{}.media_type", path), errors);
+            if let Some(src) = require_string(asset.get("src"), &format!("{}.src", path), errors) {
+                check_relative(src, &format!("{}.src", path), errors);
+            }
+        }
+    }
+}
+
+/// `MAJOR.MINOR[.PATCH][-pre][+build]`. The two-component form is accepted
+/// because every manifest writer in this crate emits `"1.0"` / `"2.0"`.
+fn is_semver(version: &str) -> bool {
+    let core = match version.find(['-', '+']) {
+        // An empty pre-release / build suffix is malformed
+        Some(i) if i + 1 == version.len() => return false,
+        Some(i) => &version[..i],
+        None => version,
+    };
+    let parts: Vec<&str> = core.split('.').collect();
+    (2..=3).contains(&parts.len())
+        && parts.iter().all(|p| {
+            !p.is_empty()
+                && p.bytes().all(|b| b.is_ascii_digit())
+                && (p.len() == 1 || !p.starts_with('0'))
+        })
+}
+
+fn require_string<'a>(
+    value: Option<&'a Value>,
+    path: &str,
+    errors: &mut Vec<ValidationError>,
+) -> Option<&'a str> {
+    match value {
+        Some(Value::String(s)) if !s.is_empty() => Some(s.as_str()),
+        Some(Value::String(_)) => {
+            errors.push(ValidationError::new(path, "must not be empty"));
+            None
+        }
+        Some(_) => {
+            errors.push(ValidationError::new(path, "must be a string"));
+            None
+        }
+        None => {
+            errors.push(ValidationError::new(path, "is required"));
+            None
+        }
+    }
+}
+
+fn check_format(value: Option<&Value>, path: &str, errors: &mut Vec<ValidationError>) {
+    if let Some(format) = require_string(value, path, errors) {
+        if !MANIFEST_FORMATS.contains(&format) {
+            errors.push(ValidationError::new(path, format!("unknown format '{}'", format)));
+        }
+    }
+}
+
+/// Asset paths are resolved against the bundle root, so they must not be
+/// absolute — checked for both Unix (`/x`) and Windows (`\x`, `C:\x`) forms
+/// regardless of the host OS.
+fn check_relative(src: &str, path: &str, errors: &mut Vec<ValidationError>) {
+    let bytes = src.as_bytes();
+    let drive = bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':';
+    if src.starts_with('/') || src.starts_with('\\') || drive {
+        errors.push(ValidationError::new(path, format!("'{}' must be a relative path", src)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn validate(value: Value) -> Vec<ValidationError> {
+        ManifestValidator::new().validate(&value)
+    }
+
+    fn paths(errors: &[ValidationError]) -> Vec<&str> {
+        errors.iter().map(|e| e.path.as_str()).collect()
+    }
+
+    fn v1() -> Value {
+        json!({
+            "version": "1.0",
+            "format": "docx",
+            "source": "report.docx",
+            "resources": {
+                "rId5": { "type": "image", "src": "assets/image1.png", "alt": "logo" }
+            }
+        })
+    }
+
+    #[test]
+    fn valid_v1_manifest() {
+        assert!(validate(v1()).is_empty());
+    }
+
+    #[test]
+    fn valid_v2_manifest() {
+        let mut m = crate::manifest::ManifestV2::new(std::path::Path::new("missing.hwp"), "hwp");
+        m.add_asset(b"png", crate::manifest::MediaType::Image, "png", Default::default());
+        let errors = ManifestValidator::new().validate_str(&m.to_json().unwrap());
+        assert!(errors.is_empty(), "{:?}", errors);
+    }
+
+    #[test]
+    fn semver_versions() {
+        for ok in ["1.0", "2.0", "1.2.3", "0.1.0-beta.1", "1.0.0+build.5", "10.20.30"] {
+            assert!(is_semver(ok), "{}", ok);
+        }
+        for bad in ["", "1", "v1.0", "1.0.0.0", "01.0", "1..0", "1.x", "1.0-"] {
+            assert!(!is_semver(bad), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn invalid_version_string() {
+        let mut m = v1();
+        m["version"] = json!("latest");
+        let errors = validate(m);
+        assert_eq!(paths(&errors), vec!["version"]);
+        assert!(errors[0].message.contains("semver"));
+    }
+
+    #[test]
+    fn missing_version() {
+        let mut m = v1();
+        m.as_object_mut().unwrap().remove("version");
+        assert_eq!(validate(m), vec![ValidationError::new("version", "is required")]);
+    }
+
+    #[test]
+    fn non_string_version() {
+        let mut m = v1();
+        m["version"] = json!(2.0);
+        assert_eq!(validate(m), vec![ValidationError::new("version", "must be a string")]);
+    }
+
+    #[test]
+    fn resource_missing_type_and_src() {
+        let mut m = v1();
+        m["resources"]["rId5"] = json!({ "alt": "logo" });
+        assert_eq!(paths(&validate(m)), vec!["resources.rId5.type", "resources.rId5.src"]);
+    }
+
+    #[test]
+    fn resource_must_be_object() {
+        let mut m = v1();
+        m["resources"]["rId5"] = json!("assets/image1.png");
+        assert_eq!(validate(m), vec![ValidationError::new("resources.rId5", "must be an object")]);
+    }
+
+    #[test]
+    fn absolute_src_rejected() {
+        for src in ["/tmp/image1.png", "\\\\server\\share\\a.png", "C:\\images\\a.png", "d:/a.png"] {
+            let mut m = v1();
+            m["resources"]["rId5"]["src"] = json!(src);
+            let errors = validate(m);
+            assert_eq!(paths(&errors), vec!["resources.rId5.src"], "{}", src);
+            assert!(errors[0].message.contains("relative"));
+        }
+    }
+
+    #[test]
+    fn relative_src_variants_accepted() {
+        for src in ["assets/a.png", "./assets/a.png", "a.png", "assets/images/c:d.png"] {
+            let mut m = v1();
+            m["resources"]["rId5"]["src"] = json!(src);
+            assert!(validate(m).is_empty(), "{}", src);
+        }
+    }
+
+    #[test]
+    fn unknown_format_rejected() {
+        let mut m = v1();
+        m["format"] = json!("pages");
+        let errors = validate(m);
+        assert_eq!(paths(&errors), vec!["format"]);
+        assert!(errors[0].message.contains("'pages'"));
+    }
+
+    #[test]
+    fn missing_format_rejected() {
+        let mut m = v1();
+        m.as_object_mut().unwrap().remove("format");
+        assert_eq!(validate(m), vec![ValidationError::new("format", "is required")]);
+    }
+
+    #[test]
+    fn v2_asset_errors_use_index_paths() {
+        let m = json!({
+            "version": "2.0",
+            "source": { "format": "pdf" },
+            "assets": [
+                { "media_type": "image", "src": "assets/images/a.png" },
+                { "src": "/abs/b.png" },
+                "oops"
+            ]
+        });
+        assert_eq!(
+            paths(&validate(m)),
+            vec!["assets[1].media_type", "assets[1].src", "assets[2]"]
+        );
+    }
+
+    #[test]
+    fn v2_source_format_checked() {
+        let m = json!({ "version": "2.0", "source": { "format": "key" }, "assets": [] });
+        assert_eq!(paths(&validate(m)), vec!["source.format"]);
+    }
+
+    #[test]
+    fn neither_resources_nor_assets() {
+        let errors = validate(json!({ "version": "1.0", "format": "hwp" }));
+        assert_eq!(paths(&errors), vec!["$"]);
+    }
+
+    #[test]
+    fn non_object_and_invalid_json() {
+        assert_eq!(paths(&validate(json!([1, 2]))), vec!["$"]);
+        let errors = ManifestValidator::new().validate_str("{ not json");
+        assert_eq!(paths(&errors), vec!["$"]);
+        assert!(errors[0].to_string().starts_with("$: invalid JSON"));
+    }
+
+    #[test]
+    fn reports_every_error() {
+        let m = json!({
+            "version": "one",
+            "format": "txt2",
+            "resources": { "a": { "type": "image", "src": "/a.png" }, "b": {} }
+        });
+        assert_eq!(validate(m).len(), 5);
+    }
+}
@@ -0,0 +1,301 @@
+//! Multipart MDX output for very large documents.
+//!
+//! A single multi-megabyte `.mdx` (500+ page HWP reports) freezes
+//! browser-side MDX consumers. When [`crate::Config::max_output_size_bytes`]
+//! is set and the rendered MDX exceeds it, the body is split at paragraph
+//! boundaries (`\n\n`) into `{stem}_001.mdx`, `{stem}_002.mdx`, … with a
+//! Prev/Next navigation line at the top and bottom of every part. The usual
+//! `{stem}.mdx` path becomes an index that links to each part, so callers
+//! that only know the stem still land somewhere sensible.
+//!
+//! Paragraphs are never cut in half — a single paragraph larger than the
+//! limit becomes its own (oversized) part. Blank lines inside a ``` fence
+//! or an HTML `<table>`, and between consecutive pipe-table blocks, are not
+//! split points either.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Files written by [`write_multipart`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultipartOutput {
+    /// Numbered part files in reading order.
+    pub parts: Vec<PathBuf>,
+    /// Index file (`{stem}.mdx`) linking to every part.
+    pub index: PathBuf,
+}
+
+/// Split `mdx` into `(frontmatter, body)`. Frontmatter includes both `---`
+/// delimiter lines; empty when the document has none.
+fn split_frontmatter(mdx: &str) -> (&str, &str) {
+    if !mdx.starts_with("---\n") {
+        return ("", mdx);
+    }
+    match mdx[4..].find("\n---\n") {
+        Some(end) => {
+            let fm_end = 4 + end + "\n---\n".len();
+            (&mdx[..fm_end], mdx[fm_end..].trim_start_matches('\n'))
+        }
+        None => ("", mdx),
+    }
+}
+
+/// Blank-line separated blocks of `body` that may go to different parts.
+///
+/// A block stays open across blank lines while it has an unclosed ``` fence
+/// or `<table>` element; a pipe-table block directly after another one is
+/// joined to it.
+fn atomic_blocks(body: &str) -> Vec<String> {
+    let mut blocks: Vec<String> = Vec::new();
+    let mut in_fence = false;
+    let mut open_tables = 0usize;
+
+    for para in body.split("\n\n") {
+        let continues = in_fence || open_tables > 0;
+        let para = if continues { para } else { para.trim_matches('\n') };
+        if para.is_empty() && !continues {
+            continue;
+        }
+        let joins_table = para.starts_with('|')
+            && blocks
+                .last()
+                .and_then(|b| b.lines().last())
+                .is_some_and(|line| line.starts_with('|'));
+        match blocks.last_mut() {
+            Some(last) if continues || joins_table => {
+                last.push_str("\n\n");
+                last.push_str(para);
+            }
+            _ => blocks.push(para.to_string()),
+        }
+
+        for line in para.lines() {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+            }
+        }
+        if !in_fence {
+            open_tables += para.matches("<table").count();
+            open_tables = open_tables.saturating_sub(para.matches("</table>").count());
+        }
+    }
+    for block in &mut blocks {
+        block.truncate(block.trim_end_matches('\n').len());
+    }
+    blocks
+}
+
+/// Group paragraphs into chunks whose body stays within `max_bytes`.
+pub fn split_at_paragraphs(body: &str, max_bytes: usize) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut current = String::new();
+
+    for para in atomic_blocks(body) {
+        let added = if current.is_empty() { para.len() } else { para.len() + 2 };
+        if !current.is_empty() && current.len() + added > max_bytes {
+            parts.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push_str("\n\n");
+        }
+        current.push_str(&para);
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+/// Frontmatter with `part` / `parts` keys appended before the closing `---`.
+fn part_frontmatter(frontmatter: &str, part: usize, total: usize) -> String {
+    let extra = format!("part: {}\nparts: {}\n", part, total);
+    match frontmatter.strip_suffix("---\n") {
+        Some(head) if !head.is_empty() => format!("{}{}---\n\n", head, extra),
+        _ => format!("---\n{}---\n\n", extra),
+    }
+}
+
+/// Prev/Next navigation line for part `idx` (0-based).
+fn nav_line(names: &[String], idx: usize) -> String {
+    let mut links: Vec<String> = Vec::new();
+    if idx > 0 {
+        links.push(format!("[Prev: {0}]({0})", names[idx - 1]));
+    }
+    links.push(format!("Part {} of {}", idx + 1, names.len()));
+    if idx + 1 < names.len() {
+        links.push(format!("[Next: {0}]({0})", names[idx + 1]));
+    }
+    format!("> {}", links.join(" · "))
+}
+
+/// Write `mdx` as numbered parts when it exceeds `max_bytes`.
+///
+/// Returns `Ok(None)` — and writes nothing — when the document fits or
+/// would not split into more than one part; the caller then writes the
+/// single-file output as usual.
+pub fn write_multipart(
+    output_dir: &Path,
+    stem: &str,
+    mdx: &str,
+    max_bytes: u64,
+) -> io::Result<Option<MultipartOutput>> {
+    if mdx.len() as u64 <= max_bytes {
+        return Ok(None);
+    }
+
+    let (frontmatter, body) = split_frontmatter(mdx);
+    let limit = usize::try_from(max_bytes).unwrap_or(usize::MAX).max(1);
+    let chunks = split_at_paragraphs(body, limit);
+    if chunks.len() < 2 {
+        return Ok(None);
+    }
+
+    fs::create_dir_all(output_dir)?;
+    let names: Vec<String> = (1..=chunks.len())
+        .map(|n| format!("{}_{:03}.mdx", stem, n))
+        .collect();
+
+    let mut parts = Vec::with_capacity(chunks.len());
+    for (idx, chunk) in chunks.iter().enumerate() {
+        let nav = nav_line(&names, idx);
+        let content = format!(
+            "{}{}\n\n{}\n\n{}\n",
+            part_frontmatter(frontmatter, idx + 1, chunks.len()),
+            nav,
+            chunk,
+            nav
+        );
+        let path = output_dir.join(&names[idx]);
+        fs::write(&path, content)?;
+        parts.push(path);
+    }
+
+    let mut index = String::from(frontmatter);
+    if !index.is_empty() {
+        index.push('\n');
+    }
+    for name in &names {
+        index.push_str(&format!("- [{0}]({0})\n", name));
+    }
+    let index_path = output_dir.join(format!("{}.mdx", stem));
+    fs::write(&index_path, index)?;
+
+    Ok(Some(MultipartOutput {
+        parts,
+        index: index_path,
+    }))
+}
+
+/// [`write_multipart`] with the limit from
+/// [`crate::Config::max_output_size_bytes`]; `Ok(None)` when it is unset.
+pub fn write_multipart_with_config(
+    output_dir: &Path,
+    stem: &str,
+    mdx: &str,
+    config: &crate::Config,
+) -> io::Result<Option<MultipartOutput>> {
+    match config.max_output_size_bytes {
+        Some(limit) => write_multipart(output_dir, stem, mdx, limit),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ten_paragraph_mdx() -> String {
+        let mut mdx = String::from("---\nversion: \"HWP 5.0.0.0\"\n---\n\n");
+        let paras: Vec<String> = (1..=10)
+            .map(|i| format!("문단 {} {}", i, "본문 내용입니다. ".repeat(12)))
+            .collect();
+        mdx.push_str(&paras.join("\n\n"));
+        mdx
+    }
+
+    #[test]
+    fn splits_large_document_with_nav_links() {
+        let dir = tempfile::tempdir().unwrap();
+        let mdx = ten_paragraph_mdx();
+        assert!(mdx.len() > 1024);
+
+        let out = write_multipart(dir.path(), "doc", &mdx, 1024)
+            .unwrap()
+            .expect("document exceeds limit → multipart");
+        assert!(out.parts.len() > 1);
+        assert_eq!(out.index, dir.path().join("doc.mdx"));
+        assert!(out.parts.iter().all(|p| p.exists()));
+        assert_eq!(out.parts[0], dir.path().join("doc_001.mdx"));
+
+        let first = fs::read_to_string(&out.parts[0]).unwrap();
+        assert!(first.starts_with("---\nversion:"));
+        assert!(first.contains("part: 1\n"));
+        assert!(first.contains("[Next: doc_002.mdx](doc_002.mdx)"));
+        assert!(!first.contains("Prev:"));
+
+        let last = fs::read_to_string(out.parts.last().unwrap()).unwrap();
+        let prev = format!("doc_{:03}.mdx", out.parts.len() - 1);
+        assert!(last.contains(&format!("[Prev: {0}]({0})", prev)));
+        assert!(!last.contains("Next:"));
+
+        let index = fs::read_to_string(&out.index).unwrap();
+        assert!(index.contains("- [doc_001.mdx](doc_001.mdx)"));
+    }
+
+    #[test]
+    fn parts_keep_every_paragraph_intact() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = write_multipart(dir.path(), "doc", &ten_paragraph_mdx(), 1024)
+            .unwrap()
+            .unwrap();
+        let all: String = out
+            .parts
+            .iter()
+            .map(|p| fs::read_to_string(p).unwrap())
+            .collect();
+        for i in 1..=10 {
+            assert_eq!(all.matches(&format!("문단 {} ", i)).count(), 1);
+        }
+    }
+
+    #[test]
+    fn small_document_is_not_split() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = write_multipart(dir.path(), "doc", "---\na: 1\n---\n\nshort", 1024).unwrap();
+        assert!(out.is_none());
+        assert!(!dir.path().join("doc_001.mdx").exists());
+    }
+
+    #[test]
+    fn config_limit_drives_split() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = crate::Config {
+            max_output_size_bytes: Some(1024),
+            ..Default::default()
+        };
+        let out = write_multipart_with_config(dir.path(), "doc", &ten_paragraph_mdx(), &config).unwrap();
+        assert!(out.is_some_and(|o| o.parts.len() > 1));
+
+        let unset = write_multipart_with_config(dir.path(), "other", &ten_paragraph_mdx(), &crate::Config::default());
+        assert!(unset.unwrap().is_none());
+    }
+
+    #[test]
+    fn fenced_code_and_tables_are_not_split() {
+        let fence = format!("```\n{}\n\n{}\n```", "a".repeat(30), "b".repeat(30));
+        let table = "<table>\n<tr><td>x</td></tr>\n\n<tr><td>y</td></tr>\n</table>";
+        let pipes = "| a | b |\n| --- | --- |\n\n| c | d |";
+        let body = format!("intro\n\n{}\n\n{}\n\n{}\n\noutro", fence, table, pipes);
+
+        let chunks = split_at_paragraphs(&body, 10);
+        assert_eq!(chunks, vec!["intro".to_string(), fence, table.to_string(), pipes.to_string(), "outro".to_string()]);
+    }
+
+    #[test]
+    fn oversized_paragraph_stays_whole() {
+        let body = format!("{}\n\n{}", "a".repeat(50), "b".repeat(10));
+        let chunks = split_at_paragraphs(&body, 20);
+        assert_eq!(chunks, vec!["a".repeat(50), "b".repeat(10)]);
+    }
+}
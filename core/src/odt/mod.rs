@@ -0,0 +1,12 @@
+//! ODT (OpenDocument Text) parser.
+//!
+//! Converts `.odt` packages — the LibreOffice/OpenOffice native format — to
+//! Markdown. Paragraphs, headings, lists and tables reuse the DOCX model
+//! types ([`crate::docx::Paragraph`], [`crate::docx::DocxTable`]) so both
+//! formats render identically downstream.
+//!
+//! Feature-gated behind `odt` (see `core/Cargo.toml`).
+
+pub mod parser;
+
+pub use parser::{OdtDocument, OdtImage, OdtMetadata, OdtParser};
@@ -0,0 +1,1187 @@
+//! ODF package parser.
+//!
+//! Reads `content.xml` in a single streaming pass. Automatic styles (from
+//! `content.xml`) and common styles (from `styles.xml`) are collected first
+//! so `<text:span>` / `<text:p>` style names can be resolved to bold, italic,
+//! underline and strike-through, following `style:parent-style-name` chains.
+//!
+//! Element names are matched with their conventional ODF prefixes
+//! (`text:`, `table:`, `draw:`, ...) — every ODF producer writes them, and
+//! local names alone collide (`svg:title` vs `text:title`).
+//!
+//! Comments (`office:annotation`), tracked changes and footnote bodies are
+//! skipped.
+
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufReader, Cursor, Read, Seek};
+use std::path::Path;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+
+use crate::docx::{DocxTable, Paragraph, TableCell, TableRow, TextRun};
+
+/// `mimetype` entry of an OpenDocument text package
+const ODT_MIMETYPE: &str = "application/vnd.oasis.opendocument.text";
+
+/// Upper bound on `text:c` (repeated spaces) so a crafted file cannot
+/// request a multi-GB string.
+const MAX_SPACE_RUN: usize = 1024;
+
+/// Embedded image referenced by a `<draw:frame>`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OdtImage {
+    /// Frame name (`draw:name`), or `imageN` when unnamed
+    pub id: String,
+    pub filename: String,
+    /// Path inside the package, e.g. `Pictures/10000000.png`
+    pub path: String,
+    /// `svg:title`, falling back to `svg:desc`
+    pub alt_text: Option<String>,
+    /// Frame width in CSS pixels (96 dpi)
+    pub width: Option<u32>,
+    /// Frame height in CSS pixels (96 dpi)
+    pub height: Option<u32>,
+    pub data: Option<Vec<u8>>,
+}
+
+/// Document metadata from `meta.xml`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OdtMetadata {
+    pub title: Option<String>,
+    /// `meta:initial-creator`, falling back to `dc:creator`
+    pub author: Option<String>,
+    pub subject: Option<String>,
+    pub created: Option<String>,
+    pub modified: Option<String>,
+    /// `meta:editing-cycles`
+    pub revision: Option<u32>,
+    pub word_count: Option<u32>,
+    pub page_count: Option<u32>,
+}
+
+/// Complete ODT document
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OdtDocument {
+    pub paragraphs: Vec<Paragraph>,
+    pub tables: Vec<DocxTable>,
+    pub images: Vec<OdtImage>,
+    pub metadata: OdtMetadata,
+}
+
+impl OdtDocument {
+    /// Get plain text content
+    pub fn text(&self) -> String {
+        self.paragraphs.iter()
+            .map(|p| p.text())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Convert to markdown
+    pub fn to_markdown(&self) -> String {
+        self.paragraphs.iter()
+            .map(|p| p.to_markdown())
+            .filter(|md| !md.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Convert to MDX format with frontmatter
+    pub fn to_mdx(&self, source_filename: &str) -> String {
+        let mut output = String::new();
+
+        output.push_str("---\n");
+        if let Some(ref title) = self.metadata.title {
+            output.push_str(&format!("title: \"{}\"\n", title.replace('"', "\\\"")));
+        }
+        if let Some(ref author) = self.metadata.author {
+            output.push_str(&format!("author: \"{}\"\n", author.replace('"', "\\\"")));
+        }
+        output.push_str(&format!("source: \"{}\"\n", source_filename));
+        output.push_str("format: odt\n");
+        output.push_str("---\n\n");
+
+        output.push_str(&self.to_markdown());
+
+        for (i, table) in self.tables.iter().enumerate() {
+            output.push_str(&format!("\n\n<!-- Table {} -->\n", i + 1));
+            output.push_str(&table.to_markdown());
+        }
+
+        output
+    }
+}
+
+/// ODT Parser, generic over the underlying reader type (see `DocxParser`).
+pub struct OdtParser<R: Read + Seek = BufReader<File>> {
+    archive: zip::ZipArchive<R>,
+}
+
+impl OdtParser<BufReader<File>> {
+    /// Open an ODT file from disk.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path.as_ref())?;
+        OdtParser::new(BufReader::new(file))
+    }
+}
+
+impl OdtParser<Cursor<Vec<u8>>> {
+    /// Create an ODT parser from in-memory data.
+    pub fn from_bytes(data: Vec<u8>) -> io::Result<Self> {
+        OdtParser::new(Cursor::new(data))
+    }
+}
+
+impl<R: Read + Seek> OdtParser<R> {
+    fn new(reader: R) -> io::Result<Self> {
+        let archive = zip::ZipArchive::new(reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("Invalid ODT: {}", e)))?;
+        let mut parser = OdtParser { archive };
+
+        // `mimetype` is mandatory in ODF 1.2 but older writers omit it; only
+        // reject packages that positively declare another format (.ods, .odp)
+        if let Ok(mimetype) = parser.read_archive_file("mimetype") {
+            if mimetype.trim() != ODT_MIMETYPE {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Not an OpenDocument text file (mimetype: {})", mimetype.trim()),
+                ));
+            }
+        }
+        Ok(parser)
+    }
+
+    /// Parse the document
+    pub fn parse(&mut self) -> io::Result<OdtDocument> {
+        let content = self.read_archive_file("content.xml")?;
+
+        let mut styles = StyleSheet::default();
+        if let Ok(xml) = self.read_archive_file("styles.xml") {
+            styles.load(&xml);
+        }
+
+        let mut doc = parse_content(&content, styles)?;
+        for image in &mut doc.images {
+            image.data = self.read_archive_file_bytes(&image.path).ok();
+        }
+        doc.metadata = self.extract_metadata()?;
+        Ok(doc)
+    }
+
+    /// Extract metadata from meta.xml
+    pub fn extract_metadata(&mut self) -> io::Result<OdtMetadata> {
+        match self.read_archive_file("meta.xml") {
+            Ok(xml) => Ok(parse_meta(&xml)),
+            Err(_) => Ok(OdtMetadata::default()),
+        }
+    }
+
+    /// Extract text only
+    pub fn extract_text(&mut self) -> io::Result<String> {
+        Ok(self.parse()?.text())
+    }
+
+    fn read_archive_file(&mut self, name: &str) -> io::Result<String> {
+        let mut file = self.archive.by_name(name)
+            .map_err(|e| io::Error::new(io::ErrorKind::NotFound, e.to_string()))?;
+
+        let mut content = String::new();
+        file.read_to_string(&mut content)?;
+        Ok(content)
+    }
+
+    fn read_archive_file_bytes(&mut self, name: &str) -> io::Result<Vec<u8>> {
+        let mut file = self.archive.by_name(name)
+            .map_err(|e| io::Error::new(io::ErrorKind::NotFound, e.to_string()))?;
+
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)?;
+        Ok(data)
+    }
+}
+
+/// Character formatting carried by a run
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct TextFormat {
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    strike: bool,
+}
+
+/// `<style:style>` text properties; `None` = inherited from the parent
+#[derive(Debug, Clone, Default)]
+struct StyleProps {
+    parent: Option<String>,
+    bold: Option<bool>,
+    italic: Option<bool>,
+    underline: Option<bool>,
+    strike: Option<bool>,
+}
+
+impl TextFormat {
+    fn apply(mut self, props: &StyleProps) -> Self {
+        self.bold = props.bold.unwrap_or(self.bold);
+        self.italic = props.italic.unwrap_or(self.italic);
+        self.underline = props.underline.unwrap_or(self.underline);
+        self.strike = props.strike.unwrap_or(self.strike);
+        self
+    }
+}
+
+/// Named styles and numbered list levels from `styles.xml` + `content.xml`
+#[derive(Debug, Default)]
+struct StyleSheet {
+    styles: HashMap<String, StyleProps>,
+    /// `(list style name, 1-based level)` pairs that use a number format
+    numbered_levels: HashSet<(String, u32)>,
+}
+
+impl StyleSheet {
+    /// Collect every `<style:style>` and `<text:list-style>` in `xml`.
+    /// Later definitions replace earlier ones with the same name.
+    fn load(&mut self, xml: &str) {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+
+        let mut current_style: Option<String> = None;
+        let mut current_list: Option<String> = None;
+
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => match e.name().as_ref() {
+                    b"style:style" => {
+                        if let Some(name) = attr(e, b"style:name") {
+                            let props = StyleProps {
+                                parent: attr(e, b"style:parent-style-name"),
+                                ..Default::default()
+                            };
+                            self.styles.insert(name.clone(), props);
+                            current_style = Some(name);
+                        }
+                    }
+                    b"style:text-properties" => {
+                        if let Some(props) = current_style.as_ref().and_then(|n| self.styles.get_mut(n)) {
+                            read_text_properties(e, props);
+                        }
+                    }
+                    b"text:list-style" => current_list = attr(e, b"style:name"),
+                    b"text:list-level-style-number" => {
+                        let level = attr(e, b"text:level").and_then(|l| l.parse().ok());
+                        if let (Some(list), Some(level)) = (current_list.clone(), level) {
+                            self.numbered_levels.insert((list, level));
+                        }
+                    }
+                    _ => {}
+                },
+                Ok(Event::End(ref e)) => match e.name().as_ref() {
+                    b"style:style" => current_style = None,
+                    b"text:list-style" => current_list = None,
+                    _ => {}
+                },
+                Ok(Event::Eof) => break,
+                Err(_) => break,
+                _ => {}
+            }
+        }
+    }
+
+    /// Formatting of style `name` layered over `base`
+    fn format(&self, name: &str, base: TextFormat) -> TextFormat {
+        // Walk to the root, then apply root-first so children override
+        let mut chain = Vec::new();
+        let mut next = Some(name);
+        while let Some(style) = next.and_then(|n| self.styles.get(n)) {
+            if chain.len() >= 16 {
+                break; // cyclic or absurdly deep parent chain
+            }
+            chain.push(style);
+            next = style.parent.as_deref();
+        }
+        chain.iter().rev().fold(base, |format, props| format.apply(props))
+    }
+
+    fn is_numbered(&self, list_style: &str, level: u32) -> bool {
+        self.numbered_levels.contains(&(list_style.to_string(), level))
+    }
+}
+
+fn read_text_properties(e: &BytesStart, props: &mut StyleProps) {
+    for a in e.attributes().flatten() {
+        let value = a.unescape_value().unwrap_or_default();
+        match a.key.as_ref() {
+            b"fo:font-weight" => {
+                let weight = value.parse::<u32>().ok();
+                props.bold = Some(value == "bold" || weight.is_some_and(|w| w >= 600));
+            }
+            b"fo:font-style" => props.italic = Some(value == "italic" || value == "oblique"),
+            b"style:text-underline-style" => props.underline = Some(value != "none"),
+            b"style:text-line-through-style" => props.strike = Some(value != "none"),
+            _ => {}
+        }
+    }
+}
+
+/// Unescaped value of attribute `name` (qualified, e.g. `b"text:style-name"`)
+fn attr(e: &BytesStart, name: &[u8]) -> Option<String> {
+    e.attributes()
+        .flatten()
+        .find(|a| a.key.as_ref() == name)
+        .map(|a| a.unescape_value().map(|v| v.into_owned()).unwrap_or_default())
+}
+
+/// ODF length (`"2.5cm"`, `"1in"`, `"72pt"`) in CSS pixels at 96 dpi
+fn length_to_px(value: &str) -> Option<u32> {
+    let value = value.trim();
+    let split = value.find(|c: char| c.is_ascii_alphabetic())?;
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number.trim().parse().ok()?;
+    let per_unit = match unit {
+        "in" => 96.0,
+        "cm" => 96.0 / 2.54,
+        "mm" => 96.0 / 25.4,
+        "pt" => 96.0 / 72.0,
+        "pc" => 16.0,
+        "px" => 1.0,
+        _ => return None,
+    };
+    let px = (number * per_unit).round();
+    (px >= 0.0 && px <= u32::MAX as f64).then_some(px as u32)
+}
+
+/// A `<text:p>` / `<text:h>` being filled
+struct OpenParagraph {
+    para: Paragraph,
+    /// Text of the run being built
+    text: String,
+    /// Format stack: paragraph style at the bottom, one entry per open span
+    formats: Vec<TextFormat>,
+    /// Whether the last emitted character was whitespace (ODF collapses
+    /// whitespace runs and drops leading whitespace)
+    after_space: bool,
+}
+
+impl OpenParagraph {
+    fn format(&self) -> TextFormat {
+        self.formats.last().copied().unwrap_or_default()
+    }
+
+    fn flush_run(&mut self) {
+        if self.text.is_empty() {
+            return;
+        }
+        let f = self.format();
+        self.para.runs.push(TextRun {
+            text: std::mem::take(&mut self.text),
+            bold: f.bold,
+            italic: f.italic,
+            underline: f.underline,
+            strike: f.strike,
+            ..Default::default()
+        });
+    }
+
+    /// Append character data, collapsing whitespace as ODF requires
+    fn push_text(&mut self, text: &str) {
+        for c in text.chars() {
+            if matches!(c, ' ' | '\t' | '\n' | '\r') {
+                if !self.after_space {
+                    self.text.push(' ');
+                    self.after_space = true;
+                }
+            } else {
+                self.text.push(c);
+                self.after_space = false;
+            }
+        }
+    }
+
+    /// Append literal text from `<text:s>`, `<text:tab>`, `<text:line-break>`
+    fn push_literal(&mut self, text: &str) {
+        self.text.push_str(text);
+        self.after_space = true;
+    }
+
+    /// Append a pre-rendered markdown fragment (image) as its own run
+    fn push_markdown(&mut self, markdown: String) {
+        self.flush_run();
+        self.para.runs.push(TextRun { text: markdown, ..Default::default() });
+        self.after_space = false;
+    }
+
+    fn finish(mut self) -> Paragraph {
+        self.flush_run();
+        // Trailing whitespace survives collapsing; drop it
+        while let Some(last) = self.para.runs.last_mut() {
+            let trimmed = last.text.trim_end_matches(' ').len();
+            last.text.truncate(trimmed);
+            if !last.text.is_empty() {
+                break;
+            }
+            self.para.runs.pop();
+        }
+        self.para
+    }
+}
+
+/// A `<text:list>` level
+struct OpenList {
+    style: Option<String>,
+    /// Items seen so far at this level
+    ordinal: u32,
+}
+
+/// A `<table:table>` being filled
+#[derive(Default)]
+struct OpenTable {
+    rows: Vec<TableRow>,
+    in_header: bool,
+    header_rows: usize,
+}
+
+impl OpenTable {
+    fn current_cell(&mut self) -> Option<&mut TableCell> {
+        self.rows.last_mut().and_then(|row| row.cells.last_mut())
+    }
+
+    fn into_table(self) -> DocxTable {
+        DocxTable {
+            rows: self.rows,
+            has_header: self.header_rows > 0,
+            column_widths_twips: Vec::new(),
+        }
+    }
+}
+
+/// A `<draw:frame>` being filled
+#[derive(Default)]
+struct OpenFrame {
+    name: Option<String>,
+    width: Option<u32>,
+    height: Option<u32>,
+    href: Option<String>,
+    title: String,
+    desc: String,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Capture {
+    Title,
+    Desc,
+}
+
+/// Streaming state for one `content.xml` pass
+struct ContentBuilder {
+    styles: StyleSheet,
+    paragraphs: Vec<Paragraph>,
+    tables: Vec<DocxTable>,
+    images: Vec<OdtImage>,
+    /// Nested paragraphs (text boxes inside frames) stack up here
+    open: Vec<OpenParagraph>,
+    lists: Vec<OpenList>,
+    /// The next paragraph is the first one of a `<text:list-item>`
+    pending_list_item: bool,
+    table_stack: Vec<OpenTable>,
+    frame: Option<OpenFrame>,
+    capture: Option<Capture>,
+    /// Depth inside a skipped subtree (annotations, notes, tracked changes)
+    skip_depth: usize,
+}
+
+impl ContentBuilder {
+    fn new(styles: StyleSheet) -> Self {
+        ContentBuilder {
+            styles,
+            paragraphs: Vec::new(),
+            tables: Vec::new(),
+            images: Vec::new(),
+            open: Vec::new(),
+            lists: Vec::new(),
+            pending_list_item: false,
+            table_stack: Vec::new(),
+            frame: None,
+            capture: None,
+            skip_depth: 0,
+        }
+    }
+
+    fn start(&mut self, e: &BytesStart, empty: bool) {
+        if self.skip_depth > 0 {
+            if !empty {
+                self.skip_depth += 1;
+            }
+            return;
+        }
+
+        match e.name().as_ref() {
+            b"office:annotation" | b"text:note" | b"text:tracked-changes" if !empty => {
+                self.skip_depth = 1;
+            }
+            name @ (b"text:p" | b"text:h") if !empty => {
+                let heading = name == b"text:h";
+                self.open_paragraph(e, heading);
+            }
+            b"text:span" if !empty => {
+                let style = attr(e, b"text:style-name");
+                if let Some(p) = self.open.last_mut() {
+                    p.flush_run();
+                    let base = p.format();
+                    let format = style.map_or(base, |s| self.styles.format(&s, base));
+                    p.formats.push(format);
+                }
+            }
+            b"text:s" => {
+                let count = attr(e, b"text:c")
+                    .and_then(|c| c.parse::<usize>().ok())
+                    .unwrap_or(1)
+                    .min(MAX_SPACE_RUN);
+                if let Some(p) = self.open.last_mut() {
+                    p.push_literal(&" ".repeat(count));
+                }
+            }
+            b"text:tab" => {
+                if let Some(p) = self.open.last_mut() {
+                    p.push_literal("\t");
+                }
+            }
+            b"text:line-break" => {
+                if let Some(p) = self.open.last_mut() {
+                    p.push_literal("\n");
+                }
+            }
+            b"text:list" if !empty => {
+                // Nested lists inherit the outer list's style
+                let style = attr(e, b"text:style-name")
+                    .or_else(|| self.lists.last().and_then(|l| l.style.clone()));
+                self.lists.push(OpenList { style, ordinal: 0 });
+            }
+            b"text:list-item" => {
+                if let Some(list) = self.lists.last_mut() {
+                    list.ordinal += 1;
+                }
+                self.pending_list_item = !empty;
+            }
+            b"table:table" if !empty => self.table_stack.push(OpenTable::default()),
+            b"table:table-header-rows" if !empty => {
+                if let Some(table) = self.table_stack.last_mut() {
+                    table.in_header = true;
+                }
+            }
+            b"table:table-row" => {
+                if let Some(table) = self.table_stack.last_mut() {
+                    table.rows.push(TableRow { height_twips: None, cells: Vec::new() });
+                    if table.in_header {
+                        table.header_rows += 1;
+                    }
+                }
+            }
+            b"table:table-cell" => {
+                let span = |name: &[u8]| attr(e, name).and_then(|v| v.parse().ok()).unwrap_or(1);
+                let cell = TableCell {
+                    col_span: span(b"table:number-columns-spanned"),
+                    row_span: span(b"table:number-rows-spanned"),
+                    ..Default::default()
+                };
+                if let Some(row) = self.table_stack.last_mut().and_then(|t| t.rows.last_mut()) {
+                    row.cells.push(cell);
+                }
+            }
+            b"table:covered-table-cell" => {
+                // Keeps the column count right; renders as an empty cell
+                let cell = TableCell { v_merge_continue: true, ..Default::default() };
+                if let Some(row) = self.table_stack.last_mut().and_then(|t| t.rows.last_mut()) {
+                    row.cells.push(cell);
+                }
+            }
+            b"draw:frame" if !empty => {
+                self.frame = Some(OpenFrame {
+                    name: attr(e, b"draw:name"),
+                    width: attr(e, b"svg:width").and_then(|w| length_to_px(&w)),
+                    height: attr(e, b"svg:height").and_then(|h| length_to_px(&h)),
+                    ..Default::default()
+                });
+            }
+            b"draw:image" => {
+                // The first image wins; later ones are fallbacks for the same frame
+                if let Some(frame) = self.frame.as_mut() {
+                    if frame.href.is_none() {
+                        frame.href = attr(e, b"xlink:href");
+                    }
+                }
+            }
+            b"svg:title" if !empty && self.frame.is_some() => self.capture = Some(Capture::Title),
+            b"svg:desc" if !empty && self.frame.is_some() => self.capture = Some(Capture::Desc),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, name: &[u8]) {
+        if self.skip_depth > 0 {
+            self.skip_depth -= 1;
+            return;
+        }
+
+        match name {
+            b"text:p" | b"text:h" => {
+                if let Some(p) = self.open.pop() {
+                    let para = p.finish();
+                    self.emit_paragraph(para);
+                }
+            }
+            b"text:span" => {
+                if let Some(p) = self.open.last_mut() {
+                    if p.formats.len() > 1 {
+                        p.flush_run();
+                        p.formats.pop();
+                    }
+                }
+            }
+            b"text:list" => {
+                self.lists.pop();
+                self.pending_list_item = false;
+            }
+            b"text:list-item" => self.pending_list_item = false,
+            b"table:table-header-rows" => {
+                if let Some(table) = self.table_stack.last_mut() {
+                    table.in_header = false;
+                }
+            }
+            b"table:table" => {
+                if let Some(table) = self.table_stack.pop() {
+                    self.emit_table(table.into_table());
+                }
+            }
+            b"draw:frame" => {
+                if let Some(frame) = self.frame.take() {
+                    self.emit_frame(frame);
+                }
+            }
+            b"svg:title" | b"svg:desc" => self.capture = None,
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.skip_depth > 0 {
+            return;
+        }
+        if let (Some(capture), Some(frame)) = (self.capture, self.frame.as_mut()) {
+            match capture {
+                Capture::Title => frame.title.push_str(text),
+                Capture::Desc => frame.desc.push_str(text),
+            }
+        } else if let Some(p) = self.open.last_mut() {
+            p.push_text(text);
+        }
+    }
+
+    fn open_paragraph(&mut self, e: &BytesStart, heading: bool) {
+        let style = attr(e, b"text:style-name");
+        let mut para = Paragraph { style: style.clone(), ..Default::default() };
+
+        let base = if heading {
+            // Heading styles are usually bold; `#` already says so
+            let level: u32 = attr(e, b"text:outline-level")
+                .and_then(|l| l.parse().ok())
+                .unwrap_or(1);
+            para.outline_level = Some(level.max(1) - 1);
+            TextFormat::default()
+        } else {
+            style.map_or_else(TextFormat::default, |s| self.styles.format(&s, TextFormat::default()))
+        };
+
+        if std::mem::take(&mut self.pending_list_item) && !heading {
+            if let Some(list) = self.lists.last() {
+                let depth = self.lists.len() as u32;
+                let numbered = list
+                    .style
+                    .as_deref()
+                    .is_some_and(|s| self.styles.is_numbered(s, depth));
+                para.is_list_item = true;
+                para.indent_level = depth - 1;
+                para.list_type = Some(if numbered { "number" } else { "bullet" }.to_string());
+                para.list_ordinal = list.ordinal;
+            }
+        }
+
+        self.open.push(OpenParagraph {
+            para,
+            text: String::new(),
+            formats: vec![base],
+            after_space: true,
+        });
+    }
+
+    /// Route a finished paragraph to the enclosing table cell or the body
+    fn emit_paragraph(&mut self, para: Paragraph) {
+        if para.runs.is_empty() {
+            return;
+        }
+        if let Some(table) = self.table_stack.last_mut() {
+            let text: String = para.runs.iter().map(|r| r.to_markdown()).collect();
+            if let Some(cell) = table.current_cell() {
+                if !cell.content.is_empty() {
+                    cell.content.push('\n');
+                }
+                cell.content.push_str(&text);
+            }
+        } else {
+            self.paragraphs.push(para);
+        }
+    }
+
+    /// Top-level tables are kept; nested tables are flattened into the
+    /// enclosing cell's text since GFM cannot nest tables.
+    fn emit_table(&mut self, table: DocxTable) {
+        match self.table_stack.last_mut() {
+            Some(outer) => {
+                let text = table.rows.iter()
+                    .flat_map(|row| row.cells.iter())
+                    .map(|cell| cell.content.as_str())
+                    .filter(|content| !content.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                if let Some(cell) = outer.current_cell() {
+                    if !cell.content.is_empty() && !text.is_empty() {
+                        cell.content.push('\n');
+                    }
+                    cell.content.push_str(&text);
+                }
+            }
+            None => self.tables.push(table),
+        }
+    }
+
+    fn emit_frame(&mut self, frame: OpenFrame) {
+        let Some(href) = frame.href else {
+            return; // text box or embedded object without a preview image
+        };
+        let path = href.strip_prefix("./").unwrap_or(&href).to_string();
+        let filename = path.rsplit('/').next().unwrap_or(&path).to_string();
+        let id = frame.name.unwrap_or_else(|| format!("image{}", self.images.len() + 1));
+        let alt_text = [frame.title, frame.desc]
+            .into_iter()
+            .map(|t| t.trim().to_string())
+            .find(|t| !t.is_empty());
+
+        let alt = alt_text.as_deref().unwrap_or(&id).replace('[', "\\[").replace(']', "\\]");
+        let markdown = format!("![{}](assets/{})", alt, filename);
+
+        self.images.push(OdtImage {
+            id,
+            filename,
+            path,
+            alt_text,
+            width: frame.width,
+            height: frame.height,
+            data: None,
+        });
+
+        match self.open.last_mut() {
+            Some(p) => p.push_markdown(markdown),
+            None => {
+                // Page-anchored frame outside any paragraph
+                let para = Paragraph {
+                    runs: vec![TextRun { text: markdown, ..Default::default() }],
+                    ..Default::default()
+                };
+                self.emit_paragraph(para);
+            }
+        }
+    }
+}
+
+/// Parse `content.xml` into paragraphs, tables and image references.
+/// Automatic styles in `xml` are merged into `styles` first. Metadata and
+/// image bytes are filled in by [`OdtParser::parse`].
+fn parse_content(xml: &str, mut styles: StyleSheet) -> io::Result<OdtDocument> {
+    styles.load(xml);
+    let mut builder = ContentBuilder::new(styles);
+
+    // Whitespace is significant between spans; ODF collapsing is done in
+    // `OpenParagraph::push_text` instead
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(false);
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) => builder.start(e, false),
+            Ok(Event::Empty(ref e)) => builder.start(e, true),
+            Ok(Event::End(ref e)) => builder.end(e.name().as_ref()),
+            Ok(Event::Text(ref e)) => {
+                let text = e.unescape().unwrap_or_default();
+                builder.text(&text);
+            }
+            Ok(Event::CData(ref e)) => {
+                let text = String::from_utf8_lossy(e).into_owned();
+                builder.text(&text);
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Invalid content.xml: {}", e),
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    Ok(OdtDocument {
+        paragraphs: builder.paragraphs,
+        tables: builder.tables,
+        images: builder.images,
+        metadata: OdtMetadata::default(),
+    })
+}
+
+/// Parse `meta.xml`
+fn parse_meta(xml: &str) -> OdtMetadata {
+    let mut metadata = OdtMetadata::default();
+    let mut creator = None;
+
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+    let mut current_element = Vec::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) => current_element = e.name().as_ref().to_vec(),
+            Ok(Event::Empty(ref e)) => {
+                if e.name().as_ref() == b"meta:document-statistic" {
+                    metadata.page_count = attr(e, b"meta:page-count").and_then(|v| v.parse().ok());
+                    metadata.word_count = attr(e, b"meta:word-count").and_then(|v| v.parse().ok());
+                }
+            }
+            Ok(Event::Text(ref e)) => {
+                let text = e.unescape().unwrap_or_default().to_string();
+                match current_element.as_slice() {
+                    b"dc:title" => metadata.title = Some(text),
+                    b"dc:subject" => metadata.subject = Some(text),
+                    b"meta:initial-creator" => metadata.author = Some(text),
+                    b"dc:creator" => creator = Some(text),
+                    b"meta:creation-date" => metadata.created = Some(text),
+                    b"dc:date" => metadata.modified = Some(text),
+                    b"meta:editing-cycles" => metadata.revision = text.parse().ok(),
+                    _ => {}
+                }
+            }
+            Ok(Event::End(_)) => current_element.clear(),
+            Ok(Event::Eof) => break,
+            Err(_) => break,
+            _ => {}
+        }
+    }
+
+    if metadata.author.is_none() {
+        metadata.author = creator;
+    }
+    metadata
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    /// Wrap `body` (the children of `<office:text>`) in a `content.xml`
+    /// with the given automatic styles.
+    fn content_xml(styles: &str, body: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" xmlns:draw="urn:oasis:names:tc:opendocument:xmlns:drawing:1.0" xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0" xmlns:xlink="http://www.w3.org/1999/xlink" xmlns:svg="urn:oasis:names:tc:opendocument:xmlns:svg-compatible:1.0">
+<office:automatic-styles>{}</office:automatic-styles>
+<office:body><office:text>{}</office:text></office:body>
+</office:document-content>"#,
+            styles, body
+        )
+    }
+
+    fn parse(styles: &str, body: &str) -> OdtDocument {
+        parse_content(&content_xml(styles, body), StyleSheet::default()).expect("parse")
+    }
+
+    const SPAN_STYLES: &str = r#"
+<style:style style:name="T1" style:family="text"><style:text-properties fo:font-weight="bold"/></style:style>
+<style:style style:name="T2" style:family="text"><style:text-properties fo:font-style="italic"/></style:style>
+<style:style style:name="T3" style:family="text"><style:text-properties style:text-line-through-style="solid"/></style:style>"#;
+
+    #[test]
+    fn test_plain_paragraphs() {
+        let doc = parse("", "<text:p>첫 문단</text:p><text:p>Second</text:p><text:p/>");
+        assert_eq!(doc.paragraphs.len(), 2);
+        assert_eq!(doc.text(), "첫 문단\nSecond");
+        assert_eq!(doc.to_markdown(), "첫 문단\n\nSecond");
+    }
+
+    #[test]
+    fn test_whitespace_collapsing() {
+        let doc = parse("", "<text:p>\n  one   two\n  three  </text:p>");
+        assert_eq!(doc.text(), "one two three");
+    }
+
+    #[test]
+    fn test_explicit_spaces_tabs_and_line_breaks() {
+        let doc = parse("", r#"<text:p>a<text:s text:c="3"/>b<text:tab/>c<text:line-break/>d<text:s/>e</text:p>"#);
+        assert_eq!(doc.text(), "a   b\tc\nd e");
+    }
+
+    #[test]
+    fn test_bold_span() {
+        let doc = parse(SPAN_STYLES, r#"<text:p>plain <text:span text:style-name="T1">bold</text:span> tail</text:p>"#);
+        let runs = &doc.paragraphs[0].runs;
+        assert_eq!(runs.len(), 3);
+        assert!(!runs[0].bold);
+        assert!(runs[1].bold);
+        assert_eq!(runs[1].text, "bold");
+        assert_eq!(doc.to_markdown(), "plain **bold** tail");
+    }
+
+    #[test]
+    fn test_italic_and_strike_spans() {
+        let doc = parse(
+            SPAN_STYLES,
+            r#"<text:p><text:span text:style-name="T2">it</text:span><text:span text:style-name="T3">gone</text:span></text:p>"#,
+        );
+        let runs = &doc.paragraphs[0].runs;
+        assert!(runs[0].italic && !runs[0].bold);
+        assert!(runs[1].strike);
+        assert_eq!(doc.to_markdown(), "*it*~~gone~~");
+    }
+
+    #[test]
+    fn test_nested_spans_combine_formatting() {
+        let doc = parse(
+            SPAN_STYLES,
+            r#"<text:p><text:span text:style-name="T1">b <text:span text:style-name="T2">bi</text:span></text:span></text:p>"#,
+        );
+        let runs = &doc.paragraphs[0].runs;
+        assert_eq!(runs.len(), 2);
+        assert!(runs[0].bold && !runs[0].italic);
+        assert!(runs[1].bold && runs[1].italic);
+    }
+
+    #[test]
+    fn test_numeric_font_weight_and_explicit_normal() {
+        let styles = r#"
+<style:style style:name="Heavy" style:family="text"><style:text-properties fo:font-weight="700"/></style:style>
+<style:style style:name="Light" style:family="text"><style:text-properties fo:font-weight="normal"/></style:style>"#;
+        let doc = parse(
+            styles,
+            r#"<text:p><text:span text:style-name="Heavy">x<text:span text:style-name="Light">y</text:span></text:span></text:p>"#,
+        );
+        let runs = &doc.paragraphs[0].runs;
+        assert!(runs[0].bold);
+        assert!(!runs[1].bold);
+    }
+
+    #[test]
+    fn test_paragraph_style_inherits_from_parent() {
+        let mut sheet = StyleSheet::default();
+        sheet.load(r#"<office:document-styles><office:styles>
+<style:style style:name="Strong_20_Body" style:family="paragraph"><style:text-properties fo:font-weight="bold"/></style:style>
+</office:styles></office:document-styles>"#);
+        let xml = content_xml(
+            r#"<style:style style:name="P1" style:family="paragraph" style:parent-style-name="Strong_20_Body"/>"#,
+            r#"<text:p text:style-name="P1">상속</text:p>"#,
+        );
+        let doc = parse_content(&xml, sheet).unwrap();
+        assert!(doc.paragraphs[0].runs[0].bold);
+        assert_eq!(doc.paragraphs[0].style.as_deref(), Some("P1"));
+    }
+
+    #[test]
+    fn test_heading_outline_levels() {
+        let doc = parse(
+            "",
+            r#"<text:h text:outline-level="2">개요</text:h><text:h>Title</text:h><text:p>body</text:p>"#,
+        );
+        assert_eq!(doc.paragraphs[0].outline_level, Some(1));
+        assert_eq!(doc.paragraphs[1].outline_level, Some(0));
+        assert_eq!(doc.to_markdown(), "## 개요\n\n# Title\n\nbody");
+    }
+
+    #[test]
+    fn test_bullet_list() {
+        let doc = parse(
+            "",
+            "<text:list><text:list-item><text:p>one</text:p></text:list-item><text:list-item><text:p>two</text:p></text:list-item></text:list>",
+        );
+        assert!(doc.paragraphs.iter().all(|p| p.is_list_item));
+        assert_eq!(doc.to_markdown(), "- one\n\n- two");
+    }
+
+    #[test]
+    fn test_numbered_list_from_list_style() {
+        let styles = r#"<text:list-style style:name="L1"><text:list-level-style-number text:level="1" style:num-format="1"/><text:list-level-style-bullet text:level="2" text:bullet-char="•"/></text:list-style>"#;
+        let doc = parse(
+            styles,
+            r#"<text:list text:style-name="L1"><text:list-item><text:p>first</text:p></text:list-item><text:list-item><text:p>second</text:p><text:list><text:list-item><text:p>nested</text:p></text:list-item></text:list></text:list-item></text:list>"#,
+        );
+        assert_eq!(doc.paragraphs[1].list_ordinal, 2);
+        assert_eq!(doc.paragraphs[2].indent_level, 1);
+        assert_eq!(doc.to_markdown(), "1. first\n\n2. second\n\n  - nested");
+    }
+
+    #[test]
+    fn test_list_item_continuation_paragraph_is_not_a_new_item() {
+        let doc = parse(
+            "",
+            "<text:list><text:list-item><text:p>item</text:p><text:p>more</text:p></text:list-item></text:list>",
+        );
+        assert!(doc.paragraphs[0].is_list_item);
+        assert!(!doc.paragraphs[1].is_list_item);
+    }
+
+    #[test]
+    fn test_table_cells_and_header_rows() {
+        let doc = parse(
+            "",
+            r#"<text:p>before</text:p><table:table table:name="T"><table:table-column table:number-columns-repeated="2"/>
+<table:table-header-rows><table:table-row><table:table-cell><text:p>이름</text:p></table:table-cell><table:table-cell><text:p>값</text:p></table:table-cell></table:table-row></table:table-header-rows>
+<table:table-row><table:table-cell><text:p>a|b</text:p></table:table-cell><table:table-cell><text:p>1</text:p><text:p>2</text:p></table:table-cell></table:table-row>
+</table:table>"#,
+        );
+        // Cell paragraphs stay out of the body
+        assert_eq!(doc.paragraphs.len(), 1);
+        assert_eq!(doc.tables.len(), 1);
+        let table = &doc.tables[0];
+        assert!(table.has_header);
+        assert_eq!(table.rows[1].cells[1].content, "1\n2");
+        assert_eq!(table.to_markdown(), "| 이름 | 값 |\n| --- | --- |\n| a\\|b | 1 2 |");
+    }
+
+    #[test]
+    fn test_table_spans_and_covered_cells() {
+        let doc = parse(
+            "",
+            r#"<table:table><table:table-row><table:table-cell table:number-columns-spanned="2"><text:p>wide</text:p></table:table-cell><table:covered-table-cell/></table:table-row>
+<table:table-row><table:table-cell><text:p>x</text:p></table:table-cell><table:table-cell/></table:table-row></table:table>"#,
+        );
+        let table = &doc.tables[0];
+        assert!(!table.has_header);
+        assert_eq!(table.rows[0].cells[0].col_span, 2);
+        assert!(table.rows[0].cells[1].v_merge_continue);
+        assert_eq!(table.rows[1].cells.len(), 2);
+        assert_eq!(table.to_markdown(), "| wide |  |\n| --- | --- |\n| x |  |");
+    }
+
+    #[test]
+    fn test_nested_table_is_flattened_into_cell() {
+        let doc = parse(
+            "",
+            r#"<table:table><table:table-row><table:table-cell><text:p>outer</text:p>
+<table:table><table:table-row><table:table-cell><text:p>in1</text:p></table:table-cell><table:table-cell><text:p>in2</text:p></table:table-cell></table:table-row></table:table>
+</table:table-cell></table:table-row></table:table>"#,
+        );
+        assert_eq!(doc.tables.len(), 1);
+        assert_eq!(doc.tables[0].rows[0].cells[0].content, "outer\nin1 in2");
+    }
+
+    #[test]
+    fn test_image_frame() {
+        let doc = parse(
+            "",
+            r#"<text:p>see <draw:frame draw:name="Chart [1]" svg:width="2.54cm" svg:height="1in"><draw:image xlink:href="Pictures/chart.png"/><svg:title>매출 [2024]</svg:title></draw:frame></text:p>"#,
+        );
+        assert_eq!(doc.images.len(), 1);
+        let image = &doc.images[0];
+        assert_eq!(image.id, "Chart [1]");
+        assert_eq!(image.path, "Pictures/chart.png");
+        assert_eq!(image.filename, "chart.png");
+        assert_eq!(image.alt_text.as_deref(), Some("매출 [2024]"));
+        assert_eq!((image.width, image.height), (Some(96), Some(96)));
+        assert_eq!(doc.to_markdown(), "see ![매출 \\[2024\\]](assets/chart.png)");
+    }
+
+    #[test]
+    fn test_unnamed_image_and_text_box_frame() {
+        let doc = parse(
+            "",
+            r#"<text:p><draw:frame><draw:image xlink:href="./Pictures/a.jpg"><svg:desc>설명</svg:desc></draw:image></draw:frame></text:p>
+<text:p><draw:frame><draw:text-box><text:p>boxed</text:p></draw:text-box></draw:frame></text:p>"#,
+        );
+        assert_eq!(doc.images.len(), 1);
+        assert_eq!(doc.images[0].id, "image1");
+        assert_eq!(doc.images[0].path, "Pictures/a.jpg");
+        assert_eq!(doc.images[0].alt_text.as_deref(), Some("설명"));
+        assert_eq!(doc.to_markdown(), "![설명](assets/a.jpg)\n\nboxed");
+    }
+
+    #[test]
+    fn test_annotations_and_notes_are_skipped() {
+        let doc = parse(
+            "",
+            r#"<text:p>text<office:annotation><text:p>comment</text:p></office:annotation><text:note text:note-class="footnote"><text:note-citation>1</text:note-citation><text:note-body><text:p>note</text:p></text:note-body></text:note> end</text:p>"#,
+        );
+        assert_eq!(doc.paragraphs.len(), 1);
+        assert_eq!(doc.text(), "text end");
+    }
+
+    #[test]
+    fn test_length_to_px() {
+        assert_eq!(length_to_px("1in"), Some(96));
+        assert_eq!(length_to_px("2.54cm"), Some(96));
+        assert_eq!(length_to_px("25.4mm"), Some(96));
+        assert_eq!(length_to_px("72pt"), Some(96));
+        assert_eq!(length_to_px("10px"), Some(10));
+        assert_eq!(length_to_px("50%"), None);
+        assert_eq!(length_to_px("12"), None);
+    }
+
+    #[test]
+    fn test_package_round_trip() {
+        let meta = r#"<office:document-meta xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:meta="urn:oasis:names:tc:opendocument:xmlns:meta:1.0" xmlns:dc="http://purl.org/dc/elements/1.1/"><office:meta>
+<dc:title>보고서</dc:title><dc:creator>Editor</dc:creator><meta:initial-creator>홍길동</meta:initial-creator>
+<meta:editing-cycles>3</meta:editing-cycles><meta:document-statistic meta:page-count="2" meta:word-count="42"/>
+</office:meta></office:document-meta>"#;
+        let content = content_xml(
+            "",
+            r#"<text:h text:outline-level="1">제목</text:h><text:p><draw:frame svg:width="1in" svg:height="1in"><draw:image xlink:href="Pictures/p.png"/></draw:frame></text:p>"#,
+        );
+
+        let mut buf = Vec::new();
+        {
+            let mut zw = zip::ZipWriter::new(Cursor::new(&mut buf));
+            let stored = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+            for (name, data) in [
+                ("mimetype", ODT_MIMETYPE.as_bytes()),
+                ("content.xml", content.as_bytes()),
+                ("meta.xml", meta.as_bytes()),
+                ("Pictures/p.png", &[0x89, b'P', b'N', b'G'][..]),
+            ] {
+                zw.start_file(name, stored).unwrap();
+                zw.write_all(data).unwrap();
+            }
+            zw.finish().unwrap();
+        }
+
+        let doc = OdtParser::from_bytes(buf).unwrap().parse().unwrap();
+        assert_eq!(doc.metadata.title.as_deref(), Some("보고서"));
+        assert_eq!(doc.metadata.author.as_deref(), Some("홍길동"));
+        assert_eq!(doc.metadata.revision, Some(3));
+        assert_eq!(doc.metadata.page_count, Some(2));
+        assert_eq!(doc.metadata.word_count, Some(42));
+        assert_eq!(doc.images[0].data.as_deref(), Some(&[0x89, b'P', b'N', b'G'][..]));
+
+        let mdx = doc.to_mdx("report.odt");
+        assert!(mdx.starts_with("---\ntitle: \"보고서\"\nauthor: \"홍길동\"\nsource: \"report.odt\"\nformat: odt\n---\n\n# 제목"));
+    }
+
+    #[test]
+    fn test_rejects_other_opendocument_types() {
+        let mut buf = Vec::new();
+        {
+            let mut zw = zip::ZipWriter::new(Cursor::new(&mut buf));
+            zw.start_file("mimetype", SimpleFileOptions::default()).unwrap();
+            zw.write_all(b"application/vnd.oasis.opendocument.spreadsheet").unwrap();
+            zw.finish().unwrap();
+        }
+        let err = OdtParser::from_bytes(buf).err().expect("spreadsheet rejected");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
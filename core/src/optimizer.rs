@@ -15,15 +15,21 @@
 //! - JPEG: Quality adjustment, progressive encoding
 //! - PNG: Compression level, interlacing
 //! - WebP: Lossy/lossless encoding, quality control
-//! - GIF: Color palette optimization
+//! - AVIF: Lossy AV1 encoding via `ravif` (image's `avif` codec)
+//! - GIF: Color palette optimization, APNG conversion
 //!
 //! This module is designed to work independently from the main pipeline,
 //! allowing parallel development with the orchestrator (1.7).
 
-use image::{DynamicImage, ImageFormat, GenericImageView, ImageEncoder};
-use image::codecs::png::PngEncoder;
+use image::{AnimationDecoder, DynamicImage, ImageFormat, GenericImageView, ImageEncoder};
+use image::codecs::gif::GifDecoder;
+use image::codecs::png::{CompressionType, FilterType as PngFilterType, PngEncoder};
 use image::codecs::jpeg::JpegEncoder;
-use std::io::Cursor;
+use image::codecs::avif::AvifEncoder;
+use resvg::{tiny_skia, usvg};
+use std::io::{self, Cursor};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
 use thiserror::Error;
 
 /// Optimization errors
@@ -46,6 +52,9 @@ pub enum ImageType {
     Png,
     WebP,
     Gif,
+    Avif,
+    /// PNG with an `acTL` chunk (APNG)
+    AnimatedPng,
     Unknown,
 }
 
@@ -60,11 +69,18 @@ impl ImageType {
         if &data[0..2] == b"\xFF\xD8" {
             ImageType::Jpeg
         } else if &data[0..4] == b"\x89PNG" {
-            ImageType::Png
+            if is_animated_png(data) {
+                ImageType::AnimatedPng
+            } else {
+                ImageType::Png
+            }
         } else if &data[0..4] == b"RIFF" && data.len() >= 12 && &data[8..12] == b"WEBP" {
             ImageType::WebP
         } else if &data[0..4] == b"GIF8" {
             ImageType::Gif
+        } else if data.len() >= 12 && &data[4..8] == b"ftyp" && matches!(&data[8..12], b"avif" | b"avis") {
+            // ISO-BMFF `ftyp` box at offset 4 with an AVIF major brand
+            ImageType::Avif
         } else {
             ImageType::Unknown
         }
@@ -77,6 +93,8 @@ impl ImageType {
             "png" => ImageType::Png,
             "webp" => ImageType::WebP,
             "gif" => ImageType::Gif,
+            "avif" => ImageType::Avif,
+            "apng" => ImageType::AnimatedPng,
             _ => ImageType::Unknown,
         }
     }
@@ -88,6 +106,8 @@ impl ImageType {
             ImageType::Png => "png",
             ImageType::WebP => "webp",
             ImageType::Gif => "gif",
+            ImageType::Avif => "avif",
+            ImageType::AnimatedPng => "png",
             ImageType::Unknown => "bin",
         }
     }
@@ -99,6 +119,8 @@ impl ImageType {
             ImageType::Png => "image/png",
             ImageType::WebP => "image/webp",
             ImageType::Gif => "image/gif",
+            ImageType::Avif => "image/avif",
+            ImageType::AnimatedPng => "image/apng",
             ImageType::Unknown => "application/octet-stream",
         }
     }
@@ -109,33 +131,101 @@ impl ImageType {
 pub struct OptimizeSettings {
     /// JPEG quality (1-100)
     pub jpeg_quality: u8,
+    /// Emit progressive JPEG (needs the `mozjpeg` feature; baseline otherwise)
+    pub jpeg_progressive: bool,
     /// PNG compression level (1-9, where 9 is maximum compression)
     pub png_compression: u8,
     /// WebP quality (1-100, 0 for lossless)
     pub webp_quality: u8,
     /// WebP lossless mode
     pub webp_lossless: bool,
+    /// AVIF quality (1-100)
+    pub avif_quality: u8,
     /// Maximum dimension (resize if larger)
     pub max_dimension: Option<u32>,
     /// Strip metadata (EXIF, etc.)
     pub strip_metadata: bool,
-    /// Convert to WebP if smaller
+    /// Convert to WebP (or AVIF) if smaller
     pub prefer_webp: bool,
     /// Minimum file size reduction to accept optimization (0.0-1.0)
     pub min_reduction: f32,
+    /// Text drawn over the image before encoding
+    pub watermark: Option<WatermarkConfig>,
+}
+
+/// Corner (or center) a watermark is anchored to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WatermarkPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+    Center,
+}
+
+impl std::str::FromStr for WatermarkPosition {
+    type Err = String;
+
+    /// Kebab-case names as used by the CLI, e.g. `bottom-right`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "top-left" => Ok(Self::TopLeft),
+            "top-right" => Ok(Self::TopRight),
+            "bottom-left" => Ok(Self::BottomLeft),
+            "bottom-right" => Ok(Self::BottomRight),
+            "center" => Ok(Self::Center),
+            other => Err(format!(
+                "unknown watermark position '{}' (top-left, top-right, bottom-left, bottom-right, center)",
+                other
+            )),
+        }
+    }
 }
 
+/// Text watermark for [`Optimizer::apply_watermark_overlay`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatermarkConfig {
+    pub text: String,
+    /// Overall opacity (0.0-1.0), multiplied with the alpha of `color`
+    pub opacity: f32,
+    pub position: WatermarkPosition,
+    /// Font size in pixels
+    pub font_size: u32,
+    /// RGBA text color
+    pub color: [u8; 4],
+}
+
+impl WatermarkConfig {
+    /// Semi-transparent white 24px text in the bottom-right corner
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            opacity: 0.5,
+            position: WatermarkPosition::BottomRight,
+            font_size: 24,
+            color: [255, 255, 255, 255],
+        }
+    }
+}
+
+/// Default AVIF quality — AV1 holds up visually at much lower settings than JPEG
+const AVIF_QUALITY: u8 = 60;
+
 impl Default for OptimizeSettings {
     fn default() -> Self {
         Self {
             jpeg_quality: 85,
+            jpeg_progressive: false,
             png_compression: 6,
             webp_quality: 80,
             webp_lossless: false,
+            avif_quality: AVIF_QUALITY,
             max_dimension: None,
             strip_metadata: true,
             prefer_webp: true,
             min_reduction: 0.1, // At least 10% reduction
+            watermark: None,
         }
     }
 }
@@ -145,13 +235,16 @@ impl OptimizeSettings {
     pub fn high_quality() -> Self {
         Self {
             jpeg_quality: 95,
+            jpeg_progressive: false,
             png_compression: 4,
             webp_quality: 90,
             webp_lossless: false,
+            avif_quality: 80,
             max_dimension: None,
             strip_metadata: false,
             prefer_webp: false,
             min_reduction: 0.05,
+            watermark: None,
         }
     }
 
@@ -164,13 +257,34 @@ impl OptimizeSettings {
     pub fn max_compression() -> Self {
         Self {
             jpeg_quality: 70,
+            jpeg_progressive: false,
             png_compression: 9,
             webp_quality: 65,
             webp_lossless: false,
+            avif_quality: 50,
             max_dimension: Some(1920),
             strip_metadata: true,
             prefer_webp: true,
             min_reduction: 0.0,
+            watermark: None,
+        }
+    }
+
+    /// Thumbnail preset used by [`Optimizer::generate_thumbnail`]:
+    /// JPEG quality 70, PNG compression 9, WebP quality 60
+    pub fn thumbnail() -> Self {
+        Self {
+            jpeg_quality: 70,
+            jpeg_progressive: false,
+            png_compression: 9,
+            webp_quality: 60,
+            webp_lossless: false,
+            avif_quality: AVIF_QUALITY,
+            max_dimension: None,
+            strip_metadata: true,
+            prefer_webp: false,
+            min_reduction: 0.0,
+            watermark: None,
         }
     }
 
@@ -178,13 +292,16 @@ impl OptimizeSettings {
     pub fn web() -> Self {
         Self {
             jpeg_quality: 80,
+            jpeg_progressive: false,
             png_compression: 7,
             webp_quality: 75,
             webp_lossless: false,
+            avif_quality: AVIF_QUALITY,
             max_dimension: Some(2048),
             strip_metadata: true,
             prefer_webp: true,
             min_reduction: 0.1,
+            watermark: None,
         }
     }
 }
@@ -208,6 +325,8 @@ pub struct OptimizeResult {
     pub height: u32,
     /// Whether the image was resized
     pub resized: bool,
+    /// Output is a progressive (SOF2) JPEG
+    pub is_progressive: bool,
     /// Size reduction ratio (0.0-1.0)
     pub reduction: f32,
 }
@@ -218,12 +337,93 @@ impl OptimizeResult {
         self.reduction >= min_reduction
     }
 
-    /// Get formatted size reduction
+    /// True when the output format differs from the input (e.g. PNG → AVIF)
+    pub fn format_changed(&self) -> bool {
+        self.output_format != self.original_format
+    }
+
+    /// Get formatted size reduction; negative when the output grew
     pub fn reduction_percent(&self) -> String {
         format!("{:.1}%", self.reduction * 100.0)
     }
 }
 
+/// One image rewritten by [`Optimizer::batch_optimize_dir`]
+#[derive(Debug, Clone)]
+pub struct FileResult {
+    pub path: PathBuf,
+    pub format: ImageType,
+    pub original_size: u64,
+    pub optimized_size: u64,
+}
+
+/// Outcome of [`Optimizer::batch_optimize_dir`]
+#[derive(Debug, Clone, Default)]
+pub struct BatchOptimizeReport {
+    /// Images rewritten with a smaller encoding
+    pub processed: Vec<FileResult>,
+    /// Sum of `original_size - optimized_size` over `processed`
+    pub total_saved_bytes: u64,
+    /// Non-image files and images that did not shrink by `min_reduction`
+    pub skipped: usize,
+    /// `(path, message)` for files that could not be optimized; each was
+    /// restored from its backup
+    pub errors: Vec<(String, String)>,
+}
+
+/// Thumbnail edge lengths produced by [`Optimizer::generate_thumbnail_set`]
+pub const THUMBNAIL_SIZES: [u32; 3] = [64, 128, 256];
+
+/// Preview thumbnails of one image at [`THUMBNAIL_SIZES`]
+#[derive(Debug, Clone)]
+pub struct ThumbnailSet {
+    /// Encoding shared by all three variants
+    pub format: ImageType,
+    /// Fits within 64×64
+    pub small: Vec<u8>,
+    /// Fits within 128×128
+    pub medium: Vec<u8>,
+    /// Fits within 256×256
+    pub large: Vec<u8>,
+}
+
+/// One width of a [`SrcSet`]
+#[derive(Debug, Clone)]
+pub struct SrcSetVariant {
+    /// Pixel width of the encoded image
+    pub width: u32,
+    pub data: Vec<u8>,
+    pub format: ImageType,
+    /// `srcset` width descriptor, e.g. `"400w"`
+    pub descriptor: String,
+}
+
+impl SrcSetVariant {
+    /// `"{base_path}_{descriptor}.{ext}"`, e.g. `assets/photo_400w.jpg`
+    pub fn path(&self, base_path: &str) -> String {
+        format!("{}_{}.{}", base_path, self.descriptor, self.format.extension())
+    }
+}
+
+/// Responsive variants of one image from [`Optimizer::generate_srcset`],
+/// narrowest first
+#[derive(Debug, Clone)]
+pub struct SrcSet {
+    pub variants: Vec<SrcSetVariant>,
+}
+
+impl SrcSet {
+    /// Value of an `<img srcset="...">` attribute, with each variant at
+    /// [`SrcSetVariant::path`] under `base_path`
+    pub fn to_html_attr(&self, base_path: &str) -> String {
+        self.variants
+            .iter()
+            .map(|v| format!("{} {}", v.path(base_path), v.descriptor))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
 /// Image optimizer for various formats
 pub struct Optimizer {
     settings: OptimizeSettings,
@@ -280,7 +480,10 @@ impl Optimizer {
         let (width, height) = img.dimensions();
 
         // Optionally resize
-        let (processed_img, resized) = self.maybe_resize(img);
+        let (mut processed_img, resized) = self.maybe_resize(img);
+        if let Some(ref watermark) = self.settings.watermark {
+            Self::apply_watermark_overlay(&mut processed_img, watermark)?;
+        }
 
         // Try different formats and pick the best
         let (optimized_data, output_format) = self.find_best_encoding(&processed_img, original_format)?;
@@ -288,10 +491,18 @@ impl Optimizer {
         let optimized_size = optimized_data.len();
         let reduction = 1.0 - (optimized_size as f32 / original_size as f32);
 
-        // If no improvement, return original
-        if reduction < self.settings.min_reduction && !resized {
+        // If no improvement, return original (minus EXIF when stripping);
+        // a watermarked image always differs from the original
+        if reduction < self.settings.min_reduction && !resized && self.settings.watermark.is_none() {
+            let data = match original_format {
+                ImageType::Jpeg if self.settings.strip_metadata => {
+                    Self::strip_exif(data).unwrap_or_else(|_| data.to_vec())
+                }
+                _ => data.to_vec(),
+            };
             return Ok(OptimizeResult {
-                data: data.to_vec(),
+                is_progressive: is_progressive_jpeg(&data),
+                data,
                 original_size,
                 optimized_size: original_size,
                 original_format,
@@ -304,6 +515,7 @@ impl Optimizer {
         }
 
         Ok(OptimizeResult {
+            is_progressive: is_progressive_jpeg(&optimized_data),
             data: optimized_data,
             original_size,
             optimized_size,
@@ -324,13 +536,17 @@ impl Optimizer {
         let img = image::load_from_memory(data)?;
         let (width, height) = img.dimensions();
 
-        let (processed_img, resized) = self.maybe_resize(img);
+        let (mut processed_img, resized) = self.maybe_resize(img);
+        if let Some(ref watermark) = self.settings.watermark {
+            Self::apply_watermark_overlay(&mut processed_img, watermark)?;
+        }
 
         let optimized_data = self.encode_to_format(&processed_img, target_format)?;
         let optimized_size = optimized_data.len();
         let reduction = 1.0 - (optimized_size as f32 / original_size as f32);
 
         Ok(OptimizeResult {
+            is_progressive: is_progressive_jpeg(&optimized_data),
             data: optimized_data,
             original_size,
             optimized_size,
@@ -343,6 +559,129 @@ impl Optimizer {
         })
     }
 
+    /// Downscale to fit within `max_size`×`max_size` (aspect ratio kept)
+    /// and encode with [`OptimizeSettings::thumbnail`]. Images already
+    /// within bounds are re-encoded without upscaling.
+    pub fn generate_thumbnail(data: &[u8], max_size: u32, format: ImageType) -> Result<Vec<u8>, OptimizeError> {
+        if max_size == 0 {
+            return Err(OptimizeError::EncodingError("thumbnail size must be positive".to_string()));
+        }
+        let img = image::load_from_memory(data)?;
+        let thumb = if img.width() > max_size || img.height() > max_size {
+            img.thumbnail(max_size, max_size)
+        } else {
+            img
+        };
+        // JPEG has no alpha channel
+        let thumb = if format == ImageType::Jpeg && thumb.color().has_alpha() {
+            DynamicImage::ImageRgb8(thumb.to_rgb8())
+        } else {
+            thumb
+        };
+
+        Self::with_settings(OptimizeSettings::thumbnail()).encode_to_format(&thumb, format)
+    }
+
+    /// 64px, 128px and 256px previews. Images with transparency are
+    /// encoded as PNG, everything else as JPEG.
+    pub fn generate_thumbnail_set(data: &[u8]) -> Result<ThumbnailSet, OptimizeError> {
+        let format = if image::load_from_memory(data)?.color().has_alpha() {
+            ImageType::Png
+        } else {
+            ImageType::Jpeg
+        };
+        let [small, medium, large] = THUMBNAIL_SIZES;
+        Ok(ThumbnailSet {
+            format,
+            small: Self::generate_thumbnail(data, small, format)?,
+            medium: Self::generate_thumbnail(data, medium, format)?,
+            large: Self::generate_thumbnail(data, large, format)?,
+        })
+    }
+
+    /// Resize to each of `widths` (aspect ratio kept) and encode every
+    /// variant with this optimizer's settings, so `prefer_webp` may pick
+    /// WebP or AVIF. Widths are sorted and de-duplicated; widths above the
+    /// original are dropped rather than upscaled, leaving the original
+    /// width when none remain.
+    pub fn generate_srcset(&self, data: &[u8], widths: &[u32]) -> Result<SrcSet, OptimizeError> {
+        if widths.is_empty() || widths.contains(&0) {
+            return Err(OptimizeError::EncodingError("srcset widths must be positive".to_string()));
+        }
+        let original_format = match ImageType::from_bytes(data) {
+            ImageType::Unknown | ImageType::AnimatedPng => ImageType::Png,
+            format => format,
+        };
+        let img = image::load_from_memory(data)?;
+
+        let mut targets: Vec<u32> = widths.iter().copied().filter(|&w| w <= img.width()).collect();
+        targets.sort_unstable();
+        targets.dedup();
+        if targets.is_empty() {
+            targets.push(img.width());
+        }
+
+        let mut variants = Vec::with_capacity(targets.len());
+        for width in targets {
+            let resized = if width == img.width() {
+                img.clone()
+            } else {
+                img.resize(width, u32::MAX, image::imageops::FilterType::Lanczos3)
+            };
+            let (data, format) = self.find_best_encoding(&resized, original_format)?;
+            variants.push(SrcSetVariant {
+                width: resized.width(),
+                data,
+                format,
+                descriptor: format!("{}w", resized.width()),
+            });
+        }
+        Ok(SrcSet { variants })
+    }
+
+    /// Draw `config.text` over `img`, alpha-blended at `config.position`.
+    ///
+    /// Text is laid out as SVG and rasterized with resvg using the system
+    /// fonts; without any installed font nothing is drawn. Images without
+    /// an alpha channel stay opaque RGB.
+    pub fn apply_watermark_overlay(img: &mut DynamicImage, config: &WatermarkConfig) -> Result<(), OptimizeError> {
+        let (width, height) = img.dimensions();
+        if config.text.is_empty() || width == 0 || height == 0 {
+            return Ok(());
+        }
+
+        let options = usvg::Options { fontdb: watermark_fontdb(), ..Default::default() };
+        let tree = usvg::Tree::from_str(&watermark_svg(config, width, height), &options)
+            .map_err(|e| OptimizeError::EncodingError(format!("watermark SVG: {}", e)))?;
+        let mut overlay = tiny_skia::Pixmap::new(width, height)
+            .ok_or_else(|| OptimizeError::EncodingError("watermark canvas too large".to_string()))?;
+        resvg::render(&tree, tiny_skia::Transform::default(), &mut overlay.as_mut());
+
+        let had_alpha = img.color().has_alpha();
+        let mut canvas = img.to_rgba8();
+        for (dst, src) in canvas.pixels_mut().zip(overlay.pixels()) {
+            if src.alpha() == 0 {
+                continue;
+            }
+            // `src` is premultiplied; composite "source over" in float
+            let src_a = src.alpha() as f32 / 255.0;
+            let dst_a = dst[3] as f32 / 255.0;
+            let out_a = src_a + dst_a * (1.0 - src_a);
+            for (c, src_c) in [src.red(), src.green(), src.blue()].into_iter().enumerate() {
+                let premul = src_c as f32 / 255.0 + dst[c] as f32 / 255.0 * dst_a * (1.0 - src_a);
+                dst[c] = (premul / out_a * 255.0).round().clamp(0.0, 255.0) as u8;
+            }
+            dst[3] = (out_a * 255.0).round() as u8;
+        }
+
+        *img = if had_alpha {
+            DynamicImage::ImageRgba8(canvas)
+        } else {
+            DynamicImage::ImageRgb8(DynamicImage::ImageRgba8(canvas).to_rgb8())
+        };
+        Ok(())
+    }
+
     /// Resize image if necessary
     fn maybe_resize(&self, img: DynamicImage) -> (DynamicImage, bool) {
         if let Some(max_dim) = self.settings.max_dimension {
@@ -367,7 +706,7 @@ impl Optimizer {
         let mut best_format = original_format;
         let mut best_size = usize::MAX;
 
-        // Try original format first
+        // Try original format first (progressive when requested, see encode_to_format)
         if let Ok(data) = self.encode_to_format(img, original_format) {
             best_size = data.len();
             best_data = Some(data);
@@ -382,6 +721,12 @@ impl Optimizer {
                     best_format = ImageType::WebP;
                 }
             }
+            if let Ok(avif_data) = self.encode_to_format(img, ImageType::Avif) {
+                if avif_data.len() < best_size {
+                    best_data = Some(avif_data);
+                    best_format = ImageType::Avif;
+                }
+            }
         }
 
         best_data
@@ -395,6 +740,12 @@ impl Optimizer {
 
         match format {
             ImageType::Jpeg => {
+                if self.settings.jpeg_progressive {
+                    // Fall through to baseline when progressive is unavailable
+                    if let Ok(data) = self.encode_jpeg_progressive(img) {
+                        return Ok(data);
+                    }
+                }
                 let encoder = JpegEncoder::new_with_quality(&mut output, self.settings.jpeg_quality);
                 encoder.write_image(
                     img.as_bytes(),
@@ -404,7 +755,12 @@ impl Optimizer {
                 )?;
             }
             ImageType::Png => {
-                let encoder = PngEncoder::new(&mut output);
+                let compression = match self.settings.png_compression {
+                    0..=3 => CompressionType::Fast,
+                    4..=7 => CompressionType::Default,
+                    _ => CompressionType::Best,
+                };
+                let encoder = PngEncoder::new_with_quality(&mut output, compression, PngFilterType::Adaptive);
                 encoder.write_image(
                     img.as_bytes(),
                     img.width(),
@@ -418,6 +774,23 @@ impl Optimizer {
             ImageType::Gif => {
                 img.write_to(&mut output, ImageFormat::Gif)?;
             }
+            ImageType::Avif => {
+                // speed 6: ravif's middle ground between size and encode time
+                let encoder = AvifEncoder::new_with_speed_quality(&mut output, 6, self.settings.avif_quality.clamp(1, 100));
+                let rgba = img.to_rgba8();
+                encoder.write_image(
+                    rgba.as_raw(),
+                    rgba.width(),
+                    rgba.height(),
+                    image::ExtendedColorType::Rgba8,
+                )?;
+            }
+            // A single DynamicImage has no frames to animate
+            ImageType::AnimatedPng => {
+                return Err(OptimizeError::InvalidFormat(
+                    "animated PNG output needs convert_gif_to_apng".to_string(),
+                ));
+            }
             ImageType::Unknown => {
                 return Err(OptimizeError::InvalidFormat("Unknown format".to_string()));
             }
@@ -426,11 +799,171 @@ impl Optimizer {
         Ok(output.into_inner())
     }
 
+    /// Encode as progressive JPEG via mozjpeg
+    #[cfg(feature = "mozjpeg")]
+    pub fn encode_jpeg_progressive(&self, img: &DynamicImage) -> Result<Vec<u8>, OptimizeError> {
+        let rgb = img.to_rgb8();
+        let mut comp = mozjpeg::Compress::new(mozjpeg::ColorSpace::JCS_RGB);
+        comp.set_size(rgb.width() as usize, rgb.height() as usize);
+        comp.set_quality(self.settings.jpeg_quality.clamp(1, 100) as f32);
+        comp.set_progressive_mode();
+
+        let mut started = comp.start_compress(Vec::new())?;
+        started.write_scanlines(rgb.as_raw())?;
+        Ok(started.finish()?)
+    }
+
+    /// Progressive JPEG needs the `mozjpeg` feature
+    #[cfg(not(feature = "mozjpeg"))]
+    pub fn encode_jpeg_progressive(&self, _img: &DynamicImage) -> Result<Vec<u8>, OptimizeError> {
+        Err(OptimizeError::EncodingError(
+            "progressive JPEG requires the `mozjpeg` feature".to_string(),
+        ))
+    }
+
+    /// Remove APP1 segments (EXIF, XMP) from a JPEG without re-encoding.
+    ///
+    /// Other segments, including APP0 JFIF and APP14 Adobe, and the
+    /// entropy-coded data are copied byte for byte.
+    pub fn strip_exif(data: &[u8]) -> Result<Vec<u8>, OptimizeError> {
+        if !data.starts_with(&[0xFF, 0xD8]) {
+            return Err(OptimizeError::InvalidFormat("not a JPEG".to_string()));
+        }
+        let truncated = || OptimizeError::InvalidFormat("truncated JPEG segment".to_string());
+
+        let mut out = Vec::with_capacity(data.len());
+        out.extend_from_slice(&data[..2]);
+        let mut pos = 2;
+        while pos < data.len() {
+            if data[pos] != 0xFF || pos + 1 >= data.len() {
+                return Err(truncated());
+            }
+            let marker = data[pos + 1];
+            match marker {
+                0xFF => pos += 1, // fill byte
+                // Standalone markers carry no length
+                0x01 | 0xD0..=0xD9 => {
+                    out.extend_from_slice(&data[pos..pos + 2]);
+                    pos += 2;
+                }
+                _ => {
+                    let len_bytes = data.get(pos + 2..pos + 4).ok_or_else(truncated)?;
+                    let end = pos + 2 + u16::from_be_bytes([len_bytes[0], len_bytes[1]]) as usize;
+                    if end > data.len() {
+                        return Err(truncated());
+                    }
+                    if marker == 0xDA {
+                        // Start of scan: the rest is image data
+                        out.extend_from_slice(&data[pos..]);
+                        break;
+                    }
+                    if marker != 0xE1 {
+                        out.extend_from_slice(&data[pos..end]);
+                    }
+                    pos = end;
+                }
+            }
+        }
+        Ok(out)
+    }
+
     /// Batch optimize multiple images
     pub fn optimize_batch(&self, images: &[&[u8]]) -> Vec<Result<OptimizeResult, OptimizeError>> {
         images.iter().map(|data| self.optimize_auto(data)).collect()
     }
 
+    /// Optimize every image below `dir` in place, keeping each file's format.
+    ///
+    /// Files are recognised by their bytes ([`ImageType::from_bytes`]), not
+    /// their extension. Each image is copied to `<name>.bak` first; the
+    /// backup is removed once the smaller encoding is written and moved
+    /// back over the file if anything fails. Only a missing or unreadable
+    /// `dir` is an error — per-file failures go to
+    /// [`BatchOptimizeReport::errors`].
+    pub fn batch_optimize_dir(dir: &Path, settings: &OptimizeSettings) -> io::Result<BatchOptimizeReport> {
+        if !dir.is_dir() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("not a directory: {}", dir.display()),
+            ));
+        }
+        let optimizer = Self::with_settings(settings.clone());
+        let mut report = BatchOptimizeReport::default();
+
+        for entry in walkdir::WalkDir::new(dir).sort_by_file_name() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    let path = e.path().map(|p| p.display().to_string()).unwrap_or_default();
+                    report.errors.push((path, e.to_string()));
+                    continue;
+                }
+            };
+            let path = entry.path();
+            let is_backup = path.extension().is_some_and(|e| e == "bak");
+            if !entry.file_type().is_file() || is_backup {
+                continue;
+            }
+
+            let data = match std::fs::read(path) {
+                Ok(data) => data,
+                Err(e) => {
+                    report.errors.push((path.display().to_string(), e.to_string()));
+                    continue;
+                }
+            };
+            let format = ImageType::from_bytes(&data);
+            if format == ImageType::Unknown {
+                report.skipped += 1;
+                continue;
+            }
+
+            match optimizer.optimize_file_in_place(path, &data, format) {
+                Ok(Some(result)) => {
+                    report.total_saved_bytes += result.original_size - result.optimized_size;
+                    report.processed.push(result);
+                }
+                Ok(None) => report.skipped += 1,
+                Err(e) => report.errors.push((path.display().to_string(), e.to_string())),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Rewrite `path` (currently `data`) when re-encoding as `format` saves
+    /// at least `min_reduction`; `Ok(None)` leaves it untouched
+    fn optimize_file_in_place(
+        &self,
+        path: &Path,
+        data: &[u8],
+        format: ImageType,
+    ) -> Result<Option<FileResult>, OptimizeError> {
+        let mut backup = path.as_os_str().to_owned();
+        backup.push(".bak");
+        let backup = PathBuf::from(backup);
+        std::fs::copy(path, &backup)?;
+
+        let outcome = self.optimize_to_format(data, format).and_then(|result| {
+            if result.optimized_size >= result.original_size || result.reduction < self.settings.min_reduction {
+                return Ok(None);
+            }
+            std::fs::write(path, &result.data)?;
+            Ok(Some(FileResult {
+                path: path.to_path_buf(),
+                format,
+                original_size: result.original_size as u64,
+                optimized_size: result.optimized_size as u64,
+            }))
+        });
+
+        match outcome {
+            Ok(_) => std::fs::remove_file(&backup)?,
+            Err(_) => std::fs::rename(&backup, path)?,
+        }
+        outcome
+    }
+
     /// Convert image to WebP format
     pub fn to_webp(&self, data: &[u8]) -> Result<Vec<u8>, OptimizeError> {
         self.optimize_to_format(data, ImageType::WebP)
@@ -443,11 +976,115 @@ impl Optimizer {
             .map(|r| r.data)
     }
 
+    /// Convert image to AVIF format
+    pub fn to_avif(&self, data: &[u8]) -> Result<Vec<u8>, OptimizeError> {
+        self.optimize_to_format(data, ImageType::Avif)
+            .map(|r| r.data)
+    }
+
     /// Convert image to PNG format
     pub fn to_png(&self, data: &[u8]) -> Result<Vec<u8>, OptimizeError> {
         self.optimize_to_format(data, ImageType::Png)
             .map(|r| r.data)
     }
+
+    /// Re-encode an animated GIF as APNG (`acTL`/`fcTL`/`fdAT` chunks),
+    /// keeping each frame's delay. Single-frame GIFs become a plain PNG.
+    ///
+    /// Frames come out of the decoder fully composited, so every APNG frame
+    /// covers the whole canvas. The decoder does not expose the GIF loop
+    /// count; the APNG loops forever.
+    pub fn convert_gif_to_apng(data: &[u8]) -> Result<Vec<u8>, OptimizeError> {
+        if ImageType::from_bytes(data) != ImageType::Gif {
+            return Err(OptimizeError::InvalidFormat("expected GIF input".to_string()));
+        }
+        let frames = GifDecoder::new(Cursor::new(data))?.into_frames().collect_frames()?;
+        let Some(first) = frames.first() else {
+            return Err(OptimizeError::InvalidFormat("GIF has no frames".to_string()));
+        };
+        if frames.len() == 1 {
+            let img = DynamicImage::ImageRgba8(first.buffer().clone());
+            return Self::new().encode_to_format(&img, ImageType::Png);
+        }
+
+        let (width, height) = first.buffer().dimensions();
+        let mut output = Vec::new();
+        let mut encoder = png::Encoder::new(&mut output, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_animated(frames.len() as u32, 0).map_err(apng_error)?;
+        let mut writer = encoder.write_header().map_err(apng_error)?;
+        for frame in &frames {
+            // fcTL delay is a u16 fraction of a second; GIF delays are centiseconds
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let delay_ms = (numer / denom.max(1)).min(u16::MAX as u32) as u16;
+            writer.set_frame_delay(delay_ms, 1000).map_err(apng_error)?;
+            writer.write_image_data(frame.buffer().as_raw()).map_err(apng_error)?;
+        }
+        writer.finish().map_err(apng_error)?;
+        Ok(output)
+    }
+}
+
+fn apng_error(e: png::EncodingError) -> OptimizeError {
+    OptimizeError::EncodingError(format!("APNG: {}", e))
+}
+
+/// True if an `acTL` chunk comes before the first `IDAT`
+fn is_animated_png(data: &[u8]) -> bool {
+    let mut pos = 8;
+    while let Some(header) = data.get(pos..pos + 8) {
+        match &header[4..8] {
+            b"acTL" => return true,
+            b"IDAT" | b"IEND" => return false,
+            _ => {}
+        }
+        let len = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        pos = pos.saturating_add(12).saturating_add(len);
+    }
+    false
+}
+
+/// System font database for watermark text, loaded once per process
+fn watermark_fontdb() -> Arc<usvg::fontdb::Database> {
+    static FONTDB: OnceLock<Arc<usvg::fontdb::Database>> = OnceLock::new();
+    FONTDB
+        .get_or_init(|| {
+            let mut fontdb = usvg::fontdb::Database::new();
+            fontdb.load_system_fonts();
+            Arc::new(fontdb)
+        })
+        .clone()
+}
+
+/// `width`×`height` SVG holding the watermark `<text>`, inset by half the
+/// font size from the anchored edges
+fn watermark_svg(config: &WatermarkConfig, width: u32, height: u32) -> String {
+    let size = config.font_size.max(1) as f32;
+    let margin = size * 0.5;
+    let (w, h) = (width as f32, height as f32);
+    // Baselines: ~0.8em below the top edge for the cap height, ~0.2em
+    // above the bottom edge for descenders
+    let (x, anchor) = match config.position {
+        WatermarkPosition::TopLeft | WatermarkPosition::BottomLeft => (margin, "start"),
+        WatermarkPosition::TopRight | WatermarkPosition::BottomRight => (w - margin, "end"),
+        WatermarkPosition::Center => (w / 2.0, "middle"),
+    };
+    let y = match config.position {
+        WatermarkPosition::TopLeft | WatermarkPosition::TopRight => margin + size * 0.8,
+        WatermarkPosition::BottomLeft | WatermarkPosition::BottomRight => h - margin - size * 0.2,
+        WatermarkPosition::Center => h / 2.0 + size * 0.35,
+    };
+    let [r, g, b, a] = config.color;
+    let opacity = config.opacity.clamp(0.0, 1.0) * a as f32 / 255.0;
+    let text = config
+        .text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}"><text x="{x}" y="{y}" font-family="sans-serif" font-size="{size}" text-anchor="{anchor}" fill="rgb({r},{g},{b})" fill-opacity="{opacity}">{text}</text></svg>"#
+    )
 }
 
 impl Default for Optimizer {
@@ -456,6 +1093,31 @@ impl Default for Optimizer {
     }
 }
 
+/// True when `data` is a JPEG whose frame header is SOF2 (`FF C2`).
+/// Walks the marker segments up to the first SOF/SOS so entropy-coded
+/// bytes are never mistaken for a marker.
+pub fn is_progressive_jpeg(data: &[u8]) -> bool {
+    if !data.starts_with(&[0xFF, 0xD8]) {
+        return false;
+    }
+    let mut pos = 2;
+    while pos + 4 <= data.len() {
+        if data[pos] != 0xFF {
+            return false;
+        }
+        match data[pos + 1] {
+            0xFF => pos += 1, // fill byte
+            0xC2 => return true,
+            0xC0 | 0xC1 | 0xC3 | 0xC5..=0xC7 | 0xC9..=0xCB | 0xCD..=0xCF | 0xDA => return false,
+            _ => {
+                let len = u16::from_be_bytes([data[pos + 2], data[pos + 3]]) as usize;
+                pos += 2 + len;
+            }
+        }
+    }
+    false
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -491,6 +1153,62 @@ mod tests {
         assert_eq!(ImageType::from_bytes(&[0x00, 0x00]), ImageType::Unknown);
     }
 
+    /// Two 4×4 frames (red 100 ms, blue 250 ms), or only the red one
+    fn synthetic_gif(two_frames: bool) -> Vec<u8> {
+        use image::codecs::gif::GifEncoder;
+        use image::{Delay, Frame, Rgba, RgbaImage};
+
+        let mut frames = vec![Frame::from_parts(
+            RgbaImage::from_pixel(4, 4, Rgba([255, 0, 0, 255])),
+            0,
+            0,
+            Delay::from_numer_denom_ms(100, 1),
+        )];
+        if two_frames {
+            frames.push(Frame::from_parts(
+                RgbaImage::from_pixel(4, 4, Rgba([0, 0, 255, 255])),
+                0,
+                0,
+                Delay::from_numer_denom_ms(250, 1),
+            ));
+        }
+        let mut gif = Vec::new();
+        {
+            let mut encoder = GifEncoder::new(&mut gif);
+            encoder.encode_frames(frames).unwrap();
+        }
+        gif
+    }
+
+    #[test]
+    fn test_convert_gif_to_apng() {
+        let apng = Optimizer::convert_gif_to_apng(&synthetic_gif(true)).unwrap();
+        assert!(apng.starts_with(b"\x89PNG"));
+        for chunk in [b"acTL", b"fcTL", b"fdAT"] {
+            assert!(apng.windows(4).any(|w| w == chunk), "missing {:?}", chunk);
+        }
+        assert_eq!(ImageType::from_bytes(&apng), ImageType::AnimatedPng);
+
+        let decoder = image::codecs::png::PngDecoder::new(Cursor::new(&apng)).unwrap();
+        let frames = decoder.apng().unwrap().into_frames().collect_frames().unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].delay().numer_denom_ms(), (100, 1));
+        assert_eq!(frames[1].delay().numer_denom_ms(), (250, 1));
+        assert_eq!(frames[1].buffer().get_pixel(0, 0).0, [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn test_convert_static_gif_and_non_gif() {
+        let png = Optimizer::convert_gif_to_apng(&synthetic_gif(false)).unwrap();
+        assert_eq!(ImageType::from_bytes(&png), ImageType::Png);
+        assert!(!png.windows(4).any(|w| w == b"acTL"));
+
+        let err = Optimizer::convert_gif_to_apng(&png).unwrap_err();
+        assert!(matches!(err, OptimizeError::InvalidFormat(_)), "{}", err);
+        assert_eq!(ImageType::AnimatedPng.mime_type(), "image/apng");
+        assert_eq!(ImageType::from_extension("apng").extension(), "png");
+    }
+
     #[test]
     fn test_image_type_from_extension() {
         assert_eq!(ImageType::from_extension("jpg"), ImageType::Jpeg);
@@ -530,6 +1248,7 @@ mod tests {
             width: 100,
             height: 100,
             resized: false,
+            is_progressive: false,
             reduction: 0.3,
         };
 
@@ -545,4 +1264,358 @@ mod tests {
         assert_eq!(ImageType::Png.extension(), "png");
         assert_eq!(ImageType::WebP.mime_type(), "image/webp");
     }
+
+    #[test]
+    fn test_avif_detection_and_properties() {
+        let header = b"\x00\x00\x00\x20ftypavif\x00\x00\x00\x00";
+        assert_eq!(ImageType::from_bytes(header), ImageType::Avif);
+        assert_eq!(ImageType::from_bytes(b"\x00\x00\x00\x20ftypavis"), ImageType::Avif);
+        // Other ISO-BMFF brands (HEIC) are not AVIF
+        assert_eq!(ImageType::from_bytes(b"\x00\x00\x00\x20ftypheic"), ImageType::Unknown);
+        assert_eq!(ImageType::from_extension("AVIF"), ImageType::Avif);
+        assert_eq!(ImageType::Avif.extension(), "avif");
+        assert_eq!(ImageType::Avif.mime_type(), "image/avif");
+        assert_eq!(OptimizeSettings::default().avif_quality, 60);
+    }
+
+    #[test]
+    fn test_encode_png_to_avif() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(32, 32, |x, y| {
+            image::Rgb([(x * 8) as u8, (y * 8) as u8, 128])
+        }));
+        let mut png = Cursor::new(Vec::new());
+        img.write_to(&mut png, ImageFormat::Png).unwrap();
+
+        let result = Optimizer::new()
+            .optimize_to_format(png.get_ref(), ImageType::Avif)
+            .unwrap();
+        assert_eq!(ImageType::from_bytes(&result.data), ImageType::Avif);
+        assert_eq!(result.original_format, ImageType::Png);
+        assert!(result.format_changed());
+    }
+
+    #[test]
+    fn test_reduction_percent_negative_when_larger() {
+        let result = OptimizeResult {
+            data: vec![],
+            original_size: 400,
+            optimized_size: 500,
+            original_format: ImageType::Png,
+            output_format: ImageType::Avif,
+            width: 4,
+            height: 4,
+            resized: false,
+            is_progressive: false,
+            reduction: 1.0 - 500.0 / 400.0,
+        };
+        assert_eq!(result.reduction_percent(), "-25.0%");
+        assert!(result.format_changed());
+    }
+
+    fn gradient_png() -> Vec<u8> {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(32, 32, |x, y| {
+            image::Rgb([(x * 8) as u8, (y * 8) as u8, 128])
+        }));
+        let mut png = Cursor::new(Vec::new());
+        img.write_to(&mut png, ImageFormat::Png).unwrap();
+        png.into_inner()
+    }
+
+    fn noisy_jpeg(quality: u8) -> Vec<u8> {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(64, 64, |x, y| {
+            image::Rgb([(x * 37 + y * 11) as u8, (x * y) as u8, (y * 53) as u8])
+        }));
+        let mut jpeg = Cursor::new(Vec::new());
+        JpegEncoder::new_with_quality(&mut jpeg, quality).write_image(
+            img.as_bytes(),
+            img.width(),
+            img.height(),
+            img.color().into(),
+        ).unwrap();
+        jpeg.into_inner()
+    }
+
+    #[test]
+    fn test_batch_optimize_dir_rewrites_and_skips() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("images");
+        std::fs::create_dir(&nested).unwrap();
+        let jpeg = noisy_jpeg(100);
+        std::fs::write(nested.join("photo.jpg"), &jpeg).unwrap();
+        std::fs::write(dir.path().join("chart.png"), gradient_png()).unwrap();
+        std::fs::write(dir.path().join("doc.mdx"), "# not an image").unwrap();
+
+        let settings = OptimizeSettings { jpeg_quality: 50, ..OptimizeSettings::balanced() };
+        let report = Optimizer::batch_optimize_dir(dir.path(), &settings).unwrap();
+
+        assert!(report.errors.is_empty(), "{:?}", report.errors);
+        let photo = report.processed.iter().find(|f| f.path.ends_with("photo.jpg")).expect("jpeg rewritten");
+        assert_eq!(photo.format, ImageType::Jpeg);
+        assert_eq!(photo.original_size, jpeg.len() as u64);
+        let written = std::fs::read(nested.join("photo.jpg")).unwrap();
+        assert_eq!(written.len() as u64, photo.optimized_size);
+        assert_eq!(ImageType::from_bytes(&written), ImageType::Jpeg);
+
+        // The PNG is either rewritten or left alone; the .mdx always skipped
+        assert_eq!(report.processed.len() + report.skipped, 3);
+        let saved: u64 = report.processed.iter().map(|f| f.original_size - f.optimized_size).sum();
+        assert_eq!(report.total_saved_bytes, saved);
+        assert!(image::load_from_memory(&std::fs::read(dir.path().join("chart.png")).unwrap()).is_ok());
+        assert_eq!(std::fs::read_to_string(dir.path().join("doc.mdx")).unwrap(), "# not an image");
+
+        let backups = walkdir::WalkDir::new(dir.path())
+            .into_iter()
+            .flatten()
+            .filter(|e| e.path().extension().is_some_and(|x| x == "bak"))
+            .count();
+        assert_eq!(backups, 0);
+    }
+
+    #[test]
+    fn test_batch_optimize_dir_restores_corrupt_image() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut truncated = noisy_jpeg(90);
+        truncated.truncate(40);
+        let path = dir.path().join("broken.jpg");
+        std::fs::write(&path, &truncated).unwrap();
+
+        let report = Optimizer::batch_optimize_dir(dir.path(), &OptimizeSettings::balanced()).unwrap();
+        assert!(report.processed.is_empty());
+        assert_eq!(report.errors.len(), 1);
+        assert!(report.errors[0].0.ends_with("broken.jpg"));
+        assert_eq!(std::fs::read(&path).unwrap(), truncated);
+        assert!(!dir.path().join("broken.jpg.bak").exists());
+    }
+
+    #[test]
+    fn test_batch_optimize_dir_missing_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = Optimizer::batch_optimize_dir(&dir.path().join("nope"), &OptimizeSettings::balanced()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_generate_thumbnail_keeps_aspect_ratio() {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(400, 200, |x, _| {
+            image::Rgb([(x % 256) as u8, 64, 128])
+        }));
+        let mut png = Cursor::new(Vec::new());
+        img.write_to(&mut png, ImageFormat::Png).unwrap();
+
+        let thumb = Optimizer::generate_thumbnail(png.get_ref(), 128, ImageType::Jpeg).unwrap();
+        assert_eq!(ImageType::from_bytes(&thumb), ImageType::Jpeg);
+        let decoded = image::load_from_memory(&thumb).unwrap();
+        assert_eq!(decoded.dimensions(), (128, 64));
+
+        // Small images are not upscaled
+        let small = Optimizer::generate_thumbnail(&gradient_png(), 128, ImageType::Png).unwrap();
+        assert_eq!(image::load_from_memory(&small).unwrap().dimensions(), (32, 32));
+    }
+
+    #[test]
+    fn test_generate_thumbnail_set() {
+        let img = DynamicImage::ImageRgba8(image::RgbaImage::from_fn(300, 600, |x, y| {
+            image::Rgba([(x % 256) as u8, (y % 256) as u8, 0, 200])
+        }));
+        let mut png = Cursor::new(Vec::new());
+        img.write_to(&mut png, ImageFormat::Png).unwrap();
+
+        let set = Optimizer::generate_thumbnail_set(png.get_ref()).unwrap();
+        assert_eq!(set.format, ImageType::Png);
+        let height = |data: &[u8]| image::load_from_memory(data).unwrap().height();
+        assert_eq!(height(&set.small), 64);
+        assert_eq!(height(&set.medium), 128);
+        assert_eq!(height(&set.large), 256);
+
+        assert!(Optimizer::generate_thumbnail_set(b"not an image").is_err());
+    }
+
+    fn wide_png(width: u32, height: u32) -> Vec<u8> {
+        let img = DynamicImage::ImageRgb8(image::RgbImage::from_fn(width, height, |x, y| {
+            image::Rgb([(x % 256) as u8, (y % 256) as u8, 96])
+        }));
+        let mut png = Cursor::new(Vec::new());
+        img.write_to(&mut png, ImageFormat::Png).unwrap();
+        png.into_inner()
+    }
+
+    #[test]
+    fn test_generate_srcset_widths_and_aspect_ratio() {
+        let set = Optimizer::new().generate_srcset(&wide_png(800, 400), &[400, 200]).unwrap();
+        let widths: Vec<u32> = set.variants.iter().map(|v| v.width).collect();
+        assert_eq!(widths, vec![200, 400], "sorted narrowest first");
+        for variant in &set.variants {
+            let decoded = image::load_from_memory(&variant.data).unwrap();
+            assert_eq!(decoded.dimensions(), (variant.width, variant.width / 2));
+            assert_eq!(variant.descriptor, format!("{}w", variant.width));
+        }
+    }
+
+    #[test]
+    fn test_generate_srcset_keeps_source_format() {
+        let optimizer = Optimizer::with_settings(OptimizeSettings::high_quality());
+        let set = optimizer.generate_srcset(&noisy_jpeg(90), &[32]).unwrap();
+        assert_eq!(set.variants[0].format, ImageType::Jpeg);
+        assert_eq!(ImageType::from_bytes(&set.variants[0].data), ImageType::Jpeg);
+    }
+
+    #[test]
+    fn test_generate_srcset_does_not_upscale() {
+        let set = Optimizer::new().generate_srcset(&gradient_png(), &[16, 64, 128]).unwrap();
+        let widths: Vec<u32> = set.variants.iter().map(|v| v.width).collect();
+        assert_eq!(widths, vec![16]);
+
+        let set = Optimizer::new().generate_srcset(&gradient_png(), &[64]).unwrap();
+        assert_eq!(set.variants.len(), 1);
+        assert_eq!(set.variants[0].width, 32, "falls back to the original width");
+    }
+
+    #[test]
+    fn test_generate_srcset_dedups_widths() {
+        let set = Optimizer::new().generate_srcset(&wide_png(300, 100), &[150, 150, 300]).unwrap();
+        let descriptors: Vec<&str> = set.variants.iter().map(|v| v.descriptor.as_str()).collect();
+        assert_eq!(descriptors, vec!["150w", "300w"]);
+    }
+
+    #[test]
+    fn test_generate_srcset_rejects_bad_input() {
+        let optimizer = Optimizer::new();
+        assert!(optimizer.generate_srcset(&gradient_png(), &[]).is_err());
+        assert!(optimizer.generate_srcset(&gradient_png(), &[0, 16]).is_err());
+        assert!(optimizer.generate_srcset(b"not an image", &[16]).is_err());
+    }
+
+    #[test]
+    fn test_srcset_to_html_attr() {
+        let variant = |width: u32, format: ImageType| SrcSetVariant {
+            width,
+            data: Vec::new(),
+            format,
+            descriptor: format!("{}w", width),
+        };
+        let set = SrcSet { variants: vec![variant(400, ImageType::Jpeg), variant(800, ImageType::WebP)] };
+        assert_eq!(
+            set.to_html_attr("assets/photo"),
+            "assets/photo_400w.jpg 400w, assets/photo_800w.webp 800w"
+        );
+        assert_eq!(SrcSet { variants: Vec::new() }.to_html_attr("x"), "");
+    }
+
+    #[test]
+    fn test_strip_exif_keeps_jfif_and_adobe() {
+        let segment = |marker: u8, payload: &[u8]| {
+            let mut seg = vec![0xFF, marker];
+            seg.extend_from_slice(&(payload.len() as u16 + 2).to_be_bytes());
+            seg.extend_from_slice(payload);
+            seg
+        };
+        let jfif = segment(0xE0, b"JFIF\0\x01\x01");
+        let exif = segment(0xE1, b"Exif\0\0GPS 37.5665N 126.9780E");
+        let adobe = segment(0xEE, b"Adobe\0\x64");
+        let scan = [0xFF, 0xDA, 0x00, 0x02, 0x12, 0xFF, 0xE1, 0x34, 0xFF, 0xD9];
+
+        let mut jpeg = vec![0xFF, 0xD8];
+        for part in [&jfif[..], &exif, &adobe, &scan] {
+            jpeg.extend_from_slice(part);
+        }
+
+        let stripped = Optimizer::strip_exif(&jpeg).unwrap();
+        assert!(!stripped.windows(4).any(|w| w == b"Exif"));
+        assert!(stripped.windows(4).any(|w| w == b"JFIF"));
+        assert!(stripped.windows(5).any(|w| w == b"Adobe"));
+        // Scan data is copied verbatim, even bytes that look like APP1
+        assert!(stripped.ends_with(&scan));
+        assert_eq!(stripped.len(), jpeg.len() - exif.len());
+
+        assert!(Optimizer::strip_exif(&gradient_png()).is_err());
+        assert!(Optimizer::strip_exif(&jpeg[..jfif.len() + 4]).is_err());
+    }
+
+    #[test]
+    fn test_watermark_position_and_svg() {
+        assert_eq!("bottom-right".parse::<WatermarkPosition>(), Ok(WatermarkPosition::BottomRight));
+        assert_eq!("Top-Left".parse::<WatermarkPosition>(), Ok(WatermarkPosition::TopLeft));
+        assert!("middle".parse::<WatermarkPosition>().is_err());
+
+        let mut config = WatermarkConfig::new("A&B <draft>");
+        config.font_size = 20;
+        let svg = watermark_svg(&config, 200, 100);
+        assert!(svg.contains(r#"x="190" y="86" "#), "{}", svg);
+        assert!(svg.contains(r#"text-anchor="end""#));
+        assert!(svg.contains(r#"fill-opacity="0.5""#));
+        assert!(svg.contains("A&amp;B &lt;draft&gt;"));
+
+        config.position = WatermarkPosition::TopLeft;
+        assert!(watermark_svg(&config, 200, 100).contains(r#"x="10" y="26" "#));
+    }
+
+    #[test]
+    fn test_apply_watermark_overlay() {
+        let mut img = DynamicImage::ImageRgb8(image::RgbImage::new(200, 100));
+        let config = WatermarkConfig { opacity: 1.0, ..WatermarkConfig::new("MDM") };
+        Optimizer::apply_watermark_overlay(&mut img, &config).unwrap();
+
+        assert!(!img.color().has_alpha());
+        let rgb = img.to_rgb8();
+        let lit = |x0: u32, y0: u32| {
+            (x0..x0 + 100).flat_map(|x| (y0..y0 + 50).map(move |y| (x, y))).any(|(x, y)| rgb.get_pixel(x, y)[0] > 0)
+        };
+        assert!(!lit(0, 0), "top-left must stay untouched");
+        // Text only renders when the system has a font
+        assert_eq!(lit(100, 50), !watermark_fontdb().is_empty());
+    }
+
+    #[test]
+    fn test_optimize_auto_applies_watermark() {
+        let optimizer = Optimizer::with_settings(OptimizeSettings {
+            prefer_webp: false,
+            min_reduction: 1.0,
+            watermark: Some(WatermarkConfig::new("MDM")),
+            ..Default::default()
+        });
+        let original = gradient_png();
+        let result = optimizer.optimize_auto(&original).unwrap();
+        assert_eq!(result.output_format, ImageType::Png);
+
+        // min_reduction 1.0 would otherwise hand back the original bytes
+        let before = image::load_from_memory(&original).unwrap().to_rgb8();
+        let after = image::load_from_memory(&result.data).unwrap().to_rgb8();
+        assert_eq!(after.dimensions(), before.dimensions());
+        assert_eq!(after != before, !watermark_fontdb().is_empty());
+    }
+
+    #[test]
+    fn test_is_progressive_jpeg() {
+        // SOI, APP0 (length 4), SOF2
+        assert!(is_progressive_jpeg(&[0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00, 0xFF, 0xC2, 0x00, 0x0B]));
+        assert!(!is_progressive_jpeg(&[0xFF, 0xD8, 0xFF, 0xC0, 0x00, 0x11]));
+        assert!(!is_progressive_jpeg(b"\x89PNG\r\n\x1a\n"));
+
+        let baseline = Optimizer::new().to_jpeg(&gradient_png()).unwrap();
+        assert!(!is_progressive_jpeg(&baseline));
+    }
+
+    #[test]
+    #[cfg(not(feature = "mozjpeg"))]
+    fn test_progressive_falls_back_to_baseline() {
+        let optimizer = Optimizer::with_settings(OptimizeSettings {
+            jpeg_progressive: true,
+            ..Default::default()
+        });
+        let result = optimizer.optimize_to_format(&gradient_png(), ImageType::Jpeg).unwrap();
+        assert_eq!(ImageType::from_bytes(&result.data), ImageType::Jpeg);
+        assert!(!result.is_progressive);
+    }
+
+    #[test]
+    #[cfg(feature = "mozjpeg")]
+    fn test_progressive_jpeg_has_sof2_marker() {
+        let optimizer = Optimizer::with_settings(OptimizeSettings {
+            jpeg_progressive: true,
+            ..Default::default()
+        });
+        let result = optimizer.optimize_to_format(&gradient_png(), ImageType::Jpeg).unwrap();
+        assert!(result.data.windows(2).any(|w| w == [0xFF, 0xC2]));
+        assert!(result.is_progressive);
+    }
 }
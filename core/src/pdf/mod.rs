@@ -16,9 +16,19 @@ pub use pdf_ocr::{ocr_pdf_with_rasterizer, OcrPdfOptions, OcrTextBlock, PageOcr,
 pub use pdf_ocr::ocr_pdf;
 
 pub use parser::{
+    parse_page_range,
+    ocr_png,
+    OCR_DPI,
     PdfParser,
     PdfDocument,
+    ColorMode,
+    ParseOptions,
+    TocEntry,
+    PdfLink,
+    FormField,
+    FormFieldType,
     PdfError,
+    PdfParseResult,
     EncryptionInfo,
     LayoutElement,
     LayoutElementType,
@@ -29,4 +39,6 @@ pub use parser::{
     PdfTable,
     PageContent,
     ImageFormat,
+    PageRenderCache,
+    PageRenderKey,
 };
@@ -8,6 +8,7 @@ use flate2::read::ZlibDecoder;
 use rayon::prelude::*;
 use std::fs::File;
 use std::io::{self, Read};
+use std::ops::RangeInclusive;
 use std::path::Path;
 use thiserror::Error;
 
@@ -26,10 +27,52 @@ pub enum PdfError {
     #[error("PDF parsing error: {0}")]
     ParseError(String),
 
+    #[error("Object {0} {1} R not found")]
+    ObjectNotFound(u32, u16),
+
+    #[error("Invalid PDF structure: {0}")]
+    InvalidStructure(String),
+
+    #[error("Stream decompression failed: {0}")]
+    DecompressionFailed(String),
+
+    #[error("Unsupported color space: {0}")]
+    UnsupportedColorSpace(String),
+
+    #[error("Invalid page range: {0}")]
+    InvalidPageRange(String),
+
     #[error("IO error: {0}")]
     IoError(#[from] io::Error),
 }
 
+/// Result of the fallible [`PdfParser`] operations
+pub type PdfParseResult<T> = Result<T, PdfError>;
+
+impl From<lopdf::Error> for PdfError {
+    /// lopdf failures all stem from malformed or unsupported file structure;
+    /// lookups of a known object id map to [`PdfError::ObjectNotFound`]
+    /// at the call site instead
+    fn from(e: lopdf::Error) -> Self {
+        PdfError::InvalidStructure(e.to_string())
+    }
+}
+
+impl From<PdfError> for io::Error {
+    /// For callers that still speak `io::Result`: I/O errors pass through,
+    /// bad page ranges are `InvalidInput`, encryption problems
+    /// `PermissionDenied`, everything else `InvalidData`
+    fn from(e: PdfError) -> Self {
+        let kind = match e {
+            PdfError::IoError(inner) => return inner,
+            PdfError::InvalidPageRange(_) => io::ErrorKind::InvalidInput,
+            PdfError::EncryptedNoPassword | PdfError::InvalidPassword => io::ErrorKind::PermissionDenied,
+            _ => io::ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, e)
+    }
+}
+
 /// PDF encryption information
 #[derive(Debug, Clone)]
 pub struct EncryptionInfo {
@@ -148,6 +191,128 @@ pub struct PdfDocument {
     pub fonts: Vec<PdfFont>,
     pub tables: Vec<PdfTable>,
     pub layout: Vec<LayoutElement>,
+    /// Outline (bookmark) tree; empty when the PDF has no `/Outlines`
+    pub toc: Vec<TocEntry>,
+    /// External links from `/Link` annotations with a `/URI` action
+    pub hyperlinks: Vec<PdfLink>,
+    /// Fillable form fields from the catalog `/AcroForm`
+    pub form_fields: Vec<FormField>,
+    /// Column-aware reading-order text (see [`PdfParser::to_text_with_layout`]),
+    /// only with [`ParseOptions::preserve_layout`]
+    pub layout_text: Option<String>,
+    /// Color model of images and fill/stroke operators (see
+    /// [`PdfParser::detect_color_mode`])
+    pub color_mode: ColorMode,
+}
+
+/// Device color model used by a document, for print preflight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColorMode {
+    /// Only gray (also the result for a document that sets no color, since
+    /// the initial fill color is `DeviceGray` black)
+    #[default]
+    Grayscale,
+    Rgb,
+    Cmyk,
+    /// More than one of the above
+    Mixed,
+}
+
+impl ColorMode {
+    /// Mode of a document containing content in both `self` and `other`
+    pub fn combine(self, other: ColorMode) -> ColorMode {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (ColorMode::Grayscale, b) => b,
+            (a, ColorMode::Grayscale) => a,
+            _ => ColorMode::Mixed,
+        }
+    }
+}
+
+/// Which device color models a document touches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct ColorUsage {
+    gray: bool,
+    rgb: bool,
+    cmyk: bool,
+}
+
+impl ColorUsage {
+    fn mark(&mut self, components: usize) {
+        match components {
+            1 => self.gray = true,
+            3 => self.rgb = true,
+            4 => self.cmyk = true,
+            _ => {}
+        }
+    }
+
+    fn mode(self) -> ColorMode {
+        match (self.gray, self.rgb, self.cmyk) {
+            (_, false, false) => ColorMode::Grayscale,
+            (false, true, false) => ColorMode::Rgb,
+            (false, false, true) => ColorMode::Cmyk,
+            _ => ColorMode::Mixed,
+        }
+    }
+}
+
+/// Options for [`PdfParser::parse_with_options`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParseOptions {
+    /// Also fill [`PdfDocument::layout_text`]
+    pub preserve_layout: bool,
+}
+
+/// AcroForm field kind, from `/FT` and the button/choice `/Ff` flags
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormFieldType {
+    /// `/Tx`
+    Text,
+    /// `/Btn` without the radio flag
+    Checkbox,
+    /// `/Btn` with the radio flag (bit 16)
+    Radio,
+    /// `/Ch` (combo or list box)
+    Select,
+}
+
+/// One terminal AcroForm field
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormField {
+    /// Fully qualified name: parent `/T` values joined with `.`
+    pub name: String,
+    pub field_type: FormFieldType,
+    /// `/V`, decoded; checkbox/radio states keep their name (`Yes`, `Off`, …)
+    pub value: Option<String>,
+    /// 1-based page of the field's widget; 0 when it cannot be resolved
+    pub page: usize,
+}
+
+/// Hyperlink annotation (`/Subtype /Link` with a `/URI` action)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfLink {
+    /// 1-based page number
+    pub page: usize,
+    pub url: String,
+    /// Annotation `/Rect` as `[x1, y1, x2, y2]` in user space, normalized so
+    /// `x1 <= x2` and `y1 <= y2`
+    pub rect: [f64; 4],
+    /// Text drawn inside `rect`, if any could be matched
+    pub linked_text: Option<String>,
+}
+
+/// One outline (bookmark) entry of the PDF `/Outlines` tree
+#[derive(Debug, Clone, PartialEq)]
+pub struct TocEntry {
+    pub title: String,
+    /// 1-based target page; 0 when the destination cannot be resolved
+    pub page: usize,
+    /// Nesting depth, 1 for top-level entries
+    pub level: u32,
+    pub children: Vec<TocEntry>,
 }
 
 /// Extracted image from PDF
@@ -176,6 +341,12 @@ pub struct PdfFont {
     pub base_font: String,
     pub is_bold: bool,
     pub is_italic: bool,
+    /// Font program is embedded (`/FontFile`, `/FontFile2` or `/FontFile3`
+    /// in the descriptor; Type3 glyphs are always inline)
+    pub is_embedded: bool,
+    /// `/Encoding` name, or the `/BaseEncoding` of an encoding dictionary;
+    /// empty when the font uses its built-in encoding
+    pub encoding: String,
 }
 
 /// Font style detected from font name analysis
@@ -207,6 +378,10 @@ pub struct PdfTable {
     pub y_top: f64,
     /// Bottom Y coordinate (lowest Y in PDF space) of the detected table region.
     pub y_bottom: f64,
+    /// Column anchor X positions found by the text-position detector, for
+    /// diagnostics (a number column's anchor is its right edge). Empty for
+    /// ruling-line tables.
+    pub column_positions: Vec<f64>,
 }
 
 /// Content of a single PDF page
@@ -214,6 +389,9 @@ pub struct PdfTable {
 pub struct PageContent {
     pub page_number: usize,
     pub text: String,
+    /// 0-based index of the input this page came from in
+    /// [`PdfParser::merge`]; always 0 for a single document
+    pub source_document: usize,
 }
 
 /// PDF metadata
@@ -224,6 +402,8 @@ pub struct PdfMetadata {
     pub subject: String,
     pub creator: String,
     pub producer: String,
+    /// Declared natural language (`/Lang`, e.g. `"ko-KR"`)
+    pub language: Option<String>,
 }
 
 impl PdfParser {
@@ -231,14 +411,14 @@ impl PdfParser {
     ///
     /// File size is capped at `MAX_PDF_FILE` (512 MB) so a pathological
     /// input cannot exhaust process memory before we even start parsing.
-    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+    pub fn open<P: AsRef<Path>>(path: P) -> PdfParseResult<Self> {
         let path = path.as_ref().to_path_buf();
         let mut file = File::open(&path)?;
         let data = read_limited(&mut file, MAX_PDF_FILE)?;
 
         // Validate PDF magic bytes
         if data.len() < 5 || &data[0..5] != b"%PDF-" {
-            return Err(io::Error::new(io::ErrorKind::InvalidData, "Not a valid PDF file"));
+            return Err(PdfError::InvalidStructure("Not a valid PDF file (missing %PDF- header)".to_string()));
         }
 
         Ok(PdfParser { path, data })
@@ -248,12 +428,9 @@ impl PdfParser {
     ///
     /// This is the primary constructor for WASM environments where
     /// file system access is unavailable.
-    pub fn from_bytes(data: Vec<u8>) -> io::Result<Self> {
+    pub fn from_bytes(data: Vec<u8>) -> PdfParseResult<Self> {
         if data.len() < 5 || &data[0..5] != b"%PDF-" {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                "Not a valid PDF file",
-            ));
+            return Err(PdfError::InvalidStructure("Not a valid PDF file (missing %PDF- header)".to_string()));
         }
         Ok(PdfParser {
             path: std::path::PathBuf::from("<memory>"),
@@ -265,7 +442,7 @@ impl PdfParser {
     ///
     /// Unlike [`parse`], this method uses `pdf_extract::extract_text_from_mem`
     /// so it works in WASM and other sandboxed environments.
-    pub fn parse_from_memory(&self) -> io::Result<PdfDocument> {
+    pub fn parse_from_memory(&self) -> PdfParseResult<PdfDocument> {
         let version = self.extract_version();
 
         // In-memory path cannot use external `pdftotext` fallback (it needs a
@@ -277,12 +454,11 @@ impl PdfParser {
             pdf_extract::extract_text_from_mem(&data)
         }))
         .map_err(|_| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                "PDF extraction panicked (likely CJK CID font — use parse() for pdftotext fallback)",
+            PdfError::ParseError(
+                "PDF extraction panicked (likely CJK CID font — use parse() for pdftotext fallback)".to_string(),
             )
         })?
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("PDF extraction failed: {}", e)))?;
+        .map_err(|e| PdfError::ParseError(format!("PDF extraction failed: {}", e)))?;
 
         let page_count = self.get_page_count().unwrap_or(1);
         let pages = self.split_into_pages(&full_text, page_count);
@@ -291,6 +467,10 @@ impl PdfParser {
         let fonts = self.extract_fonts();
         let tables = self.detect_tables();
         let layout = self.extract_layout();
+        let toc = self.extract_toc();
+        let hyperlinks = self.extract_hyperlinks();
+        let form_fields = self.extract_form_fields();
+        let color_mode = self.detect_color_mode();
 
         Ok(PdfDocument {
             version,
@@ -301,6 +481,11 @@ impl PdfParser {
             fonts,
             tables,
             layout,
+            toc,
+            hyperlinks,
+            form_fields,
+            layout_text: None,
+            color_mode,
         })
     }
 
@@ -516,6 +701,107 @@ impl PdfParser {
         }
     }
 
+    /// Render one page (1-based) to PNG at `dpi` with an external
+    /// rasterizer: `pdftoppm` (poppler) when installed, else `mutool`
+    /// (MuPDF). Fails with `ParseError("no renderer available")` when
+    /// neither is on PATH.
+    pub fn render_page_to_png(&self, page: usize, dpi: u32) -> Result<Vec<u8>, PdfError> {
+        let page_count = self.page_count()?;
+        if page == 0 || page > page_count {
+            return Err(PdfError::ParseError(format!(
+                "page {} out of range (1-{})",
+                page, page_count
+            )));
+        }
+        if dpi == 0 {
+            return Err(PdfError::ParseError("dpi must be positive".to_string()));
+        }
+        let renderer = page_renderer()
+            .ok_or_else(|| PdfError::ParseError("no renderer available".to_string()))?;
+
+        // In-memory parsers have no file the tool can read
+        let temp_input = (!self.path.is_file()).then(|| {
+            std::env::temp_dir().join(format!("mdm_render_in_{}.pdf", std::process::id()))
+        });
+        if let Some(ref temp) = temp_input {
+            std::fs::write(temp, &self.data)?;
+        }
+        let input = temp_input.as_deref().unwrap_or(&self.path);
+        let output_stem =
+            std::env::temp_dir().join(format!("mdm_render_{}_p{}", std::process::id(), page));
+        let output_png = output_stem.with_extension("png");
+
+        let status = renderer.command(input, &output_stem, &output_png, page, dpi).output();
+        if let Some(ref temp) = temp_input {
+            let _ = std::fs::remove_file(temp);
+        }
+
+        let result = match status {
+            Ok(out) if out.status.success() => std::fs::read(&output_png).map_err(PdfError::from),
+            Ok(out) => Err(PdfError::ParseError(format!(
+                "{} failed: {}",
+                renderer.name(),
+                String::from_utf8_lossy(&out.stderr).trim()
+            ))),
+            Err(e) => Err(PdfError::IoError(e)),
+        };
+        let _ = std::fs::remove_file(&output_png);
+        result
+    }
+
+    /// [`Self::render_page_to_png`] through `cache`, keyed by
+    /// `(path, page, dpi, mtime)` so edited files are re-rendered.
+    /// In-memory parsers have no path or mtime and always render.
+    pub fn render_page_to_png_cached(
+        &self,
+        page: usize,
+        dpi: u32,
+        cache: &PageRenderCache,
+    ) -> Result<Vec<u8>, PdfError> {
+        let key = std::fs::canonicalize(&self.path)
+            .and_then(|path| Ok((std::fs::metadata(&path)?.modified()?, path)))
+            .ok()
+            .map(|(mtime, path)| (path, page, dpi, mtime));
+        let Some(key) = key else {
+            return self.render_page_to_png(page, dpi);
+        };
+        if let Some(png) = cache.get(&key) {
+            return Ok(png);
+        }
+        let png = self.render_page_to_png(page, dpi)?;
+        if let Err(e) = cache.insert(&key, &png) {
+            eprintln!("Warning: Could not write render cache entry: {}", e);
+        }
+        Ok(png)
+    }
+
+    /// OCR one page (1-based) with `tesseract` in `lang` (e.g. `"kor"`,
+    /// `"kor+eng"`).
+    ///
+    /// The page is rendered with [`Self::render_page_to_png`] at
+    /// [`OCR_DPI`] and recognized by [`ocr_png`], so both a rasterizer and
+    /// `tesseract` must be on PATH.
+    pub fn ocr_page(&self, page: usize, lang: &str) -> Result<String, PdfError> {
+        let png = self.render_page_to_png(page, OCR_DPI)?;
+        ocr_png(&png, lang)
+    }
+
+    /// [`Self::parse`], then replace every page's text with its
+    /// [`Self::ocr_page`] result — for scanned PDFs without a text layer.
+    ///
+    /// Layout elements and tables come from the text layer, so they are
+    /// dropped and [`PdfDocument::to_mdx`] renders the OCR page text.
+    pub fn ocr_all(&self, lang: &str) -> Result<PdfDocument, PdfError> {
+        let mut doc = self.parse()?;
+        for page in &mut doc.pages {
+            page.text = self.ocr_page(page.page_number, lang)?;
+        }
+        doc.layout.clear();
+        doc.tables.clear();
+        doc.layout_text = None;
+        Ok(doc)
+    }
+
     /// Check if decryption is needed and attempt with empty password
     pub fn try_auto_decrypt(&self) -> Result<Self, PdfError> {
         if !self.is_encrypted() {
@@ -899,7 +1185,12 @@ impl PdfParser {
     }
 
     /// Parse the PDF document
-    pub fn parse(&self) -> io::Result<PdfDocument> {
+    pub fn parse(&self) -> PdfParseResult<PdfDocument> {
+        self.parse_with_options(ParseOptions::default())
+    }
+
+    /// [`Self::parse`] with extra outputs selected by `options`
+    pub fn parse_with_options(&self, options: ParseOptions) -> PdfParseResult<PdfDocument> {
         let version = self.extract_version();
 
         // Use pdf-extract for text extraction; catch panics from CJK CID
@@ -928,6 +1219,19 @@ impl PdfParser {
 
         // Extract layout information for heading/bold/italic detection
         let layout = self.extract_layout();
+        let layout_text = options.preserve_layout.then(|| layout_reading_order(&layout));
+
+        // Outline / bookmarks
+        let toc = self.extract_toc();
+
+        // Link annotations
+        let hyperlinks = self.extract_hyperlinks();
+
+        // AcroForm fields
+        let form_fields = self.extract_form_fields();
+
+        // Device color model, for print preflight
+        let color_mode = self.detect_color_mode();
 
         Ok(PdfDocument {
             version,
@@ -938,9 +1242,331 @@ impl PdfParser {
             fonts,
             tables,
             layout,
+            toc,
+            hyperlinks,
+            form_fields,
+            layout_text,
+            color_mode,
         })
     }
 
+    /// Parse several PDFs and concatenate them into one document.
+    ///
+    /// Pages are renumbered sequentially across inputs and tagged with
+    /// [`PageContent::source_document`]; page references in images, tables,
+    /// layout, outline, links and form fields are shifted by the same offset
+    /// (unresolved `0` targets stay `0`). Metadata comes from the first
+    /// document, fonts are de-duplicated by base name, the version is the
+    /// lowest one among the inputs and the color modes are combined.
+    pub fn merge(parsers: Vec<PdfParser>) -> io::Result<PdfDocument> {
+        if parsers.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "no documents to merge"));
+        }
+
+        let mut merged: Option<PdfDocument> = None;
+        let mut offset = 0;
+        for (idx, parser) in parsers.iter().enumerate() {
+            let mut doc = parser.parse()?;
+            let shift = |page: usize| if page == 0 { 0 } else { page + offset };
+            for page in &mut doc.pages {
+                page.page_number += offset;
+                page.source_document = idx;
+            }
+            for image in &mut doc.images {
+                image.page = image.page.map(shift);
+            }
+            for table in &mut doc.tables {
+                table.page = shift(table.page);
+            }
+            for element in &mut doc.layout {
+                element.page = shift(element.page);
+            }
+            shift_toc(&mut doc.toc, &shift);
+            for link in &mut doc.hyperlinks {
+                link.page = shift(link.page);
+            }
+            for field in &mut doc.form_fields {
+                field.page = shift(field.page);
+            }
+            offset += doc.page_count;
+
+            let Some(acc) = merged.as_mut() else {
+                merged = Some(doc);
+                continue;
+            };
+            if version_key(&doc.version) < version_key(&acc.version) {
+                acc.version = doc.version;
+            }
+            acc.page_count += doc.page_count;
+            acc.pages.extend(doc.pages);
+            acc.images.extend(doc.images);
+            for font in doc.fonts {
+                if !acc.fonts.iter().any(|f| f.base_font == font.base_font) {
+                    acc.fonts.push(font);
+                }
+            }
+            acc.tables.extend(doc.tables);
+            acc.layout.extend(doc.layout);
+            acc.toc.extend(doc.toc);
+            acc.hyperlinks.extend(doc.hyperlinks);
+            acc.form_fields.extend(doc.form_fields);
+            acc.color_mode = acc.color_mode.combine(doc.color_mode);
+        }
+
+        Ok(merged.expect("at least one parser"))
+    }
+
+    /// Parse only the pages in `range` (1-based, inclusive).
+    ///
+    /// Pages outside the range are deleted from a lopdf copy before text,
+    /// layout, table and image extraction run, so a 3-page slice of a
+    /// 200-page spec only pays for 3 pages. Page numbers in the result
+    /// (`PageContent::page_number`, layout/table/image pages) refer to the
+    /// original document; `page_count` is the number of pages parsed.
+    ///
+    /// Returns [`PdfError::InvalidPageRange`] if the range is empty, starts
+    /// at 0, or ends past the last page — it is never silently truncated.
+    pub fn parse_pages(&self, range: RangeInclusive<usize>) -> PdfParseResult<PdfDocument> {
+        let mut doc = lopdf::Document::load_mem(&self.data)?;
+        let total = doc.get_pages().len();
+        let (start, end) = (*range.start(), *range.end());
+        if start == 0 || start > end {
+            return Err(PdfError::InvalidPageRange(format!(
+                "{}-{} (pages are 1-based and START must not exceed END)",
+                start, end
+            )));
+        }
+        if end > total {
+            return Err(PdfError::InvalidPageRange(format!(
+                "{}-{} exceeds document page count ({})",
+                start, end, total
+            )));
+        }
+
+        let outside: Vec<u32> = (1..=total)
+            .filter(|p| !range.contains(p))
+            .map(|p| p as u32)
+            .collect();
+        if !outside.is_empty() {
+            doc.delete_pages(&outside);
+            doc.prune_objects();
+        }
+        let mut data = Vec::new();
+        doc.save_to(&mut data)?;
+
+        // `parse()` needs a real path for the pdftotext fallback
+        let tmp = tempfile::Builder::new().suffix(".pdf").tempfile()?;
+        std::fs::write(tmp.path(), &data)?;
+        let subset = PdfParser { path: tmp.path().to_path_buf(), data };
+        let mut parsed = subset.parse()?;
+
+        let offset = start - 1;
+        for page in &mut parsed.pages {
+            page.page_number += offset;
+        }
+        for elem in &mut parsed.layout {
+            elem.page += offset;
+        }
+        for table in &mut parsed.tables {
+            table.page += offset;
+        }
+        for image in &mut parsed.images {
+            if let Some(page) = image.page.as_mut() {
+                *page += offset;
+            }
+        }
+        for link in &mut parsed.hyperlinks {
+            link.page += offset;
+        }
+        // The subset's outline points at deleted pages; use the original
+        // one, restricted to the requested range.
+        parsed.toc = toc_within(self.extract_toc(), &range);
+        // Likewise the AcroForm still lists fields on deleted pages
+        parsed.form_fields = self
+            .extract_form_fields()
+            .into_iter()
+            .filter(|field| range.contains(&field.page))
+            .collect();
+        Ok(parsed)
+    }
+
+    /// Extract the document outline (bookmarks) as a tree.
+    ///
+    /// Walks `/Outlines` → `/First` / `/Next` recursively. Destinations are
+    /// resolved from `/Dest` or a `/GoTo` action's `/D`, including named
+    /// destinations in the catalog `/Dests` dictionary or `/Names` tree.
+    /// Returns an empty list when the PDF has no outline.
+    pub fn extract_toc(&self) -> Vec<TocEntry> {
+        let Ok(doc) = lopdf::Document::load_mem(&self.data) else {
+            return Vec::new();
+        };
+        let Some(catalog) = doc
+            .trailer
+            .get(b"Root")
+            .and_then(lopdf::Object::as_reference)
+            .and_then(|id| doc.get_dictionary(id))
+            .ok()
+        else {
+            return Vec::new();
+        };
+        let Some(outlines) = catalog
+            .get(b"Outlines")
+            .ok()
+            .and_then(|o| resolve_object(&doc, o).as_dict().ok())
+        else {
+            return Vec::new();
+        };
+
+        let pages: std::collections::HashMap<lopdf::ObjectId, usize> = doc
+            .get_pages()
+            .into_iter()
+            .map(|(number, id)| (id, number as usize))
+            .collect();
+        let mut named = std::collections::HashMap::new();
+        collect_named_dests(&doc, catalog, &mut named);
+
+        let ctx = OutlineContext { doc: &doc, pages: &pages, named: &named };
+        let first = outlines.get(b"First").and_then(lopdf::Object::as_reference).ok();
+        ctx.entries(first, 1, &mut std::collections::HashSet::new())
+    }
+
+    /// Extract external hyperlinks from each page's `/Annots` array.
+    ///
+    /// Only `/Link` annotations whose `/A` action is `/S /URI` are returned;
+    /// internal `/GoTo` links are already covered by [`Self::extract_toc`]'s
+    /// page anchors. `linked_text` joins the positioned text runs whose
+    /// origin falls inside the annotation rectangle, so a run that starts in
+    /// the link and continues past it is included whole.
+    pub fn extract_hyperlinks(&self) -> Vec<PdfLink> {
+        let Ok(doc) = lopdf::Document::load_mem(&self.data) else {
+            return Vec::new();
+        };
+
+        let mut links = Vec::new();
+        for (page_num, page_id) in doc.get_pages() {
+            let Some(annots) = doc
+                .get_dictionary(page_id)
+                .ok()
+                .and_then(|page| page.get(b"Annots").ok())
+                .and_then(|a| resolve_object(&doc, a).as_array().ok())
+            else {
+                continue;
+            };
+
+            // Positioned text is only computed for pages that carry links
+            let mut texts: Option<Vec<PositionedText>> = None;
+            for annot in annots {
+                let Ok(annot) = resolve_object(&doc, annot).as_dict() else {
+                    continue;
+                };
+                let Some((url, rect)) = uri_link(&doc, annot) else {
+                    continue;
+                };
+                let texts = texts.get_or_insert_with(|| self.extract_positioned_text(&doc, page_id));
+                links.push(PdfLink {
+                    page: page_num as usize,
+                    url,
+                    rect,
+                    linked_text: text_in_rect(texts, rect),
+                });
+            }
+        }
+        links
+    }
+
+    /// Extract fillable form fields from the catalog `/AcroForm`.
+    ///
+    /// Walks `/Fields` and their `/Kids`, inheriting `/FT` and `/V` from
+    /// parent fields as the spec requires. Push buttons and signature fields
+    /// carry no user value and are skipped. The page comes from the widget's
+    /// `/P` entry, falling back to the page whose `/Annots` lists the widget.
+    pub fn extract_form_fields(&self) -> Vec<FormField> {
+        let Ok(doc) = lopdf::Document::load_mem(&self.data) else {
+            return Vec::new();
+        };
+        let Some(fields) = doc
+            .trailer
+            .get(b"Root")
+            .and_then(lopdf::Object::as_reference)
+            .and_then(|id| doc.get_dictionary(id))
+            .ok()
+            .and_then(|catalog| catalog.get(b"AcroForm").ok())
+            .and_then(|form| resolve_object(&doc, form).as_dict().ok())
+            .and_then(|form| form.get(b"Fields").ok())
+            .and_then(|fields| resolve_object(&doc, fields).as_array().ok())
+        else {
+            return Vec::new();
+        };
+
+        let mut pages = std::collections::HashMap::new();
+        let mut annot_pages = std::collections::HashMap::new();
+        for (number, page_id) in doc.get_pages() {
+            pages.insert(page_id, number as usize);
+            if let Some(annots) = doc
+                .get_dictionary(page_id)
+                .ok()
+                .and_then(|page| page.get(b"Annots").ok())
+                .and_then(|a| resolve_object(&doc, a).as_array().ok())
+            {
+                for annot in annots {
+                    if let Ok(id) = annot.as_reference() {
+                        annot_pages.insert(id, number as usize);
+                    }
+                }
+            }
+        }
+
+        let ctx = FormContext { doc: &doc, pages: &pages, annot_pages: &annot_pages };
+        let mut out = Vec::new();
+        let mut visited = std::collections::HashSet::new();
+        for field in fields {
+            ctx.collect(field, &FieldInherited::default(), 0, &mut visited, &mut out);
+        }
+        out
+    }
+
+    /// Number of pages, read from the lopdf page tree without extracting text
+    pub fn page_count(&self) -> PdfParseResult<usize> {
+        let doc = lopdf::Document::load_mem(&self.data)?;
+        Ok(doc.get_pages().len())
+    }
+
+    /// Write each page to `output_dir` as a standalone PDF named
+    /// `{stem}_page_{N:04}.pdf`, returning the created paths in page order.
+    ///
+    /// Every object a page reaches (content streams, fonts, XObjects, ...)
+    /// is copied into its split document, including resources and boxes
+    /// the page inherits from its `Pages` ancestors. Links into other pages
+    /// are dropped rather than dragging the rest of the document along.
+    pub fn split_by_page(&self, output_dir: &Path) -> PdfParseResult<Vec<std::path::PathBuf>> {
+        let doc = lopdf::Document::load_mem(&self.data)?;
+        std::fs::create_dir_all(output_dir)?;
+        let stem = match self.path.file_stem() {
+            Some(stem) if self.path != Path::new("<memory>") => stem.to_string_lossy().into_owned(),
+            _ => "document".to_string(),
+        };
+
+        let mut created = Vec::new();
+        for (number, page_id) in doc.get_pages() {
+            let mut single = single_page_document(&doc, page_id)?;
+            let path = output_dir.join(format!("{}_page_{:04}.pdf", stem, number));
+            let mut file = File::create(&path)?;
+            single.save_to(&mut file)?;
+            created.push(path);
+        }
+        Ok(created)
+    }
+
+    /// Page text in reading order that respects two-column layouts.
+    ///
+    /// Unlike [`PdfDocument::full_text`], which joins extracted text
+    /// linearly, this clusters each page's positioned text runs from
+    /// [`Self::extract_layout`] into one or two columns and reads each
+    /// column top to bottom, left column first.
+    pub fn to_text_with_layout(&self) -> String {
+        layout_reading_order(&self.extract_layout())
+    }
+
     /// Extract all images from PDF
     pub fn extract_images(&self) -> Vec<PdfImage> {
         let mut images = Vec::new();
@@ -982,28 +1608,11 @@ impl PdfParser {
                     continue;
                 }
 
-                // Determine format from filter
-                let filter: Option<Vec<u8>> = dict.get(b"Filter")
-                    .ok()
-                    .and_then(|f| f.as_name().ok())
-                    .map(|n| n.to_vec());
-
-                let (format, data) = match filter.as_deref() {
-                    Some(b"DCTDecode") => {
-                        // JPEG - use raw stream content
-                        (ImageFormat::Jpeg, stream.content.clone())
-                    }
-                    Some(b"FlateDecode") => {
-                        // Compressed data - decompress
-                        match decompress_flate(&stream.content) {
-                            Ok(decompressed) => (ImageFormat::Raw, decompressed),
-                            Err(_) => continue,
-                        }
-                    }
-                    _ => {
-                        // Raw or unsupported format
-                        (ImageFormat::Raw, stream.content.clone())
-                    }
+                // Undecodable streams and spot-color samples are skipped;
+                // `extract_image` reports why
+                let (format, data) = match decode_image_stream(&doc, stream) {
+                    Ok(decoded) => decoded,
+                    Err(_) => continue,
                 };
 
                 image_count += 1;
@@ -1021,6 +1630,94 @@ impl PdfParser {
         images
     }
 
+    /// Extract the image XObject stored as object `id` (`(number, generation)`).
+    ///
+    /// Unlike [`Self::extract_images`], which skips what it cannot use,
+    /// this says why: [`PdfError::ObjectNotFound`] for a dangling id,
+    /// [`PdfError::InvalidStructure`] when the object is not an image
+    /// stream, [`PdfError::DecompressionFailed`] for a corrupt
+    /// `FlateDecode` stream and [`PdfError::UnsupportedColorSpace`] for
+    /// raw samples in a `Separation`, `DeviceN` or `Pattern` space.
+    pub fn extract_image(&self, id: (u32, u16)) -> PdfParseResult<PdfImage> {
+        let doc = lopdf::Document::load_mem(&self.data)?;
+        let object = doc.get_object(id).map_err(|_| PdfError::ObjectNotFound(id.0, id.1))?;
+        let stream = object
+            .as_stream()
+            .map_err(|_| PdfError::InvalidStructure(format!("object {} {} R is not a stream", id.0, id.1)))?;
+        let dict = &stream.dict;
+        if dict.get(b"Subtype").and_then(lopdf::Object::as_name).ok() != Some(b"Image".as_slice()) {
+            return Err(PdfError::InvalidStructure(format!("object {} {} R is not an image XObject", id.0, id.1)));
+        }
+        let dimension = |key: &[u8]| dict.get(key).and_then(lopdf::Object::as_i64).unwrap_or(0).max(0) as u32;
+        let (width, height) = (dimension(b"Width"), dimension(b"Height"));
+        if width == 0 || height == 0 {
+            return Err(PdfError::InvalidStructure(format!("image {} {} R has no size", id.0, id.1)));
+        }
+
+        let (format, data) = decode_image_stream(&doc, stream)?;
+        Ok(PdfImage {
+            id: format!("image_{}_{}", id.0, id.1),
+            width,
+            height,
+            format,
+            data,
+            page: None,
+        })
+    }
+
+    /// Detect the device color model of the document.
+    ///
+    /// Looks at the `/ColorSpace` of every image XObject (`DeviceGray`,
+    /// `DeviceRGB`, `DeviceCMYK`, their `Cal*` forms, `ICCBased` by `/N`
+    /// and the base space of `Indexed`) and at the color operators of each
+    /// page's content stream (`g`/`G`, `rg`/`RG`, `k`/`K`). Spot colors
+    /// (`Separation`, `DeviceN`) are not counted.
+    pub fn detect_color_mode(&self) -> ColorMode {
+        self.color_usage().mode()
+    }
+
+    /// Whether any image or color operator uses CMYK — what a print
+    /// preflight cares about, even when [`Self::detect_color_mode`] reports
+    /// [`ColorMode::Mixed`]
+    pub fn has_cmyk_content(&self) -> bool {
+        self.color_usage().cmyk
+    }
+
+    fn color_usage(&self) -> ColorUsage {
+        use lopdf::content::Content;
+
+        let mut usage = ColorUsage::default();
+        let doc = match lopdf::Document::load_mem(&self.data) {
+            Ok(d) => d,
+            Err(_) => return usage,
+        };
+
+        for object in doc.objects.values() {
+            let Ok(stream) = object.as_stream() else { continue };
+            if stream.dict.get(b"Subtype").and_then(lopdf::Object::as_name).ok() != Some(b"Image".as_slice()) {
+                continue;
+            }
+            // Stencil masks have no color space of their own
+            if let Ok(space) = stream.dict.get(b"ColorSpace") {
+                usage.mark(color_space_components(&doc, space, 0));
+            }
+        }
+
+        for page_id in doc.get_pages().into_values() {
+            let Ok(bytes) = doc.get_page_content(page_id) else { continue };
+            let Ok(content) = Content::decode(&bytes) else { continue };
+            for op in &content.operations {
+                match op.operator.as_str() {
+                    "g" | "G" => usage.gray = true,
+                    "rg" | "RG" => usage.rgb = true,
+                    "k" | "K" => usage.cmyk = true,
+                    _ => {}
+                }
+            }
+        }
+        usage
+    }
+
     /// Extract all fonts from PDF
     pub fn extract_fonts(&self) -> Vec<PdfFont> {
         let mut fonts = Vec::new();
@@ -1070,6 +1767,8 @@ impl PdfParser {
                     base_font: base_font.clone(),
                     is_bold: style.is_bold,
                     is_italic: style.is_italic,
+                    is_embedded: font_is_embedded(&doc, dict),
+                    encoding: font_encoding(&doc, dict),
                 });
             }
         }
@@ -1112,6 +1811,25 @@ impl PdfParser {
         tables
     }
 
+    /// Tables found by the text-position column detector alone, with
+    /// [`PdfTable::column_positions`] filled in.
+    ///
+    /// Columns come from a histogram of text X positions (numbers anchored
+    /// on their right edge), so right-aligned amounts and loosely aligned
+    /// cells share a column. [`Self::detect_tables`] merges these with
+    /// ruling-line tables; use this to inspect how columns were inferred.
+    pub fn extract_tables_structured(&self) -> Vec<PdfTable> {
+        let Ok(doc) = lopdf::Document::load_mem(&self.data) else {
+            return Vec::new();
+        };
+        doc.get_pages()
+            .into_iter()
+            .flat_map(|(page_num, page_id)| {
+                detect_tables_from_positions(&self.extract_positioned_text(&doc, page_id), page_num as usize)
+            })
+            .collect()
+    }
+
     /// Extract text with position from a PDF page.
     ///
     /// Uses lopdf's content-stream decoder to walk PDF text operators
@@ -1370,6 +2088,7 @@ impl PdfParser {
                 .map(|(i, content)| PageContent {
                     page_number: i + 1,
                     text: content.trim().to_string(),
+                    source_document: 0,
                 })
                 .filter(|p| !p.text.is_empty())
                 .collect()
@@ -1383,6 +2102,7 @@ impl PdfParser {
                 .map(|(i, chunk)| PageContent {
                     page_number: i + 1,
                     text: chunk.join("\n").trim().to_string(),
+                    source_document: 0,
                 })
                 .filter(|p| !p.text.is_empty())
                 .collect()
@@ -1391,6 +2111,7 @@ impl PdfParser {
             vec![PageContent {
                 page_number: 1,
                 text: text.trim().to_string(),
+                source_document: 0,
             }]
         }
     }
@@ -1408,16 +2129,71 @@ impl PdfParser {
                         metadata.subject = get_pdf_string(&doc, info_dict, b"Subject");
                         metadata.creator = get_pdf_string(&doc, info_dict, b"Creator");
                         metadata.producer = get_pdf_string(&doc, info_dict, b"Producer");
+                        metadata.language = non_empty(get_pdf_string(&doc, info_dict, b"Lang"));
                     }
                 }
             }
+            // The spec places /Lang in the document catalog; Info is a common
+            // producer quirk, so it is only a fallback here.
+            if let Ok(catalog) = doc
+                .trailer
+                .get(b"Root")
+                .and_then(lopdf::Object::as_reference)
+                .and_then(|id| doc.get_dictionary(id))
+            {
+                if let Some(lang) = non_empty(get_pdf_string(&doc, catalog, b"Lang")) {
+                    metadata.language = Some(lang);
+                }
+            }
         }
         
         metadata
     }
 }
 
+fn non_empty(value: String) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
 /// Helper to get string from PDF dictionary
+/// Number of color components of a `/ColorSpace` value (1 gray, 3 RGB,
+/// 4 CMYK), or 0 for spot, pattern and unrecognised spaces
+fn color_space_components(doc: &lopdf::Document, space: &lopdf::Object, depth: u8) -> usize {
+    use lopdf::Object;
+
+    if depth > 4 {
+        return 0;
+    }
+    match resolve_object(doc, space) {
+        Object::Name(name) => match name.as_slice() {
+            b"DeviceGray" | b"CalGray" | b"G" => 1,
+            b"DeviceRGB" | b"CalRGB" | b"RGB" => 3,
+            b"DeviceCMYK" | b"CMYK" => 4,
+            _ => 0,
+        },
+        Object::Array(items) => {
+            let family = items.first().map(|f| resolve_object(doc, f));
+            match family.and_then(|f| f.as_name().ok()) {
+                Some(b"ICCBased") => items
+                    .get(1)
+                    .map(|profile| resolve_object(doc, profile))
+                    .and_then(|profile| profile.as_stream().ok())
+                    .and_then(|profile| profile.dict.get(b"N").and_then(Object::as_i64).ok())
+                    .map_or(0, |n| n.max(0) as usize),
+                Some(b"Indexed") | Some(b"I") => items
+                    .get(1)
+                    .map_or(0, |base| color_space_components(doc, base, depth + 1)),
+                Some(_) => items
+                    .first()
+                    .map_or(0, |family| color_space_components(doc, family, depth + 1)),
+                None => 0,
+            }
+        }
+        _ => 0,
+    }
+}
+
 fn get_pdf_string(_doc: &lopdf::Document, dict: &lopdf::Dictionary, key: &[u8]) -> String {
     if let Ok(obj) = dict.get(key) {
         match obj {
@@ -1433,25 +2209,570 @@ fn get_pdf_string(_doc: &lopdf::Document, dict: &lopdf::Dictionary, key: &[u8])
     }
 }
 
-/// Decompress FlateDecode (zlib) data with a hard output ceiling
-/// (`MAX_PDF_STREAM` = 128 MB). Guards against PDF decompression bombs.
-fn decompress_flate(data: &[u8]) -> io::Result<Vec<u8>> {
-    let mut decoder = ZlibDecoder::new(data);
-    read_limited(&mut decoder, MAX_PDF_STREAM)
+/// Whether a font dictionary's program is embedded. Type0 fonts are
+/// checked through their descendant CIDFont's descriptor.
+fn font_is_embedded(doc: &lopdf::Document, font: &lopdf::Dictionary) -> bool {
+    let subtype = font.get(b"Subtype").ok().and_then(|s| s.as_name().ok());
+    if subtype == Some(b"Type3".as_slice()) {
+        return true;
+    }
+    if subtype == Some(b"Type0".as_slice()) {
+        return font
+            .get(b"DescendantFonts")
+            .ok()
+            .and_then(|d| resolve_object(doc, d).as_array().ok())
+            .and_then(|fonts| fonts.first())
+            .and_then(|f| resolve_object(doc, f).as_dict().ok())
+            .is_some_and(|descendant| font_is_embedded(doc, descendant));
+    }
+    font.get(b"FontDescriptor")
+        .ok()
+        .and_then(|d| resolve_object(doc, d).as_dict().ok())
+        .is_some_and(|descriptor| {
+            [b"FontFile".as_slice(), b"FontFile2", b"FontFile3"]
+                .iter()
+                .any(|key| descriptor.has(key))
+        })
 }
 
-/// Extract numeric value from PDF object (handles both Integer and Real types)
-fn extract_number(obj: &lopdf::Object) -> Option<f64> {
-    match obj {
-        lopdf::Object::Integer(i) => Some(*i as f64),
-        lopdf::Object::Real(f) => Some(*f as f64),
-        _ => None,
+/// A font's `/Encoding`: the name itself, or `/BaseEncoding` of an
+/// encoding dictionary (`Custom` when it only lists `/Differences`)
+fn font_encoding(doc: &lopdf::Document, font: &lopdf::Dictionary) -> String {
+    let Ok(encoding) = font.get(b"Encoding") else {
+        return String::new();
+    };
+    match resolve_object(doc, encoding) {
+        lopdf::Object::Name(name) => String::from_utf8_lossy(name).into_owned(),
+        lopdf::Object::Dictionary(dict) => dict
+            .get(b"BaseEncoding")
+            .ok()
+            .and_then(|b| b.as_name().ok())
+            .map(|b| String::from_utf8_lossy(b).into_owned())
+            .unwrap_or_else(|| "Custom".to_string()),
+        _ => String::new(),
     }
 }
 
-/// Detect font style (bold/italic) from font name
-fn detect_font_style(font_name: &str) -> FontStyle {
-    let name_lower = font_name.to_lowercase();
+/// Follow an indirect reference; other objects are returned as-is
+/// Page attributes a `Page` may inherit from its `Pages` ancestors
+const INHERITABLE_PAGE_KEYS: [&[u8]; 4] = [b"Resources", b"MediaBox", b"CropBox", b"Rotate"];
+
+/// Minimal one-page document holding `page_id` and everything it references
+fn single_page_document(doc: &lopdf::Document, page_id: lopdf::ObjectId) -> PdfParseResult<lopdf::Document> {
+    let mut page = doc
+        .get_dictionary(page_id)
+        .map_err(|_| PdfError::ObjectNotFound(page_id.0, page_id.1))?
+        .clone();
+
+    // Pull inherited attributes down before the page leaves its tree
+    let mut visited = std::collections::HashSet::new();
+    let mut parent = page.get(b"Parent").and_then(lopdf::Object::as_reference).ok();
+    while let Some(id) = parent.filter(|id| visited.insert(*id)) {
+        let Ok(node) = doc.get_dictionary(id) else { break };
+        for key in INHERITABLE_PAGE_KEYS {
+            if !page.has(key) {
+                if let Ok(value) = node.get(key) {
+                    page.set(key, value.clone());
+                }
+            }
+        }
+        parent = node.get(b"Parent").and_then(lopdf::Object::as_reference).ok();
+    }
+    page.remove(b"Parent");
+
+    let mut out = lopdf::Document::with_version(doc.version.clone());
+    let new_page_id = out.new_object_id();
+    let mut copied = std::collections::HashMap::from([(page_id, new_page_id)]);
+    let mut page = copy_pdf_dictionary(doc, &mut out, &page, &mut copied);
+
+    let pages_id = out.new_object_id();
+    page.set("Parent", pages_id);
+    out.objects.insert(new_page_id, lopdf::Object::Dictionary(page));
+    out.objects.insert(
+        pages_id,
+        lopdf::Object::Dictionary(lopdf::dictionary! {
+            "Type" => "Pages",
+            "Kids" => vec![new_page_id.into()],
+            "Count" => 1,
+        }),
+    );
+    let catalog_id = out.add_object(lopdf::dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    out.trailer.set("Root", catalog_id);
+    Ok(out)
+}
+
+/// Deep-copy `obj` from `src` into `dst`, renumbering references.
+///
+/// `copied` maps source ids to their `dst` ids so shared objects are copied
+/// once and reference cycles terminate. References to other pages become
+/// `null`; following them would pull in the whole page tree.
+fn copy_pdf_object(
+    src: &lopdf::Document,
+    dst: &mut lopdf::Document,
+    obj: &lopdf::Object,
+    copied: &mut std::collections::HashMap<lopdf::ObjectId, lopdf::ObjectId>,
+) -> lopdf::Object {
+    use lopdf::Object;
+
+    match obj {
+        Object::Reference(id) => {
+            if let Some(new_id) = copied.get(id) {
+                return Object::Reference(*new_id);
+            }
+            let Ok(target) = src.get_object(*id) else {
+                return Object::Null;
+            };
+            let is_page = target
+                .as_dict()
+                .and_then(|d| d.get(b"Type"))
+                .and_then(Object::as_name)
+                .is_ok_and(|name| name == b"Page");
+            if is_page {
+                return Object::Null;
+            }
+            let new_id = dst.new_object_id();
+            copied.insert(*id, new_id);
+            let copy = copy_pdf_object(src, dst, target, copied);
+            dst.objects.insert(new_id, copy);
+            Object::Reference(new_id)
+        }
+        Object::Array(items) => Object::Array(items.iter().map(|item| copy_pdf_object(src, dst, item, copied)).collect()),
+        Object::Dictionary(dict) => Object::Dictionary(copy_pdf_dictionary(src, dst, dict, copied)),
+        Object::Stream(stream) => {
+            let mut stream = stream.clone();
+            stream.dict = copy_pdf_dictionary(src, dst, &stream.dict, copied);
+            Object::Stream(stream)
+        }
+        other => other.clone(),
+    }
+}
+
+fn copy_pdf_dictionary(
+    src: &lopdf::Document,
+    dst: &mut lopdf::Document,
+    dict: &lopdf::Dictionary,
+    copied: &mut std::collections::HashMap<lopdf::ObjectId, lopdf::ObjectId>,
+) -> lopdf::Dictionary {
+    let mut out = lopdf::Dictionary::new();
+    for (key, value) in dict.iter() {
+        out.set(key.clone(), copy_pdf_object(src, dst, value, copied));
+    }
+    out
+}
+
+fn resolve_object<'a>(doc: &'a lopdf::Document, obj: &'a lopdf::Object) -> &'a lopdf::Object {
+    match obj {
+        lopdf::Object::Reference(id) => doc.get_object(*id).unwrap_or(obj),
+        _ => obj,
+    }
+}
+
+/// Decode a PDF text string: UTF-16BE with BOM, else UTF-8 / Latin-1
+fn decode_text_string(bytes: &[u8]) -> String {
+    if let Some(utf16) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        let units: Vec<u16> = utf16
+            .chunks_exact(2)
+            .map(|c| u16::from_be_bytes([c[0], c[1]]))
+            .collect();
+        return String::from_utf16_lossy(&units);
+    }
+    String::from_utf8(bytes.to_vec()).unwrap_or_else(|_| bytes.iter().map(|&b| b as char).collect())
+}
+
+/// Named destinations from the catalog `/Dests` dictionary (PDF 1.1) and
+/// the `/Names` → `/Dests` name tree (PDF 1.2+)
+fn collect_named_dests(
+    doc: &lopdf::Document,
+    catalog: &lopdf::Dictionary,
+    out: &mut std::collections::HashMap<Vec<u8>, lopdf::Object>,
+) {
+    if let Some(dests) = catalog
+        .get(b"Dests")
+        .ok()
+        .and_then(|d| resolve_object(doc, d).as_dict().ok())
+    {
+        for (name, dest) in dests.iter() {
+            out.insert(name.clone(), dest.clone());
+        }
+    }
+    if let Some(tree) = catalog
+        .get(b"Names")
+        .ok()
+        .and_then(|n| resolve_object(doc, n).as_dict().ok())
+        .and_then(|names| names.get(b"Dests").ok())
+        .and_then(|d| resolve_object(doc, d).as_dict().ok())
+    {
+        collect_name_tree(doc, tree, out, 0);
+    }
+}
+
+fn collect_name_tree(
+    doc: &lopdf::Document,
+    node: &lopdf::Dictionary,
+    out: &mut std::collections::HashMap<Vec<u8>, lopdf::Object>,
+    depth: usize,
+) {
+    // Name trees are shallow; the cap only guards against reference cycles
+    if depth > 32 {
+        return;
+    }
+    if let Ok(lopdf::Object::Array(pairs)) = node.get(b"Names").map(|n| resolve_object(doc, n)) {
+        for pair in pairs.chunks_exact(2) {
+            if let lopdf::Object::String(name, _) = resolve_object(doc, &pair[0]) {
+                out.insert(name.clone(), pair[1].clone());
+            }
+        }
+    }
+    if let Ok(lopdf::Object::Array(kids)) = node.get(b"Kids").map(|k| resolve_object(doc, k)) {
+        for kid in kids {
+            if let Ok(child) = resolve_object(doc, kid).as_dict() {
+                collect_name_tree(doc, child, out, depth + 1);
+            }
+        }
+    }
+}
+
+/// Lookup tables shared by the recursive outline walk
+struct OutlineContext<'a> {
+    doc: &'a lopdf::Document,
+    pages: &'a std::collections::HashMap<lopdf::ObjectId, usize>,
+    named: &'a std::collections::HashMap<Vec<u8>, lopdf::Object>,
+}
+
+impl OutlineContext<'_> {
+    /// Sibling chain starting at `first`, with each item's children
+    fn entries(
+        &self,
+        first: Option<lopdf::ObjectId>,
+        level: u32,
+        visited: &mut std::collections::HashSet<lopdf::ObjectId>,
+    ) -> Vec<TocEntry> {
+        let mut entries = Vec::new();
+        let mut next = first;
+        while let Some(id) = next {
+            // Malformed files can link items into a cycle
+            if !visited.insert(id) {
+                break;
+            }
+            let Ok(item) = self.doc.get_dictionary(id) else {
+                break;
+            };
+
+            let title = match item.get(b"Title").map(|t| resolve_object(self.doc, t)) {
+                Ok(lopdf::Object::String(bytes, _)) => decode_text_string(bytes).trim().to_string(),
+                _ => String::new(),
+            };
+            let dest = item.get(b"Dest").ok().or_else(|| {
+                item.get(b"A")
+                    .ok()
+                    .and_then(|a| resolve_object(self.doc, a).as_dict().ok())
+                    .filter(|a| matches!(a.get(b"S"), Ok(lopdf::Object::Name(s)) if s == b"GoTo"))
+                    .and_then(|a| a.get(b"D").ok())
+            });
+            let page = dest.and_then(|d| self.dest_page(d, 0)).unwrap_or(0);
+            let first_child = item.get(b"First").and_then(lopdf::Object::as_reference).ok();
+            let children = self.entries(first_child, level + 1, visited);
+
+            entries.push(TocEntry { title, page, level, children });
+            next = item.get(b"Next").and_then(lopdf::Object::as_reference).ok();
+        }
+        entries
+    }
+
+    /// 1-based page number of an explicit or named destination
+    fn dest_page(&self, dest: &lopdf::Object, depth: usize) -> Option<usize> {
+        if depth > 4 {
+            return None;
+        }
+        match resolve_object(self.doc, dest) {
+            lopdf::Object::Array(items) => match items.first()? {
+                lopdf::Object::Reference(page_id) => self.pages.get(page_id).copied(),
+                // Remote-style destinations carry a 0-based page index
+                lopdf::Object::Integer(index) => usize::try_from(*index).ok().map(|i| i + 1),
+                _ => None,
+            },
+            lopdf::Object::String(name, _) | lopdf::Object::Name(name) => {
+                self.dest_page(self.named.get(name)?, depth + 1)
+            }
+            // Named destination values may be `<< /D [...] >>`
+            lopdf::Object::Dictionary(dict) => self.dest_page(dict.get(b"D").ok()?, depth + 1),
+            _ => None,
+        }
+    }
+}
+
+/// Inheritable field attributes passed down the `/Kids` hierarchy
+#[derive(Default, Clone)]
+struct FieldInherited {
+    name: String,
+    field_type: Option<Vec<u8>>,
+    flags: i64,
+    value: Option<String>,
+}
+
+/// Lookup tables shared by the recursive AcroForm walk
+struct FormContext<'a> {
+    doc: &'a lopdf::Document,
+    pages: &'a std::collections::HashMap<lopdf::ObjectId, usize>,
+    annot_pages: &'a std::collections::HashMap<lopdf::ObjectId, usize>,
+}
+
+impl FormContext<'_> {
+    fn collect(
+        &self,
+        field: &lopdf::Object,
+        parent: &FieldInherited,
+        depth: usize,
+        visited: &mut std::collections::HashSet<lopdf::ObjectId>,
+        out: &mut Vec<FormField>,
+    ) {
+        // Field trees are shallow; the guards catch malformed cycles
+        if depth > 32 {
+            return;
+        }
+        let id = field.as_reference().ok();
+        if let Some(id) = id {
+            if !visited.insert(id) {
+                return;
+            }
+        }
+        let Ok(dict) = resolve_object(self.doc, field).as_dict() else {
+            return;
+        };
+
+        let mut inherited = parent.clone();
+        if let Ok(lopdf::Object::String(partial, _)) = dict.get(b"T").map(|t| resolve_object(self.doc, t)) {
+            let partial = decode_text_string(partial);
+            inherited.name = if parent.name.is_empty() {
+                partial
+            } else {
+                format!("{}.{}", parent.name, partial)
+            };
+        }
+        if let Ok(lopdf::Object::Name(ft)) = dict.get(b"FT").map(|t| resolve_object(self.doc, t)) {
+            inherited.field_type = Some(ft.clone());
+        }
+        if let Ok(lopdf::Object::Integer(flags)) = dict.get(b"Ff").map(|f| resolve_object(self.doc, f)) {
+            inherited.flags = *flags;
+        }
+        if let Some(value) = dict.get(b"V").ok().and_then(|v| self.field_value(v)) {
+            inherited.value = Some(value);
+        }
+
+        // Kids that carry their own `/T` are child fields; the rest are the
+        // widget annotations of this field
+        let kids: Vec<&lopdf::Object> = dict
+            .get(b"Kids")
+            .ok()
+            .and_then(|k| resolve_object(self.doc, k).as_array().ok())
+            .map(|kids| kids.iter().collect())
+            .unwrap_or_default();
+        let (child_fields, widgets): (Vec<&lopdf::Object>, Vec<&lopdf::Object>) =
+            kids.into_iter().partition(|kid| {
+                resolve_object(self.doc, kid)
+                    .as_dict()
+                    .is_ok_and(|k| k.has(b"T"))
+            });
+        if !child_fields.is_empty() {
+            for child in child_fields {
+                self.collect(child, &inherited, depth + 1, visited, out);
+            }
+            return;
+        }
+
+        const RADIO: i64 = 1 << 15;
+        const PUSH_BUTTON: i64 = 1 << 16;
+        let field_type = match inherited.field_type.as_deref() {
+            Some(b"Tx") => FormFieldType::Text,
+            Some(b"Ch") => FormFieldType::Select,
+            Some(b"Btn") if inherited.flags & PUSH_BUTTON != 0 => return,
+            Some(b"Btn") if inherited.flags & RADIO != 0 => FormFieldType::Radio,
+            Some(b"Btn") => FormFieldType::Checkbox,
+            _ => return,
+        };
+
+        let page = std::iter::once((id, dict))
+            .chain(widgets.iter().filter_map(|w| {
+                Some((w.as_reference().ok(), resolve_object(self.doc, w).as_dict().ok()?))
+            }))
+            .find_map(|(id, widget)| {
+                widget
+                    .get(b"P")
+                    .and_then(lopdf::Object::as_reference)
+                    .ok()
+                    .and_then(|p| self.pages.get(&p))
+                    .or_else(|| self.annot_pages.get(&id?))
+                    .copied()
+            })
+            .unwrap_or(0);
+
+        out.push(FormField {
+            name: inherited.name,
+            field_type,
+            value: inherited.value,
+            page,
+        });
+    }
+
+    /// `/V` as text: strings are decoded, names (button states) kept as-is,
+    /// and multi-select arrays joined with `, `
+    fn field_value(&self, value: &lopdf::Object) -> Option<String> {
+        match resolve_object(self.doc, value) {
+            lopdf::Object::String(bytes, _) => Some(decode_text_string(bytes)),
+            lopdf::Object::Name(name) => Some(String::from_utf8_lossy(name).into_owned()),
+            lopdf::Object::Array(items) => {
+                let values: Vec<String> = items.iter().filter_map(|item| self.field_value(item)).collect();
+                (!values.is_empty()).then(|| values.join(", "))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// URL and normalized rectangle of a `/Link` annotation with a `/URI` action
+fn uri_link(doc: &lopdf::Document, annot: &lopdf::Dictionary) -> Option<(String, [f64; 4])> {
+    if !matches!(annot.get(b"Subtype"), Ok(lopdf::Object::Name(s)) if s == b"Link") {
+        return None;
+    }
+    let action = resolve_object(doc, annot.get(b"A").ok()?).as_dict().ok()?;
+    if !matches!(action.get(b"S"), Ok(lopdf::Object::Name(s)) if s == b"URI") {
+        return None;
+    }
+    let url = match resolve_object(doc, action.get(b"URI").ok()?) {
+        lopdf::Object::String(bytes, _) => decode_text_string(bytes).trim().to_string(),
+        _ => return None,
+    };
+    if url.is_empty() {
+        return None;
+    }
+
+    let coords: Vec<f64> = resolve_object(doc, annot.get(b"Rect").ok()?)
+        .as_array()
+        .ok()?
+        .iter()
+        .filter_map(|n| extract_number(resolve_object(doc, n)))
+        .collect();
+    let [x1, y1, x2, y2] = coords[..] else {
+        return None;
+    };
+    Some((url, [x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2)]))
+}
+
+/// Text runs whose origin lies inside `rect`, in reading order (top to
+/// bottom, then left to right). The bottom edge gets a little slack because
+/// link rectangles usually hug the glyphs while the origin sits on the
+/// baseline.
+fn text_in_rect(texts: &[PositionedText], rect: [f64; 4]) -> Option<String> {
+    const SLACK: f64 = 2.0;
+    let [x1, y1, x2, y2] = rect;
+    let mut inside: Vec<&PositionedText> = texts
+        .iter()
+        .filter(|t| t.x >= x1 - SLACK && t.x <= x2 && t.y >= y1 - SLACK && t.y <= y2 + SLACK)
+        .collect();
+    inside.sort_by(|a, b| {
+        b.y.partial_cmp(&a.y)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.x.partial_cmp(&b.x).unwrap_or(std::cmp::Ordering::Equal))
+    });
+    let text = inside
+        .iter()
+        .map(|t| t.text.trim())
+        .filter(|t| !t.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!text.is_empty()).then_some(text)
+}
+
+/// Keep outline entries that point inside `range`; children of dropped
+/// entries are promoted so in-range sections are not lost.
+fn toc_within(entries: Vec<TocEntry>, range: &RangeInclusive<usize>) -> Vec<TocEntry> {
+    let mut kept = Vec::new();
+    for mut entry in entries {
+        let children = toc_within(std::mem::take(&mut entry.children), range);
+        if range.contains(&entry.page) {
+            entry.children = children;
+            kept.push(entry);
+        } else {
+            kept.extend(children);
+        }
+    }
+    kept
+}
+
+/// Apply `shift` to every outline target, recursively.
+fn shift_toc(entries: &mut [TocEntry], shift: &impl Fn(usize) -> usize) {
+    for entry in entries {
+        entry.page = shift(entry.page);
+        shift_toc(&mut entry.children, shift);
+    }
+}
+
+/// Sortable `(major, minor)` for a header version such as `"1.7"`;
+/// unparseable versions sort last so they never win a minimum.
+fn version_key(version: &str) -> (u32, u32) {
+    let mut parts = version.split('.').map(|p| p.trim().parse::<u32>());
+    match (parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor))) => (major, minor),
+        (Some(Ok(major)), None) => (major, 0),
+        _ => (u32::MAX, u32::MAX),
+    }
+}
+
+/// Image stream payload: JPEG bytes for `DCTDecode`, raw samples otherwise
+/// (inflated for `FlateDecode`). Raw samples are only meaningful in a
+/// device, CIE-based or indexed color space.
+fn decode_image_stream(doc: &lopdf::Document, stream: &lopdf::Stream) -> PdfParseResult<(ImageFormat, Vec<u8>)> {
+    let filter = stream.dict.get(b"Filter").and_then(lopdf::Object::as_name).ok();
+    if filter == Some(b"DCTDecode".as_slice()) {
+        return Ok((ImageFormat::Jpeg, stream.content.clone()));
+    }
+
+    if let Ok(color_space) = stream.dict.get(b"ColorSpace") {
+        let family = match resolve_object(doc, color_space) {
+            lopdf::Object::Name(name) => name.clone(),
+            lopdf::Object::Array(items) => items
+                .first()
+                .and_then(|first| first.as_name().ok())
+                .map(<[u8]>::to_vec)
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        let supported = matches!(
+            family.as_slice(),
+            b"DeviceGray" | b"DeviceRGB" | b"DeviceCMYK" | b"CalGray" | b"CalRGB" | b"Lab" | b"ICCBased" | b"Indexed"
+        );
+        if !supported {
+            return Err(PdfError::UnsupportedColorSpace(String::from_utf8_lossy(&family).into_owned()));
+        }
+    }
+
+    match filter {
+        Some(b"FlateDecode") => decompress_flate(&stream.content)
+            .map(|data| (ImageFormat::Raw, data))
+            .map_err(|e| PdfError::DecompressionFailed(e.to_string())),
+        _ => Ok((ImageFormat::Raw, stream.content.clone())),
+    }
+}
+
+/// Decompress FlateDecode (zlib) data with a hard output ceiling
+/// (`MAX_PDF_STREAM` = 128 MB). Guards against PDF decompression bombs.
+fn decompress_flate(data: &[u8]) -> io::Result<Vec<u8>> {
+    let mut decoder = ZlibDecoder::new(data);
+    read_limited(&mut decoder, MAX_PDF_STREAM)
+}
+
+/// Extract numeric value from PDF object (handles both Integer and Real types)
+fn extract_number(obj: &lopdf::Object) -> Option<f64> {
+    match obj {
+        lopdf::Object::Integer(i) => Some(*i as f64),
+        lopdf::Object::Real(f) => Some(*f as f64),
+        _ => None,
+    }
+}
+
+/// Detect font style (bold/italic) from font name
+fn detect_font_style(font_name: &str) -> FontStyle {
+    let name_lower = font_name.to_lowercase();
 
     // Common bold indicators in font names
     let is_bold = name_lower.contains("bold")
@@ -1984,6 +3305,94 @@ fn approx_right_edge(t: &PositionedText) -> f64 {
     t.x + (t.text.chars().count() as f64) * PT_PER_CHAR
 }
 
+/// Reading-order text of positioned layout elements (see
+/// [`PdfParser::to_text_with_layout`]).
+///
+/// Per page, text start-X positions are clustered with 2-means; the split
+/// is kept only when the two centroids are at least 30% of the text span
+/// apart, otherwise the page is one column. Elements are read by
+/// descending Y within a column, columns left to right. Runs sharing a
+/// baseline become one line; columns and pages are separated by a blank
+/// line.
+fn layout_reading_order(elements: &[LayoutElement]) -> String {
+    const SAME_LINE_PT: f64 = 2.0;
+    const MIN_CENTROID_GAP: f64 = 0.3;
+
+    let mut pages: std::collections::BTreeMap<usize, Vec<&LayoutElement>> = std::collections::BTreeMap::new();
+    for el in elements {
+        let is_text = matches!(
+            el.element_type,
+            LayoutElementType::Text | LayoutElementType::ListItem | LayoutElementType::Header | LayoutElementType::Footer
+        );
+        if is_text && !el.content.trim().is_empty() {
+            pages.entry(el.page).or_default().push(el);
+        }
+    }
+
+    let mut blocks: Vec<String> = Vec::new();
+    for texts in pages.values() {
+        let xs: Vec<f64> = texts.iter().map(|t| t.x).collect();
+        let left = texts.iter().map(|t| t.x).fold(f64::INFINITY, f64::min);
+        let right = texts.iter().map(|t| t.x + t.width).fold(f64::NEG_INFINITY, f64::max);
+        let split = two_means(&xs)
+            .filter(|(lo, hi)| hi - lo >= (right - left) * MIN_CENTROID_GAP)
+            .map(|(lo, hi)| (lo + hi) / 2.0);
+
+        let mut columns: Vec<Vec<&LayoutElement>> = match split {
+            Some(split) => {
+                let (l, r): (Vec<&LayoutElement>, Vec<&LayoutElement>) = texts.iter().copied().partition(|t| t.x < split);
+                vec![l, r]
+            }
+            None => vec![texts.clone()],
+        };
+        for column in &mut columns {
+            column.sort_by(|a, b| b.y.total_cmp(&a.y));
+            let mut lines: Vec<Vec<&LayoutElement>> = Vec::new();
+            for &t in column.iter() {
+                match lines.last_mut() {
+                    Some(line) if (line[0].y - t.y).abs() <= SAME_LINE_PT => line.push(t),
+                    _ => lines.push(vec![t]),
+                }
+            }
+            let text: Vec<String> = lines
+                .iter_mut()
+                .map(|line| {
+                    line.sort_by(|a, b| a.x.total_cmp(&b.x));
+                    line.iter().map(|t| t.content.trim()).collect::<Vec<_>>().join(" ")
+                })
+                .collect();
+            if !text.is_empty() {
+                blocks.push(text.join("\n"));
+            }
+        }
+    }
+    blocks.join("\n\n")
+}
+
+/// 1-D k-means with k=2, seeded at the extremes. Returns the (left, right)
+/// centroids, or `None` when the values don't form two non-empty clusters.
+fn two_means(values: &[f64]) -> Option<(f64, f64)> {
+    let mut lo = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let mut hi = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if values.is_empty() || hi <= lo {
+        return None;
+    }
+    for _ in 0..32 {
+        let mid = (lo + hi) / 2.0;
+        let (left, right): (Vec<f64>, Vec<f64>) = values.iter().partition(|&&v| v < mid);
+        if left.is_empty() || right.is_empty() {
+            return None;
+        }
+        let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+        let (new_lo, new_hi) = (mean(&left), mean(&right));
+        if new_lo == lo && new_hi == hi {
+            break;
+        }
+        (lo, hi) = (new_lo, new_hi);
+    }
+    Some((lo, hi))
+}
+
 /// Detect a 2-column page layout from positioned text and return the split X.
 ///
 /// Algorithm: bin run start-X coordinates into a histogram, find the two
@@ -2181,73 +3590,27 @@ fn detect_tables_from_positions(texts: &[PositionedText], page: usize) -> Vec<Pd
             continue;
         }
 
-        // Mode column count (dominant row width)
-        let mut len_counts: std::collections::HashMap<usize, usize> =
-            std::collections::HashMap::new();
-        for r in &multi_col_rows {
-            *len_counts.entry(r.len()).or_insert(0) += 1;
-        }
-        let mode_cols = len_counts
-            .iter()
-            .max_by_key(|(len, count)| (*count, *len))
-            .map(|(len, _)| *len)
-            .unwrap_or(0);
-        if mode_cols < 2 {
-            continue;
-        }
-
-        // Require mode to represent a non-trivial share of the segment.
-        // Without this gate, text-heavy legal / guide PDFs fabricate dozens
-        // of "tables" out of numbered-list alignment. Floor at 3 hits OR
-        // 25% of multi-column rows (whichever is stricter in sparse segments).
-        let mode_hits = *len_counts.get(&mode_cols).unwrap_or(&0);
-        if mode_hits < 3 || mode_hits * 5 < multi_col_rows.len() {
+        // Column anchors from the X histogram of every text in the segment
+        let seg_texts: Vec<&PositionedText> = seg.iter().flat_map(|r| r.iter().copied()).collect();
+        let min_support = 2.max(seg.len() * 3 / 10);
+        let column_positions = histogram_columns(&seg_texts, min_support);
+        if column_positions.len() < 2 {
             continue;
         }
 
-        // Anchor column X positions from the first mode-width row
-        let anchor_row = match multi_col_rows.iter().find(|r| r.len() == mode_cols) {
-            Some(r) => *r,
-            None => continue,
-        };
-        let anchor_xs: Vec<f64> = anchor_row.iter().map(|t| t.x).collect();
-
-        // Dynamic X tolerance = 0.45 × min inter-column gap, clamped [10, 40]
-        let min_col_spacing = anchor_xs
-            .windows(2)
-            .map(|w| (w[1] - w[0]).abs())
-            .fold(f64::INFINITY, f64::min);
-        let x_tol = if min_col_spacing.is_finite() {
-            (min_col_spacing * 0.45).clamp(10.0, 40.0)
-        } else {
-            20.0
-        };
-
-        // Project every row onto anchor columns (merged cells absorbed into
-        // the nearest anchor). Track Y positions of rows that end up in
-        // the table so the renderer can map the table back to its page
-        // region precisely (no leaking into surrounding prose).
+        // Assign each text to its nearest column. Track Y positions of rows
+        // that end up in the table so the renderer can map the table back to
+        // its page region precisely (no leaking into surrounding prose).
         let mut table_rows: Vec<Vec<String>> = Vec::new();
         let mut row_ys: Vec<f64> = Vec::new();
         for r in seg.iter() {
-            let mut cells: Vec<String> = vec![String::new(); mode_cols];
+            let mut cells: Vec<String> = vec![String::new(); column_positions.len()];
             for t in r.iter() {
-                let mut best = 0usize;
-                let mut best_d = f64::INFINITY;
-                for (i, ax) in anchor_xs.iter().enumerate() {
-                    let d = (t.x - ax).abs();
-                    if d < best_d {
-                        best_d = d;
-                        best = i;
-                    }
-                }
-                // Accept text if it snaps to an anchor within 2× tolerance
-                if best_d <= x_tol * 2.0 {
-                    if !cells[best].is_empty() {
-                        cells[best].push(' ');
-                    }
-                    cells[best].push_str(&t.text);
+                let best = nearest_column(&column_positions, column_anchor_x(t));
+                if !cells[best].is_empty() {
+                    cells[best].push(' ');
                 }
+                cells[best].push_str(&t.text);
             }
             // Keep row only if ≥2 non-empty cells (filter single-line headers/noise)
             if cells.iter().filter(|c| !c.is_empty()).count() >= 2 {
@@ -2288,8 +3651,8 @@ fn detect_tables_from_positions(texts: &[PositionedText], page: usize) -> Vec<Pd
         // If column anchors are spaced >180pt apart (wider than most table
         // cells on letter-size pages), it's almost certainly a page-level
         // 2-column layout being mistaken for a table.
-        if anchor_xs.len() >= 2 {
-            let max_col_spacing = anchor_xs
+        if column_positions.len() >= 2 {
+            let max_col_spacing = column_positions
                 .windows(2)
                 .map(|w| (w[1] - w[0]).abs())
                 .fold(0.0_f64, f64::max);
@@ -2307,15 +3670,100 @@ fn detect_tables_from_positions(texts: &[PositionedText], page: usize) -> Vec<Pd
         out.push(PdfTable {
             page,
             rows: table_rows,
-            column_count: mode_cols,
+            column_count: column_positions.len(),
             y_top,
             y_bottom,
+            column_positions,
         });
     }
 
     out
 }
 
+/// Minimum distance between two detected column anchors (points)
+const MIN_COLUMN_GAP: f64 = 40.0;
+
+/// Histogram bin width for column detection (points)
+const COLUMN_BIN_WIDTH: f64 = 5.0;
+
+/// Column anchor X positions (ascending) from a histogram of
+/// [`column_anchor_x`] over `texts`.
+///
+/// Each occupied bin counts the texts within half a [`MIN_COLUMN_GAP`] of
+/// its center. Bins are taken as peaks in descending count order, skipping
+/// any within [`MIN_COLUMN_GAP`] of a peak already taken and any with fewer
+/// than `min_support` texts; each peak's position is the mean anchor of
+/// the texts it counted. Texts that wander a few points
+/// around their column land in one peak, and an indented first line
+/// (< 40pt) never becomes a column of its own.
+fn histogram_columns(texts: &[&PositionedText], min_support: usize) -> Vec<f64> {
+    let anchors: Vec<f64> = texts.iter().map(|t| column_anchor_x(t)).collect();
+    let near = |center: f64| anchors.iter().copied().filter(move |x| (x - center).abs() < MIN_COLUMN_GAP / 2.0);
+    let bins: std::collections::BTreeSet<i64> =
+        anchors.iter().map(|x| (x / COLUMN_BIN_WIDTH).floor() as i64).collect();
+
+    // Smoothed count per occupied bin: texts within half a gap of its center
+    let mut ranked: Vec<(f64, usize)> = bins
+        .into_iter()
+        .map(|bin| {
+            let center = (bin as f64 + 0.5) * COLUMN_BIN_WIDTH;
+            (center, near(center).count())
+        })
+        .collect();
+    // Highest count first; ties go to the leftmost bin
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal)));
+
+    let mut peaks: Vec<f64> = Vec::new();
+    for (center, count) in ranked {
+        if count < min_support {
+            break;
+        }
+        if peaks.iter().any(|p| (p - center).abs() < MIN_COLUMN_GAP) {
+            continue;
+        }
+        peaks.push(center);
+    }
+
+    let mut columns: Vec<f64> = peaks
+        .iter()
+        .map(|&center| near(center).sum::<f64>() / near(center).count().max(1) as f64)
+        .collect();
+    columns.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    columns
+}
+
+/// Index of the column anchor closest to `x`
+fn nearest_column(columns: &[f64], x: f64) -> usize {
+    columns
+        .iter()
+        .enumerate()
+        .min_by(|a, b| (a.1 - x).abs().partial_cmp(&(b.1 - x).abs()).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+/// X coordinate a text aligns on: its start, or for numbers (typically
+/// right-aligned in financial tables) its estimated right edge, so
+/// `1,234,567` and `89` in the same column share an anchor.
+fn column_anchor_x(t: &PositionedText) -> f64 {
+    if is_numeric_cell(&t.text) {
+        // Digits are ~0.55 em wide in common text faces
+        t.x + t.text.trim().chars().count() as f64 * t.font_size.unwrap_or(10.0) * 0.55
+    } else {
+        t.x
+    }
+}
+
+/// Amount-like cell: digits with separators, sign, parentheses, percent
+/// or currency symbol only
+fn is_numeric_cell(text: &str) -> bool {
+    let text = text.trim();
+    text.chars().any(|c| c.is_ascii_digit())
+        && text
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, ',' | '.' | '-' | '+' | '(' | ')' | '%' | '$' | '₩' | '€' | '¥'))
+}
+
 impl ImageFormat {
     /// Get file extension for this format
     pub fn extension(&self) -> &'static str {
@@ -2509,6 +3957,51 @@ fn normalize_cjk_spacing(s: &str) -> String {
     out
 }
 
+/// Nested Markdown list of outline entries linking to `#page-N`
+fn push_toc_entries(out: &mut String, entries: &[TocEntry], depth: usize) {
+    for entry in entries {
+        let title = entry.title.replace('[', "\\[").replace(']', "\\]");
+        let indent = "  ".repeat(depth);
+        if entry.page > 0 {
+            out.push_str(&format!("{}- [{}](#page-{})\n", indent, title, entry.page));
+        } else {
+            out.push_str(&format!("{}- {}\n", indent, title));
+        }
+        push_toc_entries(out, &entry.children, depth + 1);
+    }
+}
+
+/// Rewrite the first unlinked occurrence of each link's text in `content` as
+/// `[text](url)`. Returns the rewritten content and the links that have no
+/// text or whose text does not appear verbatim (e.g. split by formatting).
+fn inline_hyperlinks<'a>(content: &str, links: &'a [PdfLink]) -> (String, Vec<&'a PdfLink>) {
+    let mut out = content.to_string();
+    let mut unplaced = Vec::new();
+    for link in links {
+        let Some(text) = link.linked_text.as_deref().filter(|t| !t.is_empty()) else {
+            unplaced.push(link);
+            continue;
+        };
+        // Skip occurrences that are already the label of an earlier link
+        let found = out.match_indices(text).map(|(i, _)| i).find(|&i| {
+            !out[..i].ends_with('[') || !out[i + text.len()..].starts_with("](")
+        });
+        match found {
+            Some(i) => {
+                let replacement = format!("[{}]({})", text, markdown_link_url(&link.url));
+                out.replace_range(i..i + text.len(), &replacement);
+            }
+            None => unplaced.push(link),
+        }
+    }
+    (out, unplaced)
+}
+
+/// Escape the characters that would end a Markdown link destination early
+fn markdown_link_url(url: &str) -> String {
+    url.replace(' ', "%20").replace('(', "%28").replace(')', "%29")
+}
+
 /// Korean / CJK outline markers that begin a fresh bullet or paragraph.
 /// Common in government documents and technical reports: `□` (L1),
 /// `○` (L2), `●` (L3 emphasized), `▪`/`■`/`·` for subordinate bullets.
@@ -2533,6 +4026,69 @@ fn apply_inline_formatting(content: &str, is_bold: bool, is_italic: bool) -> Str
 }
 
 impl PdfDocument {
+    /// Every font's program is embedded — a PDF/A requirement
+    pub fn has_all_fonts_embedded(&self) -> bool {
+        self.fonts.iter().all(|font| font.is_embedded)
+    }
+
+    /// Words across all pages — see [`crate::utils::text_stats::word_count`]
+    pub fn word_count(&self) -> usize {
+        self.pages
+            .iter()
+            .map(|page| crate::utils::text_stats::word_count(&page.text))
+            .sum()
+    }
+
+    /// Estimated reading time at 238 words per minute
+    pub fn reading_time_minutes(&self) -> f64 {
+        crate::utils::text_stats::reading_time_minutes(self.word_count())
+    }
+
+    /// ISO 639-1 code of the document language.
+    ///
+    /// A declared `/Lang` wins (reduced to its primary subtag). Otherwise the
+    /// first 500 characters of [`full_text`](Self::full_text) are bucketed by
+    /// Unicode block: Hangul → `ko`, kana → `ja`, CJK ideographs → `zh`
+    /// (`ja` if any kana appears alongside), Cyrillic → `ru`, Latin → `en`.
+    /// Text with no letters at all yields `"und"`.
+    pub fn detect_language(&self) -> String {
+        if let Some(lang) = &self.metadata.language {
+            let primary = lang.split(['-', '_']).next().unwrap_or("").to_ascii_lowercase();
+            if !primary.is_empty() {
+                return primary;
+            }
+        }
+
+        let (mut hangul, mut kana, mut han, mut cyrillic, mut latin) = (0usize, 0usize, 0usize, 0usize, 0usize);
+        for c in self.full_text().chars().take(500) {
+            match c as u32 {
+                0xAC00..=0xD7A3 | 0x1100..=0x11FF | 0x3130..=0x318F => hangul += 1,
+                0x3040..=0x30FF | 0x31F0..=0x31FF | 0xFF66..=0xFF9D => kana += 1,
+                0x4E00..=0x9FFF | 0x3400..=0x4DBF | 0xF900..=0xFAFF => han += 1,
+                0x0400..=0x04FF => cyrillic += 1,
+                _ if c.is_ascii_alphabetic() => latin += 1,
+                0x00C0..=0x024F if c.is_alphabetic() => latin += 1,
+                _ => {}
+            }
+        }
+
+        // Japanese text mixes kanji with kana, so both count toward `ja`
+        let japanese = if kana > 0 { kana + han } else { 0 };
+        let chinese = if kana > 0 { 0 } else { han };
+        [
+            (hangul, "ko"),
+            (japanese, "ja"),
+            (chinese, "zh"),
+            (cyrillic, "ru"),
+            (latin, "en"),
+        ]
+        .into_iter()
+        .filter(|&(count, _)| count > 0)
+        .max_by_key(|&(count, _)| count)
+        .map_or("und", |(_, code)| code)
+        .to_string()
+    }
+
     /// Convert layout elements to markdown with heading detection, bold/italic formatting,
     /// and list item normalization.
     ///
@@ -2888,20 +4444,63 @@ impl PdfDocument {
         mdx.push_str(&format!("images: {}\n", self.images.len()));
         mdx.push_str(&format!("fonts: {}\n", self.fonts.len()));
         mdx.push_str(&format!("tables: {}\n", self.tables.len()));
+        let words = self.word_count();
+        mdx.push_str(&format!("words: {}\n", words));
+        mdx.push_str(&format!(
+            "readingTime: {:.1}\n",
+            crate::utils::text_stats::reading_time_minutes(words)
+        ));
         if !self.metadata.title.is_empty() {
             mdx.push_str(&format!("title: \"{}\"\n", self.metadata.title.replace('"', "\\\"")));
         }
         if !self.metadata.author.is_empty() {
             mdx.push_str(&format!("author: \"{}\"\n", self.metadata.author.replace('"', "\\\"")));
         }
+        mdx.push_str(&format!("language: \"{}\"\n", self.detect_language()));
         mdx.push_str("---\n\n");
 
+        // Outline → linked table of contents
+        if !self.toc.is_empty() {
+            mdx.push_str("## Table of Contents\n\n");
+            push_toc_entries(&mut mdx, &self.toc, 0);
+            mdx.push('\n');
+        }
+
         // Content: use layout-aware conversion if layout data is available
         let content = self.to_markdown_with_layout();
         let content = merge_partial_numbering(&content);
+        let (content, unplaced_links) = inline_hyperlinks(&content, &self.hyperlinks);
         mdx.push_str(&content);
         mdx.push_str("\n\n");
 
+        // Links whose text could not be located in the body
+        if !unplaced_links.is_empty() {
+            mdx.push_str("## Links\n\n");
+            for link in unplaced_links {
+                let url = markdown_link_url(&link.url);
+                match &link.linked_text {
+                    Some(text) => mdx.push_str(&format!(
+                        "- [{}]({}) (page {})\n",
+                        text.replace('[', "\\[").replace(']', "\\]"),
+                        url,
+                        link.page
+                    )),
+                    None => mdx.push_str(&format!("- <{}> (page {})\n", url, link.page)),
+                }
+            }
+            mdx.push('\n');
+        }
+
+        // Fillable form fields
+        if !self.form_fields.is_empty() {
+            mdx.push_str("## Form Fields\n\n");
+            for field in &self.form_fields {
+                let line = format!("**{}:** {}", field.name, field.value.as_deref().unwrap_or(""));
+                mdx.push_str(line.trim_end());
+                mdx.push_str("\n\n");
+            }
+        }
+
         // Image references (if any)
         if !self.images.is_empty() {
             mdx.push_str("## Images\n\n");
@@ -3015,6 +4614,26 @@ fn is_partial_numbering(s: &str) -> bool {
     bytes[1..].iter().all(|b| b.is_ascii_digit())
 }
 
+/// Parse a `--pages` value: `START-END` or a single page `N` (1-based).
+pub fn parse_page_range(spec: &str) -> Result<RangeInclusive<usize>, String> {
+    let parse = |s: &str| {
+        s.trim()
+            .parse::<usize>()
+            .map_err(|_| format!("invalid page number '{}' in '{}'", s.trim(), spec))
+    };
+    let (start, end) = match spec.split_once('-') {
+        Some((a, b)) => (parse(a)?, parse(b)?),
+        None => {
+            let n = parse(spec)?;
+            (n, n)
+        }
+    };
+    if start == 0 || start > end {
+        return Err(format!("invalid page range '{}': expected START-END with 1 <= START <= END", spec));
+    }
+    Ok(start..=end)
+}
+
 /// Extract PDF text with a two-tier strategy: primary parser (pdf-extract)
 /// guarded by `catch_unwind`, then `pdftotext` (Poppler) fallback on panic
 /// or empty output.
@@ -3080,6 +4699,143 @@ fn extract_text_with_fallback(
     }
 }
 
+/// Rendered page cache key: (canonical source path, 1-based page, dpi, source mtime)
+pub type PageRenderKey = (std::path::PathBuf, usize, u32, std::time::SystemTime);
+
+/// Disk cache of rendered page PNGs
+pub type PageRenderCache = crate::cache::Cache<PageRenderKey, Vec<u8>>;
+
+/// External page rasterizers, in order of preference
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PageRenderer {
+    Pdftoppm,
+    Mutool,
+}
+
+impl PageRenderer {
+    fn name(self) -> &'static str {
+        match self {
+            PageRenderer::Pdftoppm => "pdftoppm",
+            PageRenderer::Mutool => "mutool",
+        }
+    }
+
+    /// Command writing `page` of `input` to `output_png`
+    /// (`pdftoppm` takes the path without its `.png` extension)
+    fn command(
+        self,
+        input: &Path,
+        output_stem: &Path,
+        output_png: &Path,
+        page: usize,
+        dpi: u32,
+    ) -> std::process::Command {
+        let mut cmd = std::process::Command::new(self.name());
+        let (page, dpi) = (page.to_string(), dpi.to_string());
+        match self {
+            PageRenderer::Pdftoppm => {
+                cmd.args(["-png", "-singlefile", "-r", &dpi, "-f", &page, "-l", &page])
+                    .arg(input)
+                    .arg(output_stem);
+            }
+            PageRenderer::Mutool => {
+                cmd.args(["draw", "-q", "-r", &dpi, "-F", "png", "-o"])
+                    .arg(output_png)
+                    .arg(input)
+                    .arg(&page);
+            }
+        }
+        cmd
+    }
+}
+
+/// First available [`PageRenderer`]; probed once per process like
+/// [`pdftotext_available`].
+fn page_renderer() -> Option<PageRenderer> {
+    use std::sync::OnceLock;
+    static RENDERER: OnceLock<Option<PageRenderer>> = OnceLock::new();
+    *RENDERER.get_or_init(|| {
+        // `mutool` prints usage and exits non-zero without arguments, so
+        // only a failed spawn means it is missing
+        let runs = |name: &str, arg: Option<&str>| {
+            std::process::Command::new(name)
+                .args(arg)
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::null())
+                .status()
+        };
+        if runs("pdftoppm", Some("-v")).is_ok_and(|s| s.success()) {
+            Some(PageRenderer::Pdftoppm)
+        } else if runs("mutool", None).is_ok() {
+            Some(PageRenderer::Mutool)
+        } else {
+            None
+        }
+    })
+}
+
+/// Render resolution for [`PdfParser::ocr_page`]; tesseract is tuned for
+/// roughly 300 dpi scans
+pub const OCR_DPI: u32 = 300;
+
+/// Recognize the text of a PNG image with `tesseract` in `lang`.
+///
+/// The image is written to a temporary file (removed afterwards) and
+/// tesseract's stdout is cleaned up by [`clean_ocr_text`]; a blank image
+/// gives an empty string. Fails with `ParseError("tesseract not found")`
+/// when the binary is not on PATH, and rejects language codes other than
+/// `[A-Za-z0-9_]` joined by `+`.
+pub fn ocr_png(png: &[u8], lang: &str) -> Result<String, PdfError> {
+    let valid_lang = !lang.is_empty()
+        && lang
+            .split('+')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+    if !valid_lang {
+        return Err(PdfError::ParseError(format!("invalid OCR language '{}'", lang)));
+    }
+
+    let image = tempfile::Builder::new().prefix("mdm_ocr_").suffix(".png").tempfile()?;
+    std::fs::write(image.path(), png)?;
+    let output = std::process::Command::new("tesseract")
+        .arg(image.path())
+        .args(["stdout", "-l", lang])
+        .output()
+        .map_err(|e| match e.kind() {
+            io::ErrorKind::NotFound => PdfError::ParseError("tesseract not found".to_string()),
+            _ => PdfError::IoError(e),
+        })?;
+    if !output.status.success() {
+        return Err(PdfError::ParseError(format!(
+            "tesseract failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(clean_ocr_text(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Tidy tesseract output: drop the form feed it ends each page with, trim
+/// trailing spaces, collapse runs of blank lines to one, trim the ends.
+fn clean_ocr_text(raw: &str) -> String {
+    let mut out = String::new();
+    let mut blank = false;
+    for line in raw.lines().map(|line| line.replace('\u{c}', "")) {
+        let line = line.trim_end();
+        if line.is_empty() {
+            blank = !out.is_empty();
+            continue;
+        }
+        if blank {
+            out.push('\n');
+            blank = false;
+        }
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(line);
+    }
+    out
+}
+
 /// Cheap runtime check: is `pdftotext` on PATH and executable?
 /// Caches the result for the process lifetime so repeated calls don't fork.
 fn pdftotext_available() -> bool {
@@ -3138,6 +4894,622 @@ mod tests {
         assert_eq!(parser.extract_version(), "1.7");
     }
 
+    /// `n`-page PDF whose page `i` shows the text `Page i`.
+    fn numbered_pdf_bytes(n: usize) -> Vec<u8> {
+        use lopdf::content::{Content, Operation};
+        use lopdf::{dictionary, Object, Stream};
+
+        let mut doc = lopdf::Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+        });
+        let resources_id = doc.add_object(dictionary! {
+            "Font" => dictionary! { "F1" => font_id },
+        });
+        let mut kids = Vec::new();
+        for i in 1..=n {
+            let content = Content {
+                operations: vec![
+                    Operation::new("BT", vec![]),
+                    Operation::new("Tf", vec!["F1".into(), 24.into()]),
+                    Operation::new("Td", vec![100.into(), 700.into()]),
+                    Operation::new("Tj", vec![Object::string_literal(format!("Page {}", i))]),
+                    Operation::new("ET", vec![]),
+                ],
+            };
+            let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+            let page_id = doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => pages_id,
+                "Contents" => content_id,
+                "Resources" => resources_id,
+                "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+            });
+            kids.push(page_id.into());
+        }
+        let pages = dictionary! {
+            "Type" => "Pages",
+            "Kids" => kids,
+            "Count" => n as i64,
+        };
+        doc.objects.insert(pages_id, Object::Dictionary(pages));
+        let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+        doc.trailer.set("Root", catalog_id);
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).expect("save test pdf");
+        bytes
+    }
+
+    /// One-page PDF whose content stream runs `operators` (each with
+    /// numeric operands), plus a 1×1 image XObject in `image_space` if given.
+    fn colored_pdf_bytes(operators: &[(&str, usize)], image_space: Option<lopdf::Object>) -> Vec<u8> {
+        use lopdf::content::{Content, Operation};
+        use lopdf::{dictionary, Object, Stream};
+
+        let mut doc = lopdf::Document::load_mem(&numbered_pdf_bytes(1)).unwrap();
+        let page_id = doc.get_pages()[&1];
+        let operations = operators
+            .iter()
+            .map(|&(op, operands)| Operation::new(op, vec![Object::Real(0.5); operands]))
+            .collect();
+        let content_id = doc.add_object(Stream::new(
+            dictionary! {},
+            Content { operations }.encode().unwrap(),
+        ));
+        doc.get_dictionary_mut(page_id).unwrap().set("Contents", content_id);
+        if let Some(space) = image_space {
+            doc.add_object(Stream::new(
+                dictionary! {
+                    "Type" => "XObject", "Subtype" => "Image", "Width" => 1, "Height" => 1,
+                    "ColorSpace" => space, "BitsPerComponent" => 8,
+                },
+                vec![0; 4],
+            ));
+        }
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    fn color_mode_of(operators: &[(&str, usize)], image_space: Option<lopdf::Object>) -> ColorMode {
+        PdfParser::from_bytes(colored_pdf_bytes(operators, image_space))
+            .unwrap()
+            .detect_color_mode()
+    }
+
+    #[test]
+    fn test_color_mode_grayscale() {
+        assert_eq!(color_mode_of(&[], None), ColorMode::Grayscale, "no color set is gray");
+        assert_eq!(color_mode_of(&[("g", 1), ("G", 1)], None), ColorMode::Grayscale);
+        assert_eq!(
+            color_mode_of(&[("g", 1)], Some(lopdf::Object::Name(b"DeviceGray".to_vec()))),
+            ColorMode::Grayscale
+        );
+    }
+
+    #[test]
+    fn test_color_mode_rgb() {
+        assert_eq!(color_mode_of(&[("rg", 3)], None), ColorMode::Rgb);
+        assert_eq!(color_mode_of(&[("RG", 3), ("g", 1)], None), ColorMode::Rgb, "gray text does not make it mixed");
+        assert_eq!(color_mode_of(&[], Some(lopdf::Object::Name(b"DeviceRGB".to_vec()))), ColorMode::Rgb);
+    }
+
+    #[test]
+    fn test_color_mode_cmyk() {
+        assert_eq!(color_mode_of(&[("k", 4), ("K", 4)], None), ColorMode::Cmyk);
+        assert_eq!(color_mode_of(&[], Some(lopdf::Object::Name(b"DeviceCMYK".to_vec()))), ColorMode::Cmyk);
+    }
+
+    #[test]
+    fn test_color_mode_icc_and_indexed_images() {
+        use lopdf::{dictionary, Object, Stream};
+
+        let mut doc = lopdf::Document::load_mem(&colored_pdf_bytes(&[], None)).unwrap();
+        let profile = doc.add_object(Stream::new(dictionary! { "N" => 4 }, vec![]));
+        let icc = Object::Array(vec![Object::Name(b"ICCBased".to_vec()), profile.into()]);
+        let indexed = Object::Array(vec![
+            Object::Name(b"Indexed".to_vec()),
+            Object::Name(b"DeviceRGB".to_vec()),
+            Object::Integer(0),
+            Object::string_literal("\0\0\0"),
+        ]);
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+        let mode_with_image = |space: Object| {
+            let mut doc = lopdf::Document::load_mem(&bytes).unwrap();
+            doc.add_object(Stream::new(
+                dictionary! { "Subtype" => "Image", "Width" => 1, "Height" => 1, "ColorSpace" => space },
+                vec![0; 4],
+            ));
+            let mut out = Vec::new();
+            doc.save_to(&mut out).unwrap();
+            PdfParser::from_bytes(out).unwrap().detect_color_mode()
+        };
+        assert_eq!(mode_with_image(icc), ColorMode::Cmyk);
+        assert_eq!(mode_with_image(indexed), ColorMode::Rgb);
+        assert_eq!(
+            mode_with_image(Object::Array(vec![Object::Name(b"Separation".to_vec())])),
+            ColorMode::Grayscale,
+            "spot colors are not counted"
+        );
+    }
+
+    #[test]
+    fn test_color_mode_mixed() {
+        let parser = PdfParser::from_bytes(colored_pdf_bytes(
+            &[("rg", 3)],
+            Some(lopdf::Object::Name(b"DeviceCMYK".to_vec())),
+        ))
+        .unwrap();
+        assert_eq!(parser.detect_color_mode(), ColorMode::Mixed);
+        assert!(parser.has_cmyk_content());
+        assert!(!PdfParser::from_bytes(colored_pdf_bytes(&[("rg", 3)], None)).unwrap().has_cmyk_content());
+    }
+
+    #[test]
+    fn test_color_mode_combine() {
+        use ColorMode::*;
+        assert_eq!(Grayscale.combine(Cmyk), Cmyk);
+        assert_eq!(Rgb.combine(Grayscale), Rgb);
+        assert_eq!(Rgb.combine(Rgb), Rgb);
+        assert_eq!(Rgb.combine(Cmyk), Mixed);
+        assert_eq!(Mixed.combine(Grayscale), Mixed);
+    }
+
+    #[test]
+    fn test_page_count_without_text_extraction() {
+        let parser = PdfParser::from_bytes(numbered_pdf_bytes(5)).unwrap();
+        assert_eq!(parser.page_count().unwrap(), 5);
+    }
+
+    /// 3-page PDF with a two-level outline:
+    /// `Chapter 1` (p1) > [`Section 1.1` (p2, explicit), `Section 1.2` (p2, named)],
+    /// `부록` (UTF-16 title, p3 via a GoTo action).
+    fn outlined_pdf_bytes() -> Vec<u8> {
+        use lopdf::{dictionary, Object, StringFormat};
+
+        let mut doc = lopdf::Document::load_mem(&numbered_pdf_bytes(3)).unwrap();
+        let pages = doc.get_pages();
+        let page = |n: u32| Object::Reference(pages[&n]);
+        let fit = |n: u32| Object::Array(vec![page(n), "Fit".into()]);
+
+        let outlines_id = doc.new_object_id();
+        let chapter_id = doc.new_object_id();
+        let section1_id = doc.new_object_id();
+        let section2_id = doc.new_object_id();
+        let appendix_id = doc.new_object_id();
+
+        let mut utf16_title = vec![0xFE, 0xFF];
+        utf16_title.extend("부록".encode_utf16().flat_map(|u| u.to_be_bytes()));
+
+        doc.objects.insert(section1_id, Object::Dictionary(dictionary! {
+            "Title" => Object::string_literal("Section 1.1"),
+            "Parent" => chapter_id,
+            "Next" => section2_id,
+            "Dest" => fit(2),
+        }));
+        doc.objects.insert(section2_id, Object::Dictionary(dictionary! {
+            "Title" => Object::string_literal("Section 1.2"),
+            "Parent" => chapter_id,
+            "Prev" => section1_id,
+            "Dest" => Object::string_literal("sec-1.2"),
+        }));
+        doc.objects.insert(chapter_id, Object::Dictionary(dictionary! {
+            "Title" => Object::string_literal("Chapter 1"),
+            "Parent" => outlines_id,
+            "Next" => appendix_id,
+            "First" => section1_id,
+            "Last" => section2_id,
+            "Count" => 2,
+            "Dest" => fit(1),
+        }));
+        doc.objects.insert(appendix_id, Object::Dictionary(dictionary! {
+            "Title" => Object::String(utf16_title, StringFormat::Hexadecimal),
+            "Parent" => outlines_id,
+            "Prev" => chapter_id,
+            "A" => dictionary! {
+                "S" => "GoTo",
+                "D" => vec![page(3), "XYZ".into(), Object::Null, Object::Null, Object::Null],
+            },
+        }));
+        doc.objects.insert(outlines_id, Object::Dictionary(dictionary! {
+            "Type" => "Outlines",
+            "First" => chapter_id,
+            "Last" => appendix_id,
+            "Count" => 4,
+        }));
+
+        let catalog_id = doc.trailer.get(b"Root").unwrap().as_reference().unwrap();
+        let catalog = doc.get_dictionary_mut(catalog_id).unwrap();
+        catalog.set("Outlines", outlines_id);
+        catalog.set("Dests", dictionary! { "sec-1.2" => fit(2) });
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_extract_toc_two_levels() {
+        let parser = PdfParser::from_bytes(outlined_pdf_bytes()).unwrap();
+        let toc = parser.extract_toc();
+
+        assert_eq!(toc.len(), 2);
+        assert_eq!((toc[0].title.as_str(), toc[0].page, toc[0].level), ("Chapter 1", 1, 1));
+        let children: Vec<_> = toc[0].children.iter().map(|c| (c.title.as_str(), c.page, c.level)).collect();
+        assert_eq!(children, vec![("Section 1.1", 2, 2), ("Section 1.2", 2, 2)]);
+        assert_eq!((toc[1].title.as_str(), toc[1].page), ("부록", 3));
+        assert!(toc[1].children.is_empty());
+
+        assert!(PdfParser::from_bytes(numbered_pdf_bytes(2)).unwrap().extract_toc().is_empty());
+    }
+
+    #[test]
+    fn test_toc_restricted_to_page_range() {
+        let parser = PdfParser::from_bytes(outlined_pdf_bytes()).unwrap();
+        let toc = toc_within(parser.extract_toc(), &(2..=3));
+        let titles: Vec<_> = toc.iter().map(|e| e.title.as_str()).collect();
+        // Chapter 1 (p1) drops out; its sections are promoted
+        assert_eq!(titles, vec!["Section 1.1", "Section 1.2", "부록"]);
+    }
+
+    #[test]
+    fn test_mdx_table_of_contents() {
+        let entry = |title: &str, page, level, children| TocEntry {
+            title: title.to_string(),
+            page,
+            level,
+            children,
+        };
+        let doc = PdfDocument {
+            version: "1.7".to_string(),
+            page_count: 2,
+            pages: vec![PageContent { page_number: 1, text: "Body".to_string(), source_document: 0 }],
+            metadata: PdfMetadata::default(),
+            images: vec![],
+            fonts: vec![],
+            tables: vec![],
+            layout: vec![],
+            toc: vec![entry(
+                "Chapter [1]",
+                1,
+                1,
+                vec![entry("Section 1.1", 2, 2, vec![]), entry("Unresolved", 0, 2, vec![])],
+            )],
+            hyperlinks: vec![],
+            form_fields: vec![],
+            layout_text: None,
+            color_mode: ColorMode::default(),
+        };
+
+        let mdx = doc.to_mdx();
+        let toc_at = mdx.find("## Table of Contents\n\n").expect("toc heading");
+        assert!(toc_at < mdx.find("Body").unwrap());
+        assert!(mdx.contains("- [Chapter \\[1\\]](#page-1)\n  - [Section 1.1](#page-2)\n  - Unresolved\n"));
+    }
+
+    /// 2-page PDF whose first page ("Page 1" at 100,700) carries a URI link
+    /// over the text, a URI link over empty space and an internal GoTo link
+    fn linked_pdf_bytes() -> Vec<u8> {
+        use lopdf::{dictionary, Object};
+
+        let mut doc = lopdf::Document::load_mem(&numbered_pdf_bytes(2)).unwrap();
+        let pages = doc.get_pages();
+        let rect = |x1: i64, y1: i64, x2: i64, y2: i64| -> Object {
+            vec![x1.into(), y1.into(), x2.into(), y2.into()].into()
+        };
+        let uri = |url: &str| dictionary! { "S" => "URI", "URI" => Object::string_literal(url) };
+
+        let over_text = doc.add_object(dictionary! {
+            "Type" => "Annot",
+            "Subtype" => "Link",
+            // Deliberately reversed corners
+            "Rect" => rect(200, 730, 95, 690),
+            "A" => uri("https://example.com/docs"),
+        });
+        let blank = doc.add_object(dictionary! {
+            "Type" => "Annot",
+            "Subtype" => "Link",
+            "Rect" => rect(100, 100, 200, 120),
+            "A" => uri("https://example.com/blank"),
+        });
+        let internal = doc.add_object(dictionary! {
+            "Type" => "Annot",
+            "Subtype" => "Link",
+            "Rect" => rect(100, 300, 200, 320),
+            "A" => dictionary! { "S" => "GoTo", "D" => vec![Object::Reference(pages[&2]), "Fit".into()] },
+        });
+        doc.get_dictionary_mut(pages[&1])
+            .unwrap()
+            .set("Annots", vec![over_text.into(), blank.into(), internal.into()]);
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_extract_hyperlinks() {
+        let parser = PdfParser::from_bytes(linked_pdf_bytes()).unwrap();
+        let links = parser.extract_hyperlinks();
+
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].page, 1);
+        assert_eq!(links[0].url, "https://example.com/docs");
+        assert_eq!(links[0].rect, [95.0, 690.0, 200.0, 730.0]);
+        assert_eq!(links[0].linked_text.as_deref(), Some("Page 1"));
+        assert_eq!(links[1].url, "https://example.com/blank");
+        assert_eq!(links[1].linked_text, None);
+
+        assert!(PdfParser::from_bytes(numbered_pdf_bytes(1)).unwrap().extract_hyperlinks().is_empty());
+    }
+
+    fn form_pdf_bytes() -> Vec<u8> {
+        use lopdf::{dictionary, Object, StringFormat};
+
+        let mut doc = lopdf::Document::load_mem(&numbered_pdf_bytes(2)).unwrap();
+        let pages = doc.get_pages();
+        let utf16 = |text: &str| {
+            let mut bytes = vec![0xFE, 0xFF];
+            bytes.extend(text.encode_utf16().flat_map(u16::to_be_bytes));
+            Object::String(bytes, StringFormat::Hexadecimal)
+        };
+
+        let name = doc.add_object(dictionary! {
+            "FT" => "Tx", "T" => Object::string_literal("name"), "V" => utf16("홍길동"),
+            "Subtype" => "Widget", "P" => pages[&1],
+        });
+        // No /P: the page is found through the page's /Annots
+        let agree = doc.add_object(dictionary! {
+            "FT" => "Btn", "T" => Object::string_literal("agree"), "V" => "Yes", "Subtype" => "Widget",
+        });
+        let gender = doc.add_object(dictionary! {
+            "FT" => "Btn", "Ff" => 1i64 << 15, "T" => Object::string_literal("gender"), "V" => "F",
+            "Subtype" => "Widget", "P" => pages[&2],
+        });
+        let submit = doc.add_object(dictionary! {
+            "FT" => "Btn", "Ff" => 1i64 << 16, "T" => Object::string_literal("submit"),
+            "Subtype" => "Widget", "P" => pages[&2],
+        });
+        // Parent field: type inherited by the child, name qualified with "."
+        let city = doc.add_object(dictionary! {
+            "T" => Object::string_literal("city"), "V" => Object::string_literal("Seoul"),
+            "Subtype" => "Widget", "P" => pages[&1],
+        });
+        let addr = doc.add_object(dictionary! {
+            "FT" => "Tx", "T" => Object::string_literal("addr"), "Kids" => vec![city.into()],
+        });
+        let region = doc.add_object(dictionary! {
+            "FT" => "Ch", "T" => Object::string_literal("region"),
+            "V" => vec![Object::string_literal("A"), Object::string_literal("B")],
+            "Subtype" => "Widget", "P" => pages[&2],
+        });
+        doc.get_dictionary_mut(pages[&2]).unwrap().set("Annots", vec![agree.into()]);
+
+        let root = doc.trailer.get(b"Root").and_then(Object::as_reference).unwrap();
+        doc.get_dictionary_mut(root).unwrap().set(
+            "AcroForm",
+            dictionary! {
+                "Fields" => vec![name.into(), agree.into(), gender.into(), submit.into(), addr.into(), region.into()],
+            },
+        );
+
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_extract_form_fields() {
+        let fields = PdfParser::from_bytes(form_pdf_bytes()).unwrap().extract_form_fields();
+        let field = |name: &str, field_type, value: Option<&str>, page| FormField {
+            name: name.to_string(),
+            field_type,
+            value: value.map(str::to_string),
+            page,
+        };
+        assert_eq!(
+            fields,
+            vec![
+                field("name", FormFieldType::Text, Some("홍길동"), 1),
+                field("agree", FormFieldType::Checkbox, Some("Yes"), 2),
+                field("gender", FormFieldType::Radio, Some("F"), 2),
+                field("addr.city", FormFieldType::Text, Some("Seoul"), 1),
+                field("region", FormFieldType::Select, Some("A, B"), 2),
+            ]
+        );
+
+        assert!(PdfParser::from_bytes(numbered_pdf_bytes(1)).unwrap().extract_form_fields().is_empty());
+    }
+
+    #[test]
+    fn test_mdx_form_fields() {
+        let doc = PdfDocument {
+            version: "1.7".to_string(),
+            page_count: 1,
+            pages: vec![PageContent { page_number: 1, text: "신청서".to_string(), source_document: 0 }],
+            metadata: PdfMetadata::default(),
+            images: vec![],
+            fonts: vec![],
+            tables: vec![],
+            layout: vec![],
+            toc: vec![],
+            hyperlinks: vec![],
+            form_fields: vec![
+                FormField { name: "성명".to_string(), field_type: FormFieldType::Text, value: Some("홍길동".to_string()), page: 1 },
+                FormField { name: "연락처".to_string(), field_type: FormFieldType::Text, value: None, page: 1 },
+            ],
+            layout_text: None,
+            color_mode: ColorMode::default(),
+        };
+
+        let mdx = doc.to_mdx();
+        assert!(mdx.contains("## Form Fields\n\n**성명:** 홍길동\n\n**연락처:**\n"), "{}", mdx);
+    }
+
+    #[test]
+    fn test_word_count_and_reading_time() {
+        let page = |n: usize, text: &str| PageContent { page_number: n, text: text.to_string(), source_document: 0 };
+        let mut doc = PdfDocument {
+            version: "1.7".to_string(),
+            page_count: 3,
+            pages: vec![
+                // 5 eojeol; the lone "-" and "…" are not words
+                page(1, "제1조(목적) 이 법은 국민의 권리를 - …"),
+                page(2, "The quick brown fox, 2024."),
+                page(3, "   "),
+            ],
+            metadata: PdfMetadata::default(),
+            images: vec![],
+            fonts: vec![],
+            tables: vec![],
+            layout: vec![],
+            toc: vec![],
+            hyperlinks: vec![],
+            form_fields: vec![],
+            layout_text: None,
+            color_mode: ColorMode::default(),
+        };
+        assert_eq!(doc.word_count(), 10);
+        assert!((doc.reading_time_minutes() - 10.0 / 238.0).abs() < 1e-9);
+
+        doc.pages = vec![page(1, &"단어 ".repeat(595))];
+        assert_eq!(doc.word_count(), 595);
+        assert_eq!(doc.reading_time_minutes(), 2.5);
+        let mdx = doc.to_mdx();
+        assert!(mdx.contains("words: 595\nreadingTime: 2.5\n"), "{}", mdx);
+    }
+
+    #[test]
+    fn test_detect_language() {
+        let doc_with = |texts: &[&str], lang: Option<&str>| PdfDocument {
+            version: "1.7".to_string(),
+            page_count: texts.len(),
+            pages: texts
+                .iter()
+                .enumerate()
+                .map(|(i, text)| PageContent { page_number: i + 1, text: text.to_string(), source_document: 0 })
+                .collect(),
+            metadata: PdfMetadata { language: lang.map(str::to_string), ..Default::default() },
+            images: vec![],
+            fonts: vec![],
+            tables: vec![],
+            layout: vec![],
+            toc: vec![],
+            hyperlinks: vec![],
+            form_fields: vec![],
+            layout_text: None,
+            color_mode: ColorMode::default(),
+        };
+
+        let korean = doc_with(&["제1조(목적) 이 법은 국민의 권리와 의무를 규정함을 목적으로 한다."], None);
+        assert_eq!(korean.detect_language(), "ko");
+        assert!(korean.to_mdx().contains("language: \"ko\"\n"));
+
+        let english = doc_with(&["Annual report of the international organization, 2024."], None);
+        assert_eq!(english.detect_language(), "en");
+
+        // Korean body with English terms sprinkled in: the majority block wins
+        let mixed = doc_with(&["UN 보고서", "지속가능발전목표 SDGs 이행 현황과 향후 과제를 정리하였다"], None);
+        assert_eq!(mixed.detect_language(), "ko");
+
+        assert_eq!(doc_with(&["国際連合の報告書です。"], None).detect_language(), "ja");
+        assert_eq!(doc_with(&["联合国年度报告"], None).detect_language(), "zh");
+        assert_eq!(doc_with(&["  2024 — 1/3 "], None).detect_language(), "und");
+
+        // A declared /Lang overrides the text sample
+        assert_eq!(doc_with(&["English text only"], Some("ko-KR")).detect_language(), "ko");
+    }
+
+    #[test]
+    fn test_mdx_hyperlinks() {
+        let link = |url: &str, text: Option<&str>| PdfLink {
+            page: 1,
+            url: url.to_string(),
+            rect: [0.0, 0.0, 10.0, 10.0],
+            linked_text: text.map(str::to_string),
+        };
+        let doc = PdfDocument {
+            version: "1.7".to_string(),
+            page_count: 1,
+            pages: vec![PageContent { page_number: 1, text: "See the manual for details".to_string(), source_document: 0 }],
+            metadata: PdfMetadata::default(),
+            images: vec![],
+            fonts: vec![],
+            tables: vec![],
+            layout: vec![],
+            toc: vec![],
+            hyperlinks: vec![
+                link("https://example.com/manual (v2)", Some("manual")),
+                link("https://example.com/missing", Some("appendix")),
+                link("https://example.com/bare", None),
+            ],
+            form_fields: vec![],
+            layout_text: None,
+            color_mode: ColorMode::default(),
+        };
+
+        let mdx = doc.to_mdx();
+        assert!(mdx.contains("See the [manual](https://example.com/manual%20%28v2%29) for details"), "{}", mdx);
+        assert!(mdx.contains("## Links\n\n"));
+        assert!(mdx.contains("- [appendix](https://example.com/missing) (page 1)\n"));
+        assert!(mdx.contains("- <https://example.com/bare> (page 1)\n"));
+    }
+
+    #[test]
+    fn test_inline_hyperlinks_skips_linked_occurrences() {
+        let link = |url: &str| PdfLink {
+            page: 1,
+            url: url.to_string(),
+            rect: [0.0; 4],
+            linked_text: Some("here".to_string()),
+        };
+        let links = [link("https://a.example"), link("https://b.example")];
+        let (out, unplaced) = inline_hyperlinks("click here or here", &links);
+        assert_eq!(out, "click [here](https://a.example) or [here](https://b.example)");
+        assert!(unplaced.is_empty());
+    }
+
+    #[test]
+    fn test_parse_pages_keeps_original_page_numbers() {
+        let parser = PdfParser::from_bytes(numbered_pdf_bytes(6)).unwrap();
+        let doc = parser.parse_pages(3..=4).unwrap();
+
+        assert_eq!(doc.page_count, 2);
+        assert!(doc.pages.iter().all(|p| (3..=4).contains(&p.page_number)));
+        assert!(doc.layout.iter().all(|e| (3..=4).contains(&e.page)));
+        let text = doc.full_text();
+        assert!(text.contains("Page 3") && text.contains("Page 4"), "{}", text);
+        assert!(!text.contains("Page 1") && !text.contains("Page 6"), "{}", text);
+    }
+
+    #[test]
+    fn test_parse_pages_rejects_out_of_range() {
+        let parser = PdfParser::from_bytes(numbered_pdf_bytes(3)).unwrap();
+        let err = parser.parse_pages(2..=7).unwrap_err();
+        assert!(matches!(err, PdfError::InvalidPageRange(_)), "{:?}", err);
+        assert!(err.to_string().contains("exceeds document page count (3)"), "{}", err);
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::InvalidInput);
+
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 3..=2;
+        assert!(parser.parse_pages(reversed).is_err());
+        assert!(parser.parse_pages(0..=1).is_err());
+    }
+
+    #[test]
+    fn test_parse_page_range_spec() {
+        assert_eq!(parse_page_range("3-7"), Ok(3..=7));
+        assert_eq!(parse_page_range(" 2 - 2 "), Ok(2..=2));
+        assert_eq!(parse_page_range("5"), Ok(5..=5));
+        assert!(parse_page_range("7-3").is_err());
+        assert!(parse_page_range("0-2").is_err());
+        assert!(parse_page_range("a-b").is_err());
+    }
+
     #[test]
     fn test_page_split() {
         let parser = PdfParser {
@@ -3181,6 +5553,7 @@ mod tests {
             pages: vec![PageContent {
                 page_number: 1,
                 text: "Hello".to_string(),
+                source_document: 0,
             }],
             metadata: PdfMetadata::default(),
             images: vec![PdfImage {
@@ -3194,6 +5567,11 @@ mod tests {
             fonts: vec![],
             tables: vec![],
             layout: vec![],
+            toc: vec![],
+            hyperlinks: vec![],
+            form_fields: vec![],
+            layout_text: None,
+            color_mode: ColorMode::default(),
         };
 
         let mdx = doc.to_mdx();
@@ -3262,6 +5640,7 @@ mod tests {
             pages: vec![PageContent {
                 page_number: 1,
                 text: "Hello".to_string(),
+                source_document: 0,
             }],
             metadata: PdfMetadata::default(),
             images: vec![],
@@ -3271,16 +5650,25 @@ mod tests {
                     base_font: "Arial-Bold".to_string(),
                     is_bold: true,
                     is_italic: false,
+                    is_embedded: true,
+                    encoding: "WinAnsiEncoding".to_string(),
                 },
                 PdfFont {
                     name: "F2".to_string(),
                     base_font: "Arial-Italic".to_string(),
                     is_bold: false,
                     is_italic: true,
+                    is_embedded: true,
+                    encoding: String::new(),
                 },
             ],
             tables: vec![],
             layout: vec![],
+            toc: vec![],
+            hyperlinks: vec![],
+            form_fields: vec![],
+            layout_text: None,
+            color_mode: ColorMode::default(),
         };
 
         let mdx = doc.to_mdx();
@@ -3302,6 +5690,7 @@ mod tests {
             column_count: 3,
             y_top: 0.0,
             y_bottom: 0.0,
+            column_positions: Vec::new(),
         };
 
         let md = table.to_markdown();
@@ -3335,6 +5724,69 @@ mod tests {
         assert_eq!(table.rows[0], vec!["Name", "Age"]);
         assert_eq!(table.rows[1], vec!["Alice", "30"]);
         assert_eq!(table.rows[2], vec!["Bob", "25"]);
+        assert_eq!(table.column_positions.len(), 2);
+        assert!((table.column_positions[0] - 100.0).abs() < 1.0, "{:?}", table.column_positions);
+    }
+
+    fn positioned(text: &str, x: f64, y: f64) -> PositionedText {
+        PositionedText { text: text.to_string(), x, y, page: 1, font_size: Some(10.0), font_name: None }
+    }
+
+    #[test]
+    fn test_table_detection_right_aligned_numbers() {
+        // Amount columns right-aligned at x=300 and x=420 (10pt digits ≈ 5.5pt),
+        // so their start X varies by up to ~40pt within a column
+        let right = |text: &str, edge: f64, y: f64| positioned(text, edge - text.len() as f64 * 5.5, y);
+        let mut texts = vec![positioned("Item", 72.0, 700.0), right("2023", 300.0, 700.0), right("2024", 420.0, 700.0)];
+        for (i, (label, a, b)) in [
+            ("Revenue", "1,234,567", "1,300,000"),
+            ("Cost", "(12,000)", "89"),
+            ("Tax", "45,600", "(1,250)"),
+            ("Net", "89", "48,750"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let y = 680.0 - i as f64 * 20.0;
+            texts.extend([positioned(label, 72.0, y), right(a, 300.0, y), right(b, 420.0, y)]);
+        }
+
+        let tables = detect_tables_from_positions(&texts, 1);
+        assert_eq!(tables.len(), 1);
+        let table = &tables[0];
+        assert_eq!(table.column_count, 3);
+        assert_eq!(table.rows[0], vec!["Item", "2023", "2024"]);
+        assert_eq!(table.rows[1], vec!["Revenue", "1,234,567", "1,300,000"]);
+        assert_eq!(table.rows[2], vec!["Cost", "(12,000)", "89"]);
+        assert_eq!(table.rows[4], vec!["Net", "89", "48,750"]);
+        assert!((table.column_positions[1] - 300.0).abs() < 3.0, "{:?}", table.column_positions);
+        assert!((table.column_positions[2] - 420.0).abs() < 3.0, "{:?}", table.column_positions);
+    }
+
+    #[test]
+    fn test_numbered_list_is_not_a_table() {
+        // Marker + short text 18pt apart: one column, not a 2-column table
+        let texts: Vec<PositionedText> = (0..5)
+            .flat_map(|i| {
+                let y = 700.0 - i as f64 * 15.0;
+                [positioned(&format!("{}.", i + 1), 72.0, y), positioned("Short list item", 90.0, y)]
+            })
+            .collect();
+        assert!(detect_tables_from_positions(&texts, 1).is_empty());
+    }
+
+    #[test]
+    fn test_histogram_columns_merges_wandering_cells() {
+        let texts: Vec<PositionedText> = [100.0, 112.0, 95.0, 104.0, 240.0, 255.0, 236.0, 248.0]
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| positioned("cell", x, 700.0 - (i % 4) as f64 * 20.0))
+            .collect();
+        let refs: Vec<&PositionedText> = texts.iter().collect();
+        let columns = histogram_columns(&refs, 2);
+        assert_eq!(columns.len(), 2, "{:?}", columns);
+        assert!(columns[0] < 120.0 && columns[1] > 230.0, "{:?}", columns);
+        assert!(is_numeric_cell("(1,234.50)") && is_numeric_cell("₩12,000") && !is_numeric_cell("Q3"));
     }
 
     #[test]
@@ -3510,6 +5962,7 @@ mod tests {
             pages: vec![PageContent {
                 page_number: 1,
                 text: "Hello".to_string(),
+                source_document: 0,
             }],
             metadata: PdfMetadata::default(),
             images: vec![],
@@ -3523,8 +5976,14 @@ mod tests {
                 column_count: 2,
                 y_top: 0.0,
                 y_bottom: 0.0,
+                column_positions: Vec::new(),
             }],
             layout: vec![],
+            toc: vec![],
+            hyperlinks: vec![],
+            form_fields: vec![],
+            layout_text: None,
+            color_mode: ColorMode::default(),
         };
 
         let mdx = doc.to_mdx();
@@ -3566,6 +6025,52 @@ mod tests {
         assert_eq!(alignment, TextAlignment::Left);
     }
 
+    fn positioned(content: &str, x: f64, y: f64) -> LayoutElement {
+        LayoutElement {
+            element_type: LayoutElementType::Text,
+            content: content.to_string(),
+            page: 1,
+            x,
+            y,
+            width: 200.0,
+            height: 12.0,
+            font_size: Some(12.0),
+            font_name: None,
+            alignment: TextAlignment::Left,
+            is_bold: false,
+            is_italic: false,
+            line_spacing: 1.2,
+            indent_level: 0,
+            ref_id: None,
+        }
+    }
+
+    #[test]
+    fn test_layout_reading_order_two_columns() {
+        // Rows interleave the columns, as a naive top-to-bottom read would
+        let mut elements = Vec::new();
+        for row in 0..4 {
+            let y = 700.0 - row as f64 * 14.0;
+            elements.push(positioned(&format!("left {}", row + 1), 72.0, y));
+            elements.push(positioned(&format!("right {}", row + 1), 320.0, y));
+        }
+        let text = layout_reading_order(&elements);
+        assert_eq!(text, "left 1\nleft 2\nleft 3\nleft 4\n\nright 1\nright 2\nright 3\nright 4");
+        assert!(text.find("left 4").unwrap() < text.find("right 1").unwrap());
+    }
+
+    #[test]
+    fn test_layout_reading_order_single_column() {
+        let elements = vec![
+            positioned("second line", 90.0, 686.0),
+            positioned("first", 72.0, 700.0),
+            positioned("line", 130.0, 700.5),
+            positioned("third line", 72.0, 672.0),
+        ];
+        assert_eq!(layout_reading_order(&elements), "first line\nsecond line\nthird line");
+        assert_eq!(two_means(&[1.0, 1.0]), None);
+    }
+
     #[test]
     fn test_layout_element_creation() {
         let element = LayoutElement {
@@ -3619,6 +6124,86 @@ mod tests {
         assert!(format!("{}", err).contains("AES-512"));
     }
 
+    /// One-page PDF with `streams` appended as extra objects; returns the
+    /// bytes and the ids the streams landed at
+    fn pdf_with_streams(streams: Vec<lopdf::Stream>) -> (Vec<u8>, Vec<(u32, u16)>) {
+        let mut doc = lopdf::Document::load_mem(&numbered_pdf_bytes(1)).unwrap();
+        let ids = streams.into_iter().map(|stream| doc.add_object(stream)).collect();
+        let mut data = Vec::new();
+        doc.save_to(&mut data).unwrap();
+        (data, ids)
+    }
+
+    fn image_stream(color_space: lopdf::Object, filter: Option<&str>, content: Vec<u8>) -> lopdf::Stream {
+        use lopdf::dictionary;
+        let mut dict = dictionary! {
+            "Type" => "XObject",
+            "Subtype" => "Image",
+            "Width" => 2,
+            "Height" => 2,
+            "BitsPerComponent" => 8,
+            "ColorSpace" => color_space,
+        };
+        if let Some(filter) = filter {
+            dict.set("Filter", lopdf::Object::Name(filter.as_bytes().to_vec()));
+        }
+        lopdf::Stream::new(dict, content).with_compression(false)
+    }
+
+    #[test]
+    fn test_pdf_error_invalid_structure() {
+        let err = PdfParser::from_bytes(b"GIF89a not a pdf".to_vec()).err().unwrap();
+        assert!(matches!(err, PdfError::InvalidStructure(_)), "{:?}", err);
+
+        let garbage = PdfParser::from_bytes(b"%PDF-1.4\nthis is not an object graph".to_vec()).unwrap();
+        let err = garbage.page_count().unwrap_err();
+        assert!(matches!(err, PdfError::InvalidStructure(_)), "{:?}", err);
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_extract_image_typed_errors() {
+        use lopdf::Object;
+        let (data, ids) = pdf_with_streams(vec![
+            image_stream(Object::Name(b"DeviceGray".to_vec()), None, vec![0, 64, 128, 255]),
+            image_stream(Object::Name(b"DeviceRGB".to_vec()), Some("FlateDecode"), b"not zlib at all".to_vec()),
+            image_stream(
+                Object::Array(vec![
+                    Object::Name(b"Separation".to_vec()),
+                    Object::Name(b"PANTONE 185 C".to_vec()),
+                    Object::Name(b"DeviceCMYK".to_vec()),
+                    Object::Null,
+                ]),
+                None,
+                vec![0; 4],
+            ),
+        ]);
+        let parser = PdfParser::from_bytes(data).unwrap();
+
+        let image = parser.extract_image(ids[0]).unwrap();
+        assert_eq!((image.width, image.height), (2, 2));
+        assert!(matches!(image.format, ImageFormat::Raw));
+        assert_eq!(image.data, vec![0, 64, 128, 255]);
+
+        let err = parser.extract_image(ids[1]).unwrap_err();
+        assert!(matches!(err, PdfError::DecompressionFailed(_)), "{:?}", err);
+
+        match parser.extract_image(ids[2]).unwrap_err() {
+            PdfError::UnsupportedColorSpace(name) => assert_eq!(name, "Separation"),
+            other => panic!("expected UnsupportedColorSpace, got {:?}", other),
+        }
+
+        let missing = (ids[2].0 + 100, 0);
+        match parser.extract_image(missing).unwrap_err() {
+            PdfError::ObjectNotFound(number, generation) => assert_eq!((number, generation), missing),
+            other => panic!("expected ObjectNotFound, got {:?}", other),
+        }
+        assert!(PdfError::ObjectNotFound(12, 0).to_string().contains("12 0 R"));
+
+        // `extract_images` skips what `extract_image` rejects
+        assert_eq!(parser.extract_images().len(), 1);
+    }
+
     #[test]
     fn test_try_auto_decrypt_unencrypted() {
         let parser = PdfParser {
@@ -3685,4 +6270,126 @@ mod tests {
         let out = merge_partial_numbering(input);
         assert!(out.ends_with(".9"));
     }
+
+    #[test]
+    fn test_render_page_to_png_rejects_bad_page_and_dpi() {
+        let parser = PdfParser::from_bytes(outlined_pdf_bytes()).unwrap();
+        for (page, dpi) in [(0, 150), (4, 150), (1, 0)] {
+            assert!(
+                matches!(parser.render_page_to_png(page, dpi), Err(PdfError::ParseError(_))),
+                "page {} dpi {} should be rejected",
+                page,
+                dpi
+            );
+        }
+    }
+
+    #[test]
+    fn test_clean_ocr_text() {
+        assert_eq!(clean_ocr_text("\u{c}"), "");
+        assert_eq!(clean_ocr_text("  \n\n"), "");
+        assert_eq!(
+            clean_ocr_text("\n제1조 목적  \n이 법은\n\n\n\n제2조 정의\n\u{c}"),
+            "제1조 목적\n이 법은\n\n제2조 정의"
+        );
+    }
+
+    #[test]
+    fn test_ocr_png_rejects_bad_language() {
+        for lang in ["", "kor+", "-psm", "kor eng", "../kor"] {
+            assert!(
+                matches!(ocr_png(&[], lang), Err(PdfError::ParseError(msg)) if msg.starts_with("invalid OCR language")),
+                "{:?} should be rejected",
+                lang
+            );
+        }
+    }
+
+    #[test]
+    fn test_page_renderer_commands() {
+        let args = |r: PageRenderer| {
+            let cmd = r.command(
+                Path::new("in.pdf"),
+                Path::new("out"),
+                Path::new("out.png"),
+                2,
+                144,
+            );
+            cmd.get_args().map(|a| a.to_string_lossy().into_owned()).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            args(PageRenderer::Pdftoppm),
+            ["-png", "-singlefile", "-r", "144", "-f", "2", "-l", "2", "in.pdf", "out"]
+        );
+        assert_eq!(
+            args(PageRenderer::Mutool),
+            ["draw", "-q", "-r", "144", "-F", "png", "-o", "out.png", "in.pdf", "2"]
+        );
+    }
+
+    #[test]
+    fn test_extract_fonts_embedding_and_encoding() {
+        use lopdf::{dictionary, Object, Stream};
+
+        let mut doc = lopdf::Document::load_mem(&numbered_pdf_bytes(1)).unwrap();
+        let font_file = doc.add_object(Stream::new(dictionary! {}, vec![0; 16]));
+        let descriptor = doc.add_object(dictionary! {
+            "Type" => "FontDescriptor",
+            "FontName" => "ABCDEF+NanumGothic",
+            "FontFile2" => font_file,
+        });
+        let cid_font = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => "ABCDEF+NanumGothic",
+            "FontDescriptor" => descriptor,
+        });
+        doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type0",
+            "BaseFont" => "ABCDEF+NanumGothic-Identity-H",
+            "Encoding" => "Identity-H",
+            "DescendantFonts" => vec![Object::Reference(cid_font)],
+        });
+        doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Times-Roman",
+            "Encoding" => dictionary! {
+                "Type" => "Encoding",
+                "BaseEncoding" => "WinAnsiEncoding",
+            },
+        });
+        let mut bytes = Vec::new();
+        doc.save_to(&mut bytes).unwrap();
+
+        let fonts = PdfParser::from_bytes(bytes).unwrap().extract_fonts();
+        let font = |base: &str| fonts.iter().find(|f| f.base_font == base).unwrap();
+
+        assert!(font("ABCDEF+NanumGothic-Identity-H").is_embedded);
+        assert_eq!(font("ABCDEF+NanumGothic-Identity-H").encoding, "Identity-H");
+        assert!(font("ABCDEF+NanumGothic").is_embedded);
+        assert!(!font("Times-Roman").is_embedded);
+        assert_eq!(font("Times-Roman").encoding, "WinAnsiEncoding");
+        // Standard 14 font referenced without a descriptor
+        assert!(!font("Helvetica").is_embedded);
+        assert_eq!(font("Helvetica").encoding, "");
+    }
+
+    #[test]
+    fn test_has_all_fonts_embedded() {
+        let font = |is_embedded| PdfFont {
+            name: "F1".to_string(),
+            base_font: "NanumGothic".to_string(),
+            is_bold: false,
+            is_italic: false,
+            is_embedded,
+            encoding: String::new(),
+        };
+        let mut doc = PdfParser::from_bytes(numbered_pdf_bytes(1)).unwrap().parse().unwrap();
+        doc.fonts = vec![font(true)];
+        assert!(doc.has_all_fonts_embedded());
+        doc.fonts.push(font(false));
+        assert!(!doc.has_all_fonts_embedded());
+    }
 }
@@ -1703,6 +1703,7 @@ fn matrix_to_pdf_table(matrix: &[Vec<String>], page: usize, bbox: BBox) -> PdfTa
         column_count,
         y_top: bbox.y2,
         y_bottom: bbox.y1,
+        column_positions: Vec::new(),
     }
 }
 
@@ -1988,9 +1989,9 @@ mod tests {
 
     #[test]
     fn merge_prefers_line_over_overlapping_cluster() {
-        let line = PdfTable { page: 1, rows: vec![vec!["L".into()]], column_count: 1, y_top: 200.0, y_bottom: 100.0 };
-        let overlap = PdfTable { page: 1, rows: vec![vec!["C".into()]], column_count: 1, y_top: 150.0, y_bottom: 120.0 };
-        let disjoint = PdfTable { page: 1, rows: vec![vec!["D".into()]], column_count: 1, y_top: 90.0, y_bottom: 50.0 };
+        let line = PdfTable { page: 1, rows: vec![vec!["L".into()]], column_count: 1, y_top: 200.0, y_bottom: 100.0, column_positions: Vec::new() };
+        let overlap = PdfTable { page: 1, rows: vec![vec!["C".into()]], column_count: 1, y_top: 150.0, y_bottom: 120.0, column_positions: Vec::new() };
+        let disjoint = PdfTable { page: 1, rows: vec![vec!["D".into()]], column_count: 1, y_top: 90.0, y_bottom: 50.0, column_positions: Vec::new() };
         let out = merge_line_and_cluster(vec![line], vec![overlap, disjoint]);
         assert_eq!(out.len(), 2, "overlapping cluster dropped, disjoint kept");
         assert_eq!(out[0].rows[0][0], "L");
@@ -419,15 +419,15 @@ impl Layout {
     fn render_block(&self, block: &IRBlock, page: &mut PageBuilder, pages: &mut Vec<Vec<Op>>) {
         let [h1, h2, h3, h_rest, body] = self.sizes;
         match block {
-            IRBlock::Paragraph { text, footnote, href } => {
+            IRBlock::Paragraph { text, notes, href } => {
                 for line in text.lines() {
                     self.text_line(page, pages, line, body, &self.fonts.regular, BLACK);
                 }
                 if let Some(url) = href {
                     self.text_line(page, pages, url, body * 0.85, &self.fonts.regular, GRAY);
                 }
-                if let Some(note) = footnote {
-                    self.text_line(page, pages, note, body * 0.85, &self.fonts.italic, GRAY);
+                for note in notes {
+                    self.text_line(page, pages, &note.marker(), body * 0.85, &self.fonts.italic, GRAY);
                 }
             }
             IRBlock::Heading { level, text } => {
@@ -277,7 +277,7 @@ fn render_blocks(blocks: &[IRBlock]) -> String {
 
 fn render_block(block: &IRBlock, out: &mut String) {
     match block {
-        IRBlock::Paragraph { text, footnote, href } => {
+        IRBlock::Paragraph { text, notes, href } => {
             out.push_str("<p>");
             out.push_str(&html_escape(text));
             if let Some(url) = href {
@@ -287,8 +287,8 @@ fn render_block(block: &IRBlock, out: &mut String) {
                     html_escape(url)
                 ));
             }
-            if let Some(note) = footnote {
-                out.push_str(&format!("<br><small>{}</small>", html_escape(note)));
+            for note in notes {
+                out.push_str(&format!("<br><small>{}</small>", html_escape(&note.marker())));
             }
             out.push_str("</p>\n");
         }
@@ -464,7 +464,7 @@ fn sanitize_asset_name(alt: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ir::{IRCell, IRTable};
+    use crate::ir::{IRCell, IRTable, InlineNote};
 
     fn opts() -> RenderOptions {
         RenderOptions::default()
@@ -481,7 +481,7 @@ mod tests {
     fn paragraph_with_href_and_footnote() {
         let blocks = vec![IRBlock::Paragraph {
             text: "본문".into(),
-            footnote: Some("[각주] 내용".into()),
+            notes: vec![InlineNote { is_endnote: false, body: "내용".into() }],
             href: Some("https://example.com".into()),
         }];
         let html = render_ir_to_html(&blocks, &opts());
@@ -12,6 +12,7 @@ use image::codecs::jpeg::JpegEncoder;
 use resvg::tiny_skia;
 use resvg::usvg;
 use serde::{Deserialize, Serialize};
+use crate::hwp::ShapeComponent;
 use std::io::Cursor;
 use std::path::Path;
 use thiserror::Error;
@@ -429,6 +430,25 @@ impl Renderer {
         format!("data:image/svg+xml;base64,{}", encoded)
     }
 
+    /// Render an HWP drawing object (rectangle, ellipse, line, polygon)
+    /// as a standalone SVG document — see [`crate::hwp::shape_to_svg`].
+    pub fn shape_to_svg(shape: &ShapeComponent) -> String {
+        crate::hwp::shape_to_svg(shape)
+    }
+
+    /// Grey placeholder SVG with a diagonal cross and `label` centered —
+    /// see [`crate::placeholder::placeholder_svg`].
+    pub fn placeholder_svg(width: u32, height: u32, label: &str) -> String {
+        crate::placeholder::placeholder_svg(width, height, label)
+    }
+
+    /// `<img>` tag for an MDM reference, inlining `resolved` media or a
+    /// placeholder when it is missing — see
+    /// [`crate::placeholder::render_mdm_reference`].
+    pub fn render_mdm_reference(filename: &str, resolved: Option<&[u8]>) -> String {
+        crate::placeholder::render_mdm_reference(filename, resolved)
+    }
+
     /// Render image as data URI
     pub fn render_data_uri(&self, image_data: &[u8], format: OutputFormat) -> Result<String, RenderError> {
         let encoded = base64::Engine::encode(
@@ -448,6 +468,7 @@ impl Default for Renderer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hwp::ShapeType;
 
     #[test]
     fn test_render_svg() {
@@ -472,6 +493,65 @@ mod tests {
         assert!(data_uri.starts_with("data:image/svg+xml;base64,"));
     }
 
+    fn shape(shape_type: ShapeType) -> ShapeComponent {
+        ShapeComponent {
+            shape_type,
+            x: 750,
+            y: 1500,
+            width: 7500,
+            height: 3750,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_shape_to_svg_rectangle() {
+        let svg = Renderer::shape_to_svg(&shape(ShapeType::Rectangle));
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains(r#"<rect x="10" y="20" width="100" height="50""#));
+        assert!(svg.contains(r#"viewBox="10 20 100 50""#));
+    }
+
+    #[test]
+    fn test_shape_to_svg_ellipse() {
+        let svg = Renderer::shape_to_svg(&shape(ShapeType::Ellipse));
+        assert!(svg.contains(r#"<ellipse cx="60" cy="45" rx="50" ry="25""#));
+    }
+
+    #[test]
+    fn test_shape_to_svg_line() {
+        let mut line = shape(ShapeType::Line);
+        line.points = vec![(0, 3750), (7500, 0)];
+        let svg = Renderer::shape_to_svg(&line);
+        assert!(svg.contains(r#"<line x1="10" y1="70" x2="110" y2="20""#));
+    }
+
+    #[test]
+    fn test_shape_to_svg_polygon() {
+        let mut polygon = shape(ShapeType::Polygon);
+        polygon.points = vec![(0, 3750), (3750, 0), (7500, 3750)];
+        let svg = Renderer::shape_to_svg(&polygon);
+        assert!(svg.contains(r#"<polygon points="10,70 60,20 110,70""#));
+    }
+
+    #[test]
+    fn test_shape_to_svg_rotation() {
+        let mut rect = shape(ShapeType::Rectangle);
+        rect.rotation = 90;
+        let svg = Renderer::shape_to_svg(&rect);
+        assert!(svg.contains(r#"<g transform="rotate(90 60 45)">"#));
+        // Rotated bounding box swaps width and height
+        assert!(svg.contains(r#"width="50" height="100""#));
+    }
+
+    #[test]
+    fn test_placeholder_svg_is_valid() {
+        let svg = Renderer::placeholder_svg(320, 240, "diagram.png");
+        assert!(usvg::Tree::from_str(&svg, &usvg::Options::default()).is_ok());
+        let png = Renderer::new().render_svg_to_png(&svg).unwrap();
+        assert!(png.starts_with(&[0x89, b'P', b'N', b'G']));
+    }
+
     #[test]
     fn test_responsive_presets() {
         let web = ResponsivePresets::web();
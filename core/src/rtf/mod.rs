@@ -0,0 +1,14 @@
+//! RTF (Rich Text Format) parser.
+//!
+//! Converts `.rtf` documents to Markdown with a built-in state-machine
+//! parser (see [`parser`]): paragraphs, bold/italic runs and `\pict`
+//! images. RTF is a legacy word-processor interchange format still used by
+//! Korean government systems and older document archives.
+//!
+//! Feature-gated behind `rtf` (see `core/Cargo.toml`).
+
+pub mod parser;
+
+pub use parser::{
+    looks_like_rtf, parse_rtf, RtfDocument, RtfImage, RtfParagraph, RtfParser, RtfRun,
+};
@@ -0,0 +1,696 @@
+//! RTF state-machine parser.
+//!
+//! Walks the RTF byte stream token by token (`{`, `}`, control words,
+//! control symbols, `\'hh` escapes, plain text) while tracking a stack of
+//! group states. Formatting (`\b`, `\i`, `\plain`) is scoped to the group
+//! it appears in, non-content destinations (`\fonttbl`, `\stylesheet`,
+//! `\info`, any `\*\…` we don't understand) are skipped whole, and `\pict`
+//! groups are collected as embedded images.
+//!
+//! 8-bit text (`\'hh`) is decoded with the document's `\ansicpg` code page —
+//! Korean RTF exported by Hancom/Word is typically `\ansicpg949`.
+
+use std::io::{self, Read};
+use std::path::Path;
+
+use encoding_rs::Encoding;
+
+/// A run of text sharing one formatting state.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RtfRun {
+    pub text: String,
+    pub bold: bool,
+    pub italic: bool,
+    /// Index into [`RtfDocument::images`] when this run is an image anchor
+    pub image: Option<usize>,
+}
+
+/// A paragraph terminated by `\par` (or end of document).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RtfParagraph {
+    pub runs: Vec<RtfRun>,
+}
+
+impl RtfParagraph {
+    /// Plain text of all text runs.
+    pub fn text(&self) -> String {
+        self.runs.iter().filter(|r| r.image.is_none()).map(|r| r.text.as_str()).collect()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.runs.iter().all(|r| r.image.is_none() && r.text.trim().is_empty())
+    }
+}
+
+/// Image embedded via a `\pict` group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RtfImage {
+    /// File extension: `png`, `jpg`, `emf`, `wmf`, `bmp`
+    pub format: String,
+    pub data: Vec<u8>,
+    /// `\picwgoal` / `\pichgoal` in twips, when present
+    pub width_twips: Option<u32>,
+    pub height_twips: Option<u32>,
+}
+
+impl RtfImage {
+    /// Stable file name for the `index`-th image (0-based).
+    pub fn file_name(&self, index: usize) -> String {
+        format!("rtf_image_{:03}.{}", index + 1, self.format)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RtfDocument {
+    /// Plain text, paragraphs separated by newlines
+    pub text: String,
+    pub paragraphs: Vec<RtfParagraph>,
+    pub images: Vec<RtfImage>,
+}
+
+pub struct RtfParser {
+    data: Vec<u8>,
+}
+
+impl RtfParser {
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let mut data = Vec::new();
+        std::fs::File::open(path.as_ref())?.read_to_end(&mut data)?;
+        Ok(Self { data })
+    }
+
+    pub fn from_bytes(data: Vec<u8>) -> io::Result<Self> {
+        Ok(Self { data })
+    }
+
+    pub fn parse(&self) -> io::Result<RtfDocument> {
+        parse_rtf(&self.data)
+    }
+}
+
+/// Destinations whose content is never body text.
+const SKIPPED_DESTINATIONS: &[&str] = &[
+    "fonttbl", "colortbl", "stylesheet", "info", "header", "headerl", "headerr",
+    "headerf", "footer", "footerl", "footerr", "footerf", "listtable",
+    "listoverridetable", "revtbl", "rsidtbl", "generator", "xmlnstbl",
+    "themedata", "colorschememapping", "datastore", "latentstyles", "filetbl",
+    "pgdsctbl", "fldinst", "nonshppict", "object", "private",
+];
+
+/// Picture state for the innermost `\pict` group.
+#[derive(Debug, Clone, Default)]
+struct PictState {
+    format: Option<&'static str>,
+    hex: Vec<u8>,
+    binary: Vec<u8>,
+    width_twips: Option<u32>,
+    height_twips: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct GroupState {
+    bold: bool,
+    italic: bool,
+    skip: bool,
+    in_pict: bool,
+    /// `\ucN` — characters to skip after each `\uN`
+    uc: usize,
+}
+
+struct Interpreter {
+    stack: Vec<GroupState>,
+    state: GroupState,
+    encoding: &'static Encoding,
+    /// Undecoded `\'hh` bytes awaiting the next non-hex token
+    pending_bytes: Vec<u8>,
+    /// Fallback characters still to skip after a `\uN`
+    unicode_skip: usize,
+    /// Set by `\*`; the next control word decides whether to skip the group
+    ignorable: bool,
+    pict: Option<PictState>,
+    paragraphs: Vec<RtfParagraph>,
+    current: RtfParagraph,
+    images: Vec<RtfImage>,
+}
+
+impl Interpreter {
+    fn new() -> Self {
+        Self {
+            stack: Vec::new(),
+            state: GroupState { uc: 1, ..Default::default() },
+            encoding: encoding_rs::WINDOWS_1252,
+            pending_bytes: Vec::new(),
+            unicode_skip: 0,
+            ignorable: false,
+            pict: None,
+            paragraphs: Vec::new(),
+            current: RtfParagraph::default(),
+            images: Vec::new(),
+        }
+    }
+
+    fn flush_bytes(&mut self) {
+        if self.pending_bytes.is_empty() {
+            return;
+        }
+        let bytes = std::mem::take(&mut self.pending_bytes);
+        let (decoded, _, _) = self.encoding.decode(&bytes);
+        let decoded = decoded.into_owned();
+        self.push_text(&decoded);
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if self.state.skip || self.state.in_pict || text.is_empty() {
+            return;
+        }
+        let (bold, italic) = (self.state.bold, self.state.italic);
+        match self.current.runs.last_mut() {
+            Some(run) if run.image.is_none() && run.bold == bold && run.italic == italic => {
+                run.text.push_str(text);
+            }
+            _ => self.current.runs.push(RtfRun {
+                text: text.to_string(),
+                bold,
+                italic,
+                image: None,
+            }),
+        }
+    }
+
+    fn push_char(&mut self, c: char) {
+        let mut buf = [0u8; 4];
+        self.push_text(c.encode_utf8(&mut buf));
+    }
+
+    fn end_paragraph(&mut self) {
+        let para = std::mem::take(&mut self.current);
+        self.paragraphs.push(para);
+    }
+
+    fn open_group(&mut self) {
+        self.flush_bytes();
+        self.stack.push(self.state);
+        self.ignorable = false;
+    }
+
+    fn close_group(&mut self) {
+        self.flush_bytes();
+        if self.state.in_pict && !self.stack.last().is_some_and(|s| s.in_pict) {
+            self.finish_pict();
+        }
+        if let Some(prev) = self.stack.pop() {
+            self.state = prev;
+        }
+        self.ignorable = false;
+    }
+
+    fn finish_pict(&mut self) {
+        let Some(pict) = self.pict.take() else {
+            return;
+        };
+        let data = if pict.binary.is_empty() { decode_hex(&pict.hex) } else { pict.binary };
+        if data.is_empty() {
+            return;
+        }
+        let format = pict.format.unwrap_or_else(|| sniff_image_format(&data)).to_string();
+        self.images.push(RtfImage {
+            format,
+            data,
+            width_twips: pict.width_twips,
+            height_twips: pict.height_twips,
+        });
+        if !self.state.skip {
+            self.current.runs.push(RtfRun {
+                image: Some(self.images.len() - 1),
+                ..Default::default()
+            });
+        }
+    }
+
+    fn control_word(&mut self, word: &str, param: Option<i32>) {
+        self.flush_bytes();
+        let ignorable = std::mem::take(&mut self.ignorable);
+        if self.state.skip {
+            return;
+        }
+
+        if word == "pict" {
+            self.state.in_pict = true;
+            self.pict = Some(PictState::default());
+            return;
+        }
+        if SKIPPED_DESTINATIONS.contains(&word) {
+            self.state.skip = true;
+            return;
+        }
+
+        if self.state.in_pict {
+            if let Some(pict) = self.pict.as_mut() {
+                let positive = param.and_then(|p| u32::try_from(p).ok());
+                match word {
+                    "pngblip" => pict.format = Some("png"),
+                    "jpegblip" => pict.format = Some("jpg"),
+                    "emfblip" => pict.format = Some("emf"),
+                    "wmetafile" => pict.format = Some("wmf"),
+                    "dibitmap" | "wbitmap" => pict.format = Some("bmp"),
+                    "picwgoal" => pict.width_twips = positive,
+                    "pichgoal" => pict.height_twips = positive,
+                    _ => {}
+                }
+            }
+            return;
+        }
+
+        match word {
+            "ansicpg" => {
+                if let Some(enc) = param.and_then(encoding_for_codepage) {
+                    self.encoding = enc;
+                }
+            }
+            "par" | "sect" => self.end_paragraph(),
+            "line" => self.push_char('\n'),
+            "tab" => self.push_char('\t'),
+            "b" => self.state.bold = param != Some(0),
+            "i" => self.state.italic = param != Some(0),
+            "plain" => {
+                self.state.bold = false;
+                self.state.italic = false;
+            }
+            "uc" => self.state.uc = param.and_then(|p| usize::try_from(p).ok()).unwrap_or(1),
+            "u" => {
+                if let Some(p) = param {
+                    // Values above 32767 are written as negative i16.
+                    if let Some(c) = char::from_u32(p.rem_euclid(65536) as u32) {
+                        self.push_char(c);
+                    }
+                    self.unicode_skip = self.state.uc;
+                }
+            }
+            "emdash" => self.push_char('\u{2014}'),
+            "endash" => self.push_char('\u{2013}'),
+            "bullet" => self.push_char('\u{2022}'),
+            "lquote" => self.push_char('\u{2018}'),
+            "rquote" => self.push_char('\u{2019}'),
+            "ldblquote" => self.push_char('\u{201C}'),
+            "rdblquote" => self.push_char('\u{201D}'),
+            "shppict" => {}
+            _ => {
+                // Unknown `\*\word` destinations carry no body text.
+                if ignorable {
+                    self.state.skip = true;
+                }
+            }
+        }
+    }
+
+    fn hex_byte(&mut self, byte: u8) {
+        if self.state.in_pict {
+            return;
+        }
+        if self.unicode_skip > 0 {
+            self.unicode_skip -= 1;
+            return;
+        }
+        self.pending_bytes.push(byte);
+    }
+
+    fn text_byte(&mut self, byte: u8) {
+        if self.state.in_pict {
+            if let Some(pict) = self.pict.as_mut() {
+                if byte.is_ascii_hexdigit() {
+                    pict.hex.push(byte);
+                }
+            }
+            return;
+        }
+        if byte == b'\r' || byte == b'\n' {
+            return;
+        }
+        if self.unicode_skip > 0 {
+            self.unicode_skip -= 1;
+            return;
+        }
+        if byte.is_ascii() {
+            self.flush_bytes();
+            self.push_char(byte as char);
+        } else {
+            // Raw 8-bit text outside `\'hh` — same code page rules apply.
+            self.pending_bytes.push(byte);
+        }
+    }
+
+    fn finish(mut self) -> RtfDocument {
+        self.flush_bytes();
+        if !self.current.runs.is_empty() {
+            self.end_paragraph();
+        }
+        let paragraphs: Vec<RtfParagraph> = self
+            .paragraphs
+            .into_iter()
+            .filter(|p| !p.is_empty())
+            .collect();
+        let text = paragraphs.iter().map(|p| p.text()).collect::<Vec<_>>().join("\n");
+        RtfDocument {
+            text,
+            paragraphs,
+            images: self.images,
+        }
+    }
+}
+
+/// Map an `\ansicpgN` code page to an encoding.
+fn encoding_for_codepage(cp: i32) -> Option<&'static Encoding> {
+    let label = match cp {
+        949 => "euc-kr",
+        932 => "shift_jis",
+        936 => "gbk",
+        950 => "big5",
+        65001 => "utf-8",
+        874 => "windows-874",
+        1250..=1258 => return Encoding::for_label(format!("windows-{}", cp).as_bytes()),
+        _ => return None,
+    };
+    Encoding::for_label(label.as_bytes())
+}
+
+fn decode_hex(hex: &[u8]) -> Vec<u8> {
+    hex.chunks_exact(2)
+        .filter_map(|pair| {
+            let s = std::str::from_utf8(pair).ok()?;
+            u8::from_str_radix(s, 16).ok()
+        })
+        .collect()
+}
+
+/// Fallback when a `\pict` group names no blip type.
+fn sniff_image_format(data: &[u8]) -> &'static str {
+    if data.starts_with(&[0x89, b'P', b'N', b'G']) {
+        "png"
+    } else if data.starts_with(&[0xFF, 0xD8]) {
+        "jpg"
+    } else if data.starts_with(b"BM") {
+        "bmp"
+    } else {
+        "wmf"
+    }
+}
+
+pub fn parse_rtf(data: &[u8]) -> io::Result<RtfDocument> {
+    if !looks_like_rtf(data) {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "not an RTF document"));
+    }
+
+    let mut it = Interpreter::new();
+    let mut pos = 0;
+    while pos < data.len() {
+        match data[pos] {
+            b'{' => {
+                it.open_group();
+                pos += 1;
+            }
+            b'}' => {
+                it.close_group();
+                pos += 1;
+            }
+            b'\\' => {
+                pos += 1;
+                let Some(&next) = data.get(pos) else { break };
+                if next.is_ascii_alphabetic() {
+                    let start = pos;
+                    while pos < data.len() && data[pos].is_ascii_alphabetic() {
+                        pos += 1;
+                    }
+                    let word = std::str::from_utf8(&data[start..pos]).unwrap_or("");
+                    let num_start = pos;
+                    if pos < data.len() && data[pos] == b'-' {
+                        pos += 1;
+                    }
+                    while pos < data.len() && data[pos].is_ascii_digit() {
+                        pos += 1;
+                    }
+                    let param = std::str::from_utf8(&data[num_start..pos])
+                        .ok()
+                        .and_then(|s| s.parse::<i32>().ok());
+                    // A single space delimiter belongs to the control word.
+                    if pos < data.len() && data[pos] == b' ' {
+                        pos += 1;
+                    }
+
+                    if word == "bin" {
+                        // `\binN` — N raw bytes follow, never interpreted.
+                        let n = param.and_then(|p| usize::try_from(p).ok()).unwrap_or(0);
+                        let end = (pos + n).min(data.len());
+                        if it.state.in_pict {
+                            if let Some(pict) = it.pict.as_mut() {
+                                pict.binary.extend_from_slice(&data[pos..end]);
+                            }
+                        }
+                        pos = end;
+                        continue;
+                    }
+                    it.control_word(word, param);
+                } else {
+                    pos += 1;
+                    match next {
+                        b'\'' => {
+                            let hex = data.get(pos..pos + 2).and_then(|h| std::str::from_utf8(h).ok());
+                            if let Some(byte) = hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                                it.hex_byte(byte);
+                            }
+                            pos = (pos + 2).min(data.len());
+                        }
+                        b'*' => it.ignorable = true,
+                        b'\\' | b'{' | b'}' => {
+                            it.flush_bytes();
+                            it.push_char(next as char);
+                        }
+                        b'~' => it.push_char('\u{00A0}'),
+                        b'_' => it.push_char('\u{2011}'),
+                        b'\r' | b'\n' => it.end_paragraph(),
+                        _ => {} // `\-` optional hyphen, `\:` index sub-entry, …
+                    }
+                }
+            }
+            byte => {
+                it.text_byte(byte);
+                pos += 1;
+            }
+        }
+    }
+
+    Ok(it.finish())
+}
+
+impl RtfRun {
+    fn to_markdown(&self) -> String {
+        let trimmed = self.text.trim();
+        if trimmed.is_empty() || (!self.bold && !self.italic) {
+            return self.text.clone();
+        }
+        let marker = match (self.bold, self.italic) {
+            (true, true) => "***",
+            (true, false) => "**",
+            _ => "*",
+        };
+        // Keep surrounding spaces outside the emphasis markers.
+        let lead = &self.text[..self.text.len() - self.text.trim_start().len()];
+        let trail = &self.text[self.text.trim_end().len()..];
+        format!("{}{}{}{}{}", lead, marker, trimmed, marker, trail)
+    }
+}
+
+impl RtfDocument {
+    pub fn to_markdown(&self) -> String {
+        let mut blocks = Vec::new();
+        for para in &self.paragraphs {
+            let mut line = String::new();
+            for run in &para.runs {
+                match run.image {
+                    Some(idx) => {
+                        let name = self.images[idx].file_name(idx);
+                        line.push_str(&format!("![{}](assets/{})", name, name));
+                    }
+                    None => line.push_str(&run.to_markdown()),
+                }
+            }
+            let line = line.replace('\t', " ");
+            let line = line.trim();
+            if !line.is_empty() {
+                blocks.push(line.to_string());
+            }
+        }
+        blocks.join("\n\n")
+    }
+
+    pub fn to_mdx(&self, source_name: &str) -> String {
+        format!(
+            "---\nformat: rtf\nsource: \"{}\"\nimages: {}\n---\n\n{}",
+            source_name.replace('"', "\\\""),
+            self.images.len(),
+            self.to_markdown(),
+        )
+    }
+}
+
+pub fn looks_like_rtf(data: &[u8]) -> bool {
+    if data.len() < 5 {
+        return false;
+    }
+    let start = &data[..std::cmp::min(5, data.len())];
+    start == b"{\\rtf"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> RtfDocument {
+        parse_rtf(s.as_bytes()).expect("parse")
+    }
+
+    #[test]
+    fn test_looks_like_rtf_true() {
+        assert!(looks_like_rtf(b"{\\rtf1\\ansi\\deff0 {\\fonttbl"));
+    }
+
+    #[test]
+    fn test_looks_like_rtf_false() {
+        assert!(!looks_like_rtf(b"not rtf"));
+        assert!(!looks_like_rtf(b""));
+    }
+
+    #[test]
+    fn test_parse_simple_rtf() {
+        let rtf = b"{\\rtf1\\ansi Hello world\\par Goodbye.}";
+        let doc = parse_rtf(rtf).expect("parse");
+        assert!(doc.text.contains("Hello world"));
+        assert!(doc.text.contains("Goodbye"));
+    }
+
+    #[test]
+    fn test_rejects_non_rtf() {
+        assert!(parse_rtf(b"plain text").is_err());
+    }
+
+    #[test]
+    fn test_strips_control_words() {
+        let doc = parse(r"{\rtf1\ansi\deff0\fs24\cf1 Body\f0\fs20  text}");
+        assert_eq!(doc.text, "Body text");
+    }
+
+    #[test]
+    fn test_skips_font_and_color_tables() {
+        let doc = parse(r"{\rtf1{\fonttbl{\f0\fswiss Arial;}}{\colortbl;\red255\green0\blue0;}Visible}");
+        assert_eq!(doc.text, "Visible");
+    }
+
+    #[test]
+    fn test_skips_unknown_ignorable_destination() {
+        let doc = parse(r"{\rtf1{\*\generator Msftedit 5.41;}{\*\foo hidden}Shown}");
+        assert_eq!(doc.text, "Shown");
+    }
+
+    #[test]
+    fn test_skips_info_group() {
+        let doc = parse(r"{\rtf1{\info{\title T}{\author A}}Body}");
+        assert_eq!(doc.text, "Body");
+    }
+
+    #[test]
+    fn test_escaped_symbols() {
+        let doc = parse(r"{\rtf1 a\\b \{c\}}");
+        assert_eq!(doc.text, r"a\b {c}");
+    }
+
+    #[test]
+    fn test_paragraph_reconstruction() {
+        let doc = parse(r"{\rtf1 First\par Second\par\par Third}");
+        let texts: Vec<String> = doc.paragraphs.iter().map(|p| p.text()).collect();
+        assert_eq!(texts, vec!["First", "Second", "Third"]);
+        assert_eq!(doc.to_markdown(), "First\n\nSecond\n\nThird");
+    }
+
+    #[test]
+    fn test_bold_italic_runs() {
+        let doc = parse(r"{\rtf1 plain \b bold\b0  and \i italic\i0  end}");
+        assert_eq!(doc.to_markdown(), "plain **bold** and *italic* end");
+    }
+
+    #[test]
+    fn test_group_scoped_formatting() {
+        let doc = parse(r"{\rtf1 a {\b\i both} b}");
+        assert_eq!(doc.to_markdown(), "a ***both*** b");
+        assert!(!doc.paragraphs[0].runs.last().unwrap().bold);
+    }
+
+    #[test]
+    fn test_plain_resets_formatting() {
+        let doc = parse(r"{\rtf1\b x\plain  y}");
+        assert_eq!(doc.to_markdown(), "**x** y");
+    }
+
+    #[test]
+    fn test_hex_escape_cp949() {
+        // "한글" in CP949 = C7 D1 B1 DB
+        let doc = parse(r"{\rtf1\ansi\ansicpg949 \'c7\'d1\'b1\'db}");
+        assert_eq!(doc.text, "한글");
+    }
+
+    #[test]
+    fn test_unicode_escape_skips_fallback() {
+        let doc = parse(r"{\rtf1\uc1\u54620?\u44544?}");
+        assert_eq!(doc.text, "한글");
+    }
+
+    #[test]
+    fn test_negative_unicode_param() {
+        // \u-3913 → U+F0B7 (private use), fallback '?' skipped
+        let doc = parse(r"{\rtf1 x\u-3913?y}");
+        assert_eq!(doc.text, "x\u{F0B7}y");
+    }
+
+    #[test]
+    fn test_pict_png_extracted() {
+        let doc = parse(r"{\rtf1 before{\pict\pngblip\picwgoal1440\pichgoal720 89504e470d0a1a0a}after}");
+        assert_eq!(doc.images.len(), 1);
+        let img = &doc.images[0];
+        assert_eq!(img.format, "png");
+        assert_eq!(img.data, vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a]);
+        assert_eq!(img.width_twips, Some(1440));
+        assert_eq!(img.height_twips, Some(720));
+        assert_eq!(doc.text, "beforeafter");
+        assert_eq!(doc.to_markdown(), "before![rtf_image_001.png](assets/rtf_image_001.png)after");
+    }
+
+    #[test]
+    fn test_pict_format_sniffed_when_unnamed() {
+        let doc = parse(r"{\rtf1{\pict ffd8ffe0}}");
+        assert_eq!(doc.images[0].format, "jpg");
+    }
+
+    #[test]
+    fn test_shppict_kept_nonshppict_skipped() {
+        let doc = parse(
+            r"{\rtf1{\*\shppict{\pict\pngblip 89504e47}}{\nonshppict{\pict\wmetafile8 0100}}}",
+        );
+        assert_eq!(doc.images.len(), 1);
+        assert_eq!(doc.images[0].format, "png");
+    }
+
+    #[test]
+    fn test_pict_binary_data() {
+        let mut rtf = b"{\\rtf1{\\pict\\jpegblip\\bin4 ".to_vec();
+        rtf.extend_from_slice(&[0xFF, 0xD8, b'}', 0x00]);
+        rtf.extend_from_slice(b"}tail}");
+        let doc = parse_rtf(&rtf).unwrap();
+        assert_eq!(doc.images[0].data, vec![0xFF, 0xD8, b'}', 0x00]);
+        assert_eq!(doc.text, "tail");
+    }
+
+    #[test]
+    fn test_to_mdx_frontmatter() {
+        let doc = parse(r"{\rtf1 Hello}");
+        let mdx = doc.to_mdx("a\"b.rtf");
+        assert!(mdx.starts_with("---\nformat: rtf\nsource: \"a\\\"b.rtf\"\nimages: 0\n---\n\nHello"));
+    }
+}
@@ -2,5 +2,6 @@
 
 pub mod bounded_io;
 pub mod date_parser;
+pub mod text_stats;
 
 pub use date_parser::KoreanDateParser;
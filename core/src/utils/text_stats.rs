@@ -0,0 +1,39 @@
+//! Word count and reading time for document summaries
+//!
+//! Words are whitespace-separated tokens, so Korean text counts 어절
+//! (eojeol) rather than characters. Tokens with no letter or digit —
+//! punctuation, Markdown markers such as `#`, `|` or `---` — are skipped.
+
+/// Average adult silent reading speed, in words per minute
+pub const WORDS_PER_MINUTE: f64 = 238.0;
+
+/// Whitespace-separated tokens of `text` containing a letter or digit
+pub fn word_count(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|token| token.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// Minutes needed to read `words` words at [`WORDS_PER_MINUTE`]
+pub fn reading_time_minutes(words: usize) -> f64 {
+    words as f64 / WORDS_PER_MINUTE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_eojeol_and_skips_punctuation() {
+        assert_eq!(word_count("Hello, world !"), 2);
+        assert_eq!(word_count("대한민국의 수도는 서울이다."), 3);
+        assert_eq!(word_count("# 제1조 (목적)\n\n| --- | 가 |"), 3);
+        assert_eq!(word_count("  \n\t"), 0);
+    }
+
+    #[test]
+    fn reading_time_uses_238_wpm() {
+        assert_eq!(reading_time_minutes(0), 0.0);
+        assert_eq!(reading_time_minutes(476), 2.0);
+    }
+}
@@ -25,7 +25,7 @@ use std::time::{Duration, Instant};
 use notify::{Event, EventKind, RecursiveMode, Watcher};
 use ureq::Resolver;
 
-/// Debounce window per path — mirrors kkdoc's `DEBOUNCE_MS`.
+/// Default debounce window per path — mirrors kkdoc's `DEBOUNCE_MS`.
 const DEBOUNCE: Duration = Duration::from_millis(1000);
 /// Interval between size checks used to detect "write finished". Mirrors
 /// kkdoc's `STABLE_CHECK_MS`.
@@ -54,6 +54,8 @@ pub struct WatchOptions {
     pub format: OutputFormat,
     /// Suppress `[mdm watch] ...` progress lines on stderr.
     pub silent: bool,
+    /// Quiet period after the last change to a path before it is converted.
+    pub debounce: Duration,
 }
 
 impl Default for WatchOptions {
@@ -63,6 +65,7 @@ impl Default for WatchOptions {
             webhook: None,
             format: OutputFormat::Markdown,
             silent: false,
+            debounce: DEBOUNCE,
         }
     }
 }
@@ -208,7 +211,7 @@ where
             Ok(Ok(event)) => {
                 if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
                     for path in event.paths {
-                        pending.insert(path, Instant::now() + DEBOUNCE);
+                        pending.insert(path, Instant::now() + opts.debounce);
                     }
                 }
             }
@@ -0,0 +1,105 @@
+//! `hwp2mdm batch` input discovery against a temporary source tree.
+
+use mdm_core::batch::{collect_inputs, glob_base, mirrored_output_dir};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Build `src/{a.hwp, notes.txt, legal/b.hwpx, legal/2024/c.pdf, legal/2024/d.hwp}`.
+/// The files are stubs — discovery never opens them.
+fn source_tree() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let src = dir.path().join("src");
+    fs::create_dir_all(src.join("legal/2024")).unwrap();
+    for file in ["a.hwp", "notes.txt", "legal/b.hwpx", "legal/2024/c.pdf", "legal/2024/d.hwp"] {
+        fs::write(src.join(file), b"stub").unwrap();
+    }
+    // A directory whose name matches the pattern must not be returned
+    fs::create_dir_all(src.join("folder.hwp")).unwrap();
+    dir
+}
+
+fn relative(paths: &[PathBuf], root: &Path) -> Vec<String> {
+    paths
+        .iter()
+        .map(|p| p.strip_prefix(root).unwrap().to_string_lossy().replace('\\', "/"))
+        .collect()
+}
+
+#[test]
+fn recursive_pattern_with_brace_alternatives() {
+    let dir = source_tree();
+    let pattern = format!("{}/src/**/*.{{hwp,hwpx,pdf}}", dir.path().display());
+
+    let inputs = collect_inputs(&pattern).unwrap();
+    assert_eq!(
+        relative(&inputs, dir.path()),
+        vec!["src/a.hwp", "src/legal/2024/c.pdf", "src/legal/2024/d.hwp", "src/legal/b.hwpx"]
+    );
+}
+
+#[test]
+fn single_extension_pattern_skips_other_formats() {
+    let dir = source_tree();
+    let pattern = format!("{}/src/**/*.hwp", dir.path().display());
+
+    let inputs = collect_inputs(&pattern).unwrap();
+    assert_eq!(relative(&inputs, dir.path()), vec!["src/a.hwp", "src/legal/2024/d.hwp"]);
+}
+
+#[test]
+fn output_mirrors_source_tree() {
+    let dir = source_tree();
+    let pattern = format!("{}/src/**/*.{{hwp,hwpx,pdf}}", dir.path().display());
+    let base = glob_base(&pattern);
+    assert_eq!(base, dir.path().join("src"));
+
+    let output = dir.path().join("out");
+    let dirs: Vec<PathBuf> = collect_inputs(&pattern)
+        .unwrap()
+        .iter()
+        .map(|input| mirrored_output_dir(input, &base, &output))
+        .collect();
+    assert_eq!(
+        relative(&dirs, &output),
+        vec!["", "legal/2024", "legal/2024", "legal"]
+    );
+}
+
+#[test]
+fn invalid_pattern_is_an_error() {
+    assert!(collect_inputs("src/***/[.hwp").is_err());
+}
+
+#[test]
+fn dry_run_lists_matches_without_converting() {
+    let dir = source_tree();
+    let pattern = format!("{}/src/**/*.{{hwp,hwpx,pdf}}", dir.path().display());
+    let output = dir.path().join("out");
+
+    let result = Command::new(env!("CARGO_BIN_EXE_hwp2mdm"))
+        .args(["batch", &pattern, "--dry-run", "-o"])
+        .arg(&output)
+        .output()
+        .unwrap();
+    assert!(result.status.success());
+
+    let stdout = String::from_utf8_lossy(&result.stdout);
+    assert!(stdout.contains("[1/4] "), "{}", stdout);
+    assert!(stdout.contains("[4/4] "), "{}", stdout);
+    assert!(stdout.contains("d.hwp"), "{}", stdout);
+    assert!(!stdout.contains("notes.txt"), "{}", stdout);
+    assert!(!output.exists());
+}
+
+#[test]
+fn jobs_requires_parallel() {
+    let dir = source_tree();
+    let pattern = format!("{}/src/**/*.hwp", dir.path().display());
+
+    let result = Command::new(env!("CARGO_BIN_EXE_hwp2mdm"))
+        .args(["batch", &pattern, "--jobs", "2", "--dry-run"])
+        .output()
+        .unwrap();
+    assert!(!result.status.success());
+}
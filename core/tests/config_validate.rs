@@ -0,0 +1,80 @@
+//! `Config::validate` / `Config::validated` field checks.
+
+use mdm_core::{Config, ConfigError};
+
+fn fields(errors: &[ConfigError]) -> Vec<&str> {
+    errors.iter().map(|e| e.field.as_str()).collect()
+}
+
+#[test]
+fn default_config_is_valid() {
+    assert_eq!(Config::default().validate(), Ok(()));
+}
+
+#[test]
+fn quality_bounds_are_inclusive() {
+    for quality in [0, 100] {
+        let config = Config { quality, ..Default::default() };
+        assert!(config.validate().is_ok(), "quality {}", quality);
+    }
+}
+
+#[test]
+fn quality_above_100_is_rejected() {
+    let errors = Config { quality: 150, ..Default::default() }.validate().unwrap_err();
+    assert_eq!(fields(&errors), vec!["quality"]);
+    assert!(errors[0].message.contains("150"), "{}", errors[0].message);
+}
+
+#[test]
+fn every_supported_format_is_accepted() {
+    for format in Config::FORMATS {
+        let config = Config { format: format.to_string(), ..Default::default() };
+        assert!(config.validate().is_ok(), "format {}", format);
+    }
+}
+
+#[test]
+fn unknown_format_is_rejected() {
+    let errors = Config { format: "bmp".to_string(), ..Default::default() }.validate().unwrap_err();
+    assert_eq!(fields(&errors), vec!["format"]);
+    assert!(errors[0].message.contains("\"bmp\""));
+    assert!(errors[0].message.contains("svg|png|webp|jpeg|avif"));
+}
+
+#[test]
+fn format_match_is_exact() {
+    for format in ["PNG", "jpg", " svg", ""] {
+        let config = Config { format: format.to_string(), ..Default::default() };
+        assert!(config.validate().is_err(), "format {:?}", format);
+    }
+}
+
+#[test]
+fn zero_max_output_size_is_rejected() {
+    let errors = Config { max_output_size_bytes: Some(0), ..Default::default() }.validate().unwrap_err();
+    assert_eq!(fields(&errors), vec!["max_output_size_bytes"]);
+    assert!(Config { max_output_size_bytes: Some(1), ..Default::default() }.validate().is_ok());
+}
+
+#[test]
+fn all_errors_are_collected() {
+    let config = Config {
+        format: "bmp".to_string(),
+        quality: 101,
+        max_output_size_bytes: Some(0),
+        ..Default::default()
+    };
+    let errors = config.validate().unwrap_err();
+    assert_eq!(fields(&errors), vec!["quality", "format", "max_output_size_bytes"]);
+}
+
+#[test]
+fn validated_returns_config_or_errors() {
+    let config = Config { format: "webp".to_string(), quality: 70, ..Default::default() }.validated().unwrap();
+    assert_eq!(config.format, "webp");
+    assert_eq!(config.quality, 70);
+
+    let errors = Config { quality: 200, ..Default::default() }.validated().unwrap_err();
+    assert_eq!(errors[0].to_string(), "quality: must be 0-100, got 200");
+}
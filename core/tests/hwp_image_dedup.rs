@@ -0,0 +1,163 @@
+//! `HwpParser` BinData extraction (image deduplication, non-image streams)
+//! against synthetic BinData streams.
+
+use mdm_core::hwp::{BinDataType, HwpParser};
+use std::io::{Cursor, Write};
+
+/// Raw-deflate `data` the way HWP stores compressed BinData.
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder = flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(data).unwrap();
+    encoder.finish().unwrap()
+}
+
+/// HWP 5.0 compound file with empty DocInfo/section and the given BinData streams.
+fn build_hwp_with_bin_data(bin_data: &[(&str, Vec<u8>)]) -> Vec<u8> {
+    let mut cf = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+
+    let mut header = vec![0u8; 256];
+    header[..17].copy_from_slice(b"HWP Document File");
+    header[32..36].copy_from_slice(&[0, 3, 0, 5]); // 5.0.3.0
+    cf.create_stream("/FileHeader").unwrap().write_all(&header).unwrap();
+    cf.create_stream("/DocInfo").unwrap();
+    cf.create_storage("/BodyText").unwrap();
+    cf.create_stream("/BodyText/Section0").unwrap();
+
+    cf.create_storage("/BinData").unwrap();
+    for (name, data) in bin_data {
+        let path = format!("/BinData/{}", name);
+        cf.create_stream(&path).unwrap().write_all(&deflate(data)).unwrap();
+    }
+
+    cf.flush().unwrap();
+    cf.into_inner().into_inner()
+}
+
+/// GIF magic plus a payload; undecodable, so only byte-identical copies match.
+fn gif_like(payload: &[u8]) -> Vec<u8> {
+    let mut data = b"GIF89a".to_vec();
+    data.extend_from_slice(payload);
+    data
+}
+
+#[cfg(feature = "image-processing")]
+fn encode(img: &image::RgbImage, format: image::ImageFormat) -> Vec<u8> {
+    let mut out = Cursor::new(Vec::new());
+    img.write_to(&mut out, format).unwrap();
+    out.into_inner()
+}
+
+/// Darkens left to right, so every dHash bit is set
+#[cfg(feature = "image-processing")]
+fn gradient() -> image::RgbImage {
+    image::RgbImage::from_fn(16, 16, |x, y| image::Rgb([(255 - x * 15) as u8, (y * 15) as u8, 0]))
+}
+
+#[cfg(feature = "image-processing")]
+fn checker() -> image::RgbImage {
+    image::RgbImage::from_fn(16, 16, |x, y| {
+        let v = if (x / 4 + y / 4) % 2 == 0 { 255 } else { 0 };
+        image::Rgb([v, v, v])
+    })
+}
+
+#[cfg(feature = "image-processing")]
+#[test]
+fn identical_pngs_under_different_names_are_kept_once() {
+    let logo = encode(&gradient(), image::ImageFormat::Png);
+    let bytes = build_hwp_with_bin_data(&[
+        ("BIN0001.png", logo.clone()),
+        ("BIN0002.png", logo),
+        ("BIN0003.png", encode(&checker(), image::ImageFormat::Png)),
+    ]);
+
+    let mut parser = HwpParser::from_bytes(bytes.clone()).unwrap();
+    let unique = parser.extract_images_deduplicated().unwrap();
+    assert_eq!(unique.len(), 2);
+    assert!(unique.iter().all(|img| img.is_duplicate_of.is_none()));
+
+    // Plain extraction is unchanged
+    let mut parser = HwpParser::from_bytes(bytes).unwrap();
+    let all = parser.extract_images().unwrap();
+    assert_eq!(all.len(), 3);
+    assert!(all.iter().all(|img| img.is_duplicate_of.is_none()));
+}
+
+#[cfg(feature = "image-processing")]
+#[test]
+fn reencoded_lookalike_points_at_the_first_copy() {
+    let original = gradient();
+    // One pixel two levels darker: different bytes, same picture
+    let mut touched = original.clone();
+    touched.get_pixel_mut(0, 0).0[0] -= 2;
+    let (first, second) = (encode(&original, image::ImageFormat::Png), encode(&touched, image::ImageFormat::Png));
+    assert_ne!(first, second);
+
+    let bytes = build_hwp_with_bin_data(&[("BIN0001.png", first), ("BIN0002.png", second)]);
+    let mut parser = HwpParser::from_bytes(bytes).unwrap();
+    let images = parser.extract_images_with(true).unwrap();
+    assert_eq!(images.len(), 2);
+    assert!(images[0].is_duplicate_of.is_none());
+    assert_eq!(images[1].is_duplicate_of.as_deref(), Some(images[0].name.as_str()));
+    assert_ne!(images[0].name, images[1].name);
+}
+
+#[test]
+fn undecodable_images_match_only_identical_bytes() {
+    let logo = gif_like(b"company logo");
+    let bytes = build_hwp_with_bin_data(&[
+        ("BIN0001.gif", logo.clone()),
+        ("BIN0002.gif", logo),
+        ("BIN0003.gif", gif_like(b"company logo.")),
+    ]);
+
+    let mut parser = HwpParser::from_bytes(bytes).unwrap();
+    let images = parser.extract_images_with(true).unwrap();
+    assert_eq!(images[1].is_duplicate_of.as_deref(), Some(images[0].name.as_str()));
+    assert!(images[2].is_duplicate_of.is_none());
+}
+
+#[test]
+fn non_image_streams_only_in_all_bin_data() {
+    let ole = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1, 0, 0, 0, 0].to_vec();
+    let wav = b"RIFF\x24\x00\x00\x00WAVEfmt ".to_vec();
+    let bytes = build_hwp_with_bin_data(&[
+        ("BIN0001.gif", gif_like(b"chart preview")),
+        ("BIN0002.OLE", ole),
+        ("BIN0003.wav", wav),
+    ]);
+
+    let mut parser = HwpParser::from_bytes(bytes.clone()).unwrap();
+    let images = parser.extract_images().unwrap();
+    assert_eq!(images.len(), 1);
+    assert_eq!(images[0].bin_type, BinDataType::Image("gif".to_string()));
+
+    let mut parser = HwpParser::from_bytes(bytes).unwrap();
+    let mut all = parser.extract_all_bin_data().unwrap();
+    all.sort_by(|a, b| a.original_name.cmp(&b.original_name));
+    let kinds: Vec<_> = all.iter().map(|b| (b.name.as_str(), b.bin_type.clone())).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            ("BIN0001.gif", BinDataType::Image("gif".to_string())),
+            ("BIN0002.OLE", BinDataType::Ole),
+            ("BIN0003.wav", BinDataType::Sound),
+        ]
+    );
+}
+
+#[cfg(feature = "image-processing")]
+#[test]
+fn same_pixels_in_different_formats_are_duplicates() {
+    use image::ImageFormat;
+
+    let bytes = build_hwp_with_bin_data(&[
+        ("BIN0001.png", encode(&gradient(), ImageFormat::Png)),
+        ("BIN0002.bmp", encode(&gradient(), ImageFormat::Bmp)),
+        ("BIN0003.png", encode(&checker(), ImageFormat::Png)),
+    ]);
+
+    let mut parser = HwpParser::from_bytes(bytes).unwrap();
+    let unique = parser.extract_images_deduplicated().unwrap();
+    assert_eq!(unique.len(), 2, "{:?}", unique.iter().map(|i| &i.name).collect::<Vec<_>>());
+}
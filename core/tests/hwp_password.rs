@@ -0,0 +1,83 @@
+//! Password-protected HWP files are rejected with `ErrorKind::Unsupported`.
+
+use mdm_core::hwp::HwpParser;
+use std::io::{Cursor, ErrorKind, Write};
+
+const HWPTAG_PARA_HEADER: u32 = 0x42;
+const HWPTAG_PARA_TEXT: u32 = 0x43;
+
+/// FileHeader `encrypted` bit
+const FLAG_ENCRYPTED: u32 = 0x02;
+
+/// Encode one HWP record: `tag | level << 10 | size << 20` + payload.
+fn record(tag: u32, level: u32, data: &[u8]) -> Vec<u8> {
+    let header = tag | (level << 10) | ((data.len() as u32) << 20);
+    let mut out = header.to_le_bytes().to_vec();
+    out.extend_from_slice(data);
+    out
+}
+
+/// One plain paragraph: PARA_HEADER + PARA_TEXT (UTF-16LE, CR-terminated).
+fn paragraph(text: &str) -> Vec<u8> {
+    let mut utf16: Vec<u8> = text.encode_utf16().flat_map(|u| u.to_le_bytes()).collect();
+    utf16.extend_from_slice(&0x000Du16.to_le_bytes());
+    let mut out = record(HWPTAG_PARA_HEADER, 0, &[0u8; 22]);
+    out.extend(record(HWPTAG_PARA_TEXT, 1, &utf16));
+    out
+}
+
+/// Uncompressed HWP 5.0 compound file with one section, with the FileHeader
+/// `encrypted` bit set when `encrypted` is true.
+fn build_hwp(text: &str, encrypted: bool) -> Vec<u8> {
+    let mut cf = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+
+    let mut header = vec![0u8; 256];
+    header[..17].copy_from_slice(b"HWP Document File");
+    header[32..36].copy_from_slice(&[0, 3, 0, 5]); // 5.0.3.0
+    if encrypted {
+        header[36..40].copy_from_slice(&FLAG_ENCRYPTED.to_le_bytes());
+    }
+    cf.create_stream("/FileHeader").unwrap().write_all(&header).unwrap();
+    cf.create_stream("/DocInfo").unwrap();
+    cf.create_storage("/BodyText").unwrap();
+    cf.create_stream("/BodyText/Section0").unwrap().write_all(&paragraph(text)).unwrap();
+
+    cf.flush().unwrap();
+    cf.into_inner().into_inner()
+}
+
+fn write_temp(bytes: &[u8]) -> tempfile::NamedTempFile {
+    let mut file = tempfile::Builder::new().suffix(".hwp").tempfile().unwrap();
+    file.write_all(bytes).unwrap();
+    file
+}
+
+#[test]
+fn open_with_password_is_unsupported_for_encrypted_files() {
+    let file = write_temp(&build_hwp("본문", true));
+    let err = HwpParser::open_with_password(file.path(), "비밀번호1").err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+}
+
+#[test]
+fn encrypted_file_refuses_to_extract() {
+    let file = write_temp(&build_hwp("본문", true));
+    let mut parser = HwpParser::open(file.path()).unwrap();
+    assert!(parser.analyze().encrypted);
+    assert_eq!(parser.extract_text().unwrap_err().kind(), ErrorKind::Unsupported);
+}
+
+#[test]
+fn password_is_ignored_for_unencrypted_files() {
+    let file = write_temp(&build_hwp("일반 문서", false));
+    let mut parser = HwpParser::open_with_password(file.path(), "anything").unwrap();
+    assert!(!parser.analyze().encrypted);
+    assert!(parser.extract_text().unwrap().contains("일반 문서"));
+}
+
+#[test]
+fn real_hancom_password_file_is_unsupported() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../samples/input/password-12345.hwp");
+    let err = HwpParser::open_with_password(&path, "12345").err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+}
@@ -0,0 +1,503 @@
+//! `HwpParser::stream_sections` against a synthetic multi-section OLE file.
+
+use mdm_core::hwp::{CachedHwpParser, HwpParser, PageOrientation, ParagraphAlignment, SectionCache, StylesSummary};
+use mdm_core::cache::Cache;
+use mdm_core::ir::IRBlock;
+use std::io::{Cursor, Write};
+use std::sync::Arc;
+use std::time::Duration;
+
+const HWPTAG_PARA_HEADER: u32 = 0x42;
+const HWPTAG_PARA_TEXT: u32 = 0x43;
+const HWPTAG_PARA_LINE_SEG: u32 = 0x45;
+const HWPTAG_PAGE_DEF: u32 = 0x49;
+const HWPTAG_FACE_NAME: u32 = 0x13;
+const HWPTAG_CHAR_SHAPE: u32 = 0x15;
+const HWPTAG_PARA_SHAPE: u32 = 0x19;
+const HWPTAG_STYLE: u32 = 0x1A;
+
+/// Encode one HWP record: `tag | level << 10 | size << 20` + payload.
+fn record(tag: u32, level: u32, data: &[u8]) -> Vec<u8> {
+    let header = tag | (level << 10) | ((data.len() as u32) << 20);
+    let mut out = header.to_le_bytes().to_vec();
+    out.extend_from_slice(data);
+    out
+}
+
+/// One plain paragraph: PARA_HEADER + PARA_TEXT (UTF-16LE, CR-terminated).
+fn paragraph(text: &str) -> Vec<u8> {
+    styled_paragraph(text, 0)
+}
+
+/// Paragraph whose PARA_HEADER references `style_id` (byte offset 10).
+fn styled_paragraph(text: &str, style_id: u8) -> Vec<u8> {
+    let mut utf16: Vec<u8> = text.encode_utf16().flat_map(|u| u.to_le_bytes()).collect();
+    utf16.extend_from_slice(&0x000Du16.to_le_bytes());
+    let mut header = [0u8; 22];
+    header[10] = style_id;
+    let mut out = record(HWPTAG_PARA_HEADER, 0, &header);
+    out.extend(record(HWPTAG_PARA_TEXT, 1, &utf16));
+    out
+}
+
+/// DocInfo HWPTAG_STYLE payload referencing `para_shape_id`.
+fn style(name: &str, english: &str, para_shape_id: u16) -> Vec<u8> {
+    let mut data = Vec::new();
+    for s in [name, english] {
+        let units: Vec<u16> = s.encode_utf16().collect();
+        data.extend_from_slice(&(units.len() as u16).to_le_bytes());
+        data.extend(units.iter().flat_map(|u| u.to_le_bytes()));
+    }
+    data.extend_from_slice(&[0, 0]); // paragraph style, next style 0
+    data.extend_from_slice(&0x0412i16.to_le_bytes());
+    data.extend_from_slice(&para_shape_id.to_le_bytes());
+    data.extend_from_slice(&0u16.to_le_bytes());
+    data
+}
+
+/// Uncompressed HWP 5.0 compound file with one paragraph per section.
+fn build_hwp(sections: &[&str]) -> Vec<u8> {
+    let bodies: Vec<Vec<u8>> = sections.iter().map(|text| paragraph(text)).collect();
+    build_hwp_raw(&[], &bodies)
+}
+
+/// Uncompressed HWP 5.0 compound file from raw DocInfo / section record bytes.
+fn build_hwp_raw(doc_info: &[u8], sections: &[Vec<u8>]) -> Vec<u8> {
+    let mut cf = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+
+    let mut header = vec![0u8; 256];
+    header[..17].copy_from_slice(b"HWP Document File");
+    header[32..36].copy_from_slice(&[0, 3, 0, 5]); // 5.0.3.0
+    // flags (offset 36) = 0 → streams stored uncompressed
+    cf.create_stream("/FileHeader").unwrap().write_all(&header).unwrap();
+    cf.create_stream("/DocInfo").unwrap().write_all(doc_info).unwrap();
+
+    cf.create_storage("/BodyText").unwrap();
+    for (idx, body) in sections.iter().enumerate() {
+        let path = format!("/BodyText/Section{}", idx);
+        cf.create_stream(&path).unwrap().write_all(body).unwrap();
+    }
+
+    cf.flush().unwrap();
+    cf.into_inner().into_inner()
+}
+
+fn block_text(block: &IRBlock) -> Option<&str> {
+    match block {
+        IRBlock::Paragraph { text, .. } => Some(text.trim()),
+        IRBlock::Heading { text, .. } => Some(text.trim()),
+        _ => None,
+    }
+}
+
+#[test]
+fn stream_yields_each_section_in_order() {
+    let bytes = build_hwp(&["첫째 섹션", "둘째 섹션", "셋째 섹션"]);
+    let mut parser = HwpParser::from_bytes(bytes).unwrap();
+
+    let sections: Vec<_> = parser
+        .stream_sections()
+        .collect::<std::io::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(sections.len(), 3);
+    for (expected_idx, (section, text)) in sections
+        .iter()
+        .zip(["첫째 섹션", "둘째 섹션", "셋째 섹션"])
+        .enumerate()
+    {
+        assert_eq!(section.index, expected_idx);
+        assert!(section.tables.is_empty());
+        let texts: Vec<&str> = section.paragraphs.iter().filter_map(block_text).collect();
+        assert_eq!(texts, vec![text]);
+    }
+}
+
+#[test]
+fn find_text_reports_section_paragraph_and_offset() {
+    let bodies = vec![
+        [paragraph("첫 문단"), paragraph("검색어가 있는 둘째 문단")].concat(),
+        paragraph("다른 섹션의 검색어 검색어"),
+    ];
+    let mut parser = HwpParser::from_bytes(build_hwp_raw(&[], &bodies)).unwrap();
+
+    let matches = parser.find_text("검색어", None).unwrap();
+    let positions: Vec<_> = matches
+        .iter()
+        .map(|m| (m.section, m.paragraph_index, m.byte_offset))
+        .collect();
+    let second = "다른 섹션의 ".len();
+    assert_eq!(positions, vec![(0, 1, 0), (1, 0, second), (1, 0, second + "검색어 ".len())]);
+    assert_eq!(matches[0].context, "검색어가 있는 둘째 문단");
+
+    let limited = parser.find_text("검색어", Some(2)).unwrap();
+    assert_eq!(limited, matches[..2]);
+    assert!(parser.find_text("없는 말", None).unwrap().is_empty());
+}
+
+#[test]
+fn find_text_context_is_bounded() {
+    let text = format!("{}목표{}", "가".repeat(40), "나".repeat(40));
+    let mut parser = HwpParser::from_bytes(build_hwp(&[&text])).unwrap();
+
+    let matches = parser.find_text("목표", None).unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].byte_offset, "가".len() * 40);
+    assert_eq!(matches[0].context, format!("{}목표{}", "가".repeat(25), "나".repeat(25)));
+}
+
+#[test]
+fn stream_is_lazy() {
+    let bytes = build_hwp(&["하나", "둘"]);
+    let mut parser = HwpParser::from_bytes(bytes).unwrap();
+
+    let first = parser.stream_sections().next().unwrap().unwrap();
+    assert_eq!(first.index, 0);
+    assert_eq!(first.paragraphs.iter().filter_map(block_text).collect::<Vec<_>>(), vec!["하나"]);
+}
+
+#[test]
+fn to_mdm_is_built_from_stream() {
+    let bytes = build_hwp(&["앞 섹션 본문", "뒤 섹션 본문"]);
+    let mut parser = HwpParser::from_bytes(bytes).unwrap();
+
+    let doc = parser.to_mdm().unwrap();
+    let front = doc.content.find("앞 섹션 본문").expect("section 0 text");
+    let back = doc.content.find("뒤 섹션 본문").expect("section 1 text");
+    assert!(front < back);
+    assert_eq!(doc.metadata.section_count, 2);
+}
+
+#[test]
+fn to_mdm_reuses_cached_document() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = Arc::new(Cache::new(dir.path(), Duration::from_secs(3600)).unwrap());
+    let bytes = build_hwp(&["캐시 대상 본문"]);
+
+    let first = HwpParser::from_bytes(bytes.clone())
+        .unwrap()
+        .with_cache(Arc::clone(&cache))
+        .to_mdm()
+        .unwrap();
+    assert_eq!(cache.len(), 1);
+
+    let second = HwpParser::from_bytes(bytes)
+        .unwrap()
+        .with_cache(Arc::clone(&cache))
+        .to_mdm()
+        .unwrap();
+    assert_eq!(first.hash(), second.hash());
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+}
+
+/// `build_hwp` output plus a `BinData/BIN0001.png` stream holding `image`
+fn with_bin_data(bytes: Vec<u8>, image: &[u8]) -> Vec<u8> {
+    let mut cf = cfb::CompoundFile::open(Cursor::new(bytes)).unwrap();
+    cf.create_storage("/BinData").unwrap();
+    cf.create_stream("/BinData/BIN0001.png").unwrap().write_all(image).unwrap();
+    cf.flush().unwrap();
+    cf.into_inner().into_inner()
+}
+
+#[test]
+fn source_hash_covers_body_text_and_bin_data_sizes() {
+    let hash = |bytes: Vec<u8>| HwpParser::from_bytes(bytes).unwrap().source_hash().unwrap();
+
+    let base = hash(build_hwp(&["본문"]));
+    assert_eq!(base, hash(build_hwp(&["본문"])));
+    assert_ne!(base, hash(build_hwp(&["다른 본문"])));
+
+    let with_image = hash(with_bin_data(build_hwp(&["본문"]), &[1; 64]));
+    assert_ne!(base, with_image);
+    assert_ne!(with_image, hash(with_bin_data(build_hwp(&["본문"]), &[1; 65])));
+    // BinData bytes are not read, only their size
+    assert_eq!(with_image, hash(with_bin_data(build_hwp(&["본문"]), &[2; 64])));
+}
+
+#[test]
+fn parse_warnings_are_collected_once_per_stream() {
+    let mut truncated = paragraph("잘린 구역");
+    truncated.extend(record(HWPTAG_PARA_TEXT, 1, &[0x41, 0x00, 0x42, 0x00]));
+    truncated.truncate(truncated.len() - 2);
+    let mut parser = HwpParser::from_bytes(build_hwp_raw(&[], &[paragraph("온전한 구역"), truncated])).unwrap();
+
+    // Conversions re-read every section without piling up warnings
+    parser.to_mdm().unwrap();
+    parser.to_mdm().unwrap();
+    let warnings = parser.parse_warnings().to_vec();
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert_eq!(warnings[0].stream, "BodyText/Section1");
+    assert_eq!(warnings[0].warning.declared_size, 4);
+    assert_eq!(warnings[0].warning.available, 2);
+    assert_eq!(parser.parse_warnings(), &warnings[..]);
+}
+
+#[test]
+fn strict_records_config_stops_at_truncated_record() {
+    let mut section = paragraph("앞문");
+    // PARA_TEXT declaring 1000 bytes, none of which follow
+    section.extend((HWPTAG_PARA_TEXT | (1 << 10) | (1000 << 20)).to_le_bytes());
+    section.extend(paragraph("뒷문"));
+    let convert = |config: &mdm_core::Config| {
+        HwpParser::from_bytes(build_hwp_raw(&[], &[section.clone()]))
+            .unwrap()
+            .with_config(config)
+            .to_mdm()
+            .unwrap()
+            .content
+    };
+
+    assert!(convert(&mdm_core::Config::default()).contains("뒷문"));
+    let strict = convert(&mdm_core::Config { strict_records: true, ..Default::default() });
+    assert!(strict.contains("앞문"), "{}", strict);
+    assert!(!strict.contains("뒷문"), "{}", strict);
+}
+
+#[test]
+fn cache_disabled_by_config_skips_cache() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = Arc::new(Cache::new(dir.path(), Duration::from_secs(3600)).unwrap());
+    let config = mdm_core::Config { cache_enabled: false, ..Default::default() };
+
+    let doc = HwpParser::from_bytes(build_hwp(&["본문"]))
+        .unwrap()
+        .with_config(&config)
+        .with_cache(Arc::clone(&cache))
+        .to_mdm()
+        .unwrap();
+    assert!(doc.content.contains("본문"));
+    assert!(cache.is_empty());
+    assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+}
+
+#[test]
+fn invalid_config_fails_to_mdm() {
+    let config = mdm_core::Config { quality: 150, format: "bmp".to_string(), ..Default::default() };
+
+    let err = HwpParser::from_bytes(build_hwp(&["본문"]))
+        .unwrap()
+        .with_config(&config)
+        .to_mdm()
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(err.to_string().contains("quality"), "{}", err);
+    assert!(err.to_string().contains("format"), "{}", err);
+}
+
+#[test]
+fn content_fingerprint_matches_identical_first_sections() {
+    let body = paragraph("동일한 본문");
+    // Different DocInfo and later sections: still the same first section
+    let mut a = HwpParser::from_bytes(build_hwp_raw(&[], &[body.clone()])).unwrap();
+    let mut b = HwpParser::from_bytes(build_hwp_raw(
+        &record(HWPTAG_PARA_SHAPE, 0, &[0u8; 54]),
+        &[body, paragraph("추가 섹션")],
+    ))
+    .unwrap();
+    let mut c = HwpParser::from_bytes(build_hwp(&["다른 본문"])).unwrap();
+
+    let fingerprint = a.content_fingerprint().unwrap();
+    assert_eq!(fingerprint.len(), 16);
+    assert!(fingerprint.chars().all(|ch| ch.is_ascii_hexdigit()));
+    assert_eq!(b.content_fingerprint().unwrap(), fingerprint);
+    assert_ne!(c.content_fingerprint().unwrap(), fingerprint);
+}
+
+/// PARA_LINE_SEG payload with one 36-byte segment per vertical position.
+fn line_segs(positions: &[i32]) -> Vec<u8> {
+    positions
+        .iter()
+        .flat_map(|pos| {
+            let mut seg = [0u8; 36];
+            seg[4..8].copy_from_slice(&pos.to_le_bytes());
+            seg
+        })
+        .collect()
+}
+
+#[test]
+fn page_count_follows_line_segment_page_breaks() {
+    // Vertical position drops back to 0 twice → three pages
+    let mut body = Vec::new();
+    for positions in [&[0, 1600][..], &[3200, 0], &[1600], &[0, 1600]] {
+        body.extend(paragraph("본문"));
+        body.extend(record(HWPTAG_PARA_LINE_SEG, 1, &line_segs(positions)));
+    }
+    let mut parser = HwpParser::from_bytes(build_hwp_raw(&[], &[body])).unwrap();
+    assert_eq!(parser.page_count().unwrap(), 3);
+}
+
+#[test]
+fn page_count_without_line_segments_uses_page_def() {
+    // Body height 3200 HWPUNIT → two default lines per page
+    let page_def: Vec<u8> = [10000u32, 3200, 0, 0, 0, 0, 0, 0, 0, 0]
+        .iter()
+        .flat_map(|f| f.to_le_bytes())
+        .collect();
+    let mut body = record(HWPTAG_PAGE_DEF, 1, &page_def);
+    for text in ["하나", "둘", "셋", "넷", "다섯"] {
+        body.extend(paragraph(text));
+    }
+    let mut parser = HwpParser::from_bytes(build_hwp_raw(&[], &[body])).unwrap();
+    assert_eq!(parser.page_count().unwrap(), 3);
+
+    let mut plain = HwpParser::from_bytes(build_hwp(&["짧은 문서"])).unwrap();
+    assert_eq!(plain.page_count().unwrap(), 1);
+    let mut structure = plain.analyze();
+    assert_eq!(structure.page_count, None);
+    structure.page_count = plain.page_count().ok();
+    assert_eq!(structure.page_count, Some(1));
+}
+
+#[test]
+fn page_definitions_collect_each_section_page_def() {
+    let page_def = |fields: [u32; 10]| -> Vec<u8> {
+        let data: Vec<u8> = fields.iter().flat_map(|f| f.to_le_bytes()).collect();
+        record(HWPTAG_PAGE_DEF, 1, &data)
+    };
+    // Section 0: A4 portrait, section 1: US Letter landscape, section 2: none
+    let mut a4 = page_def([59528, 84188, 8504, 8504, 5668, 4252, 4252, 4252, 0, 0]);
+    a4.extend(paragraph("A4"));
+    let mut letter = page_def([61200, 79200, 7200, 7200, 7200, 7200, 3600, 3600, 0, 1]);
+    letter.extend(paragraph("Letter"));
+    let bytes = build_hwp_raw(&[], &[a4, letter, paragraph("용지 설정 없음")]);
+
+    let mut parser = HwpParser::from_bytes(bytes).unwrap();
+    let pages = parser.page_definitions().to_vec();
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0].orientation(), PageOrientation::Portrait);
+    assert_eq!(pages[0].paper_width_mm().round(), 210.0);
+    assert_eq!(pages[1].orientation(), PageOrientation::Landscape);
+    assert_eq!(pages[1].paper_width_mm().round(), 279.0);
+
+    let mut structure = parser.analyze();
+    assert!(structure.page_definitions.is_empty());
+    structure.page_definitions = pages;
+    assert_eq!(structure.page_definitions[1].margin_header, 3600);
+}
+
+#[test]
+fn cached_parser_serves_sections_from_cache() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache: Arc<SectionCache> = Arc::new(Cache::new(dir.path().join("cache"), Duration::from_secs(3600)).unwrap());
+    let path = dir.path().join("doc.hwp");
+    std::fs::write(&path, build_hwp(&["첫 섹션", "둘째 섹션"])).unwrap();
+
+    let mut parser = CachedHwpParser::open(&path, Arc::clone(&cache)).unwrap();
+    let uncached = HwpParser::open(&path).unwrap().extract_text().unwrap();
+    assert_eq!(parser.extract_text().unwrap(), uncached);
+    assert_eq!(cache.len(), 2);
+
+    // A second parser reads section 0 from the cache, not the file
+    let key = parser.section_key(0).unwrap();
+    cache.insert(&key, &paragraph("캐시된 섹션")).unwrap();
+    let mut again = CachedHwpParser::open(&path, Arc::clone(&cache)).unwrap();
+    let text = again.extract_text().unwrap();
+    assert!(text.contains("캐시된 섹션") && !text.contains("첫 섹션"), "{}", text);
+    assert!(text.contains("둘째 섹션"));
+}
+
+#[test]
+fn cached_parser_misses_after_mtime_change() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache: Arc<SectionCache> = Arc::new(Cache::new(dir.path().join("cache"), Duration::from_secs(3600)).unwrap());
+    let path = dir.path().join("doc.hwp");
+    std::fs::write(&path, build_hwp(&["원본"])).unwrap();
+
+    let mut parser = CachedHwpParser::open(&path, Arc::clone(&cache)).unwrap();
+    parser.extract_text().unwrap();
+    let old_key = parser.section_key(0).unwrap();
+
+    std::fs::write(&path, build_hwp(&["수정본"])).unwrap();
+    let later = old_key.2 + Duration::from_secs(10);
+    std::fs::File::options().write(true).open(&path).unwrap().set_modified(later).unwrap();
+
+    let mut parser = CachedHwpParser::open(&path, Arc::clone(&cache)).unwrap();
+    assert_ne!(parser.section_key(0).unwrap(), old_key);
+    assert!(parser.extract_text().unwrap().contains("수정본"));
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn paragraph_styles_turn_outline_paragraphs_into_headings() {
+    // ParaShape 0: centered (bits 2-4 = 3); ParaShape 1: default
+    let mut centered = vec![0u8; 54];
+    centered[..4].copy_from_slice(&(3u32 << 2).to_le_bytes());
+    let mut doc_info = record(HWPTAG_PARA_SHAPE, 0, &centered);
+    doc_info.extend(record(HWPTAG_PARA_SHAPE, 0, &[0u8; 54]));
+    doc_info.extend(record(HWPTAG_STYLE, 0, &style("바탕글", "Normal", 1)));
+    doc_info.extend(record(HWPTAG_STYLE, 0, &style("개요 1", "Outline 1", 0)));
+    doc_info.extend(record(HWPTAG_STYLE, 0, &style("개요 2", "Outline 2", 1)));
+
+    let mut body = styled_paragraph("총칙", 1);
+    body.extend(styled_paragraph("목적", 2));
+    body.extend(styled_paragraph("이 규정은 업무 처리 기준을 정한다.", 0));
+
+    let mut parser = HwpParser::from_bytes(build_hwp_raw(&doc_info, &[body])).unwrap();
+
+    let styles = parser.paragraph_styles();
+    assert_eq!(styles.len(), 3);
+    assert_eq!(styles[&0].heading_level, None);
+    assert_eq!(styles[&1].heading_level, Some(1));
+    assert_eq!(styles[&1].alignment, ParagraphAlignment::Center);
+    assert_eq!(styles[&2].heading_level, Some(2));
+
+    let text = parser.extract_text().unwrap();
+    let lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+    assert_eq!(lines, vec!["# 총칙", "## 목적", "이 규정은 업무 처리 기준을 정한다."]);
+}
+
+/// DocInfo HWPTAG_FACE_NAME payload: properties, then the length-prefixed name.
+fn face_name(name: &str) -> Vec<u8> {
+    let units: Vec<u16> = name.encode_utf16().collect();
+    let mut data = vec![0u8];
+    data.extend_from_slice(&(units.len() as u16).to_le_bytes());
+    data.extend(units.iter().flat_map(|u| u.to_le_bytes()));
+    data
+}
+
+/// DocInfo HWPTAG_CHAR_SHAPE payload with the given attribute bits at offset 46.
+fn char_shape(attr: u32) -> Vec<u8> {
+    let mut data = vec![0u8; 56];
+    data[42..46].copy_from_slice(&1000i32.to_le_bytes());
+    data[46..50].copy_from_slice(&attr.to_le_bytes());
+    data
+}
+
+#[test]
+fn styles_summary_counts_fonts_char_shapes_and_styles() {
+    // The same faces repeat in each language group (한글, 영문, …)
+    let mut doc_info = Vec::new();
+    for _ in 0..2 {
+        doc_info.extend(record(HWPTAG_FACE_NAME, 0, &face_name("함초롬바탕")));
+        doc_info.extend(record(HWPTAG_FACE_NAME, 0, &face_name("함초롬돋움")));
+    }
+    doc_info.extend(record(HWPTAG_FACE_NAME, 0, &face_name("Arial")));
+    // plain, bold, italic, bold + italic, bold again, underline
+    for attr in [0, 0b01, 0b10, 0b11, 0b01, 0b100] {
+        doc_info.extend(record(HWPTAG_CHAR_SHAPE, 0, &char_shape(attr)));
+    }
+    doc_info.extend(record(HWPTAG_STYLE, 0, &style("바탕글", "Normal", 0)));
+    doc_info.extend(record(HWPTAG_STYLE, 0, &style("본문", "Body", 0)));
+    doc_info.extend(record(HWPTAG_STYLE, 0, &style("본문", "Body", 0)));
+
+    let mut parser = HwpParser::from_bytes(build_hwp_raw(&doc_info, &[paragraph("본문")])).unwrap();
+    assert_eq!(
+        parser.extract_styles_summary().unwrap(),
+        StylesSummary {
+            font_count: 3,
+            font_names: vec!["함초롬바탕".into(), "함초롬돋움".into(), "Arial".into()],
+            char_shape_count: 6,
+            bold_count: 3,
+            italic_count: 2,
+            underline_count: 1,
+            emphasis_combinations: 5,
+            paragraph_style_count: 2,
+        }
+    );
+}
+
+#[test]
+fn styles_summary_of_empty_doc_info() {
+    let mut parser = HwpParser::from_bytes(build_hwp_raw(&[], &[paragraph("x")])).unwrap();
+    assert_eq!(parser.extract_styles_summary().unwrap(), StylesSummary::default());
+}
@@ -187,20 +187,27 @@ mod docx_tests {
 
     #[test]
     fn test_docx_table_markdown() {
-        use mdm_core::docx::parser::{DocxTable, TableCell};
+        use mdm_core::docx::parser::{DocxTable, TableCell, TableRow};
 
         let table = DocxTable {
             rows: vec![
-                vec![
-                    TableCell { content: "A".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
-                    TableCell { content: "B".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
-                ],
-                vec![
-                    TableCell { content: "1".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
-                    TableCell { content: "2".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
-                ],
+                TableRow {
+                    height_twips: None,
+                    cells: vec![
+                        TableCell { content: "A".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
+                        TableCell { content: "B".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
+                    ],
+                },
+                TableRow {
+                    height_twips: None,
+                    cells: vec![
+                        TableCell { content: "1".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
+                        TableCell { content: "2".to_string(), col_span: 1, row_span: 1, v_merge_continue: false },
+                    ],
+                },
             ],
             has_header: true,
+            column_widths_twips: Vec::new(),
         };
 
         let md = table.to_markdown();
@@ -355,6 +362,63 @@ mod docx_tests {
         assert!(doc.paragraphs[0].to_markdown().ends_with("Huge"));
         assert_eq!(doc.paragraphs[1].indent_level, 0);
     }
+
+    #[test]
+    fn test_docx_to_html_structure() {
+        use mdm_core::docx::parser::DocxParser;
+
+        let numbering_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:numbering xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:abstractNum w:abstractNumId="0"><w:lvl w:ilvl="0"><w:numFmt w:val="bullet"/></w:lvl></w:abstractNum>
+  <w:abstractNum w:abstractNumId="1"><w:lvl w:ilvl="0"><w:numFmt w:val="decimal"/></w:lvl></w:abstractNum>
+  <w:num w:numId="1"><w:abstractNumId w:val="0"/></w:num>
+  <w:num w:numId="2"><w:abstractNumId w:val="1"/></w:num>
+</w:numbering>"#;
+
+        let document_xml = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+<w:body>
+<w:p><w:pPr><w:pStyle w:val="Heading1"/></w:pPr><w:r><w:t>Report</w:t></w:r></w:p>
+<w:p>
+  <w:r><w:rPr><w:b/></w:rPr><w:t>Bold</w:t></w:r>
+  <w:r><w:t xml:space="preserve"> and </w:t></w:r>
+  <w:r><w:rPr><w:i/></w:rPr><w:t>italic</w:t></w:r>
+  <w:r><w:t xml:space="preserve"> &amp; </w:t></w:r>
+  <w:r><w:rPr><w:u w:val="single"/></w:rPr><w:t>under</w:t></w:r>
+  <w:r><w:rPr><w:strike/></w:rPr><w:t>gone</w:t></w:r>
+</w:p>
+<w:p><w:pPr><w:numPr><w:ilvl w:val="0"/><w:numId w:val="1"/></w:numPr></w:pPr><w:r><w:t>Apple</w:t></w:r></w:p>
+<w:p><w:pPr><w:numPr><w:ilvl w:val="0"/><w:numId w:val="1"/></w:numPr></w:pPr><w:r><w:t>Pear</w:t></w:r></w:p>
+<w:p><w:pPr><w:numPr><w:ilvl w:val="0"/><w:numId w:val="2"/></w:numPr></w:pPr><w:r><w:t>First</w:t></w:r></w:p>
+<w:tbl>
+  <w:tr>
+    <w:tc><w:tcPr><w:gridSpan w:val="2"/></w:tcPr><w:p><w:r><w:t>Header</w:t></w:r></w:p></w:tc>
+  </w:tr>
+  <w:tr>
+    <w:tc><w:p><w:r><w:t>A</w:t></w:r></w:p></w:tc>
+    <w:tc><w:p><w:r><w:t>B</w:t></w:r></w:p></w:tc>
+  </w:tr>
+</w:tbl>
+</w:body>
+</w:document>"#;
+
+        let bytes = build_minimal_docx(document_xml, numbering_xml);
+        let doc = DocxParser::from_bytes(bytes).unwrap().parse().unwrap();
+        let html = doc.to_html();
+
+        assert!(html.starts_with("<article>\n") && html.ends_with("</article>"), "{}", html);
+        assert!(!html.contains("---"), "no frontmatter: {}", html);
+        assert!(html.contains("<h1>Report</h1>"), "{}", html);
+        assert!(html.contains(
+            "<p><strong>Bold</strong> and <em>italic</em> &amp; <u>under</u><del>gone</del></p>"
+        ), "{}", html);
+        assert!(html.contains("<ul>\n<li>Apple</li>\n<li>Pear</li>\n</ul>"), "{}", html);
+        assert!(html.contains("<ol>\n<li>First</li>\n</ol>"), "{}", html);
+        assert!(html.contains(
+            "<table>\n<thead>\n<tr><th colspan=\"2\">Header</th></tr>\n</thead>\n\
+             <tbody>\n<tr><td>A</td><td>B</td></tr>\n</tbody>\n</table>"
+        ), "{}", html);
+    }
 }
 
 // ============================================================================
@@ -408,6 +472,7 @@ mod pdf_tests {
             column_count: 2,
             y_top: 100.0,
             y_bottom: 80.0,
+            column_positions: Vec::new(),
         };
 
         let md = table.to_markdown();
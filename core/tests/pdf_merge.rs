@@ -0,0 +1,88 @@
+//! `PdfParser::merge` against two synthetic two-page PDFs.
+
+use lopdf::content::{Content, Operation};
+use lopdf::{dictionary, Document, Object, Stream};
+use mdm_core::pdf::PdfParser;
+
+/// Two Helvetica pages whose text is `"{label} page {n}"`.
+fn two_page_pdf(version: &str, label: &str) -> Vec<u8> {
+    let mut doc = Document::with_version(version);
+    let pages_id = doc.new_object_id();
+    let font_id = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Helvetica",
+    });
+    let mut kids = Vec::new();
+    for i in 1..=2 {
+        let content = Content {
+            operations: vec![
+                Operation::new("BT", vec![]),
+                Operation::new("Tf", vec!["F1".into(), 24.into()]),
+                Operation::new("Td", vec![100.into(), 700.into()]),
+                Operation::new("Tj", vec![Object::string_literal(format!("{} page {}", label, i))]),
+                Operation::new("ET", vec![]),
+            ],
+        };
+        let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+        kids.push(
+            doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => pages_id,
+                "Contents" => content_id,
+            })
+            .into(),
+        );
+    }
+    doc.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => kids,
+            "Count" => 2,
+            "Resources" => dictionary! { "Font" => dictionary! { "F1" => font_id } },
+            "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+        }),
+    );
+    let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    doc.trailer.set("Root", catalog_id);
+    let mut bytes = Vec::new();
+    doc.save_to(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn merge_renumbers_pages_across_documents() {
+    let dir = tempfile::tempdir().unwrap();
+    let first = dir.path().join("first.pdf");
+    let second = dir.path().join("second.pdf");
+    std::fs::write(&first, two_page_pdf("1.5", "Alpha")).unwrap();
+    std::fs::write(&second, two_page_pdf("1.4", "Beta")).unwrap();
+
+    let parsers = vec![PdfParser::open(&first).unwrap(), PdfParser::open(&second).unwrap()];
+    let doc = PdfParser::merge(parsers).unwrap();
+
+    assert_eq!(doc.page_count, 4);
+    assert_eq!(doc.version, "1.4", "lowest input version wins");
+    assert_eq!(doc.fonts.len(), 1, "shared Helvetica is listed once");
+
+    for page in &doc.pages {
+        let expected = if page.source_document == 0 { 1..=2 } else { 3..=4 };
+        assert!(expected.contains(&page.page_number), "page {} from doc {}", page.page_number, page.source_document);
+    }
+    let numbers: Vec<_> = doc.pages.iter().map(|p| p.page_number).collect();
+    assert!(numbers.windows(2).all(|w| w[0] < w[1]), "{:?}", numbers);
+    assert!(doc.pages.iter().any(|p| p.source_document == 1 && p.text.contains("Beta")));
+
+    for element in &doc.layout {
+        assert!((1..=4).contains(&element.page));
+        if element.content.contains("Beta") {
+            assert!(element.page >= 3, "{:?}", element);
+        }
+    }
+}
+
+#[test]
+fn merge_rejects_empty_input() {
+    assert!(PdfParser::merge(Vec::new()).is_err());
+}
@@ -0,0 +1,74 @@
+//! `PdfParser` tesseract OCR. Recognition needs `tesseract` (and, for
+//! whole pages, `pdftoppm` or `mutool`) on PATH; without them the tests only
+//! check that the missing tool is reported.
+
+use lopdf::{dictionary, Document, Object, Stream};
+use mdm_core::pdf::{ocr_png, PdfError, PdfParser};
+
+/// 1×1 white RGB PNG
+const WHITE_PIXEL_PNG: [u8; 69] = [
+    0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x02, 0x00, 0x00, 0x00, 0x90, 0x77, 0x53,
+    0xDE, 0x00, 0x00, 0x00, 0x0C, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9C, 0x63, 0xF8, 0xFF, 0xFF, 0x3F,
+    0x00, 0x05, 0xFE, 0x02, 0xFE, 0x0D, 0xEF, 0x46, 0xB8, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E,
+    0x44, 0xAE, 0x42, 0x60, 0x82,
+];
+
+/// One empty page, like a scan whose image failed to load
+fn blank_pdf() -> Vec<u8> {
+    let mut doc = Document::with_version("1.5");
+    let pages_id = doc.new_object_id();
+    let content_id = doc.add_object(Stream::new(dictionary! {}, Vec::new()));
+    let page_id = doc.add_object(dictionary! {
+        "Type" => "Page",
+        "Parent" => pages_id,
+        "Contents" => content_id,
+        "Resources" => dictionary! {},
+        "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+    });
+    doc.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! { "Type" => "Pages", "Kids" => vec![page_id.into()], "Count" => 1 }),
+    );
+    let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    doc.trailer.set("Root", catalog_id);
+    let mut bytes = Vec::new();
+    doc.save_to(&mut bytes).unwrap();
+    bytes
+}
+
+/// The external tool is missing in this environment
+fn tool_missing<T>(result: &Result<T, PdfError>) -> bool {
+    matches!(result, Err(PdfError::ParseError(msg)) if msg == "tesseract not found" || msg == "no renderer available")
+}
+
+#[test]
+fn ocr_png_of_white_pixel_is_empty() {
+    let result = ocr_png(&WHITE_PIXEL_PNG, "eng");
+    if tool_missing(&result) {
+        eprintln!("tesseract not installed; skipping recognition");
+        return;
+    }
+    assert_eq!(result.unwrap(), "");
+}
+
+#[test]
+fn ocr_page_rejects_out_of_range_page() {
+    let parser = PdfParser::from_bytes(blank_pdf()).unwrap();
+    for page in [0, 2] {
+        assert!(matches!(parser.ocr_page(page, "eng"), Err(PdfError::ParseError(msg)) if msg.contains("out of range")));
+    }
+}
+
+#[test]
+fn ocr_all_of_blank_page_has_empty_text() {
+    let result = PdfParser::from_bytes(blank_pdf()).unwrap().ocr_all("eng");
+    if tool_missing(&result) {
+        eprintln!("renderer or tesseract not installed; skipping recognition");
+        return;
+    }
+    let doc = result.unwrap();
+    assert_eq!(doc.pages.len(), 1);
+    assert_eq!(doc.pages[0].text, "");
+    assert!(doc.layout.is_empty());
+}
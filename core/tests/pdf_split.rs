@@ -0,0 +1,92 @@
+//! `PdfParser::split_by_page` against a synthetic three-page PDF.
+
+use lopdf::content::{Content, Operation};
+use lopdf::{dictionary, Document, Object, Stream};
+use mdm_core::pdf::PdfParser;
+
+/// Three pages sharing one font; `Resources` and `MediaBox` live on the
+/// `Pages` node so the split has to copy inherited attributes.
+fn three_page_pdf() -> Vec<u8> {
+    let mut doc = Document::with_version("1.5");
+    let pages_id = doc.new_object_id();
+    let font_id = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Helvetica",
+    });
+    let mut kids = Vec::new();
+    for i in 1..=3 {
+        let content = Content {
+            operations: vec![
+                Operation::new("BT", vec![]),
+                Operation::new("Tf", vec!["F1".into(), 24.into()]),
+                Operation::new("Td", vec![100.into(), 700.into()]),
+                Operation::new("Tj", vec![Object::string_literal(format!("Page {}", i))]),
+                Operation::new("ET", vec![]),
+            ],
+        };
+        let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+        kids.push(
+            doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => pages_id,
+                "Contents" => content_id,
+            })
+            .into(),
+        );
+    }
+    doc.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => kids,
+            "Count" => 3,
+            "Resources" => dictionary! { "Font" => dictionary! { "F1" => font_id } },
+            "MediaBox" => vec![0.into(), 0.into(), 612.into(), 792.into()],
+        }),
+    );
+    let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    doc.trailer.set("Root", catalog_id);
+    let mut bytes = Vec::new();
+    doc.save_to(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn split_writes_one_pdf_per_page() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("archive.pdf");
+    std::fs::write(&input, three_page_pdf()).unwrap();
+    let out_dir = dir.path().join("pages");
+
+    let paths = PdfParser::open(&input).unwrap().split_by_page(&out_dir).unwrap();
+    let names: Vec<_> = paths
+        .iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    assert_eq!(names, ["archive_page_0001.pdf", "archive_page_0002.pdf", "archive_page_0003.pdf"]);
+
+    for (i, path) in paths.iter().enumerate() {
+        assert!(path.exists());
+        let doc = Document::load(path).unwrap();
+        let pages = doc.get_pages();
+        assert_eq!(pages.len(), 1);
+
+        let page_id = pages[&1];
+        let page = doc.get_dictionary(page_id).unwrap();
+        assert!(page.has(b"MediaBox"), "inherited MediaBox copied onto the page");
+        let font_id = page
+            .get(b"Resources")
+            .and_then(Object::as_dict)
+            .and_then(|res| res.get(b"Font"))
+            .and_then(Object::as_dict)
+            .and_then(|fonts| fonts.get(b"F1"))
+            .and_then(Object::as_reference)
+            .unwrap();
+        let font = doc.get_dictionary(font_id).unwrap();
+        assert_eq!(font.get(b"BaseFont").unwrap().as_name().unwrap(), b"Helvetica", "page {}", i + 1);
+
+        let content = String::from_utf8_lossy(&doc.get_page_content(page_id).unwrap()).into_owned();
+        assert!(content.contains(&format!("Page {}", i + 1)), "{}", content);
+    }
+}
@@ -1,10 +1,37 @@
+use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
+use mdm_placeholder::render_mdm_reference_with;
 
 #[derive(Serialize, Deserialize)]
 pub struct Token {
     pub token_type: String,
+    /// Text content, or the filename of an `mdm-reference`
     pub value: String,
+    /// `key=value` options after the filename in `![[file|key=value|...]]`;
+    /// a bare `key` maps to an empty value
+    #[serde(default)]
+    pub attributes: HashMap<String, String>,
+    /// Tokens of a filename that nests references, e.g. `![[a ![[b]] c]]`;
+    /// empty otherwise
+    #[serde(default)]
+    pub children: Vec<Token>,
+}
+
+/// Nesting depth below which reference filenames are no longer tokenized
+const MAX_REFERENCE_DEPTH: usize = 8;
+
+/// Options for [`parse_mdm_with_options`]; missing keys take the defaults
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParseOptions {
+    /// Rewrite `!include[[...]]` targets to paths resolved against `base_url`
+    /// instead of emitting them as written
+    pub resolve_includes: bool,
+    /// URL of the document being parsed. Relative includes resolve against
+    /// its directory, and an include of the document itself becomes a
+    /// `warning` token.
+    pub base_url: String,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -16,40 +43,189 @@ pub struct ParseResult {
 /// Parse MDM syntax to tokens
 #[wasm_bindgen]
 pub fn parse_mdm(input: &str) -> JsValue {
-    let tokens = tokenize(input);
+    parse_with(input, &ParseOptions::default())
+}
+
+/// [`parse_mdm`] with a [`ParseOptions`] object; `undefined` or `null`
+/// means the defaults
+#[wasm_bindgen]
+pub fn parse_mdm_with_options(input: &str, options: JsValue) -> JsValue {
+    let options = if options.is_undefined() || options.is_null() {
+        ParseOptions::default()
+    } else {
+        serde_wasm_bindgen::from_value(options).unwrap_or_default()
+    };
+    parse_with(input, &options)
+}
+
+fn parse_with(input: &str, options: &ParseOptions) -> JsValue {
+    let tokens = tokenize_with(input, options);
     let html = render_tokens(&tokens);
-    
+
     let result = ParseResult { tokens, html };
-    
+
     serde_wasm_bindgen::to_value(&result).unwrap()
 }
 
+/// Node of the tree returned by [`parse_mdm_to_ast`]
+///
+/// - `document`: root, children are `text`, `mdm-ref` and `mdm-block` nodes
+/// - `text`: `value` holds the text
+/// - `mdm-ref`: `attributes` holds `src`, `alt` and any `key=value` options;
+///   children are the parsed filename when it nests references
+/// - `mdm-block`: two or more references separated only by whitespace
+/// - `mdm-include`: `attributes` holds the `src` of an `!include[[...]]`
+/// - `warning`: `value` holds the message, e.g. for a self-include
+/// - `error`: `value` holds the unparsed source, e.g. an unclosed `![[`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AstNode {
+    pub node_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub attributes: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<AstNode>,
+}
+
+impl AstNode {
+    fn new(node_type: &str) -> Self {
+        Self {
+            node_type: node_type.to_string(),
+            value: None,
+            attributes: HashMap::new(),
+            children: Vec::new(),
+        }
+    }
+}
+
+/// Parse MDM syntax to a `document` [`AstNode`] tree for custom renderers
+#[wasm_bindgen]
+pub fn parse_mdm_to_ast(input: &str) -> JsValue {
+    // Plain objects rather than ES `Map`s for the attribute maps
+    let serializer = serde_wasm_bindgen::Serializer::json_compatible();
+    build_ast(&tokenize(input)).serialize(&serializer).unwrap()
+}
+
+fn build_ast(tokens: &[Token]) -> AstNode {
+    let mut document = AstNode::new("document");
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let token = &tokens[i];
+        if matches!(token.token_type.as_str(), "mdm-include" | "warning" | "error") {
+            let mut node = AstNode::new(&token.token_type);
+            if token.token_type != "mdm-include" {
+                node.value = Some(token.value.clone());
+            } else {
+                node.attributes.insert("src".to_string(), token.value.clone());
+            }
+            document.children.push(node);
+            i += 1;
+            continue;
+        }
+        if token.token_type != "mdm-reference" {
+            let mut text = AstNode::new("text");
+            text.value = Some(token.value.clone());
+            document.children.push(text);
+            i += 1;
+            continue;
+        }
+
+        // Collect references separated only by whitespace into one block
+        let mut refs = vec![reference_node(token)];
+        let mut next = i + 1;
+        while next + 1 < tokens.len()
+            && tokens[next].token_type == "text"
+            && tokens[next].value.trim().is_empty()
+            && tokens[next + 1].token_type == "mdm-reference"
+        {
+            refs.push(reference_node(&tokens[next + 1]));
+            next += 2;
+        }
+        if refs.len() == 1 {
+            document.children.append(&mut refs);
+        } else {
+            let mut block = AstNode::new("mdm-block");
+            block.children = refs;
+            document.children.push(block);
+        }
+        i = next;
+    }
+
+    document
+}
+
+/// `mdm-ref` node; options may override the default `alt` but not `src`
+fn reference_node(token: &Token) -> AstNode {
+    let src = reference_src(token);
+    let mut node = AstNode::new("mdm-ref");
+    node.attributes = token.attributes.clone();
+    node.attributes.entry("alt".to_string()).or_insert_with(|| src.clone());
+    node.attributes.insert("src".to_string(), src);
+    if !token.children.is_empty() {
+        node.children = build_ast(&token.children).children;
+    }
+    node
+}
+
 fn tokenize(input: &str) -> Vec<Token> {
+    tokenize_with(input, &ParseOptions::default())
+}
+
+fn tokenize_with(input: &str, options: &ParseOptions) -> Vec<Token> {
+    tokenize_at(input, options, 0)
+}
+
+fn tokenize_at(input: &str, options: &ParseOptions, depth: usize) -> Vec<Token> {
     let mut tokens = Vec::new();
     let mut current_pos = 0;
     
     while current_pos < input.len() {
         let remaining = &input[current_pos..];
-        
-        // Look for MDM reference: ![[...]]
-        if remaining.starts_with("![[") {
+
+        // Look for an include: !include[[...]]
+        if remaining.starts_with("!include[[") {
             if let Some(end_pos) = remaining.find("]]") {
-                let content = &remaining[3..end_pos];
-                tokens.push(Token {
-                    token_type: "mdm-reference".to_string(),
-                    value: content.to_string(),
-                });
+                let target = remaining["!include[[".len()..end_pos].trim();
+                tokens.push(include_token(target, options));
                 current_pos += end_pos + 2;
                 continue;
             }
         }
         
-        // Regular text
-        let next_mdm = remaining.find("![[").unwrap_or(remaining.len());
+        // Look for MDM reference: ![[...]], possibly nesting further ones
+        if let Some(inner) = remaining.strip_prefix("![[") {
+            match find_closing(inner) {
+                Some(end_pos) => {
+                    tokens.push(reference_token(&inner[..end_pos], depth));
+                    current_pos += 3 + end_pos + 2;
+                }
+                None => {
+                    tokens.push(Token {
+                        token_type: "error".to_string(),
+                        value: remaining.to_string(),
+                        attributes: HashMap::new(),
+                        children: Vec::new(),
+                    });
+                    current_pos = input.len();
+                }
+            }
+            continue;
+        }
+        
+        // Regular text, up to the next reference or include
+        let next_mdm = [remaining.find("![["), remaining.find("!include[[")]
+            .into_iter()
+            .flatten()
+            .min()
+            .unwrap_or(remaining.len());
         if next_mdm > 0 {
             tokens.push(Token {
                 token_type: "text".to_string(),
                 value: remaining[..next_mdm].to_string(),
+                attributes: HashMap::new(),
+                children: Vec::new(),
             });
             current_pos += next_mdm;
         } else {
@@ -60,6 +236,149 @@ fn tokenize(input: &str) -> Vec<Token> {
     tokens
 }
 
+/// Offset of the `]]` that closes a reference whose body starts at
+/// `body`, skipping over nested `[[...]]` pairs
+fn find_closing(body: &str) -> Option<usize> {
+    let bytes = body.as_bytes();
+    let mut depth = 1;
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        match &bytes[i..i + 2] {
+            b"[[" => {
+                depth += 1;
+                i += 2;
+            }
+            b"]]" => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+                i += 2;
+            }
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// `mdm-reference` token for the body of `![[...]]`. Options start at the
+/// first `|` outside nested references; a filename that itself contains
+/// references is tokenized into `children`.
+fn reference_token(content: &str, depth: usize) -> Token {
+    let bytes = content.as_bytes();
+    let mut nesting = 0usize;
+    let mut split = content.len();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i..].starts_with(b"[[") {
+            nesting += 1;
+            i += 2;
+        } else if bytes[i..].starts_with(b"]]") {
+            nesting = nesting.saturating_sub(1);
+            i += 2;
+        } else if bytes[i] == b'|' && nesting == 0 {
+            split = i;
+            break;
+        } else {
+            i += 1;
+        }
+    }
+    let filename = content[..split].trim();
+    let options = content.get(split + 1..).unwrap_or("");
+
+    let children = if filename.contains("![[") && depth < MAX_REFERENCE_DEPTH {
+        tokenize_at(filename, &ParseOptions::default(), depth + 1)
+    } else {
+        Vec::new()
+    };
+    Token {
+        token_type: "mdm-reference".to_string(),
+        value: filename.to_string(),
+        attributes: parse_attributes(options),
+        children,
+    }
+}
+
+/// `src` of a reference: the filename with each nested reference replaced
+/// by its own `src`, innermost first
+fn reference_src(token: &Token) -> String {
+    if token.children.is_empty() {
+        return token.value.clone();
+    }
+    token
+        .children
+        .iter()
+        .map(|child| match child.token_type.as_str() {
+            "mdm-reference" => reference_src(child),
+            _ => child.value.clone(),
+        })
+        .collect()
+}
+
+/// `mdm-include` token for `target`, or a `warning` token when it resolves
+/// to the document itself (the host would otherwise include it forever)
+fn include_token(target: &str, options: &ParseOptions) -> Token {
+    let resolved = resolve_url(&options.base_url, target);
+    let (token_type, value) = if !options.base_url.is_empty()
+        && resolved == resolve_url("", &options.base_url)
+    {
+        ("warning", format!("self-referential include: {}", target))
+    } else if options.resolve_includes {
+        ("mdm-include", resolved)
+    } else {
+        ("mdm-include", target.to_string())
+    };
+    Token {
+        token_type: token_type.to_string(),
+        value,
+        attributes: HashMap::new(),
+        children: Vec::new(),
+    }
+}
+
+/// Resolve `target` against the directory of `base`, folding `.` and `..`
+/// segments. Absolute targets (`/x`, `https://...`) only get folded.
+fn resolve_url(base: &str, target: &str) -> String {
+    let joined = if target.contains("://") || target.starts_with('/') {
+        target.to_string()
+    } else {
+        let dir = base.rfind('/').map_or("", |i| &base[..=i]);
+        format!("{}{}", dir, target)
+    };
+    // Keep `scheme://host/` out of the folding
+    let (prefix, path) = match joined.find("://") {
+        Some(i) => {
+            let host_end = joined[i + 3..].find('/').map_or(joined.len(), |j| i + 3 + j);
+            joined.split_at(host_end)
+        }
+        None => ("", joined.as_str()),
+    };
+
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "." => {}
+            ".." if segments.last().is_some_and(|s| !s.is_empty() && *s != "..") => {
+                segments.pop();
+            }
+            _ => segments.push(segment),
+        }
+    }
+    format!("{}{}", prefix, segments.join("/"))
+}
+
+/// `width=400|alt=Sales Chart` → {width: 400, alt: Sales Chart}
+fn parse_attributes(options: &str) -> HashMap<String, String> {
+    options
+        .split('|')
+        .filter_map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let key = key.trim();
+            (!key.is_empty()).then(|| (key.to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
 fn render_tokens(tokens: &[Token]) -> String {
     let mut html = String::new();
     
@@ -67,12 +386,28 @@ fn render_tokens(tokens: &[Token]) -> String {
         match token.token_type.as_str() {
             "text" => html.push_str(&token.value),
             "mdm-reference" => {
-                // Parse reference
-                let parts: Vec<&str> = token.value.split('|').collect();
-                let filename = parts[0].trim();
-                
-                // Simple image rendering
-                html.push_str(&format!("<img src=\"{}\" alt=\"{}\">", filename, filename));
+                // Sorted so the attribute order in the HTML is stable
+                let mut attributes: Vec<(&str, &str)> = token
+                    .attributes
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str()))
+                    .collect();
+                attributes.sort();
+
+                // No media store in the browser build: every reference
+                // renders as a labelled placeholder instead of a broken <img>
+                html.push_str(&render_mdm_reference_with(&reference_src(token), None, &attributes));
+            }
+            // Left for the host application to fetch and splice in
+            "mdm-include" => {
+                html.push_str(&format!("<mdm-include src=\"{}\" />", escape_attr(&token.value)));
+            }
+            "warning" => {
+                html.push_str(&format!("<!-- {} -->", token.value.replace("--", "- -")));
+            }
+            // Kept visible so the author can spot the broken reference
+            "error" => {
+                html.push_str(&format!("<span class=\"mdm-error\">{}</span>", escape_attr(&token.value)));
             }
             _ => {}
         }
@@ -81,6 +416,14 @@ fn render_tokens(tokens: &[Token]) -> String {
     html
 }
 
+fn escape_attr(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,4 +438,179 @@ mod tests {
         assert_eq!(tokens[1].token_type, "mdm-reference");
         assert_eq!(tokens[2].token_type, "text");
     }
+
+    #[test]
+    fn test_render_missing_reference_as_placeholder() {
+        let tokens = tokenize("Hello ![[image.jpg | width=300]] world");
+        let html = render_tokens(&tokens);
+
+        assert!(html.starts_with("Hello <img src=\"data:image/svg+xml;base64,"));
+        assert!(html.contains("alt=\"image.jpg\""));
+        assert!(html.ends_with("> world"));
+        assert!(!html.contains("src=\"image.jpg\""));
+    }
+
+    #[test]
+    fn test_reference_attributes() {
+        let tokens = tokenize("![[chart.png|width=400|alt=Sales Chart]]");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].value, "chart.png");
+        assert_eq!(tokens[0].attributes.len(), 2);
+        assert_eq!(tokens[0].attributes["width"], "400");
+        assert_eq!(tokens[0].attributes["alt"], "Sales Chart");
+
+        let html = render_tokens(&tokens);
+        assert!(html.contains("alt=\"Sales Chart\" width=\"400\" height=\"300\""), "{}", html);
+    }
+
+    #[test]
+    fn test_reference_attributes_missing_values() {
+        let tokens = tokenize("![[chart.png|width=|caption| =x|title=Q1]]");
+        let attrs = &tokens[0].attributes;
+        assert_eq!(attrs.len(), 3);
+        assert_eq!(attrs["width"], "");
+        assert_eq!(attrs["caption"], "");
+        assert_eq!(attrs["title"], "Q1");
+
+        // Empty values fall back to the defaults
+        let html = render_tokens(&tokens);
+        assert!(html.contains("alt=\"chart.png\" width=\"400\" height=\"300\""), "{}", html);
+        assert!(html.contains(" title=\"Q1\">"), "{}", html);
+        assert!(!html.contains("caption"), "{}", html);
+    }
+
+    #[test]
+    fn test_reference_event_handler_attributes_dropped() {
+        let html = render_tokens(&tokenize("![[chart.png|onerror=alert(1)|onclick=x|title=Q1]]"));
+        assert!(!html.contains("onerror"), "{}", html);
+        assert!(!html.contains("onclick"), "{}", html);
+        assert!(html.contains(" title=\"Q1\">"), "{}", html);
+    }
+
+    #[test]
+    fn test_build_ast() {
+        let ast = build_ast(&tokenize("Intro ![[a.png|width=300]]\n![[b.png|alt=Second]] outro ![[c.png]]"));
+        assert_eq!(ast.node_type, "document");
+        let types: Vec<&str> = ast.children.iter().map(|n| n.node_type.as_str()).collect();
+        assert_eq!(types, vec!["text", "mdm-block", "text", "mdm-ref"]);
+        assert_eq!(ast.children[0].value.as_deref(), Some("Intro "));
+
+        let block = &ast.children[1];
+        assert_eq!(block.children.len(), 2);
+        assert_eq!(block.children[0].attributes["src"], "a.png");
+        assert_eq!(block.children[0].attributes["alt"], "a.png");
+        assert_eq!(block.children[0].attributes["width"], "300");
+        assert_eq!(block.children[1].attributes["alt"], "Second");
+
+        let single = &ast.children[3];
+        assert!(single.children.is_empty());
+        assert_eq!(single.attributes["src"], "c.png");
+    }
+
+    #[test]
+    fn test_reference_empty_attributes() {
+        for input in ["![[file.png|]]", "![[file.png]]"] {
+            let tokens = tokenize(input);
+            assert_eq!(tokens[0].value, "file.png");
+            assert!(tokens[0].attributes.is_empty(), "{}", input);
+        }
+        assert_eq!(
+            render_tokens(&tokenize("![[file.png|]]")),
+            render_tokens(&tokenize("![[file.png]]"))
+        );
+    }
+
+    #[test]
+    fn test_nested_reference_tokens() {
+        let tokens = tokenize("![[image ![[overlay]] rest|width=300]] after");
+        assert_eq!(tokens.len(), 2);
+        let outer = &tokens[0];
+        assert_eq!(outer.value, "image ![[overlay]] rest");
+        assert_eq!(outer.attributes["width"], "300");
+        let types: Vec<&str> = outer.children.iter().map(|t| t.token_type.as_str()).collect();
+        assert_eq!(types, vec!["text", "mdm-reference", "text"]);
+        assert_eq!(outer.children[1].value, "overlay");
+        assert_eq!(reference_src(outer), "image overlay rest");
+        assert_eq!(tokens[1].value, " after");
+
+        // `|` inside the nested reference does not start the outer options
+        let tokens = tokenize("![[a ![[b|alt=B]] c]]");
+        assert!(tokens[0].attributes.is_empty());
+        assert_eq!(tokens[0].children[1].attributes["alt"], "B");
+    }
+
+    #[test]
+    fn test_unclosed_reference_is_error() {
+        let tokens = tokenize("Hello ![[unclosed ![[inner]]");
+        let types: Vec<&str> = tokens.iter().map(|t| t.token_type.as_str()).collect();
+        assert_eq!(types, vec!["text", "error"]);
+        assert_eq!(tokens[1].value, "![[unclosed ![[inner]]");
+        assert_eq!(
+            render_tokens(&tokens),
+            "Hello <span class=\"mdm-error\">![[unclosed ![[inner]]</span>"
+        );
+    }
+
+    #[test]
+    fn test_include_emits_custom_element() {
+        let tokens = tokenize("Intro !include[[ chapters/one.mdm ]] then ![[a.png]]");
+        let types: Vec<&str> = tokens.iter().map(|t| t.token_type.as_str()).collect();
+        assert_eq!(types, vec!["text", "mdm-include", "text", "mdm-reference"]);
+        assert_eq!(tokens[1].value, "chapters/one.mdm");
+
+        let html = render_tokens(&tokens);
+        assert!(html.starts_with("Intro <mdm-include src=\"chapters/one.mdm\" /> then <img"), "{}", html);
+
+        // Unclosed include stays text
+        let tokens = tokenize("!include[[open.mdm");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].token_type, "text");
+    }
+
+    #[test]
+    fn test_nested_includes_resolve_against_each_document() {
+        let book = ParseOptions {
+            resolve_includes: true,
+            base_url: "https://example.com/book/index.mdm".to_string(),
+        };
+        let tokens = tokenize_with("!include[[chapters/one.mdm]]", &book);
+        assert_eq!(tokens[0].value, "https://example.com/book/chapters/one.mdm");
+
+        // The host parses the included chapter with its own URL as the base
+        let chapter = ParseOptions {
+            resolve_includes: true,
+            base_url: tokens[0].value.clone(),
+        };
+        let tokens = tokenize_with("!include[[./sections/a.mdm]] !include[[../appendix.mdm]]", &chapter);
+        assert_eq!(tokens[0].value, "https://example.com/book/chapters/sections/a.mdm");
+        assert_eq!(tokens[2].value, "https://example.com/book/appendix.mdm");
+
+        // Without resolve_includes the target is kept as written
+        let written = ParseOptions { resolve_includes: false, ..chapter };
+        assert_eq!(tokenize_with("!include[[../appendix.mdm]]", &written)[0].value, "../appendix.mdm");
+
+        let ast = build_ast(&tokenize_with("!include[[chapters/one.mdm]]", &book));
+        assert_eq!(ast.children[0].node_type, "mdm-include");
+        assert_eq!(ast.children[0].attributes["src"], "https://example.com/book/chapters/one.mdm");
+    }
+
+    #[test]
+    fn test_self_referential_include_is_warning() {
+        let options = ParseOptions {
+            resolve_includes: false,
+            base_url: "docs/main.mdm".to_string(),
+        };
+        for input in ["!include[[main.mdm]]", "!include[[../docs/./main.mdm]]"] {
+            let tokens = tokenize_with(input, &options);
+            assert_eq!(tokens[0].token_type, "warning", "{}", input);
+            assert!(tokens[0].value.contains("self-referential include"));
+            let html = render_tokens(&tokens);
+            assert!(html.starts_with("<!-- ") && !html.contains("<mdm-include"), "{}", html);
+        }
+
+        // `/docs/main.mdm` is not the relative `docs/main.mdm`
+        assert_eq!(tokenize_with("!include[[/docs/main.mdm]]", &options)[0].token_type, "mdm-include");
+        // Without a base URL there is nothing to compare against
+        assert_eq!(tokenize("!include[[main.mdm]]")[0].token_type, "mdm-include");
+    }
 }
@@ -0,0 +1,98 @@
+//! `parse_mdm` and `parse_mdm_to_ast` through the JS boundary; run with `wasm-pack test --node`.
+
+#![cfg(target_arch = "wasm32")]
+
+use mdm_parser_rs::{parse_mdm, parse_mdm_to_ast, parse_mdm_with_options, AstNode, ParseResult};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+fn ast(input: &str) -> (JsValue, AstNode) {
+    let value = parse_mdm_to_ast(input);
+    let node = serde_wasm_bindgen::from_value(value.clone()).unwrap();
+    (value, node)
+}
+
+#[wasm_bindgen_test]
+fn text_and_reference_nodes() {
+    let (_, root) = ast("Hello ![[image.jpg|width=400]] world");
+    assert_eq!(root.node_type, "document");
+    assert_eq!(root.children.len(), 3);
+    assert_eq!(root.children[0].value.as_deref(), Some("Hello "));
+
+    let reference = &root.children[1];
+    assert_eq!(reference.node_type, "mdm-ref");
+    assert_eq!(reference.attributes["src"], "image.jpg");
+    assert_eq!(reference.attributes["alt"], "image.jpg");
+    assert_eq!(reference.attributes["width"], "400");
+}
+
+#[wasm_bindgen_test]
+fn consecutive_references_form_a_block() {
+    let (_, root) = ast("![[a.png]] ![[b.png]]");
+    assert_eq!(root.children.len(), 1);
+    assert_eq!(root.children[0].node_type, "mdm-block");
+    assert_eq!(root.children[0].children.len(), 2);
+}
+
+#[wasm_bindgen_test]
+fn attributes_are_plain_objects() {
+    let (value, _) = ast("![[a.png|alt=Chart]]");
+    let children = js_sys::Reflect::get(&value, &"children".into()).unwrap();
+    let first = js_sys::Reflect::get(&children, &0.into()).unwrap();
+    let attributes = js_sys::Reflect::get(&first, &"attributes".into()).unwrap();
+    let alt = js_sys::Reflect::get(&attributes, &"alt".into()).unwrap();
+    assert_eq!(alt.as_string().as_deref(), Some("Chart"));
+    // Text nodes carry no empty `children` / `attributes` keys
+    let (text, _) = ast("plain");
+    let children = js_sys::Reflect::get(&text, &"children".into()).unwrap();
+    let node = js_sys::Reflect::get(&children, &0.into()).unwrap();
+    assert!(js_sys::Reflect::get(&node, &"children".into()).unwrap().is_undefined());
+}
+
+#[wasm_bindgen_test]
+fn include_options_cross_the_boundary() {
+    let options = js_sys::Object::new();
+    js_sys::Reflect::set(&options, &"resolve_includes".into(), &true.into()).unwrap();
+    js_sys::Reflect::set(&options, &"base_url".into(), &"/book/index.mdm".into()).unwrap();
+    let result = parse_mdm_with_options("!include[[one.mdm]]", options.into());
+    let html = js_sys::Reflect::get(&result, &"html".into()).unwrap();
+    assert_eq!(html.as_string().as_deref(), Some("<mdm-include src=\"/book/one.mdm\" />"));
+
+    let defaults = parse_mdm_with_options("!include[[one.mdm]]", JsValue::UNDEFINED);
+    let html = js_sys::Reflect::get(&defaults, &"html".into()).unwrap();
+    assert_eq!(html.as_string().as_deref(), Some("<mdm-include src=\"one.mdm\" />"));
+}
+
+#[wasm_bindgen_test]
+fn two_levels_of_nested_references() {
+    let input = "![[frame ![[photo ![[overlay]] v2]] end|width=300]]";
+    let result: ParseResult = serde_wasm_bindgen::from_value(parse_mdm(input)).unwrap();
+    assert_eq!(result.tokens.len(), 1);
+    let outer = &result.tokens[0];
+    assert_eq!(outer.children.len(), 3);
+    let middle = &outer.children[1];
+    assert_eq!(middle.value, "photo ![[overlay]] v2");
+    assert_eq!(middle.children[1].value, "overlay");
+    assert!(middle.children[1].children.is_empty());
+    assert!(result.html.contains("alt=\"frame photo overlay v2 end\""), "{}", result.html);
+
+    let (_, root) = ast(input);
+    let reference = &root.children[0];
+    assert_eq!(reference.attributes["src"], "frame photo overlay v2 end");
+    assert_eq!(reference.attributes["width"], "300");
+    assert_eq!(reference.children[1].attributes["src"], "photo overlay v2");
+    assert_eq!(reference.children[1].children[1].attributes["src"], "overlay");
+}
+
+#[wasm_bindgen_test]
+fn unclosed_reference_emits_error_token() {
+    let result: ParseResult = serde_wasm_bindgen::from_value(parse_mdm("see ![[unclosed")).unwrap();
+    assert_eq!(result.tokens.len(), 2);
+    assert_eq!(result.tokens[1].token_type, "error");
+    assert_eq!(result.tokens[1].value, "![[unclosed");
+    assert_eq!(result.html, "see <span class=\"mdm-error\">![[unclosed</span>");
+
+    let (_, root) = ast("![[unclosed");
+    assert_eq!(root.children[0].node_type, "error");
+    assert_eq!(root.children[0].value.as_deref(), Some("![[unclosed"));
+}
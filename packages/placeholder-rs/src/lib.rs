@@ -0,0 +1,215 @@
+//! Placeholder media for unresolved MDM references
+//!
+//! An `![[image.jpg]]` reference whose file cannot be found used to render as
+//! a bare `<img src="image.jpg">`, which browsers show as a broken-image icon.
+//! These helpers render a self-contained `data:` URI instead: the real bytes
+//! when the media was resolved, or a grey "missing" SVG naming the file.
+//!
+//! A separate crate so the lightweight WASM parser (`packages/parser-rs`)
+//! can use it without depending on `mdm-core`. `mdm-core` re-exports it as
+//! `mdm_core::placeholder`, and its `Renderer` re-exposes both functions.
+
+use base64::Engine;
+
+/// Size of the placeholder emitted by [`render_mdm_reference`]
+pub const PLACEHOLDER_WIDTH: u32 = 400;
+pub const PLACEHOLDER_HEIGHT: u32 = 300;
+
+/// Reference options copied verbatim onto the `<img>`; `alt`, `width` and
+/// `height` are rendered separately. Anything else (`on*` handlers, `style`,
+/// `srcset`, ...) is dropped.
+const PASSTHROUGH_ATTRIBUTES: [&str; 2] = ["title", "loading"];
+
+/// Grey box with a diagonal cross and `label` centered, as an SVG document
+pub fn placeholder_svg(width: u32, height: u32, label: &str) -> String {
+    let (w, h) = (width.max(1), height.max(1));
+    let font_size = (w.min(h) / 10).clamp(10, 24);
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">
+  <rect width="{w}" height="{h}" fill="#e0e0e0" stroke="#9e9e9e" stroke-width="2"/>
+  <line x1="0" y1="0" x2="{w}" y2="{h}" stroke="#9e9e9e" stroke-width="1"/>
+  <line x1="{w}" y1="0" x2="0" y2="{h}" stroke="#9e9e9e" stroke-width="1"/>
+  <text x="50%" y="50%" text-anchor="middle" dominant-baseline="middle" fill="#616161" font-family="sans-serif" font-size="{font_size}">{label}</text>
+</svg>"##,
+        label = escape_xml(label),
+    )
+}
+
+/// `<img>` for an MDM reference: the resolved bytes inlined as a `data:` URI,
+/// or a [`placeholder_svg`] labelled with `filename` when `resolved` is `None`
+pub fn render_mdm_reference(filename: &str, resolved: Option<&[u8]>) -> String {
+    render_mdm_reference_with(filename, resolved, &[])
+}
+
+/// [`render_mdm_reference`] with the `key=value` options of
+/// `![[file|key=value|...]]` as extra `<img>` attributes.
+///
+/// `alt` replaces the filename as alt text and a numeric `width`/`height`
+/// also sizes the placeholder. Of the other options only `title` and
+/// `loading` are passed through; empty values are dropped.
+pub fn render_mdm_reference_with(
+    filename: &str,
+    resolved: Option<&[u8]>,
+    attributes: &[(&str, &str)],
+) -> String {
+    let attr = |key: &str| {
+        attributes
+            .iter()
+            .find(|(k, v)| k.eq_ignore_ascii_case(key) && !v.is_empty())
+            .map(|(_, v)| *v)
+    };
+    let alt = escape_xml(attr("alt").unwrap_or(filename));
+    let extra: String = PASSTHROUGH_ATTRIBUTES
+        .iter()
+        .filter_map(|key| attr(key).map(|v| format!(" {}=\"{}\"", key, escape_xml(v))))
+        .collect();
+
+    match resolved {
+        Some(bytes) => {
+            let mut size = String::new();
+            for key in ["width", "height"] {
+                if let Some(value) = attr(key) {
+                    size.push_str(&format!(" {}=\"{}\"", key, escape_xml(value)));
+                }
+            }
+            format!(
+                "<img src=\"data:{};base64,{}\" alt=\"{}\"{}{}>",
+                media_mime(filename, bytes),
+                base64::engine::general_purpose::STANDARD.encode(bytes),
+                alt,
+                size,
+                extra
+            )
+        }
+        None => {
+            let px = |key: &str| attr(key).and_then(|v| v.trim_end_matches("px").parse::<u32>().ok());
+            // Keep the default 4:3 box when only one side is given
+            let (width, height) = match (px("width"), px("height")) {
+                (Some(w), Some(h)) => (w, h),
+                (Some(w), None) => (w, w.saturating_mul(PLACEHOLDER_HEIGHT) / PLACEHOLDER_WIDTH),
+                (None, Some(h)) => (h.saturating_mul(PLACEHOLDER_WIDTH) / PLACEHOLDER_HEIGHT, h),
+                (None, None) => (PLACEHOLDER_WIDTH, PLACEHOLDER_HEIGHT),
+            };
+            let svg = placeholder_svg(width, height, filename);
+            format!(
+                "<img src=\"data:image/svg+xml;base64,{}\" alt=\"{}\" width=\"{}\" height=\"{}\" class=\"mdm-missing\"{}>",
+                base64::engine::general_purpose::STANDARD.encode(svg),
+                alt,
+                width,
+                height,
+                extra
+            )
+        }
+    }
+}
+
+/// MIME type from magic bytes, falling back to the file extension
+fn media_mime(filename: &str, bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(&[0x89, b'P', b'N', b'G']) {
+        return "image/png";
+    }
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        return "image/jpeg";
+    }
+    if bytes.starts_with(b"GIF8") {
+        return "image/gif";
+    }
+    if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        return "image/webp";
+    }
+    if bytes.starts_with(b"BM") {
+        return "image/bmp";
+    }
+    let ext = filename.rsplit_once('.').map(|(_, e)| e.to_ascii_lowercase()).unwrap_or_default();
+    match ext.as_str() {
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "mp3" => "audio/mpeg",
+        _ => "application/octet-stream",
+    }
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placeholder_has_box_cross_and_label() {
+        let svg = placeholder_svg(200, 100, "chart.png");
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="200" height="100""#));
+        assert!(svg.contains(r##"<rect width="200" height="100" fill="#e0e0e0""##));
+        assert!(svg.contains(r#"<line x1="0" y1="0" x2="200" y2="100""#));
+        assert!(svg.contains(r#"<line x1="200" y1="0" x2="0" y2="100""#));
+        assert!(svg.contains(">chart.png</text>"));
+    }
+
+    #[test]
+    fn placeholder_escapes_label() {
+        let svg = placeholder_svg(0, 0, "a<b>&\"c\".png");
+        assert!(svg.contains("a&lt;b&gt;&amp;&quot;c&quot;.png"));
+        assert!(svg.contains(r#"width="1" height="1""#));
+    }
+
+    #[test]
+    fn missing_reference_uses_svg_placeholder() {
+        let html = render_mdm_reference("missing.jpg", None);
+        assert!(html.starts_with("<img src=\"data:image/svg+xml;base64,"));
+        assert!(html.contains("alt=\"missing.jpg\""));
+
+        let encoded = html.split("base64,").nth(1).unwrap().split('"').next().unwrap();
+        let svg = base64::engine::general_purpose::STANDARD.decode(encoded).unwrap();
+        assert!(String::from_utf8(svg).unwrap().contains(">missing.jpg</text>"));
+    }
+
+    #[test]
+    fn resolved_reference_inlines_bytes() {
+        let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        let html = render_mdm_reference("photo.bin", Some(&png));
+        assert_eq!(html, "<img src=\"data:image/png;base64,iVBORw0KGgo=\" alt=\"photo.bin\">");
+
+        let svg = render_mdm_reference("icon.svg", Some(b"<svg/>"));
+        assert!(svg.starts_with("<img src=\"data:image/svg+xml;base64,"));
+    }
+
+    #[test]
+    fn reference_attributes_size_and_label_img() {
+        let html = render_mdm_reference_with("chart.png", None, &[("width", "200"), ("alt", "Sales \"Q1\"")]);
+        assert!(html.contains("alt=\"Sales &quot;Q1&quot;\" width=\"200\" height=\"150\""), "{}", html);
+
+        let png = [0x89, b'P', b'N', b'G'];
+        let html = render_mdm_reference_with(
+            "photo.png",
+            Some(&png),
+            &[("width", "50%"), ("title", "Photo"), ("src", "x.png"), ("on click", "x"), ("height", "")],
+        );
+        assert_eq!(
+            html,
+            "<img src=\"data:image/png;base64,iVBORw==\" alt=\"photo.png\" width=\"50%\" title=\"Photo\">"
+        );
+    }
+
+    #[test]
+    fn reference_attributes_outside_allowlist_are_dropped() {
+        let html = render_mdm_reference_with(
+            "chart.png",
+            None,
+            &[("onerror", "alert(1)"), ("ONLOAD", "x"), ("style", "display:none"), ("loading", "lazy")],
+        );
+        assert!(!html.to_ascii_lowercase().contains("onerror"), "{}", html);
+        assert!(!html.to_ascii_lowercase().contains("onload"), "{}", html);
+        assert!(!html.contains("style="), "{}", html);
+        assert!(html.ends_with(" class=\"mdm-missing\" loading=\"lazy\">"), "{}", html);
+    }
+}